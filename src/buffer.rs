@@ -156,8 +156,34 @@ pub enum BufferError {
     InvalidBuffer(Buffer),
     InvalidParameterValue(i64),
     ImmutableBufferTarget(BufferBindingTarget),
+    InvalidBindingIndex(BufferBindingRangeTarget, u32),
 }
 
+impl std::fmt::Display for BufferError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BufferError::Unexpected(error) => write!(f, "unexpected buffer error: {error}"),
+            BufferError::UnboundTarget(target) => {
+                write!(f, "no buffer object is bound to {target:?}")
+            }
+            BufferError::InvalidBuffer(buffer) => {
+                write!(f, "{} is not the name of a buffer object", buffer.0)
+            }
+            BufferError::InvalidParameterValue(value) => {
+                write!(f, "unknown buffer parameter value {value:#X}")
+            }
+            BufferError::ImmutableBufferTarget(target) => {
+                write!(f, "the buffer bound to {target:?} has immutable storage")
+            }
+            BufferError::InvalidBindingIndex(target, index) => {
+                write!(f, "binding point {index} of {target:?} is out of range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BufferError {}
+
 bitflags::bitflags! {
     pub struct BufferMapFlags: u32 {
         /// indicates that the returned pointer may be used to read buffer object data. No GL error
@@ -472,6 +498,8 @@ pub struct Buffer(pub u32);
 /// # Errors
 /// * [Error::InvalidValue] - if buffer is not a name previously returned from a call to
 /// [gen_buffers].
+/// * [Error::InvalidOperation] - if buffer is not a name previously returned from a call to
+///   [gen_buffers], as reported by core profile contexts.
 ///
 /// # Associated Gets
 /// TODO
@@ -504,7 +532,7 @@ pub fn bind_buffer_checked(target: BufferBindingTarget, buffer: Buffer) -> Resul
     bind_buffer(target, buffer);
     match get_error() {
         Error::NoError => Ok(()),
-        Error::InvalidValue => Err(BufferError::InvalidBuffer(buffer)),
+        Error::InvalidValue | Error::InvalidOperation => Err(BufferError::InvalidBuffer(buffer)),
         other => Err(BufferError::Unexpected(other)),
     }
 }
@@ -738,14 +766,13 @@ pub fn buffer_data_checked<Data: BufferData>(
     buffer_data(target, data, access_frequency, access_nature);
     match get_error() {
         Error::NoError => Ok(()),
-        Error::InvalidEnum => {
+        Error::InvalidOperation => {
             if is_buffer_immutable_storage(target)? {
                 Err(BufferError::ImmutableBufferTarget(target))
             } else {
                 Err(BufferError::UnboundTarget(target))
             }
         }
-        Error::InvalidOperation => Err(BufferError::UnboundTarget(target)),
         other => Err(BufferError::Unexpected(other)),
     }
 }
@@ -1368,7 +1395,7 @@ pub fn get_buffer_size(target: BufferBindingTarget) -> u64 {
 pub fn get_buffer_storage_flags(
     target: BufferBindingTarget,
 ) -> Result<BufferStorageFlags, BufferError> {
-    let param = get_buffer_parameter_i32(target, gl::BUFFER_USAGE);
+    let param = get_buffer_parameter_i32(target, gl::BUFFER_STORAGE_FLAGS);
     let storage_flags = BufferStorageFlags::from_bits(param as u32);
     storage_flags.ok_or(BufferError::InvalidParameterValue(param as i64))
 }
@@ -1418,14 +1445,14 @@ pub fn get_buffer_usage(
     let param = get_buffer_parameter_i32(target, gl::BUFFER_USAGE);
     match param as u32 {
         gl::STREAM_DRAW => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Draw)),
-        gl::STREAM_READ => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Draw)),
-        gl::STREAM_COPY => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Draw)),
-        gl::STATIC_DRAW => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Draw)),
-        gl::STATIC_READ => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Draw)),
-        gl::STATIC_COPY => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Draw)),
-        gl::DYNAMIC_DRAW => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Draw)),
-        gl::DYNAMIC_READ => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Draw)),
-        gl::DYNAMIC_COPY => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Draw)),
+        gl::STREAM_READ => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Read)),
+        gl::STREAM_COPY => Ok((BufferUsageFrequency::Stream, BufferUsageNature::Copy)),
+        gl::STATIC_DRAW => Ok((BufferUsageFrequency::Static, BufferUsageNature::Draw)),
+        gl::STATIC_READ => Ok((BufferUsageFrequency::Static, BufferUsageNature::Read)),
+        gl::STATIC_COPY => Ok((BufferUsageFrequency::Static, BufferUsageNature::Copy)),
+        gl::DYNAMIC_DRAW => Ok((BufferUsageFrequency::Dynamic, BufferUsageNature::Draw)),
+        gl::DYNAMIC_READ => Ok((BufferUsageFrequency::Dynamic, BufferUsageNature::Read)),
+        gl::DYNAMIC_COPY => Ok((BufferUsageFrequency::Dynamic, BufferUsageNature::Copy)),
         other => Err(BufferError::InvalidParameterValue(other as i64)),
     }
 }
@@ -0,0 +1,1057 @@
+//! # Debug Output
+//! <https://www.khronos.org/opengl/wiki/Debug_Output>
+//!
+//! # Description
+//! The core OpenGL API reference for [debug output](https://www.khronos.org/opengl/wiki/Debug_Output)
+//! (`KHR_debug`), where the GL reports errors, performance warnings and other messages to the
+//! application, either through a callback installed with [debug_message_callback], or by polling
+//! [get_debug_message_log]. Messages can be filtered with [debug_message_control], annotated with
+//! [push_debug_group] and [pop_debug_group], and objects can be given readable names with
+//! [object_label].
+//!
+//! With the `log` cargo feature, [log_debug_messages] forwards every message to the
+//! [log](https://docs.rs/log) crate, at a level matching its [DebugSeverity].
+
+use crate::prelude::*;
+use gl::types::*;
+
+/// # Source of a debug message
+/// see [debug_message_callback]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DebugSource {
+    /// Calls to the OpenGL API
+    Api,
+
+    /// Calls to a window-system API
+    WindowSystem,
+
+    /// A compiler for a shading language
+    ShaderCompiler,
+
+    /// An application associated with OpenGL
+    ThirdParty,
+
+    /// Generated by the user of this application
+    Application,
+
+    /// Some source that isn't one of these
+    Other,
+}
+
+impl From<DebugSource> for GLenum {
+    fn from(value: DebugSource) -> Self {
+        match value {
+            DebugSource::Api => gl::DEBUG_SOURCE_API,
+            DebugSource::WindowSystem => gl::DEBUG_SOURCE_WINDOW_SYSTEM,
+            DebugSource::ShaderCompiler => gl::DEBUG_SOURCE_SHADER_COMPILER,
+            DebugSource::ThirdParty => gl::DEBUG_SOURCE_THIRD_PARTY,
+            DebugSource::Application => gl::DEBUG_SOURCE_APPLICATION,
+            DebugSource::Other => gl::DEBUG_SOURCE_OTHER,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for DebugSource {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::DEBUG_SOURCE_API => Ok(DebugSource::Api),
+            gl::DEBUG_SOURCE_WINDOW_SYSTEM => Ok(DebugSource::WindowSystem),
+            gl::DEBUG_SOURCE_SHADER_COMPILER => Ok(DebugSource::ShaderCompiler),
+            gl::DEBUG_SOURCE_THIRD_PARTY => Ok(DebugSource::ThirdParty),
+            gl::DEBUG_SOURCE_APPLICATION => Ok(DebugSource::Application),
+            gl::DEBUG_SOURCE_OTHER => Ok(DebugSource::Other),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Type of a debug message
+/// see [debug_message_callback]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DebugType {
+    /// An error, typically from the API
+    Error,
+
+    /// Some behavior marked deprecated has been used
+    DeprecatedBehaviour,
+
+    /// Something has invoked undefined behavior
+    UndefinedBehaviour,
+
+    /// Some functionality the user relies upon is not portable
+    Portability,
+
+    /// Code has triggered possible performance issues
+    Performance,
+
+    /// Command stream annotation
+    Marker,
+
+    /// Group pushing, see [push_debug_group]
+    PushGroup,
+
+    /// Group popping, see [pop_debug_group]
+    PopGroup,
+
+    /// Some type that isn't one of these
+    Other,
+}
+
+impl From<DebugType> for GLenum {
+    fn from(value: DebugType) -> Self {
+        match value {
+            DebugType::Error => gl::DEBUG_TYPE_ERROR,
+            DebugType::DeprecatedBehaviour => gl::DEBUG_TYPE_DEPRECATED_BEHAVIOR,
+            DebugType::UndefinedBehaviour => gl::DEBUG_TYPE_UNDEFINED_BEHAVIOR,
+            DebugType::Portability => gl::DEBUG_TYPE_PORTABILITY,
+            DebugType::Performance => gl::DEBUG_TYPE_PERFORMANCE,
+            DebugType::Marker => gl::DEBUG_TYPE_MARKER,
+            DebugType::PushGroup => gl::DEBUG_TYPE_PUSH_GROUP,
+            DebugType::PopGroup => gl::DEBUG_TYPE_POP_GROUP,
+            DebugType::Other => gl::DEBUG_TYPE_OTHER,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for DebugType {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, ()> {
+        match value {
+            gl::DEBUG_TYPE_ERROR => Ok(DebugType::Error),
+            gl::DEBUG_TYPE_DEPRECATED_BEHAVIOR => Ok(DebugType::DeprecatedBehaviour),
+            gl::DEBUG_TYPE_UNDEFINED_BEHAVIOR => Ok(DebugType::UndefinedBehaviour),
+            gl::DEBUG_TYPE_PORTABILITY => Ok(DebugType::Portability),
+            gl::DEBUG_TYPE_PERFORMANCE => Ok(DebugType::Performance),
+            gl::DEBUG_TYPE_MARKER => Ok(DebugType::Marker),
+            gl::DEBUG_TYPE_PUSH_GROUP => Ok(DebugType::PushGroup),
+            gl::DEBUG_TYPE_POP_GROUP => Ok(DebugType::PopGroup),
+            gl::DEBUG_TYPE_OTHER => Ok(DebugType::Other),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Severity of a debug message
+/// see [debug_message_callback]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DebugSeverity {
+    /// All OpenGL Errors, shader compilation/linking errors, or highly-dangerous undefined behavior
+    High,
+
+    /// Major performance warnings, shader compilation/linking warnings, or the use of deprecated
+    /// functionality
+    Medium,
+
+    /// Redundant state change performance warning, or unimportant undefined behavior
+    Low,
+
+    /// Anything that isn't an error or performance issue
+    Notification,
+}
+
+impl From<DebugSeverity> for GLenum {
+    fn from(value: DebugSeverity) -> Self {
+        match value {
+            DebugSeverity::High => gl::DEBUG_SEVERITY_HIGH,
+            DebugSeverity::Medium => gl::DEBUG_SEVERITY_MEDIUM,
+            DebugSeverity::Low => gl::DEBUG_SEVERITY_LOW,
+            DebugSeverity::Notification => gl::DEBUG_SEVERITY_NOTIFICATION,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for DebugSeverity {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::DEBUG_SEVERITY_HIGH => Ok(DebugSeverity::High),
+            gl::DEBUG_SEVERITY_MEDIUM => Ok(DebugSeverity::Medium),
+            gl::DEBUG_SEVERITY_LOW => Ok(DebugSeverity::Low),
+            gl::DEBUG_SEVERITY_NOTIFICATION => Ok(DebugSeverity::Notification),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<DebugSeverity> for log::Level {
+    fn from(value: DebugSeverity) -> Self {
+        match value {
+            DebugSeverity::High => log::Level::Error,
+            DebugSeverity::Medium => log::Level::Warn,
+            DebugSeverity::Low => log::Level::Info,
+            DebugSeverity::Notification => log::Level::Debug,
+        }
+    }
+}
+
+/// # Debug message generated by the GL, or inserted by the application
+/// see [debug_message_callback] and [get_debug_message_log]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugMessage {
+    pub source: DebugSource,
+    pub ty: DebugType,
+    pub id: u32,
+    pub severity: DebugSeverity,
+    pub message: String,
+}
+
+impl DebugMessage {
+    /// Unknown enums, e.g. from a newer extension, decode as [DebugSource::Other],
+    /// [DebugType::Other] and [DebugSeverity::Notification]
+    fn decode(
+        source: GLenum,
+        ty: GLenum,
+        id: GLuint,
+        severity: GLenum,
+        message: &[u8],
+    ) -> DebugMessage {
+        let message = message.strip_suffix(&[0]).unwrap_or(message);
+        DebugMessage {
+            source: DebugSource::try_from(source).unwrap_or(DebugSource::Other),
+            ty: DebugType::try_from(ty).unwrap_or(DebugType::Other),
+            id,
+            severity: DebugSeverity::try_from(severity).unwrap_or(DebugSeverity::Notification),
+            message: String::from_utf8_lossy(message).into_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for DebugMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{:?} {:?} {:?} {}] {}",
+            self.severity, self.source, self.ty, self.id, self.message
+        )
+    }
+}
+
+/// # Objects that can be labelled
+/// see [object_label]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DebugObject {
+    Buffer(Buffer),
+    Framebuffer(Framebuffer),
+    Program(Program),
+    Renderbuffer(Renderbuffer),
+    Sampler(Sampler),
+    Shader(Shader),
+    Texture(Texture),
+    VertexArray(VertexArray),
+}
+
+impl DebugObject {
+    fn identifier(self) -> (GLenum, GLuint) {
+        match self {
+            DebugObject::Buffer(buffer) => (gl::BUFFER, buffer.0),
+            DebugObject::Framebuffer(framebuffer) => (gl::FRAMEBUFFER, framebuffer.0),
+            DebugObject::Program(program) => (gl::PROGRAM, program.0),
+            DebugObject::Renderbuffer(renderbuffer) => (gl::RENDERBUFFER, renderbuffer.0),
+            DebugObject::Sampler(sampler) => (gl::SAMPLER, sampler.0),
+            DebugObject::Shader(shader) => (gl::SHADER, shader.0),
+            DebugObject::Texture(texture) => (gl::TEXTURE, texture.0),
+            DebugObject::VertexArray(vertex_array) => (gl::VERTEX_ARRAY, vertex_array.0),
+        }
+    }
+}
+
+impl From<Buffer> for DebugObject {
+    fn from(value: Buffer) -> Self {
+        DebugObject::Buffer(value)
+    }
+}
+
+impl From<Framebuffer> for DebugObject {
+    fn from(value: Framebuffer) -> Self {
+        DebugObject::Framebuffer(value)
+    }
+}
+
+impl From<Program> for DebugObject {
+    fn from(value: Program) -> Self {
+        DebugObject::Program(value)
+    }
+}
+
+impl From<Renderbuffer> for DebugObject {
+    fn from(value: Renderbuffer) -> Self {
+        DebugObject::Renderbuffer(value)
+    }
+}
+
+impl From<Sampler> for DebugObject {
+    fn from(value: Sampler) -> Self {
+        DebugObject::Sampler(value)
+    }
+}
+
+impl From<Shader> for DebugObject {
+    fn from(value: Shader) -> Self {
+        DebugObject::Shader(value)
+    }
+}
+
+impl From<Texture> for DebugObject {
+    fn from(value: Texture) -> Self {
+        DebugObject::Texture(value)
+    }
+}
+
+impl From<VertexArray> for DebugObject {
+    fn from(value: VertexArray) -> Self {
+        DebugObject::VertexArray(value)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DebugError {
+    Unexpected(Error),
+
+    /// Message ids were given, without both a source and type, or with a severity
+    InvalidFilter,
+
+    /// Only [DebugSource::Application] and [DebugSource::ThirdParty] messages can be inserted
+    InvalidSource(DebugSource),
+
+    /// The message or label of this length is not shorter than `MAX_DEBUG_MESSAGE_LENGTH` or
+    /// `MAX_LABEL_LENGTH`
+    MessageTooLong(usize),
+
+    /// The debug group stack already has `MAX_DEBUG_GROUP_STACK_DEPTH` groups
+    GroupStackOverflow,
+
+    /// Only the default debug group is left on the stack
+    GroupStackUnderflow,
+
+    /// The object is not the name of an existing object of its type
+    InvalidObject(DebugObject),
+}
+
+impl std::fmt::Display for DebugError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DebugError::Unexpected(error) => write!(f, "unexpected debug output error: {error}"),
+            DebugError::InvalidFilter => write!(
+                f,
+                "message ids can only be filtered with a source and type, and no severity"
+            ),
+            DebugError::InvalidSource(source) => {
+                write!(f, "messages can not be inserted from {source:?}")
+            }
+            DebugError::MessageTooLong(length) => {
+                write!(f, "a message of {length} bytes is too long")
+            }
+            DebugError::GroupStackOverflow => write!(f, "the debug group stack is full"),
+            DebugError::GroupStackUnderflow => {
+                write!(f, "the default debug group can not be popped")
+            }
+            DebugError::InvalidObject(object) => write!(f, "{object:?} is not an existing object"),
+        }
+    }
+}
+
+impl std::error::Error for DebugError {}
+
+/// A message or label shorter than `max_length_pname` would not have generated
+/// [Error::InvalidValue]
+fn message_length_error(length: usize, max_length_pname: GLenum) -> Option<DebugError> {
+    let max_length = get_i32(max_length_pname) as usize;
+    (length >= max_length).then_some(DebugError::MessageTooLong(length))
+}
+
+type DebugCallback = Box<dyn Fn(&DebugMessage) + Send + Sync>;
+
+extern "system" fn debug_callback(
+    source: GLenum,
+    ty: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    message: *const GLchar,
+    user_param: *mut std::os::raw::c_void,
+) {
+    // SAFE: the GL passes `length` readable bytes, excluding the nul terminator
+    let message = unsafe { std::slice::from_raw_parts(message as *const u8, length as usize) };
+    let message = DebugMessage::decode(source, ty, id, severity, message);
+
+    // SAFE: `user_param` is the callback installed by `debug_message_callback`, which is kept
+    // alive until it is replaced
+    let callback = unsafe { &*(user_param as *const DebugCallback) };
+
+    // unwinding into the GL is undefined behaviour, and aborts the process
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(&message)));
+}
+
+/// Take back the closure of the current context's callback, if it was installed by rgl
+fn take_debug_callback() -> Option<Box<DebugCallback>> {
+    let mut function: *mut std::os::raw::c_void = std::ptr::null_mut();
+    let mut user_param: *mut std::os::raw::c_void = std::ptr::null_mut();
+
+    // SAFE: synchronous write into `function` and `user_param`, no memory retained
+    unsafe {
+        gl::GetPointerv(
+            gl::DEBUG_CALLBACK_FUNCTION,
+            &mut function as *const *mut std::os::raw::c_void,
+        );
+        gl::GetPointerv(
+            gl::DEBUG_CALLBACK_USER_PARAM,
+            &mut user_param as *const *mut std::os::raw::c_void,
+        );
+    }
+
+    let installed = debug_callback as *const std::os::raw::c_void;
+    if !std::ptr::eq(function, installed) || user_param.is_null() {
+        return None;
+    }
+
+    // SAFE: `user_param` was leaked from a `Box<DebugCallback>` by `debug_message_callback`
+    Some(unsafe { Box::from_raw(user_param as *mut DebugCallback) })
+}
+
+/// Drop a closure taken back by `take_debug_callback`, once the GL can no longer be calling it.
+/// While messages are asynchronous the GL may still be inside it on another thread, so it is
+/// leaked instead.
+fn release_debug_callback(previous: Option<Box<DebugCallback>>) {
+    if is_enabled(Capability::DebugOutputSynchronous) {
+        drop(previous);
+    } else {
+        std::mem::forget(previous);
+    }
+}
+
+/// # Specify a callback to receive debugging messages from the GL
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDebugMessageCallback.xhtml>
+///
+/// # Arguments
+/// * `callback` - Specifies the closure that will be called when a debug message is generated.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// enable(Capability::DebugOutput);
+/// enable(Capability::DebugOutputSynchronous);
+/// debug_message_callback(|message| eprintln!("{message}"));
+/// ```
+///
+/// # Description
+/// [debug_message_callback] sets the current debug output callback of the current context to
+/// `callback`. Each message is decoded into a [DebugMessage], and passed to `callback` while the
+/// message is only borrowed. A panic in `callback` is caught, and does not unwind into the GL.
+///
+/// When [Capability::DebugOutputSynchronous] is disabled, the GL may call `callback`
+/// asynchronously from a thread other than the one which is current on the context, so it must be
+/// [Send] and [Sync].
+///
+/// Any closure previously installed by [debug_message_callback] on the current context is dropped
+/// if [Capability::DebugOutputSynchronous] is enabled. Otherwise the GL may still be running it on
+/// another thread, so it is leaked along with everything it captured. Enable synchronous output
+/// before replacing or clearing a callback to avoid the leak.
+///
+/// `callback` is only called while [Capability::DebugOutput] is enabled. Once a callback is
+/// installed, messages are no longer stored in the message log queried by
+/// [get_debug_message_log].
+///
+/// # Compatability
+/// 4.3 or greater is required
+///
+/// # Associated Gets
+/// * [get_debug_message_log]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [debug_message_callback] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [clear_debug_message_callback]
+/// * [debug_message_control]
+/// * [debug_message_insert]
+/// * [get_debug_message_log]
+pub fn debug_message_callback<F>(callback: F)
+where
+    F: Fn(&DebugMessage) + Send + Sync + 'static,
+{
+    let previous = take_debug_callback();
+    let callback: Box<DebugCallback> = Box::new(Box::new(callback));
+    let user_param = Box::into_raw(callback) as *const std::os::raw::c_void;
+
+    // SAFE: `user_param` is leaked until the callback is replaced or cleared
+    unsafe { gl::DebugMessageCallback(Some(debug_callback), user_param) };
+    release_debug_callback(previous);
+}
+
+/// # Remove the debug output callback
+/// see [debug_message_callback]
+///
+/// Releases the closure installed by [debug_message_callback] on the current context, after which
+/// messages are stored in the message log again. The closure is only dropped while
+/// [Capability::DebugOutputSynchronous] is enabled, and leaked otherwise.
+pub fn clear_debug_message_callback() {
+    let previous = take_debug_callback();
+
+    // SAFE: no callback, so nothing is retained
+    unsafe { gl::DebugMessageCallback(None, std::ptr::null()) };
+    release_debug_callback(previous);
+}
+
+/// # Forward debug messages to the `log` crate
+/// see [debug_message_callback]
+///
+/// Installs a callback that logs each [DebugMessage] with a target of `rgl::debug`, at the
+/// [log::Level] matching its [DebugSeverity]:
+///
+/// | [DebugSeverity] | [log::Level] |
+/// |-----------------|--------------|
+/// | [DebugSeverity::High] | [log::Level::Error] |
+/// | [DebugSeverity::Medium] | [log::Level::Warn] |
+/// | [DebugSeverity::Low] | [log::Level::Info] |
+/// | [DebugSeverity::Notification] | [log::Level::Debug] |
+#[cfg(feature = "log")]
+pub fn log_debug_messages() {
+    debug_message_callback(|message| {
+        log::log!(
+            target: "rgl::debug",
+            log::Level::from(message.severity),
+            "{:?} {:?} {}: {}",
+            message.source,
+            message.ty,
+            message.id,
+            message.message
+        )
+    });
+}
+
+/// # Control the reporting of debug messages in a debug context
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDebugMessageControl.xhtml>
+///
+/// # Arguments
+/// * `source` - The source of debug messages to enable or disable, or `None` for any source.
+/// * `ty` - The type of debug messages to enable or disable, or `None` for any type.
+/// * `severity` - The severity of debug messages to enable or disable, or `None` for any severity.
+/// * `ids` - The message ids to enable or disable, or empty for any id.
+/// * `enabled` - Whether the selected messages should be enabled or disabled.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // only report high severity messages
+/// debug_message_control(None, None, None, &[], false);
+/// debug_message_control(None, None, Some(DebugSeverity::High), &[], true);
+/// ```
+///
+/// # Description
+/// [debug_message_control] controls the reporting of debug messages generated by a debug context.
+/// The parameters `source`, `ty` and `severity` form a filter to select messages from the pool of
+/// potential messages generated by the GL.
+///
+/// `source` may be a [DebugSource] to select messages generated by that source, or `None` to
+/// select messages from all sources. `ty` may be a [DebugType] to select messages of that type, or
+/// `None` to select messages of all types. `severity` may be a [DebugSeverity] to select messages
+/// of that severity, or `None` to select messages of all severities.
+///
+/// When `ids` is not empty, only the messages with those ids, of the given `source` and `ty`, are
+/// selected. Otherwise messages are selected regardless of their id.
+///
+/// If `enabled` is `true`, the referenced subset of messages will be enabled. If `false`, then
+/// those messages will be disabled.
+///
+/// This command can reference different subsets of messages by first considering the set of all
+/// messages, and filtering out messages based on the following ways:
+/// * If `source`, `ty`, or `severity` is `None`, the messages from all sources, of all types, or of
+/// all severities are referenced respectively.
+/// * When values other than `None` are specified, all messages whose source, type, or severity
+/// match the specified `source`, `ty`, or `severity` respectively will be referenced.
+/// * If `ids` is not empty, then all messages with ids in `ids` are referenced.
+///
+/// Although messages are grouped into an implicit hierarchy by their sources and types, there is
+/// no explicit per-source, per-type or per-severity enabled state. Instead, the enabled state is
+/// stored individually for each message. There is no difference between disabling all messages
+/// from one source in a single call, and individually disabling all messages from that source
+/// using their types and ids.
+///
+/// If [Capability::DebugOutput] is disabled, then it is as if messages of every source, type or
+/// severity are disabled.
+///
+/// # Compatability
+/// 4.3 or greater is required
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `ids` is not empty, and `source` or `ty` is `None`, or
+/// `severity` is not `None`.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [debug_message_control] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [debug_message_callback]
+/// * [debug_message_insert]
+/// * [get_debug_message_log]
+pub fn debug_message_control(
+    source: Option<DebugSource>,
+    ty: Option<DebugType>,
+    severity: Option<DebugSeverity>,
+    ids: &[u32],
+    enabled: bool,
+) {
+    let source = source.map_or(gl::DONT_CARE, GLenum::from);
+    let type_ = ty.map_or(gl::DONT_CARE, GLenum::from);
+    let severity = severity.map_or(gl::DONT_CARE, GLenum::from);
+    let count = ids.len() as GLsizei;
+    let enabled = GLboolean::from(enabled);
+
+    // SAFE: synchronous read of `count` ids, no memory retained
+    unsafe { gl::DebugMessageControl(source, type_, severity, count, ids.as_ptr(), enabled) }
+}
+
+/// # Error mapped control the reporting of debug messages
+/// see [debug_message_control]
+pub fn debug_message_control_checked(
+    source: Option<DebugSource>,
+    ty: Option<DebugType>,
+    severity: Option<DebugSeverity>,
+    ids: &[u32],
+    enabled: bool,
+) -> Result<(), DebugError> {
+    debug_message_control(source, ty, severity, ids, enabled);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue | Error::InvalidOperation => Err(DebugError::InvalidFilter),
+        other => Err(DebugError::Unexpected(other)),
+    }
+}
+
+/// # Inject an application-supplied message into the debug message queue
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDebugMessageInsert.xhtml>
+///
+/// # Arguments
+/// * `source` - The source of the debug message to insert.
+/// * `ty` - The type of the debug message insert.
+/// * `id` - The user-supplied identifier of the message to insert.
+/// * `severity` - The severity of the debug messages to insert.
+/// * `message` - The message to insert.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// debug_message_insert(
+///     DebugSource::Application,
+///     DebugType::Marker,
+///     0,
+///     DebugSeverity::Notification,
+///     "begin shadow pass",
+/// );
+/// ```
+///
+/// # Description
+/// [debug_message_insert] inserts a user-supplied message into the debug output queue. `source`
+/// specifies the source that will be used to classify the message, and must be
+/// [DebugSource::Application] or [DebugSource::ThirdParty]. All other sources are reserved for
+/// use by the GL implementation. `ty` indicates the type of the message to be inserted. `id`
+/// specifies the message's identifier, and `severity` its severity.
+///
+/// The message is passed to the callback installed with [debug_message_callback], or stored in
+/// the message log, like any message generated by the GL, if it is enabled by
+/// [debug_message_control].
+///
+/// # Compatability
+/// 4.3 or greater is required
+///
+/// # Errors
+/// * [Error::InvalidEnum] - if `source` is not [DebugSource::Application] or
+/// [DebugSource::ThirdParty].
+/// * [Error::InvalidValue] - if the length of `message` is not less than
+/// `MAX_DEBUG_MESSAGE_LENGTH`.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [debug_message_insert] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [debug_message_callback]
+/// * [debug_message_control]
+/// * [get_debug_message_log]
+pub fn debug_message_insert(
+    source: DebugSource,
+    ty: DebugType,
+    id: u32,
+    severity: DebugSeverity,
+    message: &str,
+) {
+    let source = GLenum::from(source);
+    let type_ = GLenum::from(ty);
+    let severity = GLenum::from(severity);
+    let length = message.len() as GLsizei;
+    let buf = message.as_ptr() as *const GLchar;
+
+    // SAFE: synchronous read of `length` bytes, no memory retained
+    unsafe { gl::DebugMessageInsert(source, type_, id, severity, length, buf) }
+}
+
+/// # Error mapped inject an application-supplied message
+/// see [debug_message_insert]
+pub fn debug_message_insert_checked(
+    source: DebugSource,
+    ty: DebugType,
+    id: u32,
+    severity: DebugSeverity,
+    message: &str,
+) -> Result<(), DebugError> {
+    debug_message_insert(source, ty, id, severity, message);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidEnum => Err(DebugError::InvalidSource(source)),
+        Error::InvalidValue => Err(message_length_error(
+            message.len(),
+            gl::MAX_DEBUG_MESSAGE_LENGTH,
+        )
+        .unwrap_or(DebugError::Unexpected(Error::InvalidValue))),
+        other => Err(DebugError::Unexpected(other)),
+    }
+}
+
+/// # Retrieve messages from the debug message log
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetDebugMessageLog.xhtml>
+///
+/// # Arguments
+/// * `count` - The maximum number of messages to retrieve from the log.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// for message in get_debug_message_log(16) {
+///     eprintln!("{message}");
+/// }
+/// ```
+///
+/// # Description
+/// [get_debug_message_log] retrieves messages from the debug message log. A maximum of `count`
+/// messages are retrieved from the log, oldest first, and removed from it. Fewer messages are
+/// returned if the log holds fewer than `count` messages.
+///
+/// Messages are only stored in the log while no callback is installed with
+/// [debug_message_callback]. Once the log is full, with `MAX_DEBUG_LOGGED_MESSAGES` messages, any
+/// further messages are discarded.
+///
+/// # Compatability
+/// 4.3 or greater is required
+///
+/// # Associated Gets
+/// * [get_debug_logged_messages]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_debug_message_log] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [debug_message_callback]
+/// * [debug_message_control]
+/// * [debug_message_insert]
+pub fn get_debug_message_log(count: u32) -> Vec<DebugMessage> {
+    let max_length = get_i32(gl::MAX_DEBUG_MESSAGE_LENGTH).max(1) as usize;
+    let len = count as usize;
+    let mut sources = vec![GLenum::default(); len];
+    let mut types = vec![GLenum::default(); len];
+    let mut ids = vec![GLuint::default(); len];
+    let mut severities = vec![GLenum::default(); len];
+    let mut lengths = vec![GLsizei::default(); len];
+    let mut log = vec![0u8; len * max_length];
+
+    // SAFE: synchronous write of at most `count` messages, into `count` sized arrays, and at most
+    // `log.len()` bytes, no memory retained
+    let fetched = unsafe {
+        gl::GetDebugMessageLog(
+            count,
+            log.len() as GLsizei,
+            sources.as_mut_ptr(),
+            types.as_mut_ptr(),
+            ids.as_mut_ptr(),
+            severities.as_mut_ptr(),
+            lengths.as_mut_ptr(),
+            log.as_mut_ptr() as *mut GLchar,
+        )
+    };
+
+    let mut offset = 0;
+    (0..(fetched as usize).min(len))
+        .map(|i| {
+            let length = (lengths[i].max(0) as usize).min(log.len() - offset);
+            let message = &log[offset..offset + length];
+            offset += length;
+            DebugMessage::decode(sources[i], types[i], ids[i], severities[i], message)
+        })
+        .collect()
+}
+
+/// # Number of messages in the debug message log
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Description
+/// Returns the number of messages currently in the debug message log, see
+/// [get_debug_message_log].
+pub fn get_debug_logged_messages() -> u32 {
+    get_i32(gl::DEBUG_LOGGED_MESSAGES) as u32
+}
+
+/// # Push a named debug group into the command stream
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glPushDebugGroup.xhtml>
+///
+/// # Arguments
+/// * `source` - The source of the debug message.
+/// * `id` - The identifier of the message.
+/// * `message` - The message to send to the debug output stream.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// push_debug_group(DebugSource::Application, 1, "shadow pass");
+/// // ... draw calls ...
+/// pop_debug_group();
+/// ```
+///
+/// # Description
+/// [push_debug_group] pushes a debug group described by the string `message` into the command
+/// stream. The value of `id` specifies the ID of messages generated. The message has the specified
+/// `source` and `id`, a type of [DebugType::PushGroup], and a severity of
+/// [DebugSeverity::Notification]. The GL will put a new debug group on top of the debug group
+/// stack which inherits the control of the volume of debug output of the debug group previously
+/// residing on the top of the debug group stack. Because debug groups are strictly hierarchical,
+/// any additional control of the debug output volume will only apply within the active debug group
+/// and the debug groups pushed on top of the active debug group.
+///
+/// # Compatability
+/// 4.3 or greater is required
+///
+/// # Errors
+/// * [Error::InvalidEnum] - if `source` is not [DebugSource::Application] or
+/// [DebugSource::ThirdParty].
+/// * [Error::InvalidValue] - if the length of `message` is not less than
+/// `MAX_DEBUG_MESSAGE_LENGTH`.
+/// * [Error::StackOverflow] - if the depth of the debug group stack is already
+/// `MAX_DEBUG_GROUP_STACK_DEPTH`.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [push_debug_group] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [debug_message_control]
+/// * [pop_debug_group]
+pub fn push_debug_group(source: DebugSource, id: u32, message: &str) {
+    let source = GLenum::from(source);
+    let length = message.len() as GLsizei;
+    let message = message.as_ptr() as *const GLchar;
+
+    // SAFE: synchronous read of `length` bytes, no memory retained
+    unsafe { gl::PushDebugGroup(source, id, length, message) }
+}
+
+/// # Error mapped push a named debug group
+/// see [push_debug_group]
+pub fn push_debug_group_checked(
+    source: DebugSource,
+    id: u32,
+    message: &str,
+) -> Result<(), DebugError> {
+    push_debug_group(source, id, message);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidEnum => Err(DebugError::InvalidSource(source)),
+        Error::InvalidValue => Err(message_length_error(
+            message.len(),
+            gl::MAX_DEBUG_MESSAGE_LENGTH,
+        )
+        .unwrap_or(DebugError::Unexpected(Error::InvalidValue))),
+        Error::StackOverflow => Err(DebugError::GroupStackOverflow),
+        other => Err(DebugError::Unexpected(other)),
+    }
+}
+
+/// # Pop the active debug group
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glPopDebugGroup.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// push_debug_group(DebugSource::Application, 1, "shadow pass");
+/// pop_debug_group();
+/// ```
+///
+/// # Description
+/// [pop_debug_group] pops the active debug group. After popping a debug group, the GL will also
+/// generate a debug output message describing its cause based on the message string, the source
+/// `source`, and an ID `id` submitted to the associated [push_debug_group] command.
+/// [DebugType::PopGroup] and [DebugSeverity::Notification] are used as the type and severity of
+/// the message.
+///
+/// # Compatability
+/// 4.3 or greater is required
+///
+/// # Errors
+/// * [Error::StackUnderflow] - if only the default debug group is on the stack.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [pop_debug_group] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [debug_message_control]
+/// * [push_debug_group]
+pub fn pop_debug_group() {
+    // SAFE: no arguments
+    unsafe { gl::PopDebugGroup() }
+}
+
+/// # Error mapped pop the active debug group
+/// see [pop_debug_group]
+pub fn pop_debug_group_checked() -> Result<(), DebugError> {
+    pop_debug_group();
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::StackUnderflow => Err(DebugError::GroupStackUnderflow),
+        other => Err(DebugError::Unexpected(other)),
+    }
+}
+
+/// # Label a named object identified within a namespace
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glObjectLabel.xhtml>
+///
+/// # Arguments
+/// * `object` - The object to label.
+/// * `label` - The label to assign to the object, or an empty string to remove its label.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut texture = Texture::default();
+/// gen_textures(std::slice::from_mut(&mut texture));
+/// bind_texture(TextureBindingTarget::Image2D, texture);
+/// object_label(texture.into(), "albedo");
+/// assert_eq!(get_object_label(texture.into()), "albedo");
+/// ```
+///
+/// # Description
+/// [object_label] labels the object identified by `object`. `label` is used by the GL to name the
+/// object in debug messages, and can be queried with [get_object_label]. An empty `label` removes
+/// the label of the object.
+///
+/// Names returned by the `gen_*` functions only become objects once they are first bound, so they
+/// can only be labelled after being bound.
+///
+/// # Compatability
+/// 4.3 or greater is required
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `object` is not the name of an existing object.
+/// * [Error::InvalidValue] - if the length of `label` is not less than `MAX_LABEL_LENGTH`.
+///
+/// # Associated Gets
+/// * [get_object_label]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [object_label] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [debug_message_insert]
+/// * [push_debug_group]
+pub fn object_label(object: DebugObject, label: &str) {
+    let (identifier, name) = object.identifier();
+    let length = label.len() as GLsizei;
+    let label = label.as_ptr() as *const GLchar;
+
+    // SAFE: synchronous read of `length` bytes, no memory retained
+    unsafe { gl::ObjectLabel(identifier, name, length, label) }
+}
+
+/// # Error mapped label a named object
+/// see [object_label]
+pub fn object_label_checked(object: DebugObject, label: &str) -> Result<(), DebugError> {
+    object_label(object, label);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(message_length_error(label.len(), gl::MAX_LABEL_LENGTH)
+            .unwrap_or(DebugError::InvalidObject(object))),
+        other => Err(DebugError::Unexpected(other)),
+    }
+}
+
+/// # Retrieve the label of a named object identified within a namespace
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetObjectLabel.xhtml>
+///
+/// # Arguments
+/// * `object` - The object whose label to retrieve.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// # let program = Program(1);
+/// object_label(program.into(), "sky");
+/// assert_eq!(get_object_label(program.into()), "sky");
+/// ```
+///
+/// # Description
+/// [get_object_label] retrieves the label of the object identified by `object`, set with
+/// [object_label]. An empty string is returned if the object has no label.
+///
+/// # Compatability
+/// 4.3 or greater is required
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `object` is not the name of an existing object.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_object_label] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [object_label]
+pub fn get_object_label(object: DebugObject) -> String {
+    let (identifier, name) = object.identifier();
+    let mut length = GLsizei::default();
+
+    // SAFE: synchronous write into `length`, no memory retained
+    unsafe { gl::GetObjectLabel(identifier, name, 0, &mut length, std::ptr::null_mut()) };
+
+    let mut label = vec![0u8; length.max(0) as usize + 1];
+    // SAFE: synchronous write of at most `label.len()` bytes, including the nul terminator
+    unsafe {
+        gl::GetObjectLabel(
+            identifier,
+            name,
+            label.len() as GLsizei,
+            &mut length,
+            label.as_mut_ptr() as *mut GLchar,
+        )
+    };
+    label.truncate(length.max(0) as usize);
+    String::from_utf8_lossy(&label).into_owned()
+}
+
+/// # Error mapped retrieve the label of a named object
+/// see [get_object_label]
+pub fn get_object_label_checked(object: DebugObject) -> Result<String, DebugError> {
+    let label = get_object_label(object);
+    match get_error() {
+        Error::NoError => Ok(label),
+        Error::InvalidValue => Err(DebugError::InvalidObject(object)),
+        other => Err(DebugError::Unexpected(other)),
+    }
+}
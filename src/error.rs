@@ -0,0 +1,156 @@
+//! # Error Handling
+//! <https://www.khronos.org/opengl/wiki/OpenGL_Error>
+//!
+//! # Description
+//! Every `_checked` function returns an error specific to its module (e.g. [TextureError] or
+//! [ShaderError]). [RglError] unifies all of them, along with the raw [Error] from [get_error], so
+//! calls from different modules can be chained with `?`:
+//! ```no_run
+//! use rgl::prelude::*;
+//!
+//! fn setup(texture: Texture, vao: VertexArray) -> Result<(), RglError> {
+//!     bind_texture_checked(TextureBindingTarget::Image2D, texture)?;
+//!     bind_vertex_array_checked(vao)?;
+//!     match get_error() {
+//!         Error::NoError => Ok(()),
+//!         error => Err(error.into()),
+//!     }
+//! }
+//! ```
+
+use crate::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum RglError {
+    Gl(Error),
+    Buffer(BufferError),
+    Container(ContainerError),
+    Debug(DebugError),
+    Draw(DrawError),
+    Framebuffer(FramebufferError),
+    Rasterization(RasterizationError),
+    Sampler(SamplerError),
+    Shader(ShaderError),
+    State(StateError),
+    Texture(TextureError),
+    Uniform(UniformError),
+    VertexArray(VertexArrayError),
+}
+
+impl std::fmt::Display for RglError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RglError::Gl(error) => write!(f, "OpenGL error: {error}"),
+            RglError::Buffer(error) => error.fmt(f),
+            RglError::Container(error) => error.fmt(f),
+            RglError::Debug(error) => error.fmt(f),
+            RglError::Draw(error) => error.fmt(f),
+            RglError::Framebuffer(error) => error.fmt(f),
+            RglError::Rasterization(error) => error.fmt(f),
+            RglError::Sampler(error) => error.fmt(f),
+            RglError::Shader(error) => error.fmt(f),
+            RglError::State(error) => error.fmt(f),
+            RglError::Texture(error) => error.fmt(f),
+            RglError::Uniform(error) => error.fmt(f),
+            RglError::VertexArray(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for RglError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RglError::Gl(error) => Some(error),
+            RglError::Buffer(error) => Some(error),
+            RglError::Container(error) => Some(error),
+            RglError::Debug(error) => Some(error),
+            RglError::Draw(error) => Some(error),
+            RglError::Framebuffer(error) => Some(error),
+            RglError::Rasterization(error) => Some(error),
+            RglError::Sampler(error) => Some(error),
+            RglError::Shader(error) => Some(error),
+            RglError::State(error) => Some(error),
+            RglError::Texture(error) => Some(error),
+            RglError::Uniform(error) => Some(error),
+            RglError::VertexArray(error) => Some(error),
+        }
+    }
+}
+
+impl From<Error> for RglError {
+    fn from(value: Error) -> Self {
+        RglError::Gl(value)
+    }
+}
+
+impl From<BufferError> for RglError {
+    fn from(value: BufferError) -> Self {
+        RglError::Buffer(value)
+    }
+}
+
+impl From<ContainerError> for RglError {
+    fn from(value: ContainerError) -> Self {
+        RglError::Container(value)
+    }
+}
+
+impl From<DebugError> for RglError {
+    fn from(value: DebugError) -> Self {
+        RglError::Debug(value)
+    }
+}
+
+impl From<DrawError> for RglError {
+    fn from(value: DrawError) -> Self {
+        RglError::Draw(value)
+    }
+}
+
+impl From<FramebufferError> for RglError {
+    fn from(value: FramebufferError) -> Self {
+        RglError::Framebuffer(value)
+    }
+}
+
+impl From<RasterizationError> for RglError {
+    fn from(value: RasterizationError) -> Self {
+        RglError::Rasterization(value)
+    }
+}
+
+impl From<SamplerError> for RglError {
+    fn from(value: SamplerError) -> Self {
+        RglError::Sampler(value)
+    }
+}
+
+impl From<ShaderError> for RglError {
+    fn from(value: ShaderError) -> Self {
+        RglError::Shader(value)
+    }
+}
+
+impl From<StateError> for RglError {
+    fn from(value: StateError) -> Self {
+        RglError::State(value)
+    }
+}
+
+impl From<TextureError> for RglError {
+    fn from(value: TextureError) -> Self {
+        RglError::Texture(value)
+    }
+}
+
+impl From<UniformError> for RglError {
+    fn from(value: UniformError) -> Self {
+        RglError::Uniform(value)
+    }
+}
+
+impl From<VertexArrayError> for RglError {
+    fn from(value: VertexArrayError) -> Self {
+        RglError::VertexArray(value)
+    }
+}
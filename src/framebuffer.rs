@@ -96,21 +96,529 @@ pub enum FramebufferAttachmentObjectType {
     Renderbuffer,
 }
 
+impl TryFrom<GLenum> for FramebufferAttachmentObjectType {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::NONE => Ok(FramebufferAttachmentObjectType::None),
+            gl::FRAMEBUFFER_DEFAULT => Ok(FramebufferAttachmentObjectType::Framebuffer),
+            gl::TEXTURE => Ok(FramebufferAttachmentObjectType::Texture),
+            gl::RENDERBUFFER => Ok(FramebufferAttachmentObjectType::Renderbuffer),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Data type of the components of an attached image
+/// see [get_framebuffer_attachment_component_type]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FramebufferComponentType {
+    None,
+    Float,
+    Int,
+    UnsignedInt,
+    SignedNormalized,
+    UnsignedNormalized,
+}
+
+impl TryFrom<GLenum> for FramebufferComponentType {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::NONE => Ok(FramebufferComponentType::None),
+            gl::FLOAT => Ok(FramebufferComponentType::Float),
+            gl::INT => Ok(FramebufferComponentType::Int),
+            gl::UNSIGNED_INT => Ok(FramebufferComponentType::UnsignedInt),
+            gl::SIGNED_NORMALIZED => Ok(FramebufferComponentType::SignedNormalized),
+            gl::UNSIGNED_NORMALIZED => Ok(FramebufferComponentType::UnsignedNormalized),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Colour encoding of the components of an attached image
+/// see [get_framebuffer_attachment_colour_encoding]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FramebufferColourEncoding {
+    Linear,
+    SRGB,
+}
+
+impl TryFrom<GLenum> for FramebufferColourEncoding {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::LINEAR => Ok(FramebufferColourEncoding::Linear),
+            gl::SRGB => Ok(FramebufferColourEncoding::SRGB),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Colour, depth and stencil renderable internal formats of a renderbuffer
+/// see [renderbuffer_storage]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum RenderbufferInternalFormat {
+    R8,
+    R16,
+    RG8,
+    RG16,
+    R3G3B2,
+    RGB4,
+    RGB5,
+    RGB565,
+    RGB8,
+    RGB10,
+    RGB12,
+    RGB16,
+    RGBA2,
+    RGBA4,
+    RGB5A1,
+    RGBA8,
+    RGB10A2,
+    RGB10A2UI,
+    RGBA12,
+    RGBA16,
+    SRGB8A8,
+    R16F,
+    RG16F,
+    RGBA16F,
+    R32F,
+    RG32F,
+    RGBA32F,
+    R11fG11fB10f,
+    R8I,
+    R8UI,
+    R16I,
+    R16UI,
+    R32I,
+    R32UI,
+    RG8I,
+    RG8UI,
+    RG16I,
+    RG16UI,
+    RG32I,
+    RG32UI,
+    RGBA8I,
+    RGBA8UI,
+    RGBA16I,
+    RGBA16UI,
+    RGBA32I,
+    RGBA32UI,
+
+    DepthComponent16,
+    DepthComponent24,
+    DepthComponent32,
+    DepthComponent32F,
     Depth24Stencil8,
     Depth32fStencil8,
+
+    StencilIndex1,
+    StencilIndex4,
+    StencilIndex8,
+    StencilIndex16,
 }
 
 impl From<RenderbufferInternalFormat> for GLenum {
     fn from(value: RenderbufferInternalFormat) -> Self {
         match value {
+            RenderbufferInternalFormat::R8 => gl::R8,
+            RenderbufferInternalFormat::R16 => gl::R16,
+            RenderbufferInternalFormat::RG8 => gl::RG8,
+            RenderbufferInternalFormat::RG16 => gl::RG16,
+            RenderbufferInternalFormat::R3G3B2 => gl::R3_G3_B2,
+            RenderbufferInternalFormat::RGB4 => gl::RGB4,
+            RenderbufferInternalFormat::RGB5 => gl::RGB5,
+            RenderbufferInternalFormat::RGB565 => gl::RGB565,
+            RenderbufferInternalFormat::RGB8 => gl::RGB8,
+            RenderbufferInternalFormat::RGB10 => gl::RGB10,
+            RenderbufferInternalFormat::RGB12 => gl::RGB12,
+            RenderbufferInternalFormat::RGB16 => gl::RGB16,
+            RenderbufferInternalFormat::RGBA2 => gl::RGBA2,
+            RenderbufferInternalFormat::RGBA4 => gl::RGBA4,
+            RenderbufferInternalFormat::RGB5A1 => gl::RGB5_A1,
+            RenderbufferInternalFormat::RGBA8 => gl::RGBA8,
+            RenderbufferInternalFormat::RGB10A2 => gl::RGB10_A2,
+            RenderbufferInternalFormat::RGB10A2UI => gl::RGB10_A2UI,
+            RenderbufferInternalFormat::RGBA12 => gl::RGBA12,
+            RenderbufferInternalFormat::RGBA16 => gl::RGBA16,
+            RenderbufferInternalFormat::SRGB8A8 => gl::SRGB8_ALPHA8,
+            RenderbufferInternalFormat::R16F => gl::R16F,
+            RenderbufferInternalFormat::RG16F => gl::RG16F,
+            RenderbufferInternalFormat::RGBA16F => gl::RGBA16F,
+            RenderbufferInternalFormat::R32F => gl::R32F,
+            RenderbufferInternalFormat::RG32F => gl::RG32F,
+            RenderbufferInternalFormat::RGBA32F => gl::RGBA32F,
+            RenderbufferInternalFormat::R11fG11fB10f => gl::R11F_G11F_B10F,
+            RenderbufferInternalFormat::R8I => gl::R8I,
+            RenderbufferInternalFormat::R8UI => gl::R8UI,
+            RenderbufferInternalFormat::R16I => gl::R16I,
+            RenderbufferInternalFormat::R16UI => gl::R16UI,
+            RenderbufferInternalFormat::R32I => gl::R32I,
+            RenderbufferInternalFormat::R32UI => gl::R32UI,
+            RenderbufferInternalFormat::RG8I => gl::RG8I,
+            RenderbufferInternalFormat::RG8UI => gl::RG8UI,
+            RenderbufferInternalFormat::RG16I => gl::RG16I,
+            RenderbufferInternalFormat::RG16UI => gl::RG16UI,
+            RenderbufferInternalFormat::RG32I => gl::RG32I,
+            RenderbufferInternalFormat::RG32UI => gl::RG32UI,
+            RenderbufferInternalFormat::RGBA8I => gl::RGBA8I,
+            RenderbufferInternalFormat::RGBA8UI => gl::RGBA8UI,
+            RenderbufferInternalFormat::RGBA16I => gl::RGBA16I,
+            RenderbufferInternalFormat::RGBA16UI => gl::RGBA16UI,
+            RenderbufferInternalFormat::RGBA32I => gl::RGBA32I,
+            RenderbufferInternalFormat::RGBA32UI => gl::RGBA32UI,
+
+            RenderbufferInternalFormat::DepthComponent16 => gl::DEPTH_COMPONENT16,
+            RenderbufferInternalFormat::DepthComponent24 => gl::DEPTH_COMPONENT24,
+            RenderbufferInternalFormat::DepthComponent32 => gl::DEPTH_COMPONENT32,
+            RenderbufferInternalFormat::DepthComponent32F => gl::DEPTH_COMPONENT32F,
             RenderbufferInternalFormat::Depth24Stencil8 => gl::DEPTH24_STENCIL8,
             RenderbufferInternalFormat::Depth32fStencil8 => gl::DEPTH32F_STENCIL8,
+
+            RenderbufferInternalFormat::StencilIndex1 => gl::STENCIL_INDEX1,
+            RenderbufferInternalFormat::StencilIndex4 => gl::STENCIL_INDEX4,
+            RenderbufferInternalFormat::StencilIndex8 => gl::STENCIL_INDEX8,
+            RenderbufferInternalFormat::StencilIndex16 => gl::STENCIL_INDEX16,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for RenderbufferInternalFormat {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::R8 => Ok(RenderbufferInternalFormat::R8),
+            gl::R16 => Ok(RenderbufferInternalFormat::R16),
+            gl::RG8 => Ok(RenderbufferInternalFormat::RG8),
+            gl::RG16 => Ok(RenderbufferInternalFormat::RG16),
+            gl::R3_G3_B2 => Ok(RenderbufferInternalFormat::R3G3B2),
+            gl::RGB4 => Ok(RenderbufferInternalFormat::RGB4),
+            gl::RGB5 => Ok(RenderbufferInternalFormat::RGB5),
+            gl::RGB565 => Ok(RenderbufferInternalFormat::RGB565),
+            gl::RGB8 => Ok(RenderbufferInternalFormat::RGB8),
+            gl::RGB10 => Ok(RenderbufferInternalFormat::RGB10),
+            gl::RGB12 => Ok(RenderbufferInternalFormat::RGB12),
+            gl::RGB16 => Ok(RenderbufferInternalFormat::RGB16),
+            gl::RGBA2 => Ok(RenderbufferInternalFormat::RGBA2),
+            gl::RGBA4 => Ok(RenderbufferInternalFormat::RGBA4),
+            gl::RGB5_A1 => Ok(RenderbufferInternalFormat::RGB5A1),
+            gl::RGBA8 => Ok(RenderbufferInternalFormat::RGBA8),
+            gl::RGB10_A2 => Ok(RenderbufferInternalFormat::RGB10A2),
+            gl::RGB10_A2UI => Ok(RenderbufferInternalFormat::RGB10A2UI),
+            gl::RGBA12 => Ok(RenderbufferInternalFormat::RGBA12),
+            gl::RGBA16 => Ok(RenderbufferInternalFormat::RGBA16),
+            gl::SRGB8_ALPHA8 => Ok(RenderbufferInternalFormat::SRGB8A8),
+            gl::R16F => Ok(RenderbufferInternalFormat::R16F),
+            gl::RG16F => Ok(RenderbufferInternalFormat::RG16F),
+            gl::RGBA16F => Ok(RenderbufferInternalFormat::RGBA16F),
+            gl::R32F => Ok(RenderbufferInternalFormat::R32F),
+            gl::RG32F => Ok(RenderbufferInternalFormat::RG32F),
+            gl::RGBA32F => Ok(RenderbufferInternalFormat::RGBA32F),
+            gl::R11F_G11F_B10F => Ok(RenderbufferInternalFormat::R11fG11fB10f),
+            gl::R8I => Ok(RenderbufferInternalFormat::R8I),
+            gl::R8UI => Ok(RenderbufferInternalFormat::R8UI),
+            gl::R16I => Ok(RenderbufferInternalFormat::R16I),
+            gl::R16UI => Ok(RenderbufferInternalFormat::R16UI),
+            gl::R32I => Ok(RenderbufferInternalFormat::R32I),
+            gl::R32UI => Ok(RenderbufferInternalFormat::R32UI),
+            gl::RG8I => Ok(RenderbufferInternalFormat::RG8I),
+            gl::RG8UI => Ok(RenderbufferInternalFormat::RG8UI),
+            gl::RG16I => Ok(RenderbufferInternalFormat::RG16I),
+            gl::RG16UI => Ok(RenderbufferInternalFormat::RG16UI),
+            gl::RG32I => Ok(RenderbufferInternalFormat::RG32I),
+            gl::RG32UI => Ok(RenderbufferInternalFormat::RG32UI),
+            gl::RGBA8I => Ok(RenderbufferInternalFormat::RGBA8I),
+            gl::RGBA8UI => Ok(RenderbufferInternalFormat::RGBA8UI),
+            gl::RGBA16I => Ok(RenderbufferInternalFormat::RGBA16I),
+            gl::RGBA16UI => Ok(RenderbufferInternalFormat::RGBA16UI),
+            gl::RGBA32I => Ok(RenderbufferInternalFormat::RGBA32I),
+            gl::RGBA32UI => Ok(RenderbufferInternalFormat::RGBA32UI),
+
+            gl::DEPTH_COMPONENT16 => Ok(RenderbufferInternalFormat::DepthComponent16),
+            gl::DEPTH_COMPONENT24 => Ok(RenderbufferInternalFormat::DepthComponent24),
+            gl::DEPTH_COMPONENT32 => Ok(RenderbufferInternalFormat::DepthComponent32),
+            gl::DEPTH_COMPONENT32F => Ok(RenderbufferInternalFormat::DepthComponent32F),
+            gl::DEPTH24_STENCIL8 => Ok(RenderbufferInternalFormat::Depth24Stencil8),
+            gl::DEPTH32F_STENCIL8 => Ok(RenderbufferInternalFormat::Depth32fStencil8),
+
+            gl::STENCIL_INDEX1 => Ok(RenderbufferInternalFormat::StencilIndex1),
+            gl::STENCIL_INDEX4 => Ok(RenderbufferInternalFormat::StencilIndex4),
+            gl::STENCIL_INDEX8 => Ok(RenderbufferInternalFormat::StencilIndex8),
+            gl::STENCIL_INDEX16 => Ok(RenderbufferInternalFormat::StencilIndex16),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Interpolation applied when a blit stretches the image
+/// see [blit_framebuffer]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BlitFilter {
+    Nearest,
+    Linear,
+}
+
+impl From<BlitFilter> for GLenum {
+    fn from(value: BlitFilter) -> Self {
+        match value {
+            BlitFilter::Nearest => gl::NEAREST,
+            BlitFilter::Linear => gl::LINEAR,
+        }
+    }
+}
+
+/// # Colour buffers of a framebuffer
+/// see [read_buffer]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ColourBuffer {
+    None,
+    FrontLeft,
+    FrontRight,
+    BackLeft,
+    BackRight,
+    Front,
+    Back,
+    Left,
+    Right,
+    Attachment(u32),
+}
+
+impl From<ColourBuffer> for GLenum {
+    fn from(value: ColourBuffer) -> Self {
+        match value {
+            ColourBuffer::None => gl::NONE,
+            ColourBuffer::FrontLeft => gl::FRONT_LEFT,
+            ColourBuffer::FrontRight => gl::FRONT_RIGHT,
+            ColourBuffer::BackLeft => gl::BACK_LEFT,
+            ColourBuffer::BackRight => gl::BACK_RIGHT,
+            ColourBuffer::Front => gl::FRONT,
+            ColourBuffer::Back => gl::BACK,
+            ColourBuffer::Left => gl::LEFT,
+            ColourBuffer::Right => gl::RIGHT,
+            ColourBuffer::Attachment(i) => gl::COLOR_ATTACHMENT0 + i,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for ColourBuffer {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::NONE => Ok(ColourBuffer::None),
+            gl::FRONT_LEFT => Ok(ColourBuffer::FrontLeft),
+            gl::FRONT_RIGHT => Ok(ColourBuffer::FrontRight),
+            gl::BACK_LEFT => Ok(ColourBuffer::BackLeft),
+            gl::BACK_RIGHT => Ok(ColourBuffer::BackRight),
+            gl::FRONT => Ok(ColourBuffer::Front),
+            gl::BACK => Ok(ColourBuffer::Back),
+            gl::LEFT => Ok(ColourBuffer::Left),
+            gl::RIGHT => Ok(ColourBuffer::Right),
+            gl::COLOR_ATTACHMENT0..=gl::COLOR_ATTACHMENT31 => {
+                Ok(ColourBuffer::Attachment(value - gl::COLOR_ATTACHMENT0))
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FramebufferError {
+    Unexpected(Error),
+
+    /// The framebuffer used for the operation is not complete
+    IncompleteFramebuffer,
+
+    /// The colour buffer does not exist for the current framebuffer
+    InvalidColourBuffer(ColourBuffer),
+
+    /// The client slice is smaller than the block of pixels, in bytes
+    InsufficientPixelData {
+        required: usize,
+        provided: usize,
+    },
+
+    /// Not zero or the name of a framebuffer object
+    InvalidFramebuffer(Framebuffer),
+
+    /// Not zero or the name of a renderbuffer object
+    InvalidRenderbuffer(Renderbuffer),
+
+    /// Zero (the default framebuffer) is bound to the target
+    UnboundTarget(FramebufferBindingTarget),
+
+    /// No renderbuffer is bound
+    UnboundRenderbuffer,
+
+    /// Not zero or the name of a texture object compatible with the texture target
+    InvalidTexture(Texture),
+
+    /// The mipmap level is out of range for the texture
+    InvalidLevel(u32),
+
+    /// The mipmap level, or the layer of that level, is out of range for the texture
+    InvalidLayer {
+        level: u32,
+        layer: u32,
+    },
+
+    /// The renderbuffer dimensions are larger than [get_max_renderbuffer_size]
+    InvalidDimensions {
+        width: u32,
+        height: u32,
+    },
+
+    /// The read framebuffer has no depth and/or stencil buffer to read with the format
+    MissingReadAttachment(TextureFormat),
+
+    /// The pixel type does not match the format, or the read framebuffer is multisampled
+    InvalidReadFormat {
+        format: TextureFormat,
+        pixel_data_type: TexturePixelType,
+    },
+
+    /// The bound pixel pack buffer is mapped, too small, or the offset is misaligned
+    InvalidPixelBuffer,
+
+    /// The GL could not allocate the data store
+    OutOfMemory,
+
+    /// The sample count is larger than [get_max_samples], or the maximum for the internal format
+    InvalidSamples(u32),
+
+    /// Depth and stencil buffers can only be blitted with [BlitFilter::Nearest], and integer colour
+    /// buffers cannot be blitted with [BlitFilter::Linear]
+    InvalidBlitFilter(BlitFilter),
+
+    /// A multisample blit into a multisample framebuffer, or between regions of different sizes or
+    /// formats
+    InvalidMultisampleBlit,
+
+    /// The depth or stencil formats of the read and draw framebuffers do not match
+    IncompatibleBlitFormats,
+
+    /// The attachment point does not exist for the framebuffer
+    InvalidAttachment(FramebufferAttachment),
+
+    /// Nothing is attached to the attachment point, or the depth and stencil attachments differ
+    MissingAttachment(FramebufferAttachment),
+
+    /// A draw buffer does not exist for the framebuffer or is repeated, or there are more than
+    /// [get_max_draw_buffers]
+    InvalidDrawBuffers,
+}
+
+impl std::fmt::Display for FramebufferError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FramebufferError::Unexpected(error) => {
+                write!(f, "unexpected framebuffer error: {error}")
+            }
+            FramebufferError::IncompleteFramebuffer => write!(f, "the framebuffer is not complete"),
+            FramebufferError::InvalidColourBuffer(buffer) => {
+                write!(f, "colour buffer {buffer:?} does not exist")
+            }
+            FramebufferError::InsufficientPixelData { required, provided } => write!(
+                f,
+                "pixel data of {provided} bytes is too small for {required} bytes of pixels"
+            ),
+            FramebufferError::InvalidFramebuffer(framebuffer) => {
+                write!(f, "{} is not the name of a framebuffer", framebuffer.0)
+            }
+            FramebufferError::InvalidRenderbuffer(renderbuffer) => {
+                write!(f, "{} is not the name of a renderbuffer", renderbuffer.0)
+            }
+            FramebufferError::UnboundTarget(target) => {
+                write!(f, "no framebuffer object is bound to {target:?}")
+            }
+            FramebufferError::UnboundRenderbuffer => write!(f, "no renderbuffer is bound"),
+            FramebufferError::InvalidTexture(texture) => write!(
+                f,
+                "{} is not the name of a texture compatible with the texture target",
+                texture.0
+            ),
+            FramebufferError::InvalidLevel(level) => {
+                write!(f, "mipmap level {level} is out of range")
+            }
+            FramebufferError::InvalidLayer { level, layer } => {
+                write!(f, "layer {layer} of mipmap level {level} is out of range")
+            }
+            FramebufferError::InvalidDimensions { width, height } => {
+                write!(f, "renderbuffer dimensions {width}x{height} are too large")
+            }
+            FramebufferError::MissingReadAttachment(format) => {
+                write!(
+                    f,
+                    "the read framebuffer has no attachment to read {format:?}"
+                )
+            }
+            FramebufferError::InvalidReadFormat {
+                format,
+                pixel_data_type,
+            } => write!(
+                f,
+                "cannot read pixels of type {pixel_data_type:?} with format {format:?}"
+            ),
+            FramebufferError::InvalidPixelBuffer => {
+                write!(
+                    f,
+                    "the bound pixel pack buffer cannot be used for the transfer"
+                )
+            }
+            FramebufferError::OutOfMemory => write!(f, "out of memory"),
+            FramebufferError::InvalidSamples(samples) => {
+                write!(
+                    f,
+                    "{samples} samples are not supported for the internal format"
+                )
+            }
+            FramebufferError::InvalidBlitFilter(filter) => {
+                write!(
+                    f,
+                    "{filter:?} filtering is not allowed for the blitted buffers"
+                )
+            }
+            FramebufferError::InvalidMultisampleBlit => {
+                write!(
+                    f,
+                    "multisample blits must resolve into a matching single sample region"
+                )
+            }
+            FramebufferError::IncompatibleBlitFormats => {
+                write!(
+                    f,
+                    "the depth or stencil formats of the framebuffers do not match"
+                )
+            }
+            FramebufferError::InvalidAttachment(attachment) => {
+                write!(
+                    f,
+                    "attachment {attachment:?} does not exist for the framebuffer"
+                )
+            }
+            FramebufferError::MissingAttachment(attachment) => {
+                write!(f, "no image is attached to {attachment:?}")
+            }
+            FramebufferError::InvalidDrawBuffers => {
+                write!(f, "the draw buffers cannot be selected for the framebuffer")
+            }
         }
     }
 }
 
+impl std::error::Error for FramebufferError {}
+
+/// Whether a framebuffer object other than zero is bound to `target`
+fn is_framebuffer_target_bound(target: FramebufferBindingTarget) -> bool {
+    let binding = match target {
+        FramebufferBindingTarget::Read => gl::READ_FRAMEBUFFER_BINDING,
+        FramebufferBindingTarget::Draw | FramebufferBindingTarget::ReadDraw => {
+            gl::DRAW_FRAMEBUFFER_BINDING
+        }
+    };
+    get_i32(binding) != 0
+}
+
 /// # Bind a framebuffer to a framebuffer target
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBindFramebuffer.xhtml>
 ///
@@ -160,6 +668,20 @@ pub fn bind_framebuffer(target: FramebufferBindingTarget, framebuffer: Framebuff
     unsafe { gl::BindFramebuffer(target, framebuffer) }
 }
 
+/// # Error mapped bind framebuffer
+/// see [bind_framebuffer]
+pub fn bind_framebuffer_checked(
+    target: FramebufferBindingTarget,
+    framebuffer: Framebuffer,
+) -> Result<(), FramebufferError> {
+    bind_framebuffer(target, framebuffer);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(FramebufferError::InvalidFramebuffer(framebuffer)),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
 /// # Bind a renderbuffer to a renderbuffer target
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBindRenderbuffer.xhtml>
 ///
@@ -198,6 +720,143 @@ pub fn bind_renderbuffer(renderbuffer: Renderbuffer) {
     unsafe { gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer) }
 }
 
+/// # Error mapped bind renderbuffer
+/// see [bind_renderbuffer]
+pub fn bind_renderbuffer_checked(renderbuffer: Renderbuffer) -> Result<(), FramebufferError> {
+    bind_renderbuffer(renderbuffer);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(FramebufferError::InvalidRenderbuffer(renderbuffer)),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Copy a block of pixels from the read framebuffer to the draw framebuffer
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBlitFramebuffer.xhtml>
+///
+/// # Arguments
+/// * `src_x0`, `src_y0`, `src_x1`, `src_y1` - Specify the bounds of the source rectangle within
+/// the read buffer of the read framebuffer.
+/// * `dst_x0`, `dst_y0`, `dst_x1`, `dst_y1` - Specify the bounds of the destination rectangle
+/// within the write buffer of the write framebuffer.
+/// * `mask` - The bitwise OR of the flags indicating which buffers are to be copied.
+/// * `filter` - Specifies the interpolation to be applied if the image is stretched.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // resolve a multisample render target into a single sample framebuffer
+/// bind_framebuffer(FramebufferBindingTarget::Read, Framebuffer(1));
+/// bind_framebuffer(FramebufferBindingTarget::Draw, Framebuffer(2));
+/// blit_framebuffer(0, 0, 640, 480, 0, 0, 640, 480, ClearMask::COLOUR, BlitFilter::Nearest);
+/// ```
+///
+/// # Description
+/// [blit_framebuffer] transfers a rectangle of pixel values from one region of the read
+/// framebuffer to another region of the draw framebuffer. `mask` selects which of the colour, depth
+/// and stencil buffers are copied. Colour is read from the read buffer of the read framebuffer (see
+/// [read_buffer]) and written to each of the draw buffers of the draw framebuffer.
+///
+/// If the source and destination rectangle dimensions do not match, the source image is stretched
+/// to fit the destination rectangle. `filter` must be [BlitFilter::Nearest] when depth or stencil
+/// is copied, and [BlitFilter::Linear] is only allowed for colour buffers with normalised or
+/// floating point formats. Swapping the bounds of a rectangle flips the image on that axis.
+///
+/// If the read framebuffer is multisampled and the draw framebuffer is not, the samples of each
+/// source pixel are resolved to a single value. This is the usual way of displaying, or sampling
+/// from, an image rendered with multisample antialiasing. The rectangles must then be the same
+/// size, and the formats of the colour buffers must match.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `mask` contains [ClearMask::DEPTH] or [ClearMask::STENCIL]
+/// and `filter` is [BlitFilter::Linear].
+/// * [Error::InvalidOperation] - if `mask` contains [ClearMask::DEPTH] or [ClearMask::STENCIL]
+/// and the source and destination depth and stencil formats do not match.
+/// * [Error::InvalidOperation] - if `filter` is [BlitFilter::Linear] and the read buffer contains
+/// integer data.
+/// * [Error::InvalidOperation] - if the draw framebuffer is multisampled.
+/// * [Error::InvalidOperation] - if the read framebuffer is multisampled, and the source and
+/// destination rectangles or colour formats differ.
+/// * [Error::InvalidFramebufferOperation] - if either the read or draw framebuffer is not
+/// complete.
+///
+/// # Associated Gets
+/// * [get_framebuffer_samples]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [blit_framebuffer] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_framebuffer]
+/// * [read_buffer]
+/// * [renderbuffer_storage_multisample]
+/// * [tex_image_2d_multisample]
+#[allow(clippy::too_many_arguments)]
+pub fn blit_framebuffer(
+    src_x0: i32,
+    src_y0: i32,
+    src_x1: i32,
+    src_y1: i32,
+    dst_x0: i32,
+    dst_y0: i32,
+    dst_x1: i32,
+    dst_y1: i32,
+    mask: ClearMask,
+    filter: BlitFilter,
+) {
+    let mask = mask.bits();
+    let filter = GLenum::from(filter);
+    unsafe {
+        gl::BlitFramebuffer(
+            src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter,
+        )
+    }
+}
+
+/// # Error mapped copy a block of pixels
+/// see [blit_framebuffer]
+#[allow(clippy::too_many_arguments)]
+pub fn blit_framebuffer_checked(
+    src_x0: i32,
+    src_y0: i32,
+    src_x1: i32,
+    src_y1: i32,
+    dst_x0: i32,
+    dst_y0: i32,
+    dst_x1: i32,
+    dst_y1: i32,
+    mask: ClearMask,
+    filter: BlitFilter,
+) -> Result<(), FramebufferError> {
+    blit_framebuffer(
+        src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation
+            if filter == BlitFilter::Linear
+                && mask.intersects(ClearMask::DEPTH | ClearMask::STENCIL) =>
+        {
+            Err(FramebufferError::InvalidBlitFilter(filter))
+        }
+        Error::InvalidOperation
+            if get_framebuffer_samples(FramebufferBindingTarget::Read) > 0
+                || get_framebuffer_samples(FramebufferBindingTarget::Draw) > 0 =>
+        {
+            Err(FramebufferError::InvalidMultisampleBlit)
+        }
+        Error::InvalidOperation if filter == BlitFilter::Linear => {
+            Err(FramebufferError::InvalidBlitFilter(filter))
+        }
+        Error::InvalidOperation => Err(FramebufferError::IncompatibleBlitFormats),
+        Error::InvalidFramebufferOperation => Err(FramebufferError::IncompleteFramebuffer),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
 /// # Check the completeness status of a framebuffer
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCheckFramebufferStatus.xhtml>
 ///
@@ -237,6 +896,7 @@ pub fn bind_renderbuffer(renderbuffer: Renderbuffer) {
 /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
 /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
 /// | [check_framebuffer_status] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [check_named_framebuffer_status] | N | N | N | N | N | N | N | N | N | N | N | Y |
 ///
 /// # See Also
 /// * [gen_framebuffers]
@@ -251,6 +911,160 @@ pub fn check_framebuffer_status(target: FramebufferBindingTarget) -> Option<Fram
     }
 }
 
+/// # Error mapped check framebuffer status
+/// see [check_framebuffer_status]
+pub fn check_framebuffer_status_checked(
+    target: FramebufferBindingTarget,
+) -> Result<FramebufferStatus, FramebufferError> {
+    let status = check_framebuffer_status(target);
+    match (get_error(), status) {
+        (Error::NoError, Some(status)) => Ok(status),
+        (other, _) => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Check the completeness status of a framebuffer object
+/// see [check_framebuffer_status]
+///
+/// # Arguments
+/// * `framebuffer` - Specifies the name of the framebuffer object, or zero for the default
+/// framebuffer.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `framebuffer` is not zero or the name of an existing
+/// framebuffer object.
+pub fn check_named_framebuffer_status(
+    framebuffer: Framebuffer,
+    target: FramebufferBindingTarget,
+) -> Option<FramebufferStatus> {
+    let framebuffer = framebuffer.0;
+    let target = GLenum::from(target);
+    let status = unsafe { gl::CheckNamedFramebufferStatus(framebuffer, target) };
+    FramebufferStatus::try_from(status).ok()
+}
+
+/// # Error mapped check named framebuffer status
+/// see [check_named_framebuffer_status]
+pub fn check_named_framebuffer_status_checked(
+    framebuffer: Framebuffer,
+    target: FramebufferBindingTarget,
+) -> Result<FramebufferStatus, FramebufferError> {
+    let status = check_named_framebuffer_status(framebuffer, target);
+    match (get_error(), status) {
+        (Error::NoError, Some(status)) => Ok(status),
+        (Error::InvalidOperation, _) => Err(FramebufferError::InvalidFramebuffer(framebuffer)),
+        (other, _) => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Create framebuffer objects
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCreateFramebuffers.xhtml>
+///
+/// # Arguments
+/// * `framebuffers` - Specifies a slice in which the names of the new framebuffer objects are
+/// stored.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut framebuffer = Framebuffer::default();
+/// create_framebuffers(std::slice::from_mut(&mut framebuffer));
+/// assert!(is_framebuffer(framebuffer));
+/// ```
+///
+/// # Description
+/// [create_framebuffers] returns new framebuffer object names in `framebuffers`, each representing
+/// a new framebuffer object initialized to the default state. Unlike the names returned by
+/// [gen_framebuffers], they are framebuffer objects before they are ever bound, so images can be
+/// attached straight away with [named_framebuffer_texture] and [named_framebuffer_renderbuffer].
+///
+/// # Compatability
+/// * 4.5 - [create_framebuffers]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [create_framebuffers] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [delete_framebuffers]
+/// * [gen_framebuffers]
+/// * [check_named_framebuffer_status]
+pub fn create_framebuffers(framebuffers: &mut [Framebuffer]) {
+    let n = framebuffers.len() as GLsizei;
+    let framebuffers = framebuffers.as_mut_ptr() as *mut GLuint;
+
+    // SAFE: `framebuffers` is an out param, and `n` dictates the pointer length given by std::slice
+    unsafe { gl::CreateFramebuffers(n, framebuffers) }
+}
+
+/// # Error mapped create framebuffers
+/// see [create_framebuffers]
+pub fn create_framebuffers_checked(
+    framebuffers: &mut [Framebuffer],
+) -> Result<(), FramebufferError> {
+    create_framebuffers(framebuffers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Create renderbuffer objects
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCreateRenderbuffers.xhtml>
+///
+/// # Arguments
+/// * `renderbuffers` - Specifies a slice in which the names of the new renderbuffer objects are
+/// stored.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut renderbuffer = Renderbuffer::default();
+/// create_renderbuffers(std::slice::from_mut(&mut renderbuffer));
+/// assert!(is_renderbuffer(renderbuffer));
+/// ```
+///
+/// # Description
+/// [create_renderbuffers] returns new renderbuffer object names in `renderbuffers`, each
+/// representing a new renderbuffer object initialized to the default state. Unlike the names
+/// returned by [gen_renderbuffers], they are renderbuffer objects before they are ever bound, so
+/// can be given storage straight away with [named_renderbuffer_storage].
+///
+/// # Compatability
+/// * 4.5 - [create_renderbuffers]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [create_renderbuffers] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [delete_renderbuffers]
+/// * [gen_renderbuffers]
+/// * [named_renderbuffer_storage]
+pub fn create_renderbuffers(renderbuffers: &mut [Renderbuffer]) {
+    let n = renderbuffers.len() as GLsizei;
+    let renderbuffers = renderbuffers.as_mut_ptr() as *mut GLuint;
+
+    // SAFE: `renderbuffers` is an out param, and `n` dictates the pointer length given by std::slice
+    unsafe { gl::CreateRenderbuffers(n, renderbuffers) }
+}
+
+/// # Error mapped create renderbuffers
+/// see [create_renderbuffers]
+pub fn create_renderbuffers_checked(
+    renderbuffers: &mut [Renderbuffer],
+) -> Result<(), FramebufferError> {
+    create_renderbuffers(renderbuffers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
 /// # Delete framebuffer objects
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDeleteFramebuffers.xhtml>
 ///
@@ -288,6 +1102,16 @@ pub fn delete_framebuffers(framebuffers: &[Framebuffer]) {
     unsafe { gl::DeleteFramebuffers(n, framebuffers) }
 }
 
+/// # Error mapped delete framebuffers
+/// see [delete_framebuffers]
+pub fn delete_framebuffers_checked(framebuffers: &[Framebuffer]) -> Result<(), FramebufferError> {
+    delete_framebuffers(framebuffers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
 /// # Delete renderbuffer objects
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDeleteRenderbuffers.xhtml>
 ///
@@ -331,30 +1155,303 @@ pub fn delete_renderbuffers(renderbuffers: &[Renderbuffer]) {
     unsafe { gl::DeleteRenderbuffers(n, renderbuffers) }
 }
 
-/// # Attach a renderbuffer as a logical buffer of a framebuffer object
-/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glFramebufferRenderbuffer.xhtml>
+/// # Error mapped delete renderbuffers
+/// see [delete_renderbuffers]
+pub fn delete_renderbuffers_checked(
+    renderbuffers: &[Renderbuffer],
+) -> Result<(), FramebufferError> {
+    delete_renderbuffers(renderbuffers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Specify which colour buffer is to be drawn into
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDrawBuffer.xhtml>
 ///
 /// # Arguments
-/// * `target` - Specifies the target to which the framebuffer is bound.
-/// * `attachment` - Specifies the attachment point of the framebuffer.
-/// * `renderbuffer` - Specifies the name of an existing renderbuffer object to attach.
+/// * `buffer` - Specifies the colour buffer that fragment colour zero is written into.
 ///
 /// # Example
 /// ```no_run
 /// # use rgl::prelude::*;
-/// framebuffer_renderbuffer(
-///     FramebufferBindingTarget::Draw,
-///     FramebufferAttachment::Colour(0),
-///     Renderbuffer(42));
+/// draw_buffer(ColourBuffer::Attachment(0));
 /// ```
 ///
 /// # Description
-/// [framebuffer_renderbuffer] and [named_framebuffer_renderbuffer] attaches a renderbuffer as one
-/// of the logical buffers of the specified framebuffer object. Renderbuffers cannot be attached to
-/// the default draw and read framebuffer, so they are not valid targets of these commands.
+/// When colours are written to the framebuffer, they are written into the colour buffer specified
+/// by [draw_buffer]. The target framebuffer object is that bound to
+/// [FramebufferBindingTarget::Draw]. For [named_framebuffer_draw_buffer], `framebuffer` is the
+/// name of the framebuffer object, or zero for the default framebuffer.
 ///
-/// For [framebuffer_renderbuffer], the framebuffer object is that bound to `target`.
-/// [FramebufferBindingTarget::ReadDraw] is equivalent to [FramebufferBindingTarget::Draw].
+/// For the default framebuffer, [ColourBuffer::Front], [ColourBuffer::Back], [ColourBuffer::Left]
+/// and [ColourBuffer::Right] write into every buffer they name, so [ColourBuffer::Front] writes
+/// into both the front left and front right buffers of a stereo configuration. For a framebuffer
+/// object, only [ColourBuffer::None] and [ColourBuffer::Attachment] are accepted, where the index
+/// ranges from zero to the value of [get_max_colour_attachments] minus one.
+///
+/// [ColourBuffer::None] disables writing colours to any buffer. Every fragment colour other than
+/// zero is discarded, see [draw_buffers] to write several fragment colours at once.
+///
+/// The initial value is [ColourBuffer::Back] for a double-buffered default framebuffer,
+/// [ColourBuffer::Front] for a single-buffered one, and [ColourBuffer::Attachment] zero for a
+/// framebuffer object.
+///
+/// # Errors
+/// * [Error::InvalidEnum] - if `buffer` is [ColourBuffer::Attachment] for the default framebuffer,
+/// or any other buffer except [ColourBuffer::None] for a framebuffer object.
+/// * [Error::InvalidOperation] - if `buffer` does not exist for the default framebuffer, or is
+/// [ColourBuffer::Attachment] with an index greater than or equal to [get_max_colour_attachments].
+/// * [Error::InvalidOperation] - by [named_framebuffer_draw_buffer] if `framebuffer` is not zero or
+/// the name of an existing framebuffer object.
+///
+/// # Associated Gets
+/// * [get_draw_buffer]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [draw_buffer] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [named_framebuffer_draw_buffer] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [blend_func]
+/// * [draw_buffers]
+/// * [read_buffer]
+pub fn draw_buffer(buffer: ColourBuffer) {
+    let buf = GLenum::from(buffer);
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::DrawBuffer(buf) }
+}
+
+/// # Error mapped draw buffer
+/// see [draw_buffer]
+pub fn draw_buffer_checked(buffer: ColourBuffer) -> Result<(), FramebufferError> {
+    draw_buffer(buffer);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidEnum | Error::InvalidOperation => {
+            Err(FramebufferError::InvalidColourBuffer(buffer))
+        }
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Specify which colour buffer is to be drawn into
+/// see [draw_buffer]
+///
+/// # Arguments
+/// * `framebuffer` - Specifies the name of the framebuffer object, or zero for the default
+/// framebuffer.
+pub fn named_framebuffer_draw_buffer(framebuffer: Framebuffer, buffer: ColourBuffer) {
+    let framebuffer = framebuffer.0;
+    let buf = GLenum::from(buffer);
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::NamedFramebufferDrawBuffer(framebuffer, buf) }
+}
+
+/// # Error mapped named framebuffer draw buffer
+/// see [named_framebuffer_draw_buffer]
+pub fn named_framebuffer_draw_buffer_checked(
+    framebuffer: Framebuffer,
+    buffer: ColourBuffer,
+) -> Result<(), FramebufferError> {
+    named_framebuffer_draw_buffer(framebuffer, buffer);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation if framebuffer.0 != 0 && !is_framebuffer(framebuffer) => {
+            Err(FramebufferError::InvalidFramebuffer(framebuffer))
+        }
+        Error::InvalidEnum | Error::InvalidOperation => {
+            Err(FramebufferError::InvalidColourBuffer(buffer))
+        }
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Specify a list of colour buffers to be drawn into
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDrawBuffers.xhtml>
+///
+/// # Arguments
+/// * `buffers` - Specifies the colour buffers that each fragment colour is written into.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// draw_buffers(&[
+///     ColourBuffer::Attachment(0),
+///     ColourBuffer::None,
+///     ColourBuffer::Attachment(2),
+/// ]);
+/// ```
+///
+/// # Description
+/// [draw_buffers] defines a slice of buffers into which outputs from the fragment shader data will
+/// be written. If a fragment shader writes a value to one or more user defined output variables,
+/// then the value of each variable will be written into the buffer specified at a location within
+/// `buffers` corresponding to the location assigned to that user defined output. The draw buffer
+/// used for user defined outputs assigned to locations greater than or equal to the length of
+/// `buffers` is implicitly set to [ColourBuffer::None] and any data written to such an output is
+/// discarded.
+///
+/// The target framebuffer object is that bound to [FramebufferBindingTarget::Draw]. For
+/// [named_framebuffer_draw_buffers], `framebuffer` is the name of the framebuffer object, or zero
+/// for the default framebuffer.
+///
+/// For a framebuffer object, each buffer is either [ColourBuffer::None] or a
+/// [ColourBuffer::Attachment], and every attachment may appear at most once. For the default
+/// framebuffer, each buffer is one of [ColourBuffer::None], [ColourBuffer::FrontLeft],
+/// [ColourBuffer::FrontRight], [ColourBuffer::BackLeft] or [ColourBuffer::BackRight].
+///
+/// # Errors
+/// * [Error::InvalidEnum] - if one of the buffers is not an accepted value.
+/// * [Error::InvalidValue] - if the length of `buffers` is greater than [get_max_draw_buffers].
+/// * [Error::InvalidOperation] - if a buffer other than [ColourBuffer::None] appears more than
+/// once, or does not exist for the framebuffer.
+/// * [Error::InvalidOperation] - by [named_framebuffer_draw_buffers] if `framebuffer` is not zero
+/// or the name of an existing framebuffer object.
+///
+/// # Associated Gets
+/// * [get_draw_buffer]
+/// * [get_max_draw_buffers]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [draw_buffers] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [named_framebuffer_draw_buffers] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [blend_func]
+/// * [draw_buffer]
+/// * [read_buffer]
+pub fn draw_buffers(buffers: &[ColourBuffer]) {
+    let bufs: Vec<GLenum> = buffers.iter().map(|buffer| GLenum::from(*buffer)).collect();
+    let n = bufs.len() as GLsizei;
+
+    // SAFE: `n` dictates the pointer length given by std::slice, no memory retained
+    unsafe { gl::DrawBuffers(n, bufs.as_ptr()) }
+}
+
+/// # Error mapped draw buffers
+/// see [draw_buffers]
+pub fn draw_buffers_checked(buffers: &[ColourBuffer]) -> Result<(), FramebufferError> {
+    draw_buffers(buffers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidEnum | Error::InvalidValue | Error::InvalidOperation => {
+            Err(FramebufferError::InvalidDrawBuffers)
+        }
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Specify a list of colour buffers to be drawn into
+/// see [draw_buffers]
+///
+/// # Arguments
+/// * `framebuffer` - Specifies the name of the framebuffer object, or zero for the default
+/// framebuffer.
+pub fn named_framebuffer_draw_buffers(framebuffer: Framebuffer, buffers: &[ColourBuffer]) {
+    let framebuffer = framebuffer.0;
+    let bufs: Vec<GLenum> = buffers.iter().map(|buffer| GLenum::from(*buffer)).collect();
+    let n = bufs.len() as GLsizei;
+
+    // SAFE: `n` dictates the pointer length given by std::slice, no memory retained
+    unsafe { gl::NamedFramebufferDrawBuffers(framebuffer, n, bufs.as_ptr()) }
+}
+
+/// # Error mapped named framebuffer draw buffers
+/// see [named_framebuffer_draw_buffers]
+pub fn named_framebuffer_draw_buffers_checked(
+    framebuffer: Framebuffer,
+    buffers: &[ColourBuffer],
+) -> Result<(), FramebufferError> {
+    named_framebuffer_draw_buffers(framebuffer, buffers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation if framebuffer.0 != 0 && !is_framebuffer(framebuffer) => {
+            Err(FramebufferError::InvalidFramebuffer(framebuffer))
+        }
+        Error::InvalidEnum | Error::InvalidValue | Error::InvalidOperation => {
+            Err(FramebufferError::InvalidDrawBuffers)
+        }
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Return the colour buffer selected for drawing a fragment colour
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Arguments
+/// * `index` - Specifies the fragment colour, less than [get_max_draw_buffers].
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_draw_buffer(0), Some(ColourBuffer::Back));
+/// ```
+///
+/// # Description
+/// Returns the colour buffer that fragment colour `index` is written into, see [draw_buffers]. The
+/// initial value of fragment colour zero is [ColourBuffer::Back] if there is a back buffer,
+/// otherwise it is [ColourBuffer::Front], and every other fragment colour is initially
+/// [ColourBuffer::None].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_draw_buffer] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [draw_buffer]
+/// * [draw_buffers]
+pub fn get_draw_buffer(index: u32) -> Option<ColourBuffer> {
+    let buffer = get_i32(gl::DRAW_BUFFER0 + index) as GLenum;
+    ColourBuffer::try_from(buffer).ok()
+}
+
+/// # Error mapped get draw buffer
+/// see [get_draw_buffer]
+pub fn get_draw_buffer_checked(index: u32) -> Result<Option<ColourBuffer>, FramebufferError> {
+    let buffer = get_draw_buffer(index);
+    match get_error() {
+        Error::NoError => Ok(buffer),
+        Error::InvalidEnum => Err(FramebufferError::InvalidDrawBuffers),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Attach a renderbuffer as a logical buffer of a framebuffer object
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glFramebufferRenderbuffer.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the framebuffer is bound.
+/// * `attachment` - Specifies the attachment point of the framebuffer.
+/// * `renderbuffer` - Specifies the name of an existing renderbuffer object to attach.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// framebuffer_renderbuffer(
+///     FramebufferBindingTarget::Draw,
+///     FramebufferAttachment::Colour(0),
+///     Renderbuffer(42));
+/// ```
+///
+/// # Description
+/// [framebuffer_renderbuffer] and [named_framebuffer_renderbuffer] attaches a renderbuffer as one
+/// of the logical buffers of the specified framebuffer object. Renderbuffers cannot be attached to
+/// the default draw and read framebuffer, so they are not valid targets of these commands.
+///
+/// For [framebuffer_renderbuffer], the framebuffer object is that bound to `target`.
+/// [FramebufferBindingTarget::ReadDraw] is equivalent to [FramebufferBindingTarget::Draw].
 ///
 /// For [named_framebuffer_renderbuffer], `framebuffer` is the name of the framebuffer object.
 ///
@@ -370,192 +1467,1797 @@ pub fn delete_renderbuffers(renderbuffers: &[Renderbuffer]) {
 /// attachments of the specified framebuffer object to be set to renderbuffer, which should have the
 /// base internal format [TextureInternalFormat::DepthStencil].
 ///
-/// The value of [get_framebuffer_attachment_type] for the specified attachment point is set to
-/// [FramebufferAttachmentObjectType::Renderbuffer] and the value of
-/// [get_framebuffer_attachment_name] is set to `renderbuffer`. All other state values of specified
-/// attachment point are set to their default values. No change is made to the state of the
-/// renderbuffer object and any previous attachment to the `attachment` logical buffer of the
-/// specified framebuffer object is broken.
+/// The value of [get_framebuffer_attachment_object_type] for the specified attachment point is set to
+/// [FramebufferAttachmentObjectType::Renderbuffer] and the value of
+/// [get_framebuffer_attachment_object_name] is set to `renderbuffer`. All other state values of specified
+/// attachment point are set to their default values. No change is made to the state of the
+/// renderbuffer object and any previous attachment to the `attachment` logical buffer of the
+/// specified framebuffer object is broken.
+///
+/// If `renderbuffer` is zero, these commands will detach the image, if any, identified by the
+/// specified attachment point of the specified framebuffer object. All state values of the
+/// attachment point are set to their default values.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if zero is bound to `target`
+/// * [Error::InvalidOperation] - if `renderbuffer` is neither zero nor the name of an existing
+/// renderbuffer object.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [framebuffer_renderbuffer] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [named_framebuffer_renderbuffer] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [gen_framebuffers]
+/// * [bind_framebuffer]
+/// * [gen_renderbuffers]
+/// * [framebuffer_texture]
+/// * [framebuffer_texture_1d]
+/// * [framebuffer_texture_2d]
+/// * [framebuffer_texture_3d]
+pub fn framebuffer_renderbuffer(
+    target: FramebufferBindingTarget,
+    attachment: FramebufferAttachment,
+    renderbuffer: Renderbuffer,
+) {
+    let target = GLenum::from(target);
+    let attachment = GLenum::from(attachment);
+    let renderbuffer = renderbuffer.0;
+    unsafe { gl::FramebufferRenderbuffer(target, attachment, gl::RENDERBUFFER, renderbuffer) }
+}
+
+/// # Error mapped framebuffer renderbuffer
+/// see [framebuffer_renderbuffer]
+pub fn framebuffer_renderbuffer_checked(
+    target: FramebufferBindingTarget,
+    attachment: FramebufferAttachment,
+    renderbuffer: Renderbuffer,
+) -> Result<(), FramebufferError> {
+    framebuffer_renderbuffer(target, attachment, renderbuffer);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation if !is_framebuffer_target_bound(target) => {
+            Err(FramebufferError::UnboundTarget(target))
+        }
+        Error::InvalidOperation => Err(FramebufferError::InvalidRenderbuffer(renderbuffer)),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Attach a renderbuffer as a logical buffer of a framebuffer object
+/// see [framebuffer_renderbuffer]
+///
+/// # Arguments
+/// * `framebuffer` - Specifies the name of the framebuffer object.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `framebuffer` is not the name of an existing framebuffer
+/// object.
+pub fn named_framebuffer_renderbuffer(
+    framebuffer: Framebuffer,
+    attachment: FramebufferAttachment,
+    renderbuffer: Renderbuffer,
+) {
+    let framebuffer = framebuffer.0;
+    let attachment = GLenum::from(attachment);
+    let renderbuffer = renderbuffer.0;
+    unsafe {
+        gl::NamedFramebufferRenderbuffer(framebuffer, attachment, gl::RENDERBUFFER, renderbuffer)
+    }
+}
+
+/// # Error mapped named framebuffer renderbuffer
+/// see [named_framebuffer_renderbuffer]
+pub fn named_framebuffer_renderbuffer_checked(
+    framebuffer: Framebuffer,
+    attachment: FramebufferAttachment,
+    renderbuffer: Renderbuffer,
+) -> Result<(), FramebufferError> {
+    named_framebuffer_renderbuffer(framebuffer, attachment, renderbuffer);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation if !is_framebuffer(framebuffer) => {
+            Err(FramebufferError::InvalidFramebuffer(framebuffer))
+        }
+        Error::InvalidOperation => Err(FramebufferError::InvalidRenderbuffer(renderbuffer)),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Attach a level of a texture object as a logical buffer of a framebuffer object
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glFramebufferTexture.xhtml>
+///
+/// # Arguments
+/// * `framebuffer_target` -  Specifies the target to which the framebuffer is bound for all
+/// commands except [named_framebuffer_texture] and [named_framebuffer_texture_layer].
+/// * `framebuffer` - Specifies the name of the framebuffer object for [named_framebuffer_texture]
+/// and [named_framebuffer_texture_layer].
+/// * `attachment` - Specifies the attachment point of the framebuffer.
+/// * `texture_target` - Specifies what type of texture is expected in the `texture` parameter, or
+/// for cube map textures, which face is to be attached.
+/// * `texture` - Specifies the name of an existing texture object to attach.
+/// * `level` - Specifies the mipmap level of the texture object to attach.
+/// * `layer` - Specifies the layer of a three-dimensional, array or cube map texture to attach.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// framebuffer_texture_2d(
+///     FramebufferBindingTarget::ReadDraw,
+///     FramebufferAttachment::DepthStencil,
+///     TextureBinding2DTarget::Image2D,
+///     Texture(42),
+///     0
+/// );
+/// framebuffer_texture_layer(
+///     FramebufferBindingTarget::Draw,
+///     FramebufferAttachment::Depth,
+///     Texture(43),
+///     0,
+///     2
+/// );
+/// ```
+///
+/// # Description
+/// These commands attach a selected mipmap level or image of a texture object as one of the logical
+/// buffers of the specified framebuffer object. Textures cannot be attached to the default draw and
+/// read framebuffer, so they are not valid targets of these commands.
+///
+/// For all commands except [named_framebuffer_texture] and [named_framebuffer_texture_layer], the
+/// framebuffer object is that bound to `framebuffer_target`. [FramebufferBindingTarget::ReadDraw]
+/// is equivalent to [FramebufferBindingTarget::Draw].
+///
+/// `attachment` specifies the logical attachment of the framebuffer.
+/// [FramebufferAttachment::Colour]'s argument has to be in range from zero to the value of
+/// [get_max_colour_attachments] minus one. Attaching a level of a texture to
+/// [FramebufferAttachment::DepthStencil] is equivalent to attaching that level to both the depth
+/// and stencil attachments.
+///
+/// If `texture` is zero, any image or array of images attached to the attachment point is
+/// detached, and every other argument is ignored. Otherwise `texture` must be the name of an
+/// existing texture object whose type matches the command:
+/// * [framebuffer_texture] and [named_framebuffer_texture] accept any texture. If the texture is
+/// a three-dimensional, cube map, or array texture, every layer of `level` is attached and the
+/// attachment is layered, so a geometry shader can select the layer rendered to with
+/// `gl_Layer`.
+/// * [framebuffer_texture_1d] attaches a one-dimensional texture.
+/// * [framebuffer_texture_2d] attaches a two-dimensional, rectangle or multisample texture, or a
+/// single face of a cube map texture named by `texture_target`.
+/// * [framebuffer_texture_3d] attaches `layer` of a three-dimensional texture.
+/// * [framebuffer_texture_layer] and [named_framebuffer_texture_layer] attach a single `layer` of a
+/// three-dimensional, array, cube map or cube map array texture. For cube map textures, `layer`
+/// is the face in the order positive x, negative x, positive y, negative y, positive z, negative
+/// z, and for cube map array textures it is `6 * index + face`.
+///
+/// # Compatability
+/// * 3.2 - [framebuffer_texture]
+/// * 4.5 - [named_framebuffer_texture], [named_framebuffer_texture_layer]
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if zero is bound to `framebuffer_target`.
+/// * [Error::InvalidOperation] - by [named_framebuffer_texture] and
+/// [named_framebuffer_texture_layer] if `framebuffer` is not the name of an existing framebuffer
+/// object.
+/// * [Error::InvalidOperation] - if `texture` is neither zero nor the name of an existing texture
+/// object, or the texture type is incompatible with `texture_target` or the command.
+/// * [Error::InvalidValue] - if `level` is not a supported texture level for `texture`.
+/// * [Error::InvalidValue] - if `layer` is larger than the number of layers of `texture`, or than
+/// `GL_MAX_3D_TEXTURE_SIZE` minus one for a three-dimensional texture.
+///
+/// # Associated Gets
+/// * [get_framebuffer_attachment_object_type]
+/// * [get_framebuffer_attachment_texture_level]
+/// * [get_framebuffer_attachment_texture_layer]
+/// * [get_framebuffer_attachment_layered]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [framebuffer_texture] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [framebuffer_texture_1d] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [framebuffer_texture_2d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [framebuffer_texture_3d] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [framebuffer_texture_layer] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [named_framebuffer_texture] | N | N | N | N | N | N | N | N | N | N | N | Y |
+/// | [named_framebuffer_texture_layer] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [bind_framebuffer]
+/// * [check_framebuffer_status]
+/// * [framebuffer_renderbuffer]
+/// * [gen_framebuffers]
+pub mod framebuffer_texture {
+    use crate::prelude::*;
+
+    /// # Attach a level of a texture object as a logical buffer of a framebuffer object
+    /// see [framebuffer_texture@mod]
+    ///
+    /// # Description
+    /// Attaches `level` of `texture` without naming its type, which is the only way to attach
+    /// every layer of an array, cube map or 3D texture for layered rendering. Any texture can be
+    /// attached this way, including multisample textures.
+    pub fn framebuffer_texture(
+        framebuffer_target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        texture: Texture,
+        level: u32,
+    ) {
+        let target = GLenum::from(framebuffer_target);
+        let attachment = GLenum::from(attachment);
+        let texture = texture.0;
+        let level = level as GLint;
+        unsafe { gl::FramebufferTexture(target, attachment, texture, level) }
+    }
+
+    /// # Error mapped attach a level of a texture object
+    /// see [framebuffer_texture@mod]
+    pub fn framebuffer_texture_checked(
+        framebuffer_target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        texture: Texture,
+        level: u32,
+    ) -> Result<(), FramebufferError> {
+        framebuffer_texture(framebuffer_target, attachment, texture, level);
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidOperation if !super::is_framebuffer_target_bound(framebuffer_target) => {
+                Err(FramebufferError::UnboundTarget(framebuffer_target))
+            }
+            Error::InvalidOperation => Err(FramebufferError::InvalidTexture(texture)),
+            Error::InvalidValue => Err(FramebufferError::InvalidLevel(level)),
+            other => Err(FramebufferError::Unexpected(other)),
+        }
+    }
+
+    /// # Attach a level of a one-dimensional texture object as a logical buffer of a framebuffer
+    /// object
+    /// see [framebuffer_texture@mod]
+    pub fn framebuffer_texture_1d(
+        framebuffer_target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        texture_target: TextureBinding1DTarget,
+        texture: Texture,
+        level: u32,
+    ) {
+        let target = GLenum::from(framebuffer_target);
+        let attachment = GLenum::from(attachment);
+        let textarget = GLenum::from(texture_target);
+        let texture = texture.0;
+        let level = level as GLint;
+        unsafe { gl::FramebufferTexture1D(target, attachment, textarget, texture, level) }
+    }
+
+    /// # Error mapped attach a level of a one-dimensional texture object
+    /// see [framebuffer_texture@mod]
+    pub fn framebuffer_texture_1d_checked(
+        framebuffer_target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        texture_target: TextureBinding1DTarget,
+        texture: Texture,
+        level: u32,
+    ) -> Result<(), FramebufferError> {
+        framebuffer_texture_1d(
+            framebuffer_target,
+            attachment,
+            texture_target,
+            texture,
+            level,
+        );
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidOperation if !super::is_framebuffer_target_bound(framebuffer_target) => {
+                Err(FramebufferError::UnboundTarget(framebuffer_target))
+            }
+            Error::InvalidOperation => Err(FramebufferError::InvalidTexture(texture)),
+            Error::InvalidValue => Err(FramebufferError::InvalidLevel(level)),
+            other => Err(FramebufferError::Unexpected(other)),
+        }
+    }
+
+    /// # Attach a level of a texture object as a logical buffer of a framebuffer object
+    /// see [framebuffer_texture@mod]
+    pub fn framebuffer_texture_2d(
+        framebuffer_target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        texture_target: TextureBinding2DTarget,
+        texture: Texture,
+        level: u32,
+    ) {
+        let target = GLenum::from(framebuffer_target);
+        let attachment = GLenum::from(attachment);
+        let textarget = GLenum::from(texture_target);
+        let texture = texture.0;
+        let level = level as GLint;
+        unsafe { gl::FramebufferTexture2D(target, attachment, textarget, texture, level) }
+    }
+
+    /// # Error mapped attach a level of a texture object
+    /// see [framebuffer_texture@mod]
+    pub fn framebuffer_texture_2d_checked(
+        framebuffer_target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        texture_target: TextureBinding2DTarget,
+        texture: Texture,
+        level: u32,
+    ) -> Result<(), FramebufferError> {
+        framebuffer_texture_2d(
+            framebuffer_target,
+            attachment,
+            texture_target,
+            texture,
+            level,
+        );
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidOperation if !super::is_framebuffer_target_bound(framebuffer_target) => {
+                Err(FramebufferError::UnboundTarget(framebuffer_target))
+            }
+            Error::InvalidOperation => Err(FramebufferError::InvalidTexture(texture)),
+            Error::InvalidValue => Err(FramebufferError::InvalidLevel(level)),
+            other => Err(FramebufferError::Unexpected(other)),
+        }
+    }
+
+    /// # Attach a layer of a three-dimensional texture object as a logical buffer of a framebuffer
+    /// object
+    /// see [framebuffer_texture@mod]
+    pub fn framebuffer_texture_3d(
+        framebuffer_target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        texture_target: TextureBinding3DTarget,
+        texture: Texture,
+        level: u32,
+        layer: u32,
+    ) {
+        let target = GLenum::from(framebuffer_target);
+        let attachment = GLenum::from(attachment);
+        let textarget = GLenum::from(texture_target);
+        let texture = texture.0;
+        let level = level as GLint;
+        let layer = layer as GLint;
+        unsafe { gl::FramebufferTexture3D(target, attachment, textarget, texture, level, layer) }
+    }
+
+    /// # Error mapped attach a layer of a three-dimensional texture object
+    /// see [framebuffer_texture@mod]
+    pub fn framebuffer_texture_3d_checked(
+        framebuffer_target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        texture_target: TextureBinding3DTarget,
+        texture: Texture,
+        level: u32,
+        layer: u32,
+    ) -> Result<(), FramebufferError> {
+        framebuffer_texture_3d(
+            framebuffer_target,
+            attachment,
+            texture_target,
+            texture,
+            level,
+            layer,
+        );
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidOperation if !super::is_framebuffer_target_bound(framebuffer_target) => {
+                Err(FramebufferError::UnboundTarget(framebuffer_target))
+            }
+            Error::InvalidOperation => Err(FramebufferError::InvalidTexture(texture)),
+            Error::InvalidValue => Err(FramebufferError::InvalidLayer { level, layer }),
+            other => Err(FramebufferError::Unexpected(other)),
+        }
+    }
+
+    /// # Attach a single layer of a texture object as a logical buffer of a framebuffer object
+    /// see [framebuffer_texture@mod]
+    pub fn framebuffer_texture_layer(
+        framebuffer_target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        texture: Texture,
+        level: u32,
+        layer: u32,
+    ) {
+        let target = GLenum::from(framebuffer_target);
+        let attachment = GLenum::from(attachment);
+        let texture = texture.0;
+        let level = level as GLint;
+        let layer = layer as GLint;
+        unsafe { gl::FramebufferTextureLayer(target, attachment, texture, level, layer) }
+    }
+
+    /// # Error mapped attach a single layer of a texture object
+    /// see [framebuffer_texture@mod]
+    pub fn framebuffer_texture_layer_checked(
+        framebuffer_target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        texture: Texture,
+        level: u32,
+        layer: u32,
+    ) -> Result<(), FramebufferError> {
+        framebuffer_texture_layer(framebuffer_target, attachment, texture, level, layer);
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidOperation if !super::is_framebuffer_target_bound(framebuffer_target) => {
+                Err(FramebufferError::UnboundTarget(framebuffer_target))
+            }
+            Error::InvalidOperation => Err(FramebufferError::InvalidTexture(texture)),
+            Error::InvalidValue => Err(FramebufferError::InvalidLayer { level, layer }),
+            other => Err(FramebufferError::Unexpected(other)),
+        }
+    }
+
+    /// # Attach a level of a texture object as a logical buffer of a framebuffer object
+    /// see [framebuffer_texture@mod]
+    pub fn named_framebuffer_texture(
+        framebuffer: Framebuffer,
+        attachment: FramebufferAttachment,
+        texture: Texture,
+        level: u32,
+    ) {
+        let framebuffer = framebuffer.0;
+        let attachment = GLenum::from(attachment);
+        let texture = texture.0;
+        let level = level as GLint;
+        unsafe { gl::NamedFramebufferTexture(framebuffer, attachment, texture, level) }
+    }
+
+    /// # Error mapped attach a level of a texture object to a named framebuffer
+    /// see [framebuffer_texture@mod]
+    pub fn named_framebuffer_texture_checked(
+        framebuffer: Framebuffer,
+        attachment: FramebufferAttachment,
+        texture: Texture,
+        level: u32,
+    ) -> Result<(), FramebufferError> {
+        named_framebuffer_texture(framebuffer, attachment, texture, level);
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidOperation if !is_framebuffer(framebuffer) => {
+                Err(FramebufferError::InvalidFramebuffer(framebuffer))
+            }
+            Error::InvalidOperation => Err(FramebufferError::InvalidTexture(texture)),
+            Error::InvalidValue => Err(FramebufferError::InvalidLevel(level)),
+            other => Err(FramebufferError::Unexpected(other)),
+        }
+    }
+
+    /// # Attach a single layer of a texture object as a logical buffer of a framebuffer object
+    /// see [framebuffer_texture@mod]
+    pub fn named_framebuffer_texture_layer(
+        framebuffer: Framebuffer,
+        attachment: FramebufferAttachment,
+        texture: Texture,
+        level: u32,
+        layer: u32,
+    ) {
+        let framebuffer = framebuffer.0;
+        let attachment = GLenum::from(attachment);
+        let texture = texture.0;
+        let level = level as GLint;
+        let layer = layer as GLint;
+        unsafe { gl::NamedFramebufferTextureLayer(framebuffer, attachment, texture, level, layer) }
+    }
+
+    /// # Error mapped attach a single layer of a texture object to a named framebuffer
+    /// see [framebuffer_texture@mod]
+    pub fn named_framebuffer_texture_layer_checked(
+        framebuffer: Framebuffer,
+        attachment: FramebufferAttachment,
+        texture: Texture,
+        level: u32,
+        layer: u32,
+    ) -> Result<(), FramebufferError> {
+        named_framebuffer_texture_layer(framebuffer, attachment, texture, level, layer);
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidOperation if !is_framebuffer(framebuffer) => {
+                Err(FramebufferError::InvalidFramebuffer(framebuffer))
+            }
+            Error::InvalidOperation => Err(FramebufferError::InvalidTexture(texture)),
+            Error::InvalidValue => Err(FramebufferError::InvalidLayer { level, layer }),
+            other => Err(FramebufferError::Unexpected(other)),
+        }
+    }
+}
+pub use framebuffer_texture::*;
+
+/// # Generate framebuffer object names
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGenFramebuffers.xhtml>
+///
+/// # Arguments
+/// * `framebuffers` - Specifies a slice in which the generated framebuffer object names are stored.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut framebuffer = Framebuffer::default();
+/// gen_framebuffers(std::slice::from_mut(&mut framebuffer));
+///
+/// let mut framebuffers = [Framebuffer::default(); 1024];
+/// gen_framebuffers(&mut framebuffers);
+/// ```
+///
+/// # Description
+/// [gen_framebuffers] generates framebuffer object names in `framebuffers`. There is no guarantee
+/// that the names form a contiguous set of integers; however, it is guaranteed that none of the
+/// returned names was in use immediately before the call to [gen_framebuffers].
+///
+/// Framebuffer object names returned by a call to [gen_framebuffers] are not returned by subsequent
+/// calls, unless they are first deleted with [delete_framebuffers].
+///
+/// The names returned in ids are marked as used, for the purposes of [gen_framebuffers] only, but
+/// they acquire state and type only when they are first bound.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [gen_framebuffers] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_framebuffer]
+/// * [delete_framebuffer]
+pub fn gen_framebuffers(framebuffers: &mut [Framebuffer]) {
+    let n = framebuffers.len() as GLsizei;
+    let framebuffers = framebuffers.as_mut_ptr() as *mut GLuint;
+    unsafe { gl::GenFramebuffers(n, framebuffers) }
+}
+
+/// # Error mapped generate framebuffers
+/// see [gen_framebuffers]
+pub fn gen_framebuffers_checked(framebuffers: &mut [Framebuffer]) -> Result<(), FramebufferError> {
+    gen_framebuffers(framebuffers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Generate renderbuffer object names
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGenRenderbuffers.xhtml>
+///
+/// # Arguments
+/// * `renderbuffers` - Specifies a slice in which the generated renderbuffer object names are
+/// stored.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut renderbuffer = Renderbuffer::default();
+/// gen_renderbuffers(std::slice::from_mut(&mut renderbuffer));
+/// assert_ne!(renderbuffer, Renderbuffer::default());
+///
+/// let mut renderbuffers = [Renderbuffer::default(); 1024];
+/// gen_renderbuffers(&mut renderbuffers);
+/// assert_ne!(renderbuffers, [Renderbuffer::default(); 1024]);
+/// ```
+///
+/// # Description
+/// [gen_renderbuffers] generates renderbuffer object names in `renderbuffers`. There is no
+/// guarantee that the names form a contiguous set of integers; however, it is guaranteed that none
+/// of the returned names was in use immediately before the call to [gen_renderbuffers].
+///
+/// Renderbuffer object names returned by a call to [gen_renderbuffers] are not returned by
+/// subsequent calls, unless they are first deleted with [delete_renderbuffers].
+///
+/// The names returned in `renderbuffers` are marked as used, for the purposes of
+/// [gen_renderbuffers] only, but they acquire state and type only when they are first bound.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [gen_renderbuffers] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [framebuffer_renderbuffer]
+/// * [delete_renderbuffers]
+pub fn gen_renderbuffers(renderbuffers: &mut [Renderbuffer]) {
+    let n = renderbuffers.len() as GLsizei;
+    let renderbuffers = renderbuffers.as_mut_ptr() as *mut GLuint;
+    unsafe { gl::GenRenderbuffers(n, renderbuffers) }
+}
+
+/// # Error mapped generate renderbuffers
+/// see [gen_renderbuffers]
+pub fn gen_renderbuffers_checked(
+    renderbuffers: &mut [Renderbuffer],
+) -> Result<(), FramebufferError> {
+    gen_renderbuffers(renderbuffers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Return the number of samples of a framebuffer
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetFramebufferParameter.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the framebuffer is bound.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let samples = get_framebuffer_samples(FramebufferBindingTarget::Read);
+/// ```
+///
+/// # Description
+/// Returns the number of samples per pixel of the framebuffer bound to `target`, which is 0 for a
+/// single sample framebuffer. For a framebuffer object, every attachment has the same number of
+/// samples once it is complete, see [FramebufferStatus::IncompleteMultisample].
+///
+/// [FramebufferBindingTarget::ReadDraw] queries the draw framebuffer.
+///
+/// # Compatability
+/// * 4.5 - querying the read framebuffer, before which only the draw framebuffer can be queried
+/// with `glGet(GL_SAMPLES)`
+///
+/// # Errors
+/// * [Error::InvalidFramebufferOperation] - if the framebuffer bound to `target` is not complete.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_framebuffer_samples] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [get_max_samples]
+/// * [renderbuffer_storage_multisample]
+/// * [tex_image_2d_multisample]
+pub fn get_framebuffer_samples(target: FramebufferBindingTarget) -> u32 {
+    let target = GLenum::from(target);
+    let mut samples = 0;
+
+    // SAFE: synchronous write of a single integer, no memory retained
+    unsafe { gl::GetFramebufferParameteriv(target, gl::SAMPLES, &mut samples) };
+    samples as u32
+}
+
+/// # Error mapped return the number of samples of a framebuffer
+/// see [get_framebuffer_samples]
+pub fn get_framebuffer_samples_checked(
+    target: FramebufferBindingTarget,
+) -> Result<u32, FramebufferError> {
+    let samples = get_framebuffer_samples(target);
+    match get_error() {
+        Error::NoError => Ok(samples),
+        Error::InvalidFramebufferOperation => Err(FramebufferError::IncompleteFramebuffer),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Return attachment parameters of a framebuffer object
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetFramebufferAttachmentParameter.xhtml>
+///
+/// # Description
+/// Each getter returns a single parameter of `attachment` of the framebuffer bound to `target`.
+/// [FramebufferBindingTarget::ReadDraw] is equivalent to [FramebufferBindingTarget::Draw].
+///
+/// For the default framebuffer, only [FramebufferAttachment::Depth] and
+/// [FramebufferAttachment::Stencil] can be queried, and their object type is
+/// [FramebufferAttachmentObjectType::Framebuffer] if the default framebuffer has that buffer. For
+/// a framebuffer object, the object type is the type of the image attached with
+/// [framebuffer_texture] or [framebuffer_renderbuffer], or [FramebufferAttachmentObjectType::None]
+/// if nothing is attached, in which case only the object type and name can be queried.
+///
+/// The texture parameters are only meaningful when a texture is attached, and are 0 otherwise.
+/// Querying the component type of [FramebufferAttachment::DepthStencil] is an error, unless the
+/// same image is attached to both the depth and stencil attachment points.
+///
+/// # Errors
+/// * [Error::InvalidEnum] - if `attachment` is not an attachment of the default framebuffer.
+/// * [Error::InvalidOperation] - if `attachment` is [FramebufferAttachment::Colour] with an index
+/// greater than or equal to [get_max_colour_attachments].
+/// * [Error::InvalidOperation] - if the object type is [FramebufferAttachmentObjectType::None] and
+/// any parameter other than the object type and name is queried.
+/// * [Error::InvalidOperation] - if the component type of [FramebufferAttachment::DepthStencil] is
+/// queried and different images are attached to the depth and stencil attachment points.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_framebuffer_attachment_parameter] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_framebuffer]
+/// * [framebuffer_renderbuffer]
+/// * [framebuffer_texture]
+pub mod get_framebuffer_attachment_parameter {
+    use crate::prelude::*;
+    use gl::types::*;
+
+    fn get_attachment_param_i32(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        pname: GLenum,
+    ) -> i32 {
+        let target = GLenum::from(target);
+        let attachment = GLenum::from(attachment);
+        let mut param = 0;
+
+        // SAFE: synchronous write of a single integer, no memory retained
+        unsafe { gl::GetFramebufferAttachmentParameteriv(target, attachment, pname, &mut param) };
+        param
+    }
+
+    fn get_attachment_param_enum<T: TryFrom<GLenum>>(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+        pname: GLenum,
+    ) -> Option<T> {
+        let val = get_attachment_param_i32(target, attachment, pname);
+        T::try_from(val as GLenum).ok()
+    }
+
+    fn attachment_param_error(attachment: FramebufferAttachment) -> Result<(), FramebufferError> {
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidEnum => Err(FramebufferError::InvalidAttachment(attachment)),
+            Error::InvalidOperation => match attachment {
+                FramebufferAttachment::Colour(i) if i >= get_max_colour_attachments() => {
+                    Err(FramebufferError::InvalidAttachment(attachment))
+                }
+                _ => Err(FramebufferError::MissingAttachment(attachment)),
+            },
+            other => Err(FramebufferError::Unexpected(other)),
+        }
+    }
+
+    /// # Return the object type of a framebuffer attachment
+    /// see [get_framebuffer_attachment_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_framebuffer_attachment_object_type(
+    ///         FramebufferBindingTarget::Draw,
+    ///         FramebufferAttachment::Colour(0)
+    ///     ),
+    ///     Some(FramebufferAttachmentObjectType::Texture)
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Returns the type of the object attached to the attachment point, or
+    /// [FramebufferAttachmentObjectType::None] if nothing is attached.
+    pub fn get_framebuffer_attachment_object_type(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Option<FramebufferAttachmentObjectType> {
+        get_attachment_param_enum(target, attachment, gl::FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE)
+    }
+
+    /// # Error mapped get object type
+    /// see [get_framebuffer_attachment_object_type]
+    pub fn get_framebuffer_attachment_object_type_checked(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Result<Option<FramebufferAttachmentObjectType>, FramebufferError> {
+        let val = get_framebuffer_attachment_object_type(target, attachment);
+        attachment_param_error(attachment)?;
+        Ok(val)
+    }
+
+    /// # Return the object name of a framebuffer attachment
+    /// see [get_framebuffer_attachment_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let name = get_framebuffer_attachment_object_name(
+    ///     FramebufferBindingTarget::Draw,
+    ///     FramebufferAttachment::Depth,
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Returns the name of the texture or renderbuffer attached to the attachment point, or 0 if
+    /// nothing is attached or the attachment belongs to the default framebuffer.
+    pub fn get_framebuffer_attachment_object_name(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> u32 {
+        get_attachment_param_i32(target, attachment, gl::FRAMEBUFFER_ATTACHMENT_OBJECT_NAME) as u32
+    }
+
+    /// # Error mapped get object name
+    /// see [get_framebuffer_attachment_object_name]
+    pub fn get_framebuffer_attachment_object_name_checked(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Result<u32, FramebufferError> {
+        let val = get_framebuffer_attachment_object_name(target, attachment);
+        attachment_param_error(attachment)?;
+        Ok(val)
+    }
+
+    /// # Return the texture level of a framebuffer attachment
+    /// see [get_framebuffer_attachment_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let level = get_framebuffer_attachment_texture_level(
+    ///     FramebufferBindingTarget::Draw,
+    ///     FramebufferAttachment::Colour(0),
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Returns the mipmap level of the attached texture image.
+    pub fn get_framebuffer_attachment_texture_level(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> u32 {
+        get_attachment_param_i32(target, attachment, gl::FRAMEBUFFER_ATTACHMENT_TEXTURE_LEVEL)
+            as u32
+    }
+
+    /// # Error mapped get texture level
+    /// see [get_framebuffer_attachment_texture_level]
+    pub fn get_framebuffer_attachment_texture_level_checked(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Result<u32, FramebufferError> {
+        let val = get_framebuffer_attachment_texture_level(target, attachment);
+        attachment_param_error(attachment)?;
+        Ok(val)
+    }
+
+    /// # Return the texture cube map face of a framebuffer attachment
+    /// see [get_framebuffer_attachment_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_framebuffer_attachment_texture_cube_map_face(
+    ///         FramebufferBindingTarget::Draw,
+    ///         FramebufferAttachment::Colour(0)
+    ///     ),
+    ///     Some(TextureBinding2DTarget::CubeMapNegativeZ)
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Returns the face of the attached cube map texture image, as given to
+    /// [framebuffer_texture_2d], or `None` if the attached texture is not a cube map or the whole
+    /// cube map is attached.
+    pub fn get_framebuffer_attachment_texture_cube_map_face(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Option<TextureBinding2DTarget> {
+        let face = get_attachment_param_i32(
+            target,
+            attachment,
+            gl::FRAMEBUFFER_ATTACHMENT_TEXTURE_CUBE_MAP_FACE,
+        );
+        TextureBinding2DTarget::try_from(face as GLenum).ok()
+    }
+
+    /// # Error mapped get texture cube map face
+    /// see [get_framebuffer_attachment_texture_cube_map_face]
+    pub fn get_framebuffer_attachment_texture_cube_map_face_checked(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Result<Option<TextureBinding2DTarget>, FramebufferError> {
+        let val = get_framebuffer_attachment_texture_cube_map_face(target, attachment);
+        attachment_param_error(attachment)?;
+        Ok(val)
+    }
+
+    /// # Return the texture layer of a framebuffer attachment
+    /// see [get_framebuffer_attachment_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let layer = get_framebuffer_attachment_texture_layer(
+    ///     FramebufferBindingTarget::Draw,
+    ///     FramebufferAttachment::Depth,
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Returns the layer of the attached three-dimensional or array texture image, as given to
+    /// [framebuffer_texture_layer], or 0 if the attachment is not a single layer.
+    pub fn get_framebuffer_attachment_texture_layer(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> u32 {
+        get_attachment_param_i32(target, attachment, gl::FRAMEBUFFER_ATTACHMENT_TEXTURE_LAYER)
+            as u32
+    }
+
+    /// # Error mapped get texture layer
+    /// see [get_framebuffer_attachment_texture_layer]
+    pub fn get_framebuffer_attachment_texture_layer_checked(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Result<u32, FramebufferError> {
+        let val = get_framebuffer_attachment_texture_layer(target, attachment);
+        attachment_param_error(attachment)?;
+        Ok(val)
+    }
+
+    /// # Return the layered state of a framebuffer attachment
+    /// see [get_framebuffer_attachment_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert!(get_framebuffer_attachment_layered(
+    ///     FramebufferBindingTarget::Draw,
+    ///     FramebufferAttachment::Depth,
+    /// ));
+    /// ```
+    ///
+    /// # Description
+    /// Returns true if every layer of a texture is attached, which is the case when a three-
+    /// dimensional, cube map or array texture is attached with [framebuffer_texture].
+    pub fn get_framebuffer_attachment_layered(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> bool {
+        get_attachment_param_i32(target, attachment, gl::FRAMEBUFFER_ATTACHMENT_LAYERED) != 0
+    }
+
+    /// # Error mapped get layered state
+    /// see [get_framebuffer_attachment_layered]
+    pub fn get_framebuffer_attachment_layered_checked(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Result<bool, FramebufferError> {
+        let val = get_framebuffer_attachment_layered(target, attachment);
+        attachment_param_error(attachment)?;
+        Ok(val)
+    }
+
+    /// # Return the component type of a framebuffer attachment
+    /// see [get_framebuffer_attachment_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_framebuffer_attachment_component_type(
+    ///         FramebufferBindingTarget::Draw,
+    ///         FramebufferAttachment::Colour(0)
+    ///     ),
+    ///     Some(FramebufferComponentType::UnsignedNormalized)
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Returns the data type of the components of the attached image, or
+    /// [FramebufferComponentType::None] for a stencil only image.
+    pub fn get_framebuffer_attachment_component_type(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Option<FramebufferComponentType> {
+        get_attachment_param_enum(
+            target,
+            attachment,
+            gl::FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE,
+        )
+    }
+
+    /// # Error mapped get component type
+    /// see [get_framebuffer_attachment_component_type]
+    pub fn get_framebuffer_attachment_component_type_checked(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Result<Option<FramebufferComponentType>, FramebufferError> {
+        let val = get_framebuffer_attachment_component_type(target, attachment);
+        attachment_param_error(attachment)?;
+        Ok(val)
+    }
+
+    /// # Return the colour encoding of a framebuffer attachment
+    /// see [get_framebuffer_attachment_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_framebuffer_attachment_colour_encoding(
+    ///         FramebufferBindingTarget::Draw,
+    ///         FramebufferAttachment::Colour(0)
+    ///     ),
+    ///     Some(FramebufferColourEncoding::SRGB)
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Returns the encoding of the components of the attached image, which is
+    /// [FramebufferColourEncoding::SRGB] for sRGB internal formats and
+    /// [FramebufferColourEncoding::Linear] otherwise.
+    pub fn get_framebuffer_attachment_colour_encoding(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Option<FramebufferColourEncoding> {
+        get_attachment_param_enum(
+            target,
+            attachment,
+            gl::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+        )
+    }
+
+    /// # Error mapped get colour encoding
+    /// see [get_framebuffer_attachment_colour_encoding]
+    pub fn get_framebuffer_attachment_colour_encoding_checked(
+        target: FramebufferBindingTarget,
+        attachment: FramebufferAttachment,
+    ) -> Result<Option<FramebufferColourEncoding>, FramebufferError> {
+        let val = get_framebuffer_attachment_colour_encoding(target, attachment);
+        attachment_param_error(attachment)?;
+        Ok(val)
+    }
+}
+pub use get_framebuffer_attachment_parameter::*;
+
+/// # Return parameters of a renderbuffer object
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetRenderbufferParameter.xhtml>
+///
+/// # Description
+/// Each getter returns a single parameter of the renderbuffer object bound to the renderbuffer
+/// target, as established by [renderbuffer_storage] or [renderbuffer_storage_multisample].
+///
+/// Until storage is allocated, every size and dimension is 0 and the internal format is the
+/// unsized `RGBA`, which has no matching [RenderbufferInternalFormat].
+///
+/// The channel sizes are the actual resolution, in bits, of each component of the stored image,
+/// which may differ from the resolution implied by the requested internal format.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if zero is bound to the renderbuffer target.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_renderbuffer_parameter] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_renderbuffer]
+/// * [renderbuffer_storage]
+/// * [renderbuffer_storage_multisample]
+pub mod get_renderbuffer_parameter {
+    use crate::prelude::*;
+    use gl::types::*;
+
+    fn get_renderbuffer_param_i32(pname: GLenum) -> i32 {
+        let mut param = 0;
+
+        // SAFE: synchronous write of a single integer, no memory retained
+        unsafe { gl::GetRenderbufferParameteriv(gl::RENDERBUFFER, pname, &mut param) };
+        param
+    }
+
+    fn renderbuffer_param_error() -> Result<(), FramebufferError> {
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidOperation => Err(FramebufferError::UnboundRenderbuffer),
+            other => Err(FramebufferError::Unexpected(other)),
+        }
+    }
+
+    /// # Return the width of a renderbuffer
+    /// see [get_renderbuffer_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let width = get_renderbuffer_width();
+    /// ```
+    ///
+    /// # Description
+    /// Returns the width, in pixels, of the image of the renderbuffer. The initial value is 0.
+    pub fn get_renderbuffer_width() -> u32 {
+        get_renderbuffer_param_i32(gl::RENDERBUFFER_WIDTH) as u32
+    }
+
+    /// # Error mapped get width
+    /// see [get_renderbuffer_width]
+    pub fn get_renderbuffer_width_checked() -> Result<u32, FramebufferError> {
+        let val = get_renderbuffer_width();
+        renderbuffer_param_error()?;
+        Ok(val)
+    }
+
+    /// # Return the height of a renderbuffer
+    /// see [get_renderbuffer_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let height = get_renderbuffer_height();
+    /// ```
+    ///
+    /// # Description
+    /// Returns the height, in pixels, of the image of the renderbuffer. The initial value is 0.
+    pub fn get_renderbuffer_height() -> u32 {
+        get_renderbuffer_param_i32(gl::RENDERBUFFER_HEIGHT) as u32
+    }
+
+    /// # Error mapped get height
+    /// see [get_renderbuffer_height]
+    pub fn get_renderbuffer_height_checked() -> Result<u32, FramebufferError> {
+        let val = get_renderbuffer_height();
+        renderbuffer_param_error()?;
+        Ok(val)
+    }
+
+    /// # Return the internal format of a renderbuffer
+    /// see [get_renderbuffer_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_renderbuffer_internal_format(),
+    ///     Some(RenderbufferInternalFormat::Depth24Stencil8)
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal format of the image of the renderbuffer, as given to
+    /// [renderbuffer_storage]. A format without a matching [RenderbufferInternalFormat], such as the
+    /// initial `RGBA`, is returned as `None`.
+    pub fn get_renderbuffer_internal_format() -> Option<RenderbufferInternalFormat> {
+        let val = get_renderbuffer_param_i32(gl::RENDERBUFFER_INTERNAL_FORMAT);
+        RenderbufferInternalFormat::try_from(val as GLenum).ok()
+    }
+
+    /// # Error mapped get internal format
+    /// see [get_renderbuffer_internal_format]
+    pub fn get_renderbuffer_internal_format_checked(
+    ) -> Result<Option<RenderbufferInternalFormat>, FramebufferError> {
+        let val = get_renderbuffer_internal_format();
+        renderbuffer_param_error()?;
+        Ok(val)
+    }
+
+    /// # Return the samples of a renderbuffer
+    /// see [get_renderbuffer_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let samples = get_renderbuffer_samples();
+    /// ```
+    ///
+    /// # Description
+    /// Returns the number of samples of the image of the renderbuffer, which may be more than were
+    /// requested from [renderbuffer_storage_multisample], or 0 for a single sample image. The
+    /// initial value is 0.
+    pub fn get_renderbuffer_samples() -> u32 {
+        get_renderbuffer_param_i32(gl::RENDERBUFFER_SAMPLES) as u32
+    }
+
+    /// # Error mapped get samples
+    /// see [get_renderbuffer_samples]
+    pub fn get_renderbuffer_samples_checked() -> Result<u32, FramebufferError> {
+        let val = get_renderbuffer_samples();
+        renderbuffer_param_error()?;
+        Ok(val)
+    }
+
+    /// # Return the red size of a renderbuffer
+    /// see [get_renderbuffer_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_renderbuffer_red_size();
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the red component of the image of the
+    /// renderbuffer, or 0 if the internal format has no red component.
+    pub fn get_renderbuffer_red_size() -> u32 {
+        get_renderbuffer_param_i32(gl::RENDERBUFFER_RED_SIZE) as u32
+    }
+
+    /// # Error mapped get red size
+    /// see [get_renderbuffer_red_size]
+    pub fn get_renderbuffer_red_size_checked() -> Result<u32, FramebufferError> {
+        let val = get_renderbuffer_red_size();
+        renderbuffer_param_error()?;
+        Ok(val)
+    }
+
+    /// # Return the green size of a renderbuffer
+    /// see [get_renderbuffer_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_renderbuffer_green_size();
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the green component of the image of the
+    /// renderbuffer, or 0 if the internal format has no green component.
+    pub fn get_renderbuffer_green_size() -> u32 {
+        get_renderbuffer_param_i32(gl::RENDERBUFFER_GREEN_SIZE) as u32
+    }
+
+    /// # Error mapped get green size
+    /// see [get_renderbuffer_green_size]
+    pub fn get_renderbuffer_green_size_checked() -> Result<u32, FramebufferError> {
+        let val = get_renderbuffer_green_size();
+        renderbuffer_param_error()?;
+        Ok(val)
+    }
+
+    /// # Return the blue size of a renderbuffer
+    /// see [get_renderbuffer_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_renderbuffer_blue_size();
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the blue component of the image of the
+    /// renderbuffer, or 0 if the internal format has no blue component.
+    pub fn get_renderbuffer_blue_size() -> u32 {
+        get_renderbuffer_param_i32(gl::RENDERBUFFER_BLUE_SIZE) as u32
+    }
+
+    /// # Error mapped get blue size
+    /// see [get_renderbuffer_blue_size]
+    pub fn get_renderbuffer_blue_size_checked() -> Result<u32, FramebufferError> {
+        let val = get_renderbuffer_blue_size();
+        renderbuffer_param_error()?;
+        Ok(val)
+    }
+
+    /// # Return the alpha size of a renderbuffer
+    /// see [get_renderbuffer_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_renderbuffer_alpha_size();
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the alpha component of the image of the
+    /// renderbuffer, or 0 if the internal format has no alpha component.
+    pub fn get_renderbuffer_alpha_size() -> u32 {
+        get_renderbuffer_param_i32(gl::RENDERBUFFER_ALPHA_SIZE) as u32
+    }
+
+    /// # Error mapped get alpha size
+    /// see [get_renderbuffer_alpha_size]
+    pub fn get_renderbuffer_alpha_size_checked() -> Result<u32, FramebufferError> {
+        let val = get_renderbuffer_alpha_size();
+        renderbuffer_param_error()?;
+        Ok(val)
+    }
+
+    /// # Return the depth size of a renderbuffer
+    /// see [get_renderbuffer_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_renderbuffer_depth_size();
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the depth component of the image of the
+    /// renderbuffer, or 0 if the internal format has no depth component.
+    pub fn get_renderbuffer_depth_size() -> u32 {
+        get_renderbuffer_param_i32(gl::RENDERBUFFER_DEPTH_SIZE) as u32
+    }
+
+    /// # Error mapped get depth size
+    /// see [get_renderbuffer_depth_size]
+    pub fn get_renderbuffer_depth_size_checked() -> Result<u32, FramebufferError> {
+        let val = get_renderbuffer_depth_size();
+        renderbuffer_param_error()?;
+        Ok(val)
+    }
+
+    /// # Return the stencil size of a renderbuffer
+    /// see [get_renderbuffer_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_renderbuffer_stencil_size();
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the stencil component of the image of
+    /// the renderbuffer, or 0 if the internal format has no stencil component.
+    pub fn get_renderbuffer_stencil_size() -> u32 {
+        get_renderbuffer_param_i32(gl::RENDERBUFFER_STENCIL_SIZE) as u32
+    }
+
+    /// # Error mapped get stencil size
+    /// see [get_renderbuffer_stencil_size]
+    pub fn get_renderbuffer_stencil_size_checked() -> Result<u32, FramebufferError> {
+        let val = get_renderbuffer_stencil_size();
+        renderbuffer_param_error()?;
+        Ok(val)
+    }
+}
+pub use get_renderbuffer_parameter::*;
+
+/// # Determine if a name corresponds to a framebuffer object
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glIsFramebuffer.xhtml>
+///
+/// # Arguments
+/// * `framebuffer` - Specifies a value that may be the name of a framebuffer object.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(is_framebuffer(Framebuffer(42)));
+/// assert!(!is_framebuffer(Framebuffer(0)));
+/// ```
+///
+/// # Description
+/// [is_framebuffer] returns true if `framebuffer` is currently the name of a framebuffer object.
+/// If `framebuffer` is zero, or if `framebuffer` is not the name of a framebuffer object, or if an
+/// error occurs, [is_framebuffer] returns false. If `framebuffer` is a name returned by
+/// [gen_framebuffers], but that has not yet been bound through a call to [bind_framebuffer], then
+/// the name is not a framebuffer object and [is_framebuffer] returns false.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [is_framebuffer] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_framebuffer]
+/// * [create_framebuffers]
+/// * [delete_framebuffers]
+/// * [gen_framebuffers]
+pub fn is_framebuffer(framebuffer: Framebuffer) -> bool {
+    let framebuffer = framebuffer.0;
+
+    // SAFE: synchronous integer copy
+    let val = unsafe { gl::IsFramebuffer(framebuffer) };
+    val == gl::TRUE
+}
+
+/// # Error mapped is framebuffer
+/// see [is_framebuffer]
+pub fn is_framebuffer_checked(framebuffer: Framebuffer) -> Result<bool, FramebufferError> {
+    let val = is_framebuffer(framebuffer);
+    match get_error() {
+        Error::NoError => Ok(val),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Determine if a name corresponds to a renderbuffer object
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glIsRenderbuffer.xhtml>
+///
+/// # Arguments
+/// * `renderbuffer` - Specifies a value that may be the name of a renderbuffer object.
 ///
-/// If `renderbuffer` is zero, these commands will detach the image, if any, identified by the
-/// specified attachment point of the specified framebuffer object. All state values of the
-/// attachment point are set to their default values.
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(is_renderbuffer(Renderbuffer(42)));
+/// assert!(!is_renderbuffer(Renderbuffer(0)));
+/// ```
 ///
-/// # Errors
-/// * [Error::InvalidOperation] - if zero is bound to `target`
+/// # Description
+/// [is_renderbuffer] returns true if `renderbuffer` is currently the name of a renderbuffer object.
+/// If `renderbuffer` is zero, or if `renderbuffer` is not the name of a renderbuffer object, or if
+/// an error occurs, [is_renderbuffer] returns false. If `renderbuffer` is a name returned by
+/// [gen_renderbuffers], but that has not yet been bound through a call to [bind_renderbuffer], then
+/// the name is not a renderbuffer object and [is_renderbuffer] returns false.
 ///
 /// # Version Support
 ///
 /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
 /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-/// | [framebuffer_renderbuffer] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [is_renderbuffer] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
 ///
 /// # See Also
-/// * [gen_framebuffers]
-/// * [bind_framebuffer]
+/// * [bind_renderbuffer]
+/// * [create_renderbuffers]
+/// * [delete_renderbuffers]
 /// * [gen_renderbuffers]
-/// * [framebuffer_texture]
-/// * [framebuffer_texture_1d]
-/// * [framebuffer_texture_2d]
-/// * [framebuffer_texture_3d]
-pub fn framebuffer_renderbuffer(
-    target: FramebufferBindingTarget,
-    attachment: FramebufferAttachment,
-    renderbuffer: Renderbuffer,
-) {
-    let target = GLenum::from(target);
-    let attachment = GLenum::from(attachment);
+pub fn is_renderbuffer(renderbuffer: Renderbuffer) -> bool {
     let renderbuffer = renderbuffer.0;
-    unsafe { gl::FramebufferRenderbuffer(target, attachment, gl::RENDERBUFFER, renderbuffer) }
+
+    // SAFE: synchronous integer copy
+    let val = unsafe { gl::IsRenderbuffer(renderbuffer) };
+    val == gl::TRUE
 }
 
-/// # Attach a level of a texture object as a logical buffer of a framebuffer object
-/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glFramebufferTexture.xhtml>
+/// # Error mapped is renderbuffer
+/// see [is_renderbuffer]
+pub fn is_renderbuffer_checked(renderbuffer: Renderbuffer) -> Result<bool, FramebufferError> {
+    let val = is_renderbuffer(renderbuffer);
+    match get_error() {
+        Error::NoError => Ok(val),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Select a colour buffer source for pixels
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glReadBuffer.xhtml>
 ///
 /// # Arguments
-/// * `framebuffer_target` -  Specifies the target to which the framebuffer is bound for all
-/// commands except [named_framebuffer_texture].
-/// * `attachment` - Specifies the attachment point of the framebuffer.
-/// * `texture_target` - Specifies what type of texture is expected in the `texture` parameter, or
-/// for cube map textures, which face is to be attached.
-/// * `texture` - Specifies the name of an existing texture object to attach.
-/// * `level` - Specifies the mipmap level of the texture object to attach.
+/// * `mode` - Specifies a colour buffer.
 ///
 /// # Example
 /// ```no_run
 /// # use rgl::prelude::*;
-/// framebuffer_texture_2d(
-///     FramebufferBindingTarget::ReadDraw,
-///     FramebufferAttachment::DepthStencil,
-///     TextureBinding2DTarget::Image2D,
-///     Texture(42),
-///     0
-/// );
+/// read_buffer(ColourBuffer::Attachment(1));
 /// ```
 ///
 /// # Description
-/// These commands attach a selected mipmap level or image of a texture object as one of the logical
-/// buffers of the specified framebuffer object. Textures cannot be attached to the default draw and
-/// read framebuffer, so they are not valid targets of these commands.
-///
-/// TODO
+/// [read_buffer] specifies a colour buffer as the source for subsequent [read_pixels],
+/// [copy_tex_image_1d], [copy_tex_image_2d], [copy_tex_sub_image_1d], [copy_tex_sub_image_2d], and
+/// [copy_tex_sub_image_3d] commands. The target framebuffer object is that bound to
+/// [FramebufferBindingTarget::Read].
+///
+/// In a fully configured system, [ColourBuffer::Front], [ColourBuffer::Left], and
+/// [ColourBuffer::FrontLeft] all name the front left buffer, [ColourBuffer::FrontRight] and
+/// [ColourBuffer::Right] name the front right buffer, and [ColourBuffer::BackLeft] and
+/// [ColourBuffer::Back] name the back left buffer. Further more, [ColourBuffer::Attachment] may be
+/// used to indicate the `i`th colour attachment where `i` ranges from zero to the value of
+/// [get_max_colour_attachments] minus one.
+///
+/// Nonstereo double-buffered configurations have only a front left and a back left buffer.
+/// Single-buffered configurations have a front left and a front right buffer if stereo, and only a
+/// front left buffer if nonstereo. It is an error to specify a nonexistent buffer to
+/// [read_buffer].
+///
+/// `mode` is initially [ColourBuffer::Front] in single-buffered configurations and
+/// [ColourBuffer::Back] in double-buffered configurations.
 ///
 /// # Errors
+/// * [Error::InvalidEnum] - if `mode` is [ColourBuffer::Attachment] with an index greater than or
+///   equal to [get_max_colour_attachments].
+/// * [Error::InvalidOperation] - if `mode` specifies a buffer that does not exist for the current
+///   GL context.
+///
+/// # Associated Gets
+/// * [get_read_buffer]
 ///
 /// # Version Support
 ///
 /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
 /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-/// | [framebuffer_texture_2d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [read_buffer] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [named_framebuffer_read_buffer] | N | N | N | N | N | N | N | N | N | N | N | Y |
 ///
 /// # See Also
-pub mod framebuffer_texture {
-    use crate::prelude::*;
+/// * [draw_buffer]
+/// * [read_pixels]
+pub fn read_buffer(mode: ColourBuffer) {
+    let mode = GLenum::from(mode);
 
-    /// # Attach a level of a texture object as a logical buffer of a framebuffer object
-    /// see [framebuffer_texture@mod]
-    pub fn framebuffer_texture_2d(
-        framebuffer_target: FramebufferBindingTarget,
-        attachment: FramebufferAttachment,
-        texture_target: TextureBinding2DTarget,
-        texture: Texture,
-        level: u32,
-    ) {
-        let target = GLenum::from(framebuffer_target);
-        let attachment = GLenum::from(attachment);
-        let textarget = GLenum::from(texture_target);
-        let texture = texture.0;
-        let level = level as GLint;
-        unsafe { gl::FramebufferTexture2D(target, attachment, textarget, texture, level) }
+    // SAFE: synchronous integer copy
+    unsafe { gl::ReadBuffer(mode) }
+}
+
+/// # Error mapped read buffer
+/// see [read_buffer]
+pub fn read_buffer_checked(mode: ColourBuffer) -> Result<(), FramebufferError> {
+    read_buffer(mode);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidEnum | Error::InvalidOperation => {
+            Err(FramebufferError::InvalidColourBuffer(mode))
+        }
+        other => Err(FramebufferError::Unexpected(other)),
     }
 }
-pub use framebuffer_texture::*;
 
-/// # Generate framebuffer object names
-/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGenFramebuffers.xhtml>
+/// # Select a colour buffer source for pixels
+/// see [read_buffer]
 ///
 /// # Arguments
-/// * `framebuffers` - Specifies a slice in which the generated framebuffer object names are stored.
+/// * `framebuffer` - Specifies the name of the framebuffer object, or zero for the default
+/// framebuffer.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `framebuffer` is not zero or the name of an existing
+/// framebuffer object.
+pub fn named_framebuffer_read_buffer(framebuffer: Framebuffer, mode: ColourBuffer) {
+    let framebuffer = framebuffer.0;
+    let mode = GLenum::from(mode);
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::NamedFramebufferReadBuffer(framebuffer, mode) }
+}
+
+/// # Error mapped named framebuffer read buffer
+/// see [named_framebuffer_read_buffer]
+pub fn named_framebuffer_read_buffer_checked(
+    framebuffer: Framebuffer,
+    mode: ColourBuffer,
+) -> Result<(), FramebufferError> {
+    named_framebuffer_read_buffer(framebuffer, mode);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation if framebuffer.0 != 0 && !is_framebuffer(framebuffer) => {
+            Err(FramebufferError::InvalidFramebuffer(framebuffer))
+        }
+        Error::InvalidEnum | Error::InvalidOperation => {
+            Err(FramebufferError::InvalidColourBuffer(mode))
+        }
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Return the colour buffer selected for reading
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
 ///
 /// # Example
 /// ```no_run
 /// # use rgl::prelude::*;
-/// let mut framebuffer = Framebuffer::default();
-/// gen_framebuffers(std::slice::from_mut(&mut framebuffer));
-///
-/// let mut framebuffers = [Framebuffer::default(); 1024];
-/// gen_framebuffers(&mut framebuffers);
+/// assert_eq!(get_read_buffer(), Some(ColourBuffer::Back));
 /// ```
 ///
 /// # Description
-/// [gen_framebuffers] generates framebuffer object names in `framebuffers`. There is no guarantee
-/// that the names form a contiguous set of integers; however, it is guaranteed that none of the
-/// returned names was in use immediately before the call to [gen_framebuffers].
-///
-/// Framebuffer object names returned by a call to [gen_framebuffers] are not returned by subsequent
-/// calls, unless they are first deleted with [delete_framebuffers].
-///
-/// The names returned in ids are marked as used, for the purposes of [gen_framebuffers] only, but
-/// they acquire state and type only when they are first bound.
+/// Returns the colour buffer selected for reading, see [read_buffer]. The initial value is
+/// [ColourBuffer::Back] if there is a back buffer, otherwise it is [ColourBuffer::Front].
 ///
 /// # Version Support
 ///
 /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
 /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-/// | [gen_framebuffers] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_read_buffer] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
 ///
 /// # See Also
-/// * [bind_framebuffer]
-/// * [delete_framebuffer]
-pub fn gen_framebuffers(framebuffers: &mut [Framebuffer]) {
-    let n = framebuffers.len() as GLsizei;
-    let framebuffers = framebuffers.as_mut_ptr() as *mut GLuint;
-    unsafe { gl::GenFramebuffers(n, framebuffers) }
+/// * [read_buffer]
+pub fn get_read_buffer() -> Option<ColourBuffer> {
+    let mode = get_i32(gl::READ_BUFFER) as GLenum;
+    ColourBuffer::try_from(mode).ok()
 }
 
-/// # Generate renderbuffer object names
-/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGenRenderbuffers.xhtml>
+/// # Error mapped get read buffer
+/// see [get_read_buffer]
+pub fn get_read_buffer_checked() -> Result<Option<ColourBuffer>, FramebufferError> {
+    let mode = get_read_buffer();
+    match get_error() {
+        Error::NoError => Ok(mode),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Read a block of pixels from the frame buffer
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glReadPixels.xhtml>
 ///
 /// # Arguments
-/// * `renderbuffers` - Specifies a slice in which the generated renderbuffer object names are
-/// stored.
+/// * `x`, `y` - Specify the window coordinates of the first pixel that is read from the frame
+///   buffer. This location is the lower left corner of a rectangular block of pixels.
+/// * `width`, `height` - Specify the dimensions of the pixel rectangle. `width` and `height` of one
+///   correspond to a single pixel.
+/// * `format` - Specifies the format of the pixel data.
+/// * `pixel_data_type` - Specifies the data type of the pixel data.
+/// * `data` - Returns the pixel data.
 ///
 /// # Example
 /// ```no_run
 /// # use rgl::prelude::*;
-/// let mut renderbuffer = Renderbuffer::default();
-/// gen_renderbuffers(std::slice::from_mut(&mut renderbuffer));
-/// assert_ne!(renderbuffer, Renderbuffer::default());
-///
-/// let mut renderbuffers = [Renderbuffer::default(); 1024];
-/// gen_renderbuffers(&mut renderbuffers);
-/// assert_ne!(renderbuffers, [Renderbuffer::default(); 1024]);
+/// let mut pixels = vec![0u8; 800 * 600 * 4];
+/// read_pixels(0, 0, 800, 600, TextureFormat::RGBA, TexturePixelType::U8, &mut pixels);
 /// ```
 ///
 /// # Description
-/// [gen_renderbuffers] generates renderbuffer object names in `renderbuffers`. There is no
-/// guarantee that the names form a contiguous set of integers; however, it is guaranteed that none
-/// of the returned names was in use immediately before the call to [gen_renderbuffers].
+/// [read_pixels] returns pixel data from the frame buffer, starting with the pixel whose lower left
+/// corner is at location (`x`, `y`), into `data`. Several parameters control the processing of the
+/// pixel data before it is placed into client memory. These parameters are set with [pixel_store].
+///
+/// [read_pixels] returns values from each pixel with lower left corner at `(x + i, y + j)` for
+/// `0 <= i < width` and `0 <= j < height`. This pixel is said to be the `i`th pixel in the `j`th
+/// row. Pixels are returned in row order from the lowest to the highest row, left to right in each
+/// row.
+///
+/// `format` specifies the format for the returned pixel values:
+/// * [TextureFormat::StencilIndex] - Stencil values are read from the stencil buffer.
+/// * [TextureFormat::DepthComponent] - Depth values are read from the depth buffer. Each component
+///   is converted to floating point such that the minimum depth value maps to 0 and the maximum
+///   value maps to 1.
+/// * [TextureFormat::DepthStencil] - Values are taken from both the depth and stencil buffers.
+///   `pixel_data_type` must be [TexturePixelType::D24S8] or [TexturePixelType::D32fS8].
+/// * All other formats - Colour values are taken from the colour buffer selected by
+///   [read_buffer].
+///
+/// Finally, the indices or components are converted to the proper format, as specified by
+/// `pixel_data_type`.
+///
+/// Before reading, the size of `data` is checked against `width` × `height` × bytes-per-pixel of
+/// `format` and `pixel_data_type`, including any row padding from the current pixel pack
+/// alignment. If `data` is too small, [read_pixels] panics rather than let the GL write past the
+/// end of the slice, while [read_pixels_checked] returns [FramebufferError::InsufficientPixelData]
+/// without reading anything.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelPack] target, use
+/// [read_pixels_to_pack_buffer] instead, where the pixels are written into the buffer object's
+/// data store at a byte offset for asynchronous readback.
+///
+/// Values for pixels that lie outside the window connected to the current GL context are
+/// undefined. If an error is generated, no change is made to the contents of `data`.
+///
+/// # Panics
+/// [read_pixels] panics if `data` is too small to hold the pixels.
 ///
-/// Renderbuffer object names returned by a call to [gen_renderbuffers] are not returned by
-/// subsequent calls, unless they are first deleted with [delete_renderbuffers].
-///
-/// The names returned in `renderbuffers` are marked as used, for the purposes of
-/// [gen_renderbuffers] only, but they acquire state and type only when they are first bound.
+/// # Errors
+/// * [FramebufferError::InsufficientPixelData] - if `data` is too small to hold the pixels, from
+///   [read_pixels_checked].
+/// * [Error::InvalidOperation] - if `format` is [TextureFormat::StencilIndex] and there is no
+///   stencil buffer.
+/// * [Error::InvalidOperation] - if `format` is [TextureFormat::DepthComponent] and there is no
+///   depth buffer.
+/// * [Error::InvalidOperation] - if `format` is [TextureFormat::DepthStencil] and there is no depth
+///   buffer or if there is no stencil buffer.
+/// * [Error::InvalidEnum] - if `format` is [TextureFormat::DepthStencil] and `pixel_data_type` is
+///   not [TexturePixelType::D24S8] or [TexturePixelType::D32fS8].
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of [TexturePixelType::R3G3B2],
+///   [TexturePixelType::B2G3R3], [TexturePixelType::R5G6B5], or [TexturePixelType::B5G6R5] and
+///   `format` is not [TextureFormat::RGB].
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of [TexturePixelType::R4G4B4A4],
+///   [TexturePixelType::A4B4G4R4], [TexturePixelType::R5G5B5A1], [TexturePixelType::A1B5G5R5],
+///   [TexturePixelType::R8G8B8A8], [TexturePixelType::A8B8G8R8],
+///   [TexturePixelType::R10G10B10A2], or [TexturePixelType::A2B10G10R10] and `format` is neither
+///   [TextureFormat::RGBA] nor [TextureFormat::BGRA].
+/// * [Error::InvalidOperation] - if the read framebuffer is complete, and has a sample count
+///   greater than zero.
+/// * [Error::InvalidFramebufferOperation] - if the read framebuffer is not complete.
+///
+/// # Associated Gets
+/// * [get_pixel_pack_buffer_binding]
+/// * [get_read_buffer]
 ///
 /// # Version Support
 ///
 /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
 /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-/// | [gen_renderbuffers] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [read_pixels] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [read_pixels_to_pack_buffer] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
 ///
 /// # See Also
-/// * [framebuffer_renderbuffer]
-/// * [delete_renderbuffers]
-pub fn gen_renderbuffers(renderbuffers: &mut [Renderbuffer]) {
-    let n = renderbuffers.len() as GLsizei;
-    let renderbuffers = renderbuffers.as_mut_ptr() as *mut GLuint;
-    unsafe { gl::GenRenderbuffers(n, renderbuffers) }
+/// * [get_tex_image]
+/// * [pixel_store]
+/// * [read_buffer]
+pub fn read_pixels<DataType: Sized + Copy>(
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: &mut [DataType],
+) {
+    let required = pixel_pack_size(width, height, None, format, pixel_data_type);
+    let provided = std::mem::size_of_val(data);
+    assert!(
+        provided >= required,
+        "pixel data holds {provided} bytes, but the pixels require {required}"
+    );
+
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let format = GLenum::from(format);
+    let type_ = GLenum::from(pixel_data_type);
+    let pixels = data.as_mut_ptr() as *mut std::os::raw::c_void;
+
+    // SAFE: synchronous write into `data`, which was checked to be large enough for the whole
+    // block of pixels. No memory retained
+    unsafe { gl::ReadPixels(x, y, width, height, format, type_, pixels) };
+}
+
+/// # Error mapped read pixels
+/// see [read_pixels]
+pub fn read_pixels_checked<DataType: Sized + Copy>(
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: &mut [DataType],
+) -> Result<(), FramebufferError> {
+    let required = pixel_pack_size(width, height, None, format, pixel_data_type);
+    let provided = std::mem::size_of_val(data);
+    if provided < required {
+        return Err(FramebufferError::InsufficientPixelData { required, provided });
+    }
+    read_pixels(x, y, width, height, format, pixel_data_type, data);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation
+            if matches!(
+                format,
+                TextureFormat::StencilIndex
+                    | TextureFormat::DepthComponent
+                    | TextureFormat::DepthStencil
+            ) =>
+        {
+            Err(FramebufferError::MissingReadAttachment(format))
+        }
+        Error::InvalidEnum | Error::InvalidOperation => Err(FramebufferError::InvalidReadFormat {
+            format,
+            pixel_data_type,
+        }),
+        Error::InvalidFramebufferOperation => Err(FramebufferError::IncompleteFramebuffer),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Read a block of pixels from the frame buffer into a pixel pack buffer
+/// see [read_pixels]
+///
+/// # Arguments
+/// * `offset` - Specifies the byte offset into the data store of the buffer object bound to
+///   [BufferBindingTarget::PixelPack] where the pixels are written.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// bind_buffer(BufferBindingTarget::PixelPack, Buffer(42));
+/// read_pixels_to_pack_buffer(0, 0, 800, 600, TextureFormat::RGBA, TexturePixelType::U8, 0);
+/// ```
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if the buffer object's data store is currently mapped.
+/// * [Error::InvalidOperation] - if the data would be packed to the buffer object such that the
+///   memory writes required would exceed the data store size.
+/// * [Error::InvalidOperation] - if `offset` is not evenly divisible into the number of bytes
+///   needed to store in memory a datum indicated by `pixel_data_type`.
+pub fn read_pixels_to_pack_buffer(
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    offset: u64,
+) {
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let format = GLenum::from(format);
+    let type_ = GLenum::from(pixel_data_type);
+    let pixels = offset as *mut std::os::raw::c_void;
+
+    // SAFE: with a pixel pack buffer bound, `pixels` is a byte offset into the buffer object, which
+    // the GL bounds checks
+    unsafe { gl::ReadPixels(x, y, width, height, format, type_, pixels) }
+}
+
+/// # Error mapped read pixels into a pixel pack buffer
+/// see [read_pixels_to_pack_buffer]
+pub fn read_pixels_to_pack_buffer_checked(
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    offset: u64,
+) -> Result<(), FramebufferError> {
+    read_pixels_to_pack_buffer(x, y, width, height, format, pixel_data_type, offset);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(FramebufferError::InvalidPixelBuffer),
+        Error::InvalidFramebufferOperation => Err(FramebufferError::IncompleteFramebuffer),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
 }
 
 /// # Establish data storage, format and dimensions of a renderbuffer object's image
@@ -593,12 +3295,14 @@ pub fn gen_renderbuffers(renderbuffers: &mut [Renderbuffer]) {
 /// * [Error::InvalidValue] - if either `width` or `height` is greater than the value of
 /// [get_max_renderbuffer_size].
 /// * [Error::OutOfMemory] - if the GL is unable to create a data store of the requested size.
+/// * [Error::InvalidOperation] - if zero is bound to the renderbuffer target.
 ///
 /// # Version Support
 ///
 /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
 /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
 /// | [renderbuffer_storage] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [named_renderbuffer_storage] | N | N | N | N | N | N | N | N | N | N | N | Y |
 ///
 /// # See Also
 /// * [gen_renderbuffers]
@@ -613,3 +3317,212 @@ pub fn renderbuffer_storage(internal_format: RenderbufferInternalFormat, width:
     let height = height as GLsizei;
     unsafe { gl::RenderbufferStorage(gl::RENDERBUFFER, internalformat, width, height) }
 }
+
+/// # Error mapped renderbuffer storage
+/// see [renderbuffer_storage]
+pub fn renderbuffer_storage_checked(
+    internal_format: RenderbufferInternalFormat,
+    width: u32,
+    height: u32,
+) -> Result<(), FramebufferError> {
+    renderbuffer_storage(internal_format, width, height);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(FramebufferError::InvalidDimensions { width, height }),
+        Error::InvalidOperation => Err(FramebufferError::UnboundRenderbuffer),
+        Error::OutOfMemory => Err(FramebufferError::OutOfMemory),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Establish data storage, format and dimensions of a renderbuffer object's image
+/// see [renderbuffer_storage]
+///
+/// # Arguments
+/// * `renderbuffer` - Specifies the name of the renderbuffer object.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `renderbuffer` is not the name of an existing renderbuffer
+/// object.
+pub fn named_renderbuffer_storage(
+    renderbuffer: Renderbuffer,
+    internal_format: RenderbufferInternalFormat,
+    width: u32,
+    height: u32,
+) {
+    let renderbuffer = renderbuffer.0;
+    let internalformat = GLenum::from(internal_format);
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    unsafe { gl::NamedRenderbufferStorage(renderbuffer, internalformat, width, height) }
+}
+
+/// # Error mapped named renderbuffer storage
+/// see [named_renderbuffer_storage]
+pub fn named_renderbuffer_storage_checked(
+    renderbuffer: Renderbuffer,
+    internal_format: RenderbufferInternalFormat,
+    width: u32,
+    height: u32,
+) -> Result<(), FramebufferError> {
+    named_renderbuffer_storage(renderbuffer, internal_format, width, height);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(FramebufferError::InvalidDimensions { width, height }),
+        Error::InvalidOperation => Err(FramebufferError::InvalidRenderbuffer(renderbuffer)),
+        Error::OutOfMemory => Err(FramebufferError::OutOfMemory),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Establish data storage, format, dimensions and sample count of a renderbuffer object's image
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glRenderbufferStorageMultisample.xhtml>
+///
+/// # Arguments
+/// * `samples` - Specifies the number of samples to be used for the renderbuffer object's storage.
+/// * `internal_format` - Specifies the internal format to use for the renderbuffer object's image.
+/// * `width` - Specifies the width of the renderbuffer, in pixels.
+/// * `height` - Specifies the height of the renderbuffer, in pixels.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// renderbuffer_storage_multisample(4, RenderbufferInternalFormat::Depth24Stencil8, 1920, 1080);
+/// ```
+///
+/// # Description
+/// [renderbuffer_storage_multisample] establishes the data storage, format, dimensions and number
+/// of samples of the image of the renderbuffer object currently bound to the renderbuffer target.
+///
+/// `samples` specifies the number of samples to be used for the renderbuffer object's image, and
+/// must be less than or equal to [get_max_samples]. If `internal_format` is a signed or unsigned
+/// integer format then `samples` must also be less than or equal to the implementation's maximum
+/// number of integer samples. The GL may allocate more samples than requested, but never fewer,
+/// and `samples` of zero allocates a single sample image, see [renderbuffer_storage].
+///
+/// Every attachment of a complete framebuffer must have the same number of samples, so the sample
+/// count of a multisample depth/stencil renderbuffer must match any multisample colour
+/// attachment. Multisample images cannot be read directly, and are instead resolved into a single
+/// sample framebuffer with [blit_framebuffer].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `samples` is greater than [get_max_samples].
+/// * [Error::InvalidValue] - if either `width` or `height` is greater than the value of
+/// [get_max_renderbuffer_size].
+/// * [Error::InvalidOperation] - if `internal_format` is an integer format and `samples` is greater
+/// than the maximum number of integer samples.
+/// * [Error::InvalidOperation] - if zero is bound to the renderbuffer target.
+/// * [Error::OutOfMemory] - if the GL is unable to create a data store of the requested size.
+///
+/// # Associated Gets
+/// * [get_framebuffer_samples]
+/// * [get_max_samples]
+/// * [get_renderbuffer_samples]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [renderbuffer_storage_multisample] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [named_renderbuffer_storage_multisample] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [bind_renderbuffer]
+/// * [blit_framebuffer]
+/// * [framebuffer_renderbuffer]
+/// * [renderbuffer_storage]
+pub fn renderbuffer_storage_multisample(
+    samples: u32,
+    internal_format: RenderbufferInternalFormat,
+    width: u32,
+    height: u32,
+) {
+    let samples = samples as GLsizei;
+    let internalformat = GLenum::from(internal_format);
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    unsafe {
+        gl::RenderbufferStorageMultisample(gl::RENDERBUFFER, samples, internalformat, width, height)
+    }
+}
+
+/// # Error mapped multisample renderbuffer storage
+/// see [renderbuffer_storage_multisample]
+pub fn renderbuffer_storage_multisample_checked(
+    samples: u32,
+    internal_format: RenderbufferInternalFormat,
+    width: u32,
+    height: u32,
+) -> Result<(), FramebufferError> {
+    renderbuffer_storage_multisample(samples, internal_format, width, height);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue if samples > get_max_samples() => {
+            Err(FramebufferError::InvalidSamples(samples))
+        }
+        Error::InvalidValue => Err(FramebufferError::InvalidDimensions { width, height }),
+        Error::InvalidOperation if get_i32(gl::RENDERBUFFER_BINDING) == 0 => {
+            Err(FramebufferError::UnboundRenderbuffer)
+        }
+        Error::InvalidOperation => Err(FramebufferError::InvalidSamples(samples)),
+        Error::OutOfMemory => Err(FramebufferError::OutOfMemory),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
+
+/// # Establish data storage, format, dimensions and sample count of a renderbuffer object's image
+/// see [renderbuffer_storage_multisample]
+///
+/// # Arguments
+/// * `renderbuffer` - Specifies the name of the renderbuffer object.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `renderbuffer` is not the name of an existing renderbuffer
+/// object.
+pub fn named_renderbuffer_storage_multisample(
+    renderbuffer: Renderbuffer,
+    samples: u32,
+    internal_format: RenderbufferInternalFormat,
+    width: u32,
+    height: u32,
+) {
+    let renderbuffer = renderbuffer.0;
+    let samples = samples as GLsizei;
+    let internalformat = GLenum::from(internal_format);
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    unsafe {
+        gl::NamedRenderbufferStorageMultisample(
+            renderbuffer,
+            samples,
+            internalformat,
+            width,
+            height,
+        )
+    }
+}
+
+/// # Error mapped named multisample renderbuffer storage
+/// see [named_renderbuffer_storage_multisample]
+pub fn named_renderbuffer_storage_multisample_checked(
+    renderbuffer: Renderbuffer,
+    samples: u32,
+    internal_format: RenderbufferInternalFormat,
+    width: u32,
+    height: u32,
+) -> Result<(), FramebufferError> {
+    named_renderbuffer_storage_multisample(renderbuffer, samples, internal_format, width, height);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue if samples > get_max_samples() => {
+            Err(FramebufferError::InvalidSamples(samples))
+        }
+        Error::InvalidValue => Err(FramebufferError::InvalidDimensions { width, height }),
+        Error::InvalidOperation if !is_renderbuffer(renderbuffer) => {
+            Err(FramebufferError::InvalidRenderbuffer(renderbuffer))
+        }
+        Error::InvalidOperation => Err(FramebufferError::InvalidSamples(samples)),
+        Error::OutOfMemory => Err(FramebufferError::OutOfMemory),
+        other => Err(FramebufferError::Unexpected(other)),
+    }
+}
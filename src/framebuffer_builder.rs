@@ -0,0 +1,709 @@
+//! # Framebuffer Builder
+//! <https://www.khronos.org/opengl/wiki/Framebuffer_Object#Framebuffer_Completeness>
+//!
+//! # Description
+//! Creation of a complete framebuffer object, together with the textures and renderbuffers of its
+//! attachments. [FramebufferBuilder] takes a description of the image of each attachment point,
+//! and [FramebufferBuilder::build] creates and attaches every image:
+//! ```no_run
+//! use rgl::prelude::*;
+//!
+//! let built = FramebufferBuilder::new()
+//!     .colour(0, AttachmentImage::texture(TextureInternalFormat::RGBA8, 1280, 720))
+//!     .colour(1, AttachmentImage::texture(TextureInternalFormat::RGBA16F, 1280, 720))
+//!     .depth_stencil(AttachmentImage::renderbuffer(
+//!         RenderbufferInternalFormat::Depth24Stencil8,
+//!         1280,
+//!         720,
+//!     ))
+//!     .build()
+//!     .unwrap();
+//! bind_framebuffer(FramebufferBindingTarget::Draw, built.framebuffer);
+//! ```
+//!
+//! [check_framebuffer_status] only says that a framebuffer is incomplete, not which attachment is
+//! at fault. So before any object is created, every image is checked against the metadata of its
+//! internal format, its size and its number of samples. When the framebuffer cannot be completed,
+//! a [FramebufferReport] names each offending attachment with an [AttachmentProblem].
+
+use crate::prelude::*;
+
+/// # Image created for an attachment point of a [FramebufferBuilder]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AttachmentImage {
+    /// A renderbuffer, which is multisampled when `samples` is not 0
+    Renderbuffer {
+        internal_format: RenderbufferInternalFormat,
+        width: u32,
+        height: u32,
+        samples: u32,
+    },
+
+    /// A texture of `levels` mip levels, of which `level` is attached. When `layers` is not 0, the
+    /// texture is a 2D array, of which only `layer` is attached. When `samples` is not 0, the
+    /// texture is a single level 2D multisample texture
+    Texture {
+        internal_format: TextureInternalFormat,
+        width: u32,
+        height: u32,
+        levels: u32,
+        layers: u32,
+        samples: u32,
+        level: u32,
+        layer: u32,
+    },
+}
+
+impl AttachmentImage {
+    /// A single sampled renderbuffer
+    pub fn renderbuffer(
+        internal_format: RenderbufferInternalFormat,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        AttachmentImage::Renderbuffer {
+            internal_format,
+            width,
+            height,
+            samples: 0,
+        }
+    }
+
+    /// A single sampled 2D texture of a single level
+    pub fn texture(internal_format: TextureInternalFormat, width: u32, height: u32) -> Self {
+        AttachmentImage::Texture {
+            internal_format,
+            width,
+            height,
+            levels: 1,
+            layers: 0,
+            samples: 0,
+            level: 0,
+            layer: 0,
+        }
+    }
+
+    /// Multisample the image with `samples` samples
+    pub fn with_samples(mut self, samples: u32) -> Self {
+        match &mut self {
+            AttachmentImage::Renderbuffer { samples: s, .. }
+            | AttachmentImage::Texture { samples: s, .. } => *s = samples,
+        }
+        self
+    }
+
+    /// Give a texture `levels` mip levels, and attach `level`. Renderbuffers have no mip levels,
+    /// and are returned unchanged
+    pub fn with_levels(mut self, levels: u32, level: u32) -> Self {
+        if let AttachmentImage::Texture {
+            levels: l,
+            level: a,
+            ..
+        } = &mut self
+        {
+            (*l, *a) = (levels, level);
+        }
+        self
+    }
+
+    /// Make a texture a 2D array of `layers` layers, and attach `layer`. Renderbuffers have no
+    /// layers, and are returned unchanged
+    pub fn with_layers(mut self, layers: u32, layer: u32) -> Self {
+        if let AttachmentImage::Texture {
+            layers: l,
+            layer: a,
+            ..
+        } = &mut self
+        {
+            (*l, *a) = (layers, layer);
+        }
+        self
+    }
+
+    /// Width and height of the base level
+    fn size(&self) -> (u32, u32) {
+        match *self {
+            AttachmentImage::Renderbuffer { width, height, .. }
+            | AttachmentImage::Texture { width, height, .. } => (width, height),
+        }
+    }
+
+    /// Width and height of the attached image, which is a mip level of a texture
+    fn attached_size(&self) -> (u32, u32) {
+        let (width, height) = self.size();
+        match *self {
+            AttachmentImage::Renderbuffer { .. } => (width, height),
+            AttachmentImage::Texture { level, .. } => (
+                width.checked_shr(level).unwrap_or(0).max(1),
+                height.checked_shr(level).unwrap_or(0).max(1),
+            ),
+        }
+    }
+
+    fn samples(&self) -> u32 {
+        match *self {
+            AttachmentImage::Renderbuffer { samples, .. }
+            | AttachmentImage::Texture { samples, .. } => samples,
+        }
+    }
+
+    fn renderable(&self) -> Option<Renderable> {
+        match *self {
+            AttachmentImage::Renderbuffer {
+                internal_format, ..
+            } => Some(renderbuffer_renderable(internal_format)),
+            AttachmentImage::Texture {
+                internal_format, ..
+            } => texture_renderable(internal_format),
+        }
+    }
+}
+
+/// Which attachment points an internal format can be rendered to at
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Renderable {
+    Colour,
+    Depth,
+    Stencil,
+    DepthStencil,
+}
+
+impl Renderable {
+    fn supports(self, attachment: FramebufferAttachment) -> bool {
+        match attachment {
+            FramebufferAttachment::Colour(_) => self == Renderable::Colour,
+            FramebufferAttachment::Depth => {
+                matches!(self, Renderable::Depth | Renderable::DepthStencil)
+            }
+            FramebufferAttachment::Stencil => {
+                matches!(self, Renderable::Stencil | Renderable::DepthStencil)
+            }
+            FramebufferAttachment::DepthStencil => self == Renderable::DepthStencil,
+        }
+    }
+}
+
+/// Every sized renderbuffer format is renderable, at the attachment points of its group
+fn renderbuffer_renderable(format: RenderbufferInternalFormat) -> Renderable {
+    use RenderbufferInternalFormat as Format;
+    match format {
+        Format::DepthComponent16
+        | Format::DepthComponent24
+        | Format::DepthComponent32
+        | Format::DepthComponent32F => Renderable::Depth,
+        Format::Depth24Stencil8 | Format::Depth32fStencil8 => Renderable::DepthStencil,
+        Format::StencilIndex1
+        | Format::StencilIndex4
+        | Format::StencilIndex8
+        | Format::StencilIndex16 => Renderable::Stencil,
+        _ => Renderable::Colour,
+    }
+}
+
+/// The texture formats the GL specification marks colour-renderable, which are the colour formats
+/// a renderbuffer can be allocated with. Unsized, compressed, shared exponent, signed normalized,
+/// three component integer and float, and sRGB formats without alpha are not renderable
+fn texture_renderable(format: TextureInternalFormat) -> Option<Renderable> {
+    use TextureInternalFormat as Format;
+    match format {
+        Format::DepthComponent16 | Format::DepthComponent24 | Format::DepthComponent32F => {
+            Some(Renderable::Depth)
+        }
+        Format::R8
+        | Format::R16
+        | Format::RG8
+        | Format::RG16
+        | Format::R3G3B2
+        | Format::RGB4
+        | Format::RGB5
+        | Format::RGB8
+        | Format::RGB10
+        | Format::RGB12
+        | Format::RGBA2
+        | Format::RGBA4
+        | Format::RGB5A1
+        | Format::RGBA8
+        | Format::RGB10A2
+        | Format::RGB10A2UI
+        | Format::RGBA12
+        | Format::RGBA16
+        | Format::SRGB8A8
+        | Format::R16F
+        | Format::RG16F
+        | Format::RGBA16F
+        | Format::R32F
+        | Format::RG32F
+        | Format::RGBA32F
+        | Format::R11fG11fB10f
+        | Format::R8I
+        | Format::R8UI
+        | Format::R16I
+        | Format::R16UI
+        | Format::R32I
+        | Format::R32UI
+        | Format::RG8I
+        | Format::RG8UI
+        | Format::RG16I
+        | Format::RG16UI
+        | Format::RG32I
+        | Format::RG32UI
+        | Format::RGBA8I
+        | Format::RGBA8UI
+        | Format::RGBA16I
+        | Format::RGBA16UI
+        | Format::RGBA32I
+        | Format::RGBA32UI => Some(Renderable::Colour),
+        _ => None,
+    }
+}
+
+/// # Reason an attachment of a [FramebufferBuilder] prevents a complete framebuffer
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AttachmentProblem {
+    /// The internal format cannot be rendered to at the attachment point. Colour attachments need
+    /// a colour-renderable format, and depth and stencil attachments need a depth- or
+    /// stencil-renderable format. Unsized, compressed, shared exponent, signed normalized and three
+    /// component integer and float texture formats are never renderable
+    Unrenderable,
+
+    /// The image has a width or height of 0
+    EmptyImage,
+
+    /// A depth or stencil image is attached together with a depth stencil image, which already
+    /// provides both attachment points
+    DepthStencilConflict,
+
+    /// The attached level is not one of the `levels` of the texture
+    InvalidLevel { level: u32, levels: u32 },
+
+    /// The attached layer is not one of the `layers` of the texture
+    InvalidLayer { layer: u32, layers: u32 },
+
+    /// The texture is both multisampled and layered, which is not supported
+    MultisampleArray,
+
+    /// The attached image is a different size from the first attachment
+    SizeMismatch {
+        expected: (u32, u32),
+        found: (u32, u32),
+    },
+
+    /// The image has a different number of samples from the first attachment
+    SampleMismatch { expected: u32, found: u32 },
+
+    /// GL generated the error while creating or attaching the image
+    Rejected(Error),
+}
+
+impl std::fmt::Display for AttachmentProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttachmentProblem::Unrenderable => {
+                write!(
+                    f,
+                    "the internal format is not renderable at this attachment"
+                )
+            }
+            AttachmentProblem::EmptyImage => write!(f, "the image has no texels"),
+            AttachmentProblem::DepthStencilConflict => {
+                write!(
+                    f,
+                    "the depth stencil attachment already provides this image"
+                )
+            }
+            AttachmentProblem::InvalidLevel { level, levels } => {
+                write!(f, "level {level} is not one of the {levels} levels")
+            }
+            AttachmentProblem::InvalidLayer { layer, layers } => {
+                write!(f, "layer {layer} is not one of the {layers} layers")
+            }
+            AttachmentProblem::MultisampleArray => {
+                write!(f, "multisample array textures are not supported")
+            }
+            AttachmentProblem::SizeMismatch { expected, found } => {
+                write!(
+                    f,
+                    "the image is {found:?}, but the first attachment is {expected:?}"
+                )
+            }
+            AttachmentProblem::SampleMismatch { expected, found } => {
+                write!(
+                    f,
+                    "the image has {found} samples, but the first attachment has {expected}"
+                )
+            }
+            AttachmentProblem::Rejected(error) => write!(f, "GL rejected the image with {error:?}"),
+        }
+    }
+}
+
+/// # Report of a framebuffer that a [FramebufferBuilder] could not complete
+#[derive(Debug, Clone, PartialEq)]
+pub struct FramebufferReport {
+    /// Status of the created framebuffer, as returned by [check_named_framebuffer_status], or
+    /// `None` when the problems were found before any object was created
+    pub status: Option<FramebufferStatus>,
+
+    /// Error GL had already recorded before [FramebufferBuilder::build] was called, which is
+    /// taken from GL and reported here instead of creating anything
+    pub pending_error: Option<Error>,
+
+    /// Every attachment at fault, with the reason. This can be empty when only GL found the
+    /// framebuffer incomplete, such as for [FramebufferStatus::Unsupported]
+    pub problems: Vec<(FramebufferAttachment, AttachmentProblem)>,
+}
+
+impl std::fmt::Display for FramebufferReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.status {
+            Some(status) => write!(f, "the framebuffer is incomplete ({status:?})")?,
+            None => write!(f, "the framebuffer cannot be completed")?,
+        }
+        if let Some(error) = self.pending_error {
+            write!(f, "; GL had already recorded {error:?}")?;
+        }
+        for (attachment, problem) in &self.problems {
+            write!(f, "; {attachment:?}: {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FramebufferReport {}
+
+/// # Object created for an attachment point by a [FramebufferBuilder]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AttachedObject {
+    Texture(Texture),
+    Renderbuffer(Renderbuffer),
+}
+
+/// # Complete framebuffer created by a [FramebufferBuilder]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuiltFramebuffer {
+    pub framebuffer: Framebuffer,
+
+    /// Each attachment point with the object created for it, in the order they were attached
+    pub attachments: Vec<(FramebufferAttachment, AttachedObject)>,
+}
+
+impl BuiltFramebuffer {
+    /// The texture created for `attachment`, if it is a texture attachment
+    pub fn texture(&self, attachment: FramebufferAttachment) -> Option<Texture> {
+        self.attachments
+            .iter()
+            .find_map(|(a, object)| match object {
+                AttachedObject::Texture(texture) if *a == attachment => Some(*texture),
+                _ => None,
+            })
+    }
+
+    /// The renderbuffer created for `attachment`, if it is a renderbuffer attachment
+    pub fn renderbuffer(&self, attachment: FramebufferAttachment) -> Option<Renderbuffer> {
+        self.attachments
+            .iter()
+            .find_map(|(a, object)| match object {
+                AttachedObject::Renderbuffer(renderbuffer) if *a == attachment => {
+                    Some(*renderbuffer)
+                }
+                _ => None,
+            })
+    }
+
+    /// Delete the framebuffer, and every texture and renderbuffer created for it
+    pub fn delete(self) {
+        delete_attachments(self.framebuffer, &self.attachments);
+    }
+}
+
+fn delete_attachments(
+    framebuffer: Framebuffer,
+    attachments: &[(FramebufferAttachment, AttachedObject)],
+) {
+    delete_framebuffers(&[framebuffer]);
+    for (_, object) in attachments {
+        match *object {
+            AttachedObject::Texture(texture) => delete_textures(&[texture]),
+            AttachedObject::Renderbuffer(renderbuffer) => delete_renderbuffers(&[renderbuffer]),
+        }
+    }
+}
+
+/// # Builder of a complete framebuffer and its attachments
+/// see [BuiltFramebuffer]
+///
+/// The first attachment is the reference for the size and number of samples of every other
+/// attachment. Attaching an image to the same attachment point twice replaces the earlier image.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FramebufferBuilder {
+    attachments: Vec<(FramebufferAttachment, AttachmentImage)>,
+}
+
+impl FramebufferBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach `image` to `attachment`
+    pub fn attach(mut self, attachment: FramebufferAttachment, image: AttachmentImage) -> Self {
+        match self.attachments.iter_mut().find(|(a, _)| *a == attachment) {
+            Some((_, existing)) => *existing = image,
+            None => self.attachments.push((attachment, image)),
+        }
+        self
+    }
+
+    /// Attach `image` to the colour attachment `index`
+    pub fn colour(self, index: u32, image: AttachmentImage) -> Self {
+        self.attach(FramebufferAttachment::Colour(index), image)
+    }
+
+    /// Attach `image` to the depth attachment
+    pub fn depth(self, image: AttachmentImage) -> Self {
+        self.attach(FramebufferAttachment::Depth, image)
+    }
+
+    /// Attach `image` to the stencil attachment
+    pub fn stencil(self, image: AttachmentImage) -> Self {
+        self.attach(FramebufferAttachment::Stencil, image)
+    }
+
+    /// Attach `image` to both the depth and stencil attachments
+    pub fn depth_stencil(self, image: AttachmentImage) -> Self {
+        self.attach(FramebufferAttachment::DepthStencil, image)
+    }
+
+    /// Check every attachment against the metadata of its internal format, and against the size
+    /// and number of samples of the first attachment, without any GL call
+    pub fn validate(&self) -> Vec<(FramebufferAttachment, AttachmentProblem)> {
+        let mut problems = vec![];
+        let reference = self.attachments.first().map(|(_, image)| image);
+        let depth_stencil = self
+            .attachments
+            .iter()
+            .any(|(attachment, _)| *attachment == FramebufferAttachment::DepthStencil);
+        for (attachment, image) in &self.attachments {
+            let mut problem = |problem| problems.push((*attachment, problem));
+            if depth_stencil
+                && matches!(
+                    attachment,
+                    FramebufferAttachment::Depth | FramebufferAttachment::Stencil
+                )
+            {
+                problem(AttachmentProblem::DepthStencilConflict);
+            }
+            if !image.renderable().is_some_and(|r| r.supports(*attachment)) {
+                problem(AttachmentProblem::Unrenderable);
+            }
+            let (width, height) = image.size();
+            if width == 0 || height == 0 {
+                problem(AttachmentProblem::EmptyImage);
+            }
+            if let AttachmentImage::Texture {
+                levels,
+                layers,
+                samples,
+                level,
+                layer,
+                ..
+            } = *image
+            {
+                let levels = if samples > 0 { 1 } else { levels };
+                if samples > 0 && layers > 0 {
+                    problem(AttachmentProblem::MultisampleArray);
+                }
+                if level >= levels {
+                    problem(AttachmentProblem::InvalidLevel { level, levels });
+                }
+                if layer >= layers.max(1) {
+                    problem(AttachmentProblem::InvalidLayer { layer, layers });
+                }
+            }
+            if let Some(reference) = reference {
+                let (expected, found) = (reference.attached_size(), image.attached_size());
+                if expected != found {
+                    problem(AttachmentProblem::SizeMismatch { expected, found });
+                }
+                let (expected, found) = (reference.samples(), image.samples());
+                if expected != found {
+                    problem(AttachmentProblem::SampleMismatch { expected, found });
+                }
+            }
+        }
+        problems
+    }
+
+    /// # Create the framebuffer and the images of its attachments
+    ///
+    /// Nothing is created when [FramebufferBuilder::validate] finds any problem. Otherwise the
+    /// framebuffer and each image are created and attached. Every colour attachment is selected as
+    /// a draw buffer, and the lowest is selected as the read buffer.
+    ///
+    /// The framebuffer and renderbuffers are created through direct state access. The storage of
+    /// each texture is allocated through its target on the active texture unit, whose previous
+    /// binding is then restored, so no binding is changed.
+    ///
+    /// Nothing is created either when GL has already recorded an error, which is taken and returned
+    /// as [FramebufferReport::pending_error] rather than being blamed on an attachment. Every
+    /// object is deleted again when GL generates an error, or the framebuffer is not complete.
+    pub fn build(&self) -> Result<BuiltFramebuffer, FramebufferReport> {
+        let problems = self.validate();
+        if !problems.is_empty() {
+            return Err(FramebufferReport {
+                status: None,
+                pending_error: None,
+                problems,
+            });
+        }
+
+        // an error left by an earlier call would otherwise be blamed on the first attachment
+        match get_error() {
+            Error::NoError => {}
+            error => {
+                return Err(FramebufferReport {
+                    status: None,
+                    pending_error: Some(error),
+                    problems: vec![],
+                })
+            }
+        }
+
+        let mut framebuffer = Framebuffer::default();
+        create_framebuffers(std::slice::from_mut(&mut framebuffer));
+        let mut attachments = vec![];
+        let mut problems = vec![];
+        for (attachment, image) in &self.attachments {
+            attachments.push((*attachment, create_image(framebuffer, *attachment, image)));
+            match get_error() {
+                Error::NoError => {}
+                error => problems.push((*attachment, AttachmentProblem::Rejected(error))),
+            }
+        }
+
+        let mut colours: Vec<u32> = self
+            .attachments
+            .iter()
+            .filter_map(|(attachment, _)| match attachment {
+                FramebufferAttachment::Colour(index) => Some(*index),
+                _ => None,
+            })
+            .collect();
+        colours.sort();
+        let draw_buffers: Vec<ColourBuffer> = match colours.last() {
+            Some(last) => (0..=*last)
+                .map(|index| match colours.contains(&index) {
+                    true => ColourBuffer::Attachment(index),
+                    false => ColourBuffer::None,
+                })
+                .collect(),
+            None => vec![ColourBuffer::None],
+        };
+        named_framebuffer_draw_buffers(framebuffer, &draw_buffers);
+        let read_buffer = colours
+            .first()
+            .map_or(ColourBuffer::None, |index| ColourBuffer::Attachment(*index));
+        named_framebuffer_read_buffer(framebuffer, read_buffer);
+
+        let status = check_named_framebuffer_status(framebuffer, FramebufferBindingTarget::Draw);
+        if problems.is_empty() && status == Some(FramebufferStatus::Complete) {
+            return Ok(BuiltFramebuffer {
+                framebuffer,
+                attachments,
+            });
+        }
+        delete_attachments(framebuffer, &attachments);
+        Err(FramebufferReport {
+            status,
+            pending_error: None,
+            problems,
+        })
+    }
+}
+
+fn create_image(
+    framebuffer: Framebuffer,
+    attachment: FramebufferAttachment,
+    image: &AttachmentImage,
+) -> AttachedObject {
+    match *image {
+        AttachmentImage::Renderbuffer {
+            internal_format,
+            width,
+            height,
+            samples,
+        } => {
+            let mut renderbuffer = Renderbuffer::default();
+            create_renderbuffers(std::slice::from_mut(&mut renderbuffer));
+            match samples {
+                0 => named_renderbuffer_storage(renderbuffer, internal_format, width, height),
+                _ => named_renderbuffer_storage_multisample(
+                    renderbuffer,
+                    samples,
+                    internal_format,
+                    width,
+                    height,
+                ),
+            }
+            named_framebuffer_renderbuffer(framebuffer, attachment, renderbuffer);
+            AttachedObject::Renderbuffer(renderbuffer)
+        }
+        AttachmentImage::Texture {
+            internal_format,
+            width,
+            height,
+            levels,
+            layers,
+            samples,
+            level,
+            layer,
+        } => {
+            let mut texture = Texture::default();
+            gen_textures(std::slice::from_mut(&mut texture));
+            // the storage is allocated through the binding of the active texture unit, which is
+            // restored afterwards
+            if samples > 0 {
+                let previous = get_texture_binding_multisample_2d();
+                bind_texture(TextureBindingTarget::Multisample2D, texture);
+                tex_storage_2d_multisample(
+                    TextureMultisample2DTarget::Multisample2D,
+                    samples,
+                    internal_format,
+                    width,
+                    height,
+                    true,
+                );
+                bind_texture(TextureBindingTarget::Multisample2D, previous);
+            } else if layers > 0 {
+                let previous = get_texture_binding_2d_array();
+                bind_texture(TextureBindingTarget::Array2D, texture);
+                tex_storage_3d(
+                    TextureBinding3DTarget::Image2DArray,
+                    levels,
+                    internal_format,
+                    width,
+                    height,
+                    layers,
+                );
+                bind_texture(TextureBindingTarget::Array2D, previous);
+            } else {
+                let previous = get_texture_binding_2d();
+                bind_texture(TextureBindingTarget::Image2D, texture);
+                tex_storage_2d(
+                    TextureStorage2DTarget::Image2D,
+                    levels,
+                    internal_format,
+                    width,
+                    height,
+                );
+                bind_texture(TextureBindingTarget::Image2D, previous);
+            }
+            match layers {
+                0 => named_framebuffer_texture(framebuffer, attachment, texture, level),
+                _ => {
+                    named_framebuffer_texture_layer(framebuffer, attachment, texture, level, layer)
+                }
+            }
+            AttachedObject::Texture(texture)
+        }
+    }
+}
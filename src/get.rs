@@ -1,10 +1,15 @@
 //! # Return the value or values of a selected parameter
 //! <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+//!
+//! # Description
+//! The context-wide state queries that do not belong to any single module. Only the indexed
+//! queries have a `_checked` counter part, since an index is the only argument here the GL can
+//! reject; the plain queries leave nothing for [get_error] to report on a supported context.
 
 use crate::prelude::*;
 use gl::types::*;
 
-fn get_bool(pname: GLenum) -> bool {
+pub(crate) fn get_bool(pname: GLenum) -> bool {
     let mut val = GLboolean::default();
 
     // SAFE: synchronous write into `val`, no memory retained
@@ -20,7 +25,7 @@ fn get_f64(pname: GLenum) -> f64 {
     val
 }
 
-fn get_f32(pname: GLenum) -> f32 {
+pub(crate) fn get_f32(pname: GLenum) -> f32 {
     let mut val = GLfloat::default();
 
     // SAFE: synchronous write into `val`, no memory retained
@@ -28,7 +33,7 @@ fn get_f32(pname: GLenum) -> f32 {
     val
 }
 
-fn get_i32(pname: GLenum) -> i32 {
+pub(crate) fn get_i32(pname: GLenum) -> i32 {
     let mut val = GLint::default();
 
     // SAFE: synchronous write into `val`, no memory retained
@@ -36,35 +41,1517 @@ fn get_i32(pname: GLenum) -> i32 {
     val
 }
 
+pub(crate) fn get_i64(pname: GLenum) -> i64 {
+    let mut val = GLint64::default();
+
+    // SAFE: synchronous write into `val`, no memory retained
+    unsafe { gl::GetInteger64v(pname, &mut val) };
+    val
+}
+
+pub(crate) fn get_bool_v<const N: usize>(pname: GLenum) -> [bool; N] {
+    let mut vals = [GLboolean::default(); N];
+
+    // SAFE: synchronous write into `vals`, `N` is the element count of `pname`
+    unsafe { gl::GetBooleanv(pname, vals.as_mut_ptr()) };
+    vals.map(|val| val == gl::TRUE)
+}
+
+pub(crate) fn get_f32v<const N: usize>(pname: GLenum) -> [f32; N] {
+    let mut vals = [GLfloat::default(); N];
+
+    // SAFE: synchronous write into `vals`, `N` is the element count of `pname`
+    unsafe { gl::GetFloatv(pname, vals.as_mut_ptr()) };
+    vals
+}
+
+pub(crate) fn get_f64v<const N: usize>(pname: GLenum) -> [f64; N] {
+    let mut vals = [GLdouble::default(); N];
+
+    // SAFE: synchronous write into `vals`, `N` is the element count of `pname`
+    unsafe { gl::GetDoublev(pname, vals.as_mut_ptr()) };
+    vals
+}
+
+pub(crate) fn get_i32v<const N: usize>(pname: GLenum) -> [i32; N] {
+    let mut vals = [GLint::default(); N];
+
+    // SAFE: synchronous write into `vals`, `N` is the element count of `pname`
+    unsafe { gl::GetIntegerv(pname, vals.as_mut_ptr()) };
+    vals
+}
+
+pub(crate) fn get_bool_indexed<const N: usize>(target: GLenum, index: u32) -> [bool; N] {
+    let mut vals = [GLboolean::default(); N];
+
+    // SAFE: synchronous write into `vals`, `N` is the element count of `target`
+    unsafe { gl::GetBooleani_v(target, index, vals.as_mut_ptr()) };
+    vals.map(|val| val == gl::TRUE)
+}
+
+pub(crate) fn get_f64_indexed<const N: usize>(target: GLenum, index: u32) -> [f64; N] {
+    let mut vals = [GLdouble::default(); N];
+
+    // SAFE: synchronous write into `vals`, `N` is the element count of `target`
+    unsafe { gl::GetDoublei_v(target, index, vals.as_mut_ptr()) };
+    vals
+}
+
+pub(crate) fn get_f32_indexed<const N: usize>(target: GLenum, index: u32) -> [f32; N] {
+    let mut vals = [GLfloat::default(); N];
+
+    // SAFE: synchronous write into `vals`, `N` is the element count of `target`
+    unsafe { gl::GetFloati_v(target, index, vals.as_mut_ptr()) };
+    vals
+}
+
+pub(crate) fn get_i32_indexed<const N: usize>(target: GLenum, index: u32) -> [i32; N] {
+    let mut vals = [GLint::default(); N];
+
+    // SAFE: synchronous write into `vals`, `N` is the element count of `target`
+    unsafe { gl::GetIntegeri_v(target, index, vals.as_mut_ptr()) };
+    vals
+}
+
+pub(crate) fn get_i64_indexed<const N: usize>(target: GLenum, index: u32) -> [i64; N] {
+    let mut vals = [GLint64::default(); N];
+
+    // SAFE: synchronous write into `vals`, `N` is the element count of `target`
+    unsafe { gl::GetInteger64i_v(target, index, vals.as_mut_ptr()) };
+    vals
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// active_texture(42);
+/// assert_eq!(get_active_texture(), 42);
+/// ```
+///
+/// # Description
+/// Returns a single value indicating the active multitexture unit. The initial value is `0``. See
+/// [active_texture].
+///
+/// # Compatability
+///
+/// # Errors
+///
+/// # Associated Gets
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_active_texture] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+pub fn get_active_texture() -> u32 {
+    get_i32(gl::ACTIVE_TEXTURE) as u32 - gl::TEXTURE0
+}
+
+/// # Return the buffer object bound to a buffer target
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// bind_buffer(BufferBindingTarget::Array, Buffer(42));
+/// assert_eq!(get_array_buffer_binding(), Buffer(42));
+/// ```
+///
+/// # Description
+/// Each getter returns the buffer object currently bound to one of the buffer targets, or
+/// `Buffer(0)` when no buffer object is bound to it. See [bind_buffer].
+///
+/// | Getter | Buffer Target |
+/// |--------|---------------|
+/// | [get_array_buffer_binding] | [BufferBindingTarget::Array] |
+/// | [get_atomic_counter_buffer_binding] | [BufferBindingTarget::AtomicCounter] |
+/// | [get_copy_read_buffer_binding] | [BufferBindingTarget::CopyRead] |
+/// | [get_copy_write_buffer_binding] | [BufferBindingTarget::CopyWrite] |
+/// | [get_dispatch_indirect_buffer_binding] | [BufferBindingTarget::DispatchIndirect] |
+/// | [get_draw_indirect_buffer_binding] | [BufferBindingTarget::DrawIndirect] |
+/// | [get_element_array_buffer_binding] | [BufferBindingTarget::ElementArray] |
+/// | [get_pixel_pack_buffer_binding] | [BufferBindingTarget::PixelPack] |
+/// | [get_pixel_unpack_buffer_binding] | [BufferBindingTarget::PixelUnpack] |
+/// | [get_query_buffer_binding] | [BufferBindingTarget::Query] |
+/// | [get_shader_storage_buffer_binding] | [BufferBindingTarget::ShaderStorage] |
+/// | [get_texture_buffer_binding] | [BufferBindingTarget::Texture] |
+/// | [get_transform_feedback_buffer_binding] | [BufferBindingTarget::TransformFeedback] |
+/// | [get_uniform_buffer_binding] | [BufferBindingTarget::Uniform] |
+///
+/// The element array buffer binding is part of the state of the bound vertex array object. For the
+/// indexed binding points of [bind_buffer_base] and [bind_buffer_range], see
+/// [get_buffer_binding_indexed].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_array_buffer_binding] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_atomic_counter_buffer_binding] | N | N | N | N | N | N | N | N | Y | Y | Y | Y |
+/// | [get_copy_read_buffer_binding] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_copy_write_buffer_binding] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_dispatch_indirect_buffer_binding] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+/// | [get_draw_indirect_buffer_binding] | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+/// | [get_element_array_buffer_binding] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_pixel_pack_buffer_binding] | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_pixel_unpack_buffer_binding] | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_query_buffer_binding] | N | N | N | N | N | N | N | N | N | N | Y | Y |
+/// | [get_shader_storage_buffer_binding] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+/// | [get_texture_buffer_binding] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_transform_feedback_buffer_binding] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_uniform_buffer_binding] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_buffer]
+/// * [get_buffer_binding_indexed]
+pub fn get_array_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::ARRAY_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the atomic counter buffer target
+/// see [get_array_buffer_binding]
+pub fn get_atomic_counter_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::ATOMIC_COUNTER_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the copy read buffer target
+/// see [get_array_buffer_binding]
+pub fn get_copy_read_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::COPY_READ_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the copy write buffer target
+/// see [get_array_buffer_binding]
+pub fn get_copy_write_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::COPY_WRITE_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the dispatch indirect buffer target
+/// see [get_array_buffer_binding]
+pub fn get_dispatch_indirect_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::DISPATCH_INDIRECT_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the draw indirect buffer target
+/// see [get_array_buffer_binding]
+pub fn get_draw_indirect_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::DRAW_INDIRECT_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the element array buffer target
+/// see [get_array_buffer_binding]
+pub fn get_element_array_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::ELEMENT_ARRAY_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the pixel pack buffer target
+/// see [get_array_buffer_binding]
+pub fn get_pixel_pack_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::PIXEL_PACK_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the pixel unpack buffer target
+/// see [get_array_buffer_binding]
+pub fn get_pixel_unpack_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::PIXEL_UNPACK_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the query buffer target
+/// see [get_array_buffer_binding]
+pub fn get_query_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::QUERY_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the shader storage buffer target
+/// see [get_array_buffer_binding]
+pub fn get_shader_storage_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::SHADER_STORAGE_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the texture buffer target
+/// see [get_array_buffer_binding]
+pub fn get_texture_buffer_binding() -> Buffer {
+    // `GL_TEXTURE_BUFFER_BINDING` shares its value with `GL_TEXTURE_BUFFER`
+    Buffer(get_i32(gl::TEXTURE_BUFFER) as u32)
+}
+
+/// # Return the buffer object bound to the transform feedback buffer target
+/// see [get_array_buffer_binding]
+pub fn get_transform_feedback_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::TRANSFORM_FEEDBACK_BUFFER_BINDING) as u32)
+}
+
+/// # Return the buffer object bound to the uniform buffer target
+/// see [get_array_buffer_binding]
+pub fn get_uniform_buffer_binding() -> Buffer {
+    Buffer(get_i32(gl::UNIFORM_BUFFER_BINDING) as u32)
+}
+
+/// The `glGet` parameter names of the binding, start and size of an indexed buffer binding point
+fn indexed_buffer_pnames(target: BufferBindingRangeTarget) -> [GLenum; 3] {
+    match target {
+        BufferBindingRangeTarget::AtomicCounter => [
+            gl::ATOMIC_COUNTER_BUFFER_BINDING,
+            gl::ATOMIC_COUNTER_BUFFER_START,
+            gl::ATOMIC_COUNTER_BUFFER_SIZE,
+        ],
+        BufferBindingRangeTarget::ShaderStorage => [
+            gl::SHADER_STORAGE_BUFFER_BINDING,
+            gl::SHADER_STORAGE_BUFFER_START,
+            gl::SHADER_STORAGE_BUFFER_SIZE,
+        ],
+        BufferBindingRangeTarget::TransformFeedback => [
+            gl::TRANSFORM_FEEDBACK_BUFFER_BINDING,
+            gl::TRANSFORM_FEEDBACK_BUFFER_START,
+            gl::TRANSFORM_FEEDBACK_BUFFER_SIZE,
+        ],
+        BufferBindingRangeTarget::Uniform => [
+            gl::UNIFORM_BUFFER_BINDING,
+            gl::UNIFORM_BUFFER_START,
+            gl::UNIFORM_BUFFER_SIZE,
+        ],
+    }
+}
+
+fn indexed_buffer_error(target: BufferBindingRangeTarget, index: u32) -> Result<(), BufferError> {
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(BufferError::InvalidBindingIndex(target, index)),
+        other => Err(BufferError::Unexpected(other)),
+    }
+}
+
+/// # Return the buffer object bound to an indexed buffer binding point
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target of the indexed binding point.
+/// * `index` - Specifies the index of the binding point within the array specified by `target`.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// bind_buffer_range(BufferBindingRangeTarget::Uniform, 2, Buffer(42), 256, 64);
+/// let target = BufferBindingRangeTarget::Uniform;
+/// assert_eq!(get_buffer_binding_indexed(target, 2), Buffer(42));
+/// assert_eq!(get_buffer_start_indexed(target, 2), 256);
+/// assert_eq!(get_buffer_size_indexed(target, 2), 64);
+/// ```
+///
+/// # Description
+/// Each getter returns a single value for the binding point `index` of `target`:
+/// * [get_buffer_binding_indexed] - the buffer object bound to the binding point, or `Buffer(0)`
+/// when no buffer object is bound to it.
+/// * [get_buffer_start_indexed] - the start offset of the bound range, which is 0 for a binding
+/// made with [bind_buffer_base].
+/// * [get_buffer_size_indexed] - the size of the bound range, which is 0 for a binding made with
+/// [bind_buffer_base].
+///
+/// Binding a buffer with [bind_buffer_base] or [bind_buffer_range] also binds it to the generic
+/// binding point of `target`, see [get_uniform_buffer_binding].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `index` is greater than or equal to the number of binding points
+/// of `target`, e.g. [get_max_uniform_buffer_bindings].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_buffer_binding_indexed] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_buffer_start_indexed] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_buffer_size_indexed] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_buffer_base]
+/// * [bind_buffer_range]
+pub fn get_buffer_binding_indexed(target: BufferBindingRangeTarget, index: u32) -> Buffer {
+    let [binding, _, _] = indexed_buffer_pnames(target);
+    let [buffer] = get_i32_indexed(binding, index);
+    Buffer(buffer as u32)
+}
+
+/// # Error mapped get buffer binding indexed
+/// see [get_buffer_binding_indexed]
+pub fn get_buffer_binding_indexed_checked(
+    target: BufferBindingRangeTarget,
+    index: u32,
+) -> Result<Buffer, BufferError> {
+    let buffer = get_buffer_binding_indexed(target, index);
+    indexed_buffer_error(target, index).map(|()| buffer)
+}
+
+/// # Return the start offset of the range bound to an indexed buffer binding point
+/// see [get_buffer_binding_indexed]
+pub fn get_buffer_start_indexed(target: BufferBindingRangeTarget, index: u32) -> i64 {
+    let [_, start, _] = indexed_buffer_pnames(target);
+    let [offset] = get_i64_indexed(start, index);
+    offset
+}
+
+/// # Error mapped get buffer start indexed
+/// see [get_buffer_start_indexed]
+pub fn get_buffer_start_indexed_checked(
+    target: BufferBindingRangeTarget,
+    index: u32,
+) -> Result<i64, BufferError> {
+    let offset = get_buffer_start_indexed(target, index);
+    indexed_buffer_error(target, index).map(|()| offset)
+}
+
+/// # Return the size of the range bound to an indexed buffer binding point
+/// see [get_buffer_binding_indexed]
+pub fn get_buffer_size_indexed(target: BufferBindingRangeTarget, index: u32) -> u64 {
+    let [_, _, size] = indexed_buffer_pnames(target);
+    let [size] = get_i64_indexed(size, index);
+    size as u64
+}
+
+/// # Error mapped get buffer size indexed
+/// see [get_buffer_size_indexed]
+pub fn get_buffer_size_indexed_checked(
+    target: BufferBindingRangeTarget,
+    index: u32,
+) -> Result<u64, BufferError> {
+    let size = get_buffer_size_indexed(target, index);
+    indexed_buffer_error(target, index).map(|()| size)
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// clear_colour(0.1, 0.2, 0.3, 1.0);
+/// assert_eq!(get_colour_clear_value(), [0.1, 0.2, 0.3, 1.0]);
+/// ```
+///
+/// # Description
+/// Returns four values, the red, green, blue, and alpha values used to clear the colour buffers.
+/// Integer values, if requested, are linearly mapped from the internal floating-point
+/// representation such that 1.0 returns the most positive representable integer value, and -1.0
+/// returns the most negative representable integer value. The initial value is
+/// `[0.0, 0.0, 0.0, 0.0]`. See [clear_colour].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_colour_clear_value] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_depth_clear_value]
+/// * [get_stencil_clear_value]
+pub fn get_colour_clear_value() -> [f32; 4] {
+    get_f32v(gl::COLOR_CLEAR_VALUE)
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// colour_mask(true, true, true, false);
+/// assert_eq!(get_colour_writemask(), [true, true, true, false]);
+/// ```
+///
+/// # Description
+/// Returns four boolean values, the red, green, blue, and alpha write enables for the colour
+/// buffers of the first draw buffer. The initial value is `[true, true, true, true]`. See
+/// [colour_mask].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_colour_writemask] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_colour_writemask_buffer]
+pub fn get_colour_writemask() -> [bool; 4] {
+    get_bool_v(gl::COLOR_WRITEMASK)
+}
+
+/// # Return the value or values of a selected indexed parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Arguments
+/// * `buffer_index` - Specifies the index of the draw buffer to query.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// colour_mask_buffer(1, false, false, false, false);
+/// assert_eq!(get_colour_writemask_buffer(1), [false; 4]);
+/// ```
+///
+/// # Description
+/// Returns four boolean values, the red, green, blue, and alpha write enables for the colour
+/// buffers of the draw buffer `buffer_index`. The initial value is `[true, true, true, true]`.
+/// See [colour_mask_buffer].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_colour_writemask_buffer] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_colour_writemask]
+pub fn get_colour_writemask_buffer(buffer_index: u32) -> [bool; 4] {
+    get_bool_indexed(gl::COLOR_WRITEMASK, buffer_index)
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// use_program(Program(42));
+/// assert_eq!(get_current_program(), Program(42));
+/// ```
+///
+/// # Description
+/// Returns the program object that is currently active, or `Program(0)` when no program object is
+/// in use. See [use_program].
+///
+/// # Compatability
+///
+/// # Errors
+///
+/// # Associated Gets
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_current_program] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+pub fn get_current_program() -> Program {
+    Program(get_i32(gl::CURRENT_PROGRAM) as u32)
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// clear_depth(0.0);
+/// assert_eq!(get_depth_clear_value(), 0.0);
+/// ```
+///
+/// # Description
+/// Returns one value, the value that is used to clear the depth buffer. Integer values, if
+/// requested, are linearly mapped from the internal floating-point representation such that 1.0
+/// returns the most positive representable integer value, and -1.0 returns the most negative
+/// representable integer value. The initial value is 1. See [clear_depth].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_depth_clear_value] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_colour_clear_value]
+/// * [get_stencil_clear_value]
+pub fn get_depth_clear_value() -> f64 {
+    get_f64(gl::DEPTH_CLEAR_VALUE)
+}
+
+/// # Return the depth comparison function and depth write mask
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// depth_func(CompareFunc::LessOrEqual);
+/// depth_mask(false);
+/// assert_eq!(get_depth_func(), Some(CompareFunc::LessOrEqual));
+/// assert!(!get_depth_writemask());
+/// ```
+///
+/// # Description
+/// Each getter returns a single value:
+/// * [get_depth_func] - the depth comparison function. The initial value is [CompareFunc::Less].
+/// See [depth_func].
+/// * [get_depth_writemask] - whether the depth buffer is enabled for writing. The initial value
+/// is `true`. See [depth_mask].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_depth_func] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_depth_writemask] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_depth_clear_value]
+/// * [get_depth_range]
+/// * [is_enabled]
+pub fn get_depth_func() -> Option<CompareFunc> {
+    CompareFunc::try_from(get_i32(gl::DEPTH_FUNC) as GLenum).ok()
+}
+
+/// # Return whether the depth buffer is enabled for writing
+/// see [get_depth_func]
+pub fn get_depth_writemask() -> bool {
+    get_bool(gl::DEPTH_WRITEMASK)
+}
+
+/// # Return the framebuffer objects bound to the framebuffer targets
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// bind_framebuffer(FramebufferBindingTarget::Read, Framebuffer(42));
+/// assert_eq!(get_read_framebuffer_binding(), Framebuffer(42));
+/// assert_eq!(get_draw_framebuffer_binding(), Framebuffer(0));
+/// ```
+///
+/// # Description
+/// [get_draw_framebuffer_binding] returns the framebuffer object bound to the draw framebuffer
+/// target and [get_read_framebuffer_binding] the one bound to the read framebuffer target. The
+/// initial value of both is `Framebuffer(0)`, the default framebuffer. See [bind_framebuffer].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_draw_framebuffer_binding] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_read_framebuffer_binding] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_framebuffer]
+/// * [get_renderbuffer_binding]
+pub fn get_draw_framebuffer_binding() -> Framebuffer {
+    Framebuffer(get_i32(gl::DRAW_FRAMEBUFFER_BINDING) as u32)
+}
+
+/// # Return the framebuffer object bound to the read framebuffer target
+/// see [get_draw_framebuffer_binding]
+pub fn get_read_framebuffer_binding() -> Framebuffer {
+    Framebuffer(get_i32(gl::READ_FRAMEBUFFER_BINDING) as u32)
+}
+
+/// # Return the version of the context
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let supports_dsa = (get_major_version(), get_minor_version()) >= (4, 5);
+/// ```
+///
+/// # Description
+/// [get_major_version] and [get_minor_version] return the major and minor version numbers of the
+/// OpenGL API supported by the current context. [get_num_extensions] returns the number of
+/// extensions supported by the GL implementation for the current context.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_major_version] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_minor_version] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_num_extensions] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+pub fn get_major_version() -> u32 {
+    get_i32(gl::MAJOR_VERSION) as u32
+}
+
+/// # Return the minor version of the context
+/// see [get_major_version]
+pub fn get_minor_version() -> u32 {
+    get_i32(gl::MINOR_VERSION) as u32
+}
+
+/// # Return the number of extensions supported by the context
+/// see [get_major_version]
+pub fn get_num_extensions() -> u32 {
+    get_i32(gl::NUM_EXTENSIONS) as u32
+}
+
+/// # Return the indexed buffer binding limits and alignments
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let alignment = get_uniform_buffer_offset_alignment() as i64;
+/// let offset = (100 + alignment - 1) / alignment * alignment;
+/// bind_buffer_range(BufferBindingRangeTarget::Uniform, 0, Buffer(42), offset, 64);
+/// ```
+///
+/// # Description
+/// Each getter returns a single value:
+/// * [get_max_atomic_counter_buffer_bindings] - the maximum number of atomic counter buffer
+/// binding points. The value must be at least 1.
+/// * [get_max_shader_storage_buffer_bindings] - the maximum number of shader storage buffer
+/// binding points. The value must be at least 8.
+/// * [get_max_transform_feedback_buffers] - the maximum number of transform feedback buffer
+/// binding points. The value must be at least 4.
+/// * [get_max_uniform_buffer_bindings] - the maximum number of uniform buffer binding points. The
+/// value must be at least 84.
+/// * [get_max_uniform_block_size] - the maximum size in basic machine units of a uniform block.
+/// The value must be at least 16384.
+/// * [get_shader_storage_buffer_offset_alignment] - the minimum required alignment for the
+/// `offset` of a shader storage buffer range given to [bind_buffer_range]. The initial value is 1.
+/// * [get_uniform_buffer_offset_alignment] - the minimum required alignment for the `offset` of a
+/// uniform buffer range given to [bind_buffer_range]. The initial value is 1.
+/// * [get_min_map_buffer_alignment] - the minimum alignment in basic machine units of pointers
+/// returned from [map_buffer_range]. The value must be at least 64.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_atomic_counter_buffer_bindings] | N | N | N | N | N | N | N | N | Y | Y | Y | Y |
+/// | [get_max_shader_storage_buffer_bindings] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+/// | [get_max_transform_feedback_buffers] | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+/// | [get_max_uniform_buffer_bindings] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_uniform_block_size] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_shader_storage_buffer_offset_alignment] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+/// | [get_uniform_buffer_offset_alignment] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_min_map_buffer_alignment] | N | N | N | N | N | N | N | N | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_buffer_base]
+/// * [get_buffer_binding_indexed]
+pub fn get_max_atomic_counter_buffer_bindings() -> u32 {
+    get_i32(gl::MAX_ATOMIC_COUNTER_BUFFER_BINDINGS) as u32
+}
+
+/// # Return the maximum number of shader storage buffer binding points
+/// see [get_max_atomic_counter_buffer_bindings]
+pub fn get_max_shader_storage_buffer_bindings() -> u32 {
+    get_i32(gl::MAX_SHADER_STORAGE_BUFFER_BINDINGS) as u32
+}
+
+/// # Return the maximum number of transform feedback buffer binding points
+/// see [get_max_atomic_counter_buffer_bindings]
+pub fn get_max_transform_feedback_buffers() -> u32 {
+    get_i32(gl::MAX_TRANSFORM_FEEDBACK_BUFFERS) as u32
+}
+
+/// # Return the maximum number of uniform buffer binding points
+/// see [get_max_atomic_counter_buffer_bindings]
+pub fn get_max_uniform_buffer_bindings() -> u32 {
+    get_i32(gl::MAX_UNIFORM_BUFFER_BINDINGS) as u32
+}
+
+/// # Return the maximum size of a uniform block
+/// see [get_max_atomic_counter_buffer_bindings]
+pub fn get_max_uniform_block_size() -> u64 {
+    get_i64(gl::MAX_UNIFORM_BLOCK_SIZE) as u64
+}
+
+/// # Return the alignment of shader storage buffer range offsets
+/// see [get_max_atomic_counter_buffer_bindings]
+pub fn get_shader_storage_buffer_offset_alignment() -> u32 {
+    get_i32(gl::SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT) as u32
+}
+
+/// # Return the alignment of uniform buffer range offsets
+/// see [get_max_atomic_counter_buffer_bindings]
+pub fn get_uniform_buffer_offset_alignment() -> u32 {
+    get_i32(gl::UNIFORM_BUFFER_OFFSET_ALIGNMENT) as u32
+}
+
+/// # Return the alignment of mapped buffer pointers
+/// see [get_max_atomic_counter_buffer_bindings]
+pub fn get_min_map_buffer_alignment() -> u32 {
+    get_i32(gl::MIN_MAP_BUFFER_ALIGNMENT) as u32
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(get_max_combined_texture_image_units() >= 80);
+/// ```
+///
+/// # Description
+/// Returns a single value, the maximum supported texture image units that can be used to access
+/// texture maps from the vertex shader and the fragment processor combined. If both the vertex
+/// shader and the fragment processing stage access the same texture image unit, then that counts
+/// as using two texture image units against this limit. The value must be at least 48. See
+/// [active_texture] and [bind_sampler].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_combined_texture_image_units] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+pub fn get_max_combined_texture_image_units() -> u32 {
+    get_i32(gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS) as u32
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(get_max_colour_attachments() >= 8);
+/// ```
+///
+/// # Description
+/// Returns a single value, the maximum number of colour attachment points of a framebuffer object,
+/// so the largest index of [FramebufferAttachment::Colour] is one less. The value must be at least
+/// 8. See [framebuffer_texture] and [framebuffer_renderbuffer].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_colour_attachments] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_max_draw_buffers]
+pub fn get_max_colour_attachments() -> u32 {
+    get_i32(gl::MAX_COLOR_ATTACHMENTS) as u32
+}
+
+/// # Return the sample limits of multisample textures and integer formats
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let samples = get_max_colour_texture_samples().min(8);
+/// ```
+///
+/// # Description
+/// Each getter returns a single value:
+/// * [get_max_colour_texture_samples] - the maximum number of samples in a colour multisample
+/// texture.
+/// * [get_max_depth_texture_samples] - the maximum number of samples in a multisample depth or
+/// depth-stencil texture.
+/// * [get_max_integer_samples] - the maximum number of samples supported in integer format
+/// multisample buffers.
+///
+/// See [tex_image_2d_multisample] and [renderbuffer_storage_multisample].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_colour_texture_samples] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_depth_texture_samples] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_integer_samples] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_max_samples]
+pub fn get_max_colour_texture_samples() -> u32 {
+    get_i32(gl::MAX_COLOR_TEXTURE_SAMPLES) as u32
+}
+
+/// # Return the maximum number of samples of a depth texture
+/// see [get_max_colour_texture_samples]
+pub fn get_max_depth_texture_samples() -> u32 {
+    get_i32(gl::MAX_DEPTH_TEXTURE_SAMPLES) as u32
+}
+
+/// # Return the maximum number of samples of an integer format buffer
+/// see [get_max_colour_texture_samples]
+pub fn get_max_integer_samples() -> u32 {
+    get_i32(gl::MAX_INTEGER_SAMPLES) as u32
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(get_max_draw_buffers() >= 8);
+/// ```
+///
+/// # Description
+/// Returns a single value, the maximum number of simultaneous outputs that may be written in a
+/// fragment shader, which is the largest number of buffers that can be given to [draw_buffers].
+/// The value must be at least 8.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_draw_buffers] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_max_colour_attachments]
+pub fn get_max_draw_buffers() -> u32 {
+    get_i32(gl::MAX_DRAW_BUFFERS) as u32
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(get_max_dual_source_draw_buffers() >= 1);
+/// ```
+///
+/// # Description
+/// Returns one value, the maximum number of active draw buffers when using dual-source blending.
+/// The value must be at least 1. See [blend_func] and [blend_func_separate].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_dual_source_draw_buffers] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_max_draw_buffers]
+pub fn get_max_dual_source_draw_buffers() -> u32 {
+    get_i32(gl::MAX_DUAL_SOURCE_DRAW_BUFFERS) as u32
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(get_max_renderbuffer_size() >= 1024);
+/// ```
+///
+/// # Description
+/// Returns a single value, the largest width or height of a renderbuffer that the GL can handle.
+/// The value must be at least 1024. See [renderbuffer_storage].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_renderbuffer_size] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+pub fn get_max_renderbuffer_size() -> u32 {
+    get_i32(gl::MAX_RENDERBUFFER_SIZE) as u32
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(get_max_samples() >= 4);
+/// ```
+///
+/// # Description
+/// Returns a single value, the maximum number of samples supported for multisampling. The value
+/// must be at least 4. See [renderbuffer_storage_multisample] and [tex_image_2d_multisample].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_samples] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_framebuffer_samples]
+pub fn get_max_samples() -> u32 {
+    get_i32(gl::MAX_SAMPLES) as u32
+}
+
+/// `GL_MAX_TEXTURE_MAX_ANISOTROPY`, core in 4.6 so missing from the 4.5 bindings
+const MAX_TEXTURE_MAX_ANISOTROPY: GLenum = 0x84FF;
+
 /// # Return the value or values of a selected parameter
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
 ///
 /// # Example
 /// ```no_run
 /// # use rgl::prelude::*;
-/// active_texture(42);
-/// assert_eq!(get_active_texture(), 42);
+/// assert!(get_max_texture_max_anisotropy() >= 2.0);
 /// ```
 ///
 /// # Description
-/// Returns a single value indicating the active multitexture unit. The initial value is `0``. See
-/// [active_texture].
+/// Returns a single value, the maximum degree of anisotropy that can be set with
+/// [texture_target_max_anisotropy]. The value must be at least 2.0, or is 0.0 when anisotropic
+/// filtering is not supported.
 ///
 /// # Compatability
+/// * 4.6 - or `ARB_texture_filter_anisotropic` / `EXT_texture_filter_anisotropic`
 ///
-/// # Errors
+/// # Version Support
 ///
-/// # Associated Gets
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_texture_max_anisotropy] | N | N | N | N | N | N | N | N | N | N | N | N |
+///
+/// # See Also
+/// * [texture_parameter_max_anisotropy]
+pub fn get_max_texture_max_anisotropy() -> f32 {
+    get_f32(MAX_TEXTURE_MAX_ANISOTROPY)
+}
+
+/// # Return the texture size and unit limits
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(get_max_texture_size() >= 1024);
+/// assert!(get_max_array_texture_layers() >= 256);
+/// ```
+///
+/// # Description
+/// Each getter returns a single value, a rough estimate of the largest texture that the GL can
+/// handle or the number of texture units available:
+/// * [get_max_texture_size] - the largest width or height of a one- or two-dimensional texture.
+/// The value must be at least 1024. See [tex_image_1d] and [tex_image_2d].
+/// * [get_max_3d_texture_size] - the largest width, height or depth of a three-dimensional
+/// texture. The value must be at least 64. See [tex_image_3d].
+/// * [get_max_array_texture_layers] - the maximum number of layers allowed in an array texture.
+/// The value must be at least 256.
+/// * [get_max_cube_map_texture_size] - the largest width or height of a cube map texture face. The
+/// value must be at least 1024.
+/// * [get_max_rectangle_texture_size] - the largest width or height of a rectangle texture. The
+/// value must be at least 1024.
+/// * [get_max_texture_buffer_size] - the maximum number of texels allowed in the texel array of
+/// a texture buffer object. The value must be at least 65536.
+/// * [get_max_texture_image_units] - the maximum supported texture image units that can be used to
+/// access texture maps from the fragment shader. The value must be at least 16.
+/// * [get_max_texture_lod_bias] - the maximum, absolute value of the texture level-of-detail bias.
+/// The value must be at least 2.0.
 ///
 /// # Version Support
 ///
 /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
 /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-/// | [get_active_texture] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_texture_size] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_3d_texture_size] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_array_texture_layers] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_cube_map_texture_size] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_rectangle_texture_size] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_texture_buffer_size] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_texture_image_units] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_texture_lod_bias] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
 ///
 /// # See Also
-pub fn get_active_texture() -> u32 {
-    get_i32(gl::ACTIVE_TEXTURE) as u32
+/// * [get_max_combined_texture_image_units]
+/// * [get_max_renderbuffer_size]
+pub fn get_max_texture_size() -> u32 {
+    get_i32(gl::MAX_TEXTURE_SIZE) as u32
+}
+
+/// # Return the maximum size of a three-dimensional texture
+/// see [get_max_texture_size]
+pub fn get_max_3d_texture_size() -> u32 {
+    get_i32(gl::MAX_3D_TEXTURE_SIZE) as u32
+}
+
+/// # Return the maximum number of array texture layers
+/// see [get_max_texture_size]
+pub fn get_max_array_texture_layers() -> u32 {
+    get_i32(gl::MAX_ARRAY_TEXTURE_LAYERS) as u32
+}
+
+/// # Return the maximum size of a cube map texture face
+/// see [get_max_texture_size]
+pub fn get_max_cube_map_texture_size() -> u32 {
+    get_i32(gl::MAX_CUBE_MAP_TEXTURE_SIZE) as u32
+}
+
+/// # Return the maximum size of a rectangle texture
+/// see [get_max_texture_size]
+pub fn get_max_rectangle_texture_size() -> u32 {
+    get_i32(gl::MAX_RECTANGLE_TEXTURE_SIZE) as u32
+}
+
+/// # Return the maximum number of texels of a texture buffer
+/// see [get_max_texture_size]
+pub fn get_max_texture_buffer_size() -> u32 {
+    get_i32(gl::MAX_TEXTURE_BUFFER_SIZE) as u32
+}
+
+/// # Return the maximum number of fragment shader texture image units
+/// see [get_max_texture_size]
+pub fn get_max_texture_image_units() -> u32 {
+    get_i32(gl::MAX_TEXTURE_IMAGE_UNITS) as u32
+}
+
+/// # Return the maximum absolute texture level-of-detail bias
+/// see [get_max_texture_size]
+pub fn get_max_texture_lod_bias() -> f32 {
+    get_f32(gl::MAX_TEXTURE_LOD_BIAS)
+}
+
+/// # Return the vertex attribute and element limits
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(get_max_vertex_attribs() >= 16);
+/// ```
+///
+/// # Description
+/// Each getter returns a single value:
+/// * [get_max_vertex_attribs] - the maximum number of 4-component generic vertex attributes
+/// accessible to a vertex shader. The value must be at least 16. See [vertex_attrib_pointer].
+/// * [get_max_vertex_attrib_bindings] - the maximum number of vertex buffers that may be bound.
+/// The value must be at least 16.
+/// * [get_max_vertex_attrib_relative_offset] - the maximum offset that may be added to a vertex
+/// binding offset. The value must be at least 2047.
+/// * [get_max_elements_indices] - the recommended maximum number of vertex array indices.
+/// * [get_max_elements_vertices] - the recommended maximum number of vertex array vertices.
+/// * [get_max_element_index] - the maximum index that may be specified during the transfer of
+/// generic vertex attributes to the GL. The value must be at least 2<sup>24</sup> − 1.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_vertex_attribs] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_vertex_attrib_bindings] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+/// | [get_max_vertex_attrib_relative_offset] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+/// | [get_max_elements_indices] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_elements_vertices] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_max_element_index] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [draw_elements]
+/// * [vertex_attrib_pointer]
+pub fn get_max_vertex_attribs() -> u32 {
+    get_i32(gl::MAX_VERTEX_ATTRIBS) as u32
+}
+
+/// # Return the maximum number of vertex buffer binding points
+/// see [get_max_vertex_attribs]
+pub fn get_max_vertex_attrib_bindings() -> u32 {
+    get_i32(gl::MAX_VERTEX_ATTRIB_BINDINGS) as u32
+}
+
+/// # Return the maximum relative offset of a vertex attribute
+/// see [get_max_vertex_attribs]
+pub fn get_max_vertex_attrib_relative_offset() -> u32 {
+    get_i32(gl::MAX_VERTEX_ATTRIB_RELATIVE_OFFSET) as u32
+}
+
+/// # Return the recommended maximum number of vertex array indices
+/// see [get_max_vertex_attribs]
+pub fn get_max_elements_indices() -> u32 {
+    get_i32(gl::MAX_ELEMENTS_INDICES) as u32
+}
+
+/// # Return the recommended maximum number of vertex array vertices
+/// see [get_max_vertex_attribs]
+pub fn get_max_elements_vertices() -> u32 {
+    get_i32(gl::MAX_ELEMENTS_VERTICES) as u32
+}
+
+/// # Return the maximum vertex array index
+/// see [get_max_vertex_attribs]
+pub fn get_max_element_index() -> u64 {
+    get_i64(gl::MAX_ELEMENT_INDEX) as u64
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_primitive_restart_index(), 0);
+/// ```
+///
+/// # Description
+/// Returns one value, the current primitive restart index. The initial value is 0. The index is
+/// only used when [Capability::PrimitiveRestart] is enabled.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_primitive_restart_index] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [draw_elements]
+pub fn get_primitive_restart_index() -> u32 {
+    get_i32(gl::PRIMITIVE_RESTART_INDEX) as u32
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// bind_renderbuffer(Renderbuffer(42));
+/// assert_eq!(get_renderbuffer_binding(), Renderbuffer(42));
+/// ```
+///
+/// # Description
+/// Returns the renderbuffer object currently bound to the renderbuffer target, or
+/// `Renderbuffer(0)` when no renderbuffer object is bound to it. See [bind_renderbuffer].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_renderbuffer_binding] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_draw_framebuffer_binding]
+pub fn get_renderbuffer_binding() -> Renderbuffer {
+    Renderbuffer(get_i32(gl::RENDERBUFFER_BINDING) as u32)
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// active_texture(2);
+/// bind_sampler(2, Sampler(42));
+/// assert_eq!(get_sampler_binding(), Sampler(42));
+/// ```
+///
+/// # Description
+/// Returns the sampler object currently bound to the active texture unit, or `Sampler(0)` when no
+/// sampler is bound to it. See [active_texture] and [bind_sampler].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_sampler_binding] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+pub fn get_sampler_binding() -> Sampler {
+    Sampler(get_i32(gl::SAMPLER_BINDING) as u32)
+}
+
+/// # Return the stencil test state
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// stencil_func(CompareFunc::Equal, 1, 0xff);
+/// stencil_op(StencilOp::Keep, StencilOp::Keep, StencilOp::Replace);
+/// assert_eq!(get_stencil_func(), Some(CompareFunc::Equal));
+/// assert_eq!(get_stencil_ref(), 1);
+/// assert_eq!(get_stencil_pass_depth_pass(), Some(StencilOp::Replace));
+/// ```
+///
+/// # Description
+/// Each getter returns a single value of the stencil state for front-facing polygons and
+/// non-polygons, while the `get_stencil_back_*` getters return the same value for back-facing
+/// polygons:
+/// * [get_stencil_func] - the function used to compare the stencil reference value with the
+/// stencil buffer value. The initial value is [CompareFunc::Always]. See [stencil_func].
+/// * [get_stencil_ref] - the reference value that is compared with the contents of the stencil
+/// buffer. The initial value is 0. See [stencil_func].
+/// * [get_stencil_value_mask] - the mask that is used to mask both the stencil reference value
+/// and the stencil buffer value before they are compared. The initial value is all 1's. See
+/// [stencil_func].
+/// * [get_stencil_writemask] - the mask that controls writing of the stencil bitplanes. The
+/// initial value is all 1's. See [stencil_mask].
+/// * [get_stencil_fail] - the action that is taken when the stencil test fails. The initial value
+/// is [StencilOp::Keep]. See [stencil_op].
+/// * [get_stencil_pass_depth_fail] - the action that is taken when the stencil test passes, but
+/// the depth test fails. The initial value is [StencilOp::Keep]. See [stencil_op].
+/// * [get_stencil_pass_depth_pass] - the action that is taken when the stencil test passes and the
+/// depth test passes. The initial value is [StencilOp::Keep]. See [stencil_op].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_stencil_func] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_ref] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_value_mask] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_writemask] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_fail] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_pass_depth_fail] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_pass_depth_pass] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_back_func] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_back_ref] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_back_value_mask] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_back_writemask] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_back_fail] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_back_pass_depth_fail] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_stencil_back_pass_depth_pass] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_stencil_clear_value]
+/// * [stencil_func_separate]
+/// * [stencil_mask_separate]
+/// * [stencil_op_separate]
+pub fn get_stencil_func() -> Option<CompareFunc> {
+    CompareFunc::try_from(get_i32(gl::STENCIL_FUNC) as GLenum).ok()
+}
+
+/// # Return the stencil reference value
+/// see [get_stencil_func]
+pub fn get_stencil_ref() -> i32 {
+    get_i32(gl::STENCIL_REF)
+}
+
+/// # Return the stencil value mask
+/// see [get_stencil_func]
+pub fn get_stencil_value_mask() -> u32 {
+    get_i32(gl::STENCIL_VALUE_MASK) as u32
+}
+
+/// # Return the stencil write mask
+/// see [get_stencil_func]
+pub fn get_stencil_writemask() -> u32 {
+    get_i32(gl::STENCIL_WRITEMASK) as u32
+}
+
+/// # Return the stencil test failure action
+/// see [get_stencil_func]
+pub fn get_stencil_fail() -> Option<StencilOp> {
+    StencilOp::try_from(get_i32(gl::STENCIL_FAIL) as GLenum).ok()
+}
+
+/// # Return the stencil test pass and depth test failure action
+/// see [get_stencil_func]
+pub fn get_stencil_pass_depth_fail() -> Option<StencilOp> {
+    StencilOp::try_from(get_i32(gl::STENCIL_PASS_DEPTH_FAIL) as GLenum).ok()
+}
+
+/// # Return the stencil test pass and depth test pass action
+/// see [get_stencil_func]
+pub fn get_stencil_pass_depth_pass() -> Option<StencilOp> {
+    StencilOp::try_from(get_i32(gl::STENCIL_PASS_DEPTH_PASS) as GLenum).ok()
+}
+
+/// # Return the back-facing stencil function
+/// see [get_stencil_func]
+pub fn get_stencil_back_func() -> Option<CompareFunc> {
+    CompareFunc::try_from(get_i32(gl::STENCIL_BACK_FUNC) as GLenum).ok()
+}
+
+/// # Return the back-facing stencil reference value
+/// see [get_stencil_func]
+pub fn get_stencil_back_ref() -> i32 {
+    get_i32(gl::STENCIL_BACK_REF)
+}
+
+/// # Return the back-facing stencil value mask
+/// see [get_stencil_func]
+pub fn get_stencil_back_value_mask() -> u32 {
+    get_i32(gl::STENCIL_BACK_VALUE_MASK) as u32
+}
+
+/// # Return the back-facing stencil write mask
+/// see [get_stencil_func]
+pub fn get_stencil_back_writemask() -> u32 {
+    get_i32(gl::STENCIL_BACK_WRITEMASK) as u32
+}
+
+/// # Return the back-facing stencil test failure action
+/// see [get_stencil_func]
+pub fn get_stencil_back_fail() -> Option<StencilOp> {
+    StencilOp::try_from(get_i32(gl::STENCIL_BACK_FAIL) as GLenum).ok()
+}
+
+/// # Return the back-facing stencil test pass and depth test failure action
+/// see [get_stencil_func]
+pub fn get_stencil_back_pass_depth_fail() -> Option<StencilOp> {
+    StencilOp::try_from(get_i32(gl::STENCIL_BACK_PASS_DEPTH_FAIL) as GLenum).ok()
+}
+
+/// # Return the back-facing stencil test pass and depth test pass action
+/// see [get_stencil_func]
+pub fn get_stencil_back_pass_depth_pass() -> Option<StencilOp> {
+    StencilOp::try_from(get_i32(gl::STENCIL_BACK_PASS_DEPTH_PASS) as GLenum).ok()
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// clear_stencil(1);
+/// assert_eq!(get_stencil_clear_value(), 1);
+/// ```
+///
+/// # Description
+/// Returns one value, the index to which the stencil bitplanes are cleared. The initial value is
+/// 0. See [clear_stencil].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_stencil_clear_value] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_colour_clear_value]
+/// * [get_depth_clear_value]
+pub fn get_stencil_clear_value() -> i32 {
+    get_i32(gl::STENCIL_CLEAR_VALUE)
+}
+
+/// # Return the texture objects bound to the active texture unit
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// active_texture(3);
+/// bind_texture(TextureBindingTarget::Image2D, Texture(42));
+/// assert_eq!(get_texture_binding_2d(), Texture(42));
+/// ```
+///
+/// # Description
+/// Each getter returns the texture object currently bound to one of the texture targets of the
+/// active texture unit, or `Texture(0)` when no texture object is bound to it. See
+/// [active_texture] and [bind_texture].
+///
+/// | Getter | Texture Target |
+/// |--------|----------------|
+/// | [get_texture_binding_1d] | [TextureBindingTarget::Image1D] |
+/// | [get_texture_binding_1d_array] | [TextureBindingTarget::Array1D] |
+/// | [get_texture_binding_2d] | [TextureBindingTarget::Image2D] |
+/// | [get_texture_binding_2d_array] | [TextureBindingTarget::Array2D] |
+/// | [get_texture_binding_multisample_2d] | [TextureBindingTarget::Multisample2D] |
+/// | [get_texture_binding_multisample_2d_array] | [TextureBindingTarget::Multisample2DArray] |
+/// | [get_texture_binding_3d] | [TextureBindingTarget::Image3D] |
+/// | [get_texture_binding_buffer] | [TextureBindingTarget::Buffer] |
+/// | [get_texture_binding_cube_map] | [TextureBindingTarget::CubeMap] |
+/// | [get_texture_binding_cube_map_array] | [TextureBindingTarget::CubeMapArray] |
+/// | [get_texture_binding_rectangle] | [TextureBindingTarget::Rectangle] |
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_texture_binding_1d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_texture_binding_1d_array] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_texture_binding_2d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_texture_binding_2d_array] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_texture_binding_multisample_2d] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_texture_binding_multisample_2d_array] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_texture_binding_3d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_texture_binding_buffer] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_texture_binding_cube_map] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_texture_binding_cube_map_array] | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+/// | [get_texture_binding_rectangle] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_texture]
+/// * [get_active_texture]
+pub fn get_texture_binding_1d() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_1D) as u32)
+}
+
+/// # Return the texture object bound to the one-dimensional array texture target
+/// see [get_texture_binding_1d]
+pub fn get_texture_binding_1d_array() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_1D_ARRAY) as u32)
+}
+
+/// # Return the texture object bound to the two-dimensional texture target
+/// see [get_texture_binding_1d]
+pub fn get_texture_binding_2d() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_2D) as u32)
+}
+
+/// # Return the texture object bound to the two-dimensional array texture target
+/// see [get_texture_binding_1d]
+pub fn get_texture_binding_2d_array() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_2D_ARRAY) as u32)
+}
+
+/// # Return the texture object bound to the two-dimensional multisample texture target
+/// see [get_texture_binding_1d]
+pub fn get_texture_binding_multisample_2d() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_2D_MULTISAMPLE) as u32)
+}
+
+/// # Return the texture object bound to the two-dimensional multisample array texture target
+/// see [get_texture_binding_1d]
+pub fn get_texture_binding_multisample_2d_array() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY) as u32)
+}
+
+/// # Return the texture object bound to the three-dimensional texture target
+/// see [get_texture_binding_1d]
+pub fn get_texture_binding_3d() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_3D) as u32)
+}
+
+/// # Return the texture object bound to the buffer texture target
+/// see [get_texture_binding_1d]
+pub fn get_texture_binding_buffer() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_BUFFER) as u32)
+}
+
+/// # Return the texture object bound to the cube map texture target
+/// see [get_texture_binding_1d]
+pub fn get_texture_binding_cube_map() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_CUBE_MAP) as u32)
+}
+
+/// # Return the texture object bound to the cube map array texture target
+/// see [get_texture_binding_1d]
+pub fn get_texture_binding_cube_map_array() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_CUBE_MAP_ARRAY) as u32)
+}
+
+/// # Return the texture object bound to the rectangle texture target
+/// see [get_texture_binding_1d]
+pub fn get_texture_binding_rectangle() -> Texture {
+    Texture(get_i32(gl::TEXTURE_BINDING_RECTANGLE) as u32)
+}
+
+/// # Return the value or values of a selected parameter
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// bind_vertex_array(VertexArray(42));
+/// assert_eq!(get_vertex_array_binding(), VertexArray(42));
+/// ```
+///
+/// # Description
+/// Returns the vertex array object currently bound, or `VertexArray(0)` when no vertex array
+/// object is bound. See [bind_vertex_array].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_vertex_array_binding] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_element_array_buffer_binding]
+pub fn get_vertex_array_binding() -> VertexArray {
+    VertexArray(get_i32(gl::VERTEX_ARRAY_BINDING) as u32)
 }
 
 /// # OpenGL Error Values
@@ -121,6 +1608,26 @@ impl From<GLenum> for Error {
     }
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoError => write!(f, "GL_NO_ERROR"),
+            Error::InvalidEnum => write!(f, "GL_INVALID_ENUM"),
+            Error::InvalidValue => write!(f, "GL_INVALID_VALUE"),
+            Error::InvalidOperation => write!(f, "GL_INVALID_OPERATION"),
+            Error::InvalidFramebufferOperation => write!(f, "GL_INVALID_FRAMEBUFFER_OPERATION"),
+            Error::OutOfMemory => write!(f, "GL_OUT_OF_MEMORY"),
+            Error::StackUnderflow => write!(f, "GL_STACK_UNDERFLOW"),
+            Error::StackOverflow => write!(f, "GL_STACK_OVERFLOW"),
+            Error::ImplementationSpecific(code) => {
+                write!(f, "implementation specific error {code:#06X}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
 /// # Return error information
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetError.xhtml>
 ///
@@ -0,0 +1,296 @@
+//! # Headless Context
+//! <https://docs.mesa3d.org/egl.html>
+//!
+//! # Description
+//! Off-screen OpenGL contexts for testing, enabled with the `headless` cargo feature. A context is
+//! created on the [device](https://registry.khronos.org/EGL/extensions/EXT/EGL_EXT_platform_device.txt)
+//! EGL platform, using the software device Mesa exposes for its `llvmpipe` driver, so neither a
+//! GPU nor a display is needed, and the driver is the same on every machine. `libEGL.so.1` is
+//! loaded at runtime.
+//!
+//! Each [HeadlessContext] is current on the thread that created it and renders into its own
+//! framebuffer with a colour and a depth attachment, so every test can own a fresh context:
+//! ```no_run
+//! use rgl::headless::HeadlessContext;
+//! use rgl::prelude::*;
+//!
+//! let context = HeadlessContext::new(64, 64).unwrap();
+//! clear_colour(1.0, 0.0, 0.0, 1.0);
+//! clear(ClearMask::COLOUR);
+//! assert_eq!(context.read_colour()[0], [255, 0, 0, 255]);
+//! ```
+
+use crate::prelude::*;
+use khronos_egl as egl;
+
+type EglInstance = egl::DynamicInstance<egl::EGL1_5>;
+
+/// `EGL_PLATFORM_DEVICE_EXT`
+const PLATFORM_DEVICE_EXT: egl::Enum = 0x313F;
+
+/// Device extension of Mesa's software rasterizers
+const MESA_DEVICE_SOFTWARE: &str = "EGL_MESA_device_software";
+
+/// `eglQueryDevicesEXT`
+type QueryDevices =
+    unsafe extern "system" fn(egl::Int, *mut egl::NativeDisplayType, *mut egl::Int) -> egl::Boolean;
+
+/// `eglQueryDeviceStringEXT`
+type QueryDeviceString =
+    unsafe extern "system" fn(egl::NativeDisplayType, egl::Int) -> *const std::os::raw::c_char;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeadlessError {
+    /// `libEGL.so.1` could not be loaded, or does not support EGL 1.5
+    LibraryLoad(String),
+
+    /// An EGL call failed
+    Egl(egl::Error),
+
+    /// Mesa's software device is not listed by `EGL_EXT_device_enumeration`
+    NoSoftwareDevice,
+
+    /// No EGL config supports desktop OpenGL
+    NoConfig,
+
+    /// The off-screen framebuffer could not be completed
+    IncompleteFramebuffer(Option<FramebufferStatus>),
+}
+
+impl From<egl::Error> for HeadlessError {
+    fn from(value: egl::Error) -> Self {
+        HeadlessError::Egl(value)
+    }
+}
+
+fn instance() -> Result<&'static EglInstance, HeadlessError> {
+    static INSTANCE: std::sync::OnceLock<Result<EglInstance, String>> = std::sync::OnceLock::new();
+    INSTANCE
+        .get_or_init(|| {
+            // SAFE: libEGL has no initialisation routines with preconditions
+            unsafe { EglInstance::load_required() }.map_err(|err| err.to_string())
+        })
+        .as_ref()
+        .map_err(|err| HeadlessError::LibraryLoad(err.clone()))
+}
+
+/// Find the software device through `EGL_EXT_device_enumeration` and `EGL_EXT_device_query`,
+/// rather than the `LIBGL_ALWAYS_SOFTWARE` environment variable, which would have to be set before
+/// any test thread starts
+fn software_device(egl: &EglInstance) -> Result<egl::NativeDisplayType, HeadlessError> {
+    let (Some(query_devices), Some(query_device_string)) = (
+        egl.get_proc_address("eglQueryDevicesEXT"),
+        egl.get_proc_address("eglQueryDeviceStringEXT"),
+    ) else {
+        return Err(HeadlessError::NoSoftwareDevice);
+    };
+    // SAFE: both are the entry points of their extensions, which have these signatures
+    let query_devices: QueryDevices = unsafe { std::mem::transmute(query_devices) };
+    let query_device_string: QueryDeviceString =
+        unsafe { std::mem::transmute(query_device_string) };
+
+    let mut count = 0;
+    // SAFE: without a device array, only `count` is written
+    if unsafe { query_devices(0, std::ptr::null_mut(), &mut count) } != egl::TRUE {
+        return Err(HeadlessError::NoSoftwareDevice);
+    }
+    let mut devices = vec![std::ptr::null_mut(); count as usize];
+    // SAFE: at most `count` devices are written into `devices`, which holds `count` elements
+    if unsafe { query_devices(count, devices.as_mut_ptr(), &mut count) } != egl::TRUE {
+        return Err(HeadlessError::NoSoftwareDevice);
+    }
+    devices.truncate(count as usize);
+
+    devices
+        .into_iter()
+        .find(|&device| {
+            // SAFE: the device was returned above, and the string is static and nul terminated
+            let extensions = unsafe { query_device_string(device, egl::EXTENSIONS) };
+            !extensions.is_null()
+                && unsafe { std::ffi::CStr::from_ptr(extensions) }
+                    .to_string_lossy()
+                    .split_whitespace()
+                    .any(|extension| extension == MESA_DEVICE_SOFTWARE)
+        })
+        .ok_or(HeadlessError::NoSoftwareDevice)
+}
+
+fn display(egl: &EglInstance) -> Result<egl::Display, HeadlessError> {
+    static DISPLAY: std::sync::OnceLock<Result<usize, HeadlessError>> = std::sync::OnceLock::new();
+    let display = DISPLAY.get_or_init(|| {
+        let device = software_device(egl)?;
+        // SAFE: the device platform takes a device returned by `eglQueryDevicesEXT`
+        let display =
+            unsafe { egl.get_platform_display(PLATFORM_DEVICE_EXT, device, &[egl::ATTRIB_NONE]) }?;
+        egl.initialize(display)?;
+
+        crate::load_with(|name| match egl.get_proc_address(name) {
+            Some(f) => f as *const std::os::raw::c_void,
+            None => std::ptr::null(),
+        });
+        Ok(display.as_ptr() as usize)
+    });
+
+    // SAFE: the display was created above, and is never terminated
+    display
+        .clone()
+        .map(|display| unsafe { egl::Display::from_ptr(display as egl::EGLDisplay) })
+}
+
+/// # Off-screen OpenGL context
+/// A core profile OpenGL 4.5 context, current on the creating thread, with a bound
+/// [FramebufferBindingTarget::ReadDraw] framebuffer of an RGBA8 colour texture and a
+/// depth/stencil renderbuffer. The viewport is set to the framebuffer size.
+///
+/// Dropping the context deletes the framebuffer objects and releases the context from the thread.
+pub struct HeadlessContext {
+    egl: &'static EglInstance,
+    display: egl::Display,
+    context: egl::Context,
+    framebuffer: Framebuffer,
+    colour: Texture,
+    depth_stencil: Renderbuffer,
+    width: u32,
+    height: u32,
+}
+
+impl HeadlessContext {
+    /// Create a fresh context and framebuffer of `width` × `height` pixels, and make it current on
+    /// this thread
+    pub fn new(width: u32, height: u32) -> Result<HeadlessContext, HeadlessError> {
+        let egl = instance()?;
+        let display = display(egl)?;
+
+        let config_attributes = [
+            egl::RENDERABLE_TYPE,
+            egl::OPENGL_BIT,
+            egl::SURFACE_TYPE,
+            egl::PBUFFER_BIT,
+            egl::NONE,
+        ];
+        let config = egl
+            .choose_first_config(display, &config_attributes)?
+            .ok_or(HeadlessError::NoConfig)?;
+
+        egl.bind_api(egl::OPENGL_API)?;
+        let context_attributes = [
+            egl::CONTEXT_MAJOR_VERSION,
+            4,
+            egl::CONTEXT_MINOR_VERSION,
+            5,
+            egl::CONTEXT_OPENGL_PROFILE_MASK,
+            egl::CONTEXT_OPENGL_CORE_PROFILE_BIT,
+            egl::NONE,
+        ];
+        let context = egl.create_context(display, config, None, &context_attributes)?;
+        egl.make_current(display, None, None, Some(context))?;
+
+        let mut context = HeadlessContext {
+            egl,
+            display,
+            context,
+            framebuffer: Framebuffer::default(),
+            colour: Texture::default(),
+            depth_stencil: Renderbuffer::default(),
+            width,
+            height,
+        };
+
+        gen_framebuffers(std::slice::from_mut(&mut context.framebuffer));
+        bind_framebuffer(FramebufferBindingTarget::ReadDraw, context.framebuffer);
+
+        gen_textures(std::slice::from_mut(&mut context.colour));
+        bind_texture(TextureBindingTarget::Image2D, context.colour);
+        tex_image_2d::<u8>(
+            TextureBinding2DTarget::Image2D,
+            0,
+            TextureInternalFormat::RGBA8,
+            width,
+            height,
+            TextureFormat::RGBA,
+            TexturePixelType::U8,
+            TextureData::Reserve,
+        );
+        bind_texture(TextureBindingTarget::Image2D, Texture::default());
+        framebuffer_texture_2d(
+            FramebufferBindingTarget::ReadDraw,
+            FramebufferAttachment::Colour(0),
+            TextureBinding2DTarget::Image2D,
+            context.colour,
+            0,
+        );
+
+        gen_renderbuffers(std::slice::from_mut(&mut context.depth_stencil));
+        bind_renderbuffer(context.depth_stencil);
+        renderbuffer_storage(RenderbufferInternalFormat::Depth24Stencil8, width, height);
+        bind_renderbuffer(Renderbuffer::default());
+        framebuffer_renderbuffer(
+            FramebufferBindingTarget::ReadDraw,
+            FramebufferAttachment::DepthStencil,
+            context.depth_stencil,
+        );
+
+        match check_framebuffer_status(FramebufferBindingTarget::ReadDraw) {
+            Some(FramebufferStatus::Complete) => {}
+            status => return Err(HeadlessError::IncompleteFramebuffer(status)),
+        }
+
+        viewport(Rect {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        });
+        Ok(context)
+    }
+
+    /// The off-screen framebuffer, bound on creation
+    pub fn framebuffer(&self) -> Framebuffer {
+        self.framebuffer
+    }
+
+    /// The RGBA8 texture attached as [FramebufferAttachment::Colour] `0`
+    pub fn colour_texture(&self) -> Texture {
+        self.colour
+    }
+
+    /// The renderbuffer attached as [FramebufferAttachment::DepthStencil]
+    pub fn depth_stencil_renderbuffer(&self) -> Renderbuffer {
+        self.depth_stencil
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Read back every pixel of the colour attachment, bottom row first
+    pub fn read_colour(&self) -> Vec<[u8; 4]> {
+        let mut pixels = vec![[0; 4]; (self.width * self.height) as usize];
+        bind_framebuffer(FramebufferBindingTarget::Read, self.framebuffer);
+        read_buffer(ColourBuffer::Attachment(0));
+        read_pixels(
+            0,
+            0,
+            self.width,
+            self.height,
+            TextureFormat::RGBA,
+            TexturePixelType::U8,
+            &mut pixels,
+        );
+        pixels
+    }
+}
+
+impl Drop for HeadlessContext {
+    fn drop(&mut self) {
+        delete_framebuffers(&[self.framebuffer]);
+        delete_textures(&[self.colour]);
+        delete_renderbuffers(&[self.depth_stencil]);
+        let _ = self.egl.make_current(self.display, None, None, None);
+        let _ = self.egl.destroy_context(self.display, self.context);
+    }
+}
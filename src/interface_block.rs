@@ -0,0 +1,695 @@
+//! # Interface Block Layout
+//! <https://www.khronos.org/opengl/wiki/Interface_Block_(GLSL)#Memory_layout>
+//!
+//! # Description
+//! Rust-side std140 and std430 layouts for the contents of uniform and shader storage blocks.
+//!
+//! [BlockMember] computes the alignment, size and offsets of a type within a block, and writes it
+//! to the bytes of a buffer in that layout. It is implemented for the GLSL scalars, vectors and
+//! matrices (`f32` is `float`, `[u32; 3]` is `uvec3`, `[[f32; 4]; 3]` is `mat3x4`), for arrays
+//! through [BlockArray], and for structs declared with [block_struct]:
+//! ```no_run
+//! use rgl::prelude::*;
+//!
+//! block_struct! {
+//!     /// layout(std140, binding = 2) uniform Material { vec3 colour; float shininess; };
+//!     #[repr(C)]
+//!     pub struct Material {
+//!         pub colour: [f32; 3],
+//!         pub shininess: f32,
+//!     }
+//! }
+//!
+//! let material = Material { colour: [1.0, 0.5, 0.0], shininess: 32.0 };
+//! let bytes = material.to_bytes(BlockLayout::Std140);
+//! let (frequency, nature) = (BufferUsageFrequency::Static, BufferUsageNature::Draw);
+//! buffer_data(BufferBindingTarget::Uniform, bytes.as_slice(), frequency, nature);
+//! ```
+//!
+//! The declaration of a block in GLSL and in Rust can drift apart, so [verify_block_layout]
+//! compares the Rust offsets of each member to the offsets the driver reports for the block.
+
+use crate::prelude::*;
+
+/// # Memory layout of an interface block
+/// see [BlockMember]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BlockLayout {
+    /// `layout(std140)`, supported by uniform and shader storage blocks, where the alignment of
+    /// arrays and structs is rounded up to that of a `vec4`
+    Std140,
+
+    /// `layout(std430)`, only supported by shader storage blocks, where arrays and structs are
+    /// aligned to their largest member
+    Std430,
+}
+
+impl BlockLayout {
+    /// The base alignment of an array with elements, or a struct with members, that are aligned
+    /// to at most `alignment`
+    pub fn aggregate_alignment(self, alignment: usize) -> usize {
+        match self {
+            BlockLayout::Std140 => align_to(alignment, 16),
+            BlockLayout::Std430 => alignment,
+        }
+    }
+}
+
+/// Round `offset` up to the next multiple of `alignment`
+pub const fn align_to(offset: usize, alignment: usize) -> usize {
+    offset.div_ceil(alignment) * alignment
+}
+
+/// # Layout of a basic member of an interface block
+/// see [BlockMember::layout]
+///
+/// Members are named the way the driver names the active variables of a block, so a struct
+/// member is `"light.colour"` and an array of basic types is `"weights[0]"`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockMemberLayout {
+    pub name: String,
+    pub ty: GlslType,
+    pub offset: usize,
+
+    /// The stride between elements of an array, or 0 when the member is not an array
+    pub array_stride: usize,
+
+    /// The stride between columns of a matrix, or 0 when the member is not a matrix
+    pub matrix_stride: usize,
+}
+
+/// # A type that can be a member of an interface block
+/// see [BlockLayout]
+///
+/// Implemented for scalars, vectors and matrices, for arrays through [BlockArray], and for structs
+/// declared with [block_struct].
+pub trait BlockMember {
+    /// Whether this is a scalar, vector or matrix
+    const BASIC: bool = false;
+
+    /// The base alignment of this type in `layout`
+    fn alignment(layout: BlockLayout) -> usize;
+
+    /// The size of this type in `layout`, including any padding at the end
+    fn size(layout: BlockLayout) -> usize;
+
+    /// Write this value in `layout` to the start of `bytes`, which is at least [Self::size] long,
+    /// without touching any padding
+    fn write(&self, layout: BlockLayout, bytes: &mut [u8]);
+
+    /// Append the layout of each basic member of this type, named `name` and placed at `offset`
+    fn members(
+        layout: BlockLayout,
+        name: &str,
+        offset: usize,
+        members: &mut Vec<BlockMemberLayout>,
+    );
+
+    /// The layout of each basic member of this type, when used as the contents of a block
+    fn layout(layout: BlockLayout) -> Vec<BlockMemberLayout> {
+        let mut members = vec![];
+        Self::members(layout, "", 0, &mut members);
+        members
+    }
+
+    /// Serialize this value in `layout`, with zeroed padding, ready for [buffer_data]
+    fn to_bytes(&self, layout: BlockLayout) -> Vec<u8> {
+        let mut bytes = vec![0; Self::size(layout)];
+        self.write(layout, &mut bytes);
+        bytes
+    }
+}
+
+macro_rules! scalar_members {
+    ($($ty:ty => $scalar:ident, $size:literal, |$value:ident| $bytes:expr;)*) => {
+        $(
+            impl BlockMember for $ty {
+                const BASIC: bool = true;
+
+                fn alignment(_layout: BlockLayout) -> usize {
+                    $size
+                }
+
+                fn size(_layout: BlockLayout) -> usize {
+                    $size
+                }
+
+                fn write(&self, _layout: BlockLayout, bytes: &mut [u8]) {
+                    let $value = *self;
+                    bytes[..$size].copy_from_slice(&$bytes);
+                }
+
+                fn members(
+                    _layout: BlockLayout,
+                    name: &str,
+                    offset: usize,
+                    members: &mut Vec<BlockMemberLayout>,
+                ) {
+                    members.push(BlockMemberLayout {
+                        name: name.to_string(),
+                        ty: GlslType::Scalar(GlslScalarType::$scalar),
+                        offset,
+                        array_stride: 0,
+                        matrix_stride: 0,
+                    });
+                }
+            }
+
+            vector_members!($ty => $scalar, 2 components aligned to 2);
+            vector_members!($ty => $scalar, 3 components aligned to 4);
+            vector_members!($ty => $scalar, 4 components aligned to 4);
+        )*
+    };
+}
+
+macro_rules! vector_members {
+    ($ty:ty => $scalar:ident, $n:literal components aligned to $align:literal) => {
+        impl BlockMember for [$ty; $n] {
+            const BASIC: bool = true;
+
+            fn alignment(layout: BlockLayout) -> usize {
+                $align * <$ty>::size(layout)
+            }
+
+            fn size(layout: BlockLayout) -> usize {
+                $n * <$ty>::size(layout)
+            }
+
+            fn write(&self, layout: BlockLayout, bytes: &mut [u8]) {
+                let size = <$ty>::size(layout);
+                for (component, bytes) in self.iter().zip(bytes.chunks_mut(size)) {
+                    component.write(layout, bytes);
+                }
+            }
+
+            fn members(
+                _layout: BlockLayout,
+                name: &str,
+                offset: usize,
+                members: &mut Vec<BlockMemberLayout>,
+            ) {
+                members.push(BlockMemberLayout {
+                    name: name.to_string(),
+                    ty: GlslType::Vector(GlslScalarType::$scalar, $n),
+                    offset,
+                    array_stride: 0,
+                    matrix_stride: 0,
+                });
+            }
+        }
+    };
+}
+
+scalar_members! {
+    f32 => Float, 4, |value| value.to_ne_bytes();
+    f64 => Double, 8, |value| value.to_ne_bytes();
+    i32 => Int, 4, |value| value.to_ne_bytes();
+    u32 => UnsignedInt, 4, |value| value.to_ne_bytes();
+    bool => Bool, 4, |value| (value as u32).to_ne_bytes();
+}
+
+/// Column-major matrices are laid out as an array of column vectors
+macro_rules! matrix_members {
+    ($($ty:ty => $scalar:ident;)*) => {
+        $(
+            matrix_members!(@columns $ty => $scalar, 2);
+            matrix_members!(@columns $ty => $scalar, 3);
+            matrix_members!(@columns $ty => $scalar, 4);
+        )*
+    };
+    (@columns $ty:ty => $scalar:ident, $columns:literal) => {
+        matrix_members!(@matrix $ty => $scalar, $columns, 2);
+        matrix_members!(@matrix $ty => $scalar, $columns, 3);
+        matrix_members!(@matrix $ty => $scalar, $columns, 4);
+    };
+    (@matrix $ty:ty => $scalar:ident, $columns:literal, $rows:literal) => {
+        impl BlockMember for [[$ty; $rows]; $columns] {
+            const BASIC: bool = true;
+
+            fn alignment(layout: BlockLayout) -> usize {
+                layout.aggregate_alignment(<[$ty; $rows]>::alignment(layout))
+            }
+
+            fn size(layout: BlockLayout) -> usize {
+                $columns * Self::alignment(layout)
+            }
+
+            fn write(&self, layout: BlockLayout, bytes: &mut [u8]) {
+                let stride = Self::alignment(layout);
+                for (column, bytes) in self.iter().zip(bytes.chunks_mut(stride)) {
+                    column.write(layout, bytes);
+                }
+            }
+
+            fn members(
+                layout: BlockLayout,
+                name: &str,
+                offset: usize,
+                members: &mut Vec<BlockMemberLayout>,
+            ) {
+                members.push(BlockMemberLayout {
+                    name: name.to_string(),
+                    ty: GlslType::Matrix {
+                        ty: GlslScalarType::$scalar,
+                        columns: $columns,
+                        rows: $rows,
+                    },
+                    offset,
+                    array_stride: 0,
+                    matrix_stride: Self::alignment(layout),
+                });
+            }
+        }
+    };
+}
+
+matrix_members! {
+    f32 => Float;
+    f64 => Double;
+}
+
+/// # An array member of an interface block
+/// see [BlockMember]
+///
+/// Arrays of basic types are named like the driver names them, by their first element
+/// (`"weights[0]"`), while arrays of structs and arrays of arrays name every element
+/// (`"lights[1].colour"`).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BlockArray<T, const N: usize>(pub [T; N]);
+
+impl<T: BlockMember, const N: usize> BlockArray<T, N> {
+    /// The stride between elements of the array in `layout`
+    pub fn stride(layout: BlockLayout) -> usize {
+        align_to(T::size(layout), Self::alignment(layout))
+    }
+}
+
+impl<T: BlockMember, const N: usize> BlockMember for BlockArray<T, N> {
+    fn alignment(layout: BlockLayout) -> usize {
+        layout.aggregate_alignment(T::alignment(layout))
+    }
+
+    fn size(layout: BlockLayout) -> usize {
+        N * Self::stride(layout)
+    }
+
+    fn write(&self, layout: BlockLayout, bytes: &mut [u8]) {
+        let stride = Self::stride(layout);
+        for (element, bytes) in self.0.iter().zip(bytes.chunks_mut(stride)) {
+            element.write(layout, bytes);
+        }
+    }
+
+    fn members(
+        layout: BlockLayout,
+        name: &str,
+        offset: usize,
+        members: &mut Vec<BlockMemberLayout>,
+    ) {
+        let stride = Self::stride(layout);
+        if T::BASIC {
+            let first = members.len();
+            T::members(layout, &format!("{name}[0]"), offset, members);
+            for member in &mut members[first..] {
+                member.array_stride = stride;
+            }
+        } else {
+            for index in 0..N {
+                let name = format!("{name}[{index}]");
+                T::members(layout, &name, offset + index * stride, members);
+            }
+        }
+    }
+}
+
+impl<T: Default + Copy, const N: usize> Default for BlockArray<T, N> {
+    fn default() -> Self {
+        BlockArray([T::default(); N])
+    }
+}
+
+impl<T, const N: usize> std::ops::Deref for BlockArray<T, N> {
+    type Target = [T; N];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> std::ops::DerefMut for BlockArray<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// # Declare structs that can be members of an interface block
+/// see [BlockMember]
+///
+/// Declares each struct as written, and implements [BlockMember] for it, laying out the fields in
+/// declaration order like a GLSL struct or block. Every field type must implement [BlockMember].
+///
+/// # Example
+/// ```no_run
+/// use rgl::prelude::*;
+///
+/// block_struct! {
+///     #[derive(Debug, Default, Copy, Clone)]
+///     pub struct Light {
+///         pub position: [f32; 3],
+///         pub intensity: f32,
+///     }
+///
+///     /// layout(std430, binding = 1) buffer Lights { mat4 view; Light lights[4]; };
+///     #[derive(Debug, Default, Copy, Clone)]
+///     pub struct Lights {
+///         pub view: [[f32; 4]; 4],
+///         pub lights: BlockArray<Light, 4>,
+///     }
+/// }
+///
+/// assert_eq!(Lights::size(BlockLayout::Std430), 128);
+/// ```
+#[macro_export]
+macro_rules! block_struct {
+    ($(
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field_vis:vis $field:ident: $ty:ty),* $(,)?
+        }
+    )*) => {
+        $(
+            $(#[$meta])*
+            $vis struct $name {
+                $($(#[$field_meta])* $field_vis $field: $ty),*
+            }
+
+            impl $crate::interface_block::BlockMember for $name {
+                fn alignment(layout: $crate::interface_block::BlockLayout) -> usize {
+                    use $crate::interface_block::BlockMember;
+                    let alignment = 1 $(.max(<$ty as BlockMember>::alignment(layout)))*;
+                    layout.aggregate_alignment(alignment)
+                }
+
+                fn size(layout: $crate::interface_block::BlockLayout) -> usize {
+                    use $crate::interface_block::{align_to, BlockMember};
+                    let mut offset = 0;
+                    $(
+                        offset = align_to(offset, <$ty as BlockMember>::alignment(layout));
+                        offset += <$ty as BlockMember>::size(layout);
+                    )*
+                    align_to(offset, Self::alignment(layout))
+                }
+
+                fn write(&self, layout: $crate::interface_block::BlockLayout, bytes: &mut [u8]) {
+                    use $crate::interface_block::{align_to, BlockMember};
+                    let mut offset = 0;
+                    $(
+                        offset = align_to(offset, <$ty as BlockMember>::alignment(layout));
+                        self.$field.write(layout, &mut bytes[offset..]);
+                        offset += <$ty as BlockMember>::size(layout);
+                    )*
+                    let _ = offset;
+                }
+
+                fn members(
+                    layout: $crate::interface_block::BlockLayout,
+                    name: &str,
+                    offset: usize,
+                    members: &mut Vec<$crate::interface_block::BlockMemberLayout>,
+                ) {
+                    use $crate::interface_block::{align_to, BlockMember};
+                    let mut field_offset = 0;
+                    $(
+                        let field = match name {
+                            "" => stringify!($field).to_string(),
+                            name => format!("{name}.{}", stringify!($field)),
+                        };
+                        field_offset =
+                            align_to(field_offset, <$ty as BlockMember>::alignment(layout));
+                        <$ty as BlockMember>::members(
+                            layout,
+                            &field,
+                            offset + field_offset,
+                            members,
+                        );
+                        field_offset += <$ty as BlockMember>::size(layout);
+                    )*
+                    let _ = field_offset;
+                }
+            }
+        )*
+    };
+}
+
+/// # Differences between a Rust block layout and the driver's
+/// see [verify_block_layout]
+///
+/// `expected` is the value reported by the driver, and `found` is the value computed by
+/// [BlockMember].
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutMismatch {
+    /// The program has no active block with the name
+    BlockNotFound,
+
+    /// The block has an active member that is missing from the Rust type
+    MissingMember(String),
+
+    /// The Rust type is smaller than the buffer size required by the block
+    Size { expected: usize, found: usize },
+
+    /// A member has a different type in the Rust type
+    Type {
+        member: String,
+        expected: GlslType,
+        found: GlslType,
+    },
+
+    /// A member is placed at a different offset in the Rust type
+    Offset {
+        member: String,
+        expected: usize,
+        found: usize,
+    },
+
+    /// An array member has a different stride between elements in the Rust type
+    ArrayStride {
+        member: String,
+        expected: usize,
+        found: usize,
+    },
+
+    /// A matrix member has a different stride between columns in the Rust type
+    MatrixStride {
+        member: String,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl std::fmt::Display for LayoutMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LayoutMismatch::BlockNotFound => write!(f, "the program has no such active block"),
+            LayoutMismatch::MissingMember(member) => {
+                write!(f, "the block member {member} is missing from the Rust type")
+            }
+            LayoutMismatch::Size { expected, found } => {
+                write!(
+                    f,
+                    "the block requires {expected} bytes, but the Rust type has {found}"
+                )
+            }
+            LayoutMismatch::Type {
+                member,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "{member} is a {expected:?} in the block, but a {found:?} in Rust"
+                )
+            }
+            LayoutMismatch::Offset {
+                member,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "{member} is at offset {expected} in the block, but {found} in Rust"
+                )
+            }
+            LayoutMismatch::ArrayStride {
+                member,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "{member} has array stride {expected} in the block, but {found} in Rust"
+                )
+            }
+            LayoutMismatch::MatrixStride {
+                member,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "{member} has matrix stride {expected} in the block, but {found} in Rust"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LayoutMismatch {}
+
+/// # Verify a Rust block layout against the driver
+/// see [BlockMember]
+///
+/// # Arguments
+/// * `program` - Specifies a linked program containing the block.
+/// * `interface` - Specifies [ProgramInterface::UniformBlock] or
+/// [ProgramInterface::ShaderStorageBlock].
+/// * `block` - Specifies the name of the block, not its instance name.
+/// * `layout` - Specifies the layout declared for the block.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// block_struct! {
+///     pub struct Material {
+///         pub colour: [f32; 3],
+///         pub shininess: f32,
+///     }
+/// }
+///
+/// let interface = ProgramInterface::UniformBlock;
+/// let layout = BlockLayout::Std140;
+/// verify_block_layout::<Material>(Program(42), interface, c"Material", layout).unwrap();
+/// ```
+///
+/// # Description
+/// Compares the type, offset, array stride and matrix stride of every active member of `block`,
+/// as reported by [get_program_resource], to the layout of `T` computed by [BlockMember::layout].
+/// Members are matched by name, ignoring any `"Block."` prefix the driver adds to the members of
+/// blocks with an instance name. Members of the Rust type that are not active in the block are not
+/// reported.
+///
+/// The Rust type must also be at least as large as the buffer size the driver requires for the
+/// block. The size of a block ending in an array without a size is not checked.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [verify_block_layout] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [get_active_uniform_block_data_size]
+/// * [ProgramReflection]
+pub fn verify_block_layout<T: BlockMember>(
+    program: Program,
+    interface: ProgramInterface,
+    block: &std::ffi::CStr,
+    layout: BlockLayout,
+) -> Result<(), LayoutMismatch> {
+    use ProgramResourceProperty as P;
+
+    let variables = match interface {
+        ProgramInterface::ShaderStorageBlock => ProgramInterface::BufferVariable,
+        _ => ProgramInterface::Uniform,
+    };
+    let index = get_program_resource_index(program, interface, block)
+        .ok_or(LayoutMismatch::BlockNotFound)?;
+
+    let mut params = [0; 2];
+    get_program_resource(
+        program,
+        interface,
+        index,
+        &[P::BufferDataSize, P::NumActiveVariables],
+        &mut params,
+    );
+    let [data_size, num_variables] = params;
+
+    let mut indices = vec![0; num_variables.max(0) as usize];
+    let written = get_program_resource(
+        program,
+        interface,
+        index,
+        &[P::ActiveVariables],
+        &mut indices,
+    )
+    .len();
+    indices.truncate(written);
+
+    let members = T::layout(layout);
+    let prefix = format!("{}.", block.to_string_lossy());
+    let mut unsized_array = false;
+
+    for index in indices {
+        let props = [
+            P::NameLength,
+            P::Type,
+            P::Offset,
+            P::ArraySize,
+            P::ArrayStride,
+            P::MatrixStride,
+        ];
+        let mut params = [0; 6];
+        get_program_resource(program, variables, index as u32, &props, &mut params);
+        let [name_length, ty, offset, array_size, array_stride, matrix_stride] = params;
+        unsized_array |= array_size == 0;
+
+        let name = reflect_name(program, variables, index as u32, name_length);
+        let name = name.strip_prefix(&prefix).unwrap_or(&name);
+        let member = members
+            .iter()
+            .find(|member| member.name == name)
+            .ok_or_else(|| LayoutMismatch::MissingMember(name.to_string()))?;
+
+        let member_name = || member.name.clone();
+        let ty = GlslType::from(ty as u32);
+        if ty != member.ty {
+            let (member, expected, found) = (member_name(), ty, member.ty);
+            return Err(LayoutMismatch::Type {
+                member,
+                expected,
+                found,
+            });
+        }
+        if offset as usize != member.offset {
+            let (member, expected, found) = (member_name(), offset as usize, member.offset);
+            return Err(LayoutMismatch::Offset {
+                member,
+                expected,
+                found,
+            });
+        }
+        if array_stride as usize != member.array_stride {
+            let (expected, found) = (array_stride as usize, member.array_stride);
+            let member = member_name();
+            return Err(LayoutMismatch::ArrayStride {
+                member,
+                expected,
+                found,
+            });
+        }
+        if matrix_stride as usize != member.matrix_stride {
+            let (expected, found) = (matrix_stride as usize, member.matrix_stride);
+            let member = member_name();
+            return Err(LayoutMismatch::MatrixStride {
+                member,
+                expected,
+                found,
+            });
+        }
+    }
+
+    let (expected, found) = (data_size as usize, T::size(layout));
+    if !unsized_array && found < expected {
+        return Err(LayoutMismatch::Size { expected, found });
+    }
+    Ok(())
+}
@@ -105,27 +105,122 @@
 //! [get_error](rgl::prelude::get_error), and transform the vague
 //! [opengl error enum](rgl::prelude::Error), with a module-specific error (e.g.
 //! [BufferError](rgl::prelude::BufferError)) that is returned through the rust `Result` idiom to
-//! accurately describe the per-function error that occurred.
+//! accurately describe the per-function error that occurred. The exceptions are the argument-free
+//! queries in [get](rgl::get) and helpers that make no OpenGL call, such as
+//! [compressed_image_size](rgl::prelude::compressed_image_size).
+//!
+//! Every module-specific error implements [std::error::Error], and converts into the unifying
+//! [RglError](rgl::prelude::RglError), along with the raw [Error](rgl::prelude::Error), so calls
+//! across modules can share one `?`.
+//!
+//! ## Owned Objects
+//! The raw handles ([Buffer](rgl::prelude::Buffer), [Texture](rgl::prelude::Texture), ...) are
+//! plain copyable integers, deleted only by an explicit `delete_*` call. The opt-in owned types
+//! ([OwnedBuffer](rgl::prelude::OwnedBuffer), [OwnedTexture](rgl::prelude::OwnedTexture), ...)
+//! generate their object on construction and delete it on drop, while dereferencing to the raw
+//! handle:
+//! ```no_run
+//! use rgl::prelude as rgl;
+//! let texture = rgl::OwnedTexture::generate();
+//! rgl::bind_texture(rgl::TextureBindingTarget::Image2D, *texture);
+//! ```
+//!
+//! ## Debug Output
+//! OpenGL 4.3 debug output can be routed to a rust closure, which is shielded from unwinding into
+//! the driver:
+//! ```no_run
+//! use rgl::prelude as rgl;
+//! rgl::enable(rgl::Capability::DebugOutput);
+//! rgl::enable(rgl::Capability::DebugOutputSynchronous);
+//! rgl::debug_message_callback(|message| eprintln!("{message}"));
+//! ```
+//! or, with the `log` feature, forwarded to the [log](https://docs.rs/log) crate by
+//! [log_debug_messages](rgl::debug::log_debug_messages).
+//!
+//! ## Interface Blocks
+//! The contents of uniform and shader storage blocks can be declared in rust with
+//! [block_struct](rgl::block_struct), which lays them out in std140 or std430, and checks that
+//! layout against the driver with [verify_block_layout](rgl::interface_block::verify_block_layout):
+//! ```no_run
+//! use rgl::prelude as rgl;
+//! rgl::block_struct! {
+//!     struct Material {
+//!         colour: [f32; 3],
+//!         shininess: f32,
+//!     }
+//! }
+//! let material = Material { colour: [1.0, 0.5, 0.0], shininess: 32.0 };
+//! let bytes = rgl::BlockMember::to_bytes(&material, rgl::BlockLayout::Std140);
+//! ```
+//!
+//! ## Compressed Textures
+//! Block compressed (BC4 to BC7) textures can be loaded from KTX2 and DDS files with
+//! [TextureContainer](rgl::texture_container::TextureContainer), which uploads every mip level,
+//! array layer and cube face into immutable storage:
+//! ```no_run
+//! use rgl::prelude as rgl;
+//! let bytes = std::fs::read("albedo.ktx2").unwrap();
+//! let texture = rgl::load_texture_container(&bytes).unwrap();
+//! ```
+//!
+//! ## State Snapshots
+//! Code that leaves the context state changed behind it (e.g. an immediate-mode UI library) can be
+//! wrapped in a [GlStateSnapshot](crate::state_snapshot::GlStateSnapshot), which captures every
+//! piece of state rgl can set, restores it, and lists what differs between two snapshots:
+//! ```no_run
+//! use rgl::prelude as rgl;
+//! let snapshot = rgl::GlStateSnapshot::capture();
+//! // ... draw the UI ...
+//! for difference in snapshot.diff(&rgl::GlStateSnapshot::capture()) {
+//!     eprintln!("{difference}");
+//! }
+//! snapshot.restore();
+//! ```
+//!
+//! ## Testing
+//! The integration tests render off-screen through Mesa's `llvmpipe` software driver, so they need
+//! `libEGL.so.1` and Mesa installed, but no GPU or display. They are behind the `headless` feature,
+//! which also exposes [HeadlessContext](rgl::headless::HeadlessContext) for downstream tests:
+//! ```sh
+//! cargo test --features headless
+//! ```
+//!
+//! Render code can also be unit tested without any driver through the `mock` feature, where
+//! [MockContext](rgl::mock::MockContext) records every GL call made by rgl with its decoded
+//! arguments.
 //!
 //! # TODO
-//! * implement all remaining `_checked` functions. (Currently just some in [buffer](rgl::buffer))
+//! * implement the remaining `_checked` functions in [buffer](rgl::buffer),
+//!   [rasterization](rgl::rasterization) and [shader_state](rgl::shader_state)
 //! * add the entire API reference
-//! * off-screen rendering unit-tests, which could act as an opengl implementation test
 //! * CI/CD automation
 //! * github integration
 //! * cleanup `FROM` implementations. Use privates so a public `u32::from(rgl::SomeEnum)` would fail
 //! * merge comparison funcs? ([TextureCompareFunc], [DepthFunc], [StencilFunc])
 
 pub mod buffer;
+pub mod debug;
+pub mod error;
 pub mod framebuffer;
+pub mod framebuffer_builder;
 pub mod get;
+#[cfg(feature = "headless")]
+pub mod headless;
+pub mod interface_block;
 pub mod masks;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod owned;
 pub mod post;
 pub mod prelude;
+pub mod rasterization;
+pub mod sampler;
 pub mod shader_creation;
 pub mod shader_query;
 pub mod shader_state;
+pub mod state_snapshot;
 pub mod texture;
+pub mod texture_container;
 pub mod vertex_array;
 pub mod vertex_render;
 
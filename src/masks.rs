@@ -27,7 +27,7 @@ bitflags::bitflags! {
 ///
 /// Use [is_enabled] or [get] to determine the current setting of any capability. The initial value
 /// for each capability with the exception of [Dither] and [Multisample] is false.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Capability {
     /// If enabled, blend the computed fragment color values with the values in the color buffers.
     /// See [blend_func].
@@ -80,16 +80,19 @@ pub enum Capability {
     /// See [sample_coverage].
     Multisample,
 
-    /// If enabled, and if the polygon is rendered in [Fill] mode, an offset is added to depth values
-    /// of a polygon's fragments before the depth comparison is performed. See [polygon_offset].
+    /// If enabled, and if the polygon is rendered in [PolygonMode::Fill] mode, an offset is added
+    /// to depth values of a polygon's fragments before the depth comparison is performed. See
+    /// [polygon_offset].
     PolygonOffsetFill,
 
-    /// If enabled, and if the polygon is rendered in [Line] mode, an offset is added to depth values
-    /// of a polygon's fragments before the depth comparison is performed. See [polygon_offset].
+    /// If enabled, and if the polygon is rendered in [PolygonMode::Line] mode, an offset is added
+    /// to depth values of a polygon's fragments before the depth comparison is performed. See
+    /// [polygon_offset].
     PolygonOffsetLine,
 
     /// If enabled, an offset is added to depth values of a polygon's fragments before the depth
-    /// comparison is performed, if the polygon is rendered in [Point] mode. See [polygon_offset].
+    /// comparison is performed, if the polygon is rendered in [PolygonMode::Point] mode. See
+    /// [polygon_offset].
     PolygonOffsetPoint,
 
     /// If enabled, draw polygons with proper filtering. Otherwise, draw aliased polygons. For correct
@@ -125,16 +128,16 @@ pub enum Capability {
     SampleAlphaToOne,
 
     /// If enabled, the fragment's coverage is ANDed with the temporary coverage value. If
-    /// [SampleCoverageInvert] is set, invert the coverage value. See [sample_coverage].
+    /// [get_sample_coverage_invert] is set, invert the coverage value. See [sample_coverage].
     SampleCoverage,
 
     /// If enabled, the active fragment shader is run once for each covered sample, or at fraction
-    /// of this rate as determined by the current value of [MinSampleShadingValue].
+    /// of this rate as determined by the current value of [get_min_sample_shading_value].
     /// See [min_sample_shading].
     SampleShading,
 
     /// If enabled, the sample coverage mask generated for a fragment during rasterization will be
-    /// ANDed with the value of [SampleMaskValue] before shading occurs. See [sample_mask].
+    /// ANDed with the value of [get_sample_mask_value] before shading occurs. See [sample_mask].
     SampleMask,
 
     /// If enabled, discard fragments that are outside the scissor rectangle. See [scissor].
@@ -210,6 +213,7 @@ impl From<StencilFace> for GLenum {
 }
 
 /// # Comparison Functions
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum CompareFunc {
     Never,
     Always,
@@ -236,6 +240,146 @@ impl From<CompareFunc> for GLenum {
     }
 }
 
+impl TryFrom<GLenum> for CompareFunc {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::NEVER => Ok(CompareFunc::Never),
+            gl::ALWAYS => Ok(CompareFunc::Always),
+            gl::EQUAL => Ok(CompareFunc::Equal),
+            gl::NOTEQUAL => Ok(CompareFunc::NotEqual),
+            gl::LESS => Ok(CompareFunc::Less),
+            gl::LEQUAL => Ok(CompareFunc::LessOrEqual),
+            gl::GREATER => Ok(CompareFunc::Greater),
+            gl::GEQUAL => Ok(CompareFunc::GreaterOrEqual),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Buffer of a framebuffer cleared with floating point values
+/// see [clear_buffer_f32]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ClearBufferF32 {
+    /// The fixed-point or floating point colour buffers selected by a draw buffer index, cleared to
+    /// `[red, green, blue, alpha]`
+    Colour(u32, [f32; 4]),
+
+    /// The depth buffer, cleared to a depth value
+    Depth(f32),
+}
+
+impl ClearBufferF32 {
+    fn draw_buffer(self) -> u32 {
+        match self {
+            ClearBufferF32::Colour(draw_buffer, _) => draw_buffer,
+            ClearBufferF32::Depth(_) => 0,
+        }
+    }
+}
+
+/// # Buffer of a framebuffer cleared with signed integer values
+/// see [clear_buffer_i32]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ClearBufferI32 {
+    /// The signed integer colour buffers selected by a draw buffer index, cleared to
+    /// `[red, green, blue, alpha]`
+    Colour(u32, [i32; 4]),
+
+    /// The stencil buffer, cleared to a stencil index
+    Stencil(i32),
+}
+
+impl ClearBufferI32 {
+    fn draw_buffer(self) -> u32 {
+        match self {
+            ClearBufferI32::Colour(draw_buffer, _) => draw_buffer,
+            ClearBufferI32::Stencil(_) => 0,
+        }
+    }
+}
+
+/// # Buffer of a framebuffer cleared with unsigned integer values
+/// see [clear_buffer_u32]
+///
+/// Only colour buffers hold unsigned integers, as the depth buffer is cleared with
+/// [ClearBufferF32::Depth] and the stencil buffer with [ClearBufferI32::Stencil]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ClearBufferU32 {
+    /// The unsigned integer colour buffers selected by a draw buffer index, cleared to
+    /// `[red, green, blue, alpha]`
+    Colour(u32, [u32; 4]),
+}
+
+impl ClearBufferU32 {
+    fn draw_buffer(self) -> u32 {
+        match self {
+            ClearBufferU32::Colour(draw_buffer, _) => draw_buffer,
+        }
+    }
+}
+
+/// # Errors of whole framebuffer and per-fragment state
+/// see the [masks](crate::masks) and [post](crate::post) modules
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StateError {
+    Unexpected(Error),
+
+    /// The capability is not supported by the context
+    UnsupportedCapability(Capability),
+
+    /// The draw buffer index is not less than [get_max_draw_buffers]
+    InvalidDrawBufferIndex(u32),
+
+    /// The name is neither zero, for the default framebuffer, nor a framebuffer object
+    InvalidFramebuffer(Framebuffer),
+
+    /// The index is out of range of the indexed states of the capability
+    InvalidCapabilityIndex(Capability, u32),
+}
+
+impl std::fmt::Display for StateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::Unexpected(error) => write!(f, "unexpected state error: {error}"),
+            StateError::UnsupportedCapability(capability) => {
+                write!(f, "capability {capability:?} is not supported")
+            }
+            StateError::InvalidDrawBufferIndex(index) => {
+                write!(f, "draw buffer index {index} is out of range")
+            }
+            StateError::InvalidFramebuffer(framebuffer) => {
+                write!(f, "{framebuffer:?} is not a framebuffer")
+            }
+            StateError::InvalidCapabilityIndex(capability, index) => {
+                write!(
+                    f,
+                    "index {index} is out of range for capability {capability:?}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+pub(crate) fn state_error() -> Result<(), StateError> {
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(StateError::Unexpected(other)),
+    }
+}
+
+/// Maps the error of a per-draw-buffer function, for which [Error::InvalidValue] always means the
+/// draw buffer index is out of range
+pub(crate) fn draw_buffer_error(buffer_index: u32) -> Result<(), StateError> {
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(StateError::InvalidDrawBufferIndex(buffer_index)),
+        other => Err(StateError::Unexpected(other)),
+    }
+}
+
 /// # Clear buffers to preset values
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glClear.xhtml>
 ///
@@ -290,6 +434,13 @@ pub fn clear(mask: ClearMask) {
     unsafe { gl::Clear(mask) }
 }
 
+/// # Error mapped clear
+/// see [clear]
+pub fn clear_checked(mask: ClearMask) -> Result<(), StateError> {
+    clear(mask);
+    state_error()
+}
+
 /// # Specify clear values for the color buffers
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glClearColor.xhtml>
 ///
@@ -308,7 +459,7 @@ pub fn clear(mask: ClearMask) {
 /// colour buffers. Values specified by [clear_colour] are clamped to the range [0,1].
 ///
 /// # Associated Gets
-/// * [get_colour_clear]
+/// * [get_colour_clear](get_colour_clear_value)
 ///
 /// # Version Support
 ///
@@ -318,11 +469,416 @@ pub fn clear(mask: ClearMask) {
 ///
 /// # See Also
 /// * [clear]
+/// * [clear_buffer_f32]
 pub fn clear_colour(red: f32, green: f32, blue: f32, alpha: f32) {
     // SAFE: synchronous integer copy
     unsafe { gl::ClearColor(red, green, blue, alpha) }
 }
 
+/// # Error mapped clear colour
+/// see [clear_colour]
+pub fn clear_colour_checked(red: f32, green: f32, blue: f32, alpha: f32) -> Result<(), StateError> {
+    clear_colour(red, green, blue, alpha);
+    state_error()
+}
+
+/// # Specify the clear value for the depth buffer
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glClearDepth.xhtml>
+///
+/// # Arguments
+/// * `depth` - Specifies the depth value used when the depth buffer is cleared. The initial value
+/// is 1.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // reversed depth, with a depth func of CompareFunc::Greater
+/// clear_depth(0.0);
+/// ```
+///
+/// # Description
+/// [clear_depth] specifies the depth value used by [clear] to clear the depth buffer. Values
+/// specified by [clear_depth] are clamped to the range \[0,1\].
+///
+/// # Associated Gets
+/// * [get_depth_clear_value]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [clear_depth] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [clear]
+/// * [clear_buffer_f32]
+pub fn clear_depth(depth: f64) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::ClearDepth(depth) }
+}
+
+/// # Error mapped clear depth
+/// see [clear_depth]
+pub fn clear_depth_checked(depth: f64) -> Result<(), StateError> {
+    clear_depth(depth);
+    state_error()
+}
+
+/// # Specify the clear value for the stencil buffer
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glClearStencil.xhtml>
+///
+/// # Arguments
+/// * `stencil` - Specifies the index used when the stencil buffer is cleared. The initial value is
+/// 0.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// clear_stencil(1);
+/// ```
+///
+/// # Description
+/// [clear_stencil] specifies the index used by [clear] to clear the stencil buffer. `stencil` is
+/// masked with 2<sup>m</sup> − 1, where `m` is the number of bits in the stencil buffer.
+///
+/// # Associated Gets
+/// * [get_stencil_clear_value]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [clear_stencil] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [clear]
+/// * [clear_buffer_i32]
+/// * [stencil_mask]
+pub fn clear_stencil(stencil: i32) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::ClearStencil(stencil) }
+}
+
+/// # Error mapped clear stencil
+/// see [clear_stencil]
+pub fn clear_stencil_checked(stencil: i32) -> Result<(), StateError> {
+    clear_stencil(stencil);
+    state_error()
+}
+
+fn clear_named_framebuffer_error(
+    framebuffer: Framebuffer,
+    draw_buffer: u32,
+) -> Result<(), StateError> {
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(StateError::InvalidFramebuffer(framebuffer)),
+        Error::InvalidValue => Err(StateError::InvalidDrawBufferIndex(draw_buffer)),
+        other => Err(StateError::Unexpected(other)),
+    }
+}
+
+/// # Clear individual buffers of a framebuffer
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glClearBuffer.xhtml>
+///
+/// # Arguments
+/// * `framebuffer` - Specifies the name of the framebuffer object for [clear_named_framebuffer_f32]
+/// and the other named functions.
+/// * `buffer` - Specifies the buffer to clear, and the value or values to clear it to.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // an albedo colour attachment, next to an unsigned integer object id attachment
+/// draw_buffers(&[ColourBuffer::Attachment(0), ColourBuffer::Attachment(1)]);
+/// clear_buffer_f32(ClearBufferF32::Colour(0, [0.0, 0.0, 0.0, 1.0]));
+/// clear_buffer_u32(ClearBufferU32::Colour(1, [u32::MAX; 4]));
+/// clear_buffer_depth_stencil(1.0, 0);
+/// ```
+///
+/// # Description
+/// These commands clear a single buffer of the framebuffer bound to
+/// [FramebufferBindingTarget::Draw], or of `framebuffer` for the named functions, to the given
+/// value or values.
+///
+/// A colour buffer is selected by its draw buffer index `i`, the value of [get_draw_buffer]`(i)`
+/// set by [draw_buffers]. If that draw buffer is [ColourBuffer::None], the command has no effect.
+/// Otherwise every colour buffer it identifies is cleared to the same `[red, green, blue, alpha]`
+/// value. Clamping and type conversion for fixed-point colour buffers are performed in the same
+/// fashion as for [clear_colour]. [clear_buffer_f32] should be used to clear fixed-point and
+/// floating point colour buffers, [clear_buffer_i32] to clear signed integer colour buffers, and
+/// [clear_buffer_u32] to clear unsigned integer colour buffers. Clearing a colour buffer with the
+/// wrong kind of value leaves it undefined.
+///
+/// The depth buffer can only be cleared with [ClearBufferF32::Depth], where clamping and type
+/// conversion are performed in the same fashion as for [clear_depth]. The stencil buffer can only
+/// be cleared with [ClearBufferI32::Stencil], which is masked in the same fashion as for
+/// [clear_stencil]. [clear_buffer_depth_stencil] clears both the depth and the stencil buffer at
+/// once.
+///
+/// The result of these commands is no different than calling [clear], with the clear value of the
+/// buffer set, except that only one buffer is affected and the clear values are left unchanged.
+/// The pixel ownership test, the scissor test, sRGB conversion, dithering, and the buffer
+/// writemasks affect the operation of these commands.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - by the named functions, if `framebuffer` is not zero or the name
+/// of an existing framebuffer object.
+/// * [Error::InvalidValue] - if a colour buffer draw buffer index is greater than or equal to
+/// [get_max_draw_buffers].
+///
+/// # Associated Gets
+/// * [get_draw_buffer]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [clear_buffer_f32] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [clear_buffer_i32] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [clear_buffer_u32] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [clear_buffer_depth_stencil] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [clear_named_framebuffer_f32] | N | N | N | N | N | N | N | N | N | N | N | Y |
+/// | [clear_named_framebuffer_i32] | N | N | N | N | N | N | N | N | N | N | N | Y |
+/// | [clear_named_framebuffer_u32] | N | N | N | N | N | N | N | N | N | N | N | Y |
+/// | [clear_named_framebuffer_depth_stencil] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [clear]
+/// * [clear_colour]
+/// * [clear_depth]
+/// * [clear_stencil]
+/// * [colour_mask]
+/// * [depth_mask]
+/// * [draw_buffers]
+/// * [stencil_mask]
+pub fn clear_buffer_f32(buffer: ClearBufferF32) {
+    match buffer {
+        ClearBufferF32::Colour(draw_buffer, value) => {
+            let drawbuffer = draw_buffer as GLint;
+
+            // SAFE: `value` holds the 4 components read for a colour buffer, no memory retained
+            unsafe { gl::ClearBufferfv(gl::COLOR, drawbuffer, value.as_ptr()) }
+        }
+        ClearBufferF32::Depth(depth) => {
+            // SAFE: a single value is read for the depth buffer, no memory retained
+            unsafe { gl::ClearBufferfv(gl::DEPTH, 0, &depth) }
+        }
+    }
+}
+
+/// # Error mapped clear buffer f32
+/// see [clear_buffer_f32]
+pub fn clear_buffer_f32_checked(buffer: ClearBufferF32) -> Result<(), StateError> {
+    clear_buffer_f32(buffer);
+    draw_buffer_error(buffer.draw_buffer())
+}
+
+/// # Clear an individual buffer of a framebuffer object
+/// see [clear_buffer_f32]
+///
+/// # Arguments
+/// * `framebuffer` - Specifies the name of the framebuffer object, or zero for the default
+/// framebuffer.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `framebuffer` is not zero or the name of an existing
+/// framebuffer object.
+pub fn clear_named_framebuffer_f32(framebuffer: Framebuffer, buffer: ClearBufferF32) {
+    let framebuffer_name = framebuffer.0;
+    match buffer {
+        ClearBufferF32::Colour(draw_buffer, value) => {
+            let drawbuffer = draw_buffer as GLint;
+
+            // SAFE: `value` holds the 4 components read for a colour buffer, no memory retained
+            unsafe {
+                gl::ClearNamedFramebufferfv(framebuffer_name, gl::COLOR, drawbuffer, value.as_ptr())
+            }
+        }
+        ClearBufferF32::Depth(depth) => {
+            // SAFE: a single value is read for the depth buffer, no memory retained
+            unsafe { gl::ClearNamedFramebufferfv(framebuffer_name, gl::DEPTH, 0, &depth) }
+        }
+    }
+}
+
+/// # Error mapped clear named framebuffer f32
+/// see [clear_named_framebuffer_f32]
+pub fn clear_named_framebuffer_f32_checked(
+    framebuffer: Framebuffer,
+    buffer: ClearBufferF32,
+) -> Result<(), StateError> {
+    clear_named_framebuffer_f32(framebuffer, buffer);
+    clear_named_framebuffer_error(framebuffer, buffer.draw_buffer())
+}
+
+/// # Clear an individual buffer of a framebuffer with signed integers
+/// see [clear_buffer_f32]
+pub fn clear_buffer_i32(buffer: ClearBufferI32) {
+    match buffer {
+        ClearBufferI32::Colour(draw_buffer, value) => {
+            let drawbuffer = draw_buffer as GLint;
+
+            // SAFE: `value` holds the 4 components read for a colour buffer, no memory retained
+            unsafe { gl::ClearBufferiv(gl::COLOR, drawbuffer, value.as_ptr()) }
+        }
+        ClearBufferI32::Stencil(stencil) => {
+            // SAFE: a single value is read for the stencil buffer, no memory retained
+            unsafe { gl::ClearBufferiv(gl::STENCIL, 0, &stencil) }
+        }
+    }
+}
+
+/// # Error mapped clear buffer i32
+/// see [clear_buffer_i32]
+pub fn clear_buffer_i32_checked(buffer: ClearBufferI32) -> Result<(), StateError> {
+    clear_buffer_i32(buffer);
+    draw_buffer_error(buffer.draw_buffer())
+}
+
+/// # Clear an individual buffer of a framebuffer object with signed integers
+/// see [clear_buffer_f32]
+///
+/// # Arguments
+/// * `framebuffer` - Specifies the name of the framebuffer object, or zero for the default
+/// framebuffer.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `framebuffer` is not zero or the name of an existing
+/// framebuffer object.
+pub fn clear_named_framebuffer_i32(framebuffer: Framebuffer, buffer: ClearBufferI32) {
+    let framebuffer_name = framebuffer.0;
+    match buffer {
+        ClearBufferI32::Colour(draw_buffer, value) => {
+            let drawbuffer = draw_buffer as GLint;
+
+            // SAFE: `value` holds the 4 components read for a colour buffer, no memory retained
+            unsafe {
+                gl::ClearNamedFramebufferiv(framebuffer_name, gl::COLOR, drawbuffer, value.as_ptr())
+            }
+        }
+        ClearBufferI32::Stencil(stencil) => {
+            // SAFE: a single value is read for the stencil buffer, no memory retained
+            unsafe { gl::ClearNamedFramebufferiv(framebuffer_name, gl::STENCIL, 0, &stencil) }
+        }
+    }
+}
+
+/// # Error mapped clear named framebuffer i32
+/// see [clear_named_framebuffer_i32]
+pub fn clear_named_framebuffer_i32_checked(
+    framebuffer: Framebuffer,
+    buffer: ClearBufferI32,
+) -> Result<(), StateError> {
+    clear_named_framebuffer_i32(framebuffer, buffer);
+    clear_named_framebuffer_error(framebuffer, buffer.draw_buffer())
+}
+
+/// # Clear an individual colour buffer of a framebuffer with unsigned integers
+/// see [clear_buffer_f32]
+pub fn clear_buffer_u32(buffer: ClearBufferU32) {
+    match buffer {
+        ClearBufferU32::Colour(draw_buffer, value) => {
+            let drawbuffer = draw_buffer as GLint;
+
+            // SAFE: `value` holds the 4 components read for a colour buffer, no memory retained
+            unsafe { gl::ClearBufferuiv(gl::COLOR, drawbuffer, value.as_ptr()) }
+        }
+    }
+}
+
+/// # Error mapped clear buffer u32
+/// see [clear_buffer_u32]
+pub fn clear_buffer_u32_checked(buffer: ClearBufferU32) -> Result<(), StateError> {
+    clear_buffer_u32(buffer);
+    draw_buffer_error(buffer.draw_buffer())
+}
+
+/// # Clear an individual colour buffer of a framebuffer object with unsigned integers
+/// see [clear_buffer_u32]
+///
+/// # Arguments
+/// * `framebuffer` - Specifies the name of the framebuffer object, or zero for the default
+/// framebuffer.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `framebuffer` is not zero or the name of an existing
+/// framebuffer object.
+pub fn clear_named_framebuffer_u32(framebuffer: Framebuffer, buffer: ClearBufferU32) {
+    let framebuffer_name = framebuffer.0;
+    match buffer {
+        ClearBufferU32::Colour(draw_buffer, value) => {
+            let drawbuffer = draw_buffer as GLint;
+
+            // SAFE: `value` holds the 4 components read for a colour buffer, no memory retained
+            unsafe {
+                gl::ClearNamedFramebufferuiv(
+                    framebuffer_name,
+                    gl::COLOR,
+                    drawbuffer,
+                    value.as_ptr(),
+                )
+            }
+        }
+    }
+}
+
+/// # Error mapped clear named framebuffer u32
+/// see [clear_named_framebuffer_u32]
+pub fn clear_named_framebuffer_u32_checked(
+    framebuffer: Framebuffer,
+    buffer: ClearBufferU32,
+) -> Result<(), StateError> {
+    clear_named_framebuffer_u32(framebuffer, buffer);
+    clear_named_framebuffer_error(framebuffer, buffer.draw_buffer())
+}
+
+/// # Clear the depth and stencil buffers of a framebuffer
+/// see [clear_buffer_f32]
+///
+/// # Arguments
+/// * `depth` - Specifies the value to clear the depth buffer to.
+/// * `stencil` - Specifies the index to clear the stencil buffer to.
+pub fn clear_buffer_depth_stencil(depth: f32, stencil: i32) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::ClearBufferfi(gl::DEPTH_STENCIL, 0, depth, stencil) }
+}
+
+/// # Error mapped clear buffer depth stencil
+/// see [clear_buffer_depth_stencil]
+pub fn clear_buffer_depth_stencil_checked(depth: f32, stencil: i32) -> Result<(), StateError> {
+    clear_buffer_depth_stencil(depth, stencil);
+    state_error()
+}
+
+/// # Clear the depth and stencil buffers of a framebuffer object
+/// see [clear_buffer_depth_stencil]
+///
+/// # Arguments
+/// * `framebuffer` - Specifies the name of the framebuffer object, or zero for the default
+/// framebuffer.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `framebuffer` is not zero or the name of an existing
+/// framebuffer object.
+pub fn clear_named_framebuffer_depth_stencil(framebuffer: Framebuffer, depth: f32, stencil: i32) {
+    let framebuffer = framebuffer.0;
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::ClearNamedFramebufferfi(framebuffer, gl::DEPTH_STENCIL, 0, depth, stencil) }
+}
+
+/// # Error mapped clear named framebuffer depth stencil
+/// see [clear_named_framebuffer_depth_stencil]
+pub fn clear_named_framebuffer_depth_stencil_checked(
+    framebuffer: Framebuffer,
+    depth: f32,
+    stencil: i32,
+) -> Result<(), StateError> {
+    clear_named_framebuffer_depth_stencil(framebuffer, depth, stencil);
+    clear_named_framebuffer_error(framebuffer, 0)
+}
+
 /// # Enable server-side GL capabilities
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glEnable.xhtml>
 ///
@@ -344,8 +900,11 @@ pub fn clear_colour(red: f32, green: f32, blue: f32, alpha: f32) {
 /// # Compatability
 ///
 /// # Errors
+/// * [Error::InvalidEnum] - if `capability` is not supported by the context version, e.g.
+///   [Capability::DebugOutput] before 4.3.
 ///
 /// # Associated Gets
+/// * [is_enabled]
 ///
 /// # Version Support
 ///
@@ -359,6 +918,7 @@ pub fn clear_colour(red: f32, green: f32, blue: f32, alpha: f32) {
 /// * [cull_face]
 /// * [depth_func]
 /// * [depth_range]
+/// * [enable_indexed]
 /// * [get]
 /// * [is_enabled]
 /// * [line_width]
@@ -376,6 +936,17 @@ pub fn enable(capability: Capability) {
     unsafe { gl::Enable(cap) }
 }
 
+/// # Error mapped enable
+/// see [enable]
+pub fn enable_checked(capability: Capability) -> Result<(), StateError> {
+    enable(capability);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidEnum => Err(StateError::UnsupportedCapability(capability)),
+        other => Err(StateError::Unexpected(other)),
+    }
+}
+
 /// # Disable server-side GL capabilities
 /// see [enable]
 pub fn disable(capability: Capability) {
@@ -383,6 +954,272 @@ pub fn disable(capability: Capability) {
     unsafe { gl::Disable(cap) }
 }
 
+/// # Error mapped disable
+/// see [disable]
+pub fn disable_checked(capability: Capability) -> Result<(), StateError> {
+    disable(capability);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidEnum => Err(StateError::UnsupportedCapability(capability)),
+        other => Err(StateError::Unexpected(other)),
+    }
+}
+
+/// # Enable or disable an indexed server-side GL capability
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glEnable.xhtml>
+///
+/// # Arguments
+/// * `capability` - Specifies a GL capability with an indexed state.
+/// * `index` - Specifies the index of the capability state to change.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // blend into the second draw buffer only
+/// enable_indexed(Capability::Blend, 1);
+/// ```
+///
+/// # Description
+/// [enable_indexed] and [disable_indexed] enable and disable the state of a capability for a
+/// single index. [Capability::Blend] is indexed by draw buffer and [Capability::ScissorTest] is
+/// indexed by viewport. [enable] and [disable] change the state of every index at once.
+///
+/// # Errors
+/// * [Error::InvalidEnum] - if `capability` does not have an indexed state.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to the number of indexed states
+/// of `capability`, i.e. [get_max_draw_buffers] or [get_max_viewports].
+///
+/// # Associated Gets
+/// * [is_enabled_indexed]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [enable_indexed] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [disable_indexed] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable]
+/// * [blend_func_buffer]
+/// * [scissor_indexed]
+pub fn enable_indexed(capability: Capability, index: u32) {
+    let cap = GLenum::from(capability);
+    unsafe { gl::Enablei(cap, index) }
+}
+
+/// # Error mapped enable indexed
+/// see [enable_indexed]
+pub fn enable_indexed_checked(capability: Capability, index: u32) -> Result<(), StateError> {
+    enable_indexed(capability, index);
+    indexed_capability_error(capability, index)
+}
+
+/// # Disable an indexed server-side GL capability
+/// see [enable_indexed]
+pub fn disable_indexed(capability: Capability, index: u32) {
+    let cap = GLenum::from(capability);
+    unsafe { gl::Disablei(cap, index) }
+}
+
+/// # Error mapped disable indexed
+/// see [disable_indexed]
+pub fn disable_indexed_checked(capability: Capability, index: u32) -> Result<(), StateError> {
+    disable_indexed(capability, index);
+    indexed_capability_error(capability, index)
+}
+
+fn indexed_capability_error(capability: Capability, index: u32) -> Result<(), StateError> {
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidEnum => Err(StateError::UnsupportedCapability(capability)),
+        Error::InvalidValue => Err(StateError::InvalidCapabilityIndex(capability, index)),
+        other => Err(StateError::Unexpected(other)),
+    }
+}
+
+/// # Test whether a capability is enabled
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glIsEnabled.xhtml>
+///
+/// # Arguments
+/// * `capability` - Specifies a symbolic constant indicating a GL capability.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// enable(Capability::DepthTest);
+/// assert!(is_enabled(Capability::DepthTest));
+/// ```
+///
+/// # Description
+/// [is_enabled] returns `true` if `capability` is an enabled capability and returns `false`
+/// otherwise. Initially all capabilities except [Capability::Dither] and
+/// [Capability::Multisample] are disabled. For a capability with an indexed state, the state of
+/// index 0 is returned.
+///
+/// # Errors
+/// * [Error::InvalidEnum] - if `capability` is not supported by the context version.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [is_enabled] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable]
+/// * [is_enabled_indexed]
+pub fn is_enabled(capability: Capability) -> bool {
+    let cap = GLenum::from(capability);
+    unsafe { gl::IsEnabled(cap) == gl::TRUE }
+}
+
+/// # Error mapped is enabled
+/// see [is_enabled]
+pub fn is_enabled_checked(capability: Capability) -> Result<bool, StateError> {
+    let enabled = is_enabled(capability);
+    match get_error() {
+        Error::NoError => Ok(enabled),
+        Error::InvalidEnum => Err(StateError::UnsupportedCapability(capability)),
+        other => Err(StateError::Unexpected(other)),
+    }
+}
+
+/// # Test whether an indexed capability is enabled
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glIsEnabled.xhtml>
+///
+/// # Arguments
+/// * `capability` - Specifies a GL capability with an indexed state.
+/// * `index` - Specifies the index of the capability state to test.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// enable_indexed(Capability::ScissorTest, 3);
+/// assert!(is_enabled_indexed(Capability::ScissorTest, 3));
+/// assert!(!is_enabled_indexed(Capability::ScissorTest, 0));
+/// ```
+///
+/// # Description
+/// [is_enabled_indexed] returns `true` if the state `index` of `capability` is enabled and
+/// returns `false` otherwise. See [enable_indexed].
+///
+/// # Errors
+/// * [Error::InvalidEnum] - if `capability` does not have an indexed state.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to the number of indexed states
+/// of `capability`.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [is_enabled_indexed] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable_indexed]
+/// * [is_enabled]
+pub fn is_enabled_indexed(capability: Capability, index: u32) -> bool {
+    let cap = GLenum::from(capability);
+    unsafe { gl::IsEnabledi(cap, index) == gl::TRUE }
+}
+
+/// # Error mapped is enabled indexed
+/// see [is_enabled_indexed]
+pub fn is_enabled_indexed_checked(capability: Capability, index: u32) -> Result<bool, StateError> {
+    let enabled = is_enabled_indexed(capability, index);
+    indexed_capability_error(capability, index).map(|()| enabled)
+}
+
+/// # Enable and disable writing of frame buffer colour components
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glColorMask.xhtml>
+///
+/// # Arguments
+/// * `buffer_index` - For [colour_mask_buffer], specifies the index of the draw buffer whose
+/// colour mask to set.
+/// * `red`, `green`, `blue`, `alpha` - Specify whether red, green, blue, and alpha are to be
+/// written into the frame buffer. The initial values are all `true`, indicating that the colour
+/// components are written.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // keep the alpha channel of every draw buffer, and leave draw buffer 1 untouched
+/// colour_mask(true, true, true, false);
+/// colour_mask_buffer(1, false, false, false, false);
+/// ```
+///
+/// # Description
+/// [colour_mask] and [colour_mask_buffer] specify whether the individual colour components in the
+/// frame buffer can or cannot be written. [colour_mask_buffer] sets the mask for a specific draw
+/// buffer, whereas [colour_mask] sets the mask for all draw buffers. If `red` is `false`, for
+/// example, no change is made to the red component of any pixel in any of the colour buffers,
+/// regardless of the drawing operation attempted.
+///
+/// Changes to individual bits of components cannot be controlled. Rather, changes are either
+/// enabled or disabled for entire colour components.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
+///
+/// # Associated Gets
+/// * [get_colour_writemask]
+/// * [get_colour_writemask_buffer]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [colour_mask] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [colour_mask_buffer] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [clear]
+/// * [clear_buffer_f32]
+/// * [depth_mask]
+/// * [stencil_mask]
+pub fn colour_mask(red: bool, green: bool, blue: bool, alpha: bool) {
+    let [red, green, blue, alpha] = [red, green, blue, alpha].map(GLboolean::from);
+    unsafe { gl::ColorMask(red, green, blue, alpha) }
+}
+
+/// # Error mapped colour mask
+/// see [colour_mask]
+pub fn colour_mask_checked(
+    red: bool,
+    green: bool,
+    blue: bool,
+    alpha: bool,
+) -> Result<(), StateError> {
+    colour_mask(red, green, blue, alpha);
+    state_error()
+}
+
+/// # Enable and disable writing of colour components of a draw buffer
+/// see [colour_mask]
+///
+/// # Arguments
+/// * `buffer_index` - specifies the index of the draw buffer whose colour mask to set.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
+pub fn colour_mask_buffer(buffer_index: u32, red: bool, green: bool, blue: bool, alpha: bool) {
+    let [red, green, blue, alpha] = [red, green, blue, alpha].map(GLboolean::from);
+    unsafe { gl::ColorMaski(buffer_index, red, green, blue, alpha) }
+}
+
+/// # Error mapped colour mask buffer
+/// see [colour_mask_buffer]
+pub fn colour_mask_buffer_checked(
+    buffer_index: u32,
+    red: bool,
+    green: bool,
+    blue: bool,
+    alpha: bool,
+) -> Result<(), StateError> {
+    colour_mask_buffer(buffer_index, red, green, blue, alpha);
+    draw_buffer_error(buffer_index)
+}
+
 /// # Enable or disable writing into the depth buffer
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDepthMask.xhtml>
 ///
@@ -406,7 +1243,7 @@ pub fn disable(capability: Capability) {
 /// test should be enabled and set to [depth_func]([DepthFunc::Always]).
 ///
 /// # Associated Gets
-/// * [get_depth_write_mask]
+/// * [get_depth_writemask]
 ///
 /// # Version Support
 ///
@@ -424,6 +1261,13 @@ pub fn depth_mask(enabled: bool) {
     unsafe { gl::DepthMask(flag) }
 }
 
+/// # Error mapped depth mask
+/// see [depth_mask]
+pub fn depth_mask_checked(enabled: bool) -> Result<(), StateError> {
+    depth_mask(enabled);
+    state_error()
+}
+
 /// # Specify the value used for depth buffer comparisons
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDepthFunc.xhtml>
 ///
@@ -480,6 +1324,13 @@ pub fn depth_func(func: CompareFunc) {
     unsafe { gl::DepthFunc(func) }
 }
 
+/// # Error mapped depth func
+/// see [depth_func]
+pub fn depth_func_checked(func: CompareFunc) -> Result<(), StateError> {
+    depth_func(func);
+    state_error()
+}
+
 /// # Control the front and back writing of individual bits in the stencil planes
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glStencilMask.xhtml>
 ///
@@ -538,6 +1389,13 @@ pub fn stencil_mask(mask: u32) {
     unsafe { gl::StencilMask(mask) }
 }
 
+/// # Error mapped stencil mask
+/// see [stencil_mask]
+pub fn stencil_mask_checked(mask: u32) -> Result<(), StateError> {
+    stencil_mask(mask);
+    state_error()
+}
+
 /// # Control the front and/or back writing of individual bits in the stencil planes
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glStencilMaskSeparate.xhtml>
 ///
@@ -589,3 +1447,10 @@ pub fn stencil_mask_separate(face: StencilFace, mask: u32) {
     let face = GLenum::from(face);
     unsafe { gl::StencilMaskSeparate(face, mask) }
 }
+
+/// # Error mapped stencil mask separate
+/// see [stencil_mask_separate]
+pub fn stencil_mask_separate_checked(face: StencilFace, mask: u32) -> Result<(), StateError> {
+    stencil_mask_separate(face, mask);
+    state_error()
+}
@@ -0,0 +1,2550 @@
+//! # Mock Context
+//! <https://docs.rs/gl/0.14.0/gl/fn.load_with.html>
+//!
+//! # Description
+//! A driverless stand-in for OpenGL, enabled with the `mock` cargo feature. [MockContext::install]
+//! loads stub function pointers through [load_with](crate::load_with), and every call made through
+//! rgl is then recorded with its decoded arguments, so render code can be unit tested against the
+//! exact call sequence it produces:
+//! ```
+//! use rgl::mock::MockContext;
+//! use rgl::prelude::*;
+//!
+//! let context = MockContext::install();
+//! let mut vbo = Buffer::default();
+//! gen_buffers(std::slice::from_mut(&mut vbo));
+//! bind_buffer(BufferBindingTarget::Array, vbo);
+//! assert_eq!(
+//!     context.log(),
+//!     ["GenBuffers(1, [1])", "BindBuffer(ARRAY_BUFFER, 1)"]
+//! );
+//! ```
+//!
+//! The stubs keep a tiny fake object namespace, so names returned by the `gen_*` and `create_*`
+//! functions can be bound and deleted, the `is_*` functions answer plausibly, buffer contents and
+//! object labels round trip, and misuse such as binding an unknown name is reported through
+//! [get_error]. Other state is not simulated: shaders always compile, programs always link, debug
+//! messages are never generated, and most queries leave their out parameters untouched.
+//!
+//! Mock state is kept per thread, so tests running in parallel each see their own log. Functions
+//! that rgl does not call are not stubbed, and panic with `gl function was not loaded`. The stubs
+//! replace the global function pointers, so a [MockContext] can not share a process with a real
+//! context.
+
+use crate::prelude::*;
+use gl::types::*;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::os::raw::c_void;
+
+/// # Decoded argument of a [MockCall]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockArg {
+    /// A `GLenum`, with its name if it is one used by rgl
+    Enum {
+        value: GLenum,
+        name: Option<&'static str>,
+    },
+
+    /// A `GLbitfield`, with the names of its known bits and the remaining unknown bits
+    Bitfield {
+        value: GLbitfield,
+        names: Vec<&'static str>,
+        unknown: GLbitfield,
+    },
+
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+
+    /// A nul terminated or sized `GLchar` string
+    Str(String),
+
+    /// The contents of a sized data pointer
+    Bytes(Vec<u8>),
+
+    /// The elements of an array pointer
+    Array(Vec<MockArg>),
+
+    /// A pointer with undecoded contents
+    Pointer(usize),
+
+    /// An out parameter
+    Out,
+}
+
+impl From<i32> for MockArg {
+    fn from(value: i32) -> Self {
+        MockArg::Int(value as i64)
+    }
+}
+
+impl From<i64> for MockArg {
+    fn from(value: i64) -> Self {
+        MockArg::Int(value)
+    }
+}
+
+impl From<isize> for MockArg {
+    fn from(value: isize) -> Self {
+        MockArg::Int(value as i64)
+    }
+}
+
+impl From<u32> for MockArg {
+    fn from(value: u32) -> Self {
+        MockArg::UInt(value as u64)
+    }
+}
+
+impl From<f32> for MockArg {
+    fn from(value: f32) -> Self {
+        MockArg::Float(value as f64)
+    }
+}
+
+impl From<f64> for MockArg {
+    fn from(value: f64) -> Self {
+        MockArg::Float(value)
+    }
+}
+
+fn write_list<T: std::fmt::Display>(f: &mut std::fmt::Formatter, items: &[T]) -> std::fmt::Result {
+    write!(f, "[")?;
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    write!(f, "]")
+}
+
+impl std::fmt::Display for MockArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MockArg::Enum {
+                name: Some(name), ..
+            } => write!(f, "{name}"),
+            MockArg::Enum { value, name: None } => write!(f, "{value:#06X}"),
+            MockArg::Bitfield { names, unknown, .. } => {
+                let mut parts: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+                if *unknown != 0 || parts.is_empty() {
+                    parts.push(format!("{unknown:#X}"));
+                }
+                write!(f, "{}", parts.join(" | "))
+            }
+            MockArg::Bool(true) => write!(f, "TRUE"),
+            MockArg::Bool(false) => write!(f, "FALSE"),
+            MockArg::Int(value) => write!(f, "{value}"),
+            MockArg::UInt(value) => write!(f, "{value}"),
+            MockArg::Float(value) => write!(f, "{value:?}"),
+            MockArg::Str(value) => write!(f, "{value:?}"),
+            MockArg::Bytes(bytes) => write_list(f, bytes),
+            MockArg::Array(items) => write_list(f, items),
+            MockArg::Pointer(0) => write!(f, "NULL"),
+            MockArg::Pointer(_) => write!(f, "<pointer>"),
+            MockArg::Out => write!(f, "_"),
+        }
+    }
+}
+
+/// # Recorded call of a [MockContext]
+/// Displays like the C call without its `gl` prefix, e.g. `BindBuffer(ARRAY_BUFFER, 3)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockCall {
+    /// The function name, without its `gl` prefix
+    pub name: &'static str,
+    pub args: Vec<MockArg>,
+}
+
+impl std::fmt::Display for MockCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (i, arg) in self.args.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{arg}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+macro_rules! names {
+    ($($name:ident),* $(,)?) => {
+        &[$((gl::$name, stringify!($name))),*]
+    };
+}
+
+/// Names of the enums used by rgl, apart from the ambiguous values in [PRIMITIVES] and [FACTORS]
+#[rustfmt::skip]
+const ENUMS: &[(GLenum, &str)] = names![
+    ACTIVE_ATTRIBUTES, ACTIVE_ATTRIBUTE_MAX_LENGTH, ACTIVE_RESOURCES, ACTIVE_TEXTURE,
+    ACTIVE_UNIFORMS, ACTIVE_UNIFORM_BLOCKS, ACTIVE_UNIFORM_MAX_LENGTH, ACTIVE_VARIABLES,
+    ALIASED_LINE_WIDTH_RANGE, ALPHA, ALWAYS, ARRAY_BUFFER, ARRAY_BUFFER_BINDING, ARRAY_SIZE,
+    ARRAY_STRIDE, ATOMIC_COUNTER_BUFFER, ATOMIC_COUNTER_BUFFER_INDEX, ATTACHED_SHADERS, BACK,
+    BACK_LEFT, BACK_RIGHT, BGR, BGRA, BGRA_INTEGER, BGR_INTEGER, BLEND, BLEND_COLOR,
+    BLEND_DST_ALPHA, BLEND_DST_RGB, BLEND_EQUATION_ALPHA, BLEND_EQUATION_RGB, BLEND_SRC_ALPHA,
+    BLEND_SRC_RGB, BLOCK_INDEX, BLUE, BUFFER, BUFFER_ACCESS, BUFFER_BINDING, BUFFER_DATA_SIZE,
+    BUFFER_IMMUTABLE_STORAGE, BUFFER_MAPPED, BUFFER_MAP_LENGTH, BUFFER_MAP_OFFSET,
+    BUFFER_MAP_POINTER, BUFFER_SIZE, BUFFER_STORAGE_FLAGS, BUFFER_USAGE, BUFFER_VARIABLE, BYTE, CCW,
+    CLAMP_TO_BORDER, CLAMP_TO_EDGE, COLOR, COLOR_ATTACHMENT0, COLOR_ATTACHMENT1, COLOR_ATTACHMENT2,
+    COLOR_ATTACHMENT31, COLOR_CLEAR_VALUE, COLOR_LOGIC_OP, COLOR_WRITEMASK, COMPARE_REF_TO_TEXTURE,
+    COMPATIBLE_SUBROUTINES, COMPILE_STATUS, COMPRESSED_RED, COMPRESSED_RED_RGTC1, COMPRESSED_RG,
+    COMPRESSED_RGB, COMPRESSED_RGBA, COMPRESSED_RGBA_BPTC_UNORM, COMPRESSED_RGB_BPTC_SIGNED_FLOAT,
+    COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT, COMPRESSED_RG_RGTC2, COMPRESSED_SIGNED_RED_RGTC1,
+    COMPRESSED_SIGNED_RG_RGTC2, COMPRESSED_SRGB, COMPRESSED_SRGB_ALPHA,
+    COMPRESSED_SRGB_ALPHA_BPTC_UNORM, COMPUTE_SHADER, COMPUTE_SUBROUTINE,
+    COMPUTE_SUBROUTINE_UNIFORM, CONSTANT_ALPHA, CONSTANT_COLOR, COPY_READ_BUFFER, COPY_WRITE_BUFFER,
+    CULL_FACE, CULL_FACE_MODE, CW, DEBUG_CALLBACK_FUNCTION, DEBUG_CALLBACK_USER_PARAM,
+    DEBUG_LOGGED_MESSAGES, DEBUG_OUTPUT, DEBUG_OUTPUT_SYNCHRONOUS, DEBUG_SEVERITY_HIGH,
+    DEBUG_SEVERITY_LOW, DEBUG_SEVERITY_MEDIUM, DEBUG_SEVERITY_NOTIFICATION, DEBUG_SOURCE_API,
+    DEBUG_SOURCE_APPLICATION, DEBUG_SOURCE_OTHER, DEBUG_SOURCE_SHADER_COMPILER,
+    DEBUG_SOURCE_THIRD_PARTY, DEBUG_SOURCE_WINDOW_SYSTEM, DEBUG_TYPE_DEPRECATED_BEHAVIOR,
+    DEBUG_TYPE_ERROR, DEBUG_TYPE_MARKER, DEBUG_TYPE_OTHER, DEBUG_TYPE_PERFORMANCE,
+    DEBUG_TYPE_POP_GROUP, DEBUG_TYPE_PORTABILITY, DEBUG_TYPE_PUSH_GROUP,
+    DEBUG_TYPE_UNDEFINED_BEHAVIOR, DECR, DECR_WRAP, DELETE_STATUS, DEPTH, DEPTH24_STENCIL8,
+    DEPTH32F_STENCIL8, DEPTH_ATTACHMENT, DEPTH_CLAMP, DEPTH_CLEAR_VALUE, DEPTH_COMPONENT,
+    DEPTH_COMPONENT16, DEPTH_COMPONENT24, DEPTH_COMPONENT32, DEPTH_COMPONENT32F, DEPTH_RANGE,
+    DEPTH_STENCIL, DEPTH_STENCIL_ATTACHMENT, DEPTH_STENCIL_TEXTURE_MODE, DEPTH_TEST,
+    DEPTH_WRITEMASK, DISPATCH_INDIRECT_BUFFER, DITHER, DONT_CARE, DOUBLE, DRAW_BUFFER0,
+    DRAW_FRAMEBUFFER, DRAW_FRAMEBUFFER_BINDING, DRAW_INDIRECT_BUFFER, DST_ALPHA, DST_COLOR,
+    DYNAMIC_COPY, DYNAMIC_DRAW, DYNAMIC_READ, ELEMENT_ARRAY_BUFFER, EQUAL, FILL,
+    FIRST_VERTEX_CONVENTION, FIXED, FLOAT, FLOAT_32_UNSIGNED_INT_24_8_REV, FRAGMENT_SHADER,
+    FRAGMENT_SUBROUTINE, FRAGMENT_SUBROUTINE_UNIFORM, FRAMEBUFFER,
+    FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING, FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE,
+    FRAMEBUFFER_ATTACHMENT_LAYERED, FRAMEBUFFER_ATTACHMENT_OBJECT_NAME,
+    FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE, FRAMEBUFFER_ATTACHMENT_TEXTURE_CUBE_MAP_FACE,
+    FRAMEBUFFER_ATTACHMENT_TEXTURE_LAYER, FRAMEBUFFER_ATTACHMENT_TEXTURE_LEVEL,
+    FRAMEBUFFER_COMPLETE, FRAMEBUFFER_INCOMPLETE_ATTACHMENT, FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER,
+    FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS, FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT,
+    FRAMEBUFFER_INCOMPLETE_MULTISAMPLE, FRAMEBUFFER_INCOMPLETE_READ_BUFFER, FRAMEBUFFER_SRGB,
+    FRAMEBUFFER_UNDEFINED, FRAMEBUFFER_UNSUPPORTED, FRONT, FRONT_AND_BACK, FRONT_FACE, FRONT_LEFT,
+    FRONT_RIGHT, FUNC_ADD, FUNC_REVERSE_SUBTRACT, FUNC_SUBTRACT, GEOMETRY_SHADER,
+    GEOMETRY_SUBROUTINE, GEOMETRY_SUBROUTINE_UNIFORM, GEQUAL, GREATER, GREEN, HALF_FLOAT, INCR,
+    INCR_WRAP, INFO_LOG_LENGTH, INT, INT_2_10_10_10_REV, INVALID_ENUM,
+    INVALID_FRAMEBUFFER_OPERATION, INVALID_OPERATION, INVALID_VALUE, INVERT, IS_PER_PATCH,
+    IS_ROW_MAJOR, KEEP, LAST_VERTEX_CONVENTION, LEFT, LEQUAL, LESS, LINE, LINEAR,
+    LINEAR_MIPMAP_LINEAR, LINEAR_MIPMAP_NEAREST, LINE_SMOOTH, LINE_WIDTH, LINK_STATUS, LOCATION,
+    LOCATION_COMPONENT, LOCATION_INDEX, LOWER_LEFT, MATRIX_STRIDE, MAX, MAX_3D_TEXTURE_SIZE,
+    MAX_ARRAY_TEXTURE_LAYERS, MAX_COLOR_ATTACHMENTS, MAX_COMBINED_TEXTURE_IMAGE_UNITS,
+    MAX_DEBUG_MESSAGE_LENGTH, MAX_DRAW_BUFFERS, MAX_ELEMENT_INDEX, MAX_LABEL_LENGTH,
+    MAX_NAME_LENGTH, MAX_NUM_ACTIVE_VARIABLES, MAX_NUM_COMPATIBLE_SUBROUTINES,
+    MAX_RENDERBUFFER_SIZE, MAX_SAMPLES, MAX_SAMPLE_MASK_WORDS, MAX_SHADER_STORAGE_BUFFER_BINDINGS,
+    MAX_TEXTURE_SIZE, MAX_UNIFORM_BUFFER_BINDINGS, MAX_VIEWPORTS, MAX_VIEWPORT_DIMS, MIN,
+    MIN_SAMPLE_SHADING_VALUE, MIRRORED_REPEAT, MIRROR_CLAMP_TO_EDGE, MULTISAMPLE, NAME_LENGTH,
+    NEAREST, NEAREST_MIPMAP_LINEAR, NEAREST_MIPMAP_NEAREST, NEVER, NONE, NOTEQUAL,
+    NUM_ACTIVE_VARIABLES, NUM_COMPATIBLE_SUBROUTINES, OFFSET, ONE_MINUS_CONSTANT_ALPHA,
+    ONE_MINUS_CONSTANT_COLOR, ONE_MINUS_DST_ALPHA, ONE_MINUS_DST_COLOR, ONE_MINUS_SRC1_ALPHA,
+    ONE_MINUS_SRC1_COLOR, ONE_MINUS_SRC_ALPHA, ONE_MINUS_SRC_COLOR, OUT_OF_MEMORY, PACK_ALIGNMENT,
+    PACK_IMAGE_HEIGHT, PACK_ROW_LENGTH, PACK_SKIP_IMAGES, PACK_SKIP_PIXELS, PACK_SKIP_ROWS,
+    PIXEL_PACK_BUFFER, PIXEL_UNPACK_BUFFER, POINT, POINT_FADE_THRESHOLD_SIZE, POINT_SIZE,
+    POINT_SIZE_RANGE, POINT_SPRITE_COORD_ORIGIN, POLYGON_MODE, POLYGON_OFFSET_FACTOR,
+    POLYGON_OFFSET_FILL, POLYGON_OFFSET_LINE, POLYGON_OFFSET_POINT, POLYGON_OFFSET_UNITS,
+    POLYGON_SMOOTH, PRIMITIVE_RESTART, PRIMITIVE_RESTART_FIXED_INDEX, PROGRAM, PROGRAM_INPUT,
+    PROGRAM_OUTPUT, PROGRAM_POINT_SIZE, PROVOKING_VERTEX, PROXY_TEXTURE_1D, PROXY_TEXTURE_1D_ARRAY,
+    PROXY_TEXTURE_2D, PROXY_TEXTURE_2D_ARRAY, PROXY_TEXTURE_2D_MULTISAMPLE, PROXY_TEXTURE_3D,
+    PROXY_TEXTURE_CUBE_MAP, PROXY_TEXTURE_CUBE_MAP_ARRAY, PROXY_TEXTURE_RECTANGLE, QUERY_BUFFER,
+    R11F_G11F_B10F, R16, R16F, R16I, R16UI, R16_SNORM, R32F, R32I, R32UI, R3_G3_B2, R8, R8I, R8UI,
+    R8_SNORM, RASTERIZER_DISCARD, READ_BUFFER, READ_FRAMEBUFFER, READ_FRAMEBUFFER_BINDING,
+    READ_ONLY, READ_WRITE, RED, RED_INTEGER, REFERENCED_BY_COMPUTE_SHADER,
+    REFERENCED_BY_FRAGMENT_SHADER, REFERENCED_BY_GEOMETRY_SHADER, REFERENCED_BY_TESS_CONTROL_SHADER,
+    REFERENCED_BY_TESS_EVALUATION_SHADER, REFERENCED_BY_VERTEX_SHADER, RENDERBUFFER,
+    RENDERBUFFER_ALPHA_SIZE, RENDERBUFFER_BINDING, RENDERBUFFER_BLUE_SIZE, RENDERBUFFER_DEPTH_SIZE,
+    RENDERBUFFER_GREEN_SIZE, RENDERBUFFER_HEIGHT, RENDERBUFFER_INTERNAL_FORMAT,
+    RENDERBUFFER_RED_SIZE, RENDERBUFFER_SAMPLES, RENDERBUFFER_STENCIL_SIZE, RENDERBUFFER_WIDTH,
+    REPEAT, REPLACE, RG, RG16, RG16F, RG16I, RG16UI, RG16_SNORM, RG32F, RG32I, RG32UI, RG8, RG8I,
+    RG8UI, RG8_SNORM, RGB, RGB10, RGB10_A2, RGB10_A2UI, RGB12, RGB16F, RGB16I, RGB16UI, RGB16_SNORM,
+    RGB32F, RGB32I, RGB32UI, RGB4, RGB5, RGB565, RGB5_A1, RGB8, RGB8I, RGB8UI, RGB8_SNORM, RGB9_E5,
+    RGBA, RGBA12, RGBA16, RGBA16F, RGBA16I, RGBA16UI, RGBA2, RGBA32F, RGBA32I, RGBA32UI, RGBA4,
+    RGBA8, RGBA8I, RGBA8UI, RGBA8_SNORM, RGBA_INTEGER, RGB_INTEGER, RG_INTEGER, RIGHT, SAMPLER,
+    SAMPLES, SAMPLE_ALPHA_TO_COVERAGE, SAMPLE_ALPHA_TO_ONE, SAMPLE_COVERAGE, SAMPLE_COVERAGE_INVERT,
+    SAMPLE_COVERAGE_VALUE, SAMPLE_MASK, SAMPLE_MASK_VALUE, SAMPLE_POSITION, SAMPLE_SHADING,
+    SCISSOR_BOX, SCISSOR_TEST, SHADER, SHADER_STORAGE_BLOCK, SHADER_STORAGE_BUFFER, SHADER_TYPE,
+    SHORT, SMOOTH_LINE_WIDTH_RANGE, SRC1_ALPHA, SRC1_COLOR, SRC_ALPHA, SRC_ALPHA_SATURATE,
+    SRC_COLOR, SRGB8, SRGB8_ALPHA8, STACK_OVERFLOW, STACK_UNDERFLOW, STATIC_COPY, STATIC_DRAW,
+    STATIC_READ, STENCIL, STENCIL_ATTACHMENT, STENCIL_BACK_PASS_DEPTH_FAIL, STENCIL_CLEAR_VALUE,
+    STENCIL_INDEX, STENCIL_INDEX8, STENCIL_TEST, STREAM_COPY, STREAM_DRAW, STREAM_READ,
+    TESS_CONTROL_SHADER, TESS_CONTROL_SUBROUTINE, TESS_CONTROL_SUBROUTINE_UNIFORM,
+    TESS_EVALUATION_SHADER, TESS_EVALUATION_SUBROUTINE, TESS_EVALUATION_SUBROUTINE_UNIFORM, TEXTURE,
+    TEXTURE0, TEXTURE_1D, TEXTURE_1D_ARRAY, TEXTURE_2D, TEXTURE_2D_ARRAY, TEXTURE_2D_MULTISAMPLE,
+    TEXTURE_2D_MULTISAMPLE_ARRAY, TEXTURE_3D, TEXTURE_ALPHA_SIZE, TEXTURE_BASE_LEVEL,
+    TEXTURE_BINDING_2D, TEXTURE_BINDING_2D_ARRAY, TEXTURE_BINDING_2D_MULTISAMPLE,
+    TEXTURE_BLUE_SIZE, TEXTURE_BORDER_COLOR, TEXTURE_BUFFER, TEXTURE_COMPARE_FUNC,
+    TEXTURE_COMPARE_MODE, TEXTURE_COMPRESSED, TEXTURE_COMPRESSED_IMAGE_SIZE, TEXTURE_CUBE_MAP,
+    TEXTURE_CUBE_MAP_ARRAY, TEXTURE_CUBE_MAP_NEGATIVE_X, TEXTURE_CUBE_MAP_NEGATIVE_Y,
+    TEXTURE_CUBE_MAP_NEGATIVE_Z, TEXTURE_CUBE_MAP_POSITIVE_X, TEXTURE_CUBE_MAP_POSITIVE_Y,
+    TEXTURE_CUBE_MAP_POSITIVE_Z, TEXTURE_CUBE_MAP_SEAMLESS, TEXTURE_DEPTH, TEXTURE_DEPTH_SIZE,
+    TEXTURE_GREEN_SIZE, TEXTURE_HEIGHT, TEXTURE_IMMUTABLE_FORMAT, TEXTURE_IMMUTABLE_LEVELS,
+    TEXTURE_INTERNAL_FORMAT, TEXTURE_LOD_BIAS, TEXTURE_MAG_FILTER, TEXTURE_MAX_LEVEL,
+    TEXTURE_MAX_LOD, TEXTURE_MIN_FILTER, TEXTURE_MIN_LOD, TEXTURE_RECTANGLE, TEXTURE_RED_SIZE,
+    TEXTURE_STENCIL_SIZE, TEXTURE_SWIZZLE_A, TEXTURE_SWIZZLE_B, TEXTURE_SWIZZLE_G,
+    TEXTURE_SWIZZLE_R, TEXTURE_SWIZZLE_RGBA, TEXTURE_WIDTH, TEXTURE_WRAP_R, TEXTURE_WRAP_S,
+    TEXTURE_WRAP_T, TOP_LEVEL_ARRAY_SIZE, TOP_LEVEL_ARRAY_STRIDE, TRANSFORM_FEEDBACK_BUFFER,
+    TRANSFORM_FEEDBACK_BUFFER_INDEX, TRANSFORM_FEEDBACK_BUFFER_STRIDE, TRANSFORM_FEEDBACK_VARYING,
+    TYPE, UNIFORM, UNIFORM_BLOCK, UNIFORM_BLOCK_ACTIVE_UNIFORMS,
+    UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES, UNIFORM_BLOCK_BINDING, UNIFORM_BLOCK_DATA_SIZE,
+    UNIFORM_BLOCK_NAME_LENGTH, UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER,
+    UNIFORM_BLOCK_REFERENCED_BY_FRAGMENT_SHADER, UNIFORM_BLOCK_REFERENCED_BY_GEOMETRY_SHADER,
+    UNIFORM_BLOCK_REFERENCED_BY_TESS_CONTROL_SHADER,
+    UNIFORM_BLOCK_REFERENCED_BY_TESS_EVALUATION_SHADER, UNIFORM_BLOCK_REFERENCED_BY_VERTEX_SHADER,
+    UNIFORM_BUFFER, UNIFORM_BUFFER_SIZE, UNPACK_ALIGNMENT, UNPACK_IMAGE_HEIGHT, UNPACK_ROW_LENGTH,
+    UNPACK_SKIP_IMAGES, UNPACK_SKIP_PIXELS, UNPACK_SKIP_ROWS, UNSIGNED_BYTE,
+    UNSIGNED_BYTE_2_3_3_REV, UNSIGNED_BYTE_3_3_2, UNSIGNED_INT, UNSIGNED_INT_10F_11F_11F_REV,
+    UNSIGNED_INT_10_10_10_2, UNSIGNED_INT_24_8, UNSIGNED_INT_2_10_10_10_REV,
+    UNSIGNED_INT_5_9_9_9_REV, UNSIGNED_INT_8_8_8_8, UNSIGNED_INT_8_8_8_8_REV, UNSIGNED_SHORT,
+    UNSIGNED_SHORT_1_5_5_5_REV, UNSIGNED_SHORT_4_4_4_4, UNSIGNED_SHORT_4_4_4_4_REV,
+    UNSIGNED_SHORT_5_5_5_1, UNSIGNED_SHORT_5_6_5, UNSIGNED_SHORT_5_6_5_REV, UPPER_LEFT,
+    VALIDATE_STATUS, VERTEX_ARRAY, VERTEX_ARRAY_BINDING, VERTEX_SHADER, VERTEX_SUBROUTINE,
+    VERTEX_SUBROUTINE_UNIFORM, VIEWPORT, WRITE_ONLY,
+];
+
+#[rustfmt::skip]
+const PRIMITIVES: &[(GLenum, &str)] = names![
+    POINTS, LINES, LINE_LOOP, LINE_STRIP, TRIANGLES, TRIANGLE_STRIP, TRIANGLE_FAN,
+    LINES_ADJACENCY, LINE_STRIP_ADJACENCY, TRIANGLES_ADJACENCY, TRIANGLE_STRIP_ADJACENCY, PATCHES,
+];
+
+#[rustfmt::skip]
+const TEXTURE_UNITS: &[(GLenum, &str)] = names![
+    TEXTURE0, TEXTURE1, TEXTURE2, TEXTURE3, TEXTURE4, TEXTURE5, TEXTURE6, TEXTURE7, TEXTURE8,
+    TEXTURE9, TEXTURE10, TEXTURE11, TEXTURE12, TEXTURE13, TEXTURE14, TEXTURE15, TEXTURE16,
+    TEXTURE17, TEXTURE18, TEXTURE19, TEXTURE20, TEXTURE21, TEXTURE22, TEXTURE23, TEXTURE24,
+    TEXTURE25, TEXTURE26, TEXTURE27, TEXTURE28, TEXTURE29, TEXTURE30, TEXTURE31,
+];
+
+/// Blend factors and stencil operations that collide with other enums
+const FACTORS: &[(GLenum, &str)] = names![ZERO, ONE];
+
+const CLEAR_BITS: &[(GLbitfield, &str)] =
+    names![COLOR_BUFFER_BIT, DEPTH_BUFFER_BIT, STENCIL_BUFFER_BIT];
+
+#[rustfmt::skip]
+const MAP_BITS: &[(GLbitfield, &str)] = names![
+    MAP_READ_BIT, MAP_WRITE_BIT, MAP_INVALIDATE_RANGE_BIT, MAP_INVALIDATE_BUFFER_BIT,
+    MAP_FLUSH_EXPLICIT_BIT, MAP_UNSYNCHRONIZED_BIT, MAP_PERSISTENT_BIT, MAP_COHERENT_BIT,
+];
+
+#[rustfmt::skip]
+const STORAGE_BITS: &[(GLbitfield, &str)] = names![
+    DYNAMIC_STORAGE_BIT, MAP_READ_BIT, MAP_WRITE_BIT, MAP_PERSISTENT_BIT, MAP_COHERENT_BIT,
+    CLIENT_STORAGE_BIT,
+];
+
+fn enum_in(value: GLenum, table: &[(GLenum, &'static str)]) -> MockArg {
+    let name = table
+        .iter()
+        .chain(ENUMS)
+        .find(|(known, _)| *known == value)
+        .map(|(_, name)| *name);
+    MockArg::Enum { value, name }
+}
+
+fn enum_(value: GLenum) -> MockArg {
+    enum_in(value, &[])
+}
+
+fn bits(value: GLbitfield, table: &[(GLbitfield, &'static str)]) -> MockArg {
+    let names = table
+        .iter()
+        .filter(|(bit, _)| value & bit != 0)
+        .map(|(_, name)| *name)
+        .collect();
+    let unknown = table.iter().fold(value, |value, (bit, _)| value & !bit);
+    MockArg::Bitfield {
+        value,
+        names,
+        unknown,
+    }
+}
+
+fn boolean(value: GLboolean) -> MockArg {
+    MockArg::Bool(value != gl::FALSE)
+}
+
+/// A `GLint` parameter that holds an enum for some `pname`s
+fn parameter(pname: GLenum, param: GLint) -> MockArg {
+    match pname {
+        gl::DEPTH_STENCIL_TEXTURE_MODE
+        | gl::POINT_SPRITE_COORD_ORIGIN
+        | gl::TEXTURE_COMPARE_FUNC
+        | gl::TEXTURE_COMPARE_MODE
+        | gl::TEXTURE_MIN_FILTER
+        | gl::TEXTURE_MAG_FILTER
+        | gl::TEXTURE_SWIZZLE_R
+        | gl::TEXTURE_SWIZZLE_G
+        | gl::TEXTURE_SWIZZLE_B
+        | gl::TEXTURE_SWIZZLE_A
+        | gl::TEXTURE_WRAP_S
+        | gl::TEXTURE_WRAP_T
+        | gl::TEXTURE_WRAP_R => enum_(param as GLenum),
+        _ => MockArg::from(param),
+    }
+}
+
+/// A pointer argument that is really a byte offset into a bound buffer
+fn offset(pointer: *const c_void) -> MockArg {
+    MockArg::UInt(pointer as usize as u64)
+}
+
+fn pointer<T>(pointer: *const T) -> MockArg {
+    MockArg::Pointer(pointer as usize)
+}
+
+fn len(count: impl TryInto<usize>) -> usize {
+    count.try_into().unwrap_or(0)
+}
+
+/// The number of values read by the `glClearBuffer*` functions for `buffer`
+fn clear_len(buffer: GLenum) -> usize {
+    if buffer == gl::COLOR {
+        4
+    } else {
+        1
+    }
+}
+
+fn array<T: Copy + Into<MockArg>>(pointer: *const T, len: usize) -> MockArg {
+    if pointer.is_null() {
+        return MockArg::Pointer(0);
+    }
+
+    // SAFE: rgl passes `len` readable elements, as required by the stubbed GL function
+    let values = unsafe { std::slice::from_raw_parts(pointer, len) };
+    MockArg::Array(values.iter().map(|value| (*value).into()).collect())
+}
+
+fn enums(pointer: *const GLenum, len: usize) -> MockArg {
+    if pointer.is_null() {
+        return MockArg::Pointer(0);
+    }
+
+    // SAFE: rgl passes `len` readable enums, as required by the stubbed GL function
+    let values = unsafe { std::slice::from_raw_parts(pointer, len) };
+    MockArg::Array(values.iter().map(|value| enum_(*value)).collect())
+}
+
+fn bytes(pointer: *const c_void, size: GLsizeiptr) -> MockArg {
+    if pointer.is_null() {
+        return MockArg::Pointer(0);
+    }
+
+    // SAFE: rgl passes `size` readable bytes, as required by the stubbed GL function
+    let bytes = unsafe { std::slice::from_raw_parts(pointer as *const u8, len(size)) };
+    MockArg::Bytes(bytes.to_vec())
+}
+
+fn string(pointer: *const GLchar) -> MockArg {
+    // SAFE: rgl passes a nul terminated string
+    let string = unsafe { std::ffi::CStr::from_ptr(pointer) };
+    MockArg::Str(string.to_string_lossy().into_owned())
+}
+
+fn text(pointer: *const GLchar, length: GLsizei) -> MockArg {
+    if length < 0 {
+        return string(pointer);
+    }
+
+    // SAFE: rgl passes `length` readable bytes
+    let bytes = unsafe { std::slice::from_raw_parts(pointer as *const u8, len(length)) };
+    MockArg::Str(String::from_utf8_lossy(bytes).into_owned())
+}
+
+fn strings(count: GLsizei, strings: *const *const GLchar, lengths: *const GLint) -> MockArg {
+    let strings = (0..len(count))
+        .map(|i| {
+            // SAFE: rgl passes `count` strings, with `count` lengths
+            let (string, length) = unsafe { (*strings.add(i), *lengths.add(i)) };
+            // SAFE: `string` has `length` readable bytes
+            let bytes = unsafe { std::slice::from_raw_parts(string as *const u8, len(length)) };
+            MockArg::Str(String::from_utf8_lossy(bytes).into_owned())
+        })
+        .collect();
+    MockArg::Array(strings)
+}
+
+fn write<T>(pointer: *mut T, value: T) {
+    if !pointer.is_null() {
+        // SAFE: rgl passes a writable out parameter
+        unsafe { pointer.write(value) }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Kind {
+    Buffer,
+    Texture,
+    VertexArray,
+    Framebuffer,
+    Renderbuffer,
+    Sampler,
+}
+
+#[derive(Default)]
+struct BufferStore {
+    data: Vec<u8>,
+    usage: GLenum,
+    flags: GLbitfield,
+    immutable: bool,
+    mapping: Option<(usize, usize, GLbitfield)>,
+}
+
+impl BufferStore {
+    fn range(&self, offset: GLintptr, size: GLsizeiptr) -> Result<std::ops::Range<usize>, GLenum> {
+        let start = usize::try_from(offset).map_err(|_| gl::INVALID_VALUE)?;
+        let size = usize::try_from(size).map_err(|_| gl::INVALID_VALUE)?;
+        match start.checked_add(size) {
+            Some(end) if end <= self.data.len() => Ok(start..end),
+            _ => Err(gl::INVALID_VALUE),
+        }
+    }
+
+    fn data(&mut self, size: GLsizeiptr, data: *const c_void, usage: GLenum) -> Result<(), GLenum> {
+        if self.immutable {
+            return Err(gl::INVALID_OPERATION);
+        }
+        let size = usize::try_from(size).map_err(|_| gl::INVALID_VALUE)?;
+        self.data = match bytes(data, size as GLsizeiptr) {
+            MockArg::Bytes(bytes) => bytes,
+            _ => vec![0; size],
+        };
+        self.usage = usage;
+        self.mapping = None;
+        Ok(())
+    }
+
+    fn storage(
+        &mut self,
+        size: GLsizeiptr,
+        data: *const c_void,
+        flags: GLbitfield,
+    ) -> Result<(), GLenum> {
+        if self.immutable {
+            return Err(gl::INVALID_OPERATION);
+        }
+        if size <= 0 {
+            return Err(gl::INVALID_VALUE);
+        }
+        self.data(size, data, gl::DYNAMIC_DRAW)?;
+        self.flags = flags;
+        self.immutable = true;
+        Ok(())
+    }
+
+    fn sub_data(
+        &mut self,
+        offset: GLintptr,
+        size: GLsizeiptr,
+        data: *const c_void,
+    ) -> Result<(), GLenum> {
+        let range = self.range(offset, size)?;
+        if let MockArg::Bytes(bytes) = bytes(data, size) {
+            self.data[range].copy_from_slice(&bytes);
+        }
+        Ok(())
+    }
+
+    fn get_sub_data(
+        &self,
+        offset: GLintptr,
+        size: GLsizeiptr,
+        data: *mut c_void,
+    ) -> Result<(), GLenum> {
+        let range = self.range(offset, size)?;
+        let source = &self.data[range];
+
+        // SAFE: rgl passes `size` writable bytes
+        unsafe { std::ptr::copy_nonoverlapping(source.as_ptr(), data as *mut u8, source.len()) };
+        Ok(())
+    }
+
+    fn map(
+        &mut self,
+        offset: GLintptr,
+        length: GLsizeiptr,
+        access: GLbitfield,
+    ) -> Result<usize, GLenum> {
+        if self.mapping.is_some() {
+            return Err(gl::INVALID_OPERATION);
+        }
+        let range = self.range(offset, length)?;
+        self.mapping = Some((range.start, range.len(), access));
+        Ok(self.pointer())
+    }
+
+    fn pointer(&mut self) -> usize {
+        match self.mapping {
+            Some((offset, _, _)) => self.data[offset..].as_mut_ptr() as usize,
+            None => 0,
+        }
+    }
+
+    fn unmap(&mut self) -> Result<GLboolean, GLenum> {
+        match self.mapping.take() {
+            Some(_) => Ok(gl::TRUE),
+            None => Err(gl::INVALID_OPERATION),
+        }
+    }
+
+    fn parameter(&self, pname: GLenum) -> Result<i64, GLenum> {
+        let (offset, length, access) = self.mapping.unwrap_or_default();
+        let value = match pname {
+            gl::BUFFER_ACCESS => {
+                let access = match access & (gl::MAP_READ_BIT | gl::MAP_WRITE_BIT) {
+                    gl::MAP_READ_BIT => gl::READ_ONLY,
+                    gl::MAP_WRITE_BIT => gl::WRITE_ONLY,
+                    _ => gl::READ_WRITE,
+                };
+                access as i64
+            }
+            gl::BUFFER_ACCESS_FLAGS => access as i64,
+            gl::BUFFER_IMMUTABLE_STORAGE => self.immutable as i64,
+            gl::BUFFER_MAPPED => self.mapping.is_some() as i64,
+            gl::BUFFER_MAP_LENGTH => length as i64,
+            gl::BUFFER_MAP_OFFSET => offset as i64,
+            gl::BUFFER_SIZE => self.data.len() as i64,
+            gl::BUFFER_STORAGE_FLAGS => self.flags as i64,
+            gl::BUFFER_USAGE => self.usage as i64,
+            _ => return Err(gl::INVALID_ENUM),
+        };
+        Ok(value)
+    }
+}
+
+enum Object {
+    /// A name returned by `Gen*`, that has not been bound yet
+    Reserved(Kind),
+    Buffer(Box<BufferStore>),
+    Texture,
+    VertexArray,
+    Framebuffer {
+        attachments: usize,
+    },
+    Renderbuffer,
+    Sampler,
+    Shader(GLenum),
+    Program(Vec<GLuint>),
+}
+
+impl Object {
+    fn new(kind: Kind) -> Object {
+        match kind {
+            Kind::Buffer => Object::Buffer(Box::default()),
+            Kind::Texture => Object::Texture,
+            Kind::VertexArray => Object::VertexArray,
+            Kind::Framebuffer => Object::Framebuffer { attachments: 0 },
+            Kind::Renderbuffer => Object::Renderbuffer,
+            Kind::Sampler => Object::Sampler,
+        }
+    }
+
+    fn kind(&self) -> Option<Kind> {
+        match self {
+            Object::Reserved(kind) => Some(*kind),
+            Object::Buffer(_) => Some(Kind::Buffer),
+            Object::Texture => Some(Kind::Texture),
+            Object::VertexArray => Some(Kind::VertexArray),
+            Object::Framebuffer { .. } => Some(Kind::Framebuffer),
+            Object::Renderbuffer => Some(Kind::Renderbuffer),
+            Object::Sampler => Some(Kind::Sampler),
+            Object::Shader(_) | Object::Program(_) => None,
+        }
+    }
+}
+
+/// Binding key of the vertex array, which has no target
+const VERTEX_ARRAY: GLenum = gl::VERTEX_ARRAY_BINDING;
+
+/// Binding key of the program in use, which has no target
+const PROGRAM: GLenum = gl::CURRENT_PROGRAM;
+
+#[derive(Default)]
+struct MockState {
+    calls: Vec<MockCall>,
+    error: GLenum,
+    last_name: GLuint,
+    objects: HashMap<GLuint, Object>,
+    bindings: HashMap<GLenum, GLuint>,
+    active_texture: GLuint,
+    labels: HashMap<(GLenum, GLuint), String>,
+    debug_callback: (usize, usize),
+    enabled: HashSet<GLenum>,
+}
+
+impl MockState {
+    fn error(&mut self, error: GLenum) {
+        if self.error == gl::NO_ERROR {
+            self.error = error;
+        }
+    }
+
+    fn gen(&mut self, count: GLsizei, names: *mut GLuint, kind: Kind) {
+        for i in 0..len(count) {
+            self.last_name += 1;
+            // sampler names are sampler objects as soon as they are generated
+            let object = match kind {
+                Kind::Sampler => Object::Sampler,
+                kind => Object::Reserved(kind),
+            };
+            self.objects.insert(self.last_name, object);
+            // SAFE: rgl passes `count` writable names
+            write(unsafe { names.add(i) }, self.last_name);
+        }
+        if count < 0 {
+            self.error(gl::INVALID_VALUE);
+        }
+    }
+
+    fn create(&mut self, object: Object) -> GLuint {
+        self.last_name += 1;
+        self.objects.insert(self.last_name, object);
+        self.last_name
+    }
+
+    fn delete(&mut self, count: GLsizei, names: *const GLuint, kind: Kind) {
+        if let MockArg::Array(names) = array(names, len(count)) {
+            for name in names {
+                if let MockArg::UInt(name) = name {
+                    self.delete_name(name as GLuint, Some(kind));
+                }
+            }
+        }
+        if count < 0 {
+            self.error(gl::INVALID_VALUE);
+        }
+    }
+
+    fn delete_name(&mut self, name: GLuint, kind: Option<Kind>) {
+        if name != 0 && self.objects.get(&name).map(Object::kind) == Some(kind) {
+            self.objects.remove(&name);
+            self.bindings.retain(|_, bound| *bound != name);
+        }
+    }
+
+    fn is(&self, name: GLuint, kind: Kind) -> GLboolean {
+        match self.objects.get(&name) {
+            Some(Object::Reserved(_)) | None => gl::FALSE,
+            Some(object) => GLboolean::from(object.kind() == Some(kind)),
+        }
+    }
+
+    fn bind(&mut self, target: GLenum, name: GLuint, kind: Kind) {
+        match self.objects.get(&name) {
+            _ if name == 0 => {}
+            Some(Object::Reserved(reserved)) if *reserved == kind => {
+                self.objects.insert(name, Object::new(kind));
+            }
+            Some(object) if object.kind() == Some(kind) => {}
+            _ => return self.error(gl::INVALID_OPERATION),
+        }
+        match target {
+            gl::FRAMEBUFFER => {
+                self.bindings.insert(gl::DRAW_FRAMEBUFFER, name);
+                self.bindings.insert(gl::READ_FRAMEBUFFER, name);
+            }
+            target => {
+                self.bindings.insert(target, name);
+            }
+        }
+    }
+
+    fn bound(&self, target: GLenum) -> GLuint {
+        let target = match target {
+            gl::FRAMEBUFFER => gl::DRAW_FRAMEBUFFER,
+            target => target,
+        };
+        self.bindings.get(&target).copied().unwrap_or(0)
+    }
+
+    fn buffer<T: Default>(
+        &mut self,
+        name: GLuint,
+        f: impl FnOnce(&mut BufferStore) -> Result<T, GLenum>,
+    ) -> T {
+        let result = match self.objects.get_mut(&name) {
+            Some(Object::Buffer(buffer)) => f(buffer),
+            _ => Err(gl::INVALID_OPERATION),
+        };
+        result.unwrap_or_else(|error| {
+            self.error(error);
+            T::default()
+        })
+    }
+
+    fn attach(&mut self, target: GLenum) {
+        self.attach_named(self.bound(target))
+    }
+
+    fn attach_named(&mut self, framebuffer: GLuint) {
+        match self.objects.get_mut(&framebuffer) {
+            Some(Object::Framebuffer { attachments }) => *attachments += 1,
+            _ => self.error(gl::INVALID_OPERATION),
+        }
+    }
+
+    /// Draw buffer indices are limited by the reported `GL_MAX_DRAW_BUFFERS` of 8
+    fn draw_buffer(&mut self, drawbuffer: GLint) {
+        if !(0..8).contains(&drawbuffer) {
+            self.error(gl::INVALID_VALUE);
+        }
+    }
+
+    /// Only blending is indexed by draw buffer, and the scissor test by viewport, which isn't limited
+    fn capability_index(&mut self, cap: GLenum, index: GLuint) {
+        match cap {
+            gl::BLEND => self.draw_buffer(index as GLint),
+            gl::SCISSOR_TEST => {}
+            _ => self.error(gl::INVALID_ENUM),
+        }
+    }
+
+    /// Named framebuffer functions accept zero, the default framebuffer, or a framebuffer object
+    fn framebuffer(&mut self, framebuffer: GLuint) -> bool {
+        let exists = framebuffer == 0 || self.is(framebuffer, Kind::Framebuffer) == gl::TRUE;
+        if !exists {
+            self.error(gl::INVALID_OPERATION);
+        }
+        exists
+    }
+
+    fn framebuffer_status(&self, framebuffer: GLuint) -> GLenum {
+        match self.objects.get(&framebuffer) {
+            Some(Object::Framebuffer { attachments: 0 }) => {
+                gl::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT
+            }
+            _ => gl::FRAMEBUFFER_COMPLETE,
+        }
+    }
+
+    fn shader(&mut self, name: GLuint) -> Option<GLenum> {
+        match self.objects.get(&name) {
+            Some(Object::Shader(ty)) => Some(*ty),
+            Some(_) => {
+                self.error(gl::INVALID_OPERATION);
+                None
+            }
+            None => {
+                self.error(gl::INVALID_VALUE);
+                None
+            }
+        }
+    }
+
+    fn program(&mut self, name: GLuint) -> Option<&mut Vec<GLuint>> {
+        match self.objects.get(&name) {
+            Some(Object::Program(_)) => {}
+            Some(_) => {
+                self.error(gl::INVALID_OPERATION);
+                return None;
+            }
+            None => {
+                self.error(gl::INVALID_VALUE);
+                return None;
+            }
+        }
+        match self.objects.get_mut(&name) {
+            Some(Object::Program(attached)) => Some(attached),
+            _ => None,
+        }
+    }
+
+    fn attach_shader(&mut self, program: GLuint, shader: GLuint) {
+        if self.shader(shader).is_none() {
+            return;
+        }
+        match self.program(program) {
+            Some(attached) if attached.contains(&shader) => self.error(gl::INVALID_OPERATION),
+            Some(attached) => attached.push(shader),
+            None => {}
+        }
+    }
+
+    fn detach_shader(&mut self, program: GLuint, shader: GLuint) {
+        if self.shader(shader).is_none() {
+            return;
+        }
+        match self.program(program) {
+            Some(attached) if attached.contains(&shader) => attached.retain(|s| *s != shader),
+            Some(_) => self.error(gl::INVALID_OPERATION),
+            None => {}
+        }
+    }
+
+    fn get_shader(&mut self, shader: GLuint, pname: GLenum, params: *mut GLint) {
+        let Some(ty) = self.shader(shader) else {
+            return;
+        };
+        match pname {
+            gl::SHADER_TYPE => write(params, ty as GLint),
+            gl::COMPILE_STATUS => write(params, gl::TRUE as GLint),
+            gl::DELETE_STATUS | gl::INFO_LOG_LENGTH | gl::SHADER_SOURCE_LENGTH => write(params, 0),
+            _ => self.error(gl::INVALID_ENUM),
+        }
+    }
+
+    fn get_program(&mut self, program: GLuint, pname: GLenum, params: *mut GLint) {
+        let Some(attached) = self.program(program) else {
+            return;
+        };
+        match pname {
+            gl::ATTACHED_SHADERS => write(params, attached.len() as GLint),
+            gl::LINK_STATUS | gl::VALIDATE_STATUS => write(params, gl::TRUE as GLint),
+            _ => write(params, 0),
+        }
+    }
+
+    /// Mock programs have no active resources, so every resource index is out of range
+    fn no_resource(&mut self, program: GLuint) {
+        if self.program(program).is_some() {
+            self.error(gl::INVALID_VALUE);
+        }
+    }
+
+    /// Direct state access texture functions generate INVALID_OPERATION for names that are not
+    /// texture objects
+    fn texture(&mut self, texture: GLuint) {
+        if self.is(texture, Kind::Texture) == gl::FALSE {
+            self.error(gl::INVALID_OPERATION);
+        }
+    }
+
+    /// Direct state access renderbuffer functions generate INVALID_OPERATION for names that are not
+    /// renderbuffer objects
+    fn renderbuffer(&mut self, renderbuffer: GLuint) {
+        if self.is(renderbuffer, Kind::Renderbuffer) == gl::FALSE {
+            self.error(gl::INVALID_OPERATION);
+        }
+    }
+
+    /// Sampler functions generate INVALID_OPERATION for names that are not sampler objects
+    fn sampler(&mut self, sampler: GLuint) {
+        if self.is(sampler, Kind::Sampler) == gl::FALSE {
+            self.error(gl::INVALID_OPERATION);
+        }
+    }
+
+    fn bind_samplers(&mut self, first: GLuint, count: GLsizei, samplers: *const GLuint) {
+        if first as usize + len(count) > 80 {
+            return self.error(gl::INVALID_OPERATION);
+        }
+        if let MockArg::Array(samplers) = array(samplers, len(count)) {
+            for sampler in samplers {
+                match sampler {
+                    MockArg::UInt(0) => {}
+                    MockArg::UInt(sampler) => self.sampler(sampler as GLuint),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn get_attached_shaders(
+        &mut self,
+        program: GLuint,
+        max_count: GLsizei,
+        count: *mut GLsizei,
+        shaders: *mut GLuint,
+    ) {
+        let Some(attached) = self.program(program) else {
+            return;
+        };
+        let attached = &attached[..attached.len().min(len(max_count))];
+        for (i, shader) in attached.iter().enumerate() {
+            // SAFE: rgl passes `max_count` writable names
+            write(unsafe { shaders.add(i) }, *shader);
+        }
+        write(count, attached.len() as GLsizei);
+    }
+
+    fn get_info_log(&mut self, length: *mut GLsizei, info_log: *mut GLchar, buf_size: GLsizei) {
+        write(length, 0);
+        if buf_size > 0 {
+            write(info_log, 0);
+        }
+    }
+
+    fn get_integer(&mut self, pname: GLenum, data: *mut GLint) {
+        match pname {
+            gl::ACTIVE_TEXTURE => write(data, (gl::TEXTURE0 + self.active_texture) as GLint),
+            gl::PACK_ALIGNMENT | gl::UNPACK_ALIGNMENT => write(data, 4),
+            gl::MAX_TEXTURE_SIZE => write(data, 16384),
+            gl::MAX_ARRAY_TEXTURE_LAYERS => write(data, 2048),
+            gl::MAX_3D_TEXTURE_SIZE => write(data, 2048),
+            gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS => write(data, 80),
+            gl::MAX_RENDERBUFFER_SIZE => write(data, 16384),
+            gl::MAX_SAMPLES => write(data, 8),
+            gl::MAX_COLOR_ATTACHMENTS | gl::MAX_DRAW_BUFFERS => write(data, 8),
+            gl::MAX_DEBUG_MESSAGE_LENGTH => write(data, 1024),
+            gl::MAX_LABEL_LENGTH => write(data, 256),
+            gl::MAX_SAMPLE_MASK_WORDS => write(data, 1),
+            gl::ARRAY_BUFFER_BINDING => write(data, self.bound(gl::ARRAY_BUFFER) as GLint),
+            gl::DRAW_FRAMEBUFFER_BINDING => write(data, self.bound(gl::DRAW_FRAMEBUFFER) as GLint),
+            gl::READ_FRAMEBUFFER_BINDING => write(data, self.bound(gl::READ_FRAMEBUFFER) as GLint),
+            gl::RENDERBUFFER_BINDING => write(data, self.bound(gl::RENDERBUFFER) as GLint),
+            gl::VERTEX_ARRAY_BINDING => write(data, self.bound(VERTEX_ARRAY) as GLint),
+            gl::MAX_UNIFORM_BUFFER_BINDINGS => write(data, 72),
+            gl::MAX_SHADER_STORAGE_BUFFER_BINDINGS => write(data, 8),
+            gl::CURRENT_PROGRAM => write(data, self.bound(PROGRAM) as GLint),
+            _ => {}
+        }
+    }
+
+    fn labelled(&mut self, identifier: GLenum, name: GLuint) -> bool {
+        let exists = match (identifier, self.objects.get(&name)) {
+            (gl::PROGRAM, Some(Object::Program(_))) | (gl::SHADER, Some(Object::Shader(_))) => true,
+            (_, Some(Object::Reserved(_))) | (_, None) => false,
+            (identifier, Some(object)) => {
+                let kind = match identifier {
+                    gl::BUFFER => Some(Kind::Buffer),
+                    gl::TEXTURE => Some(Kind::Texture),
+                    gl::VERTEX_ARRAY => Some(Kind::VertexArray),
+                    gl::FRAMEBUFFER => Some(Kind::Framebuffer),
+                    gl::RENDERBUFFER => Some(Kind::Renderbuffer),
+                    gl::SAMPLER => Some(Kind::Sampler),
+                    _ => None,
+                };
+                kind.is_some() && object.kind() == kind
+            }
+        };
+        if !exists {
+            self.error(gl::INVALID_VALUE);
+        }
+        exists
+    }
+
+    fn object_label(
+        &mut self,
+        identifier: GLenum,
+        name: GLuint,
+        length: GLsizei,
+        label: *const GLchar,
+    ) {
+        if !self.labelled(identifier, name) {
+            return;
+        }
+        match text(label, length) {
+            MockArg::Str(label) if !label.is_empty() => {
+                self.labels.insert((identifier, name), label);
+            }
+            _ => {
+                self.labels.remove(&(identifier, name));
+            }
+        }
+    }
+
+    fn get_object_label(
+        &mut self,
+        identifier: GLenum,
+        name: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        label: *mut GLchar,
+    ) {
+        if !self.labelled(identifier, name) {
+            return;
+        }
+        let stored = self
+            .labels
+            .get(&(identifier, name))
+            .cloned()
+            .unwrap_or_default();
+        if label.is_null() || buf_size <= 0 {
+            return write(length, stored.len() as GLsizei);
+        }
+        let copied = stored.len().min(len(buf_size) - 1);
+
+        // SAFE: rgl passes `buf_size` writable bytes
+        unsafe {
+            std::ptr::copy_nonoverlapping(stored.as_ptr(), label as *mut u8, copied);
+            label.add(copied).write(0);
+        }
+        write(length, copied as GLsizei);
+    }
+
+    fn use_program(&mut self, program: GLuint) {
+        if program == 0 || self.program(program).is_some() {
+            self.bindings.insert(PROGRAM, program);
+        }
+    }
+
+    fn rect(&mut self, width: GLsizei, height: GLsizei) {
+        if width < 0 || height < 0 {
+            self.error(gl::INVALID_VALUE);
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<MockState> = RefCell::default();
+}
+
+/// # Mock OpenGL context
+/// Installs the stub function pointers on creation, and gives access to the log of the calls made
+/// on this thread. Dropping the context clears the log and the fake object namespace.
+pub struct MockContext {
+    _thread: std::marker::PhantomData<*const ()>,
+}
+
+impl MockContext {
+    /// Install the stubs, and start a fresh log and object namespace for this thread
+    pub fn install() -> MockContext {
+        static INSTALL: std::sync::Once = std::sync::Once::new();
+        INSTALL.call_once(|| crate::load_with(stub));
+        STATE.set(MockState::default());
+        MockContext {
+            _thread: std::marker::PhantomData,
+        }
+    }
+
+    /// Every call recorded on this thread, oldest first
+    pub fn calls(&self) -> Vec<MockCall> {
+        STATE.with_borrow(|state| state.calls.clone())
+    }
+
+    /// Every call recorded on this thread, displayed like `BindBuffer(ARRAY_BUFFER, 3)`
+    pub fn log(&self) -> Vec<String> {
+        STATE.with_borrow(|state| state.calls.iter().map(MockCall::to_string).collect())
+    }
+
+    /// Forget the recorded calls, keeping the object namespace
+    pub fn clear_log(&self) {
+        STATE.with_borrow_mut(|state| state.calls.clear())
+    }
+
+    /// Record `error` as if the last call had generated it, to be returned by the next
+    /// [get_error]. Has no effect if an error is already recorded.
+    pub fn set_error(&self, error: Error) {
+        let error = match error {
+            Error::NoError => gl::NO_ERROR,
+            Error::InvalidEnum => gl::INVALID_ENUM,
+            Error::InvalidValue => gl::INVALID_VALUE,
+            Error::InvalidOperation => gl::INVALID_OPERATION,
+            Error::InvalidFramebufferOperation => gl::INVALID_FRAMEBUFFER_OPERATION,
+            Error::OutOfMemory => gl::OUT_OF_MEMORY,
+            Error::StackUnderflow => gl::STACK_UNDERFLOW,
+            Error::StackOverflow => gl::STACK_OVERFLOW,
+            Error::ImplementationSpecific(error) => error,
+        };
+        STATE.with_borrow_mut(|state| state.error(error))
+    }
+}
+
+impl Drop for MockContext {
+    fn drop(&mut self) {
+        STATE.set(MockState::default());
+    }
+}
+
+macro_rules! stubs {
+    ($(
+        fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?
+            [$($decoded:expr),* $(,)?] |$state:ident| $body:expr;
+    )*) => {
+        $(
+            #[allow(non_snake_case, unused_variables, clippy::let_unit_value)]
+            extern "system" fn $name($($arg: $ty),*) $(-> $ret)? {
+                STATE.with_borrow_mut(|$state| {
+                    let ret = $body;
+                    let args = vec![$(MockArg::from($decoded)),*];
+                    $state.calls.push(MockCall { name: stringify!($name), args });
+                    ret
+                })
+            }
+        )*
+
+        fn stub(symbol: &'static str) -> *const c_void {
+            match symbol.strip_prefix("gl") {
+                $(Some(stringify!($name)) => $name as *const c_void,)*
+                _ => std::ptr::null(),
+            }
+        }
+    };
+}
+
+stubs! {
+    fn ActiveTexture(texture: GLenum) [enum_in(texture, TEXTURE_UNITS)] |state| {
+        state.active_texture = texture.wrapping_sub(gl::TEXTURE0)
+    };
+    fn AttachShader(program: GLuint, shader: GLuint) [program, shader] |state| {
+        state.attach_shader(program, shader)
+    };
+    fn BindAttribLocation(program: GLuint, index: GLuint, name: *const GLchar)
+        [program, index, string(name)] |state| {
+        state.program(program);
+    };
+    fn BindBuffer(target: GLenum, buffer: GLuint) [enum_(target), buffer] |state| {
+        state.bind(target, buffer, Kind::Buffer)
+    };
+    fn BindBufferBase(target: GLenum, index: GLuint, buffer: GLuint)
+        [enum_(target), index, buffer] |state| state.bind(target, buffer, Kind::Buffer);
+    fn BindBufferRange(
+        target: GLenum,
+        index: GLuint,
+        buffer: GLuint,
+        offset: GLintptr,
+        size: GLsizeiptr
+    ) [enum_(target), index, buffer, offset, size] |state| {
+        state.bind(target, buffer, Kind::Buffer)
+    };
+    fn BindFramebuffer(target: GLenum, framebuffer: GLuint) [enum_(target), framebuffer] |state| {
+        state.bind(target, framebuffer, Kind::Framebuffer)
+    };
+    fn BindRenderbuffer(target: GLenum, renderbuffer: GLuint) [enum_(target), renderbuffer]
+        |state| state.bind(target, renderbuffer, Kind::Renderbuffer);
+    fn BindSampler(unit: GLuint, sampler: GLuint) [unit, sampler] |state| {
+        if unit >= 80 {
+            state.error(gl::INVALID_VALUE)
+        } else if sampler != 0 {
+            state.sampler(sampler)
+        }
+    };
+    fn BindSamplers(first: GLuint, count: GLsizei, samplers: *const GLuint)
+        [first, count, array(samplers, len(count))] |state| {
+        state.bind_samplers(first, count, samplers)
+    };
+    fn BindTexture(target: GLenum, texture: GLuint) [enum_(target), texture] |state| {
+        state.bind(target, texture, Kind::Texture)
+    };
+    fn BindVertexArray(array: GLuint) [array] |state| {
+        state.bind(VERTEX_ARRAY, array, Kind::VertexArray)
+    };
+    fn BlendColor(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat)
+        [red, green, blue, alpha] |state| ();
+    fn BlendEquation(mode: GLenum) [enum_(mode)] |state| ();
+    fn BlendEquationSeparate(modeRGB: GLenum, modeAlpha: GLenum)
+        [enum_(modeRGB), enum_(modeAlpha)] |state| ();
+    fn BlendEquationSeparatei(buf: GLuint, modeRGB: GLenum, modeAlpha: GLenum)
+        [buf, enum_(modeRGB), enum_(modeAlpha)] |state| state.draw_buffer(buf as GLint);
+    fn BlendEquationi(buf: GLuint, mode: GLenum) [buf, enum_(mode)] |state| {
+        state.draw_buffer(buf as GLint)
+    };
+    fn BlendFunc(sfactor: GLenum, dfactor: GLenum)
+        [enum_in(sfactor, FACTORS), enum_in(dfactor, FACTORS)] |state| ();
+    fn BlendFuncSeparate(
+        sfactorRGB: GLenum,
+        dfactorRGB: GLenum,
+        sfactorAlpha: GLenum,
+        dfactorAlpha: GLenum
+    ) [
+        enum_in(sfactorRGB, FACTORS),
+        enum_in(dfactorRGB, FACTORS),
+        enum_in(sfactorAlpha, FACTORS),
+        enum_in(dfactorAlpha, FACTORS),
+    ] |state| ();
+    fn BlendFuncSeparatei(
+        buf: GLuint,
+        srcRGB: GLenum,
+        dstRGB: GLenum,
+        srcAlpha: GLenum,
+        dstAlpha: GLenum
+    ) [
+        buf,
+        enum_in(srcRGB, FACTORS),
+        enum_in(dstRGB, FACTORS),
+        enum_in(srcAlpha, FACTORS),
+        enum_in(dstAlpha, FACTORS),
+    ] |state| state.draw_buffer(buf as GLint);
+    fn BlendFunci(buf: GLuint, src: GLenum, dst: GLenum)
+        [buf, enum_in(src, FACTORS), enum_in(dst, FACTORS)] |state| ();
+    fn BlitFramebuffer(
+        srcX0: GLint,
+        srcY0: GLint,
+        srcX1: GLint,
+        srcY1: GLint,
+        dstX0: GLint,
+        dstY0: GLint,
+        dstX1: GLint,
+        dstY1: GLint,
+        mask: GLbitfield,
+        filter: GLenum
+    ) [
+        srcX0,
+        srcY0,
+        srcX1,
+        srcY1,
+        dstX0,
+        dstY0,
+        dstX1,
+        dstY1,
+        bits(mask, CLEAR_BITS),
+        enum_(filter),
+    ] |state| ();
+    fn BufferData(target: GLenum, size: GLsizeiptr, data: *const c_void, usage: GLenum)
+        [enum_(target), size, bytes(data, size), enum_(usage)] |state| {
+        state.buffer(state.bound(target), |buffer| buffer.data(size, data, usage))
+    };
+    fn BufferStorage(target: GLenum, size: GLsizeiptr, data: *const c_void, flags: GLbitfield)
+        [enum_(target), size, bytes(data, size), bits(flags, STORAGE_BITS)] |state| {
+        state.buffer(state.bound(target), |buffer| buffer.storage(size, data, flags))
+    };
+    fn BufferSubData(target: GLenum, offset: GLintptr, size: GLsizeiptr, data: *const c_void)
+        [enum_(target), offset, size, bytes(data, size)] |state| {
+        state.buffer(state.bound(target), |buffer| buffer.sub_data(offset, size, data))
+    };
+    fn CheckFramebufferStatus(target: GLenum) -> GLenum [enum_(target)] |state| {
+        state.framebuffer_status(state.bound(target))
+    };
+    fn CheckNamedFramebufferStatus(framebuffer: GLuint, target: GLenum) -> GLenum
+        [framebuffer, enum_(target)] |state| {
+        if state.framebuffer(framebuffer) {
+            state.framebuffer_status(framebuffer)
+        } else {
+            0
+        }
+    };
+    fn Clear(mask: GLbitfield) [bits(mask, CLEAR_BITS)] |state| ();
+    fn ClearBufferfi(buffer: GLenum, drawbuffer: GLint, depth: GLfloat, stencil: GLint)
+        [enum_(buffer), drawbuffer, depth, stencil] |state| ();
+    fn ClearBufferfv(buffer: GLenum, drawbuffer: GLint, value: *const GLfloat)
+        [enum_(buffer), drawbuffer, array(value, clear_len(buffer))] |state| {
+        state.draw_buffer(drawbuffer)
+    };
+    fn ClearBufferiv(buffer: GLenum, drawbuffer: GLint, value: *const GLint)
+        [enum_(buffer), drawbuffer, array(value, clear_len(buffer))] |state| {
+        state.draw_buffer(drawbuffer)
+    };
+    fn ClearBufferuiv(buffer: GLenum, drawbuffer: GLint, value: *const GLuint)
+        [enum_(buffer), drawbuffer, array(value, 4)] |state| state.draw_buffer(drawbuffer);
+    fn ClearColor(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat)
+        [red, green, blue, alpha] |state| ();
+    fn ClearDepth(depth: GLdouble) [depth] |state| ();
+    fn ClearNamedFramebufferfi(
+        framebuffer: GLuint,
+        buffer: GLenum,
+        drawbuffer: GLint,
+        depth: GLfloat,
+        stencil: GLint
+    ) [framebuffer, enum_(buffer), drawbuffer, depth, stencil] |state| {
+        state.framebuffer(framebuffer);
+    };
+    fn ClearNamedFramebufferfv(
+        framebuffer: GLuint,
+        buffer: GLenum,
+        drawbuffer: GLint,
+        value: *const GLfloat
+    ) [framebuffer, enum_(buffer), drawbuffer, array(value, clear_len(buffer))] |state| {
+        if state.framebuffer(framebuffer) {
+            state.draw_buffer(drawbuffer)
+        }
+    };
+    fn ClearNamedFramebufferiv(
+        framebuffer: GLuint,
+        buffer: GLenum,
+        drawbuffer: GLint,
+        value: *const GLint
+    ) [framebuffer, enum_(buffer), drawbuffer, array(value, clear_len(buffer))] |state| {
+        if state.framebuffer(framebuffer) {
+            state.draw_buffer(drawbuffer)
+        }
+    };
+    fn ClearNamedFramebufferuiv(
+        framebuffer: GLuint,
+        buffer: GLenum,
+        drawbuffer: GLint,
+        value: *const GLuint
+    ) [framebuffer, enum_(buffer), drawbuffer, array(value, 4)] |state| {
+        if state.framebuffer(framebuffer) {
+            state.draw_buffer(drawbuffer)
+        }
+    };
+    fn ClearStencil(s: GLint) [s] |state| ();
+    fn ColorMask(red: GLboolean, green: GLboolean, blue: GLboolean, alpha: GLboolean)
+        [boolean(red), boolean(green), boolean(blue), boolean(alpha)] |state| ();
+    fn ColorMaski(
+        index: GLuint,
+        r: GLboolean,
+        g: GLboolean,
+        b: GLboolean,
+        a: GLboolean
+    ) [index, boolean(r), boolean(g), boolean(b), boolean(a)] |state| {
+        state.draw_buffer(index as GLint)
+    };
+    fn CompileShader(shader: GLuint) [shader] |state| {
+        state.shader(shader);
+    };
+    fn CompressedTexImage1D(
+        target: GLenum,
+        level: GLint,
+        internalformat: GLenum,
+        width: GLsizei,
+        border: GLint,
+        imageSize: GLsizei,
+        data: *const c_void
+    ) [
+        enum_(target),
+        level,
+        enum_(internalformat),
+        width,
+        border,
+        imageSize,
+        pointer(data),
+    ] |state| state.rect(width, imageSize);
+    fn CompressedTexImage2D(
+        target: GLenum,
+        level: GLint,
+        internalformat: GLenum,
+        width: GLsizei,
+        height: GLsizei,
+        border: GLint,
+        imageSize: GLsizei,
+        data: *const c_void
+    ) [
+        enum_(target),
+        level,
+        enum_(internalformat),
+        width,
+        height,
+        border,
+        imageSize,
+        pointer(data),
+    ] |state| state.rect(width, height.min(imageSize));
+    fn CompressedTexImage3D(
+        target: GLenum,
+        level: GLint,
+        internalformat: GLenum,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        border: GLint,
+        imageSize: GLsizei,
+        data: *const c_void
+    ) [
+        enum_(target),
+        level,
+        enum_(internalformat),
+        width,
+        height,
+        depth,
+        border,
+        imageSize,
+        pointer(data),
+    ] |state| state.rect(width, height.min(depth).min(imageSize));
+    fn CompressedTexSubImage1D(
+        target: GLenum,
+        level: GLint,
+        xoffset: GLint,
+        width: GLsizei,
+        format: GLenum,
+        imageSize: GLsizei,
+        data: *const c_void
+    ) [
+        enum_(target),
+        level,
+        xoffset,
+        width,
+        enum_(format),
+        imageSize,
+        pointer(data),
+    ] |state| state.rect(width, imageSize);
+    fn CompressedTexSubImage2D(
+        target: GLenum,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: GLenum,
+        imageSize: GLsizei,
+        data: *const c_void
+    ) [
+        enum_(target),
+        level,
+        xoffset,
+        yoffset,
+        width,
+        height,
+        enum_(format),
+        imageSize,
+        pointer(data),
+    ] |state| state.rect(width, height.min(imageSize));
+    fn CompressedTexSubImage3D(
+        target: GLenum,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: GLenum,
+        imageSize: GLsizei,
+        data: *const c_void
+    ) [
+        enum_(target),
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        enum_(format),
+        imageSize,
+        pointer(data),
+    ] |state| state.rect(width, height.min(depth).min(imageSize));
+    fn CreateFramebuffers(n: GLsizei, framebuffers: *mut GLuint) [n, MockArg::Out] |state| {
+        for i in 0..len(n) {
+            // SAFE: rgl passes `n` writable names
+            let name = state.create(Object::Framebuffer { attachments: 0 });
+            write(unsafe { framebuffers.add(i) }, name);
+        }
+    };
+    fn CreateProgram() -> GLuint [] |state| state.create(Object::Program(Vec::new()));
+    fn CreateRenderbuffers(n: GLsizei, renderbuffers: *mut GLuint) [n, MockArg::Out] |state| {
+        for i in 0..len(n) {
+            // SAFE: rgl passes `n` writable names
+            write(unsafe { renderbuffers.add(i) }, state.create(Object::Renderbuffer));
+        }
+    };
+    fn CreateSamplers(n: GLsizei, samplers: *mut GLuint) [n, MockArg::Out] |state| {
+        for i in 0..len(n) {
+            // SAFE: rgl passes `n` writable names
+            write(unsafe { samplers.add(i) }, state.create(Object::Sampler));
+        }
+    };
+    fn CreateShader(type_: GLenum) -> GLuint [enum_(type_)] |state| {
+        state.create(Object::Shader(type_))
+    };
+    fn CullFace(mode: GLenum) [enum_(mode)] |state| ();
+    fn DebugMessageCallback(callback: GLDEBUGPROC, userParam: *const c_void)
+        [MockArg::Pointer(callback.map_or(0, |f| f as usize)), pointer(userParam)] |state| {
+        state.debug_callback = (callback.map_or(0, |f| f as usize), userParam as usize)
+    };
+    fn DebugMessageControl(
+        source: GLenum,
+        type_: GLenum,
+        severity: GLenum,
+        count: GLsizei,
+        ids: *const GLuint,
+        enabled: GLboolean
+    ) [
+        enum_(source),
+        enum_(type_),
+        enum_(severity),
+        count,
+        array(ids, len(count)),
+        boolean(enabled),
+    ] |state| ();
+    fn DebugMessageInsert(
+        source: GLenum,
+        type_: GLenum,
+        id: GLuint,
+        severity: GLenum,
+        length: GLsizei,
+        buf: *const GLchar
+    ) [enum_(source), enum_(type_), id, enum_(severity), length, text(buf, length)] |state| ();
+    fn DeleteBuffers(n: GLsizei, buffers: *const GLuint) [n, array(buffers, len(n))] |state| {
+        state.delete(n, buffers, Kind::Buffer)
+    };
+    fn DeleteFramebuffers(n: GLsizei, framebuffers: *const GLuint)
+        [n, array(framebuffers, len(n))] |state| {
+        state.delete(n, framebuffers, Kind::Framebuffer)
+    };
+    fn DeleteProgram(program: GLuint) [program] |state| state.delete_name(program, None);
+    fn DeleteRenderbuffers(n: GLsizei, renderbuffers: *const GLuint)
+        [n, array(renderbuffers, len(n))] |state| {
+        state.delete(n, renderbuffers, Kind::Renderbuffer)
+    };
+    fn DeleteShader(shader: GLuint) [shader] |state| state.delete_name(shader, None);
+    fn DeleteSamplers(n: GLsizei, samplers: *const GLuint) [n, array(samplers, len(n))] |state| {
+        state.delete(n, samplers, Kind::Sampler)
+    };
+    fn DeleteTextures(n: GLsizei, textures: *const GLuint) [n, array(textures, len(n))] |state| {
+        state.delete(n, textures, Kind::Texture)
+    };
+    fn DeleteVertexArrays(n: GLsizei, arrays: *const GLuint) [n, array(arrays, len(n))] |state| {
+        state.delete(n, arrays, Kind::VertexArray)
+    };
+    fn DepthFunc(func: GLenum) [enum_(func)] |state| ();
+    fn DepthMask(flag: GLboolean) [boolean(flag)] |state| ();
+    fn DepthRange(n: GLdouble, f: GLdouble) [n, f] |state| ();
+    fn DepthRangeArrayv(first: GLuint, count: GLsizei, v: *const GLdouble)
+        [first, count, array(v, len(count) * 2)] |state| ();
+    fn DepthRangeIndexed(index: GLuint, n: GLdouble, f: GLdouble) [index, n, f] |state| ();
+    fn DetachShader(program: GLuint, shader: GLuint) [program, shader] |state| {
+        state.detach_shader(program, shader)
+    };
+    fn Disable(cap: GLenum) [enum_(cap)] |state| {
+        state.enabled.remove(&cap);
+    };
+    fn DisableVertexArrayAttrib(vaobj: GLuint, index: GLuint) [vaobj, index] |state| ();
+    fn DisableVertexAttribArray(index: GLuint) [index] |state| ();
+    fn Disablei(target: GLenum, index: GLuint) [enum_(target), index] |state| {
+        state.capability_index(target, index)
+    };
+    fn DrawArrays(mode: GLenum, first: GLint, count: GLsizei)
+        [enum_in(mode, PRIMITIVES), first, count] |state| ();
+    fn DrawBuffer(buf: GLenum) [enum_(buf)] |state| ();
+    fn DrawBuffers(n: GLsizei, bufs: *const GLenum) [n, enums(bufs, len(n))] |state| {
+        if n > 8 {
+            state.error(gl::INVALID_VALUE)
+        }
+    };
+    fn DrawElements(mode: GLenum, count: GLsizei, type_: GLenum, indices: *const c_void)
+        [enum_in(mode, PRIMITIVES), count, enum_(type_), offset(indices)] |state| ();
+    fn DrawElementsInstanced(
+        mode: GLenum,
+        count: GLsizei,
+        type_: GLenum,
+        indices: *const c_void,
+        instancecount: GLsizei
+    ) [enum_in(mode, PRIMITIVES), count, enum_(type_), offset(indices), instancecount] |state| ();
+    fn Enable(cap: GLenum) [enum_(cap)] |state| {
+        state.enabled.insert(cap);
+    };
+    fn EnableVertexArrayAttrib(vaobj: GLuint, index: GLuint) [vaobj, index] |state| ();
+    fn EnableVertexAttribArray(index: GLuint) [index] |state| ();
+    fn Enablei(target: GLenum, index: GLuint) [enum_(target), index] |state| {
+        state.capability_index(target, index)
+    };
+    fn FramebufferRenderbuffer(
+        target: GLenum,
+        attachment: GLenum,
+        renderbuffertarget: GLenum,
+        renderbuffer: GLuint
+    ) [enum_(target), enum_(attachment), enum_(renderbuffertarget), renderbuffer] |state| {
+        state.attach(target)
+    };
+    fn FramebufferTexture(target: GLenum, attachment: GLenum, texture: GLuint, level: GLint)
+        [enum_(target), enum_(attachment), texture, level] |state| state.attach(target);
+    fn FramebufferTexture1D(
+        target: GLenum,
+        attachment: GLenum,
+        textarget: GLenum,
+        texture: GLuint,
+        level: GLint
+    ) [enum_(target), enum_(attachment), enum_(textarget), texture, level] |state| {
+        state.attach(target)
+    };
+    fn FramebufferTexture2D(
+        target: GLenum,
+        attachment: GLenum,
+        textarget: GLenum,
+        texture: GLuint,
+        level: GLint
+    ) [enum_(target), enum_(attachment), enum_(textarget), texture, level] |state| {
+        state.attach(target)
+    };
+    fn FramebufferTexture3D(
+        target: GLenum,
+        attachment: GLenum,
+        textarget: GLenum,
+        texture: GLuint,
+        level: GLint,
+        zoffset: GLint
+    ) [enum_(target), enum_(attachment), enum_(textarget), texture, level, zoffset] |state| {
+        state.attach(target)
+    };
+    fn FramebufferTextureLayer(
+        target: GLenum,
+        attachment: GLenum,
+        texture: GLuint,
+        level: GLint,
+        layer: GLint
+    ) [enum_(target), enum_(attachment), texture, level, layer] |state| state.attach(target);
+    fn FrontFace(mode: GLenum) [enum_(mode)] |state| ();
+    fn GenBuffers(n: GLsizei, buffers: *mut GLuint) [n, array(buffers, len(n))] |state| {
+        state.gen(n, buffers, Kind::Buffer)
+    };
+    fn GenFramebuffers(n: GLsizei, framebuffers: *mut GLuint)
+        [n, array(framebuffers, len(n))] |state| {
+        state.gen(n, framebuffers, Kind::Framebuffer)
+    };
+    fn GenRenderbuffers(n: GLsizei, renderbuffers: *mut GLuint)
+        [n, array(renderbuffers, len(n))] |state| {
+        state.gen(n, renderbuffers, Kind::Renderbuffer)
+    };
+    fn GenSamplers(n: GLsizei, samplers: *mut GLuint) [n, array(samplers, len(n))] |state| {
+        state.gen(n, samplers, Kind::Sampler)
+    };
+    fn GenTextures(n: GLsizei, textures: *mut GLuint) [n, array(textures, len(n))] |state| {
+        state.gen(n, textures, Kind::Texture)
+    };
+    fn GenVertexArrays(n: GLsizei, arrays: *mut GLuint) [n, array(arrays, len(n))] |state| {
+        state.gen(n, arrays, Kind::VertexArray)
+    };
+    fn GenerateMipmap(target: GLenum) [enum_(target)] |state| ();
+    fn GenerateTextureMipmap(texture: GLuint) [texture] |state| ();
+    fn GetAttachedShaders(
+        program: GLuint,
+        maxCount: GLsizei,
+        count: *mut GLsizei,
+        shaders: *mut GLuint
+    ) [program, maxCount, MockArg::Out, MockArg::Out] |state| {
+        state.get_attached_shaders(program, maxCount, count, shaders)
+    };
+    fn GetBooleani_v(target: GLenum, index: GLuint, data: *mut GLboolean)
+        [enum_(target), index, MockArg::Out] |state| ();
+    fn GetBooleanv(pname: GLenum, data: *mut GLboolean) [enum_(pname), MockArg::Out] |state| ();
+    fn GetBufferParameteri64v(target: GLenum, pname: GLenum, params: *mut GLint64)
+        [enum_(target), enum_(pname), MockArg::Out] |state| {
+        let value = state.buffer(state.bound(target), |buffer| buffer.parameter(pname));
+        write(params, value)
+    };
+    fn GetBufferParameteriv(target: GLenum, pname: GLenum, params: *mut GLint)
+        [enum_(target), enum_(pname), MockArg::Out] |state| {
+        let value = state.buffer(state.bound(target), |buffer| buffer.parameter(pname));
+        write(params, value as GLint)
+    };
+    fn GetActiveAttrib(
+        program: GLuint,
+        index: GLuint,
+        bufSize: GLsizei,
+        length: *mut GLsizei,
+        size: *mut GLint,
+        type_: *mut GLenum,
+        name: *mut GLchar
+    ) [program, index, bufSize, MockArg::Out, MockArg::Out, MockArg::Out, MockArg::Out] |state| {
+        state.no_resource(program)
+    };
+    fn GetActiveUniform(
+        program: GLuint,
+        index: GLuint,
+        bufSize: GLsizei,
+        length: *mut GLsizei,
+        size: *mut GLint,
+        type_: *mut GLenum,
+        name: *mut GLchar
+    ) [program, index, bufSize, MockArg::Out, MockArg::Out, MockArg::Out, MockArg::Out] |state| {
+        state.no_resource(program)
+    };
+    fn GetActiveUniformBlockName(
+        program: GLuint,
+        uniformBlockIndex: GLuint,
+        bufSize: GLsizei,
+        length: *mut GLsizei,
+        uniformBlockName: *mut GLchar
+    ) [program, uniformBlockIndex, bufSize, MockArg::Out, MockArg::Out] |state| {
+        state.no_resource(program)
+    };
+    fn GetActiveUniformBlockiv(
+        program: GLuint,
+        uniformBlockIndex: GLuint,
+        pname: GLenum,
+        params: *mut GLint
+    ) [program, uniformBlockIndex, enum_(pname), MockArg::Out] |state| state.no_resource(program);
+    fn GetAttribLocation(program: GLuint, name: *const GLchar) -> GLint
+        [program, string(name)] |state| {
+        state.program(program);
+        -1
+    };
+    fn GetBufferPointerv(target: GLenum, pname: GLenum, params: *const *mut c_void)
+        [enum_(target), enum_(pname), MockArg::Out] |state| {
+        let pointer = state.buffer(state.bound(target), |buffer| Ok(buffer.pointer()));
+        write(params as *mut *mut c_void, pointer as *mut c_void)
+    };
+    fn GetBufferSubData(target: GLenum, offset: GLintptr, size: GLsizeiptr, data: *mut c_void)
+        [enum_(target), offset, size, MockArg::Out] |state| {
+        state.buffer(state.bound(target), |buffer| buffer.get_sub_data(offset, size, data))
+    };
+    fn GetCompressedTexImage(target: GLenum, level: GLint, img: *mut c_void)
+        [enum_(target), level, pointer(img)] |state| ();
+    fn GetDebugMessageLog(
+        count: GLuint,
+        bufSize: GLsizei,
+        sources: *mut GLenum,
+        types: *mut GLenum,
+        ids: *mut GLuint,
+        severities: *mut GLenum,
+        lengths: *mut GLsizei,
+        messageLog: *mut GLchar
+    ) -> GLuint [
+        count,
+        bufSize,
+        MockArg::Out,
+        MockArg::Out,
+        MockArg::Out,
+        MockArg::Out,
+        MockArg::Out,
+        MockArg::Out,
+    ] |state| 0;
+    fn GetDoublei_v(target: GLenum, index: GLuint, data: *mut GLdouble)
+        [enum_(target), index, MockArg::Out] |state| ();
+    fn GetDoublev(pname: GLenum, data: *mut GLdouble) [enum_(pname), MockArg::Out] |state| ();
+    fn GetError() -> GLenum [] |state| std::mem::replace(&mut state.error, gl::NO_ERROR);
+    fn GetFloati_v(target: GLenum, index: GLuint, data: *mut GLfloat)
+        [enum_(target), index, MockArg::Out] |state| ();
+    fn GetFloatv(pname: GLenum, data: *mut GLfloat) [enum_(pname), MockArg::Out] |state| ();
+    fn GetFramebufferAttachmentParameteriv(
+        target: GLenum,
+        attachment: GLenum,
+        pname: GLenum,
+        params: *mut GLint
+    ) [enum_(target), enum_(attachment), enum_(pname), MockArg::Out] |state| ();
+    fn GetFramebufferParameteriv(target: GLenum, pname: GLenum, params: *mut GLint)
+        [enum_(target), enum_(pname), MockArg::Out] |state| ();
+    fn GetInteger64i_v(target: GLenum, index: GLuint, data: *mut GLint64)
+        [enum_(target), index, MockArg::Out] |state| ();
+    fn GetInteger64v(pname: GLenum, data: *mut GLint64) [enum_(pname), MockArg::Out] |state| ();
+    fn GetIntegeri_v(target: GLenum, index: GLuint, data: *mut GLint)
+        [enum_(target), index, MockArg::Out] |state| ();
+    fn GetIntegerv(pname: GLenum, data: *mut GLint) [enum_(pname), MockArg::Out] |state| {
+        state.get_integer(pname, data)
+    };
+    fn GetMultisamplefv(pname: GLenum, index: GLuint, val: *mut GLfloat)
+        [enum_(pname), index, MockArg::Out] |state| ();
+    fn GetNamedBufferPointerv(buffer: GLuint, pname: GLenum, params: *const *mut c_void)
+        [buffer, enum_(pname), MockArg::Out] |state| {
+        let pointer = state.buffer(buffer, |buffer| Ok(buffer.pointer()));
+        write(params as *mut *mut c_void, pointer as *mut c_void)
+    };
+    fn GetNamedBufferSubData(buffer: GLuint, offset: GLintptr, size: GLsizeiptr, data: *mut c_void)
+        [buffer, offset, size, MockArg::Out] |state| {
+        state.buffer(buffer, |buffer| buffer.get_sub_data(offset, size, data))
+    };
+    fn GetObjectLabel(
+        identifier: GLenum,
+        name: GLuint,
+        bufSize: GLsizei,
+        length: *mut GLsizei,
+        label: *mut GLchar
+    ) [enum_(identifier), name, bufSize, MockArg::Out, MockArg::Out] |state| {
+        state.get_object_label(identifier, name, bufSize, length, label)
+    };
+    fn GetPointerv(pname: GLenum, params: *const *mut c_void) [enum_(pname), MockArg::Out]
+        |state| {
+        let pointer = match pname {
+            gl::DEBUG_CALLBACK_FUNCTION => state.debug_callback.0,
+            gl::DEBUG_CALLBACK_USER_PARAM => state.debug_callback.1,
+            _ => 0,
+        };
+        write(params as *mut *mut c_void, pointer as *mut c_void)
+    };
+    fn GetProgramInfoLog(
+        program: GLuint,
+        bufSize: GLsizei,
+        length: *mut GLsizei,
+        infoLog: *mut GLchar
+    ) [program, bufSize, MockArg::Out, MockArg::Out] |state| {
+        if state.program(program).is_some() {
+            state.get_info_log(length, infoLog, bufSize)
+        }
+    };
+    fn GetProgramInterfaceiv(
+        program: GLuint,
+        programInterface: GLenum,
+        pname: GLenum,
+        params: *mut GLint
+    ) [program, enum_(programInterface), enum_(pname), MockArg::Out] |state| {
+        if state.program(program).is_some() {
+            write(params, 0)
+        }
+    };
+    fn GetProgramResourceIndex(program: GLuint, programInterface: GLenum, name: *const GLchar)
+        -> GLuint [program, enum_(programInterface), string(name)] |state| {
+        state.program(program);
+        gl::INVALID_INDEX
+    };
+    fn GetProgramResourceLocation(program: GLuint, programInterface: GLenum, name: *const GLchar)
+        -> GLint [program, enum_(programInterface), string(name)] |state| {
+        state.program(program);
+        -1
+    };
+    fn GetProgramResourceName(
+        program: GLuint,
+        programInterface: GLenum,
+        index: GLuint,
+        bufSize: GLsizei,
+        length: *mut GLsizei,
+        name: *mut GLchar
+    ) [program, enum_(programInterface), index, bufSize, MockArg::Out, MockArg::Out] |state| {
+        state.no_resource(program)
+    };
+    fn GetProgramResourceiv(
+        program: GLuint,
+        programInterface: GLenum,
+        index: GLuint,
+        propCount: GLsizei,
+        props: *const GLenum,
+        bufSize: GLsizei,
+        length: *mut GLsizei,
+        params: *mut GLint
+    ) [
+        program,
+        enum_(programInterface),
+        index,
+        propCount,
+        enums(props, len(propCount)),
+        bufSize,
+        MockArg::Out,
+        MockArg::Out,
+    ] |state| state.no_resource(program);
+    fn GetProgramiv(program: GLuint, pname: GLenum, params: *mut GLint)
+        [program, enum_(pname), MockArg::Out] |state| state.get_program(program, pname, params);
+    fn GetRenderbufferParameteriv(target: GLenum, pname: GLenum, params: *mut GLint)
+        [enum_(target), enum_(pname), MockArg::Out] |state| {
+        if state.bound(target) == 0 {
+            state.error(gl::INVALID_OPERATION)
+        }
+    };
+    fn GetSamplerParameterfv(sampler: GLuint, pname: GLenum, params: *mut GLfloat)
+        [sampler, enum_(pname), MockArg::Out] |state| state.sampler(sampler);
+    fn GetSamplerParameteriv(sampler: GLuint, pname: GLenum, params: *mut GLint)
+        [sampler, enum_(pname), MockArg::Out] |state| state.sampler(sampler);
+    fn GetShaderInfoLog(
+        shader: GLuint,
+        bufSize: GLsizei,
+        length: *mut GLsizei,
+        infoLog: *mut GLchar
+    ) [shader, bufSize, MockArg::Out, MockArg::Out] |state| {
+        if state.shader(shader).is_some() {
+            state.get_info_log(length, infoLog, bufSize)
+        }
+    };
+    fn GetShaderiv(shader: GLuint, pname: GLenum, params: *mut GLint)
+        [shader, enum_(pname), MockArg::Out] |state| state.get_shader(shader, pname, params);
+    fn GetTexImage(target: GLenum, level: GLint, format: GLenum, type_: GLenum, pixels: *mut c_void)
+        [enum_(target), level, enum_(format), enum_(type_), pointer(pixels)] |state| ();
+    fn GetTexLevelParameteriv(target: GLenum, level: GLint, pname: GLenum, params: *mut GLint)
+        [enum_(target), level, enum_(pname), MockArg::Out] |state| ();
+    fn GetTexParameterIiv(target: GLenum, pname: GLenum, params: *mut GLint)
+        [enum_(target), enum_(pname), MockArg::Out] |state| ();
+    fn GetTexParameterIuiv(target: GLenum, pname: GLenum, params: *mut GLuint)
+        [enum_(target), enum_(pname), MockArg::Out] |state| ();
+    fn GetTexParameterfv(target: GLenum, pname: GLenum, params: *mut GLfloat)
+        [enum_(target), enum_(pname), MockArg::Out] |state| ();
+    fn GetTexParameteriv(target: GLenum, pname: GLenum, params: *mut GLint)
+        [enum_(target), enum_(pname), MockArg::Out] |state| ();
+    fn GetUniformBlockIndex(program: GLuint, uniformBlockName: *const GLchar) -> GLuint
+        [program, string(uniformBlockName)] |state| {
+        state.program(program);
+        gl::INVALID_INDEX
+    };
+    fn GetUniformLocation(program: GLuint, name: *const GLchar) -> GLint
+        [program, string(name)] |state| {
+        state.program(program);
+        -1
+    };
+    fn IsBuffer(buffer: GLuint) -> GLboolean [buffer] |state| state.is(buffer, Kind::Buffer);
+    fn IsEnabled(cap: GLenum) -> GLboolean [enum_(cap)] |state| {
+        GLboolean::from(state.enabled.contains(&cap))
+    };
+    fn IsEnabledi(target: GLenum, index: GLuint) -> GLboolean [enum_(target), index] |state| {
+        state.capability_index(target, index);
+        gl::FALSE
+    };
+    fn IsFramebuffer(framebuffer: GLuint) -> GLboolean [framebuffer] |state| {
+        state.is(framebuffer, Kind::Framebuffer)
+    };
+    fn IsProgram(program: GLuint) -> GLboolean [program] |state| {
+        GLboolean::from(matches!(state.objects.get(&program), Some(Object::Program(_))))
+    };
+    fn IsRenderbuffer(renderbuffer: GLuint) -> GLboolean [renderbuffer] |state| {
+        state.is(renderbuffer, Kind::Renderbuffer)
+    };
+    fn IsSampler(sampler: GLuint) -> GLboolean [sampler] |state| state.is(sampler, Kind::Sampler);
+    fn IsShader(shader: GLuint) -> GLboolean [shader] |state| {
+        GLboolean::from(matches!(state.objects.get(&shader), Some(Object::Shader(_))))
+    };
+    fn IsTexture(texture: GLuint) -> GLboolean [texture] |state| state.is(texture, Kind::Texture);
+    fn IsVertexArray(array: GLuint) -> GLboolean [array] |state| {
+        state.is(array, Kind::VertexArray)
+    };
+    fn LineWidth(width: GLfloat) [width] |state| {
+        if width <= 0.0 {
+            state.error(gl::INVALID_VALUE)
+        }
+    };
+    fn LinkProgram(program: GLuint) [program] |state| {
+        state.program(program);
+    };
+    fn MapBuffer(target: GLenum, access: GLenum) -> *mut c_void [enum_(target), enum_(access)]
+        |state| {
+        let access = match access {
+            gl::READ_ONLY => gl::MAP_READ_BIT,
+            gl::WRITE_ONLY => gl::MAP_WRITE_BIT,
+            _ => gl::MAP_READ_BIT | gl::MAP_WRITE_BIT,
+        };
+        let pointer = state.buffer(state.bound(target), |buffer| {
+            buffer.map(0, buffer.data.len() as GLsizeiptr, access)
+        });
+        pointer as *mut c_void
+    };
+    fn MapBufferRange(
+        target: GLenum,
+        offset: GLintptr,
+        length: GLsizeiptr,
+        access: GLbitfield
+    ) -> *mut c_void [enum_(target), offset, length, bits(access, MAP_BITS)] |state| {
+        let pointer = state.buffer(state.bound(target), |buffer| buffer.map(offset, length, access));
+        pointer as *mut c_void
+    };
+    fn MapNamedBuffer(buffer: GLuint, access: GLenum) -> *mut c_void [buffer, enum_(access)]
+        |state| {
+        let access = match access {
+            gl::READ_ONLY => gl::MAP_READ_BIT,
+            gl::WRITE_ONLY => gl::MAP_WRITE_BIT,
+            _ => gl::MAP_READ_BIT | gl::MAP_WRITE_BIT,
+        };
+        let pointer = state.buffer(buffer, |buffer| {
+            buffer.map(0, buffer.data.len() as GLsizeiptr, access)
+        });
+        pointer as *mut c_void
+    };
+    fn MapNamedBufferRange(
+        buffer: GLuint,
+        offset: GLintptr,
+        length: GLsizeiptr,
+        access: GLbitfield
+    ) -> *mut c_void [buffer, offset, length, bits(access, MAP_BITS)] |state| {
+        let pointer = state.buffer(buffer, |buffer| buffer.map(offset, length, access));
+        pointer as *mut c_void
+    };
+    fn MinSampleShading(value: GLfloat) [value] |state| ();
+    fn NamedBufferData(buffer: GLuint, size: GLsizeiptr, data: *const c_void, usage: GLenum)
+        [buffer, size, bytes(data, size), enum_(usage)] |state| {
+        state.buffer(buffer, |buffer| buffer.data(size, data, usage))
+    };
+    fn NamedBufferStorage(buffer: GLuint, size: GLsizeiptr, data: *const c_void, flags: GLbitfield)
+        [buffer, size, bytes(data, size), bits(flags, STORAGE_BITS)] |state| {
+        state.buffer(buffer, |buffer| buffer.storage(size, data, flags))
+    };
+    fn NamedBufferSubData(buffer: GLuint, offset: GLintptr, size: GLsizeiptr, data: *const c_void)
+        [buffer, offset, size, bytes(data, size)] |state| {
+        state.buffer(buffer, |buffer| buffer.sub_data(offset, size, data))
+    };
+    fn NamedFramebufferDrawBuffer(framebuffer: GLuint, buf: GLenum)
+        [framebuffer, enum_(buf)] |state| {
+        state.framebuffer(framebuffer);
+    };
+    fn NamedFramebufferDrawBuffers(framebuffer: GLuint, n: GLsizei, bufs: *const GLenum)
+        [framebuffer, n, enums(bufs, len(n))] |state| {
+        if state.framebuffer(framebuffer) && n > 8 {
+            state.error(gl::INVALID_VALUE)
+        }
+    };
+    fn NamedFramebufferReadBuffer(framebuffer: GLuint, src: GLenum)
+        [framebuffer, enum_(src)] |state| {
+        state.framebuffer(framebuffer);
+    };
+    fn NamedFramebufferRenderbuffer(
+        framebuffer: GLuint,
+        attachment: GLenum,
+        renderbuffertarget: GLenum,
+        renderbuffer: GLuint
+    ) [framebuffer, enum_(attachment), enum_(renderbuffertarget), renderbuffer] |state| {
+        state.attach_named(framebuffer)
+    };
+    fn NamedFramebufferTexture(
+        framebuffer: GLuint,
+        attachment: GLenum,
+        texture: GLuint,
+        level: GLint
+    ) [framebuffer, enum_(attachment), texture, level] |state| state.attach_named(framebuffer);
+    fn NamedFramebufferTextureLayer(
+        framebuffer: GLuint,
+        attachment: GLenum,
+        texture: GLuint,
+        level: GLint,
+        layer: GLint
+    ) [framebuffer, enum_(attachment), texture, level, layer] |state| {
+        state.attach_named(framebuffer)
+    };
+    fn NamedRenderbufferStorage(
+        renderbuffer: GLuint,
+        internalformat: GLenum,
+        width: GLsizei,
+        height: GLsizei
+    ) [renderbuffer, enum_(internalformat), width, height] |state| {
+        state.renderbuffer(renderbuffer);
+        state.rect(width, height)
+    };
+    fn NamedRenderbufferStorageMultisample(
+        renderbuffer: GLuint,
+        samples: GLsizei,
+        internalformat: GLenum,
+        width: GLsizei,
+        height: GLsizei
+    ) [renderbuffer, samples, enum_(internalformat), width, height] |state| {
+        state.renderbuffer(renderbuffer);
+        if samples > 8 {
+            state.error(gl::INVALID_VALUE)
+        } else {
+            state.rect(width, height)
+        }
+    };
+    fn ObjectLabel(identifier: GLenum, name: GLuint, length: GLsizei, label: *const GLchar)
+        [enum_(identifier), name, length, text(label, length)] |state| {
+        state.object_label(identifier, name, length, label)
+    };
+    fn PointParameterf(pname: GLenum, param: GLfloat) [enum_(pname), param] |state| {
+        if param < 0.0 {
+            state.error(gl::INVALID_VALUE)
+        }
+    };
+    fn PointParameteri(pname: GLenum, param: GLint) [enum_(pname), parameter(pname, param)]
+        |state| ();
+    fn PointSize(size: GLfloat) [size] |state| {
+        if size <= 0.0 {
+            state.error(gl::INVALID_VALUE)
+        }
+    };
+    fn PolygonMode(face: GLenum, mode: GLenum) [enum_(face), enum_(mode)] |state| ();
+    fn PolygonOffset(factor: GLfloat, units: GLfloat) [factor, units] |state| ();
+    fn PopDebugGroup() [] |state| ();
+    fn ProvokingVertex(mode: GLenum) [enum_(mode)] |state| ();
+    fn PushDebugGroup(source: GLenum, id: GLuint, length: GLsizei, message: *const GLchar)
+        [enum_(source), id, length, text(message, length)] |state| ();
+    fn ReadBuffer(src: GLenum) [enum_(src)] |state| ();
+    fn ReadPixels(
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: GLenum,
+        type_: GLenum,
+        pixels: *mut c_void
+    ) [x, y, width, height, enum_(format), enum_(type_), pointer(pixels)] |state| {
+        state.rect(width, height)
+    };
+    fn RenderbufferStorage(target: GLenum, internalformat: GLenum, width: GLsizei, height: GLsizei)
+        [enum_(target), enum_(internalformat), width, height] |state| state.rect(width, height);
+    fn RenderbufferStorageMultisample(
+        target: GLenum,
+        samples: GLsizei,
+        internalformat: GLenum,
+        width: GLsizei,
+        height: GLsizei
+    ) [enum_(target), samples, enum_(internalformat), width, height] |state| {
+        if samples > 8 {
+            state.error(gl::INVALID_VALUE)
+        } else {
+            state.rect(width, height)
+        }
+    };
+    fn SampleCoverage(value: GLfloat, invert: GLboolean) [value, boolean(invert)] |state| ();
+    fn SampleMaski(maskNumber: GLuint, mask: GLbitfield) [maskNumber, mask] |state| {
+        // the reported `GL_MAX_SAMPLE_MASK_WORDS` is 1
+        if maskNumber >= 1 {
+            state.error(gl::INVALID_VALUE)
+        }
+    };
+    fn SamplerParameterIiv(sampler: GLuint, pname: GLenum, param: *const GLint)
+        [sampler, enum_(pname), array(param, 4)] |state| state.sampler(sampler);
+    fn SamplerParameterIuiv(sampler: GLuint, pname: GLenum, param: *const GLuint)
+        [sampler, enum_(pname), array(param, 4)] |state| state.sampler(sampler);
+    fn SamplerParameterf(sampler: GLuint, pname: GLenum, param: GLfloat)
+        [sampler, enum_(pname), param] |state| state.sampler(sampler);
+    fn SamplerParameterfv(sampler: GLuint, pname: GLenum, param: *const GLfloat)
+        [sampler, enum_(pname), array(param, 4)] |state| state.sampler(sampler);
+    fn SamplerParameteri(sampler: GLuint, pname: GLenum, param: GLint)
+        [sampler, enum_(pname), parameter(pname, param)] |state| state.sampler(sampler);
+    fn Scissor(x: GLint, y: GLint, width: GLsizei, height: GLsizei) [x, y, width, height]
+        |state| state.rect(width, height);
+    fn ScissorArrayv(first: GLuint, count: GLsizei, v: *const GLint)
+        [first, count, array(v, len(count) * 4)] |state| ();
+    fn ScissorIndexed(index: GLuint, left: GLint, bottom: GLint, width: GLsizei, height: GLsizei)
+        [index, left, bottom, width, height] |state| state.rect(width, height);
+    fn ShaderStorageBlockBinding(
+        program: GLuint,
+        storageBlockIndex: GLuint,
+        storageBlockBinding: GLuint
+    ) [program, storageBlockIndex, storageBlockBinding] |state| state.no_resource(program);
+    fn ShaderSource(
+        shader: GLuint,
+        count: GLsizei,
+        string: *const *const GLchar,
+        length: *const GLint
+    ) [shader, count, strings(count, string, length), MockArg::Pointer(length as usize)] |state| {
+        state.shader(shader);
+    };
+    fn StencilFunc(func: GLenum, ref_: GLint, mask: GLuint) [enum_(func), ref_, mask] |state| ();
+    fn StencilFuncSeparate(face: GLenum, func: GLenum, ref_: GLint, mask: GLuint)
+        [enum_(face), enum_(func), ref_, mask] |state| ();
+    fn StencilMask(mask: GLuint) [mask] |state| ();
+    fn StencilMaskSeparate(face: GLenum, mask: GLuint) [enum_(face), mask] |state| ();
+    fn StencilOp(fail: GLenum, zfail: GLenum, zpass: GLenum)
+        [enum_in(fail, FACTORS), enum_in(zfail, FACTORS), enum_in(zpass, FACTORS)] |state| ();
+    fn StencilOpSeparate(face: GLenum, sfail: GLenum, dpfail: GLenum, dppass: GLenum) [
+        enum_(face),
+        enum_in(sfail, FACTORS),
+        enum_in(dpfail, FACTORS),
+        enum_in(dppass, FACTORS),
+    ] |state| ();
+    fn TexImage1D(
+        target: GLenum,
+        level: GLint,
+        internalformat: GLint,
+        width: GLsizei,
+        border: GLint,
+        format: GLenum,
+        type_: GLenum,
+        pixels: *const c_void
+    ) [
+        enum_(target),
+        level,
+        enum_(internalformat as GLenum),
+        width,
+        border,
+        enum_(format),
+        enum_(type_),
+        pointer(pixels),
+    ] |state| state.rect(width, 1);
+    fn TexImage2D(
+        target: GLenum,
+        level: GLint,
+        internalformat: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        border: GLint,
+        format: GLenum,
+        type_: GLenum,
+        pixels: *const c_void
+    ) [
+        enum_(target),
+        level,
+        enum_(internalformat as GLenum),
+        width,
+        height,
+        border,
+        enum_(format),
+        enum_(type_),
+        pointer(pixels),
+    ] |state| state.rect(width, height);
+    fn TexImage2DMultisample(
+        target: GLenum,
+        samples: GLsizei,
+        internalformat: GLenum,
+        width: GLsizei,
+        height: GLsizei,
+        fixedsamplelocations: GLboolean
+    ) [
+        enum_(target),
+        samples,
+        enum_(internalformat),
+        width,
+        height,
+        boolean(fixedsamplelocations),
+    ] |state| state.rect(width, height);
+    fn TexImage3D(
+        target: GLenum,
+        level: GLint,
+        internalformat: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        border: GLint,
+        format: GLenum,
+        type_: GLenum,
+        pixels: *const c_void
+    ) [
+        enum_(target),
+        level,
+        enum_(internalformat as GLenum),
+        width,
+        height,
+        depth,
+        border,
+        enum_(format),
+        enum_(type_),
+        pointer(pixels),
+    ] |state| state.rect(width, height.min(depth));
+    fn TexParameterIiv(target: GLenum, pname: GLenum, params: *const GLint)
+        [enum_(target), enum_(pname), array(params, 4)] |state| ();
+    fn TexParameterIuiv(target: GLenum, pname: GLenum, params: *const GLuint)
+        [enum_(target), enum_(pname), array(params, 4)] |state| ();
+    fn TexParameterf(target: GLenum, pname: GLenum, param: GLfloat)
+        [enum_(target), enum_(pname), param] |state| ();
+    fn TexParameterfv(target: GLenum, pname: GLenum, params: *const GLfloat)
+        [enum_(target), enum_(pname), array(params, 4)] |state| ();
+    fn TexParameteri(target: GLenum, pname: GLenum, param: GLint)
+        [enum_(target), enum_(pname), parameter(pname, param)] |state| ();
+    fn TexParameteriv(target: GLenum, pname: GLenum, params: *const GLint)
+        [enum_(target), enum_(pname), array(params, 4)] |state| ();
+    fn TexStorage1D(target: GLenum, levels: GLsizei, internalformat: GLenum, width: GLsizei)
+        [enum_(target), levels, enum_(internalformat), width]
+        |state| state.rect(levels - 1, width - 1);
+    fn TexStorage2D(
+        target: GLenum,
+        levels: GLsizei,
+        internalformat: GLenum,
+        width: GLsizei,
+        height: GLsizei
+    ) [enum_(target), levels, enum_(internalformat), width, height]
+        |state| state.rect(levels.min(width) - 1, height - 1);
+    fn TexStorage2DMultisample(
+        target: GLenum,
+        samples: GLsizei,
+        internalformat: GLenum,
+        width: GLsizei,
+        height: GLsizei,
+        fixedsamplelocations: GLboolean
+    ) [
+        enum_(target),
+        samples,
+        enum_(internalformat),
+        width,
+        height,
+        boolean(fixedsamplelocations),
+    ] |state| state.rect(width, height);
+    fn TexStorage3D(
+        target: GLenum,
+        levels: GLsizei,
+        internalformat: GLenum,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei
+    ) [enum_(target), levels, enum_(internalformat), width, height, depth]
+        |state| state.rect(levels.min(width) - 1, height.min(depth) - 1);
+    fn TexSubImage1D(
+        target: GLenum,
+        level: GLint,
+        xoffset: GLint,
+        width: GLsizei,
+        format: GLenum,
+        type_: GLenum,
+        pixels: *const c_void
+    ) [
+        enum_(target),
+        level,
+        xoffset,
+        width,
+        enum_(format),
+        enum_(type_),
+        pointer(pixels),
+    ] |state| state.rect(width, 0);
+    fn TexSubImage2D(
+        target: GLenum,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: GLenum,
+        type_: GLenum,
+        pixels: *const c_void
+    ) [
+        enum_(target),
+        level,
+        xoffset,
+        yoffset,
+        width,
+        height,
+        enum_(format),
+        enum_(type_),
+        pointer(pixels),
+    ] |state| state.rect(width, height);
+    fn TexSubImage3D(
+        target: GLenum,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: GLenum,
+        type_: GLenum,
+        pixels: *const c_void
+    ) [
+        enum_(target),
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        enum_(format),
+        enum_(type_),
+        pointer(pixels),
+    ] |state| state.rect(width, height.min(depth));
+    fn TextureParameterIiv(texture: GLuint, pname: GLenum, params: *const GLint)
+        [texture, enum_(pname), array(params, 4)] |state| state.texture(texture);
+    fn TextureParameterIuiv(texture: GLuint, pname: GLenum, params: *const GLuint)
+        [texture, enum_(pname), array(params, 4)] |state| state.texture(texture);
+    fn TextureParameterf(texture: GLuint, pname: GLenum, param: GLfloat)
+        [texture, enum_(pname), param] |state| state.texture(texture);
+    fn TextureParameterfv(texture: GLuint, pname: GLenum, params: *const GLfloat)
+        [texture, enum_(pname), array(params, 4)] |state| state.texture(texture);
+    fn TextureParameteri(texture: GLuint, pname: GLenum, param: GLint)
+        [texture, enum_(pname), parameter(pname, param)] |state| state.texture(texture);
+    fn TextureParameteriv(texture: GLuint, pname: GLenum, params: *const GLint)
+        [texture, enum_(pname), array(params, 4)] |state| state.texture(texture);
+    fn Uniform1dv(location: GLint, count: GLsizei, value: *const GLdouble)
+        [location, count, array(value, len(count))] |state| ();
+    fn Uniform1f(location: GLint, v0: GLfloat) [location, v0] |state| ();
+    fn Uniform1fv(location: GLint, count: GLsizei, value: *const GLfloat)
+        [location, count, array(value, len(count))] |state| ();
+    fn Uniform1i(location: GLint, v0: GLint) [location, v0] |state| ();
+    fn Uniform1iv(location: GLint, count: GLsizei, value: *const GLint)
+        [location, count, array(value, len(count))] |state| ();
+    fn Uniform1ui(location: GLint, v0: GLuint) [location, v0] |state| ();
+    fn Uniform1uiv(location: GLint, count: GLsizei, value: *const GLuint)
+        [location, count, array(value, len(count))] |state| ();
+    fn Uniform2dv(location: GLint, count: GLsizei, value: *const GLdouble)
+        [location, count, array(value, len(count) * 2)] |state| ();
+    fn Uniform2f(location: GLint, v0: GLfloat, v1: GLfloat) [location, v0, v1] |state| ();
+    fn Uniform2fv(location: GLint, count: GLsizei, value: *const GLfloat)
+        [location, count, array(value, len(count) * 2)] |state| ();
+    fn Uniform2i(location: GLint, v0: GLint, v1: GLint) [location, v0, v1] |state| ();
+    fn Uniform2iv(location: GLint, count: GLsizei, value: *const GLint)
+        [location, count, array(value, len(count) * 2)] |state| ();
+    fn Uniform2ui(location: GLint, v0: GLuint, v1: GLuint) [location, v0, v1] |state| ();
+    fn Uniform2uiv(location: GLint, count: GLsizei, value: *const GLuint)
+        [location, count, array(value, len(count) * 2)] |state| ();
+    fn Uniform3dv(location: GLint, count: GLsizei, value: *const GLdouble)
+        [location, count, array(value, len(count) * 3)] |state| ();
+    fn Uniform3f(location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat)
+        [location, v0, v1, v2] |state| ();
+    fn Uniform3fv(location: GLint, count: GLsizei, value: *const GLfloat)
+        [location, count, array(value, len(count) * 3)] |state| ();
+    fn Uniform3i(location: GLint, v0: GLint, v1: GLint, v2: GLint)
+        [location, v0, v1, v2] |state| ();
+    fn Uniform3iv(location: GLint, count: GLsizei, value: *const GLint)
+        [location, count, array(value, len(count) * 3)] |state| ();
+    fn Uniform3ui(location: GLint, v0: GLuint, v1: GLuint, v2: GLuint)
+        [location, v0, v1, v2] |state| ();
+    fn Uniform3uiv(location: GLint, count: GLsizei, value: *const GLuint)
+        [location, count, array(value, len(count) * 3)] |state| ();
+    fn Uniform4dv(location: GLint, count: GLsizei, value: *const GLdouble)
+        [location, count, array(value, len(count) * 4)] |state| ();
+    fn Uniform4f(location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat, v3: GLfloat)
+        [location, v0, v1, v2, v3] |state| ();
+    fn Uniform4fv(location: GLint, count: GLsizei, value: *const GLfloat)
+        [location, count, array(value, len(count) * 4)] |state| ();
+    fn Uniform4i(location: GLint, v0: GLint, v1: GLint, v2: GLint, v3: GLint)
+        [location, v0, v1, v2, v3] |state| ();
+    fn Uniform4iv(location: GLint, count: GLsizei, value: *const GLint)
+        [location, count, array(value, len(count) * 4)] |state| ();
+    fn Uniform4ui(location: GLint, v0: GLuint, v1: GLuint, v2: GLuint, v3: GLuint)
+        [location, v0, v1, v2, v3] |state| ();
+    fn Uniform4uiv(location: GLint, count: GLsizei, value: *const GLuint)
+        [location, count, array(value, len(count) * 4)] |state| ();
+    fn UniformBlockBinding(program: GLuint, uniformBlockIndex: GLuint, uniformBlockBinding: GLuint)
+        [program, uniformBlockIndex, uniformBlockBinding] |state| state.no_resource(program);
+    fn UniformMatrix2dv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble
+    ) [location, count, boolean(transpose), array(value, len(count) * 4)] |state| ();
+    fn UniformMatrix2fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat)
+        [location, count, boolean(transpose), array(value, len(count) * 4)] |state| ();
+    fn UniformMatrix2x3dv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble
+    ) [location, count, boolean(transpose), array(value, len(count) * 6)] |state| ();
+    fn UniformMatrix2x3fv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat
+    ) [location, count, boolean(transpose), array(value, len(count) * 6)] |state| ();
+    fn UniformMatrix2x4dv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble
+    ) [location, count, boolean(transpose), array(value, len(count) * 8)] |state| ();
+    fn UniformMatrix2x4fv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat
+    ) [location, count, boolean(transpose), array(value, len(count) * 8)] |state| ();
+    fn UniformMatrix3dv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble
+    ) [location, count, boolean(transpose), array(value, len(count) * 9)] |state| ();
+    fn UniformMatrix3fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat)
+        [location, count, boolean(transpose), array(value, len(count) * 9)] |state| ();
+    fn UniformMatrix3x2dv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble
+    ) [location, count, boolean(transpose), array(value, len(count) * 6)] |state| ();
+    fn UniformMatrix3x2fv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat
+    ) [location, count, boolean(transpose), array(value, len(count) * 6)] |state| ();
+    fn UniformMatrix3x4dv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble
+    ) [location, count, boolean(transpose), array(value, len(count) * 12)] |state| ();
+    fn UniformMatrix3x4fv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat
+    ) [location, count, boolean(transpose), array(value, len(count) * 12)] |state| ();
+    fn UniformMatrix4dv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble
+    ) [location, count, boolean(transpose), array(value, len(count) * 16)] |state| ();
+    fn UniformMatrix4fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat)
+        [location, count, boolean(transpose), array(value, len(count) * 16)] |state| ();
+    fn UniformMatrix4x2dv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble
+    ) [location, count, boolean(transpose), array(value, len(count) * 8)] |state| ();
+    fn UniformMatrix4x2fv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat
+    ) [location, count, boolean(transpose), array(value, len(count) * 8)] |state| ();
+    fn UniformMatrix4x3dv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble
+    ) [location, count, boolean(transpose), array(value, len(count) * 12)] |state| ();
+    fn UniformMatrix4x3fv(
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat
+    ) [location, count, boolean(transpose), array(value, len(count) * 12)] |state| ();
+    fn UnmapBuffer(target: GLenum) -> GLboolean [enum_(target)] |state| {
+        state.buffer(state.bound(target), BufferStore::unmap)
+    };
+    fn UnmapNamedBuffer(buffer: GLuint) -> GLboolean [buffer] |state| {
+        state.buffer(buffer, BufferStore::unmap)
+    };
+    fn UseProgram(program: GLuint) [program] |state| state.use_program(program);
+    fn VertexAttribDivisor(index: GLuint, divisor: GLuint) [index, divisor] |state| ();
+    fn VertexAttribIPointer(
+        index: GLuint,
+        size: GLint,
+        type_: GLenum,
+        stride: GLsizei,
+        pointer: *const c_void
+    ) [index, size, enum_(type_), stride, offset(pointer)] |state| ();
+    fn VertexAttribLPointer(
+        index: GLuint,
+        size: GLint,
+        type_: GLenum,
+        stride: GLsizei,
+        pointer: *const c_void
+    ) [index, size, enum_(type_), stride, offset(pointer)] |state| ();
+    fn VertexAttribPointer(
+        index: GLuint,
+        size: GLint,
+        type_: GLenum,
+        normalized: GLboolean,
+        stride: GLsizei,
+        pointer: *const c_void
+    ) [index, size, enum_(type_), boolean(normalized), stride, offset(pointer)] |state| ();
+    fn Viewport(x: GLint, y: GLint, width: GLsizei, height: GLsizei) [x, y, width, height]
+        |state| state.rect(width, height);
+    fn ViewportArrayv(first: GLuint, count: GLsizei, v: *const GLfloat)
+        [first, count, array(v, len(count) * 4)] |state| ();
+    fn ViewportIndexedf(index: GLuint, x: GLfloat, y: GLfloat, w: GLfloat, h: GLfloat)
+        [index, x, y, w, h] |state| ();
+}
@@ -0,0 +1,223 @@
+//! # Owned Objects
+//! <https://www.khronos.org/opengl/wiki/OpenGL_Object>
+//!
+//! # Description
+//! Opt-in owning wrappers around the raw object handles. Each owned type generates (or creates)
+//! its object on construction, and deletes it when dropped, so objects are no longer leaked by
+//! forgetting a `delete_*` call:
+//! ```no_run
+//! use rgl::prelude::*;
+//!
+//! let vao = OwnedVertexArray::generate();
+//! let vbos = OwnedBuffer::generate_many(2);
+//! bind_vertex_array(*vao);
+//! bind_buffer(BufferBindingTarget::Array, *vbos[0]);
+//! // `vao` and both `vbos` are deleted here
+//! ```
+//!
+//! The owned types dereference to the raw handles ([Buffer], [Texture], ...), so every existing
+//! function taking a raw handle still works through `*owned`. A raw handle copied out of an owned
+//! object must not be used after the owned object is dropped.
+//!
+//! Owned objects must be dropped while the context that created them, or one sharing its objects,
+//! is current. [into_raw](OwnedBuffer::into_raw) gives up ownership without deleting the object,
+//! and [from_raw](OwnedBuffer::from_raw) takes ownership of an existing one.
+
+use crate::prelude::*;
+
+macro_rules! owned_generated {
+    ($(
+        $(#[$doc:meta])*
+        $owned:ident($raw:ident) = $gen:ident / $delete:ident;
+    )*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, PartialEq)]
+            pub struct $owned($raw);
+
+            impl $owned {
+                #[doc = concat!("Generate a single object with [", stringify!($gen), "]")]
+                pub fn generate() -> $owned {
+                    let mut raw = $raw::default();
+                    $gen(std::slice::from_mut(&mut raw));
+                    $owned(raw)
+                }
+
+                #[doc = concat!(
+                    "Generate `count` objects with a single call to [", stringify!($gen), "]"
+                )]
+                pub fn generate_many(count: usize) -> Vec<$owned> {
+                    let mut raws = vec![$raw::default(); count];
+                    $gen(&mut raws);
+                    raws.into_iter().map($owned).collect()
+                }
+
+                /// Take ownership of an existing object, which is deleted when dropped
+                pub fn from_raw(raw: $raw) -> $owned {
+                    $owned(raw)
+                }
+
+                /// Give up ownership of the object, without deleting it
+                pub fn into_raw(self) -> $raw {
+                    let raw = self.0;
+                    std::mem::forget(self);
+                    raw
+                }
+            }
+
+            impl std::ops::Deref for $owned {
+                type Target = $raw;
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            impl AsRef<$raw> for $owned {
+                fn as_ref(&self) -> &$raw {
+                    &self.0
+                }
+            }
+
+            impl std::borrow::Borrow<$raw> for $owned {
+                fn borrow(&self) -> &$raw {
+                    &self.0
+                }
+            }
+
+            impl Drop for $owned {
+                fn drop(&mut self) {
+                    $delete(&[self.0]);
+                }
+            }
+        )*
+    };
+}
+
+owned_generated! {
+    /// # Owned buffer object
+    /// A [Buffer] generated by [gen_buffers], and deleted by [delete_buffers] when dropped.
+    OwnedBuffer(Buffer) = gen_buffers / delete_buffers;
+
+    /// # Owned framebuffer object
+    /// A [Framebuffer] generated by [gen_framebuffers], and deleted by [delete_framebuffers] when
+    /// dropped.
+    OwnedFramebuffer(Framebuffer) = gen_framebuffers / delete_framebuffers;
+
+    /// # Owned renderbuffer object
+    /// A [Renderbuffer] generated by [gen_renderbuffers], and deleted by [delete_renderbuffers]
+    /// when dropped.
+    OwnedRenderbuffer(Renderbuffer) = gen_renderbuffers / delete_renderbuffers;
+
+    /// # Owned sampler object
+    /// A [Sampler] generated by [gen_samplers], and deleted by [delete_samplers] when dropped.
+    OwnedSampler(Sampler) = gen_samplers / delete_samplers;
+
+    /// # Owned texture object
+    /// A [Texture] generated by [gen_textures], and deleted by [delete_textures] when dropped.
+    OwnedTexture(Texture) = gen_textures / delete_textures;
+
+    /// # Owned vertex array object
+    /// A [VertexArray] generated by [gen_vertex_arrays], and deleted by [delete_vertex_arrays]
+    /// when dropped.
+    OwnedVertexArray(VertexArray) = gen_vertex_arrays / delete_vertex_arrays;
+}
+
+/// # Owned shader object
+/// A [Shader] created by [create_shader], and deleted by [delete_shader] when dropped. A shader
+/// attached to a program is only flagged for deletion, until it is detached.
+#[derive(Debug, PartialEq)]
+pub struct OwnedShader(Shader);
+
+impl OwnedShader {
+    /// Create an empty shader object of `shader_type` with [create_shader]
+    pub fn create(shader_type: ShaderType) -> OwnedShader {
+        OwnedShader(create_shader(shader_type))
+    }
+
+    /// Take ownership of an existing shader, which is deleted when dropped
+    pub fn from_raw(shader: Shader) -> OwnedShader {
+        OwnedShader(shader)
+    }
+
+    /// Give up ownership of the shader, without deleting it
+    pub fn into_raw(self) -> Shader {
+        let shader = self.0;
+        std::mem::forget(self);
+        shader
+    }
+}
+
+impl std::ops::Deref for OwnedShader {
+    type Target = Shader;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<Shader> for OwnedShader {
+    fn as_ref(&self) -> &Shader {
+        &self.0
+    }
+}
+
+impl std::borrow::Borrow<Shader> for OwnedShader {
+    fn borrow(&self) -> &Shader {
+        &self.0
+    }
+}
+
+impl Drop for OwnedShader {
+    fn drop(&mut self) {
+        delete_shader(self.0);
+    }
+}
+
+/// # Owned program object
+/// A [Program] created by [create_program], and deleted by [delete_program] when dropped. A
+/// program in use by [use_program] is only flagged for deletion, until it is no longer in use.
+#[derive(Debug, PartialEq)]
+pub struct OwnedProgram(Program);
+
+impl OwnedProgram {
+    /// Create an empty program object with [create_program]
+    pub fn create() -> OwnedProgram {
+        OwnedProgram(create_program())
+    }
+
+    /// Take ownership of an existing program, which is deleted when dropped
+    pub fn from_raw(program: Program) -> OwnedProgram {
+        OwnedProgram(program)
+    }
+
+    /// Give up ownership of the program, without deleting it
+    pub fn into_raw(self) -> Program {
+        let program = self.0;
+        std::mem::forget(self);
+        program
+    }
+}
+
+impl std::ops::Deref for OwnedProgram {
+    type Target = Program;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<Program> for OwnedProgram {
+    fn as_ref(&self) -> &Program {
+        &self.0
+    }
+}
+
+impl std::borrow::Borrow<Program> for OwnedProgram {
+    fn borrow(&self) -> &Program {
+        &self.0
+    }
+}
+
+impl Drop for OwnedProgram {
+    fn drop(&mut self) {
+        delete_program(self.0);
+    }
+}
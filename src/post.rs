@@ -15,6 +15,7 @@ use gl::types::*;
 
 /// # Stencil Op Actions
 /// see [stencil_op] or [stencil_op_separate]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum StencilOp {
     /// Keeps the current value.
     Keep,
@@ -59,6 +60,23 @@ impl From<StencilOp> for GLenum {
     }
 }
 
+impl TryFrom<GLenum> for StencilOp {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::KEEP => Ok(StencilOp::Keep),
+            gl::ZERO => Ok(StencilOp::Zero),
+            gl::REPLACE => Ok(StencilOp::Replace),
+            gl::INCR => Ok(StencilOp::IncrementClamp),
+            gl::INCR_WRAP => Ok(StencilOp::IncrementWrap),
+            gl::DECR => Ok(StencilOp::DecrementClamp),
+            gl::DECR_WRAP => Ok(StencilOp::DecrementWrap),
+            gl::INVERT => Ok(StencilOp::Invert),
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum BlendFactor {
     Zero,
@@ -108,6 +126,82 @@ impl From<BlendFactor> for GLenum {
     }
 }
 
+impl TryFrom<GLenum> for BlendFactor {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::ZERO => Ok(BlendFactor::Zero),
+            gl::ONE => Ok(BlendFactor::One),
+            gl::SRC_COLOR => Ok(BlendFactor::SourceColour),
+            gl::ONE_MINUS_SRC_COLOR => Ok(BlendFactor::OneMinusSourceColour),
+            gl::DST_COLOR => Ok(BlendFactor::DestColour),
+            gl::ONE_MINUS_DST_COLOR => Ok(BlendFactor::OneMinusDestColour),
+            gl::SRC_ALPHA => Ok(BlendFactor::SourceAlpha),
+            gl::ONE_MINUS_SRC_ALPHA => Ok(BlendFactor::OneMinusSourceAlpha),
+            gl::DST_ALPHA => Ok(BlendFactor::DestAlpha),
+            gl::ONE_MINUS_DST_ALPHA => Ok(BlendFactor::OneMinusDestAlpha),
+            gl::CONSTANT_COLOR => Ok(BlendFactor::ConstantColour),
+            gl::ONE_MINUS_CONSTANT_COLOR => Ok(BlendFactor::OneMinusConstantColour),
+            gl::CONSTANT_ALPHA => Ok(BlendFactor::ConstantAlpha),
+            gl::ONE_MINUS_CONSTANT_ALPHA => Ok(BlendFactor::OneMinusConstantAlpha),
+            gl::SRC_ALPHA_SATURATE => Ok(BlendFactor::SourceAlphaSaturate),
+            gl::SRC1_COLOR => Ok(BlendFactor::Source1Colour),
+            gl::ONE_MINUS_SRC1_COLOR => Ok(BlendFactor::OneMinusSource1Colour),
+            gl::SRC1_ALPHA => Ok(BlendFactor::Source1Alpha),
+            gl::ONE_MINUS_SRC1_ALPHA => Ok(BlendFactor::OneMinusSource1Alpha),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Blend equations
+/// see [blend_equation] or [blend_equation_separate]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BlendEquation {
+    /// Adds the scaled source and destination colours.
+    Add,
+
+    /// Subtracts the scaled destination colour from the scaled source colour.
+    Subtract,
+
+    /// Subtracts the scaled source colour from the scaled destination colour.
+    ReverseSubtract,
+
+    /// The component-wise minimum of the source and destination colours, ignoring the blend
+    /// factors.
+    Min,
+
+    /// The component-wise maximum of the source and destination colours, ignoring the blend
+    /// factors.
+    Max,
+}
+
+impl From<BlendEquation> for GLenum {
+    fn from(value: BlendEquation) -> Self {
+        match value {
+            BlendEquation::Add => gl::FUNC_ADD,
+            BlendEquation::Subtract => gl::FUNC_SUBTRACT,
+            BlendEquation::ReverseSubtract => gl::FUNC_REVERSE_SUBTRACT,
+            BlendEquation::Min => gl::MIN,
+            BlendEquation::Max => gl::MAX,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for BlendEquation {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::FUNC_ADD => Ok(BlendEquation::Add),
+            gl::FUNC_SUBTRACT => Ok(BlendEquation::Subtract),
+            gl::FUNC_REVERSE_SUBTRACT => Ok(BlendEquation::ReverseSubtract),
+            gl::MIN => Ok(BlendEquation::Min),
+            gl::MAX => Ok(BlendEquation::Max),
+            _ => Err(()),
+        }
+    }
+}
+
 /// # Specify pixel arithmetic
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBlendFunc.xhtml>
 ///
@@ -250,17 +344,679 @@ pub fn blend_func(source_factor: BlendFactor, dest_factor: BlendFactor) {
     unsafe { gl::BlendFunc(sfactor, dfactor) }
 }
 
+/// # Error mapped blend func
+/// see [blend_func]
+pub fn blend_func_checked(
+    source_factor: BlendFactor,
+    dest_factor: BlendFactor,
+) -> Result<(), StateError> {
+    blend_func(source_factor, dest_factor);
+    state_error()
+}
+
 /// # Specify pixel arithmetic
 /// see [blend_func]
 ///
 /// # Arguments
 /// * `blend_index` - specifies the index of the draw buffer for which to set the blend function.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
 pub fn blend_func_buffer(buffer_index: u32, source_factor: BlendFactor, dest_factor: BlendFactor) {
     let sfactor = GLenum::from(source_factor);
     let dfactor = GLenum::from(dest_factor);
     unsafe { gl::BlendFunci(buffer_index, sfactor, dfactor) }
 }
 
+/// # Error mapped blend func buffer
+/// see [blend_func_buffer]
+pub fn blend_func_buffer_checked(
+    buffer_index: u32,
+    source_factor: BlendFactor,
+    dest_factor: BlendFactor,
+) -> Result<(), StateError> {
+    blend_func_buffer(buffer_index, source_factor, dest_factor);
+    draw_buffer_error(buffer_index)
+}
+
+/// # Specify pixel arithmetic for RGB and alpha components separately
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBlendFuncSeparate.xhtml>
+///
+/// # Arguments
+/// * `source_rgb` - Specifies how the red, green, and blue blending factors are computed. The
+/// initial value is [BlendFactor::One].
+/// * `dest_rgb` - Specifies how the red, green, and blue destination blending factors are
+/// computed. The initial value is [BlendFactor::Zero].
+/// * `source_alpha` - Specifies how the alpha source blending factor is computed. The initial
+/// value is [BlendFactor::One].
+/// * `dest_alpha` - Specifies how the alpha destination blending factor is computed. The initial
+/// value is [BlendFactor::Zero].
+///
+/// # Example
+/// Premultiplied alpha keeps the colour of translucent surfaces, while the destination alpha
+/// accumulates their coverage.
+/// ```no_run
+/// # use rgl::prelude::*;
+/// blend_func_separate(
+///     BlendFactor::One,
+///     BlendFactor::OneMinusSourceAlpha,
+///     BlendFactor::One,
+///     BlendFactor::OneMinusSourceAlpha,
+/// );
+/// ```
+///
+/// # Description
+/// Pixels can be drawn using a function that blends the incoming (source) RGBA values with the RGBA
+/// values that are already in the frame buffer (the destination values). Blending is initially
+/// disabled. Use [enable] and [disable] with argument [Capability::Blend] to enable and disable
+/// blending.
+///
+/// [blend_func_separate] defines the operation of blending for all draw buffers when it is
+/// enabled. [blend_func_separate_buffer] defines the operation of blending for a single draw
+/// buffer specified by `buffer_index` when enabled for that draw buffer. `source_rgb` specifies
+/// which method is used to scale the source RGB colour components. `dest_rgb` specifies which
+/// method is used to scale the destination RGB colour components. Likewise, `source_alpha`
+/// specifies which method is used to scale the source alpha colour component, and `dest_alpha`
+/// specifies which method is used to scale the destination alpha component. The possible methods,
+/// and the equations that combine the scaled colours, are described in [blend_func] and
+/// [blend_equation].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
+///
+/// # Associated Gets
+/// * [get_blend_source_rgb]
+/// * [get_blend_source_alpha]
+/// * [get_blend_dest_rgb]
+/// * [get_blend_dest_alpha]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [blend_func_separate] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [blend_func_separate_buffer] | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [blend_colour]
+/// * [blend_equation]
+/// * [blend_func]
+/// * [enable]
+pub fn blend_func_separate(
+    source_rgb: BlendFactor,
+    dest_rgb: BlendFactor,
+    source_alpha: BlendFactor,
+    dest_alpha: BlendFactor,
+) {
+    let src_rgb = GLenum::from(source_rgb);
+    let dst_rgb = GLenum::from(dest_rgb);
+    let src_alpha = GLenum::from(source_alpha);
+    let dst_alpha = GLenum::from(dest_alpha);
+    unsafe { gl::BlendFuncSeparate(src_rgb, dst_rgb, src_alpha, dst_alpha) }
+}
+
+/// # Error mapped blend func separate
+/// see [blend_func_separate]
+pub fn blend_func_separate_checked(
+    source_rgb: BlendFactor,
+    dest_rgb: BlendFactor,
+    source_alpha: BlendFactor,
+    dest_alpha: BlendFactor,
+) -> Result<(), StateError> {
+    blend_func_separate(source_rgb, dest_rgb, source_alpha, dest_alpha);
+    state_error()
+}
+
+/// # Specify pixel arithmetic for RGB and alpha components separately
+/// see [blend_func_separate]
+///
+/// # Arguments
+/// * `buffer_index` - specifies the index of the draw buffer for which to set the blend functions.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
+pub fn blend_func_separate_buffer(
+    buffer_index: u32,
+    source_rgb: BlendFactor,
+    dest_rgb: BlendFactor,
+    source_alpha: BlendFactor,
+    dest_alpha: BlendFactor,
+) {
+    let src_rgb = GLenum::from(source_rgb);
+    let dst_rgb = GLenum::from(dest_rgb);
+    let src_alpha = GLenum::from(source_alpha);
+    let dst_alpha = GLenum::from(dest_alpha);
+    unsafe { gl::BlendFuncSeparatei(buffer_index, src_rgb, dst_rgb, src_alpha, dst_alpha) }
+}
+
+/// # Error mapped blend func separate buffer
+/// see [blend_func_separate_buffer]
+pub fn blend_func_separate_buffer_checked(
+    buffer_index: u32,
+    source_rgb: BlendFactor,
+    dest_rgb: BlendFactor,
+    source_alpha: BlendFactor,
+    dest_alpha: BlendFactor,
+) -> Result<(), StateError> {
+    blend_func_separate_buffer(buffer_index, source_rgb, dest_rgb, source_alpha, dest_alpha);
+    draw_buffer_error(buffer_index)
+}
+
+/// # Specify the equation used for both the RGB blend equation and the Alpha blend equation
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBlendEquation.xhtml>
+///
+/// # Arguments
+/// * `mode` - specifies how source and destination colours are combined. The initial value is
+/// [BlendEquation::Add].
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // darken the frame buffer through a light mask
+/// blend_equation(BlendEquation::ReverseSubtract);
+/// blend_func(BlendFactor::One, BlendFactor::One);
+/// ```
+///
+/// # Description
+/// The blend equations determine how a new pixel (the "source" colour) is combined with a pixel
+/// already in the framebuffer (the "destination" colour). This function sets both the RGB blend
+/// equation and the alpha blend equation to a single equation. [blend_equation_buffer] specifies
+/// the blend equation for a single draw buffer whereas [blend_equation] sets the blend equation
+/// for all draw buffers.
+///
+/// These equations use the source and destination blend factors specified by either [blend_func]
+/// or [blend_func_separate]. See [blend_func] or [blend_func_separate] for a description of the
+/// various blend factors.
+///
+/// In the equations that follow, source and destination colour components are referred to as
+/// (R<sub>s</sub>, G<sub>s</sub>, B<sub>s</sub>, A<sub>s</sub>) and (R<sub>d</sub>, G<sub>d</sub>,
+/// B<sub>d</sub>, A<sub>d</sub>), respectively. The result colour is referred to as
+/// (R<sub>r</sub>, G<sub>r</sub>, B<sub>r</sub>, A<sub>r</sub>). The source and destination blend
+/// factors are denoted (s<sub>R</sub>, s<sub>G</sub>, s<sub>B</sub>, s<sub>A</sub>) and
+/// (d<sub>R</sub>, d<sub>G</sub>, d<sub>B</sub>, d<sub>A</sub>), respectively. For these
+/// equations all colour components are understood to have values in the range \[0,1\].
+///
+/// | [BlendEquation] | RGB Components | Alpha Component |
+/// |-----------------|----------------|-----------------|
+/// | [BlendEquation::Add] | R<sub>r</sub> = R<sub>s</sub>s<sub>R</sub> + R<sub>d</sub>d<sub>R</sub> | A<sub>r</sub> = A<sub>s</sub>s<sub>A</sub> + A<sub>d</sub>d<sub>A</sub> |
+/// | [BlendEquation::Subtract] | R<sub>r</sub> = R<sub>s</sub>s<sub>R</sub> − R<sub>d</sub>d<sub>R</sub> | A<sub>r</sub> = A<sub>s</sub>s<sub>A</sub> − A<sub>d</sub>d<sub>A</sub> |
+/// | [BlendEquation::ReverseSubtract] | R<sub>r</sub> = R<sub>d</sub>d<sub>R</sub> − R<sub>s</sub>s<sub>R</sub> | A<sub>r</sub> = A<sub>d</sub>d<sub>A</sub> − A<sub>s</sub>s<sub>A</sub> |
+/// | [BlendEquation::Min] | R<sub>r</sub> = min(R<sub>s</sub>, R<sub>d</sub>) | A<sub>r</sub> = min(A<sub>s</sub>, A<sub>d</sub>) |
+/// | [BlendEquation::Max] | R<sub>r</sub> = max(R<sub>s</sub>, R<sub>d</sub>) | A<sub>r</sub> = max(A<sub>s</sub>, A<sub>d</sub>) |
+///
+/// The green and blue components are computed in the same way as the red component. The results
+/// of these equations are clamped to the range \[0,1\].
+///
+/// The [BlendEquation::Min] and [BlendEquation::Max] equations are useful for applications that
+/// analyze image data (image thresholding against a constant colour, for example). The
+/// [BlendEquation::Add] equation is useful for antialiasing and transparency, among other things.
+///
+/// Initially, both the RGB blend equation and the alpha blend equation are set to
+/// [BlendEquation::Add].
+///
+/// # Compatability
+/// The [BlendEquation::Min], and [BlendEquation::Max] equations do not use the source or
+/// destination factors, only the source and destination colours.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
+///
+/// # Associated Gets
+/// * [get_blend_equation_rgb]
+/// * [get_blend_equation_alpha]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [blend_equation] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [blend_equation_buffer] | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [blend_colour]
+/// * [blend_equation_separate]
+/// * [blend_func]
+/// * [blend_func_separate]
+pub fn blend_equation(mode: BlendEquation) {
+    let mode = GLenum::from(mode);
+    unsafe { gl::BlendEquation(mode) }
+}
+
+/// # Error mapped blend equation
+/// see [blend_equation]
+pub fn blend_equation_checked(mode: BlendEquation) -> Result<(), StateError> {
+    blend_equation(mode);
+    state_error()
+}
+
+/// # Specify the equation used for both the RGB and Alpha blend equations of a draw buffer
+/// see [blend_equation]
+///
+/// # Arguments
+/// * `buffer_index` - specifies the index of the draw buffer for which to set the blend equation.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
+pub fn blend_equation_buffer(buffer_index: u32, mode: BlendEquation) {
+    let mode = GLenum::from(mode);
+    unsafe { gl::BlendEquationi(buffer_index, mode) }
+}
+
+/// # Error mapped blend equation buffer
+/// see [blend_equation_buffer]
+pub fn blend_equation_buffer_checked(
+    buffer_index: u32,
+    mode: BlendEquation,
+) -> Result<(), StateError> {
+    blend_equation_buffer(buffer_index, mode);
+    draw_buffer_error(buffer_index)
+}
+
+/// # Set the RGB blend equation and the alpha blend equation separately
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBlendEquationSeparate.xhtml>
+///
+/// # Arguments
+/// * `mode_rgb` - specifies the RGB blend equation, how the red, green, and blue components of the
+/// source and destination colours are combined. The initial value is [BlendEquation::Add].
+/// * `mode_alpha` - specifies the alpha blend equation, how the alpha component of the source and
+/// destination colours are combined. The initial value is [BlendEquation::Add].
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // keep the greatest coverage in the alpha channel
+/// blend_equation_separate(BlendEquation::Add, BlendEquation::Max);
+/// ```
+///
+/// # Description
+/// The blend equations determine how a new pixel (the "source" colour) is combined with a pixel
+/// already in the framebuffer (the "destination" colour). These functions specify one blend
+/// equation for the RGB-colour components and one blend equation for the alpha component.
+/// [blend_equation_separate_buffer] specifies the blend equations for a single draw buffer whereas
+/// [blend_equation_separate] sets the blend equations for all draw buffers.
+///
+/// The blend equations use the source and destination blend factors specified by either
+/// [blend_func] or [blend_func_separate], and are described in [blend_equation].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
+///
+/// # Associated Gets
+/// * [get_blend_equation_rgb]
+/// * [get_blend_equation_alpha]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [blend_equation_separate] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [blend_equation_separate_buffer] | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [blend_colour]
+/// * [blend_equation]
+/// * [blend_func]
+/// * [blend_func_separate]
+pub fn blend_equation_separate(mode_rgb: BlendEquation, mode_alpha: BlendEquation) {
+    let mode_rgb = GLenum::from(mode_rgb);
+    let mode_alpha = GLenum::from(mode_alpha);
+    unsafe { gl::BlendEquationSeparate(mode_rgb, mode_alpha) }
+}
+
+/// # Error mapped blend equation separate
+/// see [blend_equation_separate]
+pub fn blend_equation_separate_checked(
+    mode_rgb: BlendEquation,
+    mode_alpha: BlendEquation,
+) -> Result<(), StateError> {
+    blend_equation_separate(mode_rgb, mode_alpha);
+    state_error()
+}
+
+/// # Set the RGB blend equation and the alpha blend equation of a draw buffer separately
+/// see [blend_equation_separate]
+///
+/// # Arguments
+/// * `buffer_index` - specifies the index of the draw buffer for which to set the blend equations.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
+pub fn blend_equation_separate_buffer(
+    buffer_index: u32,
+    mode_rgb: BlendEquation,
+    mode_alpha: BlendEquation,
+) {
+    let mode_rgb = GLenum::from(mode_rgb);
+    let mode_alpha = GLenum::from(mode_alpha);
+    unsafe { gl::BlendEquationSeparatei(buffer_index, mode_rgb, mode_alpha) }
+}
+
+/// # Error mapped blend equation separate buffer
+/// see [blend_equation_separate_buffer]
+pub fn blend_equation_separate_buffer_checked(
+    buffer_index: u32,
+    mode_rgb: BlendEquation,
+    mode_alpha: BlendEquation,
+) -> Result<(), StateError> {
+    blend_equation_separate_buffer(buffer_index, mode_rgb, mode_alpha);
+    draw_buffer_error(buffer_index)
+}
+
+/// # Set the blend colour
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBlendColor.xhtml>
+///
+/// # Arguments
+/// * `red`, `green`, `blue`, `alpha` - specify the components of the blend colour.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // fade the source towards the destination by a constant amount
+/// blend_colour(0.0, 0.0, 0.0, 0.25);
+/// blend_func(BlendFactor::ConstantAlpha, BlendFactor::OneMinusConstantAlpha);
+/// ```
+///
+/// # Description
+/// The blend colour may be used to calculate the source and destination blending factors, see
+/// [BlendFactor::ConstantColour] and [BlendFactor::ConstantAlpha]. The colour components are
+/// clamped to the range \[0,1\] before being stored. See [blend_func] for a complete
+/// description of the blending operations. Initially the blend colour is set to (0, 0, 0, 0).
+///
+/// # Associated Gets
+/// * [get_blend_colour]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [blend_colour] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [blend_equation]
+/// * [blend_func]
+pub fn blend_colour(red: f32, green: f32, blue: f32, alpha: f32) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::BlendColor(red, green, blue, alpha) }
+}
+
+/// # Error mapped blend colour
+/// see [blend_colour]
+pub fn blend_colour_checked(red: f32, green: f32, blue: f32, alpha: f32) -> Result<(), StateError> {
+    blend_colour(red, green, blue, alpha);
+    state_error()
+}
+
+/// # Return the blend colour
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// blend_colour(0.0, 0.0, 0.0, 0.25);
+/// assert_eq!(get_blend_colour(), [0.0, 0.0, 0.0, 0.25]);
+/// ```
+///
+/// # Description
+/// Returns four values, the red, green, blue, and alpha values which are the components of the
+/// blend colour. The initial value is `[0.0, 0.0, 0.0, 0.0]`. See [blend_colour].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_blend_colour] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+pub fn get_blend_colour() -> [f32; 4] {
+    get_f32v(gl::BLEND_COLOR)
+}
+
+/// # Error mapped get blend colour
+/// see [get_blend_colour]
+pub fn get_blend_colour_checked() -> Result<[f32; 4], StateError> {
+    let value = get_blend_colour();
+    state_error()?;
+    Ok(value)
+}
+
+/// # Return the blend function or equation state
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Arguments
+/// * `buffer_index` - For the `_buffer` getters, specifies the index of the draw buffer to query.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// blend_func_separate(
+///     BlendFactor::One,
+///     BlendFactor::OneMinusSourceAlpha,
+///     BlendFactor::Zero,
+///     BlendFactor::One,
+/// );
+/// assert_eq!(get_blend_source_rgb(), Some(BlendFactor::One));
+/// assert_eq!(get_blend_dest_alpha(), Some(BlendFactor::One));
+///
+/// blend_equation_buffer(1, BlendEquation::Max);
+/// assert_eq!(get_blend_equation_rgb_buffer(1), Some(BlendEquation::Max));
+/// ```
+///
+/// # Description
+/// Each getter returns a single value:
+/// * [get_blend_source_rgb] - the RGB source blend factor. The initial value is
+/// [BlendFactor::One].
+/// * [get_blend_source_alpha] - the alpha source blend factor. The initial value is
+/// [BlendFactor::One].
+/// * [get_blend_dest_rgb] - the RGB destination blend factor. The initial value is
+/// [BlendFactor::Zero].
+/// * [get_blend_dest_alpha] - the alpha destination blend factor. The initial value is
+/// [BlendFactor::Zero].
+/// * [get_blend_equation_rgb] - the RGB blend equation. The initial value is
+/// [BlendEquation::Add].
+/// * [get_blend_equation_alpha] - the alpha blend equation. The initial value is
+/// [BlendEquation::Add].
+///
+/// The plain getters return the state of the first draw buffer, the `_buffer` getters return the
+/// state of the draw buffer `buffer_index`, which may differ after [blend_func_buffer],
+/// [blend_func_separate_buffer], [blend_equation_buffer] or [blend_equation_separate_buffer].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `buffer_index` is greater than or equal to [get_max_draw_buffers].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_blend_source_rgb] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_blend_source_rgb_buffer] | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [blend_equation]
+/// * [blend_equation_separate]
+/// * [blend_func]
+/// * [blend_func_separate]
+pub fn get_blend_source_rgb() -> Option<BlendFactor> {
+    BlendFactor::try_from(get_i32(gl::BLEND_SRC_RGB) as GLenum).ok()
+}
+
+/// # Error mapped get blend source rgb
+/// see [get_blend_source_rgb]
+pub fn get_blend_source_rgb_checked() -> Result<Option<BlendFactor>, StateError> {
+    let value = get_blend_source_rgb();
+    state_error()?;
+    Ok(value)
+}
+
+/// # Return the alpha source blend factor
+/// see [get_blend_source_rgb]
+pub fn get_blend_source_alpha() -> Option<BlendFactor> {
+    BlendFactor::try_from(get_i32(gl::BLEND_SRC_ALPHA) as GLenum).ok()
+}
+
+/// # Error mapped get blend source alpha
+/// see [get_blend_source_alpha]
+pub fn get_blend_source_alpha_checked() -> Result<Option<BlendFactor>, StateError> {
+    let value = get_blend_source_alpha();
+    state_error()?;
+    Ok(value)
+}
+
+/// # Return the RGB destination blend factor
+/// see [get_blend_source_rgb]
+pub fn get_blend_dest_rgb() -> Option<BlendFactor> {
+    BlendFactor::try_from(get_i32(gl::BLEND_DST_RGB) as GLenum).ok()
+}
+
+/// # Error mapped get blend dest rgb
+/// see [get_blend_dest_rgb]
+pub fn get_blend_dest_rgb_checked() -> Result<Option<BlendFactor>, StateError> {
+    let value = get_blend_dest_rgb();
+    state_error()?;
+    Ok(value)
+}
+
+/// # Return the alpha destination blend factor
+/// see [get_blend_source_rgb]
+pub fn get_blend_dest_alpha() -> Option<BlendFactor> {
+    BlendFactor::try_from(get_i32(gl::BLEND_DST_ALPHA) as GLenum).ok()
+}
+
+/// # Error mapped get blend dest alpha
+/// see [get_blend_dest_alpha]
+pub fn get_blend_dest_alpha_checked() -> Result<Option<BlendFactor>, StateError> {
+    let value = get_blend_dest_alpha();
+    state_error()?;
+    Ok(value)
+}
+
+/// # Return the RGB blend equation
+/// see [get_blend_source_rgb]
+pub fn get_blend_equation_rgb() -> Option<BlendEquation> {
+    BlendEquation::try_from(get_i32(gl::BLEND_EQUATION_RGB) as GLenum).ok()
+}
+
+/// # Error mapped get blend equation rgb
+/// see [get_blend_equation_rgb]
+pub fn get_blend_equation_rgb_checked() -> Result<Option<BlendEquation>, StateError> {
+    let value = get_blend_equation_rgb();
+    state_error()?;
+    Ok(value)
+}
+
+/// # Return the alpha blend equation
+/// see [get_blend_source_rgb]
+pub fn get_blend_equation_alpha() -> Option<BlendEquation> {
+    BlendEquation::try_from(get_i32(gl::BLEND_EQUATION_ALPHA) as GLenum).ok()
+}
+
+/// # Error mapped get blend equation alpha
+/// see [get_blend_equation_alpha]
+pub fn get_blend_equation_alpha_checked() -> Result<Option<BlendEquation>, StateError> {
+    let value = get_blend_equation_alpha();
+    state_error()?;
+    Ok(value)
+}
+
+/// # Return the RGB source blend factor of a draw buffer
+/// see [get_blend_source_rgb]
+pub fn get_blend_source_rgb_buffer(buffer_index: u32) -> Option<BlendFactor> {
+    let [value] = get_i32_indexed(gl::BLEND_SRC_RGB, buffer_index);
+    BlendFactor::try_from(value as GLenum).ok()
+}
+
+/// # Error mapped get blend source rgb buffer
+/// see [get_blend_source_rgb_buffer]
+pub fn get_blend_source_rgb_buffer_checked(
+    buffer_index: u32,
+) -> Result<Option<BlendFactor>, StateError> {
+    let value = get_blend_source_rgb_buffer(buffer_index);
+    draw_buffer_error(buffer_index)?;
+    Ok(value)
+}
+
+/// # Return the alpha source blend factor of a draw buffer
+/// see [get_blend_source_rgb]
+pub fn get_blend_source_alpha_buffer(buffer_index: u32) -> Option<BlendFactor> {
+    let [value] = get_i32_indexed(gl::BLEND_SRC_ALPHA, buffer_index);
+    BlendFactor::try_from(value as GLenum).ok()
+}
+
+/// # Error mapped get blend source alpha buffer
+/// see [get_blend_source_alpha_buffer]
+pub fn get_blend_source_alpha_buffer_checked(
+    buffer_index: u32,
+) -> Result<Option<BlendFactor>, StateError> {
+    let value = get_blend_source_alpha_buffer(buffer_index);
+    draw_buffer_error(buffer_index)?;
+    Ok(value)
+}
+
+/// # Return the RGB destination blend factor of a draw buffer
+/// see [get_blend_source_rgb]
+pub fn get_blend_dest_rgb_buffer(buffer_index: u32) -> Option<BlendFactor> {
+    let [value] = get_i32_indexed(gl::BLEND_DST_RGB, buffer_index);
+    BlendFactor::try_from(value as GLenum).ok()
+}
+
+/// # Error mapped get blend dest rgb buffer
+/// see [get_blend_dest_rgb_buffer]
+pub fn get_blend_dest_rgb_buffer_checked(
+    buffer_index: u32,
+) -> Result<Option<BlendFactor>, StateError> {
+    let value = get_blend_dest_rgb_buffer(buffer_index);
+    draw_buffer_error(buffer_index)?;
+    Ok(value)
+}
+
+/// # Return the alpha destination blend factor of a draw buffer
+/// see [get_blend_source_rgb]
+pub fn get_blend_dest_alpha_buffer(buffer_index: u32) -> Option<BlendFactor> {
+    let [value] = get_i32_indexed(gl::BLEND_DST_ALPHA, buffer_index);
+    BlendFactor::try_from(value as GLenum).ok()
+}
+
+/// # Error mapped get blend dest alpha buffer
+/// see [get_blend_dest_alpha_buffer]
+pub fn get_blend_dest_alpha_buffer_checked(
+    buffer_index: u32,
+) -> Result<Option<BlendFactor>, StateError> {
+    let value = get_blend_dest_alpha_buffer(buffer_index);
+    draw_buffer_error(buffer_index)?;
+    Ok(value)
+}
+
+/// # Return the RGB blend equation of a draw buffer
+/// see [get_blend_source_rgb]
+pub fn get_blend_equation_rgb_buffer(buffer_index: u32) -> Option<BlendEquation> {
+    let [value] = get_i32_indexed(gl::BLEND_EQUATION_RGB, buffer_index);
+    BlendEquation::try_from(value as GLenum).ok()
+}
+
+/// # Error mapped get blend equation rgb buffer
+/// see [get_blend_equation_rgb_buffer]
+pub fn get_blend_equation_rgb_buffer_checked(
+    buffer_index: u32,
+) -> Result<Option<BlendEquation>, StateError> {
+    let value = get_blend_equation_rgb_buffer(buffer_index);
+    draw_buffer_error(buffer_index)?;
+    Ok(value)
+}
+
+/// # Return the alpha blend equation of a draw buffer
+/// see [get_blend_source_rgb]
+pub fn get_blend_equation_alpha_buffer(buffer_index: u32) -> Option<BlendEquation> {
+    let [value] = get_i32_indexed(gl::BLEND_EQUATION_ALPHA, buffer_index);
+    BlendEquation::try_from(value as GLenum).ok()
+}
+
+/// # Error mapped get blend equation alpha buffer
+/// see [get_blend_equation_alpha_buffer]
+pub fn get_blend_equation_alpha_buffer_checked(
+    buffer_index: u32,
+) -> Result<Option<BlendEquation>, StateError> {
+    let value = get_blend_equation_alpha_buffer(buffer_index);
+    draw_buffer_error(buffer_index)?;
+    Ok(value)
+}
+
 /// # Set front and back function and reference value for stencil testing
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glStencilFunc.xhtml>
 ///
@@ -362,6 +1118,17 @@ pub fn stencil_func(func: CompareFunc, reference: i32, mask: u32) {
     unsafe { gl::StencilFunc(func, reference, mask) }
 }
 
+/// # Error mapped stencil func
+/// see [stencil_func]
+pub fn stencil_func_checked(
+    func: CompareFunc,
+    reference: i32,
+    mask: u32,
+) -> Result<(), StateError> {
+    stencil_func(func, reference, mask);
+    state_error()
+}
+
 /// # Set front and/or back function and reference value for stencil testing
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glStencilFuncSeparate.xhtml>
 ///
@@ -456,6 +1223,18 @@ pub fn stencil_func_separate(face: StencilFace, func: CompareFunc, reference: i3
     unsafe { gl::StencilFuncSeparate(face, func, reference, mask) }
 }
 
+/// # Error mapped stencil func separate
+/// see [stencil_func_separate]
+pub fn stencil_func_separate_checked(
+    face: StencilFace,
+    func: CompareFunc,
+    reference: i32,
+    mask: u32,
+) -> Result<(), StateError> {
+    stencil_func_separate(face, func, reference, mask);
+    state_error()
+}
+
 /// # Set front and back stencil test actions
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glStencilOp.xhtml>
 ///
@@ -550,6 +1329,17 @@ pub fn stencil_op(stencil_fail_op: StencilOp, depth_fail_op: StencilOp, depth_pa
     unsafe { gl::StencilOp(sfail, zfail, zpass) }
 }
 
+/// # Error mapped stencil op
+/// see [stencil_op]
+pub fn stencil_op_checked(
+    stencil_fail_op: StencilOp,
+    depth_fail_op: StencilOp,
+    depth_pass_op: StencilOp,
+) -> Result<(), StateError> {
+    stencil_op(stencil_fail_op, depth_fail_op, depth_pass_op);
+    state_error()
+}
+
 /// # Set front and/or back stencil test actions
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glStencilOpSeparate.xhtml>
 ///
@@ -642,3 +1432,15 @@ pub fn stencil_op_separate(
     let dppass = GLenum::from(depth_pass_op);
     unsafe { gl::StencilOpSeparate(face, sfail, dpfail, dppass) }
 }
+
+/// # Error mapped stencil op separate
+/// see [stencil_op_separate]
+pub fn stencil_op_separate_checked(
+    face: StencilFace,
+    stencil_fail_op: StencilOp,
+    depth_fail_op: StencilOp,
+    depth_pass_op: StencilOp,
+) -> Result<(), StateError> {
+    stencil_op_separate(face, stencil_fail_op, depth_fail_op, depth_pass_op);
+    state_error()
+}
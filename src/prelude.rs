@@ -1,12 +1,21 @@
 pub use crate::buffer::*;
+pub use crate::debug::*;
+pub use crate::error::*;
 pub use crate::framebuffer::*;
+pub use crate::framebuffer_builder::*;
 pub use crate::get::*;
+pub use crate::interface_block::*;
 pub use crate::masks::*;
+pub use crate::owned::*;
 pub use crate::post::*;
+pub use crate::rasterization::*;
+pub use crate::sampler::*;
 pub use crate::shader_creation::*;
 pub use crate::shader_query::*;
 pub use crate::shader_state::*;
+pub use crate::state_snapshot::*;
 pub use crate::texture::*;
+pub use crate::texture_container::*;
 pub use crate::vertex_array::*;
 pub use crate::vertex_render::*;
 pub use crate::*;
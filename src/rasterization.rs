@@ -0,0 +1,2272 @@
+//! # Rasterization
+//! <https://www.khronos.org/opengl/wiki/Category:Core_API_Ref_Rasterization>
+//!
+//! # Description
+//! The core OpenGL API reference for functions that control how primitives are mapped onto the
+//! framebuffer during [rasterization](https://www.khronos.org/opengl/wiki/Rasterization). This
+//! includes the state for:
+//! * [Viewport Transform](https://www.khronos.org/opengl/wiki/Vertex_Post-Processing#Viewport_transform)
+//! * [Depth Range](https://www.khronos.org/opengl/wiki/Vertex_Post-Processing#Viewport_transform)
+//! * [Scissor Test](https://www.khronos.org/opengl/wiki/Scissor_Test)
+//! * [Face Culling](https://www.khronos.org/opengl/wiki/Face_Culling)
+//! * [Polygon Mode and Offset](https://www.khronos.org/opengl/wiki/Primitive#Polygons)
+//! * [Point and Line Size](https://www.khronos.org/opengl/wiki/Primitive#Point_primitives)
+//! * [Multisampling](https://www.khronos.org/opengl/wiki/Multisampling)
+
+use crate::prelude::*;
+use gl::types::*;
+
+/// # Window rectangle in integer coordinates
+/// The lower left corner (`x`, `y`) and the size of a rectangle in window coordinates, used by
+/// [viewport] and [scissor] (and their indexed variants).
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// # Window rectangle in floating point coordinates
+/// The lower left corner (`x`, `y`) and the size of a rectangle in window coordinates, used by
+/// [viewport_indexed] and [viewport_array].
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct RectF32 {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// # Mapping of normalized device depth to window depth
+/// see [depth_range]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct DepthRange {
+    /// mapping of the near clipping plane to window coordinates.
+    pub near: f64,
+
+    /// mapping of the far clipping plane to window coordinates.
+    pub far: f64,
+}
+
+impl Default for DepthRange {
+    fn default() -> Self {
+        DepthRange {
+            near: 0.0,
+            far: 1.0,
+        }
+    }
+}
+
+/// # Facets culled when face culling is enabled
+/// see [cull_face]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CullFaceMode {
+    Front,
+    Back,
+    FrontAndBack,
+}
+
+impl From<CullFaceMode> for GLenum {
+    fn from(value: CullFaceMode) -> Self {
+        match value {
+            CullFaceMode::Front => gl::FRONT,
+            CullFaceMode::Back => gl::BACK,
+            CullFaceMode::FrontAndBack => gl::FRONT_AND_BACK,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for CullFaceMode {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::FRONT => Ok(CullFaceMode::Front),
+            gl::BACK => Ok(CullFaceMode::Back),
+            gl::FRONT_AND_BACK => Ok(CullFaceMode::FrontAndBack),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Winding of front-facing polygons
+/// see [front_face]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FrontFace {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl From<FrontFace> for GLenum {
+    fn from(value: FrontFace) -> Self {
+        match value {
+            FrontFace::Clockwise => gl::CW,
+            FrontFace::CounterClockwise => gl::CCW,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for FrontFace {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::CW => Ok(FrontFace::Clockwise),
+            gl::CCW => Ok(FrontFace::CounterClockwise),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Rasterization of polygons
+/// see [polygon_mode]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PolygonMode {
+    /// Polygon vertices that are marked as the start of a boundary edge are drawn as points.
+    Point,
+
+    /// Boundary edges of the polygon are drawn as line segments.
+    Line,
+
+    /// The interior of the polygon is filled.
+    Fill,
+}
+
+impl From<PolygonMode> for GLenum {
+    fn from(value: PolygonMode) -> Self {
+        match value {
+            PolygonMode::Point => gl::POINT,
+            PolygonMode::Line => gl::LINE,
+            PolygonMode::Fill => gl::FILL,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for PolygonMode {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::POINT => Ok(PolygonMode::Point),
+            gl::LINE => Ok(PolygonMode::Line),
+            gl::FILL => Ok(PolygonMode::Fill),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Vertex used as the source of flat shaded varyings
+/// see [provoking_vertex]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ProvokingVertex {
+    FirstVertexConvention,
+    LastVertexConvention,
+}
+
+impl From<ProvokingVertex> for GLenum {
+    fn from(value: ProvokingVertex) -> Self {
+        match value {
+            ProvokingVertex::FirstVertexConvention => gl::FIRST_VERTEX_CONVENTION,
+            ProvokingVertex::LastVertexConvention => gl::LAST_VERTEX_CONVENTION,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for ProvokingVertex {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::FIRST_VERTEX_CONVENTION => Ok(ProvokingVertex::FirstVertexConvention),
+            gl::LAST_VERTEX_CONVENTION => Ok(ProvokingVertex::LastVertexConvention),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # Origin of the point sprite texture coordinates
+/// see [point_sprite_coord_origin]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PointSpriteCoordOrigin {
+    LowerLeft,
+    UpperLeft,
+}
+
+impl From<PointSpriteCoordOrigin> for GLenum {
+    fn from(value: PointSpriteCoordOrigin) -> Self {
+        match value {
+            PointSpriteCoordOrigin::LowerLeft => gl::LOWER_LEFT,
+            PointSpriteCoordOrigin::UpperLeft => gl::UPPER_LEFT,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for PointSpriteCoordOrigin {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::LOWER_LEFT => Ok(PointSpriteCoordOrigin::LowerLeft),
+            gl::UPPER_LEFT => Ok(PointSpriteCoordOrigin::UpperLeft),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum RasterizationError {
+    Unexpected(Error),
+
+    InvalidRect(Rect),
+    InvalidRectF32(RectF32),
+    InvalidViewportIndex(u32),
+    InvalidViewportRange { first: u32, count: usize },
+    InvalidLineWidth(f32),
+    InvalidPointSize(f32),
+    InvalidPointFadeThresholdSize(f32),
+    InvalidSampleMaskWord(u32),
+    InvalidSampleIndex(u32),
+}
+
+impl std::fmt::Display for RasterizationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RasterizationError::Unexpected(error) => {
+                write!(f, "unexpected rasterization error: {error}")
+            }
+            RasterizationError::InvalidRect(rect) => write!(
+                f,
+                "invalid rectangle {}x{} at ({}, {})",
+                rect.width, rect.height, rect.x, rect.y
+            ),
+            RasterizationError::InvalidRectF32(rect) => write!(
+                f,
+                "invalid rectangle {}x{} at ({}, {})",
+                rect.width, rect.height, rect.x, rect.y
+            ),
+            RasterizationError::InvalidViewportIndex(index) => {
+                write!(f, "viewport index {index} is out of range")
+            }
+            RasterizationError::InvalidViewportRange { first, count } => write!(
+                f,
+                "{count} viewports starting at {first} exceed the viewport range"
+            ),
+            RasterizationError::InvalidLineWidth(width) => {
+                write!(f, "line width {width} is not greater than 0")
+            }
+            RasterizationError::InvalidPointSize(size) => {
+                write!(f, "point size {size} is not greater than 0")
+            }
+            RasterizationError::InvalidPointFadeThresholdSize(size) => {
+                write!(f, "point fade threshold size {size} is negative")
+            }
+            RasterizationError::InvalidSampleMaskWord(mask_number) => {
+                write!(f, "sample mask word {mask_number} is out of range")
+            }
+            RasterizationError::InvalidSampleIndex(index) => {
+                write!(f, "sample index {index} is out of range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RasterizationError {}
+
+fn rasterization_error() -> Result<(), RasterizationError> {
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+fn viewport_range_exceeded(first: u32, count: usize) -> bool {
+    first as usize + count > get_max_viewports() as usize
+}
+
+/// # Define the scissor box
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glScissor.xhtml>
+///
+/// # Arguments
+/// * `rect` - Specify the lower left corner of the scissor box, and the width and height of the
+///   scissor box. Initially the box is set to the dimensions of the window.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// enable(Capability::ScissorTest);
+/// scissor(Rect { x: 0, y: 0, width: 400, height: 300 });
+/// ```
+///
+/// # Description
+/// [scissor] defines a rectangle, called the scissor box, in window coordinates. The `x` and `y`
+/// fields of `rect` specify the lower left corner of the box. `width` and `height` specify the
+/// width and height of the box.
+///
+/// To enable and disable the scissor test, call [enable] and [disable] with
+/// [Capability::ScissorTest]. The test is initially disabled. While the test is enabled, only
+/// pixels that lie within the scissor box can be modified by drawing commands. Window coordinates
+/// have integer values at the shared corners of frame buffer pixels. A `1x1` box at `(0, 0)` allows
+/// modification of only the lower left pixel in the window, and a `0x0` box doesn't allow
+/// modification of any pixels in the window.
+///
+/// When the scissor test is disabled, it is as though the scissor box includes the entire window.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if either `width` or `height` is greater than [i32::MAX].
+///
+/// # Associated Gets
+/// * [get_scissor_box]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [scissor] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable]
+/// * [scissor_indexed]
+/// * [viewport]
+pub fn scissor(rect: Rect) {
+    let x = rect.x;
+    let y = rect.y;
+    let width = rect.width as GLsizei;
+    let height = rect.height as GLsizei;
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::Scissor(x, y, width, height) }
+}
+
+/// # Error mapped scissor
+/// see [scissor]
+pub fn scissor_checked(rect: Rect) -> Result<(), RasterizationError> {
+    scissor(rect);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(RasterizationError::InvalidRect(rect)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Define the scissor box for a specific viewport
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glScissorIndexed.xhtml>
+///
+/// # Arguments
+/// * `index` - Specifies the index of the viewport whose scissor box to modify.
+/// * `rect` - Specify the lower left corner of the scissor box, and the width and height of the
+///   scissor box.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// scissor_indexed(1, Rect { x: 400, y: 0, width: 400, height: 300 });
+/// ```
+///
+/// # Description
+/// [scissor_indexed] defines the scissor box for a specific viewport. `index` specifies the index
+/// of scissor box to modify, and must be less than [get_max_viewports]. The scissor test is
+/// enabled and disabled for every viewport at once with [Capability::ScissorTest].
+///
+/// While the scissor test is enabled, only pixels that lie within the scissor box of the viewport
+/// selected for a primitive can be modified by drawing commands. When the scissor test is
+/// disabled, it is as though the scissor box includes the entire window.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `index` is greater than or equal to [get_max_viewports].
+/// * [Error::InvalidValue] - if either `width` or `height` is greater than [i32::MAX].
+///
+/// # Associated Gets
+/// * [get_scissor_box_indexed]
+/// * [get_max_viewports]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [scissor_indexed] | N | N | N | N | N | N | N | Y | Y | Y | Y | Y |
+/// | [scissor_array] | N | N | N | N | N | N | N | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable]
+/// * [scissor]
+/// * [viewport_indexed]
+pub fn scissor_indexed(index: u32, rect: Rect) {
+    let left = rect.x;
+    let bottom = rect.y;
+    let width = rect.width as GLsizei;
+    let height = rect.height as GLsizei;
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::ScissorIndexed(index, left, bottom, width, height) }
+}
+
+/// # Error mapped scissor indexed
+/// see [scissor_indexed]
+pub fn scissor_indexed_checked(index: u32, rect: Rect) -> Result<(), RasterizationError> {
+    scissor_indexed(index, rect);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue if viewport_range_exceeded(index, 1) => {
+            Err(RasterizationError::InvalidViewportIndex(index))
+        }
+        Error::InvalidValue => Err(RasterizationError::InvalidRect(rect)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Define the scissor box for multiple viewports
+/// see [scissor_indexed]
+///
+/// # Arguments
+/// * `first` - Specifies the index of the first viewport whose scissor box to modify.
+/// * `rects` - Specifies the scissor boxes for the viewports `first..first + rects.len()`.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `first + rects.len()` is greater than [get_max_viewports].
+/// * [Error::InvalidValue] - if any `width` or `height` is greater than [i32::MAX].
+pub fn scissor_array(first: u32, rects: &[Rect]) {
+    let count = rects.len() as GLsizei;
+    let v = rects.as_ptr() as *const GLint;
+
+    // SAFE: synchronous read of `rects`, `Rect` is 4 packed `GLint`s, no memory retained
+    unsafe { gl::ScissorArrayv(first, count, v) }
+}
+
+/// # Error mapped scissor array
+/// see [scissor_array]
+pub fn scissor_array_checked(first: u32, rects: &[Rect]) -> Result<(), RasterizationError> {
+    scissor_array(first, rects);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue if viewport_range_exceeded(first, rects.len()) => {
+            Err(RasterizationError::InvalidViewportRange {
+                first,
+                count: rects.len(),
+            })
+        }
+        Error::InvalidValue => match rects
+            .iter()
+            .find(|rect| rect.width > i32::MAX as u32 || rect.height > i32::MAX as u32)
+        {
+            Some(rect) => Err(RasterizationError::InvalidRect(*rect)),
+            None => Err(RasterizationError::Unexpected(Error::InvalidValue)),
+        },
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Set the viewport
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glViewport.xhtml>
+///
+/// # Arguments
+/// * `rect` - Specify the lower left corner of the viewport rectangle, in pixels, and the width
+///   and height of the viewport. When a GL context is first attached to a window, `width` and
+///   `height` are set to the dimensions of that window.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// viewport(Rect { x: 0, y: 0, width: 800, height: 600 });
+/// ```
+///
+/// # Description
+/// [viewport] specifies the affine transformation of `x` and `y` from normalized device
+/// coordinates to window coordinates. Let `(x_nd, y_nd)` be normalized device coordinates. Then
+/// the window coordinates `(x_w, y_w)` are computed as follows:
+///
+/// ```text
+/// x_w = (x_nd + 1) * (width / 2) + x
+/// y_w = (y_nd + 1) * (height / 2) + y
+/// ```
+///
+/// Viewport width and height are silently clamped to a range that depends on the implementation.
+/// To query this range, call [get_max_viewport_dims].
+///
+/// [viewport] sets every viewport to the same rectangle. To set a single viewport, use
+/// [viewport_indexed].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if either `width` or `height` is greater than [i32::MAX].
+///
+/// # Associated Gets
+/// * [get_viewport]
+/// * [get_max_viewport_dims]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [viewport] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [depth_range]
+/// * [scissor]
+/// * [viewport_indexed]
+pub fn viewport(rect: Rect) {
+    let x = rect.x;
+    let y = rect.y;
+    let width = rect.width as GLsizei;
+    let height = rect.height as GLsizei;
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::Viewport(x, y, width, height) }
+}
+
+/// # Error mapped viewport
+/// see [viewport]
+pub fn viewport_checked(rect: Rect) -> Result<(), RasterizationError> {
+    viewport(rect);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(RasterizationError::InvalidRect(rect)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Set a specified viewport
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glViewportIndexed.xhtml>
+///
+/// # Arguments
+/// * `index` - Specify the index of the viewport to modify.
+/// * `rect` - Specify the lower left corner of the viewport rectangle, in pixels, and the width
+///   and height of the viewport.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// viewport_indexed(1, RectF32 { x: 400.0, y: 0.0, width: 400.0, height: 300.0 });
+/// ```
+///
+/// # Description
+/// [viewport_indexed] specifies the parameters for a single viewport. `index` specifies the index
+/// of the viewport to modify, and must be less than [get_max_viewports]. Viewport transformation
+/// maps normalized device coordinates into window coordinates, in the same manner as [viewport].
+///
+/// The location of the viewport's bottom left corner, given by `(x, y)` is clamped to be within
+/// the implementation-dependent viewport bounds range. Viewport width and height are silently
+/// clamped to a range that depends on the implementation, see [get_max_viewport_dims].
+///
+/// The precision with which the GL interprets the floating point viewport bounds is
+/// implementation-dependent.
+///
+/// Calling [viewport_indexed] is equivalent to calling [viewport_array] with `first` set to
+/// `index` and a single rectangle.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `index` is greater than or equal to [get_max_viewports].
+/// * [Error::InvalidValue] - if either `width` or `height` is negative.
+///
+/// # Associated Gets
+/// * [get_viewport_indexed]
+/// * [get_max_viewports]
+/// * [get_max_viewport_dims]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [viewport_indexed] | N | N | N | N | N | N | N | Y | Y | Y | Y | Y |
+/// | [viewport_array] | N | N | N | N | N | N | N | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [depth_range_indexed]
+/// * [scissor_indexed]
+/// * [viewport]
+pub fn viewport_indexed(index: u32, rect: RectF32) {
+    let RectF32 {
+        x,
+        y,
+        width,
+        height,
+    } = rect;
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::ViewportIndexedf(index, x, y, width, height) }
+}
+
+/// # Error mapped viewport indexed
+/// see [viewport_indexed]
+pub fn viewport_indexed_checked(index: u32, rect: RectF32) -> Result<(), RasterizationError> {
+    viewport_indexed(index, rect);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue if viewport_range_exceeded(index, 1) => {
+            Err(RasterizationError::InvalidViewportIndex(index))
+        }
+        Error::InvalidValue => Err(RasterizationError::InvalidRectF32(rect)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Set multiple viewports
+/// see [viewport_indexed]
+///
+/// # Arguments
+/// * `first` - Specify the first viewport to set.
+/// * `rects` - Specifies the viewports `first..first + rects.len()`.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `first + rects.len()` is greater than [get_max_viewports].
+/// * [Error::InvalidValue] - if any `width` or `height` is negative.
+pub fn viewport_array(first: u32, rects: &[RectF32]) {
+    let count = rects.len() as GLsizei;
+    let v = rects.as_ptr() as *const GLfloat;
+
+    // SAFE: synchronous read of `rects`, `RectF32` is 4 packed `GLfloat`s, no memory retained
+    unsafe { gl::ViewportArrayv(first, count, v) }
+}
+
+/// # Error mapped viewport array
+/// see [viewport_array]
+pub fn viewport_array_checked(first: u32, rects: &[RectF32]) -> Result<(), RasterizationError> {
+    viewport_array(first, rects);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue if viewport_range_exceeded(first, rects.len()) => {
+            Err(RasterizationError::InvalidViewportRange {
+                first,
+                count: rects.len(),
+            })
+        }
+        Error::InvalidValue => match rects
+            .iter()
+            .find(|rect| rect.width < 0.0 || rect.height < 0.0)
+        {
+            Some(rect) => Err(RasterizationError::InvalidRectF32(*rect)),
+            None => Err(RasterizationError::Unexpected(Error::InvalidValue)),
+        },
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Specify mapping of depth values from normalized device coordinates to window coordinates
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDepthRange.xhtml>
+///
+/// # Arguments
+/// * `near` - Specifies the mapping of the near clipping plane to window coordinates. The initial
+///   value is 0.
+/// * `far` - Specifies the mapping of the far clipping plane to window coordinates. The initial
+///   value is 1.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// depth_range(0.0, 1.0);
+/// ```
+///
+/// # Description
+/// After clipping and division by `w`, depth coordinates range from -1 to 1, corresponding to the
+/// near and far clipping planes. [depth_range] specifies a linear mapping of the normalized depth
+/// coordinates in this range to window depth coordinates. Regardless of the actual depth buffer
+/// implementation, window coordinate depth values are treated as though they range from 0 through
+/// 1 (like color components). Thus, the values accepted by [depth_range] are both clamped to this
+/// range before they are accepted.
+///
+/// The setting of `(0, 1)` maps the near plane to 0 and the far plane to 1. With this mapping, the
+/// depth buffer range is fully utilized.
+///
+/// [depth_range] sets the depth range of every viewport. To set a single viewport, use
+/// [depth_range_indexed].
+///
+/// # Compatability
+/// It is not necessary that `near` be less than `far`. Reverse mappings such as `near = 1`, and
+/// `far = 0` are acceptable.
+///
+/// # Associated Gets
+/// * [get_depth_range]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [depth_range] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [depth_func]
+/// * [depth_range_indexed]
+/// * [polygon_offset]
+/// * [viewport]
+pub fn depth_range(near: f64, far: f64) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::DepthRange(near, far) }
+}
+
+/// # Error mapped depth range
+/// see [depth_range]
+pub fn depth_range_checked(near: f64, far: f64) -> Result<(), RasterizationError> {
+    depth_range(near, far);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Specify mapping of depth values for a specified viewport
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDepthRangeIndexed.xhtml>
+///
+/// # Arguments
+/// * `index` - Specifies the index of the viewport whose depth range to update.
+/// * `near` - Specifies the mapping of the near clipping plane to window coordinates. The initial
+///   value is 0.
+/// * `far` - Specifies the mapping of the far clipping plane to window coordinates. The initial
+///   value is 1.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// depth_range_indexed(1, 0.5, 1.0);
+/// ```
+///
+/// # Description
+/// [depth_range_indexed] specifies a linear mapping of the normalized depth coordinates in the
+/// range -1 to 1 to window depth coordinates for the viewport `index`, in the same manner as
+/// [depth_range]. Both values are clamped to the range 0 through 1 before they are accepted.
+///
+/// # Compatability
+/// It is not necessary that `near` be less than `far`. Reverse mappings such as `near = 1`, and
+/// `far = 0` are acceptable.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `index` is greater than or equal to [get_max_viewports].
+///
+/// # Associated Gets
+/// * [get_depth_range_indexed]
+/// * [get_max_viewports]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [depth_range_indexed] | N | N | N | N | N | N | N | Y | Y | Y | Y | Y |
+/// | [depth_range_array] | N | N | N | N | N | N | N | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [depth_range]
+/// * [viewport_indexed]
+pub fn depth_range_indexed(index: u32, near: f64, far: f64) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::DepthRangeIndexed(index, near, far) }
+}
+
+/// # Error mapped depth range indexed
+/// see [depth_range_indexed]
+pub fn depth_range_indexed_checked(
+    index: u32,
+    near: f64,
+    far: f64,
+) -> Result<(), RasterizationError> {
+    depth_range_indexed(index, near, far);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(RasterizationError::InvalidViewportIndex(index)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Specify mapping of depth values for multiple viewports
+/// see [depth_range_indexed]
+///
+/// # Arguments
+/// * `first` - Specifies the index of the first viewport whose depth range to update.
+/// * `ranges` - Specifies the depth ranges of the viewports `first..first + ranges.len()`.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `first + ranges.len()` is greater than [get_max_viewports].
+pub fn depth_range_array(first: u32, ranges: &[DepthRange]) {
+    let count = ranges.len() as GLsizei;
+    let v = ranges.as_ptr() as *const GLdouble;
+
+    // SAFE: synchronous read of `ranges`, `DepthRange` is 2 packed `GLdouble`s, no memory retained
+    unsafe { gl::DepthRangeArrayv(first, count, v) }
+}
+
+/// # Error mapped depth range array
+/// see [depth_range_array]
+pub fn depth_range_array_checked(
+    first: u32,
+    ranges: &[DepthRange],
+) -> Result<(), RasterizationError> {
+    depth_range_array(first, ranges);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(RasterizationError::InvalidViewportRange {
+            first,
+            count: ranges.len(),
+        }),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Specify whether front- or back-facing facets can be culled
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCullFace.xhtml>
+///
+/// # Arguments
+/// * `mode` - Specifies whether front- or back-facing facets are candidates for culling. The
+/// initial value is [CullFaceMode::Back].
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// enable(Capability::CullFace);
+/// cull_face(CullFaceMode::Back);
+/// front_face(FrontFace::CounterClockwise);
+/// ```
+///
+/// # Description
+/// [cull_face] specifies whether front- or back-facing facets are culled (as specified by `mode`)
+/// when facet culling is enabled. Facet culling is initially disabled. To enable and disable facet
+/// culling, call [enable] and [disable] with [Capability::CullFace]. Facets include triangles,
+/// quadrilaterals, polygons, and rectangles.
+///
+/// [front_face] specifies which of the clockwise and counterclockwise facets are front-facing and
+/// back-facing.
+///
+/// If `mode` is [CullFaceMode::FrontAndBack], no facets are drawn, but other primitives such as
+/// points and lines are drawn.
+///
+/// # Associated Gets
+/// * [is_enabled]\([Capability::CullFace])
+/// * [get_cull_face_mode]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [cull_face] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable]
+/// * [front_face]
+pub fn cull_face(mode: CullFaceMode) {
+    let mode = GLenum::from(mode);
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::CullFace(mode) }
+}
+
+/// # Error mapped cull face
+/// see [cull_face]
+pub fn cull_face_checked(mode: CullFaceMode) -> Result<(), RasterizationError> {
+    cull_face(mode);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Define front- and back-facing polygons
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glFrontFace.xhtml>
+///
+/// # Arguments
+/// * `mode` - Specifies the orientation of front-facing polygons. The initial value is
+/// [FrontFace::CounterClockwise].
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // a mirrored model flips the winding of its triangles
+/// front_face(FrontFace::Clockwise);
+/// ```
+///
+/// # Description
+/// In a scene composed entirely of opaque closed surfaces, back-facing polygons are never visible.
+/// Eliminating these invisible polygons has the obvious benefit of speeding up the rendering of
+/// the image. To enable and disable elimination of back-facing polygons, call [enable] and
+/// [disable] with [Capability::CullFace].
+///
+/// The projection of a polygon to window coordinates is said to have clockwise winding if an
+/// imaginary object following the path from its first vertex, its second vertex, and so on, to its
+/// last vertex, and finally back to its first vertex, moves in a clockwise direction about the
+/// interior of the polygon. The polygon's winding is said to be counterclockwise if the imaginary
+/// object following the same path moves in a counterclockwise direction about the interior of the
+/// polygon. [front_face] specifies whether polygons with clockwise winding in window coordinates,
+/// or counterclockwise winding in window coordinates, are taken to be front-facing.
+///
+/// # Associated Gets
+/// * [get_front_face]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [front_face] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [cull_face]
+pub fn front_face(mode: FrontFace) {
+    let mode = GLenum::from(mode);
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::FrontFace(mode) }
+}
+
+/// # Error mapped front face
+/// see [front_face]
+pub fn front_face_checked(mode: FrontFace) -> Result<(), RasterizationError> {
+    front_face(mode);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Select a polygon rasterization mode
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glPolygonMode.xhtml>
+///
+/// # Arguments
+/// * `mode` - Specifies how polygons will be rasterized. The initial value is [PolygonMode::Fill]
+/// for both front- and back-facing polygons.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // a wireframe debug view
+/// polygon_mode(PolygonMode::Line);
+/// ```
+///
+/// # Description
+/// [polygon_mode] controls the interpretation of polygons for rasterization, of both front- and
+/// back-facing polygons. The polygon mode affects only the final rasterization of polygons. In
+/// particular, a polygon's vertices are lit and the polygon is clipped and possibly culled before
+/// these modes are applied.
+///
+/// Three modes are defined and can be specified in `mode`:
+/// * [PolygonMode::Point] - Polygon vertices that are marked as the start of a boundary edge are
+/// drawn as points. Point attributes such as [point_size] control the rasterization of the points.
+/// Polygon rasterization attributes other than the polygon mode have no effect.
+/// * [PolygonMode::Line] - Boundary edges of the polygon are drawn as line segments. Line
+/// attributes such as [line_width] and [Capability::LineSmooth] control the rasterization of the
+/// lines. Polygon rasterization attributes other than the polygon mode have no effect.
+/// * [PolygonMode::Fill] - The interior of the polygon is filled. Polygon attributes such as
+/// [Capability::PolygonSmooth] control the rasterization of the polygon.
+///
+/// Vertices are marked as boundary or nonboundary with an edge flag. Edge flags are generated
+/// internally by the GL when it decomposes triangle strips and fans.
+///
+/// # Compatability
+/// The core profile only accepts `GL_FRONT_AND_BACK` as the face, which [polygon_mode] always
+/// passes.
+///
+/// # Associated Gets
+/// * [get_polygon_mode]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [polygon_mode] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [line_width]
+/// * [point_size]
+/// * [polygon_offset]
+pub fn polygon_mode(mode: PolygonMode) {
+    let mode = GLenum::from(mode);
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::PolygonMode(gl::FRONT_AND_BACK, mode) }
+}
+
+/// # Error mapped polygon mode
+/// see [polygon_mode]
+pub fn polygon_mode_checked(mode: PolygonMode) -> Result<(), RasterizationError> {
+    polygon_mode(mode);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Set the scale and units used to calculate depth values
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glPolygonOffset.xhtml>
+///
+/// # Arguments
+/// * `factor` - Specifies a scale factor that is used to create a variable depth offset for each
+/// polygon. The initial value is 0.
+/// * `units` - Is multiplied by an implementation-specific value to create a constant depth offset.
+/// The initial value is 0.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // push shadow casters away from the light, to avoid shadow acne
+/// enable(Capability::PolygonOffsetFill);
+/// polygon_offset(2.0, 4.0);
+/// ```
+///
+/// # Description
+/// When [Capability::PolygonOffsetFill], [Capability::PolygonOffsetLine], or
+/// [Capability::PolygonOffsetPoint] is enabled, each fragment's depth value will be offset after
+/// it is interpolated from the depth values of the appropriate vertices. The value of the offset
+/// is `factor × DZ + r × units`, where `DZ` is a measurement of the change in depth relative to the
+/// screen area of the polygon, and `r` is the smallest value that is guaranteed to produce a
+/// resolvable offset for a given implementation. The offset is added before the depth test is
+/// performed and before the value is written into the depth buffer.
+///
+/// [polygon_offset] is useful for rendering hidden-line images, for applying decals to surfaces,
+/// and for rendering solids with highlighted edges.
+///
+/// # Associated Gets
+/// * [is_enabled]\([Capability::PolygonOffsetFill])
+/// * [get_polygon_offset_factor]
+/// * [get_polygon_offset_units]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [polygon_offset] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [depth_func]
+/// * [enable]
+/// * [polygon_mode]
+pub fn polygon_offset(factor: f32, units: f32) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::PolygonOffset(factor, units) }
+}
+
+/// # Error mapped polygon offset
+/// see [polygon_offset]
+pub fn polygon_offset_checked(factor: f32, units: f32) -> Result<(), RasterizationError> {
+    polygon_offset(factor, units);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Specify the width of rasterized lines
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glLineWidth.xhtml>
+///
+/// # Arguments
+/// * `width` - Specifies the width of rasterized lines. The initial value is 1.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let [_, max_width] = get_aliased_line_width_range();
+/// line_width(max_width.min(2.0));
+/// ```
+///
+/// # Description
+/// [line_width] specifies the rasterized width of both aliased and antialiased lines. Using a line
+/// width other than 1 has different effects, depending on whether line antialiasing is enabled. To
+/// enable and disable line antialiasing, call [enable] and [disable] with
+/// [Capability::LineSmooth]. Line antialiasing is initially disabled.
+///
+/// If line antialiasing is disabled, the actual width is determined by rounding the supplied width
+/// to the nearest integer. (If the rounding results in the value 0, it is as if the line width
+/// were 1.) If `|Δx| >= |Δy|`, `i` pixels are filled in each column that is rasterized, where `i`
+/// is the rounded value of `width`. Otherwise, `i` pixels are filled in each row that is
+/// rasterized.
+///
+/// If antialiasing is enabled, line rasterization produces a fragment for each pixel square that
+/// intersects the region lying within the rectangle having width equal to the current line width,
+/// length equal to the actual length of the line, and centered on the mathematical line segment.
+/// The coverage value for each fragment is the window coordinate area of the intersection of the
+/// rectangular region with the corresponding pixel square. This value is saved and used in the
+/// final rasterization step.
+///
+/// Not all widths can be supported when line antialiasing is enabled. If an unsupported width is
+/// requested, the nearest supported width is used. Only width 1 is guaranteed to be supported;
+/// others depend on the implementation. Likewise, there is a range for aliased line widths as
+/// well. To query the range of supported widths, call [get_aliased_line_width_range] and
+/// [get_smooth_line_width_range].
+///
+/// The line width specified by [line_width] is always returned by [get_line_width]. Clamping and
+/// rounding for aliased and antialiased lines have no effect on the specified value.
+///
+/// # Compatability
+/// Wide lines are deprecated, a forward compatible context generates [Error::InvalidValue] for a
+/// `width` greater than 1.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `width` is less than or equal to 0.
+///
+/// # Associated Gets
+/// * [get_line_width]
+/// * [get_aliased_line_width_range]
+/// * [get_smooth_line_width_range]
+/// * [is_enabled]\([Capability::LineSmooth])
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [line_width] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable]
+/// * [polygon_mode]
+pub fn line_width(width: f32) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::LineWidth(width) }
+}
+
+/// # Error mapped line width
+/// see [line_width]
+pub fn line_width_checked(width: f32) -> Result<(), RasterizationError> {
+    line_width(width);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(RasterizationError::InvalidLineWidth(width)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Specify the diameter of rasterized points
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glPointSize.xhtml>
+///
+/// # Arguments
+/// * `size` - Specifies the diameter of rasterized points. The initial value is 1.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// disable(Capability::ProgramPointSize);
+/// point_size(4.0);
+/// ```
+///
+/// # Description
+/// [point_size] specifies the rasterized diameter of points. If point size mode is disabled (see
+/// [enable] with [Capability::ProgramPointSize]), this value will be used to rasterize points.
+/// Otherwise, the value written to the shading language built-in variable `gl_PointSize` will be
+/// used.
+///
+/// The point size specified by [point_size] is always returned by [get_point_size]. Clamping and
+/// rounding for points have no effect on the specified value.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `size` is less than or equal to 0.
+///
+/// # Associated Gets
+/// * [get_point_size]
+/// * [get_point_size_range]
+/// * [get_point_fade_threshold_size]
+/// * [is_enabled]\([Capability::ProgramPointSize])
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [point_size] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable]
+/// * [point_parameter]
+pub fn point_size(size: f32) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::PointSize(size) }
+}
+
+/// # Error mapped point size
+/// see [point_size]
+pub fn point_size_checked(size: f32) -> Result<(), RasterizationError> {
+    point_size(size);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(RasterizationError::InvalidPointSize(size)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Specify point parameters
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glPointParameter.xhtml>
+///
+/// # Description
+/// Point parameters control the rasterization of points, in addition to the size set by
+/// [point_size] or written to `gl_PointSize`:
+/// * [point_fade_threshold_size] - the threshold value to which point sizes are clamped if they
+/// exceed the specified value. The initial value is 1.0.
+/// * [point_sprite_coord_origin] - the origin of the point sprite texture coordinates read from
+/// `gl_PointCoord`. The initial value is [PointSpriteCoordOrigin::UpperLeft].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if the fade threshold size is less than 0.
+///
+/// # Associated Gets
+/// * [get_point_fade_threshold_size]
+/// * [get_point_sprite_coord_origin]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [point_parameter] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [point_size]
+pub mod point_parameter {
+    use crate::prelude::*;
+    use gl::types::*;
+
+    /// # Set the threshold to which point sizes are clamped
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glPointParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `size` - Specifies the threshold value to which point sizes are clamped if they exceed
+    /// the specified value. The initial value is 1.0.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// point_fade_threshold_size(2.0);
+    /// ```
+    ///
+    /// # Errors
+    /// * [Error::InvalidValue] - if `size` is less than 0.
+    ///
+    /// # Associated Gets
+    /// * [get_point_fade_threshold_size]
+    ///
+    /// # See Also
+    /// * [point_parameter]
+    pub fn point_fade_threshold_size(size: f32) {
+        // SAFE: synchronous integer copy
+        unsafe { gl::PointParameterf(gl::POINT_FADE_THRESHOLD_SIZE, size) }
+    }
+
+    /// # Error mapped point fade threshold size
+    /// see [point_fade_threshold_size]
+    pub fn point_fade_threshold_size_checked(size: f32) -> Result<(), RasterizationError> {
+        point_fade_threshold_size(size);
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidValue => Err(RasterizationError::InvalidPointFadeThresholdSize(size)),
+            other => Err(RasterizationError::Unexpected(other)),
+        }
+    }
+
+    /// # Set the origin of the point sprite texture coordinates
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glPointParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `origin` - Specifies the point sprite texture coordinate origin. The initial value is
+    /// [PointSpriteCoordOrigin::UpperLeft].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// // match the origin of textures uploaded bottom row first
+    /// point_sprite_coord_origin(PointSpriteCoordOrigin::LowerLeft);
+    /// ```
+    ///
+    /// # Associated Gets
+    /// * [get_point_sprite_coord_origin]
+    ///
+    /// # See Also
+    /// * [point_parameter]
+    pub fn point_sprite_coord_origin(origin: PointSpriteCoordOrigin) {
+        let param = GLenum::from(origin) as GLint;
+
+        // SAFE: synchronous integer copy
+        unsafe { gl::PointParameteri(gl::POINT_SPRITE_COORD_ORIGIN, param) }
+    }
+
+    /// # Error mapped point sprite coord origin
+    /// see [point_sprite_coord_origin]
+    pub fn point_sprite_coord_origin_checked(
+        origin: PointSpriteCoordOrigin,
+    ) -> Result<(), RasterizationError> {
+        point_sprite_coord_origin(origin);
+        match get_error() {
+            Error::NoError => Ok(()),
+            other => Err(RasterizationError::Unexpected(other)),
+        }
+    }
+}
+pub use point_parameter::*;
+
+/// # Specify the vertex to be used as the source of data for flat shaded varyings
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glProvokingVertex.xhtml>
+///
+/// # Arguments
+/// * `mode` - Specifies the vertex to be used as the source of data for flat shaded varyings. The
+/// initial value is [ProvokingVertex::LastVertexConvention].
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // match the convention of Direct3D and Vulkan
+/// provoking_vertex(ProvokingVertex::FirstVertexConvention);
+/// ```
+///
+/// # Description
+/// Flatshading a vertex shader varying output means to assign all vertices of the primitive the
+/// same value for that output. The vertex from which these values is derived is known as the
+/// provoking vertex and [provoking_vertex] specifies which vertex is to be used as the source of
+/// data for flat shaded varyings.
+///
+/// `mode` controls the selection of the vertex whose values are assigned to flatshaded varying
+/// outputs. The interpretation of these values for the supported primitive types is:
+///
+/// | Primitive Type of Polygon `i` | [ProvokingVertex::FirstVertexConvention] | [ProvokingVertex::LastVertexConvention] |
+/// |-------------------------------|------------------------------------------|-----------------------------------------|
+/// | point | `i` | `i` |
+/// | independent line | `2i - 1` | `2i` |
+/// | line loop | `i` | `i + 1`, if `i < n`; `1`, if `i = n` |
+/// | line strip | `i` | `i + 1` |
+/// | independent triangle | `3i - 2` | `3i` |
+/// | triangle strip | `i` | `i + 2` |
+/// | triangle fan | `i + 1` | `i + 2` |
+/// | line adjacency | `4i - 2` | `4i - 1` |
+/// | line strip adjacency | `i + 1` | `i + 2` |
+/// | triangle adjacency | `6i - 5` | `6i - 1` |
+/// | triangle strip adjacency | `2i - 1` | `2i + 3` |
+///
+/// If a vertex or geometry shader is active, user-defined varying outputs may be flatshaded by
+/// using the `flat` qualifier when declaring the output.
+///
+/// # Associated Gets
+/// * [get_provoking_vertex]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [provoking_vertex] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [draw_arrays]
+pub fn provoking_vertex(mode: ProvokingVertex) {
+    let mode = GLenum::from(mode);
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::ProvokingVertex(mode) }
+}
+
+/// # Error mapped provoking vertex
+/// see [provoking_vertex]
+pub fn provoking_vertex_checked(mode: ProvokingVertex) -> Result<(), RasterizationError> {
+    provoking_vertex(mode);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+/// # Specify multisample coverage parameters
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSampleCoverage.xhtml>
+///
+/// # Arguments
+/// * `value` - Specify a single floating-point sample coverage value. The value is clamped to the
+/// range `[0, 1]`. The initial value is 1.0.
+/// * `invert` - Specify a single boolean value representing if the coverage masks should be
+/// inverted. The initial value is `false`.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// enable(Capability::SampleCoverage);
+/// sample_coverage(0.5, false);
+/// ```
+///
+/// # Description
+/// Multisampling samples a fragment multiple times at various locations within the pixel,
+/// resulting in a more accurate rendering of edges. Multisampling is enabled by default, see
+/// [Capability::Multisample].
+///
+/// [sample_coverage] defines a mask to modify the coverage of multisampled pixel fragments. This
+/// capability is enabled and disabled using [enable] and [disable] with
+/// [Capability::SampleCoverage].
+///
+/// If the temporary coverage mask is enabled, the fragment's coverage is ANDed with a mask of the
+/// fraction `value` of the samples, which is inverted when `invert` is `true`. Using
+/// complementary `value` and `invert` pairs across two draws, the samples covered by each draw
+/// do not overlap.
+///
+/// # Associated Gets
+/// * [get_sample_coverage_value]
+/// * [get_sample_coverage_invert]
+/// * [is_enabled]\([Capability::SampleCoverage])
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [sample_coverage] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable]
+/// * [sample_mask]
+pub fn sample_coverage(value: f32, invert: bool) {
+    let invert = GLboolean::from(invert);
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::SampleCoverage(value, invert) }
+}
+
+/// # Error mapped sample coverage
+/// see [sample_coverage]
+pub fn sample_coverage_checked(value: f32, invert: bool) -> Result<(), RasterizationError> {
+    sample_coverage(value, invert);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Set the value of a sub-word of the sample mask
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSampleMaski.xhtml>
+///
+/// # Arguments
+/// * `mask_number` - Specifies which 32-bit sub-word of the sample mask to update.
+/// * `mask` - Specifies the new value of the mask sub-word. The initial value has all bits set.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // only the even samples of the fragment are written
+/// enable(Capability::SampleMask);
+/// sample_mask(0, 0x5555_5555);
+/// ```
+///
+/// # Description
+/// [sample_mask] sets one 32-bit sub-word of the multi-word sample mask. `mask_number` specifies
+/// which sub-word is to be set, bit `b` of word `mask_number` corresponding to sample
+/// `32 * mask_number + b`. The sample mask is ANDed with the fragment's coverage when
+/// [Capability::SampleMask] is enabled.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `mask_number` is greater than or equal to the value of
+/// [get_max_sample_mask_words].
+///
+/// # Associated Gets
+/// * [get_sample_mask_value]
+/// * [get_max_sample_mask_words]
+/// * [is_enabled]\([Capability::SampleMask])
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [sample_mask] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable]
+/// * [sample_coverage]
+pub fn sample_mask(mask_number: u32, mask: u32) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::SampleMaski(mask_number, mask) }
+}
+
+/// # Error mapped sample mask
+/// see [sample_mask]
+pub fn sample_mask_checked(mask_number: u32, mask: u32) -> Result<(), RasterizationError> {
+    sample_mask(mask_number, mask);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(RasterizationError::InvalidSampleMaskWord(mask_number)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Specifies minimum rate at which sample shading takes place
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glMinSampleShading.xhtml>
+///
+/// # Arguments
+/// * `value` - Specifies the rate at which samples are shaded within each covered pixel. The
+/// value is clamped to the range `[0, 1]`. The initial value is 0.0.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // shade at least every other sample
+/// enable(Capability::SampleShading);
+/// min_sample_shading(0.5);
+/// ```
+///
+/// # Description
+/// [min_sample_shading] specifies the rate at which samples are shaded within a covered pixel.
+/// Sample-rate shading is enabled by calling [enable] with [Capability::SampleShading]. If
+/// [Capability::Multisample] or [Capability::SampleShading] is disabled, sample shading has no
+/// effect. Otherwise, an implementation must provide at least as many unique color values for
+/// each covered fragment as specified by `value` times the number of samples. Specifically, a
+/// value of 1.0 indicates that each sample in the framebuffer should be independently shaded. A
+/// value of 0.0 effectively allows the GL to ignore sample rate shading. Any value between 0.0
+/// and 1.0 allows the GL to shade only a subset of the total samples within each covered
+/// fragment.
+///
+/// # Associated Gets
+/// * [get_min_sample_shading_value]
+/// * [is_enabled]\([Capability::SampleShading])
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [min_sample_shading] | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [enable]
+pub fn min_sample_shading(value: f32) {
+    // SAFE: synchronous integer copy
+    unsafe { gl::MinSampleShading(value) }
+}
+
+/// # Error mapped min sample shading
+/// see [min_sample_shading]
+pub fn min_sample_shading_checked(value: f32) -> Result<(), RasterizationError> {
+    min_sample_shading(value);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Return the current viewport
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let Rect { width, height, .. } = get_viewport();
+/// ```
+///
+/// # Description
+/// Returns the `x` and `y` window coordinates of the viewport, followed by its width and height.
+/// Initially the `x` and `y` window coordinates are both set to 0, and the width and height are set
+/// to the width and height of the window into which the GL will do its rendering. See [viewport].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_viewport] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_viewport_indexed] | N | N | N | N | N | N | N | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [viewport]
+/// * [viewport_indexed]
+pub fn get_viewport() -> Rect {
+    let [x, y, width, height] = get_i32v(gl::VIEWPORT);
+    Rect {
+        x,
+        y,
+        width: width as u32,
+        height: height as u32,
+    }
+}
+
+/// # Error mapped get viewport
+/// see [get_viewport]
+pub fn get_viewport_checked() -> Result<Rect, RasterizationError> {
+    let value = get_viewport();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return a specified viewport
+/// see [get_viewport]
+///
+/// # Arguments
+/// * `index` - Specifies the index of the viewport to query.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `index` is greater than or equal to [get_max_viewports].
+pub fn get_viewport_indexed(index: u32) -> RectF32 {
+    let [x, y, width, height] = get_f32_indexed(gl::VIEWPORT, index);
+    RectF32 {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+/// # Error mapped get viewport indexed
+/// see [get_viewport_indexed]
+pub fn get_viewport_indexed_checked(index: u32) -> Result<RectF32, RasterizationError> {
+    let rect = get_viewport_indexed(index);
+    match get_error() {
+        Error::NoError => Ok(rect),
+        Error::InvalidValue => Err(RasterizationError::InvalidViewportIndex(index)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Return the current scissor box
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_scissor_box(), Rect { x: 0, y: 0, width: 400, height: 300 });
+/// ```
+///
+/// # Description
+/// Returns the `x` and `y` window coordinates of the scissor box, followed by its width and height.
+/// Initially the `x` and `y` window coordinates are both 0 and the width and height are set to the
+/// size of the window. See [scissor].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_scissor_box] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_scissor_box_indexed] | N | N | N | N | N | N | N | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [scissor]
+/// * [scissor_indexed]
+pub fn get_scissor_box() -> Rect {
+    let [x, y, width, height] = get_i32v(gl::SCISSOR_BOX);
+    Rect {
+        x,
+        y,
+        width: width as u32,
+        height: height as u32,
+    }
+}
+
+/// # Error mapped get scissor box
+/// see [get_scissor_box]
+pub fn get_scissor_box_checked() -> Result<Rect, RasterizationError> {
+    let value = get_scissor_box();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the scissor box of a specified viewport
+/// see [get_scissor_box]
+///
+/// # Arguments
+/// * `index` - Specifies the index of the viewport to query.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `index` is greater than or equal to [get_max_viewports].
+pub fn get_scissor_box_indexed(index: u32) -> Rect {
+    let [x, y, width, height] = get_i32_indexed(gl::SCISSOR_BOX, index);
+    Rect {
+        x,
+        y,
+        width: width as u32,
+        height: height as u32,
+    }
+}
+
+/// # Error mapped get scissor box indexed
+/// see [get_scissor_box_indexed]
+pub fn get_scissor_box_indexed_checked(index: u32) -> Result<Rect, RasterizationError> {
+    let rect = get_scissor_box_indexed(index);
+    match get_error() {
+        Error::NoError => Ok(rect),
+        Error::InvalidValue => Err(RasterizationError::InvalidViewportIndex(index)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Return the current depth range
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_depth_range(), DepthRange { near: 0.0, far: 1.0 });
+/// ```
+///
+/// # Description
+/// Returns the near and far mapping limits for the depth buffer. Integer values, if requested, are
+/// linearly mapped from the internal floating-point representation such that 1.0 returns the most
+/// positive representable integer value, and -1.0 returns the most negative representable integer
+/// value. The initial value is `(0, 1)`. See [depth_range].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_depth_range] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_depth_range_indexed] | N | N | N | N | N | N | N | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [depth_range]
+/// * [depth_range_indexed]
+pub fn get_depth_range() -> DepthRange {
+    let [near, far] = get_f64v(gl::DEPTH_RANGE);
+    DepthRange { near, far }
+}
+
+/// # Error mapped get depth range
+/// see [get_depth_range]
+pub fn get_depth_range_checked() -> Result<DepthRange, RasterizationError> {
+    let value = get_depth_range();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the depth range of a specified viewport
+/// see [get_depth_range]
+///
+/// # Arguments
+/// * `index` - Specifies the index of the viewport to query.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `index` is greater than or equal to [get_max_viewports].
+pub fn get_depth_range_indexed(index: u32) -> DepthRange {
+    let [near, far] = get_f64_indexed(gl::DEPTH_RANGE, index);
+    DepthRange { near, far }
+}
+
+/// # Error mapped get depth range indexed
+/// see [get_depth_range_indexed]
+pub fn get_depth_range_indexed_checked(index: u32) -> Result<DepthRange, RasterizationError> {
+    let range = get_depth_range_indexed(index);
+    match get_error() {
+        Error::NoError => Ok(range),
+        Error::InvalidValue => Err(RasterizationError::InvalidViewportIndex(index)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Return the maximum number of simultaneous viewports
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(get_max_viewports() >= 16);
+/// ```
+///
+/// # Description
+/// Returns a single value indicating the maximum number of simultaneous viewports that are
+/// supported. The value must be at least 16. See [viewport_indexed].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_viewports] | N | N | N | N | N | N | N | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [depth_range_indexed]
+/// * [scissor_indexed]
+/// * [viewport_indexed]
+pub fn get_max_viewports() -> u32 {
+    get_i32(gl::MAX_VIEWPORTS) as u32
+}
+
+/// # Error mapped get max viewports
+/// see [get_max_viewports]
+pub fn get_max_viewports_checked() -> Result<u32, RasterizationError> {
+    let value = get_max_viewports();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the maximum supported viewport dimensions
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let [max_width, max_height] = get_max_viewport_dims();
+/// ```
+///
+/// # Description
+/// Returns two values: the maximum supported width and height of the viewport. These must be at
+/// least as large as the visible dimensions of the display being rendered to. See [viewport].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_max_viewport_dims] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [viewport]
+/// * [viewport_indexed]
+pub fn get_max_viewport_dims() -> [u32; 2] {
+    let [width, height] = get_i32v(gl::MAX_VIEWPORT_DIMS);
+    [width as u32, height as u32]
+}
+
+/// # Error mapped get max viewport dims
+/// see [get_max_viewport_dims]
+pub fn get_max_viewport_dims_checked() -> Result<[u32; 2], RasterizationError> {
+    let value = get_max_viewport_dims();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the face culling, winding and polygon state
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_cull_face_mode(), Some(CullFaceMode::Back));
+/// assert_eq!(get_front_face(), Some(FrontFace::CounterClockwise));
+/// assert_eq!(get_polygon_mode(), Some(PolygonMode::Fill));
+/// ```
+///
+/// # Description
+/// Each getter returns a single value:
+/// * [get_cull_face_mode] - indicates whether front- or back-facing facets are to be culled. The
+/// initial value is [CullFaceMode::Back]. See [cull_face].
+/// * [get_front_face] - indicates whether clockwise or counterclockwise polygon winding is treated
+/// as front-facing. The initial value is [FrontFace::CounterClockwise]. See [front_face].
+/// * [get_polygon_mode] - indicates how polygons are rasterized. The initial value is
+/// [PolygonMode::Fill]. See [polygon_mode].
+/// * [get_provoking_vertex] - the currently selected provoking vertex convention. The initial
+/// value is [ProvokingVertex::LastVertexConvention]. See [provoking_vertex].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_cull_face_mode] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_front_face] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_polygon_mode] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_provoking_vertex] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [cull_face]
+/// * [front_face]
+/// * [polygon_mode]
+/// * [provoking_vertex]
+pub fn get_cull_face_mode() -> Option<CullFaceMode> {
+    CullFaceMode::try_from(get_i32(gl::CULL_FACE_MODE) as GLenum).ok()
+}
+
+/// # Error mapped get cull face mode
+/// see [get_cull_face_mode]
+pub fn get_cull_face_mode_checked() -> Result<Option<CullFaceMode>, RasterizationError> {
+    let value = get_cull_face_mode();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the winding of front-facing polygons
+/// see [get_cull_face_mode]
+pub fn get_front_face() -> Option<FrontFace> {
+    FrontFace::try_from(get_i32(gl::FRONT_FACE) as GLenum).ok()
+}
+
+/// # Error mapped get front face
+/// see [get_front_face]
+pub fn get_front_face_checked() -> Result<Option<FrontFace>, RasterizationError> {
+    let value = get_front_face();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the polygon rasterization mode
+/// see [get_cull_face_mode]
+pub fn get_polygon_mode() -> Option<PolygonMode> {
+    // front and back facing modes are both returned, and always equal in the core profile
+    let [front, _back] = get_i32v(gl::POLYGON_MODE);
+    PolygonMode::try_from(front as GLenum).ok()
+}
+
+/// # Error mapped get polygon mode
+/// see [get_polygon_mode]
+pub fn get_polygon_mode_checked() -> Result<Option<PolygonMode>, RasterizationError> {
+    let value = get_polygon_mode();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the provoking vertex convention
+/// see [get_cull_face_mode]
+pub fn get_provoking_vertex() -> Option<ProvokingVertex> {
+    ProvokingVertex::try_from(get_i32(gl::PROVOKING_VERTEX) as GLenum).ok()
+}
+
+/// # Error mapped get provoking vertex
+/// see [get_provoking_vertex]
+pub fn get_provoking_vertex_checked() -> Result<Option<ProvokingVertex>, RasterizationError> {
+    let value = get_provoking_vertex();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the polygon offset factor and units
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// polygon_offset(2.0, 4.0);
+/// assert_eq!(get_polygon_offset_factor(), 2.0);
+/// assert_eq!(get_polygon_offset_units(), 4.0);
+/// ```
+///
+/// # Description
+/// [get_polygon_offset_factor] returns one value, the scaling factor used to determine the
+/// variable offset that is added to the depth value of each fragment generated when a polygon is
+/// rasterized. [get_polygon_offset_units] returns one value, which is multiplied by an
+/// implementation-specific value and then added to the depth value of each fragment generated
+/// when a polygon is rasterized. The initial values are 0. See [polygon_offset].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_polygon_offset_factor] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_polygon_offset_units] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [polygon_offset]
+pub fn get_polygon_offset_factor() -> f32 {
+    get_f32(gl::POLYGON_OFFSET_FACTOR)
+}
+
+/// # Error mapped get polygon offset factor
+/// see [get_polygon_offset_factor]
+pub fn get_polygon_offset_factor_checked() -> Result<f32, RasterizationError> {
+    let value = get_polygon_offset_factor();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the polygon offset units
+/// see [get_polygon_offset_factor]
+pub fn get_polygon_offset_units() -> f32 {
+    get_f32(gl::POLYGON_OFFSET_UNITS)
+}
+
+/// # Error mapped get polygon offset units
+/// see [get_polygon_offset_units]
+pub fn get_polygon_offset_units_checked() -> Result<f32, RasterizationError> {
+    let value = get_polygon_offset_units();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the point and line size state
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// point_size(4.0);
+/// assert_eq!(get_point_size(), 4.0);
+/// assert_eq!(get_line_width(), 1.0);
+/// ```
+///
+/// # Description
+/// Each getter returns a single value:
+/// * [get_line_width] - the line width as specified with [line_width]. The initial value is 1.
+/// * [get_point_size] - the point size as specified by [point_size]. The initial value is 1.
+/// * [get_point_fade_threshold_size] - the point size threshold for determining the point size.
+/// The initial value is 1. See [point_fade_threshold_size].
+/// * [get_point_sprite_coord_origin] - the origin of the point sprite texture coordinates. The
+/// initial value is [PointSpriteCoordOrigin::UpperLeft]. See [point_sprite_coord_origin].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_line_width] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_point_size] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_point_fade_threshold_size] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_point_sprite_coord_origin] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_aliased_line_width_range]
+/// * [get_point_size_range]
+pub fn get_line_width() -> f32 {
+    get_f32(gl::LINE_WIDTH)
+}
+
+/// # Error mapped get line width
+/// see [get_line_width]
+pub fn get_line_width_checked() -> Result<f32, RasterizationError> {
+    let value = get_line_width();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the point size
+/// see [get_line_width]
+pub fn get_point_size() -> f32 {
+    get_f32(gl::POINT_SIZE)
+}
+
+/// # Error mapped get point size
+/// see [get_point_size]
+pub fn get_point_size_checked() -> Result<f32, RasterizationError> {
+    let value = get_point_size();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the point fade threshold size
+/// see [get_line_width]
+pub fn get_point_fade_threshold_size() -> f32 {
+    get_f32(gl::POINT_FADE_THRESHOLD_SIZE)
+}
+
+/// # Error mapped get point fade threshold size
+/// see [get_point_fade_threshold_size]
+pub fn get_point_fade_threshold_size_checked() -> Result<f32, RasterizationError> {
+    let value = get_point_fade_threshold_size();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the origin of the point sprite texture coordinates
+/// see [get_line_width]
+pub fn get_point_sprite_coord_origin() -> Option<PointSpriteCoordOrigin> {
+    PointSpriteCoordOrigin::try_from(get_i32(gl::POINT_SPRITE_COORD_ORIGIN) as GLenum).ok()
+}
+
+/// # Error mapped get point sprite coord origin
+/// see [get_point_sprite_coord_origin]
+pub fn get_point_sprite_coord_origin_checked(
+) -> Result<Option<PointSpriteCoordOrigin>, RasterizationError> {
+    let value = get_point_sprite_coord_origin();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the range of supported point and line sizes
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let [min_width, max_width] = get_aliased_line_width_range();
+/// let [min_size, max_size] = get_point_size_range();
+/// ```
+///
+/// # Description
+/// Each getter returns two values, the smallest and largest supported size:
+/// * [get_aliased_line_width_range] - the widths for aliased lines.
+/// * [get_smooth_line_width_range] - the widths for antialiased lines, see
+/// [Capability::LineSmooth].
+/// * [get_point_size_range] - the sizes for points, of which the largest must be at least 1.
+///
+/// Sizes given to [line_width] and [point_size] outside of these ranges are clamped to them.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_aliased_line_width_range] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_smooth_line_width_range] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_point_size_range] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [line_width]
+/// * [point_size]
+pub fn get_aliased_line_width_range() -> [f32; 2] {
+    get_f32v(gl::ALIASED_LINE_WIDTH_RANGE)
+}
+
+/// # Error mapped get aliased line width range
+/// see [get_aliased_line_width_range]
+pub fn get_aliased_line_width_range_checked() -> Result<[f32; 2], RasterizationError> {
+    let value = get_aliased_line_width_range();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the range of supported antialiased line widths
+/// see [get_aliased_line_width_range]
+pub fn get_smooth_line_width_range() -> [f32; 2] {
+    get_f32v(gl::SMOOTH_LINE_WIDTH_RANGE)
+}
+
+/// # Error mapped get smooth line width range
+/// see [get_smooth_line_width_range]
+pub fn get_smooth_line_width_range_checked() -> Result<[f32; 2], RasterizationError> {
+    let value = get_smooth_line_width_range();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the range of supported point sizes
+/// see [get_aliased_line_width_range]
+pub fn get_point_size_range() -> [f32; 2] {
+    get_f32v(gl::POINT_SIZE_RANGE)
+}
+
+/// # Error mapped get point size range
+/// see [get_point_size_range]
+pub fn get_point_size_range_checked() -> Result<[f32; 2], RasterizationError> {
+    let value = get_point_size_range();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the multisample coverage and shading state
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// sample_coverage(0.25, true);
+/// assert_eq!(get_sample_coverage_value(), 0.25);
+/// assert!(get_sample_coverage_invert());
+/// ```
+///
+/// # Description
+/// Each getter returns a single value:
+/// * [get_sample_coverage_value] - the current sample coverage value. The initial value is 1.0.
+/// See [sample_coverage].
+/// * [get_sample_coverage_invert] - whether the temporary coverage value should be inverted. The
+/// initial value is `false`. See [sample_coverage].
+/// * [get_min_sample_shading_value] - the minimum fraction of samples shaded per fragment. The
+/// initial value is 0.0. See [min_sample_shading].
+/// * [get_max_sample_mask_words] - the maximum number of sample mask words, at least 1. See
+/// [sample_mask].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_sample_coverage_value] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_sample_coverage_invert] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_min_sample_shading_value] | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+/// | [get_max_sample_mask_words] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_sample_mask_value]
+/// * [get_sample_position]
+pub fn get_sample_coverage_value() -> f32 {
+    get_f32(gl::SAMPLE_COVERAGE_VALUE)
+}
+
+/// # Error mapped get sample coverage value
+/// see [get_sample_coverage_value]
+pub fn get_sample_coverage_value_checked() -> Result<f32, RasterizationError> {
+    let value = get_sample_coverage_value();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return whether the sample coverage is inverted
+/// see [get_sample_coverage_value]
+pub fn get_sample_coverage_invert() -> bool {
+    get_bool(gl::SAMPLE_COVERAGE_INVERT)
+}
+
+/// # Error mapped get sample coverage invert
+/// see [get_sample_coverage_invert]
+pub fn get_sample_coverage_invert_checked() -> Result<bool, RasterizationError> {
+    let value = get_sample_coverage_invert();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the minimum sample shading rate
+/// see [get_sample_coverage_value]
+pub fn get_min_sample_shading_value() -> f32 {
+    get_f32(gl::MIN_SAMPLE_SHADING_VALUE)
+}
+
+/// # Error mapped get min sample shading value
+/// see [get_min_sample_shading_value]
+pub fn get_min_sample_shading_value_checked() -> Result<f32, RasterizationError> {
+    let value = get_min_sample_shading_value();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return the maximum number of sample mask words
+/// see [get_sample_coverage_value]
+pub fn get_max_sample_mask_words() -> u32 {
+    get_i32(gl::MAX_SAMPLE_MASK_WORDS) as u32
+}
+
+/// # Error mapped get max sample mask words
+/// see [get_max_sample_mask_words]
+pub fn get_max_sample_mask_words_checked() -> Result<u32, RasterizationError> {
+    let value = get_max_sample_mask_words();
+    rasterization_error()?;
+    Ok(value)
+}
+
+/// # Return a sub-word of the sample mask
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>
+///
+/// # Arguments
+/// * `mask_number` - Specifies which 32-bit sub-word of the sample mask to return.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_sample_mask_value(0), u32::MAX);
+/// ```
+///
+/// # Description
+/// Returns the 32-bit sub-word `mask_number` of the sample mask. The initial value has all bits
+/// set. See [sample_mask].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `mask_number` is greater than or equal to the value of
+/// [get_max_sample_mask_words].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_sample_mask_value] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [sample_mask]
+pub fn get_sample_mask_value(mask_number: u32) -> u32 {
+    let [mask] = get_i32_indexed(gl::SAMPLE_MASK_VALUE, mask_number);
+    mask as u32
+}
+
+/// # Error mapped get sample mask value
+/// see [get_sample_mask_value]
+pub fn get_sample_mask_value_checked(mask_number: u32) -> Result<u32, RasterizationError> {
+    let mask = get_sample_mask_value(mask_number);
+    match get_error() {
+        Error::NoError => Ok(mask),
+        Error::InvalidValue => Err(RasterizationError::InvalidSampleMaskWord(mask_number)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
+
+/// # Retrieve the location of a sample
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetMultisample.xhtml>
+///
+/// # Arguments
+/// * `index` - Specifies the index of the sample whose position to query.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let [x, y] = get_sample_position(0);
+/// ```
+///
+/// # Description
+/// [get_sample_position] queries the location of the sample `index` of the draw framebuffer. The
+/// location is returned as two floating-point values, the `x` and `y` offset of the sample from
+/// the lower left corner of the pixel, in the range `[0, 1]`. The pixel center lies at
+/// `[0.5, 0.5]`.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `index` is greater than or equal to the number of samples of the
+/// draw framebuffer, see [get_framebuffer_samples].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_sample_position] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_framebuffer_samples]
+/// * [min_sample_shading]
+pub fn get_sample_position(index: u32) -> [f32; 2] {
+    let mut position = [0.0; 2];
+
+    // SAFE: synchronous write of two floats, no memory retained
+    unsafe { gl::GetMultisamplefv(gl::SAMPLE_POSITION, index, position.as_mut_ptr()) };
+    position
+}
+
+/// # Error mapped get sample position
+/// see [get_sample_position]
+pub fn get_sample_position_checked(index: u32) -> Result<[f32; 2], RasterizationError> {
+    let position = get_sample_position(index);
+    match get_error() {
+        Error::NoError => Ok(position),
+        Error::InvalidValue => Err(RasterizationError::InvalidSampleIndex(index)),
+        other => Err(RasterizationError::Unexpected(other)),
+    }
+}
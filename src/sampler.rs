@@ -0,0 +1,1228 @@
+//! # Sampler Objects
+//! <https://www.khronos.org/opengl/wiki/Sampler_Object>
+//!
+//! # Description
+//! A sampler object stores the sampling parameters of a texture access (filtering, wrapping, level
+//! of detail and depth comparison), separately from the texture itself. While a sampler is bound
+//! to a texture unit with [bind_sampler], its parameters override the [tex_parameter] state of
+//! any texture bound to that unit.
+//!
+//! Binding the same texture to two units, each with a different sampler, lets a single draw sample
+//! one texture with two different states:
+//! ```no_run
+//! use rgl::prelude::*;
+//!
+//! # let texture = Texture(1);
+//! let mut samplers = [Sampler::default(); 2];
+//! gen_samplers(&mut samplers);
+//! sampler_min_filter(samplers[0], TextureMinFilter::Nearest);
+//! sampler_mag_filter(samplers[0], TextureMagFilter::Nearest);
+//! sampler_min_filter(samplers[1], TextureMinFilter::Linear);
+//! sampler_mag_filter(samplers[1], TextureMagFilter::Linear);
+//!
+//! for unit in 0..2 {
+//!     active_texture(unit);
+//!     bind_texture(TextureBindingTarget::Image2D, texture);
+//! }
+//! bind_samplers(0, &samplers);
+//! ```
+
+use crate::prelude::*;
+use gl::types::*;
+
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+#[repr(transparent)]
+pub struct Sampler(pub u32);
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SamplerError {
+    Unexpected(Error),
+
+    InvalidSampler(Sampler),
+    InvalidTextureUnit(u32),
+    InvalidTextureUnitRange { first: u32, count: usize },
+}
+
+impl std::fmt::Display for SamplerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SamplerError::Unexpected(error) => write!(f, "unexpected sampler error: {error}"),
+            SamplerError::InvalidSampler(sampler) => {
+                write!(f, "{} is not the name of a sampler object", sampler.0)
+            }
+            SamplerError::InvalidTextureUnit(unit) => {
+                write!(f, "texture unit {unit} is out of range")
+            }
+            SamplerError::InvalidTextureUnitRange { first, count } => write!(
+                f,
+                "texture units {first}..{} are out of range",
+                *first as usize + count
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SamplerError {}
+
+/// Every sampler function generates [Error::InvalidOperation] (or [Error::InvalidValue] for
+/// [bind_sampler]) when `sampler` is not the name of a sampler object
+fn sampler_error(sampler: Sampler) -> Result<(), SamplerError> {
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation | Error::InvalidValue => Err(SamplerError::InvalidSampler(sampler)),
+        other => Err(SamplerError::Unexpected(other)),
+    }
+}
+
+/// # Bind a named sampler to a texturing unit
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBindSampler.xhtml>
+///
+/// # Arguments
+/// * `unit` - Specifies the index of the texture unit to which the sampler is bound.
+/// * `sampler` - Specifies the name of a sampler.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// bind_sampler(0, Sampler(42));
+/// ```
+///
+/// # Description
+/// [bind_sampler] binds `sampler` to the texture unit at index `unit`. `sampler` must be zero or
+/// the name of a sampler object previously returned from a call to [gen_samplers] or
+/// [create_samplers]. `unit` must be a value between zero and the value of
+/// [get_max_combined_texture_image_units] minus one.
+///
+/// When a sampler object is bound to a texture unit, its state supersedes that of the texture
+/// object bound to that texture unit. If the sampler name zero is bound to a texture unit, the
+/// currently bound texture's sampler state becomes active. A single sampler object may be bound to
+/// multiple texture units simultaneously.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `unit` is greater than or equal to the value of
+/// [get_max_combined_texture_image_units].
+/// * [Error::InvalidOperation] - if `sampler` is not zero or a name previously returned from a
+/// call to [gen_samplers] or [create_samplers], or if such a name has since been deleted with
+/// [delete_samplers].
+///
+/// # Associated Gets
+/// * [get_sampler_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [bind_sampler] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_samplers]
+/// * [delete_samplers]
+/// * [gen_samplers]
+/// * [is_sampler]
+/// * [sampler_parameter]
+pub fn bind_sampler(unit: u32, sampler: Sampler) {
+    let sampler = sampler.0;
+
+    // SAFE: synchronous integer copy
+    unsafe { gl::BindSampler(unit, sampler) }
+}
+
+/// # Error mapped bind sampler
+/// see [bind_sampler]
+pub fn bind_sampler_checked(unit: u32, sampler: Sampler) -> Result<(), SamplerError> {
+    bind_sampler(unit, sampler);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(SamplerError::InvalidTextureUnit(unit)),
+        Error::InvalidOperation => Err(SamplerError::InvalidSampler(sampler)),
+        other => Err(SamplerError::Unexpected(other)),
+    }
+}
+
+/// # Bind one or more named sampler objects to a sequence of consecutive sampler units
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBindSamplers.xhtml>
+///
+/// # Arguments
+/// * `first` - Specifies the first sampler unit to which a sampler object is to be bound.
+/// * `samplers` - Specifies the samplers to bind to the units `first..first + samplers.len()`.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// bind_samplers(0, &[Sampler(1), Sampler(0), Sampler(2)]);
+/// ```
+///
+/// # Description
+/// [bind_samplers] binds each sampler in `samplers` to the consecutive sampler units starting at
+/// `first`, as though [bind_sampler] were called for each of them. A sampler of zero unbinds any
+/// sampler currently bound to its unit.
+///
+/// # Compatability
+/// * 4.4 - [bind_samplers]
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `first + samplers.len()` is greater than the value of
+/// [get_max_combined_texture_image_units].
+/// * [Error::InvalidOperation] - if any value in `samplers` is not zero or the name of an existing
+/// sampler object.
+///
+/// # Associated Gets
+/// * [get_sampler_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [bind_samplers] | N | N | N | N | N | N | N | N | N | N | Y | Y |
+///
+/// # See Also
+/// * [bind_sampler]
+/// * [gen_samplers]
+/// * [sampler_parameter]
+pub fn bind_samplers(first: u32, samplers: &[Sampler]) {
+    let count = samplers.len() as GLsizei;
+    let samplers = samplers.as_ptr() as *const GLuint;
+
+    // SAFE: synchronous read of `samplers`, no memory retained
+    unsafe { gl::BindSamplers(first, count, samplers) }
+}
+
+/// # Error mapped bind samplers
+/// see [bind_samplers]
+pub fn bind_samplers_checked(first: u32, samplers: &[Sampler]) -> Result<(), SamplerError> {
+    bind_samplers(first, samplers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation
+            if first as usize + samplers.len()
+                > get_max_combined_texture_image_units() as usize =>
+        {
+            Err(SamplerError::InvalidTextureUnitRange {
+                first,
+                count: samplers.len(),
+            })
+        }
+        Error::InvalidOperation => match samplers
+            .iter()
+            .find(|sampler| sampler.0 != 0 && !is_sampler(**sampler))
+        {
+            Some(sampler) => Err(SamplerError::InvalidSampler(*sampler)),
+            None => Err(SamplerError::Unexpected(Error::InvalidOperation)),
+        },
+        other => Err(SamplerError::Unexpected(other)),
+    }
+}
+
+/// # Create sampler objects
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCreateSamplers.xhtml>
+///
+/// # Arguments
+/// * `samplers` - Specifies an array in which the names of the new sampler objects are stored.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut samplers = [Sampler::default(); 2];
+/// create_samplers(&mut samplers);
+/// ```
+///
+/// # Description
+/// [create_samplers] returns new sampler object names in `samplers`, each representing a new
+/// sampler object initialized to the default sampler state.
+///
+/// # Compatability
+/// * 4.5 - [create_samplers]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [create_samplers] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [gen_samplers]
+/// * [delete_samplers]
+pub fn create_samplers(samplers: &mut [Sampler]) {
+    let n = samplers.len() as GLsizei;
+    let samplers = samplers.as_mut_ptr() as *mut GLuint;
+
+    // SAFE: `samplers` is an out param, and `n` dictates the pointer length given by std::slice
+    unsafe { gl::CreateSamplers(n, samplers) }
+}
+
+/// # Error mapped create samplers
+/// see [create_samplers]
+pub fn create_samplers_checked(samplers: &mut [Sampler]) -> Result<(), SamplerError> {
+    create_samplers(samplers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(SamplerError::Unexpected(other)),
+    }
+}
+
+/// # Delete named sampler objects
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDeleteSamplers.xhtml>
+///
+/// # Arguments
+/// * `samplers` - Specifies a slice of sampler objects to be deleted.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// delete_samplers(&[Sampler(1), Sampler(2)]);
+/// ```
+///
+/// # Description
+/// [delete_samplers] deletes all sampler objects named by the elements of `samplers`. After a
+/// sampler object is deleted, its name is again unused. If a sampler object that is currently
+/// bound to a sampler unit is deleted, it is as though [bind_sampler] is called with `unit` set to
+/// the unit the sampler is bound to and `sampler` zero.
+///
+/// [delete_samplers] silently ignores 0's and names that do not correspond to existing samplers.
+///
+/// # Associated Gets
+/// * [is_sampler]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [delete_samplers] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [gen_samplers]
+/// * [bind_sampler]
+/// * [is_sampler]
+pub fn delete_samplers(samplers: &[Sampler]) {
+    let n = samplers.len() as GLsizei;
+    let samplers = samplers.as_ptr() as *const GLuint;
+
+    // SAFE: synchronous read of `samplers`, no memory retained
+    unsafe { gl::DeleteSamplers(n, samplers) }
+}
+
+/// # Error mapped delete samplers
+/// see [delete_samplers]
+pub fn delete_samplers_checked(samplers: &[Sampler]) -> Result<(), SamplerError> {
+    delete_samplers(samplers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(SamplerError::Unexpected(other)),
+    }
+}
+
+/// # Generate sampler object names
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGenSamplers.xhtml>
+///
+/// # Arguments
+/// * `samplers` - Specifies an array in which the generated sampler object names are stored.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut sampler = Sampler::default();
+/// gen_samplers(std::slice::from_mut(&mut sampler));
+/// assert_ne!(sampler, Sampler(0));
+/// ```
+///
+/// # Description
+/// [gen_samplers] returns sampler object names in `samplers`. There is no guarantee that the names
+/// form a contiguous set of integers; however, it is guaranteed that none of the returned names was
+/// in use immediately before the call to [gen_samplers].
+///
+/// Sampler object names returned by a call to [gen_samplers] are not returned by subsequent calls,
+/// unless they are first deleted with [delete_samplers].
+///
+/// # Associated Gets
+/// * [is_sampler]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [gen_samplers] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_sampler]
+/// * [create_samplers]
+/// * [delete_samplers]
+/// * [is_sampler]
+/// * [sampler_parameter]
+pub fn gen_samplers(samplers: &mut [Sampler]) {
+    let n = samplers.len() as GLsizei;
+    let samplers = samplers.as_mut_ptr() as *mut GLuint;
+
+    // SAFE: `samplers` is an out param, and `n` dictates the pointer length given by std::slice
+    unsafe { gl::GenSamplers(n, samplers) }
+}
+
+/// # Error mapped generate samplers
+/// see [gen_samplers]
+pub fn gen_samplers_checked(samplers: &mut [Sampler]) -> Result<(), SamplerError> {
+    gen_samplers(samplers);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(SamplerError::Unexpected(other)),
+    }
+}
+
+/// # Determine if a name corresponds to a sampler object
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glIsSampler.xhtml>
+///
+/// # Arguments
+/// * `sampler` - Specifies a value that may be the name of a sampler object.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(is_sampler(Sampler(42)));
+/// assert!(!is_sampler(Sampler(0)));
+/// ```
+///
+/// # Description
+/// [is_sampler] returns true if `sampler` is currently the name of a sampler object. If `sampler`
+/// is zero, or is a non-zero value that is not currently the name of a sampler object, or if an
+/// error occurs, [is_sampler] returns false.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [is_sampler] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_sampler]
+/// * [delete_samplers]
+/// * [gen_samplers]
+pub fn is_sampler(sampler: Sampler) -> bool {
+    let sampler = sampler.0;
+
+    // SAFE: synchronous integer copy
+    let val = unsafe { gl::IsSampler(sampler) };
+    val == gl::TRUE
+}
+
+/// # Error mapped is sampler
+/// see [is_sampler]
+pub fn is_sampler_checked(sampler: Sampler) -> Result<bool, SamplerError> {
+    let val = is_sampler(sampler);
+    match get_error() {
+        Error::NoError => Ok(val),
+        other => Err(SamplerError::Unexpected(other)),
+    }
+}
+
+/// # Set sampler parameters
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+///
+/// # Description
+/// The sampler parameters mirror the sampling parameters of [tex_parameter], and take effect in
+/// place of them for every texture unit the sampler is bound to with [bind_sampler].
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `sampler` is not the name of a sampler object previously
+/// returned from a call to [gen_samplers] or [create_samplers].
+///
+/// # Associated Gets
+/// * [get_sampler_parameter]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [sampler_parameter] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [bind_sampler]
+/// * [gen_samplers]
+/// * [tex_parameter]
+pub mod sampler_parameter {
+    use super::sampler_error;
+    use crate::prelude::*;
+    use gl::types::*;
+
+    fn sampler_param_i32(sampler: Sampler, pname: GLenum, param: i32) {
+        let sampler = sampler.0;
+
+        // SAFE: synchronous integer copy
+        unsafe { gl::SamplerParameteri(sampler, pname, param) }
+    }
+
+    fn sampler_param_f32(sampler: Sampler, pname: GLenum, param: f32) {
+        let sampler = sampler.0;
+
+        // SAFE: synchronous integer copy
+        unsafe { gl::SamplerParameterf(sampler, pname, param) }
+    }
+
+    /// # Set a texel that should be used for border texels
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `texel` - Specifies border values that should be used for border texels
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_border_colour_f32(Sampler(42), [0.5, 0.9, 0.2, 1.0]);
+    /// sampler_border_colour_i32(Sampler(42), [128, 250, 12, 255]);
+    /// sampler_border_colour_u32(Sampler(42), [42, 7, 11, 255]);
+    /// ```
+    ///
+    /// # Description
+    /// `texel` specifies four values that define the border values that should be used for border
+    /// texels, when a wrap mode is [TextureWrapMode::ClampToBorder]. The `i32` and `u32` variants
+    /// store the values unmodified for integer textures. The initial value is
+    /// `[0.0, 0.0, 0.0, 0.0]`.
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_border_colour_f32]
+    ///
+    /// # See Also
+    /// * [sampler_parameter]
+    /// * [texture_target_border_colour_f32]
+    pub fn sampler_border_colour_f32(sampler: Sampler, texel: [f32; 4]) {
+        let sampler = sampler.0;
+        let params = texel.as_ptr();
+
+        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
+        // no memory is retained
+        unsafe { gl::SamplerParameterfv(sampler, gl::TEXTURE_BORDER_COLOR, params) }
+    }
+
+    /// # Error mapped set border colour
+    /// see [sampler_border_colour_f32]
+    pub fn sampler_border_colour_f32_checked(
+        sampler: Sampler,
+        texel: [f32; 4],
+    ) -> Result<(), SamplerError> {
+        sampler_border_colour_f32(sampler, texel);
+        sampler_error(sampler)
+    }
+
+    /// # Set a signed integer texel that should be used for border texels
+    /// see [sampler_border_colour_f32]
+    pub fn sampler_border_colour_i32(sampler: Sampler, texel: [i32; 4]) {
+        let sampler = sampler.0;
+        let params = texel.as_ptr();
+
+        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
+        // no memory is retained
+        unsafe { gl::SamplerParameterIiv(sampler, gl::TEXTURE_BORDER_COLOR, params) }
+    }
+
+    /// # Error mapped set signed integer border colour
+    /// see [sampler_border_colour_i32]
+    pub fn sampler_border_colour_i32_checked(
+        sampler: Sampler,
+        texel: [i32; 4],
+    ) -> Result<(), SamplerError> {
+        sampler_border_colour_i32(sampler, texel);
+        sampler_error(sampler)
+    }
+
+    /// # Set an unsigned integer texel that should be used for border texels
+    /// see [sampler_border_colour_f32]
+    pub fn sampler_border_colour_u32(sampler: Sampler, texel: [u32; 4]) {
+        let sampler = sampler.0;
+        let params = texel.as_ptr();
+
+        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
+        // no memory is retained
+        unsafe { gl::SamplerParameterIuiv(sampler, gl::TEXTURE_BORDER_COLOR, params) }
+    }
+
+    /// # Error mapped set unsigned integer border colour
+    /// see [sampler_border_colour_u32]
+    pub fn sampler_border_colour_u32_checked(
+        sampler: Sampler,
+        texel: [u32; 4],
+    ) -> Result<(), SamplerError> {
+        sampler_border_colour_u32(sampler, texel);
+        sampler_error(sampler)
+    }
+
+    /// # Set the comparison operator for depth textures
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `func` - comparison operator
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_compare_func(Sampler(42), CompareFunc::LessOrEqual);
+    /// ```
+    ///
+    /// # Description
+    /// Specifies the comparison operator used when [sampler_compare_mode] is set to
+    /// [TextureCompareMode::Ref]. The initial value is [CompareFunc::LessOrEqual].
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_compare_func]
+    ///
+    /// # See Also
+    /// * [sampler_parameter]
+    /// * [texture_target_compare_func]
+    pub fn sampler_compare_func(sampler: Sampler, func: CompareFunc) {
+        let param = GLenum::from(func) as i32;
+        sampler_param_i32(sampler, gl::TEXTURE_COMPARE_FUNC, param)
+    }
+
+    /// # Error mapped set compare function
+    /// see [sampler_compare_func]
+    pub fn sampler_compare_func_checked(
+        sampler: Sampler,
+        func: CompareFunc,
+    ) -> Result<(), SamplerError> {
+        sampler_compare_func(sampler, func);
+        sampler_error(sampler)
+    }
+
+    /// # Set the texture comparison mode
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `mode` - comparison mode
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_compare_mode(Sampler(42), TextureCompareMode::Ref);
+    /// ```
+    ///
+    /// # Description
+    /// Specifies the comparison mode used when sampling depth textures through the sampler. The
+    /// initial value is [TextureCompareMode::None].
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_compare_mode]
+    ///
+    /// # See Also
+    /// * [sampler_parameter]
+    /// * [texture_target_compare_mode]
+    pub fn sampler_compare_mode(sampler: Sampler, mode: TextureCompareMode) {
+        let param = GLenum::from(mode) as i32;
+        sampler_param_i32(sampler, gl::TEXTURE_COMPARE_MODE, param)
+    }
+
+    /// # Error mapped set compare mode
+    /// see [sampler_compare_mode]
+    pub fn sampler_compare_mode_checked(
+        sampler: Sampler,
+        mode: TextureCompareMode,
+    ) -> Result<(), SamplerError> {
+        sampler_compare_mode(sampler, mode);
+        sampler_error(sampler)
+    }
+
+    /// # Set the fixed bias for the level-of-detail
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `bias` - floating point lod bias
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_lod_bias(Sampler(42), -0.5);
+    /// ```
+    ///
+    /// # Description
+    /// `bias` specifies a fixed bias value that is to be added to the level-of-detail parameter
+    /// before texture sampling. The initial value is 0.0.
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_lod_bias]
+    ///
+    /// # See Also
+    /// * [sampler_parameter]
+    /// * [texture_target_lod_bias]
+    pub fn sampler_lod_bias(sampler: Sampler, bias: f32) {
+        sampler_param_f32(sampler, gl::TEXTURE_LOD_BIAS, bias)
+    }
+
+    /// # Error mapped set level of detail bias
+    /// see [sampler_lod_bias]
+    pub fn sampler_lod_bias_checked(sampler: Sampler, bias: f32) -> Result<(), SamplerError> {
+        sampler_lod_bias(sampler, bias);
+        sampler_error(sampler)
+    }
+
+    /// # Set the maximum level-of-detail
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `lod` - maximum level-of-detail
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_max_lod(Sampler(42), 4.0);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the maximum level-of-detail parameter. This floating-point value limits the selection
+    /// of the lowest resolution mipmap (highest mipmap level). The initial value is 1000.
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_max_lod]
+    ///
+    /// # See Also
+    /// * [sampler_min_lod]
+    /// * [sampler_parameter]
+    pub fn sampler_max_lod(sampler: Sampler, lod: f32) {
+        sampler_param_f32(sampler, gl::TEXTURE_MAX_LOD, lod)
+    }
+
+    /// # Error mapped set maximum level of detail
+    /// see [sampler_max_lod]
+    pub fn sampler_max_lod_checked(sampler: Sampler, lod: f32) -> Result<(), SamplerError> {
+        sampler_max_lod(sampler, lod);
+        sampler_error(sampler)
+    }
+
+    /// # Set the minimum level-of-detail
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `lod` - minimum level-of-detail
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_min_lod(Sampler(42), 1.0);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the minimum level-of-detail parameter. This floating-point value limits the selection
+    /// of highest resolution mipmap (lowest mipmap level). The initial value is -1000.
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_min_lod]
+    ///
+    /// # See Also
+    /// * [sampler_max_lod]
+    /// * [sampler_parameter]
+    pub fn sampler_min_lod(sampler: Sampler, lod: f32) {
+        sampler_param_f32(sampler, gl::TEXTURE_MIN_LOD, lod)
+    }
+
+    /// # Error mapped set minimum level of detail
+    /// see [sampler_min_lod]
+    pub fn sampler_min_lod_checked(sampler: Sampler, lod: f32) -> Result<(), SamplerError> {
+        sampler_min_lod(sampler, lod);
+        sampler_error(sampler)
+    }
+
+    /// # Set the minifying function
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `filter` - Specifies the minifying function
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_min_filter(Sampler(42), TextureMinFilter::LinearMipmapLinear);
+    /// ```
+    ///
+    /// # Description
+    /// The texture minifying function is used whenever the level-of-detail function used when
+    /// sampling determines that the texture should be minified. See [texture_target_min_filter]
+    /// for the details of each [TextureMinFilter]. The initial value is
+    /// [TextureMinFilter::NearestMipmapLinear].
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_min_filter]
+    ///
+    /// # See Also
+    /// * [sampler_parameter]
+    /// * [texture_target_min_filter]
+    pub fn sampler_min_filter(sampler: Sampler, filter: TextureMinFilter) {
+        let param = GLenum::from(filter) as i32;
+        sampler_param_i32(sampler, gl::TEXTURE_MIN_FILTER, param)
+    }
+
+    /// # Error mapped set minifying filter
+    /// see [sampler_min_filter]
+    pub fn sampler_min_filter_checked(
+        sampler: Sampler,
+        filter: TextureMinFilter,
+    ) -> Result<(), SamplerError> {
+        sampler_min_filter(sampler, filter);
+        sampler_error(sampler)
+    }
+
+    /// # Set the magnification function
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `filter` - Specifies the magnification function
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_mag_filter(Sampler(42), TextureMagFilter::Nearest);
+    /// ```
+    ///
+    /// # Description
+    /// The texture magnification function is used whenever the level-of-detail function used when
+    /// sampling determines that the texture should be magnified. The initial value is
+    /// [TextureMagFilter::Linear].
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_mag_filter]
+    ///
+    /// # See Also
+    /// * [sampler_parameter]
+    /// * [texture_target_mag_filter]
+    pub fn sampler_mag_filter(sampler: Sampler, filter: TextureMagFilter) {
+        let param = GLenum::from(filter) as i32;
+        sampler_param_i32(sampler, gl::TEXTURE_MAG_FILTER, param)
+    }
+
+    /// # Error mapped set magnification filter
+    /// see [sampler_mag_filter]
+    pub fn sampler_mag_filter_checked(
+        sampler: Sampler,
+        filter: TextureMagFilter,
+    ) -> Result<(), SamplerError> {
+        sampler_mag_filter(sampler, filter);
+        sampler_error(sampler)
+    }
+
+    /// # Set the wrap value of the `s` coordinate
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `mode` - Specifies the texture wrap mode
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_wrap_s(Sampler(42), TextureWrapMode::ClampToEdge);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the wrap parameter for texture coordinate `s`. Initially set to
+    /// [TextureWrapMode::Repeat].
+    ///
+    /// # Compatability
+    /// * 4.4 - [TextureWrapMode::MirrorClampToEdge]
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_wrap_s]
+    ///
+    /// # See Also
+    /// * [TextureWrapMode]
+    /// * [sampler_parameter]
+    pub fn sampler_wrap_s(sampler: Sampler, mode: TextureWrapMode) {
+        let param = GLenum::from(mode) as i32;
+        sampler_param_i32(sampler, gl::TEXTURE_WRAP_S, param)
+    }
+
+    /// # Error mapped set wrap `s`
+    /// see [sampler_wrap_s]
+    pub fn sampler_wrap_s_checked(
+        sampler: Sampler,
+        mode: TextureWrapMode,
+    ) -> Result<(), SamplerError> {
+        sampler_wrap_s(sampler, mode);
+        sampler_error(sampler)
+    }
+
+    /// # Set the wrap value of the `t` coordinate
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `mode` - Specifies the texture wrap mode
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_wrap_t(Sampler(42), TextureWrapMode::ClampToEdge);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the wrap parameter for texture coordinate `t`. Initially set to
+    /// [TextureWrapMode::Repeat].
+    ///
+    /// # Compatability
+    /// * 4.4 - [TextureWrapMode::MirrorClampToEdge]
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_wrap_t]
+    ///
+    /// # See Also
+    /// * [TextureWrapMode]
+    /// * [sampler_parameter]
+    pub fn sampler_wrap_t(sampler: Sampler, mode: TextureWrapMode) {
+        let param = GLenum::from(mode) as i32;
+        sampler_param_i32(sampler, gl::TEXTURE_WRAP_T, param)
+    }
+
+    /// # Error mapped set wrap `t`
+    /// see [sampler_wrap_t]
+    pub fn sampler_wrap_t_checked(
+        sampler: Sampler,
+        mode: TextureWrapMode,
+    ) -> Result<(), SamplerError> {
+        sampler_wrap_t(sampler, mode);
+        sampler_error(sampler)
+    }
+
+    /// # Set the wrap value of the `r` coordinate
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `sampler` - Specifies the sampler object whose parameter to modify
+    /// * `mode` - Specifies the texture wrap mode
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// sampler_wrap_r(Sampler(42), TextureWrapMode::ClampToEdge);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the wrap parameter for texture coordinate `r`. Initially set to
+    /// [TextureWrapMode::Repeat].
+    ///
+    /// # Compatability
+    /// * 4.4 - [TextureWrapMode::MirrorClampToEdge]
+    ///
+    /// # Associated Gets
+    /// * [get_sampler_wrap_r]
+    ///
+    /// # See Also
+    /// * [TextureWrapMode]
+    /// * [sampler_parameter]
+    pub fn sampler_wrap_r(sampler: Sampler, mode: TextureWrapMode) {
+        let param = GLenum::from(mode) as i32;
+        sampler_param_i32(sampler, gl::TEXTURE_WRAP_R, param)
+    }
+
+    /// # Error mapped set wrap `r`
+    /// see [sampler_wrap_r]
+    pub fn sampler_wrap_r_checked(
+        sampler: Sampler,
+        mode: TextureWrapMode,
+    ) -> Result<(), SamplerError> {
+        sampler_wrap_r(sampler, mode);
+        sampler_error(sampler)
+    }
+}
+pub use sampler_parameter::*;
+
+/// # Return sampler parameter values
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetSamplerParameter.xhtml>
+///
+/// # Description
+/// Each getter returns the value of a single parameter of `sampler`, as set by the matching
+/// [sampler_parameter] function. Enum valued parameters are returned as the same enums accepted by
+/// the setters, and an unknown value is returned as `None`.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `sampler` is not the name of a sampler object previously
+/// returned from a call to [gen_samplers] or [create_samplers].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_sampler_parameter] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [gen_samplers]
+/// * [sampler_parameter]
+pub mod get_sampler_parameter {
+    use super::sampler_error;
+    use crate::prelude::*;
+    use gl::types::*;
+
+    fn get_sampler_parameter_i32(sampler: Sampler, pname: GLenum) -> i32 {
+        let sampler = sampler.0;
+        let mut param = 0;
+
+        // SAFE: synchronous write of a single integer, no memory retained
+        unsafe { gl::GetSamplerParameteriv(sampler, pname, &mut param) };
+        param
+    }
+
+    fn get_sampler_parameter_f32(sampler: Sampler, pname: GLenum) -> f32 {
+        let sampler = sampler.0;
+        let mut param = 0.0;
+
+        // SAFE: synchronous write of a single float, no memory retained
+        unsafe { gl::GetSamplerParameterfv(sampler, pname, &mut param) };
+        param
+    }
+
+    /// Decode an enum valued parameter into one of the parameter enums shared with [tex_parameter]
+    fn get_sampler_parameter_enum<T: TryFrom<GLenum>>(
+        sampler: Sampler,
+        pname: GLenum,
+    ) -> Option<T> {
+        let val = get_sampler_parameter_i32(sampler, pname);
+        T::try_from(val as GLenum).ok()
+    }
+
+    /// # Return the border colour of a sampler
+    /// see [sampler_border_colour_f32]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_sampler_border_colour_f32(Sampler(42)), [0.0; 4]);
+    /// ```
+    pub fn get_sampler_border_colour_f32(sampler: Sampler) -> [f32; 4] {
+        let sampler = sampler.0;
+        let mut params = [0.0; 4];
+
+        // SAFE: TEXTURE_BORDER_COLOR writes an array size of 4, no memory retained
+        unsafe {
+            gl::GetSamplerParameterfv(sampler, gl::TEXTURE_BORDER_COLOR, params.as_mut_ptr())
+        };
+        params
+    }
+
+    /// # Error mapped get border colour
+    /// see [get_sampler_border_colour_f32]
+    pub fn get_sampler_border_colour_f32_checked(
+        sampler: Sampler,
+    ) -> Result<[f32; 4], SamplerError> {
+        let val = get_sampler_border_colour_f32(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+
+    /// # Return the comparison operator of a sampler
+    /// see [sampler_compare_func]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_sampler_compare_func(Sampler(42)), Some(CompareFunc::LessOrEqual));
+    /// ```
+    pub fn get_sampler_compare_func(sampler: Sampler) -> Option<CompareFunc> {
+        get_sampler_parameter_enum(sampler, gl::TEXTURE_COMPARE_FUNC)
+    }
+
+    /// # Error mapped get compare function
+    /// see [get_sampler_compare_func]
+    pub fn get_sampler_compare_func_checked(
+        sampler: Sampler,
+    ) -> Result<Option<CompareFunc>, SamplerError> {
+        let val = get_sampler_compare_func(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+
+    /// # Return the comparison mode of a sampler
+    /// see [sampler_compare_mode]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_sampler_compare_mode(Sampler(42)), Some(TextureCompareMode::None));
+    /// ```
+    pub fn get_sampler_compare_mode(sampler: Sampler) -> Option<TextureCompareMode> {
+        get_sampler_parameter_enum(sampler, gl::TEXTURE_COMPARE_MODE)
+    }
+
+    /// # Error mapped get compare mode
+    /// see [get_sampler_compare_mode]
+    pub fn get_sampler_compare_mode_checked(
+        sampler: Sampler,
+    ) -> Result<Option<TextureCompareMode>, SamplerError> {
+        let val = get_sampler_compare_mode(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+
+    /// # Return the level-of-detail bias of a sampler
+    /// see [sampler_lod_bias]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_sampler_lod_bias(Sampler(42)), 0.0);
+    /// ```
+    pub fn get_sampler_lod_bias(sampler: Sampler) -> f32 {
+        get_sampler_parameter_f32(sampler, gl::TEXTURE_LOD_BIAS)
+    }
+
+    /// # Error mapped get level of detail bias
+    /// see [get_sampler_lod_bias]
+    pub fn get_sampler_lod_bias_checked(sampler: Sampler) -> Result<f32, SamplerError> {
+        let val = get_sampler_lod_bias(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+
+    /// # Return the maximum level-of-detail of a sampler
+    /// see [sampler_max_lod]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_sampler_max_lod(Sampler(42)), 1000.0);
+    /// ```
+    pub fn get_sampler_max_lod(sampler: Sampler) -> f32 {
+        get_sampler_parameter_f32(sampler, gl::TEXTURE_MAX_LOD)
+    }
+
+    /// # Error mapped get maximum level of detail
+    /// see [get_sampler_max_lod]
+    pub fn get_sampler_max_lod_checked(sampler: Sampler) -> Result<f32, SamplerError> {
+        let val = get_sampler_max_lod(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+
+    /// # Return the minimum level-of-detail of a sampler
+    /// see [sampler_min_lod]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_sampler_min_lod(Sampler(42)), -1000.0);
+    /// ```
+    pub fn get_sampler_min_lod(sampler: Sampler) -> f32 {
+        get_sampler_parameter_f32(sampler, gl::TEXTURE_MIN_LOD)
+    }
+
+    /// # Error mapped get minimum level of detail
+    /// see [get_sampler_min_lod]
+    pub fn get_sampler_min_lod_checked(sampler: Sampler) -> Result<f32, SamplerError> {
+        let val = get_sampler_min_lod(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+
+    /// # Return the minifying function of a sampler
+    /// see [sampler_min_filter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_sampler_min_filter(Sampler(42)),
+    ///     Some(TextureMinFilter::NearestMipmapLinear)
+    /// );
+    /// ```
+    pub fn get_sampler_min_filter(sampler: Sampler) -> Option<TextureMinFilter> {
+        get_sampler_parameter_enum(sampler, gl::TEXTURE_MIN_FILTER)
+    }
+
+    /// # Error mapped get minifying filter
+    /// see [get_sampler_min_filter]
+    pub fn get_sampler_min_filter_checked(
+        sampler: Sampler,
+    ) -> Result<Option<TextureMinFilter>, SamplerError> {
+        let val = get_sampler_min_filter(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+
+    /// # Return the magnification function of a sampler
+    /// see [sampler_mag_filter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_sampler_mag_filter(Sampler(42)), Some(TextureMagFilter::Linear));
+    /// ```
+    pub fn get_sampler_mag_filter(sampler: Sampler) -> Option<TextureMagFilter> {
+        get_sampler_parameter_enum(sampler, gl::TEXTURE_MAG_FILTER)
+    }
+
+    /// # Error mapped get magnification filter
+    /// see [get_sampler_mag_filter]
+    pub fn get_sampler_mag_filter_checked(
+        sampler: Sampler,
+    ) -> Result<Option<TextureMagFilter>, SamplerError> {
+        let val = get_sampler_mag_filter(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+
+    /// # Return the wrap value of the `s` coordinate of a sampler
+    /// see [sampler_wrap_s]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_sampler_wrap_s(Sampler(42)), Some(TextureWrapMode::Repeat));
+    /// ```
+    pub fn get_sampler_wrap_s(sampler: Sampler) -> Option<TextureWrapMode> {
+        get_sampler_parameter_enum(sampler, gl::TEXTURE_WRAP_S)
+    }
+
+    /// # Error mapped get wrap `s`
+    /// see [get_sampler_wrap_s]
+    pub fn get_sampler_wrap_s_checked(
+        sampler: Sampler,
+    ) -> Result<Option<TextureWrapMode>, SamplerError> {
+        let val = get_sampler_wrap_s(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+
+    /// # Return the wrap value of the `t` coordinate of a sampler
+    /// see [sampler_wrap_t]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_sampler_wrap_t(Sampler(42)), Some(TextureWrapMode::Repeat));
+    /// ```
+    pub fn get_sampler_wrap_t(sampler: Sampler) -> Option<TextureWrapMode> {
+        get_sampler_parameter_enum(sampler, gl::TEXTURE_WRAP_T)
+    }
+
+    /// # Error mapped get wrap `t`
+    /// see [get_sampler_wrap_t]
+    pub fn get_sampler_wrap_t_checked(
+        sampler: Sampler,
+    ) -> Result<Option<TextureWrapMode>, SamplerError> {
+        let val = get_sampler_wrap_t(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+
+    /// # Return the wrap value of the `r` coordinate of a sampler
+    /// see [sampler_wrap_r]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_sampler_wrap_r(Sampler(42)), Some(TextureWrapMode::Repeat));
+    /// ```
+    pub fn get_sampler_wrap_r(sampler: Sampler) -> Option<TextureWrapMode> {
+        get_sampler_parameter_enum(sampler, gl::TEXTURE_WRAP_R)
+    }
+
+    /// # Error mapped get wrap `r`
+    /// see [get_sampler_wrap_r]
+    pub fn get_sampler_wrap_r_checked(
+        sampler: Sampler,
+    ) -> Result<Option<TextureWrapMode>, SamplerError> {
+        let val = get_sampler_wrap_r(sampler);
+        sampler_error(sampler)?;
+        Ok(val)
+    }
+}
+pub use get_sampler_parameter::*;
@@ -55,6 +55,151 @@ impl From<ShaderType> for u32 {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ShaderError {
+    Unexpected(Error),
+
+    /// Not the name of a shader or program object
+    InvalidProgram(Program),
+
+    /// The name of a shader object, where a program object was expected
+    NotAProgram(Program),
+
+    /// Not the name of a shader or program object
+    InvalidShader(Shader),
+
+    /// The name of a program object, where a shader object was expected
+    NotAShader(Shader),
+
+    /// The shader is already attached to the program
+    ShaderAlreadyAttached {
+        program: Program,
+        shader: Shader,
+    },
+
+    /// The shader is not attached to the program
+    ShaderNotAttached {
+        program: Program,
+        shader: Shader,
+    },
+
+    /// The program is in use while transform feedback mode is active
+    TransformFeedbackActive(Program),
+
+    /// The shader type is not supported by the context
+    UnsupportedShaderType(ShaderType),
+
+    /// The GL returned a shader type unknown to rgl
+    UnknownShaderType(i32),
+
+    /// The program has not been successfully linked
+    ProgramNotLinked(Program),
+
+    /// The index is out of range for the queried program interface or attribute
+    InvalidIndex(u32),
+
+    /// The program interface does not support the requested query
+    UnsupportedInterfaceQuery(ProgramInterface),
+
+    /// The attribute name starts with the reserved prefix "gl_"
+    ReservedName,
+
+    /// The binding point is not less than the maximum number of buffer bindings
+    InvalidBinding(u32),
+}
+
+impl std::fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShaderError::Unexpected(error) => write!(f, "unexpected shader error: {error}"),
+            ShaderError::InvalidProgram(program) => {
+                write!(f, "{} is not the name of a program object", program.0)
+            }
+            ShaderError::NotAProgram(program) => {
+                write!(f, "{} is a shader object, not a program object", program.0)
+            }
+            ShaderError::InvalidShader(shader) => {
+                write!(f, "{} is not the name of a shader object", shader.0)
+            }
+            ShaderError::NotAShader(shader) => {
+                write!(f, "{} is a program object, not a shader object", shader.0)
+            }
+            ShaderError::ShaderAlreadyAttached { program, shader } => write!(
+                f,
+                "shader {} is already attached to program {}",
+                shader.0, program.0
+            ),
+            ShaderError::ShaderNotAttached { program, shader } => write!(
+                f,
+                "shader {} is not attached to program {}",
+                shader.0, program.0
+            ),
+            ShaderError::TransformFeedbackActive(program) => write!(
+                f,
+                "program {} is in use with transform feedback active",
+                program.0
+            ),
+            ShaderError::UnsupportedShaderType(shader_type) => {
+                write!(f, "{shader_type:?} shaders are not supported")
+            }
+            ShaderError::UnknownShaderType(value) => write!(f, "unknown shader type {value:#06X}"),
+            ShaderError::ProgramNotLinked(program) => {
+                write!(f, "program {} has not been successfully linked", program.0)
+            }
+            ShaderError::InvalidIndex(index) => write!(f, "index {index} is out of range"),
+            ShaderError::UnsupportedInterfaceQuery(interface) => {
+                write!(
+                    f,
+                    "the query is not supported by the {interface:?} interface"
+                )
+            }
+            ShaderError::ReservedName => write!(f, "names starting with \"gl_\" are reserved"),
+            ShaderError::InvalidBinding(binding) => {
+                write!(f, "{binding} exceeds the maximum number of buffer bindings")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShaderError {}
+
+/// Decode the error of a command taking a single program object
+pub(crate) fn program_error(program: Program, error: Error) -> ShaderError {
+    match error {
+        Error::InvalidValue => ShaderError::InvalidProgram(program),
+        Error::InvalidOperation => ShaderError::NotAProgram(program),
+        other => ShaderError::Unexpected(other),
+    }
+}
+
+/// Decode the error of a command taking a single shader object
+fn shader_error(shader: Shader, error: Error) -> ShaderError {
+    match error {
+        Error::InvalidValue => ShaderError::InvalidShader(shader),
+        Error::InvalidOperation => ShaderError::NotAShader(shader),
+        other => ShaderError::Unexpected(other),
+    }
+}
+
+/// Decode the error of a command taking a program and a shader object, where
+/// [Error::InvalidOperation] is also generated by the attachment state given in `attachment`
+fn attachment_error(
+    program: Program,
+    shader: Shader,
+    error: Error,
+    attachment: ShaderError,
+) -> ShaderError {
+    let is_name = |name| is_program(Program(name)) || is_shader(Shader(name));
+    match error {
+        Error::InvalidValue if !is_name(program.0) => ShaderError::InvalidProgram(program),
+        Error::InvalidValue => ShaderError::InvalidShader(shader),
+        Error::InvalidOperation if !is_program(program) => ShaderError::NotAProgram(program),
+        Error::InvalidOperation if !is_shader(shader) => ShaderError::NotAShader(shader),
+        Error::InvalidOperation => attachment,
+        other => ShaderError::Unexpected(other),
+    }
+}
+
 /// # Attaches a shader object to a program object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glAttachShader.xhtml>
 ///
@@ -119,6 +264,21 @@ pub fn attach_shader(program: Program, shader: Shader) {
     unsafe { gl::AttachShader(program, shader) }
 }
 
+/// # Error mapped attach shader
+/// see [attach_shader]
+pub fn attach_shader_checked(program: Program, shader: Shader) -> Result<(), ShaderError> {
+    attach_shader(program, shader);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(attachment_error(
+            program,
+            shader,
+            error,
+            ShaderError::ShaderAlreadyAttached { program, shader },
+        )),
+    }
+}
+
 /// # Compiles a shader object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCompileShader.xhtml>
 ///
@@ -170,6 +330,16 @@ pub fn compile_shader(shader: Shader) {
     unsafe { gl::CompileShader(shader) }
 }
 
+/// # Error mapped compile shader
+/// see [compile_shader]
+pub fn compile_shader_checked(shader: Shader) -> Result<(), ShaderError> {
+    compile_shader(shader);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(shader_error(shader, error)),
+    }
+}
+
 /// # Creates a program object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCreateProgram.xhtml>
 ///
@@ -240,6 +410,16 @@ pub fn create_program() -> Program {
     Program(val)
 }
 
+/// # Error mapped create program
+/// see [create_program]
+pub fn create_program_checked() -> Result<Program, ShaderError> {
+    let val = create_program();
+    match get_error() {
+        Error::NoError => Ok(val),
+        other => Err(ShaderError::Unexpected(other)),
+    }
+}
+
 /// # Creates a shader object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCreateShader.xhtml>
 ///
@@ -297,6 +477,17 @@ pub fn create_shader(shader_type: ShaderType) -> Shader {
     Shader(val)
 }
 
+/// # Error mapped create shader
+/// see [create_shader]
+pub fn create_shader_checked(shader_type: ShaderType) -> Result<Shader, ShaderError> {
+    let val = create_shader(shader_type);
+    match get_error() {
+        Error::NoError => Ok(val),
+        Error::InvalidEnum => Err(ShaderError::UnsupportedShaderType(shader_type)),
+        other => Err(ShaderError::Unexpected(other)),
+    }
+}
+
 /// # Deletes a program object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDeleteProgram.xhtml>
 ///
@@ -349,6 +540,16 @@ pub fn delete_program(program: Program) {
     unsafe { gl::DeleteProgram(program) }
 }
 
+/// # Error mapped delete program
+/// see [delete_program]
+pub fn delete_program_checked(program: Program) -> Result<(), ShaderError> {
+    delete_program(program);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(program_error(program, error)),
+    }
+}
+
 /// # Deletes a shader object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDeleteShader.xhtml>
 ///
@@ -398,6 +599,16 @@ pub fn delete_shader(shader: Shader) {
     unsafe { gl::DeleteShader(shader) }
 }
 
+/// # Error mapped delete shader
+/// see [delete_shader]
+pub fn delete_shader_checked(shader: Shader) -> Result<(), ShaderError> {
+    delete_shader(shader);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(shader_error(shader, error)),
+    }
+}
+
 /// # Detaches a shader object from a program object to which it is attached
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDetachShader.xhtml>
 ///
@@ -423,7 +634,7 @@ pub fn delete_shader(shader: Shader) {
 /// * [Error::InvalidValue] - if either `program` or `shader` is not a value generated by OpenGL.
 /// * [Error::InvalidOperation] - if `program` is not a program object
 /// * [Error::InvalidOperation] - if `shader` is not a shader object
-/// * [Error::InvalidOperation] - if `shader` is already attached to `program`
+/// * [Error::InvalidOperation] - if `shader` is not attached to `program`
 ///
 /// # Associated Gets
 /// * [get_attached_shaders] with the handle of a valid program object
@@ -447,6 +658,21 @@ pub fn detach_shader(program: Program, shader: Shader) {
     unsafe { gl::DetachShader(program, shader) }
 }
 
+/// # Error mapped detach shader
+/// see [detach_shader]
+pub fn detach_shader_checked(program: Program, shader: Shader) -> Result<(), ShaderError> {
+    detach_shader(program, shader);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(attachment_error(
+            program,
+            shader,
+            error,
+            ShaderError::ShaderNotAttached { program, shader },
+        )),
+    }
+}
+
 /// # Returns the handles of the shader objects attached to a program object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetAttachedShaders.xhtml>
 ///
@@ -509,6 +735,19 @@ pub fn get_attached_shaders(program: Program, buffer: &mut [Shader]) -> &[Shader
     &buffer[..count]
 }
 
+/// # Error mapped get attached shaders
+/// see [get_attached_shaders]
+pub fn get_attached_shaders_checked(
+    program: Program,
+    buffer: &mut [Shader],
+) -> Result<&[Shader], ShaderError> {
+    let shaders = get_attached_shaders(program, buffer);
+    match get_error() {
+        Error::NoError => Ok(shaders),
+        error => Err(program_error(program, error)),
+    }
+}
+
 fn get_program_iv(program: Program, pname: GLenum) -> i32 {
     let program = program.0;
     let mut params = Default::default();
@@ -563,6 +802,16 @@ pub fn get_program_delete_status(program: Program) -> bool {
     val == gl::TRUE as i32
 }
 
+/// # Error mapped get program delete status
+/// see [get_program_delete_status]
+pub fn get_program_delete_status_checked(program: Program) -> Result<bool, ShaderError> {
+    let val = get_program_delete_status(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(program_error(program, error)),
+    }
+}
+
 /// # Returns program link status
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgram.xhtml>
 ///
@@ -607,6 +856,16 @@ pub fn get_program_link_status(program: Program) -> bool {
     val == gl::TRUE as i32
 }
 
+/// # Error mapped get program link status
+/// see [get_program_link_status]
+pub fn get_program_link_status_checked(program: Program) -> Result<bool, ShaderError> {
+    let val = get_program_link_status(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(program_error(program, error)),
+    }
+}
+
 /// # Returns program validation status
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgram.xhtml>
 ///
@@ -652,6 +911,16 @@ pub fn get_program_validate_status(program: Program) -> bool {
     val == gl::TRUE as i32
 }
 
+/// # Error mapped get program validate status
+/// see [get_program_validate_status]
+pub fn get_program_validate_status_checked(program: Program) -> Result<bool, ShaderError> {
+    let val = get_program_validate_status(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(program_error(program, error)),
+    }
+}
+
 /// # Returns program info log length
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgram.xhtml>
 ///
@@ -698,6 +967,16 @@ pub fn get_program_info_log_length(program: Program) -> u32 {
     val as u32
 }
 
+/// # Error mapped get program info log length
+/// see [get_program_info_log_length]
+pub fn get_program_info_log_length_checked(program: Program) -> Result<u32, ShaderError> {
+    let val = get_program_info_log_length(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(program_error(program, error)),
+    }
+}
+
 /// # Returns program number of attached shaders
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgram.xhtml>
 ///
@@ -742,6 +1021,16 @@ pub fn get_program_attached_shaders(program: Program) -> u32 {
     val as u32
 }
 
+/// # Error mapped get program attached shaders
+/// see [get_program_attached_shaders]
+pub fn get_program_attached_shaders_checked(program: Program) -> Result<u32, ShaderError> {
+    let val = get_program_attached_shaders(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(program_error(program, error)),
+    }
+}
+
 /// # Returns program number of active attributes
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgram.xhtml>
 ///
@@ -786,6 +1075,16 @@ pub fn get_program_active_attributes(program: Program) -> u32 {
     val as u32
 }
 
+/// # Error mapped get program active attributes
+/// see [get_program_active_attributes]
+pub fn get_program_active_attributes_checked(program: Program) -> Result<u32, ShaderError> {
+    let val = get_program_active_attributes(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(program_error(program, error)),
+    }
+}
+
 /// # Returns program maximum numbfer of attributes
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgram.xhtml>
 ///
@@ -832,6 +1131,16 @@ pub fn get_program_attribute_max_length(program: Program) -> u32 {
     val as u32
 }
 
+/// # Error mapped get program attribute max length
+/// see [get_program_attribute_max_length]
+pub fn get_program_attribute_max_length_checked(program: Program) -> Result<u32, ShaderError> {
+    let val = get_program_attribute_max_length(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(program_error(program, error)),
+    }
+}
+
 /// # Returns program number of active uniforms
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgram.xhtml>
 ///
@@ -876,6 +1185,119 @@ pub fn get_program_active_uniforms(program: Program) -> u32 {
     val as u32
 }
 
+/// # Error mapped get program active uniforms
+/// see [get_program_active_uniforms]
+pub fn get_program_active_uniforms_checked(program: Program) -> Result<u32, ShaderError> {
+    let val = get_program_active_uniforms(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(program_error(program, error)),
+    }
+}
+
+/// # Returns program maximum length of active uniform names
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgram.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the program object to be queried.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_program_active_uniform_max_length(Program(42)), 11);
+/// ```
+///
+/// # Description
+/// Returns the length of the longest active uniform variable name for `program`, including the
+/// null termination character (i.e., the size of the character buffer required to store the
+/// longest uniform variable name). If no active uniform variables exist, 0 is returned.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` does not refer to a program object.
+///
+/// # Associated Gets
+/// * [get_active_uniform]
+/// * [get_program_active_uniforms]
+/// * [is_program]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_program_active_uniform_max_length] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [attach_shader]
+/// * [create_program]
+/// * [delete_program]
+/// * [get_shader]
+/// * [link_program]
+/// * [validate_program]
+pub fn get_program_active_uniform_max_length(program: Program) -> u32 {
+    let val = get_program_iv(program, gl::ACTIVE_UNIFORM_MAX_LENGTH);
+    val as u32
+}
+
+/// # Error mapped get program active uniform max length
+/// see [get_program_active_uniform_max_length]
+pub fn get_program_active_uniform_max_length_checked(program: Program) -> Result<u32, ShaderError> {
+    let val = get_program_active_uniform_max_length(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(program_error(program, error)),
+    }
+}
+
+/// # Returns program number of active uniform blocks
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgram.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the program object to be queried.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_program_active_uniform_blocks(Program(42)), 2);
+/// ```
+///
+/// # Description
+/// Returns the number of uniform blocks for `program` containing active uniforms.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` does not refer to a program object.
+///
+/// # Associated Gets
+/// * [get_active_uniform_block_name]
+/// * [get_uniform_block_index]
+/// * [is_program]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_program_active_uniform_blocks] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [create_program]
+/// * [link_program]
+/// * [uniform_block_binding]
+pub fn get_program_active_uniform_blocks(program: Program) -> u32 {
+    let val = get_program_iv(program, gl::ACTIVE_UNIFORM_BLOCKS);
+    val as u32
+}
+
+/// # Error mapped get program active uniform blocks
+/// see [get_program_active_uniform_blocks]
+pub fn get_program_active_uniform_blocks_checked(program: Program) -> Result<u32, ShaderError> {
+    let val = get_program_active_uniform_blocks(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(program_error(program, error)),
+    }
+}
+
 /// # Returns the information log for a program object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgramInfoLog.xhtml>
 ///
@@ -948,6 +1370,19 @@ pub fn get_program_info_log(program: Program, buffer: &mut [u8]) -> &[u8] {
     &buffer[..length]
 }
 
+/// # Error mapped get program info log
+/// see [get_program_info_log]
+pub fn get_program_info_log_checked(
+    program: Program,
+    buffer: &mut [u8],
+) -> Result<&[u8], ShaderError> {
+    let info_log = get_program_info_log(program, buffer);
+    match get_error() {
+        Error::NoError => Ok(info_log),
+        error => Err(program_error(program, error)),
+    }
+}
+
 fn get_shader_iv(shader: Shader, pname: GLenum) -> i32 {
     let shader = shader.0;
     let mut params = GLint::default();
@@ -1003,6 +1438,16 @@ pub fn get_shader_type(shader: Shader) -> Result<ShaderType, i32> {
     }
 }
 
+/// # Error mapped get shader type
+/// see [get_shader_type]
+pub fn get_shader_type_checked(shader: Shader) -> Result<ShaderType, ShaderError> {
+    let val = get_shader_type(shader);
+    match get_error() {
+        Error::NoError => val.map_err(ShaderError::UnknownShaderType),
+        error => Err(shader_error(shader, error)),
+    }
+}
+
 /// # Return the shader delete status
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetShader.xhtml>
 ///
@@ -1039,6 +1484,16 @@ pub fn get_shader_delete_status(shader: Shader) -> bool {
     val == gl::TRUE as i32
 }
 
+/// # Error mapped get shader delete status
+/// see [get_shader_delete_status]
+pub fn get_shader_delete_status_checked(shader: Shader) -> Result<bool, ShaderError> {
+    let val = get_shader_delete_status(shader);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(shader_error(shader, error)),
+    }
+}
+
 /// # Return the shader compile status
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetShader.xhtml>
 ///
@@ -1075,6 +1530,16 @@ pub fn get_shader_compile_status(shader: Shader) -> bool {
     val == gl::TRUE as i32
 }
 
+/// # Error mapped get shader compile status
+/// see [get_shader_compile_status]
+pub fn get_shader_compile_status_checked(shader: Shader) -> Result<bool, ShaderError> {
+    let val = get_shader_compile_status(shader);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(shader_error(shader, error)),
+    }
+}
+
 /// # Return the shader info log length
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetShader.xhtml>
 ///
@@ -1113,6 +1578,16 @@ pub fn get_shader_info_log_length(shader: Shader) -> u32 {
     val as u32
 }
 
+/// # Error mapped get shader info log length
+/// see [get_shader_info_log_length]
+pub fn get_shader_info_log_length_checked(shader: Shader) -> Result<u32, ShaderError> {
+    let val = get_shader_info_log_length(shader);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(shader_error(shader, error)),
+    }
+}
+
 /// # Returns the information log for a shader object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetShaderInfoLog.xhtml>
 ///
@@ -1180,6 +1655,19 @@ pub fn get_shader_info_log(shader: Shader, buffer: &mut [u8]) -> &[u8] {
     &buffer[..length]
 }
 
+/// # Error mapped get shader info log
+/// see [get_shader_info_log]
+pub fn get_shader_info_log_checked(
+    shader: Shader,
+    buffer: &mut [u8],
+) -> Result<&[u8], ShaderError> {
+    let info_log = get_shader_info_log(shader, buffer);
+    match get_error() {
+        Error::NoError => Ok(info_log),
+        error => Err(shader_error(shader, error)),
+    }
+}
+
 /// # Determines if a name corresponds to a program object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glIsProgram.xhtml>
 ///
@@ -1236,6 +1724,16 @@ pub fn is_program(program: Program) -> bool {
     val == gl::TRUE
 }
 
+/// # Error mapped is program
+/// see [is_program]
+pub fn is_program_checked(program: Program) -> Result<bool, ShaderError> {
+    let val = is_program(program);
+    match get_error() {
+        Error::NoError => Ok(val),
+        other => Err(ShaderError::Unexpected(other)),
+    }
+}
+
 /// # Determines if a name corresponds to a shader object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glIsShader.xhtml>
 ///
@@ -1277,6 +1775,16 @@ pub fn is_shader(shader: Shader) -> bool {
     val == gl::TRUE
 }
 
+/// # Error mapped is shader
+/// see [is_shader]
+pub fn is_shader_checked(shader: Shader) -> Result<bool, ShaderError> {
+    let val = is_shader(shader);
+    match get_error() {
+        Error::NoError => Ok(val),
+        other => Err(ShaderError::Unexpected(other)),
+    }
+}
+
 /// # Links a program object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glLinkProgram.xhtml>
 ///
@@ -1329,7 +1837,7 @@ pub fn is_shader(shader: Shader) -> bool {
 /// * The number of active outputs in the fragment shader is greater than the value of
 /// [get_max_draw_buffers].
 /// * The program has an active output assigned to a location greater than or equal to the value of
-/// [get_max_dual_source_draw_buffes] and has an active output assigned an index greater than or
+/// [get_max_dual_source_draw_buffers] and has an active output assigned an index greater than or
 /// equal to one.
 /// * More than one varying out variable is bound to the same number and index.
 /// * The explicit binding assigments do not leave enough space for the linker to automatically
@@ -1416,6 +1924,19 @@ pub fn link_program(program: Program) {
     unsafe { gl::LinkProgram(program) }
 }
 
+/// # Error mapped link program
+/// see [link_program]
+pub fn link_program_checked(program: Program) -> Result<(), ShaderError> {
+    link_program(program);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation if is_program(program) => {
+            Err(ShaderError::TransformFeedbackActive(program))
+        }
+        error => Err(program_error(program, error)),
+    }
+}
+
 /// # Replaces the source code in a shader object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glShaderSource.xhtml>
 ///
@@ -1477,3 +1998,13 @@ pub fn shader_source(shader: Shader, source: &str) {
     // SAFE: synchronous memory read of `string` and `length`, no pointers
     unsafe { gl::ShaderSource(shader, count, string, length) };
 }
+
+/// # Error mapped shader source
+/// see [shader_source]
+pub fn shader_source_checked(shader: Shader, source: &str) -> Result<(), ShaderError> {
+    shader_source(shader, source);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(shader_error(shader, error)),
+    }
+}
@@ -8,6 +8,10 @@
 //! information includes, but is not limited to uniform definitions, attributes, fragment outputs,
 //! etc. These queries also get various information about how to interface with the program
 //! (attribute indices, fragment output indices, uniform locations, etc).
+//!
+//! [ProgramReflection] walks every program interface of a linked program at once, collecting each
+//! uniform, attribute, block, buffer variable and output with its [GlslType], array size and
+//! location.
 
 use crate::prelude::*;
 use gl::types::*;
@@ -86,3 +90,2266 @@ pub fn get_uniform_location(program: Program, name: &std::ffi::CStr) -> UniformL
     let location = unsafe { gl::GetUniformLocation(program, name) };
     UniformLocation(location)
 }
+
+/// # Error mapped get uniform location
+/// see [get_uniform_location]
+pub fn get_uniform_location_checked(
+    program: Program,
+    name: &std::ffi::CStr,
+) -> Result<UniformLocation, ShaderError> {
+    let location = get_uniform_location(program, name);
+    match get_error() {
+        Error::NoError => Ok(location),
+        error => Err(linked_error(program, error)),
+    }
+}
+
+/// Decode the error of a query that requires a successfully linked program
+fn linked_error(program: Program, error: Error) -> ShaderError {
+    match error {
+        Error::InvalidOperation if is_program(program) => ShaderError::ProgramNotLinked(program),
+        error => program_error(program, error),
+    }
+}
+
+/// Decode the error of a query taking an `index` into the active variables of a program
+fn index_error(program: Program, index: u32, error: Error) -> ShaderError {
+    match error {
+        Error::InvalidValue if is_program(program) => ShaderError::InvalidIndex(index),
+        error => program_error(program, error),
+    }
+}
+
+/// Decode the error of a program interface query, where [Error::InvalidEnum] and
+/// [Error::InvalidOperation] are also generated by queries `interface` does not support
+fn interface_error(
+    program: Program,
+    interface: ProgramInterface,
+    index: Option<u32>,
+    error: Error,
+) -> ShaderError {
+    match (error, index) {
+        (Error::InvalidEnum, _) => ShaderError::UnsupportedInterfaceQuery(interface),
+        (Error::InvalidValue, Some(index)) if is_program(program) => {
+            ShaderError::InvalidIndex(index)
+        }
+        (Error::InvalidOperation, _) if is_program(program) => {
+            if get_program_link_status(program) {
+                ShaderError::UnsupportedInterfaceQuery(interface)
+            } else {
+                ShaderError::ProgramNotLinked(program)
+            }
+        }
+        (error, _) => program_error(program, error),
+    }
+}
+
+/// # Basic type of a GLSL variable
+/// see [GlslType]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GlslScalarType {
+    Bool,
+    Int,
+    UnsignedInt,
+    Float,
+    Double,
+}
+
+/// # Type of a GLSL variable
+/// see [get_active_uniform], [get_active_attrib] and [ProgramReflection]
+///
+/// Samplers and images carry the [TextureBindingTarget] their texture must be bound to, and the
+/// basic type they return (`sampler2D` is [GlslScalarType::Float], `isampler2D` is
+/// [GlslScalarType::Int], etc).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GlslType {
+    /// `float`, `double`, `int`, `uint` or `bool`
+    Scalar(GlslScalarType),
+
+    /// a vector of 2, 3 or 4 components, such as `vec3` or `ivec2`
+    Vector(GlslScalarType, u8),
+
+    /// a `float` or `double` matrix, such as `mat4` or `dmat2x3`
+    Matrix {
+        ty: GlslScalarType,
+        columns: u8,
+        rows: u8,
+    },
+
+    /// a sampler, such as `sampler2D`, `usamplerBuffer` or `samplerCubeShadow`
+    Sampler {
+        target: TextureBindingTarget,
+        ty: GlslScalarType,
+        shadow: bool,
+    },
+
+    /// an image, such as `image2D` or `uimage3D`
+    Image {
+        target: TextureBindingTarget,
+        ty: GlslScalarType,
+    },
+
+    /// `atomic_uint`
+    AtomicCounter,
+
+    /// A type returned by the GL that is unknown to rgl
+    Unknown(u32),
+}
+
+impl From<GLenum> for GlslType {
+    fn from(value: GLenum) -> Self {
+        let matrix = |ty, columns, rows| GlslType::Matrix { ty, columns, rows };
+        let sampler = |target, ty, shadow| GlslType::Sampler { target, ty, shadow };
+        let image = |target, ty| GlslType::Image { target, ty };
+        match value {
+            gl::FLOAT => GlslType::Scalar(GlslScalarType::Float),
+            gl::DOUBLE => GlslType::Scalar(GlslScalarType::Double),
+            gl::INT => GlslType::Scalar(GlslScalarType::Int),
+            gl::UNSIGNED_INT => GlslType::Scalar(GlslScalarType::UnsignedInt),
+            gl::BOOL => GlslType::Scalar(GlslScalarType::Bool),
+            gl::FLOAT_VEC2 => GlslType::Vector(GlslScalarType::Float, 2),
+            gl::FLOAT_VEC3 => GlslType::Vector(GlslScalarType::Float, 3),
+            gl::FLOAT_VEC4 => GlslType::Vector(GlslScalarType::Float, 4),
+            gl::DOUBLE_VEC2 => GlslType::Vector(GlslScalarType::Double, 2),
+            gl::DOUBLE_VEC3 => GlslType::Vector(GlslScalarType::Double, 3),
+            gl::DOUBLE_VEC4 => GlslType::Vector(GlslScalarType::Double, 4),
+            gl::INT_VEC2 => GlslType::Vector(GlslScalarType::Int, 2),
+            gl::INT_VEC3 => GlslType::Vector(GlslScalarType::Int, 3),
+            gl::INT_VEC4 => GlslType::Vector(GlslScalarType::Int, 4),
+            gl::UNSIGNED_INT_VEC2 => GlslType::Vector(GlslScalarType::UnsignedInt, 2),
+            gl::UNSIGNED_INT_VEC3 => GlslType::Vector(GlslScalarType::UnsignedInt, 3),
+            gl::UNSIGNED_INT_VEC4 => GlslType::Vector(GlslScalarType::UnsignedInt, 4),
+            gl::BOOL_VEC2 => GlslType::Vector(GlslScalarType::Bool, 2),
+            gl::BOOL_VEC3 => GlslType::Vector(GlslScalarType::Bool, 3),
+            gl::BOOL_VEC4 => GlslType::Vector(GlslScalarType::Bool, 4),
+            gl::FLOAT_MAT2 => matrix(GlslScalarType::Float, 2, 2),
+            gl::FLOAT_MAT2x3 => matrix(GlslScalarType::Float, 2, 3),
+            gl::FLOAT_MAT2x4 => matrix(GlslScalarType::Float, 2, 4),
+            gl::FLOAT_MAT3x2 => matrix(GlslScalarType::Float, 3, 2),
+            gl::FLOAT_MAT3 => matrix(GlslScalarType::Float, 3, 3),
+            gl::FLOAT_MAT3x4 => matrix(GlslScalarType::Float, 3, 4),
+            gl::FLOAT_MAT4x2 => matrix(GlslScalarType::Float, 4, 2),
+            gl::FLOAT_MAT4x3 => matrix(GlslScalarType::Float, 4, 3),
+            gl::FLOAT_MAT4 => matrix(GlslScalarType::Float, 4, 4),
+            gl::DOUBLE_MAT2 => matrix(GlslScalarType::Double, 2, 2),
+            gl::DOUBLE_MAT2x3 => matrix(GlslScalarType::Double, 2, 3),
+            gl::DOUBLE_MAT2x4 => matrix(GlslScalarType::Double, 2, 4),
+            gl::DOUBLE_MAT3x2 => matrix(GlslScalarType::Double, 3, 2),
+            gl::DOUBLE_MAT3 => matrix(GlslScalarType::Double, 3, 3),
+            gl::DOUBLE_MAT3x4 => matrix(GlslScalarType::Double, 3, 4),
+            gl::DOUBLE_MAT4x2 => matrix(GlslScalarType::Double, 4, 2),
+            gl::DOUBLE_MAT4x3 => matrix(GlslScalarType::Double, 4, 3),
+            gl::DOUBLE_MAT4 => matrix(GlslScalarType::Double, 4, 4),
+            gl::SAMPLER_1D => sampler(TextureBindingTarget::Image1D, GlslScalarType::Float, false),
+            gl::SAMPLER_2D => sampler(TextureBindingTarget::Image2D, GlslScalarType::Float, false),
+            gl::SAMPLER_3D => sampler(TextureBindingTarget::Image3D, GlslScalarType::Float, false),
+            gl::SAMPLER_CUBE => {
+                sampler(TextureBindingTarget::CubeMap, GlslScalarType::Float, false)
+            }
+            gl::SAMPLER_1D_ARRAY => {
+                sampler(TextureBindingTarget::Array1D, GlslScalarType::Float, false)
+            }
+            gl::SAMPLER_2D_ARRAY => {
+                sampler(TextureBindingTarget::Array2D, GlslScalarType::Float, false)
+            }
+            gl::SAMPLER_2D_MULTISAMPLE => sampler(
+                TextureBindingTarget::Multisample2D,
+                GlslScalarType::Float,
+                false,
+            ),
+            gl::SAMPLER_2D_MULTISAMPLE_ARRAY => sampler(
+                TextureBindingTarget::Multisample2DArray,
+                GlslScalarType::Float,
+                false,
+            ),
+            gl::SAMPLER_BUFFER => {
+                sampler(TextureBindingTarget::Buffer, GlslScalarType::Float, false)
+            }
+            gl::SAMPLER_2D_RECT => sampler(
+                TextureBindingTarget::Rectangle,
+                GlslScalarType::Float,
+                false,
+            ),
+            gl::SAMPLER_CUBE_MAP_ARRAY => sampler(
+                TextureBindingTarget::CubeMapArray,
+                GlslScalarType::Float,
+                false,
+            ),
+            gl::INT_SAMPLER_1D => {
+                sampler(TextureBindingTarget::Image1D, GlslScalarType::Int, false)
+            }
+            gl::INT_SAMPLER_2D => {
+                sampler(TextureBindingTarget::Image2D, GlslScalarType::Int, false)
+            }
+            gl::INT_SAMPLER_3D => {
+                sampler(TextureBindingTarget::Image3D, GlslScalarType::Int, false)
+            }
+            gl::INT_SAMPLER_CUBE => {
+                sampler(TextureBindingTarget::CubeMap, GlslScalarType::Int, false)
+            }
+            gl::INT_SAMPLER_1D_ARRAY => {
+                sampler(TextureBindingTarget::Array1D, GlslScalarType::Int, false)
+            }
+            gl::INT_SAMPLER_2D_ARRAY => {
+                sampler(TextureBindingTarget::Array2D, GlslScalarType::Int, false)
+            }
+            gl::INT_SAMPLER_2D_MULTISAMPLE => sampler(
+                TextureBindingTarget::Multisample2D,
+                GlslScalarType::Int,
+                false,
+            ),
+            gl::INT_SAMPLER_2D_MULTISAMPLE_ARRAY => sampler(
+                TextureBindingTarget::Multisample2DArray,
+                GlslScalarType::Int,
+                false,
+            ),
+            gl::INT_SAMPLER_BUFFER => {
+                sampler(TextureBindingTarget::Buffer, GlslScalarType::Int, false)
+            }
+            gl::INT_SAMPLER_2D_RECT => {
+                sampler(TextureBindingTarget::Rectangle, GlslScalarType::Int, false)
+            }
+            gl::INT_SAMPLER_CUBE_MAP_ARRAY => sampler(
+                TextureBindingTarget::CubeMapArray,
+                GlslScalarType::Int,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_1D => sampler(
+                TextureBindingTarget::Image1D,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_2D => sampler(
+                TextureBindingTarget::Image2D,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_3D => sampler(
+                TextureBindingTarget::Image3D,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_CUBE => sampler(
+                TextureBindingTarget::CubeMap,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_1D_ARRAY => sampler(
+                TextureBindingTarget::Array1D,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_2D_ARRAY => sampler(
+                TextureBindingTarget::Array2D,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE => sampler(
+                TextureBindingTarget::Multisample2D,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY => sampler(
+                TextureBindingTarget::Multisample2DArray,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_BUFFER => sampler(
+                TextureBindingTarget::Buffer,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_2D_RECT => sampler(
+                TextureBindingTarget::Rectangle,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::UNSIGNED_INT_SAMPLER_CUBE_MAP_ARRAY => sampler(
+                TextureBindingTarget::CubeMapArray,
+                GlslScalarType::UnsignedInt,
+                false,
+            ),
+            gl::SAMPLER_1D_SHADOW => {
+                sampler(TextureBindingTarget::Image1D, GlslScalarType::Float, true)
+            }
+            gl::SAMPLER_2D_SHADOW => {
+                sampler(TextureBindingTarget::Image2D, GlslScalarType::Float, true)
+            }
+            gl::SAMPLER_CUBE_SHADOW => {
+                sampler(TextureBindingTarget::CubeMap, GlslScalarType::Float, true)
+            }
+            gl::SAMPLER_1D_ARRAY_SHADOW => {
+                sampler(TextureBindingTarget::Array1D, GlslScalarType::Float, true)
+            }
+            gl::SAMPLER_2D_ARRAY_SHADOW => {
+                sampler(TextureBindingTarget::Array2D, GlslScalarType::Float, true)
+            }
+            gl::SAMPLER_2D_RECT_SHADOW => {
+                sampler(TextureBindingTarget::Rectangle, GlslScalarType::Float, true)
+            }
+            gl::SAMPLER_CUBE_MAP_ARRAY_SHADOW => sampler(
+                TextureBindingTarget::CubeMapArray,
+                GlslScalarType::Float,
+                true,
+            ),
+            gl::IMAGE_1D => image(TextureBindingTarget::Image1D, GlslScalarType::Float),
+            gl::IMAGE_2D => image(TextureBindingTarget::Image2D, GlslScalarType::Float),
+            gl::IMAGE_3D => image(TextureBindingTarget::Image3D, GlslScalarType::Float),
+            gl::IMAGE_2D_RECT => image(TextureBindingTarget::Rectangle, GlslScalarType::Float),
+            gl::IMAGE_CUBE => image(TextureBindingTarget::CubeMap, GlslScalarType::Float),
+            gl::IMAGE_BUFFER => image(TextureBindingTarget::Buffer, GlslScalarType::Float),
+            gl::IMAGE_1D_ARRAY => image(TextureBindingTarget::Array1D, GlslScalarType::Float),
+            gl::IMAGE_2D_ARRAY => image(TextureBindingTarget::Array2D, GlslScalarType::Float),
+            gl::IMAGE_CUBE_MAP_ARRAY => {
+                image(TextureBindingTarget::CubeMapArray, GlslScalarType::Float)
+            }
+            gl::IMAGE_2D_MULTISAMPLE => {
+                image(TextureBindingTarget::Multisample2D, GlslScalarType::Float)
+            }
+            gl::IMAGE_2D_MULTISAMPLE_ARRAY => image(
+                TextureBindingTarget::Multisample2DArray,
+                GlslScalarType::Float,
+            ),
+            gl::INT_IMAGE_1D => image(TextureBindingTarget::Image1D, GlslScalarType::Int),
+            gl::INT_IMAGE_2D => image(TextureBindingTarget::Image2D, GlslScalarType::Int),
+            gl::INT_IMAGE_3D => image(TextureBindingTarget::Image3D, GlslScalarType::Int),
+            gl::INT_IMAGE_2D_RECT => image(TextureBindingTarget::Rectangle, GlslScalarType::Int),
+            gl::INT_IMAGE_CUBE => image(TextureBindingTarget::CubeMap, GlslScalarType::Int),
+            gl::INT_IMAGE_BUFFER => image(TextureBindingTarget::Buffer, GlslScalarType::Int),
+            gl::INT_IMAGE_1D_ARRAY => image(TextureBindingTarget::Array1D, GlslScalarType::Int),
+            gl::INT_IMAGE_2D_ARRAY => image(TextureBindingTarget::Array2D, GlslScalarType::Int),
+            gl::INT_IMAGE_CUBE_MAP_ARRAY => {
+                image(TextureBindingTarget::CubeMapArray, GlslScalarType::Int)
+            }
+            gl::INT_IMAGE_2D_MULTISAMPLE => {
+                image(TextureBindingTarget::Multisample2D, GlslScalarType::Int)
+            }
+            gl::INT_IMAGE_2D_MULTISAMPLE_ARRAY => image(
+                TextureBindingTarget::Multisample2DArray,
+                GlslScalarType::Int,
+            ),
+            gl::UNSIGNED_INT_IMAGE_1D => {
+                image(TextureBindingTarget::Image1D, GlslScalarType::UnsignedInt)
+            }
+            gl::UNSIGNED_INT_IMAGE_2D => {
+                image(TextureBindingTarget::Image2D, GlslScalarType::UnsignedInt)
+            }
+            gl::UNSIGNED_INT_IMAGE_3D => {
+                image(TextureBindingTarget::Image3D, GlslScalarType::UnsignedInt)
+            }
+            gl::UNSIGNED_INT_IMAGE_2D_RECT => {
+                image(TextureBindingTarget::Rectangle, GlslScalarType::UnsignedInt)
+            }
+            gl::UNSIGNED_INT_IMAGE_CUBE => {
+                image(TextureBindingTarget::CubeMap, GlslScalarType::UnsignedInt)
+            }
+            gl::UNSIGNED_INT_IMAGE_BUFFER => {
+                image(TextureBindingTarget::Buffer, GlslScalarType::UnsignedInt)
+            }
+            gl::UNSIGNED_INT_IMAGE_1D_ARRAY => {
+                image(TextureBindingTarget::Array1D, GlslScalarType::UnsignedInt)
+            }
+            gl::UNSIGNED_INT_IMAGE_2D_ARRAY => {
+                image(TextureBindingTarget::Array2D, GlslScalarType::UnsignedInt)
+            }
+            gl::UNSIGNED_INT_IMAGE_CUBE_MAP_ARRAY => image(
+                TextureBindingTarget::CubeMapArray,
+                GlslScalarType::UnsignedInt,
+            ),
+            gl::UNSIGNED_INT_IMAGE_2D_MULTISAMPLE => image(
+                TextureBindingTarget::Multisample2D,
+                GlslScalarType::UnsignedInt,
+            ),
+            gl::UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY => image(
+                TextureBindingTarget::Multisample2DArray,
+                GlslScalarType::UnsignedInt,
+            ),
+            gl::UNSIGNED_INT_ATOMIC_COUNTER => GlslType::AtomicCounter,
+            other => GlslType::Unknown(other),
+        }
+    }
+}
+
+/// # An active attribute or uniform variable
+/// see [get_active_attrib] and [get_active_uniform]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ActiveVariable<'a> {
+    /// The name of the variable, as a sub-slice of the given buffer with the null terminator
+    /// stripped
+    pub name: &'a [u8],
+
+    /// The type of the variable
+    pub ty: GlslType,
+
+    /// The size of the variable, in units of `ty`, which is 1 for variables that are not arrays
+    pub size: u32,
+}
+
+/// # Returns information about an active attribute variable
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetActiveAttrib.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the program object to be queried.
+/// * `index` - Specifies the index of the attribute variable to be queried.
+/// * `buffer` - Specifies a mut slice of characters that is used to return the attribute name.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut buffer = [0; 64];
+/// let attrib = get_active_attrib(Program(42), 0, &mut buffer);
+/// assert_eq!(attrib.name, b"position");
+/// assert_eq!(attrib.ty, GlslType::Vector(GlslScalarType::Float, 3));
+/// ```
+///
+/// # Description
+/// [get_active_attrib] returns information about an active attribute variable in the program
+/// object specified by `program`. The number of active attributes can be obtained by calling
+/// [get_program_active_attributes]. A value of 0 for `index` selects the first active attribute
+/// variable. Permissible values for `index` range from zero to the number of active attribute
+/// variables minus one.
+///
+/// A vertex shader may use either built-in attribute variables, user-defined attribute variables,
+/// or both. Built-in attribute variables have a prefix of "gl_" and reference conventional OpenGL
+/// vertex attribtes (e.g., gl_Vertex, gl_Normal, etc.). User-defined attribute variables have
+/// arbitrary names and obtain their values through numbered generic vertex attributes. An attribute
+/// variable (either built-in or user-defined) is considered active if it is determined during the
+/// link operation that it may be accessed during program execution. Therefore, `program` should
+/// have previously been the target of a call to [link_program], but it is not necessary for it to
+/// have been linked successfully.
+///
+/// The name of the attribute is returned as much as fits in `buffer`, and the size of the buffer
+/// required to store the longest attribute name can be obtained by calling
+/// [get_program_attribute_max_length]. The returned name has the null terminator stripped.
+///
+/// The attribute variable may be a scalar, a vector or a matrix, and its size is returned in units
+/// of its type. Attribute variables that are declared as arrays report their array size.
+///
+/// This function will return as much information as it can about the specified active attribute
+/// variable. If no information is available, the returned name will be empty. This can occur if
+/// this function is called after a link operation that failed.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to the number of active attribute
+/// variables in `program`.
+///
+/// # Associated Gets
+/// * [get_program_active_attributes]
+/// * [get_program_attribute_max_length]
+/// * [is_program]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_active_attrib] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_attrib_location]
+/// * [get_attrib_location]
+/// * [link_program]
+pub fn get_active_attrib(program: Program, index: u32, buffer: &mut [u8]) -> ActiveVariable<'_> {
+    let program = program.0;
+    let buf_size = buffer.len() as GLsizei;
+    let mut length = Default::default();
+    let length_out = &mut length as *mut GLsizei;
+    let mut size = Default::default();
+    let size_out = &mut size as *mut GLint;
+    let mut ty = Default::default();
+    let ty_out = &mut ty as *mut GLenum;
+    let name_out = buffer.as_mut_ptr() as *mut GLchar;
+
+    // SAFE: synchronous write to `buffer`, `length`, `size` and `ty`, no memory retained
+    unsafe {
+        gl::GetActiveAttrib(
+            program, index, buf_size, length_out, size_out, ty_out, name_out,
+        )
+    };
+
+    // the number of characters written excludes the null terminator
+    let length = length as usize;
+    ActiveVariable {
+        name: &buffer[..length],
+        ty: GlslType::from(ty),
+        size: size as u32,
+    }
+}
+
+/// # Error mapped get active attrib
+/// see [get_active_attrib]
+pub fn get_active_attrib_checked(
+    program: Program,
+    index: u32,
+    buffer: &mut [u8],
+) -> Result<ActiveVariable<'_>, ShaderError> {
+    let attrib = get_active_attrib(program, index, buffer);
+    match get_error() {
+        Error::NoError => Ok(attrib),
+        error => Err(index_error(program, index, error)),
+    }
+}
+
+/// # Returns information about an active uniform variable
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetActiveUniform.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the program object to be queried.
+/// * `index` - Specifies the index of the uniform variable to be queried.
+/// * `buffer` - Specifies a mut slice of characters that is used to return the uniform name.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut buffer = [0; 64];
+/// let uniform = get_active_uniform(Program(42), 0, &mut buffer);
+/// assert_eq!(uniform.name, b"projection");
+/// assert_eq!(uniform.ty, GlslType::Matrix { ty: GlslScalarType::Float, columns: 4, rows: 4 });
+/// ```
+///
+/// # Description
+/// [get_active_uniform] returns information about an active uniform variable in the program object
+/// specified by `program`. The number of active uniform variables can be obtained by calling
+/// [get_program_active_uniforms]. A value of 0 for `index` selects the first active uniform
+/// variable. Permissible values for `index` range from zero to the number of active uniform
+/// variables minus one.
+///
+/// Shaders may use either built-in uniform variables, user-defined uniform variables, or both.
+/// Built-in uniform variables have a prefix of "gl_" and reference existing OpenGL state or values
+/// derived from such state (e.g., gl_DepthRange). User-defined uniform variables have arbitrary
+/// names and obtain their values from the application through calls to the `uniform_*` setters. A
+/// uniform variable (either built-in or user-defined) is considered active if it is determined
+/// during the link operation that it may be accessed during program execution. Therefore,
+/// `program` should have previously been the target of a call to [link_program], but it is not
+/// necessary for it to have been linked successfully.
+///
+/// The name of the uniform is returned as much as fits in `buffer`, and the size of the buffer
+/// required to store the longest uniform name can be obtained by calling
+/// [get_program_active_uniform_max_length]. The returned name has the null terminator stripped.
+///
+/// The uniform variable may be a scalar, a vector, a matrix, a sampler, an image or an atomic
+/// counter. Uniform variables that are declared as arrays report their array size, and the
+/// returned name has "\[0\]" appended. Only one active uniform variable will be reported for a
+/// uniform array. Uniform variables that are declared as structures or arrays of structures will
+/// not be returned directly by this function. Instead, each of these uniform variables will be
+/// reduced to its fundamental components containing the "." and "[]" operators such that each of
+/// the names is valid as an argument to [get_uniform_location].
+///
+/// This function will return as much information as it can about the specified active uniform
+/// variable. If no information is available, the returned name will be empty. This can occur if
+/// this function is called after a link operation that failed.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to the number of active uniform
+/// variables in `program`.
+///
+/// # Associated Gets
+/// * [get_program_active_uniforms]
+/// * [get_program_active_uniform_max_length]
+/// * [is_program]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_active_uniform] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_uniform_location]
+/// * [link_program]
+pub fn get_active_uniform(program: Program, index: u32, buffer: &mut [u8]) -> ActiveVariable<'_> {
+    let program = program.0;
+    let buf_size = buffer.len() as GLsizei;
+    let mut length = Default::default();
+    let length_out = &mut length as *mut GLsizei;
+    let mut size = Default::default();
+    let size_out = &mut size as *mut GLint;
+    let mut ty = Default::default();
+    let ty_out = &mut ty as *mut GLenum;
+    let name_out = buffer.as_mut_ptr() as *mut GLchar;
+
+    // SAFE: synchronous write to `buffer`, `length`, `size` and `ty`, no memory retained
+    unsafe {
+        gl::GetActiveUniform(
+            program, index, buf_size, length_out, size_out, ty_out, name_out,
+        )
+    };
+
+    // the number of characters written excludes the null terminator
+    let length = length as usize;
+    ActiveVariable {
+        name: &buffer[..length],
+        ty: GlslType::from(ty),
+        size: size as u32,
+    }
+}
+
+/// # Error mapped get active uniform
+/// see [get_active_uniform]
+pub fn get_active_uniform_checked(
+    program: Program,
+    index: u32,
+    buffer: &mut [u8],
+) -> Result<ActiveVariable<'_>, ShaderError> {
+    let uniform = get_active_uniform(program, index, buffer);
+    match get_error() {
+        Error::NoError => Ok(uniform),
+        error => Err(index_error(program, index, error)),
+    }
+}
+
+/// # Returns the location of an attribute variable
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetAttribLocation.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the program object to be queried.
+/// * `name` - Points to a null terminated string containing the name of the attribute variable
+/// whose location is to be queried.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let index = get_attrib_location(Program(42), c"position");
+/// assert_eq!(index, Some(0));
+/// ```
+///
+/// # Description
+/// [get_attrib_location] queries the previously linked program object specified by `program` for
+/// the attribute variable specified by `name` and returns the index of the generic vertex
+/// attribute that is bound to that attribute variable. If `name` is a matrix attribute variable,
+/// the index of the first column of the matrix is returned. If the named attribute variable is not
+/// an active attribute in the specified program object or if `name` starts with the reserved
+/// prefix "gl_", [None] is returned.
+///
+/// The association between an attribute variable name and a generic attribute index can be
+/// specified at any time by calling [bind_attrib_location]. Attribute bindings do not go into
+/// effect until [link_program] is called. After a program object has been linked successfully, the
+/// index values for attribute variables remain fixed until the next link command occurs. The
+/// attribute values can only be queried after a link if the link was successful.
+/// [get_attrib_location] returns the binding that actually went into effect the last time
+/// [link_program] was called for the specified program object. Attribute bindings that have been
+/// specified since the last link operation are not returned by [get_attrib_location].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidOperation] - if `program` has not been successfully linked.
+///
+/// # Associated Gets
+/// * [get_active_attrib]
+/// * [is_program]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_attrib_location] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_attrib_location]
+/// * [link_program]
+/// * [vertex_attrib_pointer]
+pub fn get_attrib_location(program: Program, name: &std::ffi::CStr) -> Option<u32> {
+    let program = program.0;
+    let name = name.as_ptr() as *const GLchar;
+
+    // SAFE: synchronous read of `name`, no memory retained
+    let location = unsafe { gl::GetAttribLocation(program, name) };
+    u32::try_from(location).ok()
+}
+
+/// # Error mapped get attrib location
+/// see [get_attrib_location]
+pub fn get_attrib_location_checked(
+    program: Program,
+    name: &std::ffi::CStr,
+) -> Result<Option<u32>, ShaderError> {
+    let location = get_attrib_location(program, name);
+    match get_error() {
+        Error::NoError => Ok(location),
+        error => Err(linked_error(program, error)),
+    }
+}
+
+/// # Associates a generic vertex attribute index with a named attribute variable
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBindAttribLocation.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the handle of the program object in which the association is to be
+/// made.
+/// * `index` - Specifies the index of the generic vertex attribute to be bound.
+/// * `name` - Specifies a null terminated string containing the name of the vertex shader
+/// attribute variable to which `index` is to be bound.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// bind_attrib_location(Program(42), 0, c"position");
+/// link_program(Program(42));
+/// ```
+///
+/// # Description
+/// [bind_attrib_location] is used to associate a user-defined attribute variable in the program
+/// object specified by `program` with a generic vertex attribute index. The name of the
+/// user-defined attribute variable is passed as a null terminated string in `name`. The generic
+/// vertex attribute index to be bound to this variable is specified by `index`. When `program` is
+/// made part of current state, values provided via the generic vertex attribute `index` will
+/// modify the value of the user-defined attribute variable specified by `name`.
+///
+/// If `name` refers to a matrix attribute variable, `index` refers to the first column of the
+/// matrix. Other matrix columns are then automatically bound to locations `index+1` for a matrix
+/// of type mat2; `index+1` and `index+2` for a matrix of type mat3; and `index+1`, `index+2`, and
+/// `index+3` for a matrix of type mat4.
+///
+/// This command makes it possible for vertex shaders to use descriptive names for attribute
+/// variables rather than generic variables that are numbered from zero to the value of
+/// [get_max_vertex_attribs] minus one.
+///
+/// Attribute variable name-to-generic attribute index bindings for a program object can be
+/// explicitly assigned at any time by calling [bind_attrib_location]. Attribute bindings do not go
+/// into effect until [link_program] is called. After a program object has been linked
+/// successfully, the index values for generic attributes remain fixed (and their values can be
+/// queried) until the next link command occurs.
+///
+/// Any attribute binding that occurs after the program object has been linked will not take effect
+/// until the next time the program object is linked.
+///
+/// # Compatability
+/// Applications are not allowed to bind any of the standard OpenGL vertex attributes using this
+/// command, as they are bound automatically when needed.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `index` is greater than or equal to [get_max_vertex_attribs].
+/// * [Error::InvalidOperation] - if `name` starts with the reserved prefix "gl_".
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+///
+/// # Associated Gets
+/// * [get_active_attrib]
+/// * [get_attrib_location]
+/// * [is_program]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [bind_attrib_location] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [disable_vertex_attrib_array]
+/// * [enable_vertex_attrib_array]
+/// * [link_program]
+/// * [vertex_attrib_pointer]
+pub fn bind_attrib_location(program: Program, index: u32, name: &std::ffi::CStr) {
+    let program = program.0;
+    let name = name.as_ptr() as *const GLchar;
+
+    // SAFE: synchronous read of `name`, no memory retained
+    unsafe { gl::BindAttribLocation(program, index, name) };
+}
+
+/// # Error mapped bind attrib location
+/// see [bind_attrib_location]
+pub fn bind_attrib_location_checked(
+    program: Program,
+    index: u32,
+    name: &std::ffi::CStr,
+) -> Result<(), ShaderError> {
+    bind_attrib_location(program, index, name);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation if is_program(program) => Err(ShaderError::ReservedName),
+        error => Err(index_error(program, index, error)),
+    }
+}
+
+/// # Retrieve the index of a named uniform block
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetUniformBlockIndex.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the name of a program containing the uniform block.
+/// * `name` - Specifies the name of the uniform block whose index to retrieve.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let index = get_uniform_block_index(Program(42), c"Material");
+/// assert_eq!(index, Some(0));
+/// ```
+///
+/// # Description
+/// [get_uniform_block_index] retrieves the index of a uniform block within `program`.
+///
+/// `program` must be the name of a program object for which the command [link_program] must have
+/// been called in the past, although it is not required that [link_program] must have succeeded.
+/// The link could have failed because the number of active uniforms exceeded the limit.
+///
+/// `name` must contain a null terminated string specifying the name of the uniform block.
+///
+/// [get_uniform_block_index] returns the uniform block index for the uniform block named `name`
+/// of `program`. If `name` does not match an active uniform block of `program`, [None] is
+/// returned. The indices of the active uniform blocks of a program are assigned in consecutive
+/// order, beginning with zero.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+///
+/// # Associated Gets
+/// * [get_active_uniform_block_name]
+/// * [get_active_uniform_block_binding]
+/// * [get_active_uniform_block_data_size]
+/// * [get_active_uniform_block_active_uniforms]
+/// * [get_active_uniform_block_active_uniform_indices]
+/// * [get_active_uniform_block_referenced_by]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_uniform_block_index] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_program_resource_index]
+/// * [uniform_block_binding]
+pub fn get_uniform_block_index(program: Program, name: &std::ffi::CStr) -> Option<u32> {
+    let program = program.0;
+    let name = name.as_ptr() as *const GLchar;
+
+    // SAFE: synchronous read of `name`, no memory retained
+    let index = unsafe { gl::GetUniformBlockIndex(program, name) };
+    (index != gl::INVALID_INDEX).then_some(index)
+}
+
+/// # Error mapped get uniform block index
+/// see [get_uniform_block_index]
+pub fn get_uniform_block_index_checked(
+    program: Program,
+    name: &std::ffi::CStr,
+) -> Result<Option<u32>, ShaderError> {
+    let index = get_uniform_block_index(program, name);
+    match get_error() {
+        Error::NoError => Ok(index),
+        error => Err(program_error(program, error)),
+    }
+}
+
+/// # Retrieve the name of an active uniform block
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetActiveUniformBlockName.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the name of a program containing the uniform block.
+/// * `index` - Specifies the index of the uniform block within `program`.
+/// * `buffer` - Specifies a mut slice of characters that is used to return the block name.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut buffer = [0; 64];
+/// let name = get_active_uniform_block_name(Program(42), 0, &mut buffer);
+/// assert_eq!(name, b"Material");
+/// ```
+///
+/// # Description
+/// [get_active_uniform_block_name] retrieves the name of the active uniform block at `index`
+/// within `program`.
+///
+/// `program` must be the name of a program object for which the command [link_program] must have
+/// been called in the past, although it is not required that [link_program] must have succeeded.
+/// The link could have failed because the number of active uniforms exceeded the limit.
+///
+/// `index` is an active uniform block index of `program`, and must be less than
+/// [get_program_active_uniform_blocks].
+///
+/// As much of the name as fits in `buffer` is written, and the returned sub-slice has the null
+/// terminator stripped. The size of the buffer required to store the name can be obtained by
+/// calling [get_active_uniform_block_name_length].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to
+/// [get_program_active_uniform_blocks].
+///
+/// # Associated Gets
+/// * [get_active_uniform_block_name_length]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_active_uniform_block_name] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_program_resource_name]
+/// * [get_uniform_block_index]
+pub fn get_active_uniform_block_name(program: Program, index: u32, buffer: &mut [u8]) -> &[u8] {
+    let program = program.0;
+    let buf_size = buffer.len() as GLsizei;
+    let mut length = Default::default();
+    let length_out = &mut length as *mut GLsizei;
+    let name_out = buffer.as_mut_ptr() as *mut GLchar;
+
+    // SAFE: synchronous write to `buffer`, no memory retained
+    unsafe { gl::GetActiveUniformBlockName(program, index, buf_size, length_out, name_out) };
+
+    // the number of characters written excludes the null terminator
+    let length = length as usize;
+    &buffer[..length]
+}
+
+/// # Error mapped get active uniform block name
+/// see [get_active_uniform_block_name]
+pub fn get_active_uniform_block_name_checked(
+    program: Program,
+    index: u32,
+    buffer: &mut [u8],
+) -> Result<&[u8], ShaderError> {
+    let length = get_active_uniform_block_name(program, index, buffer).len();
+    match get_error() {
+        Error::NoError => Ok(&buffer[..length]),
+        error => Err(index_error(program, index, error)),
+    }
+}
+
+fn get_active_uniform_block_iv(program: Program, index: u32, pname: GLenum) -> i32 {
+    let program = program.0;
+    let mut params = Default::default();
+    let params_out = &mut params as *mut GLint;
+
+    // SAFE: synchronous write to `params_out`, no memory retained
+    unsafe { gl::GetActiveUniformBlockiv(program, index, pname, params_out) };
+    params
+}
+
+/// # Returns the binding point of an active uniform block
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetActiveUniformBlock.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the name of a program containing the uniform block.
+/// * `index` - Specifies the index of the uniform block within `program`.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_active_uniform_block_binding(Program(42), 0), 2);
+/// ```
+///
+/// # Description
+/// Returns the index of the uniform buffer binding point last selected by the uniform block
+/// specified by `index` for `program`. If no uniform block has been previously specified, zero is
+/// returned.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to
+/// [get_program_active_uniform_blocks].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_active_uniform_block_binding] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_buffer_base]
+/// * [bind_buffer_range]
+/// * [uniform_block_binding]
+pub fn get_active_uniform_block_binding(program: Program, index: u32) -> u32 {
+    let val = get_active_uniform_block_iv(program, index, gl::UNIFORM_BLOCK_BINDING);
+    val as u32
+}
+
+/// # Error mapped get active uniform block binding
+/// see [get_active_uniform_block_binding]
+pub fn get_active_uniform_block_binding_checked(
+    program: Program,
+    index: u32,
+) -> Result<u32, ShaderError> {
+    let val = get_active_uniform_block_binding(program, index);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(index_error(program, index, error)),
+    }
+}
+
+/// # Returns the buffer size required by an active uniform block
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetActiveUniformBlock.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the name of a program containing the uniform block.
+/// * `index` - Specifies the index of the uniform block within `program`.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_active_uniform_block_data_size(Program(42), 0), 32);
+/// ```
+///
+/// # Description
+/// Returns the implementation-dependent minimum total buffer object size, in basic machine units,
+/// required to hold all active uniforms in the uniform block identified by `index`. It is neither
+/// guaranteed nor expected that a given implementation will arrange uniform values as tightly
+/// packed in a buffer object. The exception to this is the std140 uniform block layout, which
+/// guarantees specific packing behavior and does not require the application to query for offsets
+/// and strides. In this case the minimum size may still be queried, even though it is determined
+/// in advance based only on the uniform block declaration.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to
+/// [get_program_active_uniform_blocks].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_active_uniform_block_data_size] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [buffer_data]
+/// * [BlockLayout]
+pub fn get_active_uniform_block_data_size(program: Program, index: u32) -> u32 {
+    let val = get_active_uniform_block_iv(program, index, gl::UNIFORM_BLOCK_DATA_SIZE);
+    val as u32
+}
+
+/// # Error mapped get active uniform block data size
+/// see [get_active_uniform_block_data_size]
+pub fn get_active_uniform_block_data_size_checked(
+    program: Program,
+    index: u32,
+) -> Result<u32, ShaderError> {
+    let val = get_active_uniform_block_data_size(program, index);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(index_error(program, index, error)),
+    }
+}
+
+/// # Returns the name length of an active uniform block
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetActiveUniformBlock.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the name of a program containing the uniform block.
+/// * `index` - Specifies the index of the uniform block within `program`.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_active_uniform_block_name_length(Program(42), 0), 9);
+/// ```
+///
+/// # Description
+/// Returns the total length (including the null terminator) of the name of the uniform block
+/// identified by `index`.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to
+/// [get_program_active_uniform_blocks].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_active_uniform_block_name_length] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_active_uniform_block_name]
+pub fn get_active_uniform_block_name_length(program: Program, index: u32) -> u32 {
+    let val = get_active_uniform_block_iv(program, index, gl::UNIFORM_BLOCK_NAME_LENGTH);
+    val as u32
+}
+
+/// # Error mapped get active uniform block name length
+/// see [get_active_uniform_block_name_length]
+pub fn get_active_uniform_block_name_length_checked(
+    program: Program,
+    index: u32,
+) -> Result<u32, ShaderError> {
+    let val = get_active_uniform_block_name_length(program, index);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(index_error(program, index, error)),
+    }
+}
+
+/// # Returns the number of active uniforms of an active uniform block
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetActiveUniformBlock.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the name of a program containing the uniform block.
+/// * `index` - Specifies the index of the uniform block within `program`.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert_eq!(get_active_uniform_block_active_uniforms(Program(42), 0), 2);
+/// ```
+///
+/// # Description
+/// Returns the number of active uniforms in the uniform block identified by `index`.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to
+/// [get_program_active_uniform_blocks].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_active_uniform_block_active_uniforms] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_active_uniform_block_active_uniform_indices]
+pub fn get_active_uniform_block_active_uniforms(program: Program, index: u32) -> u32 {
+    let val = get_active_uniform_block_iv(program, index, gl::UNIFORM_BLOCK_ACTIVE_UNIFORMS);
+    val as u32
+}
+
+/// # Error mapped get active uniform block active uniforms
+/// see [get_active_uniform_block_active_uniforms]
+pub fn get_active_uniform_block_active_uniforms_checked(
+    program: Program,
+    index: u32,
+) -> Result<u32, ShaderError> {
+    let val = get_active_uniform_block_active_uniforms(program, index);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(index_error(program, index, error)),
+    }
+}
+
+/// # Returns the indices of the active uniforms of an active uniform block
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetActiveUniformBlock.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the name of a program containing the uniform block.
+/// * `index` - Specifies the index of the uniform block within `program`.
+/// * `buffer` - Specifies a mut slice that is used to return the uniform indices.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut buffer = [0; 2];
+/// let indices = get_active_uniform_block_active_uniform_indices(Program(42), 0, &mut buffer);
+/// assert_eq!(indices, &[1, 2]);
+/// ```
+///
+/// # Description
+/// Returns the uniform indices of the active uniforms in the uniform block identified by `index`,
+/// which can be passed to [get_active_uniform]. `buffer` must be at least as long as
+/// [get_active_uniform_block_active_uniforms], and the returned sub-slice holds one index for each
+/// active uniform of the block.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to
+/// [get_program_active_uniform_blocks].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_active_uniform_block_active_uniform_indices] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [get_active_uniform_block_active_uniforms]
+pub fn get_active_uniform_block_active_uniform_indices(
+    program: Program,
+    index: u32,
+    buffer: &mut [u32],
+) -> &[u32] {
+    // the GL writes every index, so never query more than fit in `buffer`
+    let count = get_active_uniform_block_active_uniforms(program, index) as usize;
+    if count == 0 || count > buffer.len() {
+        return &buffer[..0];
+    }
+
+    let program = program.0;
+    let pname = gl::UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES;
+    let params_out = buffer.as_mut_ptr() as *mut GLint;
+
+    // SAFE: synchronous write of `count` indices into `buffer`, no memory retained
+    unsafe { gl::GetActiveUniformBlockiv(program, index, pname, params_out) };
+    &buffer[..count]
+}
+
+/// # Error mapped get active uniform block active uniform indices
+/// see [get_active_uniform_block_active_uniform_indices]
+pub fn get_active_uniform_block_active_uniform_indices_checked(
+    program: Program,
+    index: u32,
+    buffer: &mut [u32],
+) -> Result<&[u32], ShaderError> {
+    let count = get_active_uniform_block_active_uniform_indices(program, index, buffer).len();
+    match get_error() {
+        Error::NoError => Ok(&buffer[..count]),
+        error => Err(index_error(program, index, error)),
+    }
+}
+
+/// # Returns whether an active uniform block is referenced by a shader stage
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetActiveUniformBlock.xhtml>
+///
+/// # Arguments
+/// * `program` - Specifies the name of a program containing the uniform block.
+/// * `index` - Specifies the index of the uniform block within `program`.
+/// * `shader_type` - Specifies the shader stage to query.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(get_active_uniform_block_referenced_by(Program(42), 0, ShaderType::Fragment));
+/// ```
+///
+/// # Description
+/// Returns true if the uniform block identified by `index` is referenced by the `shader_type`
+/// stage of `program`, and false otherwise.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to
+/// [get_program_active_uniform_blocks].
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_active_uniform_block_referenced_by] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [ShaderType::TessControl] and [ShaderType::TessEvaluation] | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+/// | [ShaderType::Compute] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [get_program_resource]
+pub fn get_active_uniform_block_referenced_by(
+    program: Program,
+    index: u32,
+    shader_type: ShaderType,
+) -> bool {
+    let pname = match shader_type {
+        ShaderType::Compute => gl::UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER,
+        ShaderType::Vertex => gl::UNIFORM_BLOCK_REFERENCED_BY_VERTEX_SHADER,
+        ShaderType::TessControl => gl::UNIFORM_BLOCK_REFERENCED_BY_TESS_CONTROL_SHADER,
+        ShaderType::TessEvaluation => gl::UNIFORM_BLOCK_REFERENCED_BY_TESS_EVALUATION_SHADER,
+        ShaderType::Geometry => gl::UNIFORM_BLOCK_REFERENCED_BY_GEOMETRY_SHADER,
+        ShaderType::Fragment => gl::UNIFORM_BLOCK_REFERENCED_BY_FRAGMENT_SHADER,
+    };
+    let val = get_active_uniform_block_iv(program, index, pname);
+    val == gl::TRUE as i32
+}
+
+/// # Error mapped get active uniform block referenced by
+/// see [get_active_uniform_block_referenced_by]
+pub fn get_active_uniform_block_referenced_by_checked(
+    program: Program,
+    index: u32,
+    shader_type: ShaderType,
+) -> Result<bool, ShaderError> {
+    let val = get_active_uniform_block_referenced_by(program, index, shader_type);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(index_error(program, index, error)),
+    }
+}
+
+/// # Program interfaces
+/// see [get_program_interface]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ProgramInterface {
+    /// The set of active uniforms
+    Uniform,
+
+    /// The set of active uniform blocks
+    UniformBlock,
+
+    /// The set of active atomic counter buffer binding points
+    AtomicCounterBuffer,
+
+    /// The set of active input variables used by the first shader stage of the program
+    ProgramInput,
+
+    /// The set of active output variables produced by the last shader stage of the program
+    ProgramOutput,
+
+    /// The set of output variables captured by transform feedback
+    TransformFeedbackVarying,
+
+    /// The set of active buffer binding points used by transform feedback
+    TransformFeedbackBuffer,
+
+    /// The set of active buffer variables used in shader storage blocks
+    BufferVariable,
+
+    /// The set of active shader storage blocks
+    ShaderStorageBlock,
+
+    /// The set of active subroutines of the vertex shader stage
+    VertexSubroutine,
+
+    /// The set of active subroutines of the tessellation control shader stage
+    TessControlSubroutine,
+
+    /// The set of active subroutines of the tessellation evaluation shader stage
+    TessEvaluationSubroutine,
+
+    /// The set of active subroutines of the geometry shader stage
+    GeometrySubroutine,
+
+    /// The set of active subroutines of the fragment shader stage
+    FragmentSubroutine,
+
+    /// The set of active subroutines of the compute shader stage
+    ComputeSubroutine,
+
+    /// The set of active subroutine uniforms of the vertex shader stage
+    VertexSubroutineUniform,
+
+    /// The set of active subroutine uniforms of the tessellation control shader stage
+    TessControlSubroutineUniform,
+
+    /// The set of active subroutine uniforms of the tessellation evaluation shader stage
+    TessEvaluationSubroutineUniform,
+
+    /// The set of active subroutine uniforms of the geometry shader stage
+    GeometrySubroutineUniform,
+
+    /// The set of active subroutine uniforms of the fragment shader stage
+    FragmentSubroutineUniform,
+
+    /// The set of active subroutine uniforms of the compute shader stage
+    ComputeSubroutineUniform,
+}
+
+impl From<ProgramInterface> for GLenum {
+    fn from(value: ProgramInterface) -> Self {
+        match value {
+            ProgramInterface::Uniform => gl::UNIFORM,
+            ProgramInterface::UniformBlock => gl::UNIFORM_BLOCK,
+            ProgramInterface::AtomicCounterBuffer => gl::ATOMIC_COUNTER_BUFFER,
+            ProgramInterface::ProgramInput => gl::PROGRAM_INPUT,
+            ProgramInterface::ProgramOutput => gl::PROGRAM_OUTPUT,
+            ProgramInterface::TransformFeedbackVarying => gl::TRANSFORM_FEEDBACK_VARYING,
+            ProgramInterface::TransformFeedbackBuffer => gl::TRANSFORM_FEEDBACK_BUFFER,
+            ProgramInterface::BufferVariable => gl::BUFFER_VARIABLE,
+            ProgramInterface::ShaderStorageBlock => gl::SHADER_STORAGE_BLOCK,
+            ProgramInterface::VertexSubroutine => gl::VERTEX_SUBROUTINE,
+            ProgramInterface::TessControlSubroutine => gl::TESS_CONTROL_SUBROUTINE,
+            ProgramInterface::TessEvaluationSubroutine => gl::TESS_EVALUATION_SUBROUTINE,
+            ProgramInterface::GeometrySubroutine => gl::GEOMETRY_SUBROUTINE,
+            ProgramInterface::FragmentSubroutine => gl::FRAGMENT_SUBROUTINE,
+            ProgramInterface::ComputeSubroutine => gl::COMPUTE_SUBROUTINE,
+            ProgramInterface::VertexSubroutineUniform => gl::VERTEX_SUBROUTINE_UNIFORM,
+            ProgramInterface::TessControlSubroutineUniform => gl::TESS_CONTROL_SUBROUTINE_UNIFORM,
+            ProgramInterface::TessEvaluationSubroutineUniform => {
+                gl::TESS_EVALUATION_SUBROUTINE_UNIFORM
+            }
+            ProgramInterface::GeometrySubroutineUniform => gl::GEOMETRY_SUBROUTINE_UNIFORM,
+            ProgramInterface::FragmentSubroutineUniform => gl::FRAGMENT_SUBROUTINE_UNIFORM,
+            ProgramInterface::ComputeSubroutineUniform => gl::COMPUTE_SUBROUTINE_UNIFORM,
+        }
+    }
+}
+
+/// # Program interface parameters
+/// see [get_program_interface]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ProgramInterfaceParameter {
+    /// The number of resources in the active resource list of the interface
+    ActiveResources,
+
+    /// The length of the longest resource name, including the null terminator
+    MaxNameLength,
+
+    /// The largest number of active variables of any single resource
+    MaxNumActiveVariables,
+
+    /// The largest number of compatible subroutines of any single subroutine uniform
+    MaxNumCompatibleSubroutines,
+}
+
+impl From<ProgramInterfaceParameter> for GLenum {
+    fn from(value: ProgramInterfaceParameter) -> Self {
+        match value {
+            ProgramInterfaceParameter::ActiveResources => gl::ACTIVE_RESOURCES,
+            ProgramInterfaceParameter::MaxNameLength => gl::MAX_NAME_LENGTH,
+            ProgramInterfaceParameter::MaxNumActiveVariables => gl::MAX_NUM_ACTIVE_VARIABLES,
+            ProgramInterfaceParameter::MaxNumCompatibleSubroutines => {
+                gl::MAX_NUM_COMPATIBLE_SUBROUTINES
+            }
+        }
+    }
+}
+
+/// # Query a property of an interface in a program
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgramInterface.xhtml>
+///
+/// # Arguments
+/// * `program` - The name of a program object whose interface to query.
+/// * `interface` - A token identifying the interface within `program` to query.
+/// * `parameter` - The name of the parameter within `interface` to query.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let uniforms = get_program_interface(
+///     Program(42),
+///     ProgramInterface::Uniform,
+///     ProgramInterfaceParameter::ActiveResources,
+/// );
+/// assert_eq!(uniforms, 3);
+/// ```
+///
+/// # Description
+/// [get_program_interface] queries the property of the interface identifed by `interface` in
+/// `program`, the property name of which is given by `parameter`.
+///
+/// If `parameter` is [ProgramInterfaceParameter::ActiveResources], the value returned is the
+/// number of resources in the active resource list for `interface`. If the list of active
+/// resources for `interface` is empty, zero is returned.
+///
+/// If `parameter` is [ProgramInterfaceParameter::MaxNameLength], the value returned is the length
+/// of the longest active name string for an active resource in `interface`. This length includes
+/// an extra character for the null terminator. If the list of active resources for `interface` is
+/// empty, zero is returned. It is an error to specify [ProgramInterfaceParameter::MaxNameLength]
+/// when `interface` is [ProgramInterface::AtomicCounterBuffer], as active atomic counter buffer
+/// resources are not assigned name strings.
+///
+/// If `parameter` is [ProgramInterfaceParameter::MaxNumActiveVariables], the value returned is the
+/// number of active variables belonging to the interface block or atomic counter buffer resource
+/// in `interface` with the most active variables. If the list of active resources for `interface`
+/// is empty, zero is returned. When `parameter` is
+/// [ProgramInterfaceParameter::MaxNumActiveVariables], `interface` must be
+/// [ProgramInterface::UniformBlock], [ProgramInterface::AtomicCounterBuffer], or
+/// [ProgramInterface::ShaderStorageBlock].
+///
+/// If `parameter` is [ProgramInterfaceParameter::MaxNumCompatibleSubroutines], the value returned
+/// is the number of compatible subroutines belonging to the active subroutine uniform in
+/// `interface` with the most compatible subroutines. If the list of active resources for
+/// `interface` is empty, zero is returned. When `parameter` is
+/// [ProgramInterfaceParameter::MaxNumCompatibleSubroutines], `interface` must be one of the
+/// `*SubroutineUniform` interfaces.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidOperation] - if `parameter` is [ProgramInterfaceParameter::MaxNameLength] and
+/// `interface` is [ProgramInterface::AtomicCounterBuffer].
+/// * [Error::InvalidOperation] - if `parameter` is
+/// [ProgramInterfaceParameter::MaxNumActiveVariables] and `interface` is not a block or atomic
+/// counter buffer interface.
+/// * [Error::InvalidOperation] - if `parameter` is
+/// [ProgramInterfaceParameter::MaxNumCompatibleSubroutines] and `interface` is not a subroutine
+/// uniform interface.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_program_interface] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [get_program_resource]
+/// * [get_program_resource_index]
+/// * [get_program_resource_location]
+/// * [get_program_resource_name]
+pub fn get_program_interface(
+    program: Program,
+    interface: ProgramInterface,
+    parameter: ProgramInterfaceParameter,
+) -> u32 {
+    let program = program.0;
+    let interface = GLenum::from(interface);
+    let pname = GLenum::from(parameter);
+    let mut params = Default::default();
+    let params_out = &mut params as *mut GLint;
+
+    // SAFE: synchronous write to `params_out`, no memory retained
+    unsafe { gl::GetProgramInterfaceiv(program, interface, pname, params_out) };
+    params as u32
+}
+
+/// # Error mapped get program interface
+/// see [get_program_interface]
+pub fn get_program_interface_checked(
+    program: Program,
+    interface: ProgramInterface,
+    parameter: ProgramInterfaceParameter,
+) -> Result<u32, ShaderError> {
+    let val = get_program_interface(program, interface, parameter);
+    match get_error() {
+        Error::NoError => Ok(val),
+        error => Err(interface_error(program, interface, None, error)),
+    }
+}
+
+/// # Query the index of a named resource within a program
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgramResourceIndex.xhtml>
+///
+/// # Arguments
+/// * `program` - The name of a program object whose resources to query.
+/// * `interface` - A token identifying the interface within `program` containing the resource
+/// named `name`.
+/// * `name` - The name of the resource to query the index of.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let index = get_program_resource_index(Program(42), ProgramInterface::UniformBlock, c"Camera");
+/// assert_eq!(index, Some(0));
+/// ```
+///
+/// # Description
+/// [get_program_resource_index] returns the unsigned integer index assigned to a resource named
+/// `name` in the interface type `interface` of program object `program`.
+///
+/// If `name` exactly matches the name string of one of the active resources for `interface`, the
+/// index of the matched resource is returned. Additionally, if `name` would exactly match the name
+/// string of an active resource if "\[0\]" were appended to `name`, the index of the matched
+/// resource is returned. Otherwise, `name` is considered not to be the name of an active resource,
+/// and [None] is returned.
+///
+/// For the interface [ProgramInterface::TransformFeedbackVarying], the value [None] should be
+/// returned when querying the index assigned to the special names `gl_NextBuffer`,
+/// `gl_SkipComponents1`, `gl_SkipComponents2`, `gl_SkipComponents3`, and `gl_SkipComponents4`.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidEnum] - if `interface` is [ProgramInterface::AtomicCounterBuffer] or
+/// [ProgramInterface::TransformFeedbackBuffer], since active atomic counter and transform feedback
+/// buffer resources are not assigned name strings.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_program_resource_index] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [get_program_interface]
+/// * [get_program_resource]
+/// * [get_program_resource_location]
+/// * [get_program_resource_name]
+pub fn get_program_resource_index(
+    program: Program,
+    interface: ProgramInterface,
+    name: &std::ffi::CStr,
+) -> Option<u32> {
+    let program = program.0;
+    let interface = GLenum::from(interface);
+    let name = name.as_ptr() as *const GLchar;
+
+    // SAFE: synchronous read of `name`, no memory retained
+    let index = unsafe { gl::GetProgramResourceIndex(program, interface, name) };
+    (index != gl::INVALID_INDEX).then_some(index)
+}
+
+/// # Error mapped get program resource index
+/// see [get_program_resource_index]
+pub fn get_program_resource_index_checked(
+    program: Program,
+    interface: ProgramInterface,
+    name: &std::ffi::CStr,
+) -> Result<Option<u32>, ShaderError> {
+    let index = get_program_resource_index(program, interface, name);
+    match get_error() {
+        Error::NoError => Ok(index),
+        error => Err(interface_error(program, interface, None, error)),
+    }
+}
+
+/// # Query the name of an indexed resource within a program
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgramResourceName.xhtml>
+///
+/// # Arguments
+/// * `program` - The name of a program object whose resources to query.
+/// * `interface` - A token identifying the interface within `program` containing the indexed
+/// resource.
+/// * `index` - The index of the resource within `interface` of `program`.
+/// * `buffer` - Specifies a mut slice of characters that is used to return the resource name.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut buffer = [0; 64];
+/// let name = get_program_resource_name(Program(42), ProgramInterface::Uniform, 0, &mut buffer);
+/// assert_eq!(name, b"projection");
+/// ```
+///
+/// # Description
+/// [get_program_resource_name] retrieves the name string assigned to the single active resource
+/// with an index of `index` in the interface `interface` of program object `program`. `index` must
+/// be less than the number of entries in the active resource list for `interface`.
+///
+/// As much of the name as fits in `buffer` is written, and the returned sub-slice has the null
+/// terminator stripped. The size of the buffer required to store the longest name of `interface`
+/// can be obtained by calling [get_program_interface] with
+/// [ProgramInterfaceParameter::MaxNameLength].
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidEnum] - if `interface` is [ProgramInterface::AtomicCounterBuffer] or
+/// [ProgramInterface::TransformFeedbackBuffer], since active atomic counter and transform feedback
+/// buffer resources are not assigned name strings.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to the number of entries in the
+/// active resource list for `interface`.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_program_resource_name] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [get_program_interface]
+/// * [get_program_resource]
+/// * [get_program_resource_index]
+/// * [get_program_resource_location]
+pub fn get_program_resource_name(
+    program: Program,
+    interface: ProgramInterface,
+    index: u32,
+    buffer: &mut [u8],
+) -> &[u8] {
+    let program = program.0;
+    let interface = GLenum::from(interface);
+    let buf_size = buffer.len() as GLsizei;
+    let mut length = Default::default();
+    let length_out = &mut length as *mut GLsizei;
+    let name_out = buffer.as_mut_ptr() as *mut GLchar;
+
+    // SAFE: synchronous write to `buffer`, no memory retained
+    unsafe {
+        gl::GetProgramResourceName(program, interface, index, buf_size, length_out, name_out)
+    };
+
+    // the number of characters written excludes the null terminator
+    let length = length as usize;
+    &buffer[..length]
+}
+
+/// # Error mapped get program resource name
+/// see [get_program_resource_name]
+pub fn get_program_resource_name_checked(
+    program: Program,
+    interface: ProgramInterface,
+    index: u32,
+    buffer: &mut [u8],
+) -> Result<&[u8], ShaderError> {
+    let name = get_program_resource_name(program, interface, index, buffer);
+    match get_error() {
+        Error::NoError => Ok(name),
+        error => Err(interface_error(program, interface, Some(index), error)),
+    }
+}
+
+/// # Query the location of a named resource within a program
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgramResourceLocation.xhtml>
+///
+/// # Arguments
+/// * `program` - The name of a program object whose resources to query.
+/// * `interface` - A token identifying the interface within `program` containing the resource
+/// named `name`.
+/// * `name` - The name of the resource to query the location of.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let location =
+///     get_program_resource_location(Program(42), ProgramInterface::ProgramOutput, c"colour");
+/// assert_eq!(location, Some(0));
+/// ```
+///
+/// # Description
+/// [get_program_resource_location] returns the location assigned to the variable named `name` in
+/// interface `interface` of program object `program`. `program` must be the name of a program that
+/// has been linked successfully. `interface` must be one of [ProgramInterface::Uniform],
+/// [ProgramInterface::ProgramInput], [ProgramInterface::ProgramOutput], or one of the
+/// `*SubroutineUniform` interfaces.
+///
+/// The value [None] is returned if an error occurred, if `name` does not identify an active
+/// variable on `interface`, or if `name` identifies an active variable that does not have a valid
+/// location assigned, as described above. The locations returned by these commands are the same
+/// locations returned when querying the [ProgramResourceProperty::Location] and
+/// [ProgramResourceProperty::LocationIndex] resource properties.
+///
+/// A string provided to [get_program_resource_location] is considered to match an active variable
+/// if:
+/// * the string exactly matches the name of the active variable
+/// * if the string identifies the base name of an active array, where the string would exactly
+/// match the name of the variable if the suffix "\[0\]" were appended to the string
+/// * if the string identifies an active element of the array, where the string ends with the
+/// concatenation of the "\[" character, an integer with no "+" sign, extra leading zeroes, or
+/// whitespace identifying an array element, and the "\]" character, the integer is less than the
+/// number of active elements of the array variable, and where the string would exactly match the
+/// enumerated name of the array if the decimal integer were replaced with zero.
+///
+/// Any other string is considered not to identify an active variable. If the string specifies an
+/// element of an array variable, [get_program_resource_location] returns the location assigned to
+/// that element. If it specifies the base name of an array, it identifies the resources associated
+/// with the first element of the array.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidOperation] - if `program` has not been linked successfully.
+/// * [Error::InvalidEnum] - if `interface` is not one of the interfaces listed above.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_program_resource_location] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [get_program_interface]
+/// * [get_program_resource]
+/// * [get_program_resource_index]
+/// * [get_program_resource_name]
+pub fn get_program_resource_location(
+    program: Program,
+    interface: ProgramInterface,
+    name: &std::ffi::CStr,
+) -> Option<u32> {
+    let program = program.0;
+    let interface = GLenum::from(interface);
+    let name = name.as_ptr() as *const GLchar;
+
+    // SAFE: synchronous read of `name`, no memory retained
+    let location = unsafe { gl::GetProgramResourceLocation(program, interface, name) };
+    u32::try_from(location).ok()
+}
+
+/// # Error mapped get program resource location
+/// see [get_program_resource_location]
+pub fn get_program_resource_location_checked(
+    program: Program,
+    interface: ProgramInterface,
+    name: &std::ffi::CStr,
+) -> Result<Option<u32>, ShaderError> {
+    let location = get_program_resource_location(program, interface, name);
+    match get_error() {
+        Error::NoError => Ok(location),
+        error => Err(interface_error(program, interface, None, error)),
+    }
+}
+
+/// # Program resource properties
+/// see [get_program_resource]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ProgramResourceProperty {
+    /// The length of the resource name, including the null terminator
+    NameLength,
+
+    /// The [GlslType] of the variable
+    Type,
+
+    /// The number of active array elements, or 1 for variables that are not arrays
+    ArraySize,
+
+    /// The offset of the variable in its buffer, in basic machine units
+    Offset,
+
+    /// The index of the uniform or shader storage block containing the variable, or -1
+    BlockIndex,
+
+    /// The stride between array elements in the buffer, in basic machine units
+    ArrayStride,
+
+    /// The stride between columns or rows of a matrix in the buffer, in basic machine units
+    MatrixStride,
+
+    /// Whether a matrix in a buffer is stored in row-major order
+    IsRowMajor,
+
+    /// The index of the atomic counter buffer containing the variable, or -1
+    AtomicCounterBufferIndex,
+
+    /// The buffer binding point assigned to the block or atomic counter buffer
+    BufferBinding,
+
+    /// The minimum size of a buffer backing the block or atomic counter buffer, in basic machine
+    /// units
+    BufferDataSize,
+
+    /// The number of active variables of the block or atomic counter buffer
+    NumActiveVariables,
+
+    /// The indices of the active variables of the block or atomic counter buffer, writing
+    /// [NumActiveVariables](ProgramResourceProperty::NumActiveVariables) values
+    ActiveVariables,
+
+    /// Whether the resource is referenced by the vertex shader
+    ReferencedByVertexShader,
+
+    /// Whether the resource is referenced by the tessellation control shader
+    ReferencedByTessControlShader,
+
+    /// Whether the resource is referenced by the tessellation evaluation shader
+    ReferencedByTessEvaluationShader,
+
+    /// Whether the resource is referenced by the geometry shader
+    ReferencedByGeometryShader,
+
+    /// Whether the resource is referenced by the fragment shader
+    ReferencedByFragmentShader,
+
+    /// Whether the resource is referenced by the compute shader
+    ReferencedByComputeShader,
+
+    /// The number of compatible subroutines of the subroutine uniform
+    NumCompatibleSubroutines,
+
+    /// The indices of the compatible subroutines of the subroutine uniform, writing
+    /// [NumCompatibleSubroutines](ProgramResourceProperty::NumCompatibleSubroutines) values
+    CompatibleSubroutines,
+
+    /// The array size of the top level block member of a buffer variable
+    TopLevelArraySize,
+
+    /// The array stride of the top level block member of a buffer variable
+    TopLevelArrayStride,
+
+    /// The location assigned to the variable, or -1
+    Location,
+
+    /// The fragment colour index assigned to the fragment output, or -1
+    LocationIndex,
+
+    /// Whether the input or output is a per-patch attribute
+    IsPerPatch,
+
+    /// The first component assigned to the input or output variable
+    LocationComponent,
+
+    /// The index of the transform feedback buffer capturing the varying
+    TransformFeedbackBufferIndex,
+
+    /// The stride of the transform feedback buffer, in basic machine units
+    TransformFeedbackBufferStride,
+}
+
+impl From<ProgramResourceProperty> for GLenum {
+    fn from(value: ProgramResourceProperty) -> Self {
+        match value {
+            ProgramResourceProperty::NameLength => gl::NAME_LENGTH,
+            ProgramResourceProperty::Type => gl::TYPE,
+            ProgramResourceProperty::ArraySize => gl::ARRAY_SIZE,
+            ProgramResourceProperty::Offset => gl::OFFSET,
+            ProgramResourceProperty::BlockIndex => gl::BLOCK_INDEX,
+            ProgramResourceProperty::ArrayStride => gl::ARRAY_STRIDE,
+            ProgramResourceProperty::MatrixStride => gl::MATRIX_STRIDE,
+            ProgramResourceProperty::IsRowMajor => gl::IS_ROW_MAJOR,
+            ProgramResourceProperty::AtomicCounterBufferIndex => gl::ATOMIC_COUNTER_BUFFER_INDEX,
+            ProgramResourceProperty::BufferBinding => gl::BUFFER_BINDING,
+            ProgramResourceProperty::BufferDataSize => gl::BUFFER_DATA_SIZE,
+            ProgramResourceProperty::NumActiveVariables => gl::NUM_ACTIVE_VARIABLES,
+            ProgramResourceProperty::ActiveVariables => gl::ACTIVE_VARIABLES,
+            ProgramResourceProperty::ReferencedByVertexShader => gl::REFERENCED_BY_VERTEX_SHADER,
+            ProgramResourceProperty::ReferencedByTessControlShader => {
+                gl::REFERENCED_BY_TESS_CONTROL_SHADER
+            }
+            ProgramResourceProperty::ReferencedByTessEvaluationShader => {
+                gl::REFERENCED_BY_TESS_EVALUATION_SHADER
+            }
+            ProgramResourceProperty::ReferencedByGeometryShader => {
+                gl::REFERENCED_BY_GEOMETRY_SHADER
+            }
+            ProgramResourceProperty::ReferencedByFragmentShader => {
+                gl::REFERENCED_BY_FRAGMENT_SHADER
+            }
+            ProgramResourceProperty::ReferencedByComputeShader => gl::REFERENCED_BY_COMPUTE_SHADER,
+            ProgramResourceProperty::NumCompatibleSubroutines => gl::NUM_COMPATIBLE_SUBROUTINES,
+            ProgramResourceProperty::CompatibleSubroutines => gl::COMPATIBLE_SUBROUTINES,
+            ProgramResourceProperty::TopLevelArraySize => gl::TOP_LEVEL_ARRAY_SIZE,
+            ProgramResourceProperty::TopLevelArrayStride => gl::TOP_LEVEL_ARRAY_STRIDE,
+            ProgramResourceProperty::Location => gl::LOCATION,
+            ProgramResourceProperty::LocationIndex => gl::LOCATION_INDEX,
+            ProgramResourceProperty::IsPerPatch => gl::IS_PER_PATCH,
+            ProgramResourceProperty::LocationComponent => gl::LOCATION_COMPONENT,
+            ProgramResourceProperty::TransformFeedbackBufferIndex => {
+                gl::TRANSFORM_FEEDBACK_BUFFER_INDEX
+            }
+            ProgramResourceProperty::TransformFeedbackBufferStride => {
+                gl::TRANSFORM_FEEDBACK_BUFFER_STRIDE
+            }
+        }
+    }
+}
+
+/// # Retrieve values for multiple properties of a single active resource within a program object
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetProgramResource.xhtml>
+///
+/// # Arguments
+/// * `program` - The name of a program object whose resources to query.
+/// * `interface` - A token identifying the interface within `program` containing the resource
+/// named `name`.
+/// * `index` - The index of the resource within `interface` of `program`.
+/// * `properties` - The properties to query.
+/// * `params` - Specifies a mut slice of integers that is used to return the property values.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut params = [0; 3];
+/// let values = get_program_resource(
+///     Program(42),
+///     ProgramInterface::Uniform,
+///     0,
+///     &[
+///         ProgramResourceProperty::Type,
+///         ProgramResourceProperty::ArraySize,
+///         ProgramResourceProperty::Location,
+///     ],
+///     &mut params,
+/// );
+/// assert_eq!(GlslType::from(values[0] as u32), GlslType::AtomicCounter);
+/// ```
+///
+/// # Description
+/// [get_program_resource] returns values for multiple properties of a single active resource with
+/// an index of `index` in the interface `interface` of program object `program`. For each resource,
+/// values for `properties.len()` properties specified by `properties` are returned. The values
+/// associated with the properties of the active resource are written to consecutive entries in
+/// `params`, in increasing order according to position in `properties`. As much data as fits in
+/// `params` is written, and the returned sub-slice holds the values actually written.
+///
+/// Properties that return a list of values, [ProgramResourceProperty::ActiveVariables] and
+/// [ProgramResourceProperty::CompatibleSubroutines], write as many values as the matching
+/// [ProgramResourceProperty::NumActiveVariables] and
+/// [ProgramResourceProperty::NumCompatibleSubroutines] report. Boolean properties are returned as
+/// 0 or 1, and properties that do not apply to a resource, such as the
+/// [ProgramResourceProperty::Location] of a uniform in a named uniform block, are returned as -1.
+///
+/// Not every property is supported by every interface, see the reference page for which
+/// properties each interface accepts.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `properties` is empty.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to the number of entries in the
+/// active resource list for `interface`.
+/// * [Error::InvalidOperation] - if any value in `properties` is not supported for `interface`.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_program_resource] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [get_program_interface]
+/// * [get_program_resource_index]
+/// * [get_program_resource_location]
+/// * [get_program_resource_name]
+pub fn get_program_resource<'a>(
+    program: Program,
+    interface: ProgramInterface,
+    index: u32,
+    properties: &[ProgramResourceProperty],
+    params: &'a mut [i32],
+) -> &'a [i32] {
+    let program = program.0;
+    let interface = GLenum::from(interface);
+    let props: Vec<GLenum> = properties.iter().copied().map(GLenum::from).collect();
+    let prop_count = props.len() as GLsizei;
+    let buf_size = params.len() as GLsizei;
+    let mut length = Default::default();
+    let length_out = &mut length as *mut GLsizei;
+    let params_out = params.as_mut_ptr();
+
+    // SAFE: synchronous read of `props`, synchronous write to `params`, no memory retained
+    unsafe {
+        gl::GetProgramResourceiv(
+            program,
+            interface,
+            index,
+            prop_count,
+            props.as_ptr(),
+            buf_size,
+            length_out,
+            params_out,
+        )
+    };
+
+    let length = length as usize;
+    &params[..length]
+}
+
+/// # Error mapped get program resource
+/// see [get_program_resource]
+pub fn get_program_resource_checked<'a>(
+    program: Program,
+    interface: ProgramInterface,
+    index: u32,
+    properties: &[ProgramResourceProperty],
+    params: &'a mut [i32],
+) -> Result<&'a [i32], ShaderError> {
+    let length = get_program_resource(program, interface, index, properties, params).len();
+    match get_error() {
+        Error::NoError => Ok(&params[..length]),
+        error => Err(interface_error(program, interface, Some(index), error)),
+    }
+}
+
+/// # A variable of a reflected program
+/// see [ProgramReflection]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReflectedVariable {
+    /// The name of the variable, with "\[0\]" appended for arrays
+    pub name: String,
+
+    /// The type of the variable
+    pub ty: GlslType,
+
+    /// The number of active array elements, or 1 for variables that are not arrays
+    pub array_size: u32,
+
+    /// The location of the variable, for uniforms in the default block, inputs and outputs
+    pub location: Option<u32>,
+
+    /// The index of the block containing the variable, for uniforms in named blocks and buffer
+    /// variables
+    pub block_index: Option<u32>,
+
+    /// The offset of the variable in its block or atomic counter buffer, in basic machine units
+    pub offset: Option<u32>,
+}
+
+/// # A uniform or shader storage block of a reflected program
+/// see [ProgramReflection]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReflectedBlock {
+    /// The name of the block
+    pub name: String,
+
+    /// The buffer binding point assigned to the block
+    pub binding: u32,
+
+    /// The minimum size of a buffer backing the block, in basic machine units
+    pub data_size: u32,
+
+    /// The indices of the variables of the block, into [ProgramReflection::uniforms] for uniform
+    /// blocks, and [ProgramReflection::buffer_variables] for shader storage blocks
+    pub variables: Vec<u32>,
+}
+
+/// # Every active resource of a linked program
+/// <https://www.khronos.org/opengl/wiki/Program_Introspection#Interface_query>
+///
+/// Walks the program interfaces of a successfully linked [Program] with [get_program_interface],
+/// [get_program_resource_name] and [get_program_resource], and collects every active uniform,
+/// attribute, block, buffer variable and fragment output. Each list is ordered by resource index,
+/// so an index returned by the GL (such as [ReflectedVariable::block_index]) can be used to index
+/// the matching list directly.
+///
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let reflection = ProgramReflection::new(Program(42));
+/// for uniform in &reflection.uniforms {
+///     println!("{}: {:?}[{}]", uniform.name, uniform.ty, uniform.array_size);
+/// }
+/// let projection = reflection.uniform_location("projection");
+/// ```
+///
+/// # Version Support
+/// Requires OpenGL 4.3, or `ARB_program_interface_query`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProgramReflection {
+    /// The active uniforms, including those in named uniform blocks
+    pub uniforms: Vec<ReflectedVariable>,
+
+    /// The active uniform blocks
+    pub uniform_blocks: Vec<ReflectedBlock>,
+
+    /// The active inputs of the first shader stage, which are the vertex attributes of a program
+    /// with a vertex shader
+    pub attributes: Vec<ReflectedVariable>,
+
+    /// The active outputs of the last shader stage, which are the fragment outputs of a program
+    /// with a fragment shader
+    pub outputs: Vec<ReflectedVariable>,
+
+    /// The active shader storage blocks
+    pub shader_storage_blocks: Vec<ReflectedBlock>,
+
+    /// The active buffer variables of the shader storage blocks
+    pub buffer_variables: Vec<ReflectedVariable>,
+}
+
+impl ProgramReflection {
+    /// Reflect every active resource of the successfully linked `program`
+    pub fn new(program: Program) -> ProgramReflection {
+        use ProgramInterface as I;
+        use ProgramResourceProperty as P;
+
+        let variable = [P::Type, P::ArraySize, P::Location, P::BlockIndex, P::Offset];
+        let buffer_variable = [P::Type, P::ArraySize, P::BlockIndex, P::Offset];
+        let io_variable = [P::Type, P::ArraySize, P::Location];
+
+        ProgramReflection {
+            uniforms: reflect_variables(program, I::Uniform, &variable),
+            uniform_blocks: reflect_blocks(program, I::UniformBlock),
+            attributes: reflect_variables(program, I::ProgramInput, &io_variable),
+            outputs: reflect_variables(program, I::ProgramOutput, &io_variable),
+            shader_storage_blocks: reflect_blocks(program, I::ShaderStorageBlock),
+            buffer_variables: reflect_variables(program, I::BufferVariable, &buffer_variable),
+        }
+    }
+
+    /// # Error mapped new
+    /// see [ProgramReflection::new]
+    pub fn new_checked(program: Program) -> Result<ProgramReflection, ShaderError> {
+        if !get_program_link_status_checked(program)? {
+            return Err(ShaderError::ProgramNotLinked(program));
+        }
+        let reflection = ProgramReflection::new(program);
+        match get_error() {
+            Error::NoError => Ok(reflection),
+            error => Err(ShaderError::Unexpected(error)),
+        }
+    }
+
+    /// The active uniform called `name`, which for arrays ends in "\[0\]"
+    pub fn uniform(&self, name: &str) -> Option<&ReflectedVariable> {
+        self.uniforms.iter().find(|uniform| uniform.name == name)
+    }
+
+    /// The location of the active uniform called `name` in the default uniform block
+    pub fn uniform_location(&self, name: &str) -> Option<UniformLocation> {
+        let location = self.uniform(name)?.location?;
+        Some(UniformLocation(location as i32))
+    }
+
+    /// The active uniform block called `name`
+    pub fn uniform_block(&self, name: &str) -> Option<&ReflectedBlock> {
+        self.uniform_blocks.iter().find(|block| block.name == name)
+    }
+
+    /// The active attribute called `name`
+    pub fn attribute(&self, name: &str) -> Option<&ReflectedVariable> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+    }
+
+    /// The active output called `name`
+    pub fn output(&self, name: &str) -> Option<&ReflectedVariable> {
+        self.outputs.iter().find(|output| output.name == name)
+    }
+
+    /// The active shader storage block called `name`
+    pub fn shader_storage_block(&self, name: &str) -> Option<&ReflectedBlock> {
+        self.shader_storage_blocks
+            .iter()
+            .find(|block| block.name == name)
+    }
+}
+
+/// The name of resource `index` of `interface`, `name_length` includes the null terminator
+pub(crate) fn reflect_name(
+    program: Program,
+    interface: ProgramInterface,
+    index: u32,
+    name_length: i32,
+) -> String {
+    let mut buffer = vec![0; name_length.max(1) as usize];
+    let name = get_program_resource_name(program, interface, index, &mut buffer);
+    String::from_utf8_lossy(name).into_owned()
+}
+
+/// Reflect each variable of `interface`, where `properties` starts with type and array size,
+/// followed by any of location, block index and offset
+fn reflect_variables(
+    program: Program,
+    interface: ProgramInterface,
+    properties: &[ProgramResourceProperty],
+) -> Vec<ReflectedVariable> {
+    let count = get_program_interface(
+        program,
+        interface,
+        ProgramInterfaceParameter::ActiveResources,
+    );
+
+    let mut props = vec![ProgramResourceProperty::NameLength];
+    props.extend_from_slice(properties);
+
+    (0..count)
+        .map(|index| {
+            let mut params = [-1; 6];
+            let written =
+                get_program_resource(program, interface, index, &props, &mut params).len();
+            let value = |property| {
+                let position = props.iter().position(|prop| *prop == property)?;
+                let value = *params[..written].get(position)?;
+                u32::try_from(value).ok()
+            };
+
+            ReflectedVariable {
+                name: reflect_name(program, interface, index, params[0]),
+                ty: GlslType::from(params[1] as u32),
+                array_size: value(ProgramResourceProperty::ArraySize).unwrap_or(1),
+                location: value(ProgramResourceProperty::Location),
+                block_index: value(ProgramResourceProperty::BlockIndex),
+                offset: value(ProgramResourceProperty::Offset),
+            }
+        })
+        .collect()
+}
+
+/// Reflect each block of `interface`, and the indices of its active variables
+fn reflect_blocks(program: Program, interface: ProgramInterface) -> Vec<ReflectedBlock> {
+    use ProgramResourceProperty as P;
+
+    let count = get_program_interface(
+        program,
+        interface,
+        ProgramInterfaceParameter::ActiveResources,
+    );
+
+    (0..count)
+        .map(|index| {
+            let props = [
+                P::NameLength,
+                P::BufferBinding,
+                P::BufferDataSize,
+                P::NumActiveVariables,
+            ];
+            let mut params = [0; 4];
+            get_program_resource(program, interface, index, &props, &mut params);
+            let [name_length, binding, data_size, num_variables] = params;
+
+            let mut variables = vec![0; num_variables.max(0) as usize];
+            let written = get_program_resource(
+                program,
+                interface,
+                index,
+                &[P::ActiveVariables],
+                &mut variables,
+            )
+            .len();
+            variables.truncate(written);
+
+            ReflectedBlock {
+                name: reflect_name(program, interface, index, name_length),
+                binding: binding as u32,
+                data_size: data_size as u32,
+                variables: variables.into_iter().map(|index| index as u32).collect(),
+            }
+        })
+        .collect()
+}
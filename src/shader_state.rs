@@ -7,6 +7,55 @@
 use crate::prelude::*;
 use gl::types::*;
 
+/// # Errors of the type-checked uniform setters
+/// see [set_uniform_checked]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum UniformError {
+    Unexpected(Error),
+
+    /// There is no current program object
+    NoProgram,
+
+    /// Not the location of an active uniform variable in the current program
+    InvalidLocation(UniformLocation),
+
+    /// The type of the value does not match the type of the uniform variable
+    TypeMismatch {
+        expected: GlslType,
+        found: GlslType,
+    },
+
+    /// More than one value was given for a uniform variable that is not an array
+    NotAnArray(UniformLocation),
+}
+
+impl std::fmt::Display for UniformError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UniformError::Unexpected(error) => write!(f, "unexpected uniform error: {error}"),
+            UniformError::NoProgram => write!(f, "there is no current program object"),
+            UniformError::InvalidLocation(location) => write!(
+                f,
+                "{} is not the location of an active uniform variable",
+                location.0
+            ),
+            UniformError::TypeMismatch { expected, found } => {
+                write!(
+                    f,
+                    "a {found:?} value was given for a {expected:?} uniform variable"
+                )
+            }
+            UniformError::NotAnArray(location) => write!(
+                f,
+                "multiple values were given for the uniform variable at {}, which is not an array",
+                location.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UniformError {}
+
 /// # Specify the value of a uniform variable for the current program object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glUniform.xhtml>
 ///
@@ -179,6 +228,7 @@ use gl::types::*;
 ///
 /// # See Also
 /// * [link_program]
+/// * [set_uniform]
 /// * [use_program]
 pub mod uniform {
     use crate::prelude::*;
@@ -632,6 +682,352 @@ pub mod uniform {
 }
 pub use uniform::*;
 
+/// # A value that can be loaded into a uniform variable
+/// see [set_uniform]
+///
+/// Implemented for the Rust equivalent of every GLSL scalar, vector and matrix type, and slices of
+/// them for uniform arrays:
+///
+/// | Rust | GLSL |
+/// |------|------|
+/// | `f32`, `i32`, `u32`, `f64`, `bool` | `float`, `int`, `uint`, `double`, `bool` |
+/// | `[f32; N]`, `[i32; N]`, `[u32; N]`, `[f64; N]`, `[bool; N]` | `vecN`, `ivecN`, `uvecN`, `dvecN`, `bvecN` |
+/// | `[[f32; R]; C]`, `[[f64; R]; C]` | `matCxR`, `dmatCxR`, given as `C` columns of `R` rows |
+/// | `[T]`, `Vec<T>` | `T[]` |
+///
+/// Samplers and images are set with the `i32` index of their texture or image unit.
+pub trait UniformValue {
+    /// The GLSL type of the value, or of each element of a slice
+    fn glsl_type(&self) -> GlslType;
+
+    /// The number of uniform array elements the value loads, which is 1 for a single value
+    fn count(&self) -> usize;
+
+    /// Load the value into `location` of the current program object
+    fn set(&self, location: UniformLocation);
+}
+
+macro_rules! uniform_values {
+    ($($ty:ty => $glsl:expr, $call:path $(, $transpose:expr)?;)*) => {
+        $(
+            impl UniformValue for $ty {
+                fn glsl_type(&self) -> GlslType {
+                    $glsl
+                }
+
+                fn count(&self) -> usize {
+                    1
+                }
+
+                fn set(&self, location: UniformLocation) {
+                    std::slice::from_ref(self).set(location)
+                }
+            }
+
+            impl UniformValue for [$ty] {
+                fn glsl_type(&self) -> GlslType {
+                    $glsl
+                }
+
+                fn count(&self) -> usize {
+                    self.len()
+                }
+
+                fn set(&self, location: UniformLocation) {
+                    let location = location.0;
+                    let count = self.len() as GLsizei;
+                    let value = self.as_ptr() as *const _;
+
+                    // SAFE: synchronous read of `count` elements, no memory retained
+                    unsafe { $call(location, count, $($transpose,)? value) }
+                }
+            }
+        )*
+    };
+}
+
+uniform_values! {
+    f32 => GlslType::Scalar(GlslScalarType::Float), gl::Uniform1fv;
+    [f32; 2] => GlslType::Vector(GlslScalarType::Float, 2), gl::Uniform2fv;
+    [f32; 3] => GlslType::Vector(GlslScalarType::Float, 3), gl::Uniform3fv;
+    [f32; 4] => GlslType::Vector(GlslScalarType::Float, 4), gl::Uniform4fv;
+    i32 => GlslType::Scalar(GlslScalarType::Int), gl::Uniform1iv;
+    [i32; 2] => GlslType::Vector(GlslScalarType::Int, 2), gl::Uniform2iv;
+    [i32; 3] => GlslType::Vector(GlslScalarType::Int, 3), gl::Uniform3iv;
+    [i32; 4] => GlslType::Vector(GlslScalarType::Int, 4), gl::Uniform4iv;
+    u32 => GlslType::Scalar(GlslScalarType::UnsignedInt), gl::Uniform1uiv;
+    [u32; 2] => GlslType::Vector(GlslScalarType::UnsignedInt, 2), gl::Uniform2uiv;
+    [u32; 3] => GlslType::Vector(GlslScalarType::UnsignedInt, 3), gl::Uniform3uiv;
+    [u32; 4] => GlslType::Vector(GlslScalarType::UnsignedInt, 4), gl::Uniform4uiv;
+    f64 => GlslType::Scalar(GlslScalarType::Double), gl::Uniform1dv;
+    [f64; 2] => GlslType::Vector(GlslScalarType::Double, 2), gl::Uniform2dv;
+    [f64; 3] => GlslType::Vector(GlslScalarType::Double, 3), gl::Uniform3dv;
+    [f64; 4] => GlslType::Vector(GlslScalarType::Double, 4), gl::Uniform4dv;
+}
+
+// matrices are given as columns, so are never transposed
+uniform_values! {
+    [[f32; 2]; 2] => GlslType::Matrix { ty: GlslScalarType::Float, columns: 2, rows: 2 },
+        gl::UniformMatrix2fv, gl::FALSE;
+    [[f32; 3]; 2] => GlslType::Matrix { ty: GlslScalarType::Float, columns: 2, rows: 3 },
+        gl::UniformMatrix2x3fv, gl::FALSE;
+    [[f32; 4]; 2] => GlslType::Matrix { ty: GlslScalarType::Float, columns: 2, rows: 4 },
+        gl::UniformMatrix2x4fv, gl::FALSE;
+    [[f32; 2]; 3] => GlslType::Matrix { ty: GlslScalarType::Float, columns: 3, rows: 2 },
+        gl::UniformMatrix3x2fv, gl::FALSE;
+    [[f32; 3]; 3] => GlslType::Matrix { ty: GlslScalarType::Float, columns: 3, rows: 3 },
+        gl::UniformMatrix3fv, gl::FALSE;
+    [[f32; 4]; 3] => GlslType::Matrix { ty: GlslScalarType::Float, columns: 3, rows: 4 },
+        gl::UniformMatrix3x4fv, gl::FALSE;
+    [[f32; 2]; 4] => GlslType::Matrix { ty: GlslScalarType::Float, columns: 4, rows: 2 },
+        gl::UniformMatrix4x2fv, gl::FALSE;
+    [[f32; 3]; 4] => GlslType::Matrix { ty: GlslScalarType::Float, columns: 4, rows: 3 },
+        gl::UniformMatrix4x3fv, gl::FALSE;
+    [[f32; 4]; 4] => GlslType::Matrix { ty: GlslScalarType::Float, columns: 4, rows: 4 },
+        gl::UniformMatrix4fv, gl::FALSE;
+    [[f64; 2]; 2] => GlslType::Matrix { ty: GlslScalarType::Double, columns: 2, rows: 2 },
+        gl::UniformMatrix2dv, gl::FALSE;
+    [[f64; 3]; 2] => GlslType::Matrix { ty: GlslScalarType::Double, columns: 2, rows: 3 },
+        gl::UniformMatrix2x3dv, gl::FALSE;
+    [[f64; 4]; 2] => GlslType::Matrix { ty: GlslScalarType::Double, columns: 2, rows: 4 },
+        gl::UniformMatrix2x4dv, gl::FALSE;
+    [[f64; 2]; 3] => GlslType::Matrix { ty: GlslScalarType::Double, columns: 3, rows: 2 },
+        gl::UniformMatrix3x2dv, gl::FALSE;
+    [[f64; 3]; 3] => GlslType::Matrix { ty: GlslScalarType::Double, columns: 3, rows: 3 },
+        gl::UniformMatrix3dv, gl::FALSE;
+    [[f64; 4]; 3] => GlslType::Matrix { ty: GlslScalarType::Double, columns: 3, rows: 4 },
+        gl::UniformMatrix3x4dv, gl::FALSE;
+    [[f64; 2]; 4] => GlslType::Matrix { ty: GlslScalarType::Double, columns: 4, rows: 2 },
+        gl::UniformMatrix4x2dv, gl::FALSE;
+    [[f64; 3]; 4] => GlslType::Matrix { ty: GlslScalarType::Double, columns: 4, rows: 3 },
+        gl::UniformMatrix4x3dv, gl::FALSE;
+    [[f64; 4]; 4] => GlslType::Matrix { ty: GlslScalarType::Double, columns: 4, rows: 4 },
+        gl::UniformMatrix4dv, gl::FALSE;
+}
+
+macro_rules! bool_uniform_values {
+    ($($ty:ty => $glsl:expr, $call:path, |$value:ident| $ints:expr;)*) => {
+        $(
+            impl UniformValue for $ty {
+                fn glsl_type(&self) -> GlslType {
+                    $glsl
+                }
+
+                fn count(&self) -> usize {
+                    1
+                }
+
+                fn set(&self, location: UniformLocation) {
+                    std::slice::from_ref(self).set(location)
+                }
+            }
+
+            impl UniformValue for [$ty] {
+                fn glsl_type(&self) -> GlslType {
+                    $glsl
+                }
+
+                fn count(&self) -> usize {
+                    self.len()
+                }
+
+                fn set(&self, location: UniformLocation) {
+                    // booleans are loaded as integers, where 0 is false and 1 is true
+                    let ints: Vec<i32> = self.iter().flat_map(|$value| $ints).collect();
+                    let location = location.0;
+                    let count = self.len() as GLsizei;
+                    let value = ints.as_ptr();
+
+                    // SAFE: synchronous read of `count` elements, no memory retained
+                    unsafe { $call(location, count, value) }
+                }
+            }
+        )*
+    };
+}
+
+bool_uniform_values! {
+    bool => GlslType::Scalar(GlslScalarType::Bool), gl::Uniform1iv, |v| [i32::from(*v)];
+    [bool; 2] => GlslType::Vector(GlslScalarType::Bool, 2), gl::Uniform2iv, |v| v.map(i32::from);
+    [bool; 3] => GlslType::Vector(GlslScalarType::Bool, 3), gl::Uniform3iv, |v| v.map(i32::from);
+    [bool; 4] => GlslType::Vector(GlslScalarType::Bool, 4), gl::Uniform4iv, |v| v.map(i32::from);
+}
+
+impl<T> UniformValue for Vec<T>
+where
+    [T]: UniformValue,
+{
+    fn glsl_type(&self) -> GlslType {
+        self.as_slice().glsl_type()
+    }
+
+    fn count(&self) -> usize {
+        self.as_slice().count()
+    }
+
+    fn set(&self, location: UniformLocation) {
+        self.as_slice().set(location)
+    }
+}
+
+/// # Specify the value of a uniform variable for the current program object
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glUniform.xhtml>
+///
+/// # Arguments
+/// * `location` - Specifies the location of the uniform variable to be modified.
+/// * `value` - Specifies the new value, or slice of values for a uniform array, see [UniformValue].
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let program = Program(42);
+/// use_program(program);
+///
+/// set_uniform(get_uniform_location(program, c"colour"), &[1.0, 0.5, 0.0, 1.0]);
+/// set_uniform(get_uniform_location(program, c"image"), &0);
+/// set_uniform(get_uniform_location(program, c"weights"), &[0.25, 0.5, 0.25][..]);
+///
+/// let identity = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+/// set_uniform_checked(get_uniform_location(program, c"normal_matrix"), &identity).unwrap();
+/// ```
+///
+/// # Description
+/// [set_uniform] modifies the value of a uniform variable or a uniform variable array of the
+/// program object that was made part of current state by calling [use_program], calling the
+/// `glUniform*` or `glUniformMatrix*` function matching the type of `value`. The location of the
+/// uniform variable to be modified is specified by `location`, which should be a value returned by
+/// [get_uniform_location].
+///
+/// A single value loads a single uniform variable, and a slice of values loads consecutive elements
+/// of a uniform array, starting at `location`. Matrices are given as arrays of columns, so are
+/// loaded without being transposed.
+///
+/// The type of `value` must match the type of the uniform variable, except that `bool` variables
+/// may be loaded with `f32`, `i32` or `u32` values of the same size, and samplers and images are
+/// loaded with an `i32`. [set_uniform_checked] compares the type of `value` with the type reported
+/// by [get_active_uniform], and returns [UniformError::TypeMismatch] before calling the GL, instead
+/// of the GL silently generating [Error::InvalidOperation].
+/// The active uniforms of the current program are looked up on every call, as a program may have
+/// been relinked, or its name reused, since the last one, so the checks cost a query per active
+/// uniform.
+///
+/// If `location` is equal to -1, the data passed in will be silently ignored and the specified
+/// uniform variable will not be changed.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if there is no current program object.
+/// * [Error::InvalidOperation] - if the type of `value` does not match the type of the uniform
+/// variable declared in the shader.
+/// * [Error::InvalidOperation] - if `value` is a slice of more than one element, and the uniform
+/// variable is not an array.
+/// * [Error::InvalidOperation] - if `location` is an invalid uniform location for the current
+/// program object and `location` is not equal to -1.
+///
+/// # Associated Gets
+/// * [get_current_program]
+/// * [get_active_uniform]
+/// * [get_uniform_location]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [set_uniform] with `f32`, `i32` and `bool` | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [set_uniform] with non-square matrices | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [set_uniform] with `u32` | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [set_uniform] with `f64` | N | N | N | N | N | N | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [link_program]
+/// * [uniform]
+/// * [use_program]
+pub fn set_uniform<T: UniformValue + ?Sized>(location: UniformLocation, value: &T) {
+    value.set(location)
+}
+
+/// # Error mapped set uniform
+/// see [set_uniform]
+pub fn set_uniform_checked<T: UniformValue + ?Sized>(
+    location: UniformLocation,
+    value: &T,
+) -> Result<(), UniformError> {
+    if location.0 != -1 {
+        let program = get_current_program();
+        if program.0 == 0 {
+            return Err(UniformError::NoProgram);
+        }
+        let Some((expected, size)) = active_uniform_at(program, location) else {
+            return Err(UniformError::InvalidLocation(location));
+        };
+        let found = value.glsl_type();
+        if !accepts(expected, found) {
+            return Err(UniformError::TypeMismatch { expected, found });
+        }
+        if size == 1 && value.count() > 1 {
+            return Err(UniformError::NotAnArray(location));
+        }
+    }
+
+    set_uniform(location, value);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(UniformError::Unexpected(error)),
+    }
+}
+
+/// The type and array size of the active uniform of `program` that `location` refers to, which may
+/// be any element of a uniform array
+fn active_uniform_at(program: Program, location: UniformLocation) -> Option<(GlslType, u32)> {
+    let mut buffer = vec![0; get_program_active_uniform_max_length(program) as usize];
+    for index in 0..get_program_active_uniforms(program) {
+        let uniform = get_active_uniform(program, index, &mut buffer);
+        let (ty, size) = (uniform.ty, uniform.size);
+
+        // array names end in "[0]", but any element may be loaded
+        let name = uniform
+            .name
+            .strip_suffix(b"[0]")
+            .unwrap_or(uniform.name)
+            .to_vec();
+        let is_array = size > 1 || uniform.name.ends_with(b"[0]");
+        for element in 0..size {
+            let mut element_name = name.clone();
+            if is_array {
+                element_name.extend_from_slice(format!("[{element}]").as_bytes());
+            }
+            let Ok(element_name) = std::ffi::CString::new(element_name) else {
+                continue;
+            };
+            if get_uniform_location(program, &element_name) == location {
+                return Some((ty, size));
+            }
+        }
+    }
+    None
+}
+
+/// Whether a uniform variable of type `uniform` can be loaded with a value of type `value`
+fn accepts(uniform: GlslType, value: GlslType) -> bool {
+    match (uniform, value) {
+        (GlslType::Scalar(GlslScalarType::Bool), GlslType::Scalar(ty))
+        | (GlslType::Vector(GlslScalarType::Bool, _), GlslType::Vector(ty, _)) => {
+            ty != GlslScalarType::Double && uniform_size(uniform) == uniform_size(value)
+        }
+        (GlslType::Sampler { .. } | GlslType::Image { .. }, value) => {
+            value == GlslType::Scalar(GlslScalarType::Int)
+        }
+        (uniform, value) => uniform == value,
+    }
+}
+
+/// The number of components of a scalar or vector type
+fn uniform_size(ty: GlslType) -> u8 {
+    match ty {
+        GlslType::Vector(_, size) => size,
+        _ => 1,
+    }
+}
+
 /// # Installs a program object as part of current rendering state
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glUseProgram.xhtml>
 ///
@@ -716,3 +1112,201 @@ pub fn use_program(program: Program) {
     let program = program.0;
     unsafe { gl::UseProgram(program) }
 }
+
+/// # Error mapped use program
+/// see [use_program]
+pub fn use_program_checked(program: Program) -> Result<(), ShaderError> {
+    use_program(program);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation if is_program(program) && !get_program_link_status(program) => {
+            Err(ShaderError::ProgramNotLinked(program))
+        }
+        Error::InvalidOperation if is_program(program) => {
+            Err(ShaderError::TransformFeedbackActive(program))
+        }
+        error => Err(program_error(program, error)),
+    }
+}
+
+/// Decode the error of a block binding command, where [Error::InvalidValue] is generated by an
+/// invalid program, block index or binding point
+fn binding_error(
+    program: Program,
+    block_index: u32,
+    binding: u32,
+    max_bindings: GLenum,
+    error: Error,
+) -> ShaderError {
+    match error {
+        Error::InvalidValue if !is_program(program) => program_error(program, error),
+        Error::InvalidValue if binding >= get_i32(max_bindings) as u32 => {
+            ShaderError::InvalidBinding(binding)
+        }
+        Error::InvalidValue => ShaderError::InvalidIndex(block_index),
+        error => program_error(program, error),
+    }
+}
+
+/// # Assign a binding point to an active uniform block
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glUniformBlockBinding.xhtml>
+///
+/// # Arguments
+/// * `program` - The name of a program object containing the active uniform block whose binding to
+/// assign.
+/// * `block_index` - The index of the active uniform block within `program` whose binding to
+/// assign.
+/// * `binding` - Specifies the binding point to which to bind the uniform block with index
+/// `block_index` within `program`.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let program = Program(42);
+/// if let Some(index) = get_uniform_block_index(program, c"Material") {
+///     uniform_block_binding(program, index, 2);
+///     bind_buffer_base(BufferBindingRangeTarget::Uniform, 2, Buffer(7));
+/// }
+/// ```
+///
+/// # Description
+/// Binding points for active uniform blocks are assigned using [uniform_block_binding]. Each of a
+/// program's active uniform blocks has a corresponding uniform buffer binding point. `program` is
+/// the name of a program object for which the command [link_program] has been issued in the past.
+///
+/// If successful, [uniform_block_binding] specifies that `program` will use the data store of the
+/// buffer object bound to the binding point `binding` to extract the values of the uniforms in the
+/// uniform block identified by `block_index`.
+///
+/// When a program object is linked or re-linked, the uniform buffer object binding point assigned
+/// to each of its active uniform blocks is reset to zero, unless set with a `binding` layout
+/// qualifier in the shader.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `block_index` is not an active uniform block index of `program`.
+/// * [Error::InvalidValue] - if `binding` is greater than or equal to the value of
+/// [gl::MAX_UNIFORM_BUFFER_BINDINGS].
+///
+/// # Associated Gets
+/// * [get_active_uniform_block_binding]
+/// * [get_uniform_block_index]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [uniform_block_binding] | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_buffer_base]
+/// * [bind_buffer_range]
+/// * [link_program]
+/// * [shader_storage_block_binding]
+pub fn uniform_block_binding(program: Program, block_index: u32, binding: u32) {
+    let program = program.0;
+    unsafe { gl::UniformBlockBinding(program, block_index, binding) }
+}
+
+/// # Error mapped uniform block binding
+/// see [uniform_block_binding]
+pub fn uniform_block_binding_checked(
+    program: Program,
+    block_index: u32,
+    binding: u32,
+) -> Result<(), ShaderError> {
+    uniform_block_binding(program, block_index, binding);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => {
+            let max_bindings = gl::MAX_UNIFORM_BUFFER_BINDINGS;
+            Err(binding_error(
+                program,
+                block_index,
+                binding,
+                max_bindings,
+                error,
+            ))
+        }
+    }
+}
+
+/// # Change an active shader storage block binding
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glShaderStorageBlockBinding.xhtml>
+///
+/// # Arguments
+/// * `program` - The name of the program containing the block whose binding to change.
+/// * `block_index` - The index of the storage block within the program.
+/// * `binding` - The index of the storage block binding to associate with the specified storage
+/// block.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let program = Program(42);
+/// let interface = ProgramInterface::ShaderStorageBlock;
+/// if let Some(index) = get_program_resource_index(program, interface, c"Lights") {
+///     shader_storage_block_binding(program, index, 1);
+///     bind_buffer_base(BufferBindingRangeTarget::ShaderStorage, 1, Buffer(7));
+/// }
+/// ```
+///
+/// # Description
+/// [shader_storage_block_binding], changes the active shader storage block with an assigned index
+/// of `block_index` in program object `program`. `block_index` must be an active shader storage
+/// block index in `program`. `binding` must be less than the value of
+/// [gl::MAX_SHADER_STORAGE_BUFFER_BINDINGS]. If successful, [shader_storage_block_binding]
+/// specifies that `program` will use the data store of the buffer object bound to the binding point
+/// `binding` to read and write the values of the buffer variables in the shader storage block
+/// identified by `block_index`.
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `program` is not a value generated by OpenGL.
+/// * [Error::InvalidOperation] - if `program` is not a program object.
+/// * [Error::InvalidValue] - if `block_index` is not an active shader storage block index in
+/// `program`.
+/// * [Error::InvalidValue] - if `binding` is not less than the value of
+/// [gl::MAX_SHADER_STORAGE_BUFFER_BINDINGS].
+///
+/// # Associated Gets
+/// * [get_program_resource] with [ProgramResourceProperty::BufferBinding]
+/// * [get_program_resource_index] with [ProgramInterface::ShaderStorageBlock]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [shader_storage_block_binding] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_buffer_base]
+/// * [bind_buffer_range]
+/// * [uniform_block_binding]
+pub fn shader_storage_block_binding(program: Program, block_index: u32, binding: u32) {
+    let program = program.0;
+    unsafe { gl::ShaderStorageBlockBinding(program, block_index, binding) }
+}
+
+/// # Error mapped shader storage block binding
+/// see [shader_storage_block_binding]
+pub fn shader_storage_block_binding_checked(
+    program: Program,
+    block_index: u32,
+    binding: u32,
+) -> Result<(), ShaderError> {
+    shader_storage_block_binding(program, block_index, binding);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => {
+            let max_bindings = gl::MAX_SHADER_STORAGE_BUFFER_BINDINGS;
+            Err(binding_error(
+                program,
+                block_index,
+                binding,
+                max_bindings,
+                error,
+            ))
+        }
+    }
+}
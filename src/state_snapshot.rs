@@ -0,0 +1,751 @@
+//! # State Snapshot
+//! <https://www.khronos.org/opengl/wiki/OpenGL_Context#Context_state>
+//!
+//! # Description
+//! Saving and restoring the context state, around code that does not leave it as it was found
+//! (e.g. a third-party UI library). [GlStateSnapshot::capture] queries every piece of state that
+//! can be set through rgl, [GlStateSnapshot::restore] sets all of it again, and
+//! [GlStateSnapshot::diff] lists what differs between two snapshots:
+//! ```no_run
+//! use rgl::prelude::*;
+//!
+//! let snapshot = GlStateSnapshot::capture();
+//! // ... hand the context to third-party code ...
+//! for difference in snapshot.diff(&GlStateSnapshot::capture()) {
+//!     println!("{difference}");
+//! }
+//! snapshot.restore();
+//! ```
+//!
+//! A snapshot covers:
+//! * every [Capability], along with the per draw buffer blending and per viewport scissor test
+//! * blending, colour write masks, depth and stencil functions and masks, and clear values
+//! * viewports, scissor boxes and depth ranges
+//! * face culling, polygon mode and offset, line width, point size and parameters, and
+//!   provoking vertex
+//! * sample coverage, sample mask and min sample shading
+//! * the current program, vertex array, buffers of every [BufferBindingTarget] and binding point
+//!   of every [BufferBindingRangeTarget], textures of every [TextureBindingTarget] and sampler of
+//!   every texture unit, framebuffers and renderbuffer
+//!
+//! Objects are captured by name only, neither their contents nor their parameters are saved.
+//! Capturing requires an OpenGL 4.4 context, as it queries the [get_query_buffer_binding].
+
+use crate::prelude::*;
+
+const CAPABILITIES: [Capability; 27] = [
+    Capability::Blend,
+    Capability::ColourLogicOp,
+    Capability::CullFace,
+    Capability::DebugOutput,
+    Capability::DebugOutputSynchronous,
+    Capability::DepthClamp,
+    Capability::DepthTest,
+    Capability::Dither,
+    Capability::FramebufferSRGB,
+    Capability::LineSmooth,
+    Capability::Multisample,
+    Capability::PolygonOffsetFill,
+    Capability::PolygonOffsetLine,
+    Capability::PolygonOffsetPoint,
+    Capability::PolygonSmooth,
+    Capability::PrimitiveRestart,
+    Capability::PrimitiveRestartFixedIndex,
+    Capability::RasterizerDiscard,
+    Capability::SampleAlphaToCoverage,
+    Capability::SampleAlphaToOne,
+    Capability::SampleCoverage,
+    Capability::SampleShading,
+    Capability::SampleMask,
+    Capability::ScissorTest,
+    Capability::StencilTest,
+    Capability::TextureCubeMapSeamless,
+    Capability::ProgramPointSize,
+];
+
+const BUFFER_TARGETS: [BufferBindingTarget; 14] = [
+    BufferBindingTarget::Array,
+    BufferBindingTarget::AtomicCounter,
+    BufferBindingTarget::CopyRead,
+    BufferBindingTarget::CopyWrite,
+    BufferBindingTarget::DispatchIndirect,
+    BufferBindingTarget::DrawIndirect,
+    BufferBindingTarget::ElementArray,
+    BufferBindingTarget::PixelPack,
+    BufferBindingTarget::PixelUnpack,
+    BufferBindingTarget::Query,
+    BufferBindingTarget::ShaderStorage,
+    BufferBindingTarget::Texture,
+    BufferBindingTarget::TransformFeedback,
+    BufferBindingTarget::Uniform,
+];
+
+const BUFFER_RANGE_TARGETS: [BufferBindingRangeTarget; 4] = [
+    BufferBindingRangeTarget::AtomicCounter,
+    BufferBindingRangeTarget::ShaderStorage,
+    BufferBindingRangeTarget::TransformFeedback,
+    BufferBindingRangeTarget::Uniform,
+];
+
+const TEXTURE_TARGETS: [TextureBindingTarget; 11] = [
+    TextureBindingTarget::Image1D,
+    TextureBindingTarget::Image2D,
+    TextureBindingTarget::Image3D,
+    TextureBindingTarget::Array1D,
+    TextureBindingTarget::Array2D,
+    TextureBindingTarget::Rectangle,
+    TextureBindingTarget::CubeMap,
+    TextureBindingTarget::CubeMapArray,
+    TextureBindingTarget::Buffer,
+    TextureBindingTarget::Multisample2D,
+    TextureBindingTarget::Multisample2DArray,
+];
+
+fn buffer_binding(target: BufferBindingTarget) -> Buffer {
+    match target {
+        BufferBindingTarget::Array => get_array_buffer_binding(),
+        BufferBindingTarget::AtomicCounter => get_atomic_counter_buffer_binding(),
+        BufferBindingTarget::CopyRead => get_copy_read_buffer_binding(),
+        BufferBindingTarget::CopyWrite => get_copy_write_buffer_binding(),
+        BufferBindingTarget::DispatchIndirect => get_dispatch_indirect_buffer_binding(),
+        BufferBindingTarget::DrawIndirect => get_draw_indirect_buffer_binding(),
+        BufferBindingTarget::ElementArray => get_element_array_buffer_binding(),
+        BufferBindingTarget::PixelPack => get_pixel_pack_buffer_binding(),
+        BufferBindingTarget::PixelUnpack => get_pixel_unpack_buffer_binding(),
+        BufferBindingTarget::Query => get_query_buffer_binding(),
+        BufferBindingTarget::ShaderStorage => get_shader_storage_buffer_binding(),
+        BufferBindingTarget::Texture => get_texture_buffer_binding(),
+        BufferBindingTarget::TransformFeedback => get_transform_feedback_buffer_binding(),
+        BufferBindingTarget::Uniform => get_uniform_buffer_binding(),
+    }
+}
+
+fn max_buffer_bindings(target: BufferBindingRangeTarget) -> u32 {
+    match target {
+        BufferBindingRangeTarget::AtomicCounter => get_max_atomic_counter_buffer_bindings(),
+        BufferBindingRangeTarget::ShaderStorage => get_max_shader_storage_buffer_bindings(),
+        BufferBindingRangeTarget::TransformFeedback => get_max_transform_feedback_buffers(),
+        BufferBindingRangeTarget::Uniform => get_max_uniform_buffer_bindings(),
+    }
+}
+
+fn texture_binding(target: TextureBindingTarget) -> Texture {
+    match target {
+        TextureBindingTarget::Image1D => get_texture_binding_1d(),
+        TextureBindingTarget::Image2D => get_texture_binding_2d(),
+        TextureBindingTarget::Image3D => get_texture_binding_3d(),
+        TextureBindingTarget::Array1D => get_texture_binding_1d_array(),
+        TextureBindingTarget::Array2D => get_texture_binding_2d_array(),
+        TextureBindingTarget::Rectangle => get_texture_binding_rectangle(),
+        TextureBindingTarget::CubeMap => get_texture_binding_cube_map(),
+        TextureBindingTarget::CubeMapArray => get_texture_binding_cube_map_array(),
+        TextureBindingTarget::Buffer => get_texture_binding_buffer(),
+        TextureBindingTarget::Multisample2D => get_texture_binding_multisample_2d(),
+        TextureBindingTarget::Multisample2DArray => get_texture_binding_multisample_2d_array(),
+    }
+}
+
+/// # Blending and colour write mask of a single draw buffer
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DrawBufferState {
+    pub blend: bool,
+    pub equation_rgb: Option<BlendEquation>,
+    pub equation_alpha: Option<BlendEquation>,
+    pub source_rgb: Option<BlendFactor>,
+    pub dest_rgb: Option<BlendFactor>,
+    pub source_alpha: Option<BlendFactor>,
+    pub dest_alpha: Option<BlendFactor>,
+    pub colour_writemask: [bool; 4],
+}
+
+impl DrawBufferState {
+    fn capture(buffer_index: u32) -> Self {
+        DrawBufferState {
+            blend: is_enabled_indexed(Capability::Blend, buffer_index),
+            equation_rgb: get_blend_equation_rgb_buffer(buffer_index),
+            equation_alpha: get_blend_equation_alpha_buffer(buffer_index),
+            source_rgb: get_blend_source_rgb_buffer(buffer_index),
+            dest_rgb: get_blend_dest_rgb_buffer(buffer_index),
+            source_alpha: get_blend_source_alpha_buffer(buffer_index),
+            dest_alpha: get_blend_dest_alpha_buffer(buffer_index),
+            colour_writemask: get_colour_writemask_buffer(buffer_index),
+        }
+    }
+
+    fn restore(&self, buffer_index: u32) {
+        if self.blend {
+            enable_indexed(Capability::Blend, buffer_index);
+        } else {
+            disable_indexed(Capability::Blend, buffer_index);
+        }
+        if let (Some(rgb), Some(alpha)) = (self.equation_rgb, self.equation_alpha) {
+            blend_equation_separate_buffer(buffer_index, rgb, alpha);
+        }
+        if let (Some(source_rgb), Some(dest_rgb), Some(source_alpha), Some(dest_alpha)) = (
+            self.source_rgb,
+            self.dest_rgb,
+            self.source_alpha,
+            self.dest_alpha,
+        ) {
+            blend_func_separate_buffer(
+                buffer_index,
+                source_rgb,
+                dest_rgb,
+                source_alpha,
+                dest_alpha,
+            );
+        }
+        let [red, green, blue, alpha] = self.colour_writemask;
+        colour_mask_buffer(buffer_index, red, green, blue, alpha);
+    }
+
+    fn diff(&self, other: &Self, buffer_index: u32, differences: &mut Differences) {
+        let mut compare = |state: &str, left: &dyn std::fmt::Debug, right: &dyn std::fmt::Debug| {
+            differences.push(
+                format!("{state} of draw buffer {buffer_index}"),
+                left,
+                right,
+            )
+        };
+        compare("blend", &self.blend, &other.blend);
+        compare(
+            "blend equation rgb",
+            &self.equation_rgb,
+            &other.equation_rgb,
+        );
+        compare(
+            "blend equation alpha",
+            &self.equation_alpha,
+            &other.equation_alpha,
+        );
+        compare("blend source rgb", &self.source_rgb, &other.source_rgb);
+        compare("blend dest rgb", &self.dest_rgb, &other.dest_rgb);
+        compare(
+            "blend source alpha",
+            &self.source_alpha,
+            &other.source_alpha,
+        );
+        compare("blend dest alpha", &self.dest_alpha, &other.dest_alpha);
+        compare(
+            "colour writemask",
+            &self.colour_writemask,
+            &other.colour_writemask,
+        );
+    }
+}
+
+/// # Stencil test and write mask of a single face
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StencilFaceState {
+    pub func: Option<CompareFunc>,
+    pub reference: i32,
+    pub value_mask: u32,
+    pub writemask: u32,
+    pub fail: Option<StencilOp>,
+    pub pass_depth_fail: Option<StencilOp>,
+    pub pass_depth_pass: Option<StencilOp>,
+}
+
+impl StencilFaceState {
+    fn capture(face: StencilFace) -> Self {
+        match face {
+            StencilFace::Back => StencilFaceState {
+                func: get_stencil_back_func(),
+                reference: get_stencil_back_ref(),
+                value_mask: get_stencil_back_value_mask(),
+                writemask: get_stencil_back_writemask(),
+                fail: get_stencil_back_fail(),
+                pass_depth_fail: get_stencil_back_pass_depth_fail(),
+                pass_depth_pass: get_stencil_back_pass_depth_pass(),
+            },
+            StencilFace::Front | StencilFace::FrontAndBack => StencilFaceState {
+                func: get_stencil_func(),
+                reference: get_stencil_ref(),
+                value_mask: get_stencil_value_mask(),
+                writemask: get_stencil_writemask(),
+                fail: get_stencil_fail(),
+                pass_depth_fail: get_stencil_pass_depth_fail(),
+                pass_depth_pass: get_stencil_pass_depth_pass(),
+            },
+        }
+    }
+
+    fn restore(&self, face: StencilFace) {
+        if let Some(func) = self.func {
+            stencil_func_separate(face, func, self.reference, self.value_mask);
+        }
+        if let (Some(fail), Some(pass_depth_fail), Some(pass_depth_pass)) =
+            (self.fail, self.pass_depth_fail, self.pass_depth_pass)
+        {
+            stencil_op_separate(face, fail, pass_depth_fail, pass_depth_pass);
+        }
+        stencil_mask_separate(face, self.writemask);
+    }
+
+    fn diff(&self, other: &Self, face: StencilFace, differences: &mut Differences) {
+        let mut compare = |state: &str, left: &dyn std::fmt::Debug, right: &dyn std::fmt::Debug| {
+            differences.push(format!("{face:?} stencil {state}"), left, right)
+        };
+        compare("func", &self.func, &other.func);
+        compare("ref", &self.reference, &other.reference);
+        compare("value mask", &self.value_mask, &other.value_mask);
+        compare("writemask", &self.writemask, &other.writemask);
+        compare("fail", &self.fail, &other.fail);
+        compare(
+            "pass depth fail",
+            &self.pass_depth_fail,
+            &other.pass_depth_fail,
+        );
+        compare(
+            "pass depth pass",
+            &self.pass_depth_pass,
+            &other.pass_depth_pass,
+        );
+    }
+}
+
+/// # Viewport, scissor box and depth range of a single viewport index
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ViewportState {
+    pub viewport: RectF32,
+    pub scissor_test: bool,
+    pub scissor_box: Rect,
+    pub depth_range: DepthRange,
+}
+
+impl ViewportState {
+    fn capture(index: u32) -> Self {
+        ViewportState {
+            viewport: get_viewport_indexed(index),
+            scissor_test: is_enabled_indexed(Capability::ScissorTest, index),
+            scissor_box: get_scissor_box_indexed(index),
+            depth_range: get_depth_range_indexed(index),
+        }
+    }
+
+    fn restore(&self, index: u32) {
+        viewport_indexed(index, self.viewport);
+        if self.scissor_test {
+            enable_indexed(Capability::ScissorTest, index);
+        } else {
+            disable_indexed(Capability::ScissorTest, index);
+        }
+        scissor_indexed(index, self.scissor_box);
+        depth_range_indexed(index, self.depth_range.near, self.depth_range.far);
+    }
+
+    fn diff(&self, other: &Self, index: u32, differences: &mut Differences) {
+        let mut compare = |state: &str, left: &dyn std::fmt::Debug, right: &dyn std::fmt::Debug| {
+            differences.push(format!("{state} of viewport {index}"), left, right)
+        };
+        compare("rect", &self.viewport, &other.viewport);
+        compare("scissor test", &self.scissor_test, &other.scissor_test);
+        compare("scissor box", &self.scissor_box, &other.scissor_box);
+        compare("depth range", &self.depth_range, &other.depth_range);
+    }
+}
+
+/// # Range of a buffer bound to an indexed binding point
+///
+/// A `size` of 0 is the whole buffer, as bound by [bind_buffer_base]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BufferRangeBinding {
+    pub buffer: Buffer,
+    pub start: i64,
+    pub size: u64,
+}
+
+/// # Textures and sampler bound to a single texture unit
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextureUnitState {
+    pub textures: Vec<(TextureBindingTarget, Texture)>,
+    pub sampler: Sampler,
+}
+
+/// # Single piece of state that differs between two snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateDifference {
+    /// Readable name of the state, e.g. `"blend source rgb of draw buffer 0"`
+    pub state: String,
+
+    /// Debug formatted value of the snapshot [GlStateSnapshot::diff] was called on
+    pub left: String,
+
+    /// Debug formatted value of the snapshot passed to [GlStateSnapshot::diff]
+    pub right: String,
+}
+
+impl std::fmt::Display for StateDifference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} != {}", self.state, self.left, self.right)
+    }
+}
+
+#[derive(Default)]
+struct Differences(Vec<StateDifference>);
+
+impl Differences {
+    fn push(&mut self, state: String, left: &dyn std::fmt::Debug, right: &dyn std::fmt::Debug) {
+        let (left, right) = (format!("{left:?}"), format!("{right:?}"));
+        if left != right {
+            self.0.push(StateDifference { state, left, right });
+        }
+    }
+}
+
+/// # Every piece of context state that can be set through rgl
+/// see [crate::state_snapshot]
+///
+/// Enums the driver reports but rgl does not know of are captured as `None`, and left as they are
+/// by [GlStateSnapshot::restore].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlStateSnapshot {
+    pub capabilities: Vec<(Capability, bool)>,
+    pub draw_buffers: Vec<DrawBufferState>,
+    pub blend_colour: [f32; 4],
+    pub colour_clear_value: [f32; 4],
+    pub depth_func: Option<CompareFunc>,
+    pub depth_writemask: bool,
+    pub depth_clear_value: f64,
+    pub stencil_front: StencilFaceState,
+    pub stencil_back: StencilFaceState,
+    pub stencil_clear_value: i32,
+    pub viewports: Vec<ViewportState>,
+    pub cull_face_mode: Option<CullFaceMode>,
+    pub front_face: Option<FrontFace>,
+    pub polygon_mode: Option<PolygonMode>,
+    pub polygon_offset_factor: f32,
+    pub polygon_offset_units: f32,
+    pub line_width: f32,
+    pub point_size: f32,
+    pub point_fade_threshold_size: f32,
+    pub point_sprite_coord_origin: Option<PointSpriteCoordOrigin>,
+    pub provoking_vertex: Option<ProvokingVertex>,
+    pub sample_coverage_value: f32,
+    pub sample_coverage_invert: bool,
+    pub sample_mask_values: Vec<u32>,
+    pub min_sample_shading_value: f32,
+    pub current_program: Program,
+    pub vertex_array: VertexArray,
+    pub buffers: Vec<(BufferBindingTarget, Buffer)>,
+    pub buffer_ranges: Vec<(BufferBindingRangeTarget, Vec<BufferRangeBinding>)>,
+    pub active_texture: u32,
+    pub texture_units: Vec<TextureUnitState>,
+    pub draw_framebuffer: Framebuffer,
+    pub read_framebuffer: Framebuffer,
+    pub renderbuffer: Renderbuffer,
+}
+
+impl GlStateSnapshot {
+    /// Query the current state of the context
+    ///
+    /// The active texture unit is changed while capturing the bindings of each unit, and set back
+    /// before returning.
+    pub fn capture() -> Self {
+        let active_unit = get_active_texture();
+        let texture_units = (0..get_max_combined_texture_image_units())
+            .map(|unit| {
+                active_texture(unit);
+                TextureUnitState {
+                    textures: TEXTURE_TARGETS
+                        .iter()
+                        .map(|&target| (target, texture_binding(target)))
+                        .collect(),
+                    sampler: get_sampler_binding(),
+                }
+            })
+            .collect();
+        active_texture(active_unit);
+
+        GlStateSnapshot {
+            capabilities: CAPABILITIES
+                .iter()
+                .map(|&capability| (capability, is_enabled(capability)))
+                .collect(),
+            draw_buffers: (0..get_max_draw_buffers())
+                .map(DrawBufferState::capture)
+                .collect(),
+            blend_colour: get_blend_colour(),
+            colour_clear_value: get_colour_clear_value(),
+            depth_func: get_depth_func(),
+            depth_writemask: get_depth_writemask(),
+            depth_clear_value: get_depth_clear_value(),
+            stencil_front: StencilFaceState::capture(StencilFace::Front),
+            stencil_back: StencilFaceState::capture(StencilFace::Back),
+            stencil_clear_value: get_stencil_clear_value(),
+            viewports: (0..get_max_viewports())
+                .map(ViewportState::capture)
+                .collect(),
+            cull_face_mode: get_cull_face_mode(),
+            front_face: get_front_face(),
+            polygon_mode: get_polygon_mode(),
+            polygon_offset_factor: get_polygon_offset_factor(),
+            polygon_offset_units: get_polygon_offset_units(),
+            line_width: get_line_width(),
+            point_size: get_point_size(),
+            point_fade_threshold_size: get_point_fade_threshold_size(),
+            point_sprite_coord_origin: get_point_sprite_coord_origin(),
+            provoking_vertex: get_provoking_vertex(),
+            sample_coverage_value: get_sample_coverage_value(),
+            sample_coverage_invert: get_sample_coverage_invert(),
+            sample_mask_values: (0..get_max_sample_mask_words())
+                .map(get_sample_mask_value)
+                .collect(),
+            min_sample_shading_value: get_min_sample_shading_value(),
+            current_program: get_current_program(),
+            vertex_array: get_vertex_array_binding(),
+            buffers: BUFFER_TARGETS
+                .iter()
+                .map(|&target| (target, buffer_binding(target)))
+                .collect(),
+            buffer_ranges: BUFFER_RANGE_TARGETS
+                .iter()
+                .map(|&target| {
+                    let bindings = (0..max_buffer_bindings(target))
+                        .map(|index| BufferRangeBinding {
+                            buffer: get_buffer_binding_indexed(target, index),
+                            start: get_buffer_start_indexed(target, index),
+                            size: get_buffer_size_indexed(target, index),
+                        })
+                        .collect();
+                    (target, bindings)
+                })
+                .collect(),
+            active_texture: active_unit,
+            texture_units,
+            draw_framebuffer: get_draw_framebuffer_binding(),
+            read_framebuffer: get_read_framebuffer_binding(),
+            renderbuffer: get_renderbuffer_binding(),
+        }
+    }
+
+    /// Set the context back to the captured state
+    ///
+    /// Any object that was deleted since capturing cannot be bound again, and raises the same
+    /// error its bind function would.
+    pub fn restore(&self) {
+        for &(capability, enabled) in &self.capabilities {
+            if enabled {
+                enable(capability);
+            } else {
+                disable(capability);
+            }
+        }
+        for (buffer_index, draw_buffer) in (0..).zip(&self.draw_buffers) {
+            draw_buffer.restore(buffer_index);
+        }
+        let [red, green, blue, alpha] = self.blend_colour;
+        blend_colour(red, green, blue, alpha);
+        let [red, green, blue, alpha] = self.colour_clear_value;
+        clear_colour(red, green, blue, alpha);
+
+        if let Some(func) = self.depth_func {
+            depth_func(func);
+        }
+        depth_mask(self.depth_writemask);
+        clear_depth(self.depth_clear_value);
+        self.stencil_front.restore(StencilFace::Front);
+        self.stencil_back.restore(StencilFace::Back);
+        clear_stencil(self.stencil_clear_value);
+
+        for (index, viewport) in (0..).zip(&self.viewports) {
+            viewport.restore(index);
+        }
+        if let Some(mode) = self.cull_face_mode {
+            cull_face(mode);
+        }
+        if let Some(mode) = self.front_face {
+            front_face(mode);
+        }
+        if let Some(mode) = self.polygon_mode {
+            polygon_mode(mode);
+        }
+        polygon_offset(self.polygon_offset_factor, self.polygon_offset_units);
+        line_width(self.line_width);
+        point_size(self.point_size);
+        point_fade_threshold_size(self.point_fade_threshold_size);
+        if let Some(origin) = self.point_sprite_coord_origin {
+            point_sprite_coord_origin(origin);
+        }
+        if let Some(mode) = self.provoking_vertex {
+            provoking_vertex(mode);
+        }
+        sample_coverage(self.sample_coverage_value, self.sample_coverage_invert);
+        for (mask_number, &mask) in (0..).zip(&self.sample_mask_values) {
+            sample_mask(mask_number, mask);
+        }
+        min_sample_shading(self.min_sample_shading_value);
+
+        use_program(self.current_program);
+        // the element array buffer binding is part of the vertex array, so is restored after it
+        bind_vertex_array(self.vertex_array);
+        // binding an indexed binding point also replaces the generic binding of its target
+        for (target, bindings) in &self.buffer_ranges {
+            for (index, binding) in (0..).zip(bindings) {
+                if binding.size == 0 {
+                    bind_buffer_base(*target, index, binding.buffer);
+                } else {
+                    bind_buffer_range(*target, index, binding.buffer, binding.start, binding.size);
+                }
+            }
+        }
+        for &(target, buffer) in &self.buffers {
+            bind_buffer(target, buffer);
+        }
+
+        for (unit, state) in (0..).zip(&self.texture_units) {
+            active_texture(unit);
+            for &(target, texture) in &state.textures {
+                bind_texture(target, texture);
+            }
+            bind_sampler(unit, state.sampler);
+        }
+        active_texture(self.active_texture);
+
+        bind_framebuffer(FramebufferBindingTarget::Draw, self.draw_framebuffer);
+        bind_framebuffer(FramebufferBindingTarget::Read, self.read_framebuffer);
+        bind_renderbuffer(self.renderbuffer);
+    }
+
+    /// List every piece of state that differs from `other`, in a readable form for debugging
+    pub fn diff(&self, other: &GlStateSnapshot) -> Vec<StateDifference> {
+        let mut differences = Differences::default();
+        for ((capability, left), (_, right)) in self.capabilities.iter().zip(&other.capabilities) {
+            differences.push(format!("{capability:?}"), left, right);
+        }
+        for (buffer_index, (left, right)) in
+            (0..).zip(self.draw_buffers.iter().zip(&other.draw_buffers))
+        {
+            left.diff(right, buffer_index, &mut differences);
+        }
+        let mut compare = |state: &str, left: &dyn std::fmt::Debug, right: &dyn std::fmt::Debug| {
+            differences.push(state.to_string(), left, right)
+        };
+        compare("blend colour", &self.blend_colour, &other.blend_colour);
+        compare(
+            "colour clear value",
+            &self.colour_clear_value,
+            &other.colour_clear_value,
+        );
+        compare("depth func", &self.depth_func, &other.depth_func);
+        compare(
+            "depth writemask",
+            &self.depth_writemask,
+            &other.depth_writemask,
+        );
+        compare(
+            "depth clear value",
+            &self.depth_clear_value,
+            &other.depth_clear_value,
+        );
+        compare(
+            "stencil clear value",
+            &self.stencil_clear_value,
+            &other.stencil_clear_value,
+        );
+        compare(
+            "cull face mode",
+            &self.cull_face_mode,
+            &other.cull_face_mode,
+        );
+        compare("front face", &self.front_face, &other.front_face);
+        compare("polygon mode", &self.polygon_mode, &other.polygon_mode);
+        compare(
+            "polygon offset factor",
+            &self.polygon_offset_factor,
+            &other.polygon_offset_factor,
+        );
+        compare(
+            "polygon offset units",
+            &self.polygon_offset_units,
+            &other.polygon_offset_units,
+        );
+        compare("line width", &self.line_width, &other.line_width);
+        compare("point size", &self.point_size, &other.point_size);
+        compare(
+            "point fade threshold size",
+            &self.point_fade_threshold_size,
+            &other.point_fade_threshold_size,
+        );
+        compare(
+            "point sprite coord origin",
+            &self.point_sprite_coord_origin,
+            &other.point_sprite_coord_origin,
+        );
+        compare(
+            "provoking vertex",
+            &self.provoking_vertex,
+            &other.provoking_vertex,
+        );
+        compare(
+            "sample coverage value",
+            &self.sample_coverage_value,
+            &other.sample_coverage_value,
+        );
+        compare(
+            "sample coverage invert",
+            &self.sample_coverage_invert,
+            &other.sample_coverage_invert,
+        );
+        compare(
+            "sample mask values",
+            &self.sample_mask_values,
+            &other.sample_mask_values,
+        );
+        compare(
+            "min sample shading value",
+            &self.min_sample_shading_value,
+            &other.min_sample_shading_value,
+        );
+        compare(
+            "current program",
+            &self.current_program,
+            &other.current_program,
+        );
+        compare("vertex array", &self.vertex_array, &other.vertex_array);
+        compare(
+            "active texture",
+            &self.active_texture,
+            &other.active_texture,
+        );
+        compare(
+            "draw framebuffer",
+            &self.draw_framebuffer,
+            &other.draw_framebuffer,
+        );
+        compare(
+            "read framebuffer",
+            &self.read_framebuffer,
+            &other.read_framebuffer,
+        );
+        compare("renderbuffer", &self.renderbuffer, &other.renderbuffer);
+
+        self.stencil_front
+            .diff(&other.stencil_front, StencilFace::Front, &mut differences);
+        self.stencil_back
+            .diff(&other.stencil_back, StencilFace::Back, &mut differences);
+        for (index, (left, right)) in (0..).zip(self.viewports.iter().zip(&other.viewports)) {
+            left.diff(right, index, &mut differences);
+        }
+        for ((target, left), (_, right)) in self.buffers.iter().zip(&other.buffers) {
+            differences.push(format!("{target:?} buffer"), left, right);
+        }
+        for ((target, left), (_, right)) in self.buffer_ranges.iter().zip(&other.buffer_ranges) {
+            for (index, (left, right)) in (0..).zip(left.iter().zip(right)) {
+                differences.push(format!("{target:?} buffer binding {index}"), left, right);
+            }
+        }
+        for (unit, (left, right)) in (0..).zip(self.texture_units.iter().zip(&other.texture_units))
+        {
+            for ((target, left), (_, right)) in left.textures.iter().zip(&right.textures) {
+                differences.push(format!("{target:?} texture of unit {unit}"), left, right);
+            }
+            differences.push(
+                format!("sampler of unit {unit}"),
+                &left.sampler,
+                &right.sampler,
+            );
+        }
+        differences.0
+    }
+}
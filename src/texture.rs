@@ -61,6 +61,17 @@ impl From<TextureDepthStencilMode> for GLenum {
     }
 }
 
+impl TryFrom<GLenum> for TextureDepthStencilMode {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::DEPTH_COMPONENT => Ok(TextureDepthStencilMode::DepthComponent),
+            gl::STENCIL_INDEX => Ok(TextureDepthStencilMode::StencilIndex),
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TextureCompareMode {
     /// Specifies that the interpolated and clamped `r` texture coordinate should be compared to the
@@ -83,6 +94,17 @@ impl From<TextureCompareMode> for GLenum {
     }
 }
 
+impl TryFrom<GLenum> for TextureCompareMode {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::COMPARE_REF_TO_TEXTURE => Ok(TextureCompareMode::Ref),
+            gl::NONE => Ok(TextureCompareMode::None),
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TextureBinding2DTarget {
     Image2D,
@@ -120,6 +142,118 @@ impl From<TextureBinding2DTarget> for u32 {
     }
 }
 
+impl TryFrom<GLenum> for TextureBinding2DTarget {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::TEXTURE_2D => Ok(TextureBinding2DTarget::Image2D),
+            gl::PROXY_TEXTURE_2D => Ok(TextureBinding2DTarget::Proxy2D),
+            gl::TEXTURE_1D_ARRAY => Ok(TextureBinding2DTarget::Image1DArray),
+            gl::PROXY_TEXTURE_1D_ARRAY => Ok(TextureBinding2DTarget::Proxy1DArray),
+            gl::TEXTURE_RECTANGLE => Ok(TextureBinding2DTarget::Rectangle),
+            gl::PROXY_TEXTURE_RECTANGLE => Ok(TextureBinding2DTarget::ProxyRectangle),
+            gl::TEXTURE_CUBE_MAP_POSITIVE_X => Ok(TextureBinding2DTarget::CubeMapPositiveX),
+            gl::TEXTURE_CUBE_MAP_POSITIVE_Y => Ok(TextureBinding2DTarget::CubeMapPositiveY),
+            gl::TEXTURE_CUBE_MAP_POSITIVE_Z => Ok(TextureBinding2DTarget::CubeMapPositiveZ),
+            gl::TEXTURE_CUBE_MAP_NEGATIVE_X => Ok(TextureBinding2DTarget::CubeMapNegativeX),
+            gl::TEXTURE_CUBE_MAP_NEGATIVE_Y => Ok(TextureBinding2DTarget::CubeMapNegativeY),
+            gl::TEXTURE_CUBE_MAP_NEGATIVE_Z => Ok(TextureBinding2DTarget::CubeMapNegativeZ),
+            gl::PROXY_TEXTURE_CUBE_MAP => Ok(TextureBinding2DTarget::ProxyCubeMap),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # One-dimensional texture image targets
+/// see [tex_image_1d] and [tex_storage_1d]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextureBinding1DTarget {
+    Image1D,
+    Proxy1D,
+}
+
+impl From<TextureBinding1DTarget> for GLenum {
+    fn from(value: TextureBinding1DTarget) -> Self {
+        match value {
+            TextureBinding1DTarget::Image1D => gl::TEXTURE_1D,
+            TextureBinding1DTarget::Proxy1D => gl::PROXY_TEXTURE_1D,
+        }
+    }
+}
+
+/// # Three-dimensional texture image targets
+/// see [tex_image_3d] and [tex_storage_3d]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextureBinding3DTarget {
+    Image3D,
+    Proxy3D,
+    Image2DArray,
+    Proxy2DArray,
+    CubeMapArray,
+    ProxyCubeMapArray,
+}
+
+impl From<TextureBinding3DTarget> for GLenum {
+    fn from(value: TextureBinding3DTarget) -> Self {
+        match value {
+            TextureBinding3DTarget::Image3D => gl::TEXTURE_3D,
+            TextureBinding3DTarget::Proxy3D => gl::PROXY_TEXTURE_3D,
+            TextureBinding3DTarget::Image2DArray => gl::TEXTURE_2D_ARRAY,
+            TextureBinding3DTarget::Proxy2DArray => gl::PROXY_TEXTURE_2D_ARRAY,
+            TextureBinding3DTarget::CubeMapArray => gl::TEXTURE_CUBE_MAP_ARRAY,
+            TextureBinding3DTarget::ProxyCubeMapArray => gl::PROXY_TEXTURE_CUBE_MAP_ARRAY,
+        }
+    }
+}
+
+/// # Two-dimensional immutable texture storage targets
+/// see [tex_storage_2d]
+///
+/// Unlike [TextureBinding2DTarget], storage is allocated for all faces of a cube map at once.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextureStorage2DTarget {
+    Image2D,
+    Proxy2D,
+    Image1DArray,
+    Proxy1DArray,
+    Rectangle,
+    ProxyRectangle,
+    CubeMap,
+    ProxyCubeMap,
+}
+
+impl From<TextureStorage2DTarget> for GLenum {
+    fn from(value: TextureStorage2DTarget) -> Self {
+        match value {
+            TextureStorage2DTarget::Image2D => gl::TEXTURE_2D,
+            TextureStorage2DTarget::Proxy2D => gl::PROXY_TEXTURE_2D,
+            TextureStorage2DTarget::Image1DArray => gl::TEXTURE_1D_ARRAY,
+            TextureStorage2DTarget::Proxy1DArray => gl::PROXY_TEXTURE_1D_ARRAY,
+            TextureStorage2DTarget::Rectangle => gl::TEXTURE_RECTANGLE,
+            TextureStorage2DTarget::ProxyRectangle => gl::PROXY_TEXTURE_RECTANGLE,
+            TextureStorage2DTarget::CubeMap => gl::TEXTURE_CUBE_MAP,
+            TextureStorage2DTarget::ProxyCubeMap => gl::PROXY_TEXTURE_CUBE_MAP,
+        }
+    }
+}
+
+/// # Two-dimensional multisample texture targets
+/// see [tex_image_2d_multisample]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextureMultisample2DTarget {
+    Multisample2D,
+    ProxyMultisample2D,
+}
+
+impl From<TextureMultisample2DTarget> for GLenum {
+    fn from(value: TextureMultisample2DTarget) -> Self {
+        match value {
+            TextureMultisample2DTarget::Multisample2D => gl::TEXTURE_2D_MULTISAMPLE,
+            TextureMultisample2DTarget::ProxyMultisample2D => gl::PROXY_TEXTURE_2D_MULTISAMPLE,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TextureInternalFormat {
     DepthComponent,
@@ -307,6 +441,99 @@ impl From<TextureInternalFormat> for GLenum {
     }
 }
 
+impl TryFrom<GLenum> for TextureInternalFormat {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::DEPTH_COMPONENT => Ok(TextureInternalFormat::DepthComponent),
+            gl::DEPTH_STENCIL => Ok(TextureInternalFormat::DepthStencil),
+            gl::RED => Ok(TextureInternalFormat::R),
+            gl::RG => Ok(TextureInternalFormat::RG),
+            gl::RGB => Ok(TextureInternalFormat::RGB),
+            gl::RGBA => Ok(TextureInternalFormat::RGBA),
+            gl::R8 => Ok(TextureInternalFormat::R8),
+            gl::R8_SNORM => Ok(TextureInternalFormat::R8snorm),
+            gl::R16 => Ok(TextureInternalFormat::R16),
+            gl::R16_SNORM => Ok(TextureInternalFormat::R16snorm),
+            gl::RG8 => Ok(TextureInternalFormat::RG8),
+            gl::RG8_SNORM => Ok(TextureInternalFormat::RG8snorm),
+            gl::RG16 => Ok(TextureInternalFormat::RG16),
+            gl::RG16_SNORM => Ok(TextureInternalFormat::RG16snorm),
+            gl::R3_G3_B2 => Ok(TextureInternalFormat::R3G3B2),
+            gl::RGB4 => Ok(TextureInternalFormat::RGB4),
+            gl::RGB5 => Ok(TextureInternalFormat::RGB5),
+            gl::RGB8 => Ok(TextureInternalFormat::RGB8),
+            gl::RGB8_SNORM => Ok(TextureInternalFormat::RGB8snorm),
+            gl::RGB10 => Ok(TextureInternalFormat::RGB10),
+            gl::RGB12 => Ok(TextureInternalFormat::RGB12),
+            gl::RGB16_SNORM => Ok(TextureInternalFormat::RGB16snorm),
+            gl::RGBA2 => Ok(TextureInternalFormat::RGBA2),
+            gl::RGBA4 => Ok(TextureInternalFormat::RGBA4),
+            gl::RGB5_A1 => Ok(TextureInternalFormat::RGB5A1),
+            gl::RGBA8 => Ok(TextureInternalFormat::RGBA8),
+            gl::RGBA8_SNORM => Ok(TextureInternalFormat::RGBA8snorm),
+            gl::RGB10_A2 => Ok(TextureInternalFormat::RGB10A2),
+            gl::RGB10_A2UI => Ok(TextureInternalFormat::RGB10A2UI),
+            gl::RGBA12 => Ok(TextureInternalFormat::RGBA12),
+            gl::RGBA16 => Ok(TextureInternalFormat::RGBA16),
+            gl::SRGB8 => Ok(TextureInternalFormat::SRGB8),
+            gl::SRGB8_ALPHA8 => Ok(TextureInternalFormat::SRGB8A8),
+            gl::R16F => Ok(TextureInternalFormat::R16F),
+            gl::RG16F => Ok(TextureInternalFormat::RG16F),
+            gl::RGB16F => Ok(TextureInternalFormat::RGB16F),
+            gl::RGBA16F => Ok(TextureInternalFormat::RGBA16F),
+            gl::R32F => Ok(TextureInternalFormat::R32F),
+            gl::RG32F => Ok(TextureInternalFormat::RG32F),
+            gl::RGB32F => Ok(TextureInternalFormat::RGB32F),
+            gl::RGBA32F => Ok(TextureInternalFormat::RGBA32F),
+            gl::R11F_G11F_B10F => Ok(TextureInternalFormat::R11fG11fB10f),
+            gl::RGB9_E5 => Ok(TextureInternalFormat::RGB9E5),
+            gl::R8I => Ok(TextureInternalFormat::R8I),
+            gl::R8UI => Ok(TextureInternalFormat::R8UI),
+            gl::R16I => Ok(TextureInternalFormat::R16I),
+            gl::R16UI => Ok(TextureInternalFormat::R16UI),
+            gl::R32I => Ok(TextureInternalFormat::R32I),
+            gl::R32UI => Ok(TextureInternalFormat::R32UI),
+            gl::RG8I => Ok(TextureInternalFormat::RG8I),
+            gl::RG8UI => Ok(TextureInternalFormat::RG8UI),
+            gl::RG16I => Ok(TextureInternalFormat::RG16I),
+            gl::RG16UI => Ok(TextureInternalFormat::RG16UI),
+            gl::RG32I => Ok(TextureInternalFormat::RG32I),
+            gl::RG32UI => Ok(TextureInternalFormat::RG32UI),
+            gl::RGB8I => Ok(TextureInternalFormat::RGB8I),
+            gl::RGB8UI => Ok(TextureInternalFormat::RGB8UI),
+            gl::RGB16I => Ok(TextureInternalFormat::RGB16I),
+            gl::RGB16UI => Ok(TextureInternalFormat::RGB16UI),
+            gl::RGB32I => Ok(TextureInternalFormat::RGB32I),
+            gl::RGB32UI => Ok(TextureInternalFormat::RGB32UI),
+            gl::RGBA8I => Ok(TextureInternalFormat::RGBA8I),
+            gl::RGBA8UI => Ok(TextureInternalFormat::RGBA8UI),
+            gl::RGBA16I => Ok(TextureInternalFormat::RGBA16I),
+            gl::RGBA16UI => Ok(TextureInternalFormat::RGBA16UI),
+            gl::RGBA32I => Ok(TextureInternalFormat::RGBA32I),
+            gl::RGBA32UI => Ok(TextureInternalFormat::RGBA32UI),
+            gl::DEPTH_COMPONENT16 => Ok(TextureInternalFormat::DepthComponent16),
+            gl::DEPTH_COMPONENT24 => Ok(TextureInternalFormat::DepthComponent24),
+            gl::DEPTH_COMPONENT32F => Ok(TextureInternalFormat::DepthComponent32F),
+            gl::COMPRESSED_RED => Ok(TextureInternalFormat::CompressedR),
+            gl::COMPRESSED_RG => Ok(TextureInternalFormat::CompressedRG),
+            gl::COMPRESSED_RGB => Ok(TextureInternalFormat::CompressedRGB),
+            gl::COMPRESSED_RGBA => Ok(TextureInternalFormat::CompressedRGBA),
+            gl::COMPRESSED_SRGB => Ok(TextureInternalFormat::CompressedSRGB),
+            gl::COMPRESSED_SRGB_ALPHA => Ok(TextureInternalFormat::CompressedSRGBA),
+            gl::COMPRESSED_RED_RGTC1 => Ok(TextureInternalFormat::CompressedRRGTC1),
+            gl::COMPRESSED_SIGNED_RED_RGTC1 => Ok(TextureInternalFormat::CompressedSignedRRGTC1),
+            gl::COMPRESSED_RG_RGTC2 => Ok(TextureInternalFormat::CompressedRGRGTC2),
+            gl::COMPRESSED_SIGNED_RG_RGTC2 => Ok(TextureInternalFormat::CompressedSignedRGRGTC2),
+            gl::COMPRESSED_RGBA_BPTC_UNORM => Ok(TextureInternalFormat::CompressedRGBABPTCUNORM),
+            gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM => {
+                Ok(TextureInternalFormat::CompressedSRGBABPTCUNORM)
+            }
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TextureFormat {
     R,
@@ -372,6 +599,8 @@ pub enum TexturePixelType {
     A2B10G10R10,
     A5B9G9R9,
     B10fG11fR11f,
+    D24S8,
+    D32fS8,
 }
 
 impl From<TexturePixelType> for GLenum {
@@ -399,16 +628,261 @@ impl From<TexturePixelType> for GLenum {
             TexturePixelType::A2B10G10R10 => gl::UNSIGNED_INT_2_10_10_10_REV,
             TexturePixelType::A5B9G9R9 => gl::UNSIGNED_INT_5_9_9_9_REV,
             TexturePixelType::B10fG11fR11f => gl::UNSIGNED_INT_10F_11F_11F_REV,
+            TexturePixelType::D24S8 => gl::UNSIGNED_INT_24_8,
+            TexturePixelType::D32fS8 => gl::FLOAT_32_UNSIGNED_INT_24_8_REV,
         }
     }
 }
 
+/// # Source of the pixels of a texture image upload
+/// see [tex_image_2d] and [tex_sub_image_2d]
 pub enum TextureData<'data, DataType> {
+    /// A client slice, which must hold the whole image
     Data(&'data [DataType]),
+
+    /// A byte offset into the buffer bound to [BufferBindingTarget::PixelUnpack]
     Offset(u64),
+
+    /// No pixels, only allocate the image
     Reserve,
 }
 
+/// # Source of the pixels of a texture subimage upload
+/// see [tex_sub_image_2d]
+///
+/// Unlike [TextureData] there is nothing to reserve, as the image being updated already exists.
+pub enum TextureSubData<'data, DataType> {
+    /// A client slice, which must hold the whole subimage
+    Data(&'data [DataType]),
+
+    /// A byte offset into the buffer bound to [BufferBindingTarget::PixelUnpack]
+    Offset(u64),
+}
+
+impl<DataType> Clone for TextureSubData<'_, DataType> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<DataType> Copy for TextureSubData<'_, DataType> {}
+
+impl<'data, DataType> From<TextureSubData<'data, DataType>> for TextureData<'data, DataType> {
+    fn from(value: TextureSubData<'data, DataType>) -> Self {
+        match value {
+            TextureSubData::Data(data) => TextureData::Data(data),
+            TextureSubData::Offset(offset) => TextureData::Offset(offset),
+        }
+    }
+}
+
+/// # Source of the blocks of a compressed texture image upload
+/// see [compressed_tex_image_2d] and [compressed_tex_sub_image_2d]
+pub enum CompressedTextureData<'data> {
+    /// A client slice, which must hold the whole compressed image
+    Data(&'data [u8]),
+
+    /// `size` bytes at a byte offset into the buffer bound to [BufferBindingTarget::PixelUnpack]
+    Offset { offset: u64, size: usize },
+}
+
+/// # Texture image targets that can be read back
+/// see [get_tex_image]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextureImageTarget {
+    Image1D,
+    Image2D,
+    Image3D,
+    Array1D,
+    Array2D,
+    Rectangle,
+    CubeMapPositiveX,
+    CubeMapPositiveY,
+    CubeMapPositiveZ,
+    CubeMapNegativeX,
+    CubeMapNegativeY,
+    CubeMapNegativeZ,
+    CubeMapArray,
+}
+
+impl From<TextureImageTarget> for GLenum {
+    fn from(value: TextureImageTarget) -> Self {
+        match value {
+            TextureImageTarget::Image1D => gl::TEXTURE_1D,
+            TextureImageTarget::Image2D => gl::TEXTURE_2D,
+            TextureImageTarget::Image3D => gl::TEXTURE_3D,
+            TextureImageTarget::Array1D => gl::TEXTURE_1D_ARRAY,
+            TextureImageTarget::Array2D => gl::TEXTURE_2D_ARRAY,
+            TextureImageTarget::Rectangle => gl::TEXTURE_RECTANGLE,
+            TextureImageTarget::CubeMapPositiveX => gl::TEXTURE_CUBE_MAP_POSITIVE_X,
+            TextureImageTarget::CubeMapPositiveY => gl::TEXTURE_CUBE_MAP_POSITIVE_Y,
+            TextureImageTarget::CubeMapPositiveZ => gl::TEXTURE_CUBE_MAP_POSITIVE_Z,
+            TextureImageTarget::CubeMapNegativeX => gl::TEXTURE_CUBE_MAP_NEGATIVE_X,
+            TextureImageTarget::CubeMapNegativeY => gl::TEXTURE_CUBE_MAP_NEGATIVE_Y,
+            TextureImageTarget::CubeMapNegativeZ => gl::TEXTURE_CUBE_MAP_NEGATIVE_Z,
+            TextureImageTarget::CubeMapArray => gl::TEXTURE_CUBE_MAP_ARRAY,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextureError {
+    Unexpected(Error),
+
+    /// The client slice is smaller than the image, in bytes
+    InsufficientPixelData {
+        required: usize,
+        provided: usize,
+    },
+
+    /// The texture unit index is not less than [get_max_combined_texture_image_units]
+    InvalidTextureUnit(u32),
+
+    /// Not the name of a texture object
+    InvalidTexture(Texture),
+
+    /// The texture was previously bound to a different target
+    MismatchedTarget(TextureBindingTarget),
+
+    /// The cube map (array) texture bound to the target is not cube complete
+    IncompleteCubeMap(TextureBindingTarget),
+
+    /// The mipmap level is out of range for the target
+    InvalidLevel(u32),
+
+    /// The image dimensions are too large, or not square for a cube map face
+    InvalidDimensions {
+        width: u32,
+        height: u32,
+    },
+
+    /// The pixel format, pixel type and internal format combination is not allowed
+    IncompatibleFormat {
+        format: TextureFormat,
+        pixel_data_type: TexturePixelType,
+    },
+
+    /// The bound pixel buffer object is mapped, too small, or the offset is misaligned
+    InvalidPixelBuffer,
+
+    /// The texture parameter value is not allowed for the target
+    InvalidParameterForTarget(TextureBindingTarget),
+
+    /// The texture parameter value is not allowed for the target of the texture
+    InvalidParameterForTexture(Texture),
+
+    /// The region lies outside of the texture image, or the image is not defined
+    InvalidRegion,
+
+    /// The internal format is not a sized format, as required by immutable storage
+    UnsizedInternalFormat(TextureInternalFormat),
+
+    /// The texture already has immutable storage, or no texture is bound to the target
+    ImmutableStorage,
+
+    /// Not a specific compressed format supported by the target, or not the format of the image
+    InvalidCompressedFormat(TextureInternalFormat),
+
+    /// The size of the compressed data does not match the dimensions of the image
+    InvalidCompressedImageSize(usize),
+
+    /// The texture image is not stored in a compressed internal format
+    UncompressedImage,
+
+    /// The sample count is zero, or larger than the maximum for the internal format
+    InvalidSamples(u32),
+
+    /// The internal format is not colour, depth or stencil renderable, as required by multisample
+    /// textures
+    UnrenderableInternalFormat(TextureInternalFormat),
+}
+
+impl std::fmt::Display for TextureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextureError::Unexpected(error) => write!(f, "unexpected texture error: {error}"),
+            TextureError::InsufficientPixelData { required, provided } => write!(
+                f,
+                "pixel data of {provided} bytes is too small for an image of {required} bytes"
+            ),
+            TextureError::InvalidTextureUnit(index) => {
+                write!(f, "texture unit {index} is out of range")
+            }
+            TextureError::InvalidTexture(texture) => {
+                write!(f, "{} is not the name of a texture", texture.0)
+            }
+            TextureError::MismatchedTarget(target) => {
+                write!(f, "texture was created with a target other than {target:?}")
+            }
+            TextureError::IncompleteCubeMap(target) => {
+                write!(f, "texture bound to {target:?} is not cube complete")
+            }
+            TextureError::InvalidLevel(level) => write!(f, "mipmap level {level} is out of range"),
+            TextureError::InvalidDimensions { width, height } => {
+                write!(
+                    f,
+                    "image dimensions {width}x{height} are invalid for the target"
+                )
+            }
+            TextureError::IncompatibleFormat {
+                format,
+                pixel_data_type,
+            } => write!(
+                f,
+                "pixel type {pixel_data_type:?} is incompatible with format {format:?}"
+            ),
+            TextureError::InvalidPixelBuffer => {
+                write!(f, "the bound pixel buffer cannot be used for the transfer")
+            }
+            TextureError::InvalidParameterForTarget(target) => {
+                write!(f, "texture parameter is not allowed for {target:?}")
+            }
+            TextureError::InvalidParameterForTexture(texture) => {
+                write!(
+                    f,
+                    "texture parameter is not allowed for texture {}",
+                    texture.0
+                )
+            }
+            TextureError::InvalidRegion => {
+                write!(f, "the region lies outside of the texture image")
+            }
+            TextureError::UnsizedInternalFormat(format) => {
+                write!(f, "{format:?} is not a sized internal format")
+            }
+            TextureError::ImmutableStorage => {
+                write!(
+                    f,
+                    "the bound texture already has immutable storage, or is the default"
+                )
+            }
+            TextureError::InvalidCompressedFormat(format) => {
+                write!(f, "{format:?} is not a compressed format supported here")
+            }
+            TextureError::InvalidCompressedImageSize(size) => {
+                write!(
+                    f,
+                    "compressed data of {size} bytes does not match the image dimensions"
+                )
+            }
+            TextureError::UncompressedImage => {
+                write!(f, "the texture image is not stored in a compressed format")
+            }
+            TextureError::InvalidSamples(samples) => {
+                write!(
+                    f,
+                    "{samples} samples are not supported for the internal format"
+                )
+            }
+            TextureError::UnrenderableInternalFormat(format) => {
+                write!(f, "{format:?} is not a renderable internal format")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TextureError {}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TextureWrapMode {
     /// Causes the integer part of the coordinate to be ignored; the GL uses only the fractional
@@ -447,8 +921,23 @@ impl From<TextureWrapMode> for u32 {
     }
 }
 
+impl TryFrom<GLenum> for TextureWrapMode {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::REPEAT => Ok(TextureWrapMode::Repeat),
+            gl::MIRRORED_REPEAT => Ok(TextureWrapMode::MirroredRepeat),
+            gl::CLAMP_TO_EDGE => Ok(TextureWrapMode::ClampToEdge),
+            gl::CLAMP_TO_BORDER => Ok(TextureWrapMode::ClampToBorder),
+            gl::MIRROR_CLAMP_TO_EDGE => Ok(TextureWrapMode::MirrorClampToEdge),
+            _ => Err(()),
+        }
+    }
+}
+
 /// # The texture minifying function
 /// see [texture_target_min_filter]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TextureMinFilter {
     /// Returns the value of the texture element that is nearest (in Manhattan distance) to the
     /// specified texture coordinates.
@@ -496,8 +985,24 @@ impl From<TextureMinFilter> for u32 {
     }
 }
 
+impl TryFrom<GLenum> for TextureMinFilter {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::NEAREST => Ok(TextureMinFilter::Nearest),
+            gl::LINEAR => Ok(TextureMinFilter::Linear),
+            gl::NEAREST_MIPMAP_NEAREST => Ok(TextureMinFilter::NearestMipmapNearest),
+            gl::LINEAR_MIPMAP_NEAREST => Ok(TextureMinFilter::LinearMipmapNearest),
+            gl::NEAREST_MIPMAP_LINEAR => Ok(TextureMinFilter::NearestMipmapLinear),
+            gl::LINEAR_MIPMAP_LINEAR => Ok(TextureMinFilter::LinearMipmapLinear),
+            _ => Err(()),
+        }
+    }
+}
+
 /// # The texture magnification function
 /// see [texture_target_mag_filter]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TextureMagFilter {
     /// Returns the value of the texture element that is nearest (in Manhattan distance) to the
     /// specified texture coordinates.
@@ -519,6 +1024,68 @@ impl From<TextureMagFilter> for u32 {
     }
 }
 
+impl TryFrom<GLenum> for TextureMagFilter {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::NEAREST => Ok(TextureMagFilter::Nearest),
+            gl::LINEAR => Ok(TextureMagFilter::Linear),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # The source of a texture colour component
+/// see [texture_target_swizzle_rgba]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextureSwizzle {
+    /// The value of the red component
+    Red,
+
+    /// The value of the green component
+    Green,
+
+    /// The value of the blue component
+    Blue,
+
+    /// The value of the alpha component
+    Alpha,
+
+    /// The constant value 0
+    Zero,
+
+    /// The constant value 1
+    One,
+}
+
+impl From<TextureSwizzle> for GLenum {
+    fn from(value: TextureSwizzle) -> Self {
+        match value {
+            TextureSwizzle::Red => gl::RED,
+            TextureSwizzle::Green => gl::GREEN,
+            TextureSwizzle::Blue => gl::BLUE,
+            TextureSwizzle::Alpha => gl::ALPHA,
+            TextureSwizzle::Zero => gl::ZERO,
+            TextureSwizzle::One => gl::ONE,
+        }
+    }
+}
+
+impl TryFrom<GLenum> for TextureSwizzle {
+    type Error = ();
+    fn try_from(value: GLenum) -> Result<Self, Self::Error> {
+        match value {
+            gl::RED => Ok(TextureSwizzle::Red),
+            gl::GREEN => Ok(TextureSwizzle::Green),
+            gl::BLUE => Ok(TextureSwizzle::Blue),
+            gl::ALPHA => Ok(TextureSwizzle::Alpha),
+            gl::ZERO => Ok(TextureSwizzle::Zero),
+            gl::ONE => Ok(TextureSwizzle::One),
+            _ => Err(()),
+        }
+    }
+}
+
 /// # Select active texture unit
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glActiveTexture.xhtml>
 ///
@@ -571,6 +1138,17 @@ pub fn active_texture(texture_index: u32) {
     unsafe { gl::ActiveTexture(texture) }
 }
 
+/// # Error mapped active texture
+/// see [active_texture]
+pub fn active_texture_checked(texture_index: u32) -> Result<(), TextureError> {
+    active_texture(texture_index);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidEnum => Err(TextureError::InvalidTextureUnit(texture_index)),
+        other => Err(TextureError::Unexpected(other)),
+    }
+}
+
 /// # Bind a named texture to a texturing target
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBindTexture.xhtml>
 ///
@@ -615,7 +1193,7 @@ pub fn active_texture(texture_index: u32) {
 ///
 /// # Errors
 /// * [Error::InvalidValue] - if `texture` is not a name returned from a previous call to
-/// [gen_textures].
+/// [gen_textures]. Core profile implementations may generate [Error::InvalidOperation] instead.
 /// * [Error::InvalidOperation] - if `texture` was previously created with a target that doesn't
 /// match that of `target`.
 ///
@@ -625,7 +1203,7 @@ pub fn active_texture(texture_index: u32) {
 /// * [get_texture_binding_rectangle]
 /// * [get_texture_binding_buffer]
 /// * [get_texture_binding_cube_map]
-/// * [get_texture_binding_cub_map_array]
+/// * [get_texture_binding_cube_map_array]
 /// * [get_texture_binding_multisample_2d], [get_texture_binding_multisample_2d_array]
 ///
 /// # Version Support
@@ -651,6 +1229,24 @@ pub fn bind_texture(target: TextureBindingTarget, texture: Texture) {
     unsafe { gl::BindTexture(target, texture) }
 }
 
+/// # Error mapped bind texture
+/// see [bind_texture]
+pub fn bind_texture_checked(
+    target: TextureBindingTarget,
+    texture: Texture,
+) -> Result<(), TextureError> {
+    bind_texture(target, texture);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(TextureError::InvalidTexture(texture)),
+        Error::InvalidOperation if !is_texture(texture) => {
+            Err(TextureError::InvalidTexture(texture))
+        }
+        Error::InvalidOperation => Err(TextureError::MismatchedTarget(target)),
+        other => Err(TextureError::Unexpected(other)),
+    }
+}
+
 /// # Delete named textures
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDeleteTextures.xhtml>
 ///
@@ -695,6 +1291,16 @@ pub fn delete_textures(textures: &[Texture]) {
     unsafe { gl::DeleteTextures(n, textures) }
 }
 
+/// # Error mapped delete textures
+/// see [delete_textures]
+pub fn delete_textures_checked(textures: &[Texture]) -> Result<(), TextureError> {
+    delete_textures(textures);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(TextureError::Unexpected(other)),
+    }
+}
+
 /// # Generate mipmaps for a specified texture object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGenerateMipmap.xhtml>
 ///
@@ -753,6 +1359,17 @@ pub fn generate_mipmap(target: TextureBindingTarget) {
     unsafe { gl::GenerateMipmap(target) }
 }
 
+/// # Error mapped generate mipmap
+/// see [generate_mipmap]
+pub fn generate_mipmap_checked(target: TextureBindingTarget) -> Result<(), TextureError> {
+    generate_mipmap(target);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(TextureError::IncompleteCubeMap(target)),
+        other => Err(TextureError::Unexpected(other)),
+    }
+}
+
 /// # Generate mipmaps for a specified texture object
 /// see [generate_mipmap]
 ///
@@ -768,6 +1385,17 @@ pub fn generate_texture_mipmap(texture: Texture) {
     unsafe { gl::GenerateTextureMipmap(texture) }
 }
 
+/// # Error mapped generate texture mipmap
+/// see [generate_texture_mipmap]
+pub fn generate_texture_mipmap_checked(texture: Texture) -> Result<(), TextureError> {
+    generate_texture_mipmap(texture);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(TextureError::InvalidTexture(texture)),
+        other => Err(TextureError::Unexpected(other)),
+    }
+}
+
 /// # Generate texture names
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGenTextures.xhtml>
 ///
@@ -817,780 +1445,6170 @@ pub fn gen_textures(textures: &mut [Texture]) {
     unsafe { gl::GenTextures(n, textures) }
 }
 
-/// # Determine if a name corresponds to a texture
-/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glIsTexture.xhtml>
-///
-/// # Arguments
-/// * `texture` - Specifies a value that may be the name of a texture.
-///
-/// # Example
-/// ```no_run
-/// # use rgl::prelude::*;
-/// assert!(is_texture(Texture(42)));
-/// assert!(!is_texture(Texture(0)));
-/// ```
-///
-/// # Description
-/// [is_texture] returns true if `texture` is currently the name of a texture. If `texture` is zero,
-/// or is a non-zero value that is not currently the name of a texture, or if an error occurs,
-/// [is_texture] returns false.
-///
-/// A name returned by [gen_textures], but not yet associated with a texture by calling
-/// [bind_texture], is not the name of a texture.
-///
-/// # Version Support
-///
-/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-/// | [is_texture] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-///
-/// # See Also
-/// * [bind_texture]
-/// * [copy_tex_image_1d], [copy_tex_image_2d]
-/// * [delete_textures]
-/// * [gen_textures]
-/// * [get_ex_parameter]
-/// * [tex_image_1d], [tex_image_2d], [tex_image_3d]
-/// * [tex_parameter]
-pub fn is_texture(texture: Texture) -> bool {
-    let texture = texture.0;
+/// # Error mapped generate textures
+/// see [gen_textures]
+pub fn gen_textures_checked(textures: &mut [Texture]) -> Result<(), TextureError> {
+    gen_textures(textures);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(TextureError::Unexpected(other)),
+    }
+}
 
-    // SAFE: synchronous integer copy
-    let val = unsafe { gl::IsTexture(texture) };
-    val == gl::TRUE
+/// Byte size of a single element (used for row alignment), and of a whole pixel
+fn pixel_layout(format: TextureFormat, pixel_data_type: TexturePixelType) -> (usize, usize) {
+    let (element_size, packed) = match pixel_data_type {
+        TexturePixelType::U8 | TexturePixelType::I8 => (1, false),
+        TexturePixelType::U16 | TexturePixelType::I16 | TexturePixelType::F16 => (2, false),
+        TexturePixelType::U32 | TexturePixelType::I32 | TexturePixelType::F32 => (4, false),
+        TexturePixelType::R3G3B2 | TexturePixelType::B2G3R3 => (1, true),
+        TexturePixelType::R5G6B5
+        | TexturePixelType::B5G6R5
+        | TexturePixelType::R4G4B4A4
+        | TexturePixelType::A4B4G4R4
+        | TexturePixelType::R5G5B5A1
+        | TexturePixelType::A1B5G5R5 => (2, true),
+        TexturePixelType::R8G8B8A8
+        | TexturePixelType::A8B8G8R8
+        | TexturePixelType::R10G10B10A2
+        | TexturePixelType::A2B10G10R10
+        | TexturePixelType::A5B9G9R9
+        | TexturePixelType::B10fG11fR11f
+        | TexturePixelType::D24S8 => (4, true),
+        TexturePixelType::D32fS8 => (8, true),
+    };
+
+    if packed {
+        return (element_size, element_size);
+    }
+
+    let components = match format {
+        TextureFormat::R
+        | TextureFormat::I32R
+        | TextureFormat::StencilIndex
+        | TextureFormat::DepthComponent
+        | TextureFormat::DepthStencil => 1,
+        TextureFormat::RG | TextureFormat::I32RG => 2,
+        TextureFormat::RGB | TextureFormat::BGR | TextureFormat::I32RGB | TextureFormat::I32BGR => {
+            3
+        }
+        TextureFormat::RGBA
+        | TextureFormat::BGRA
+        | TextureFormat::I32RGBA
+        | TextureFormat::I32BGRA => 4,
+    };
+    (element_size, element_size * components)
 }
 
-/// # Specify a two-dimensional texture image
-/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexImage2D.xhtml>
-///
-/// # Arguments
-/// * `target` - Specifies the target texture.
-/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
-/// the `n`th mipmap reduction image. If target is [TextureBinding2DTarget::Rectangle] or
-/// [TextureBinding2DTarget::ProxyRectangle], `level` must be 0.
-/// * `internal_format` - Specifies the number of color components in the texture.
-/// * `width` - Specifies the width of the texture image. All implementations support texture images
-/// that are at least 1024 texels wide.
-/// * `height` - Specifies the height of the texture image, or the number of layers in a texture
-/// array, in the case of the [TextureBinding2DTarget::Image1DArray] and
-/// [TextureBinding2DTarget::Proxy1DArray] targets. All implementations support 2D texture images
-/// that are at least 1024 texels high, and texture arrays that are at least 256 layers deep.
-/// * `format` - Specifies the format of the pixel data.
-/// * `pixel_data_type` - Specifies the data type of the pixel data.
-/// * `data` - Specifies a slice to the image data in memory, or single `u64` offset to a
-/// [BufferBindingTarget::PixelUnpack] buffer.
+/// The pixel store parameters of either pack or unpack operations, in the order alignment, row
+/// length, skip pixels, skip rows, image height and skip images
+const PACK_PARAMETERS: [GLenum; 6] = [
+    gl::PACK_ALIGNMENT,
+    gl::PACK_ROW_LENGTH,
+    gl::PACK_SKIP_PIXELS,
+    gl::PACK_SKIP_ROWS,
+    gl::PACK_IMAGE_HEIGHT,
+    gl::PACK_SKIP_IMAGES,
+];
+const UNPACK_PARAMETERS: [GLenum; 6] = [
+    gl::UNPACK_ALIGNMENT,
+    gl::UNPACK_ROW_LENGTH,
+    gl::UNPACK_SKIP_PIXELS,
+    gl::UNPACK_SKIP_ROWS,
+    gl::UNPACK_IMAGE_HEIGHT,
+    gl::UNPACK_SKIP_IMAGES,
+];
+
+/// Number of bytes accessed by a pixel transfer of the given dimensions, respecting the current
+/// pixel store state selected by `parameters`. `depth` is only given for three-dimensional images.
+fn pixel_transfer_size(
+    parameters: [GLenum; 6],
+    width: u32,
+    height: u32,
+    depth: Option<u32>,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+) -> usize {
+    if width == 0 || height == 0 || depth == Some(0) {
+        return 0;
+    }
+
+    let [alignment, row_length, skip_pixels, skip_rows, image_height, skip_images] = parameters;
+    let (element_size, pixel_size) = pixel_layout(format, pixel_data_type);
+    let width = width as usize;
+    let height = height as usize;
+    let non_zero_or = |pname, default| match get_i32(pname) {
+        0 => default,
+        n => n as usize,
+    };
+
+    let alignment = get_i32(alignment) as usize;
+    let row_length = non_zero_or(row_length, width);
+    let skip_pixels = get_i32(skip_pixels) as usize;
+    let skip_rows = get_i32(skip_rows) as usize;
+
+    let row_size = row_length * pixel_size;
+    let row_stride = if element_size >= alignment {
+        row_size
+    } else {
+        row_size.div_ceil(alignment) * alignment
+    };
+
+    let image_offset = match depth {
+        Some(depth) => {
+            let image_height = non_zero_or(image_height, height);
+            let skip_images = get_i32(skip_images) as usize;
+            (skip_images + depth as usize - 1) * image_height * row_stride
+        }
+        None => 0,
+    };
+
+    image_offset + (skip_rows + height - 1) * row_stride + (skip_pixels + width) * pixel_size
+}
+
+/// Number of bytes written by a pixel pack operation (e.g. [read_pixels] or [get_tex_image]) of
+/// the given dimensions, respecting the current `PACK_*` pixel store state. `depth` is only given
+/// for three-dimensional images.
+pub(crate) fn pixel_pack_size(
+    width: u32,
+    height: u32,
+    depth: Option<u32>,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+) -> usize {
+    pixel_transfer_size(
+        PACK_PARAMETERS,
+        width,
+        height,
+        depth,
+        format,
+        pixel_data_type,
+    )
+}
+
+/// Number of bytes read by a pixel unpack operation (e.g. [tex_image_2d] or [tex_sub_image_2d])
+/// of the given dimensions, respecting the current `UNPACK_*` pixel store state. `depth` is only
+/// given for three-dimensional images.
+pub(crate) fn pixel_unpack_size(
+    width: u32,
+    height: u32,
+    depth: Option<u32>,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+) -> usize {
+    pixel_transfer_size(
+        UNPACK_PARAMETERS,
+        width,
+        height,
+        depth,
+        format,
+        pixel_data_type,
+    )
+}
+
+/// Check a client slice of `data` holds the whole image, before the GL reads from it. Pixel
+/// unpack buffer offsets are bounds checked by the GL instead
+fn check_pixel_data<DataType>(
+    width: u32,
+    height: u32,
+    depth: Option<u32>,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: &TextureData<DataType>,
+) -> Result<(), TextureError> {
+    let TextureData::Data(data) = data else {
+        return Ok(());
+    };
+    let required = pixel_unpack_size(width, height, depth, format, pixel_data_type);
+    let provided = std::mem::size_of_val(*data);
+    if provided < required {
+        return Err(TextureError::InsufficientPixelData { required, provided });
+    }
+    Ok(())
+}
+
+/// Panic if a client slice of `data` is too small for the image, as the GL would read past its end
+fn assert_pixel_data<DataType>(
+    width: u32,
+    height: u32,
+    depth: Option<u32>,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: &TextureData<DataType>,
+) {
+    if let Err(TextureError::InsufficientPixelData { required, provided }) =
+        check_pixel_data(width, height, depth, format, pixel_data_type, data)
+    {
+        panic!("pixel data holds {provided} bytes, but the image requires {required}");
+    }
+}
+
+/// Convert `data` into the pointer argument of the pixel unpack commands
+fn pixel_data_pointer<DataType>(data: TextureData<DataType>) -> *const std::os::raw::c_void {
+    let data = match data {
+        TextureData::Data(data) => data.as_ptr(),
+        TextureData::Offset(offset) => offset as *const _,
+        TextureData::Reserve => std::ptr::null(),
+    };
+    data as *const std::os::raw::c_void
+}
+
+fn get_tex_level_parameter_i32(target: GLenum, level: u32, pname: GLenum) -> i32 {
+    let level = level as GLint;
+    let mut params = GLint::default();
+
+    // SAFE: synchronous write into `params`, no memory retained
+    unsafe { gl::GetTexLevelParameteriv(target, level, pname, &mut params) };
+    params
+}
+
+fn get_tex_image_size(
+    target: TextureImageTarget,
+    level: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+) -> usize {
+    let is_three_dimensional = matches!(
+        target,
+        TextureImageTarget::Image3D
+            | TextureImageTarget::Array2D
+            | TextureImageTarget::CubeMapArray
+    );
+    let target = GLenum::from(target);
+    let width = get_tex_level_parameter_i32(target, level, gl::TEXTURE_WIDTH) as u32;
+    let height = get_tex_level_parameter_i32(target, level, gl::TEXTURE_HEIGHT) as u32;
+    let depth = is_three_dimensional
+        .then(|| get_tex_level_parameter_i32(target, level, gl::TEXTURE_DEPTH) as u32);
+    pixel_pack_size(width, height, depth, format, pixel_data_type)
+}
+
+/// # Return a texture image
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetTexImage.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture is bound.
+/// * `level` - Specifies the level-of-detail number of the desired image. Level 0 is the base
+///   image level. Level `n` is the `n`th mipmap reduction image.
+/// * `format` - Specifies a pixel format for the returned data.
+/// * `pixel_data_type` - Specifies a pixel type for the returned data.
+/// * `data` - Returns the texture image. Should be a slice of the type specified in
+///   `pixel_data_type`.
 ///
 /// # Example
 /// ```no_run
 /// # use rgl::prelude::*;
-/// tex_image_2d(
-///     TextureBinding2DTarget::Image2D,
+/// let mut pixels = vec![0u8; 256 * 256 * 4];
+/// get_tex_image(
+///     TextureImageTarget::Image2D,
 ///     0,
-///     TextureInternalFormat::RGB,
-///     1920,
-///     1080,
 ///     TextureFormat::RGBA,
 ///     TexturePixelType::U8,
-///     TextureData::Data(&[0, 0, 1, 1, 0, 1, 0, 1]),
+///     &mut pixels,
 /// );
 /// ```
 ///
 /// # Description
-/// Texturing allows elements of an image array to be read by shaders.
-///
-/// To define texture images, call [tex_image_2d]. The arguments describe the parameters of the
-/// texture image, such as `height`, `width`, level-of-detail number (see [tex_parameter]), and
-/// number of colour components provided. The last three arguments describe how the image is
-/// represented in memory.
-///
-/// If `target` is [TextureBinding2DTarget::Proxy2D], [TextureBinding2DTarget::Proxy1DArray],
-/// [TextureBinding2DTarget::ProxyCubeMap], or [TextureBinding2DTarget::ProxyRectangle], no data is
-/// read from data, but all of the texture image state is recalculated, checked for consistency, and
-/// checked against the implementation's capabilities. If the implementation cannot handle a texture
-/// of the requested texture size, it sets all of the image state to 0, but does not generate an
-/// error (see [get_error]). To query for an entire mipmap array, use an image array level greater
-/// than or equal to 1.
-///
-/// If `target` is [TextureBinding2DTarget::Image2D], [TextureBinding2DTarget::Rectangle] or one of
-/// the `TextureBinding2DTarget::CubeMap*` targets, data is read from data as a sequence of `i8`,
-/// `u8`, `i16`, `u16`, `i32`, `u32`, or `f32`, depending on type. These values are grouped into
-/// sets of one, two, three, or four values, depending on format, to form elements. Each data byte
-/// is treated as eight 1-bit elements, with bit ordering determined by
-/// [pixel_store_unpack_lsb_first].
-///
-/// If `target` is [TextureBinding2DTarget::Image1DArray], data is interpreted as an array of
-/// one-dimensional images.
-///
-/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
-/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
-/// the buffer object's data store. This is handled by using a single offset `u64` values as data
-/// instead of a slice.
-///
-/// The first element corresponds to the lower left corner of the texture image. Subsequent elements
-/// progress left-to-right through the remaining texels in the lowest row of the texture image, and
-/// then in successively higher rows of the texture image. The final element corresponds to the
-/// upper right corner of the texture image.
-///
-/// `format` determines the composition of each element in `data`.
-///
-/// If an application wants to store the texture at a certain resolution or in a certain format, it
-/// can request the resolution and format with `internal_format`. The GL will choose an internal
-/// representation that closely approximates that requested by `internal_format`, but it may not
-/// match exactly. (The representations specified by [TextureInternalFormat::R],
-/// [TextureInternalFormat::RG], [TextureInternalFormat::RGB], and [TextureInternalFormat::RGB] must
-/// match exactly.)
-///
-/// If the `internal_format` parameter is one of the generic compressed formats,
-/// [TextureInternalFormat::CompressedR], [TextureInternalFormat::CompressedRG],
-/// [TextureInternalFormat::CompressedRGB], or [TextureInternalFormat::CompressedRGBA], the GL will
-/// replace the internal format with the symbolic constant for a specific internal format and
-/// compress the texture before storage. If no corresponding internal format is available, or the GL
-/// can not compress that image for any reason, the internal format is instead replaced with a
-/// corresponding base internal format.
+/// [get_tex_image] returns a texture image into `data`. `target` specifies whether the desired
+/// texture image is one specified by [tex_image_1d] ([TextureImageTarget::Image1D]),
+/// [tex_image_2d] ([TextureImageTarget::Array1D], [TextureImageTarget::Rectangle],
+/// [TextureImageTarget::Image2D] or any of the `TextureImageTarget::CubeMap*` faces), or
+/// [tex_image_3d] ([TextureImageTarget::Array2D], [TextureImageTarget::Image3D],
+/// [TextureImageTarget::CubeMapArray]). `level` specifies the level-of-detail number of the
+/// desired image. `format` and `pixel_data_type` specify the format and type of the desired image
+/// array. See [tex_image_2d] for a description of the acceptable values for the `format` and
+/// `pixel_data_type` parameters, respectively.
 ///
-/// If the `internal_format` parameter is [TextureInternalFormat::SRGB],
-/// [TextureInternalFormat::SRGB8], [TextureInternalFormat::SRGBA], or
-/// [TextureInternalFormat::SRGBA8], the texture is treated as if the red, green, or blue components
-/// are encoded in the sRGB color space. Any alpha component is left unchanged. The conversion from
-/// the sRGB encoded component c<sub>s</sub> to a linear component c<sub>l</sub> is:
-///
-/// TODO (latex like using https://docs.rs/rustdoc-katex-demo/0.1.5/rustdoc_katex_demo/index.html)
-/// Assume c<sub>s</sub> is the sRGB component in the range \[0,1\].
-///
-/// Use the [TextureBinding2DTarget::Proxy2D], [TextureBinding2DTarget::Proxy1DArray],
-/// [TextureBinding2DTarget::ProxyRectangle], or [TextureBinding2DTarget::ProxyCubeMap] target to
-/// try out a resolution and format. The implementation will update and recompute its best match for
-/// the requested storage resolution and format. To then query this state, call
-/// [get_tex_level_parameter]. If the texture cannot be accommodated, texture state is set to 0.
-///
-/// A one-component texture image uses only the red component of the RGBA colour extracted from
-/// data. A two-component image uses the R and G values. A three-component image uses the R, G, and
-/// B values. A four-component image uses all of the RGBA components.
-///
-/// Image-based shadowing can be enabled by comparing texture r coordinates to depth texture values
-/// to generate a boolean result. See [tex_parameter] for details on texture comparison.
+/// To understand the operation of [get_tex_image], consider the selected internal four-component
+/// texture image to be an RGBA colour buffer the size of the image. The semantics of
+/// [get_tex_image] are then identical to those of [read_pixels], with the exception that no pixel
+/// transfer operations are performed, when called with the same `format` and `pixel_data_type`,
+/// with `x` and `y` set to 0, `width` set to the width of the texture image and `height` set to 1
+/// for 1D images, or to the height of the texture image for 2D images.
 ///
-/// The [pixel_store] mode affects texture images.
+/// If the selected texture image does not contain four components, the following mappings are
+/// applied. Single-component textures are treated as RGBA buffers with red set to the
+/// single-component value, green set to 0, blue set to 0, and alpha set to 1. Two-component
+/// textures are treated as RGBA buffers with red set to the value of component zero, alpha set to
+/// the value of component one, and green and blue set to 0. Finally, three-component textures are
+/// treated as RGBA buffers with red set to component zero, green set to component one, blue set to
+/// component two, and alpha set to 1.
 ///
-/// `data` may be a single `u64` value. In this case, texture memory is allocated to accommodate a
-/// texture of width `width` and height `height`. You can then download subtextures to initialize
-/// this texture memory. The image is undefined if the user tries to apply an uninitialized portion
-/// of the texture image to a primitive.
+/// Before reading, the dimensions of the texture image are queried and, together with `format`,
+/// `pixel_data_type` and the current pixel pack alignment, used to determine the required size of
+/// `data`. If `data` is too small, [get_tex_image] panics rather than let the GL write past the end
+/// of the slice, while [get_tex_image_checked] returns [TextureError::InsufficientPixelData]
+/// without reading anything.
 ///
-/// [tex_image_2d] specifies the two-dimensional texture for the current texture unit, specified
-/// with [active_texture].
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelPack] target, use
+/// [get_tex_image_to_pack_buffer] instead, where the image is written into the buffer object's
+/// data store at a byte offset.
 ///
 /// # Compatability
-/// * 3.0 - [TexturePixelType::F16]
 /// * 4.4 - [TextureFormat::StencilIndex]
 ///
+/// # Panics
+/// [get_tex_image] panics if `data` is too small to hold the texture image.
+///
 /// # Errors
-/// * [Error::InvalidEnum] - if `target` is one of the six cube map 2D image targets and the `width`
-/// and `height` parameters are not equal.
-/// * [Error::InvalidValue] - if `width` or `height` is greater than [get_max_texture_size]
-/// * [Error::InvalidValue] - if `target` is [TextureBinding2DTarget::Image1DArray] or
-/// [TextureBinding2DTarget::Proxy1DArray] and `height` is greater than
-/// [get_max_array_texture_layers]
-/// * [Error::InvalidValue] - if `level` is greater than log<sub>2</sub>([get_max_texture_size])
+/// * [TextureError::InsufficientPixelData] - if `data` is too small to hold the texture image,
+///   from [get_tex_image_checked].
+/// * [Error::InvalidValue] - if `level` is greater than log<sub>2</sub>([get_max_texture_size]).
 /// * [Error::InvalidOperation] - if `pixel_data_type` is one of [TexturePixelType::R3G3B2],
-/// [TexturePixelType::B2G3R3], [TexturePixelType::R5G6B5], [TexturePixelType::B5G6R5], or
-/// [TexturePixelType::B10fG11fR11f], and `format` is not [TextureFormat::RGB].
-/// * [Error::InvalidOperation] - if `pixel_data_type` is one of [TexturePixelType::R4G4B4A4],
-/// [TexturePixelType::A4B4G4R4], [TexturePixelType::R5G5B5A1],
-/// [TexturePixelType::A1B5G5R5], [TexturePixelType::R8G8B8A8],
-/// [TexturePixelType::A8B8G8R8], [TexturePixelType::R10G10B10A2],
-/// [TexturePixelType::A2B10G10R10], [TexturePixelType::A5B9G9R9], and `format` is neither
-/// [TextureFormat::RGBA] or [TextureFormat::BGRA].
+///   [TexturePixelType::B2G3R3], [TexturePixelType::R5G6B5], [TexturePixelType::B5G6R5], or
+///   [TexturePixelType::B10fG11fR11f], and `format` is not [TextureFormat::RGB].
 /// * [Error::InvalidOperation] - if `pixel_data_type` is one of [TexturePixelType::R4G4B4A4],
-/// [TexturePixelType::A4B4G4R4], [TexturePixelType::R5G5B5A1],
-/// [TexturePixelType::A1B5G5R5], [TexturePixelType::R8G8B8A8],
-/// [TexturePixelType::A8B8G8R8], [TexturePixelType::R10G10B10A2],
-/// [TexturePixelType::A2B10G10R10], [TexturePixelType::A5B9G9R9] and `format` is neither
-/// * [Error::InvalidOperation] - if `target` is not [TextureBinding2DTarget::Image2D],
-/// [TextureBinding2DTarget::Proxy2D], [TextureBinding2DTarget::Rectangle], or
-/// [TextureBinding2DTarget::ProxyRectangle], and `internalformat` is
-/// [TextureInternalFormat::DepthComponent], [TextureInternalFormat::DepthComponent16],
-/// [TextureInternalFormat::DepthComponent24], or [TextureInternalFormat::DepthComponent32F]
-/// * [Error::InvalidOperation] - if `format` is [TextureFormat::DepthComponent], and
-/// `internalformat` is not [TextureInternalFormat::DepthComponent],
-/// [TextureInternalFormat::DepthComponent16], [TextureInternalFormat::DepthComponent24], or
-/// [TextureInternalFormat::DepthComponent32F]
-/// * [Error::InvalidOperation] - if `internalformat` is [TextureInternalFormat::DepthComponent],
-/// [TextureInternalFormat::DepthComponent16], [TextureInternalFormat::DepthComponent24], or
-/// [TextureInternalFormat::DepthComponent32F], and `format` is not [TextureFormat::DepthComponent]
-/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
-/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
-/// mapped.
+///   [TexturePixelType::A4B4G4R4], [TexturePixelType::R5G5B5A1], [TexturePixelType::A1B5G5R5],
+///   [TexturePixelType::R8G8B8A8], [TexturePixelType::A8B8G8R8],
+///   [TexturePixelType::R10G10B10A2], [TexturePixelType::A2B10G10R10], or
+///   [TexturePixelType::A5B9G9R9], and `format` is neither [TextureFormat::RGBA] or
+///   [TextureFormat::BGRA].
 /// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
-/// [BufferBindingTarget::PixelUnpack] target and the data would be unpacked from the buffer object
-/// such that the memory reads required would exceed the data store size.
+///   [BufferBindingTarget::PixelPack] target and the buffer object's data store is currently
+///   mapped.
 /// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
-/// [BufferBindingTarget::PixelUnpack] target and the `data` is not evently divisble into the number
-/// of bytes needed to store in memory a datum indicated by `pixel_data_type`.
-/// * [Error::InvalidValue] - if `target` is [TextureBinding2DTarget::Rectangle] or
-/// [TextureBinding2DTarget::ProxyRectangle] and `level` is not 0.///
+///   [BufferBindingTarget::PixelPack] target and the data would be packed to the buffer object
+///   such that the memory writes required would exceed the data store size.
 ///
 /// # Associated Gets
-/// * [get_tex_image]
-/// * [get_pixel_unpack_buffer_binding]
+/// * [get_tex_level_parameter]
+/// * [get_pixel_pack_buffer_binding]
 ///
 /// # Version Support
 ///
 /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
 /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-/// | [tex_image_2d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_tex_image] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+/// | [get_tex_image_to_pack_buffer] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
 ///
 /// # See Also
 /// * [active_texture]
-/// * [copy_tex_image_1d], [copy_tex_image_2d],
-/// * [copy_tex_sub_image_1d], [copy_tex_sub_image_2d], [copy_tex_sub_image_3d]
+/// * [bind_texture]
 /// * [pixel_store]
-/// * [tex_image_1d], [tex_image_3d]
-/// * [tex_sub_image_1d], [tex_sub_image_2d], [tex_sub_image_2d]
+/// * [read_pixels]
+/// * [tex_image_1d], [tex_image_2d], [tex_image_3d]
 /// * [tex_parameter]
-#[allow(clippy::too_many_arguments)]
-pub fn tex_image_2d<DataType>(
-    target: TextureBinding2DTarget,
+pub fn get_tex_image<DataType: Sized + Copy>(
+    target: TextureImageTarget,
     level: u32,
-    internal_format: TextureInternalFormat,
-    width: u32,
-    height: u32,
     format: TextureFormat,
     pixel_data_type: TexturePixelType,
-    data: TextureData<DataType>,
+    data: &mut [DataType],
 ) {
+    let required = get_tex_image_size(target, level, format, pixel_data_type);
+    let provided = std::mem::size_of_val(data);
+    assert!(
+        provided >= required,
+        "pixel data holds {provided} bytes, but the image requires {required}"
+    );
+
     let target = GLenum::from(target);
     let level = level as GLint;
-    let internal_format = GLenum::from(internal_format) as GLint;
-    let width = width as GLsizei;
-    let height = height as GLsizei;
     let format = GLenum::from(format);
     let type_ = GLenum::from(pixel_data_type);
+    let pixels = data.as_mut_ptr() as *mut std::os::raw::c_void;
 
-    let data = match data {
-        TextureData::Data(data) => data.as_ptr(),
-        TextureData::Offset(offset) => offset as *const _,
-        TextureData::Reserve => std::ptr::null(),
-    } as *const std::os::raw::c_void;
+    // SAFE: synchronous write into `data`, which was checked to be large enough for the whole
+    // image. No memory retained
+    unsafe { gl::GetTexImage(target, level, format, type_, pixels) };
+}
 
-    // SAFE: synchronous read of `data`, no memory retained
-    unsafe {
-        gl::TexImage2D(
-            target,
-            level,
-            internal_format,
-            width,
-            height,
-            0,
+/// # Error mapped get texture image
+/// see [get_tex_image]
+pub fn get_tex_image_checked<DataType: Sized + Copy>(
+    target: TextureImageTarget,
+    level: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: &mut [DataType],
+) -> Result<(), TextureError> {
+    let required = get_tex_image_size(target, level, format, pixel_data_type);
+    let provided = std::mem::size_of_val(data);
+    if provided < required {
+        return Err(TextureError::InsufficientPixelData { required, provided });
+    }
+    get_tex_image(target, level, format, pixel_data_type, data);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(TextureError::InvalidLevel(level)),
+        Error::InvalidOperation => Err(TextureError::IncompatibleFormat {
             format,
-            type_,
-            data,
-        )
+            pixel_data_type,
+        }),
+        other => Err(TextureError::Unexpected(other)),
     }
 }
 
-/// # Set texture parameters
-/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-///
-/// # Associated Gets
-/// * [get_tex_parameter]
-/// * [get_tex_level_parameter]
+/// # Return a texture image into a pixel pack buffer
+/// see [get_tex_image]
 ///
-/// # See Also
-/// * [active_texture]
-/// * [bind_texture]
-/// * [copy_tex_image_1d], [copy_tex_image_2d],
-/// * [copy_tex_sub_image_1d], [copy_tex_sub_image_2d], [copy_tex_sub_image_3d]
-/// * [pixel_store]
-/// * [sampler_parameter]
-/// * [tex_image_1d], [tex_image_2d], [tex_image_3d],
-/// * [tex_sub_image_1d], [tex_sub_image_2d], [tex_sub_image_3d]
-pub mod tex_parameter {
-    use crate::prelude::*;
-    use gl::types::*;
+/// # Arguments
+/// * `offset` - Specifies the byte offset into the data store of the buffer object bound to
+///   [BufferBindingTarget::PixelPack] where the image is written.
+pub fn get_tex_image_to_pack_buffer(
+    target: TextureImageTarget,
+    level: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    offset: u64,
+) {
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let format = GLenum::from(format);
+    let type_ = GLenum::from(pixel_data_type);
+    let pixels = offset as *mut std::os::raw::c_void;
 
-    fn tex_param_i32(target: TextureBindingTarget, pname: GLenum, param: i32) {
-        let target = GLenum::from(target);
+    // SAFE: with a pixel pack buffer bound, `pixels` is a byte offset into the buffer object, which
+    // the GL bounds checks
+    unsafe { gl::GetTexImage(target, level, format, type_, pixels) }
+}
 
-        // SAFE: synchronous integer copy
-        unsafe { gl::TexParameteri(target, pname, param) }
+/// # Error mapped get texture image into a pixel pack buffer
+/// see [get_tex_image_to_pack_buffer]
+pub fn get_tex_image_to_pack_buffer_checked(
+    target: TextureImageTarget,
+    level: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    offset: u64,
+) -> Result<(), TextureError> {
+    get_tex_image_to_pack_buffer(target, level, format, pixel_data_type, offset);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(TextureError::InvalidLevel(level)),
+        Error::InvalidOperation => Err(TextureError::InvalidPixelBuffer),
+        other => Err(TextureError::Unexpected(other)),
     }
+}
 
-    fn tex_param_f32(target: TextureBindingTarget, pname: GLenum, param: f32) {
-        let target = GLenum::from(target);
+/// # Determine if a name corresponds to a texture
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glIsTexture.xhtml>
+///
+/// # Arguments
+/// * `texture` - Specifies a value that may be the name of a texture.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// assert!(is_texture(Texture(42)));
+/// assert!(!is_texture(Texture(0)));
+/// ```
+///
+/// # Description
+/// [is_texture] returns true if `texture` is currently the name of a texture. If `texture` is zero,
+/// or is a non-zero value that is not currently the name of a texture, or if an error occurs,
+/// [is_texture] returns false.
+///
+/// A name returned by [gen_textures], but not yet associated with a texture by calling
+/// [bind_texture], is not the name of a texture.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [is_texture] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [bind_texture]
+/// * [copy_tex_image_1d], [copy_tex_image_2d]
+/// * [delete_textures]
+/// * [gen_textures]
+/// * [get_ex_parameter]
+/// * [tex_image_1d], [tex_image_2d], [tex_image_3d]
+/// * [tex_parameter]
+pub fn is_texture(texture: Texture) -> bool {
+    let texture = texture.0;
 
-        // SAFE: synchronous integer copy
-        unsafe { gl::TexParameterf(target, pname, param) }
+    // SAFE: synchronous integer copy
+    let val = unsafe { gl::IsTexture(texture) };
+    val == gl::TRUE
+}
+
+/// # Error mapped is texture
+/// see [is_texture]
+pub fn is_texture_checked(texture: Texture) -> Result<bool, TextureError> {
+    let val = is_texture(texture);
+    match get_error() {
+        Error::NoError => Ok(val),
+        other => Err(TextureError::Unexpected(other)),
     }
+}
 
-    /// # Set the mode used to read from depth-stencil format textures
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `mode` - Specifies the mode used to read from depth-stencil format textures.
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_depth_stencil_texture_mode(
-    ///     TextureBindingTarget::Image2D,
-    ///     TextureDepthStencilMode::DepthComponent
-    /// );
-    /// ```
-    ///
-    /// # Description
-    /// Specifies the mode used to read from depth-stencil format textures. If the depth stencil
-    /// mode is [TextureDepthStencilMode::DepthComponent], then reads from depth-stencil format
-    /// textures will return the depth component of the texel in R<sub>t</sub> and the stencil
-    /// component will be discarded. If the depth stencil mode is
-    /// [TextureDepthStencilMode::StencilIndex] then the stencil component is returned in R<sub>t</sub>
-    /// and the depth component is discarded. The initial value is
-    /// [TextureDepthStencilMode::DepthComponent].
-    ///
-    /// # Compatability
-    /// * requires 4.3
-    ///
-    /// # Version Support
-    ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_depth_stencil_texture_mode] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    ///
-    /// # See Also
-    /// * [tex_parameter]
-    pub fn texture_target_depth_stencil_texture_mode(
-        target: TextureBindingTarget,
-        mode: TextureDepthStencilMode,
-    ) {
-        let param = GLenum::from(mode) as i32;
-        tex_param_i32(target, gl::DEPTH_STENCIL_TEXTURE_MODE, param)
+/// # Specify a one-dimensional texture image
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexImage1D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target texture.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `internal_format` - Specifies the number of color components in the texture.
+/// * `width` - Specifies the width of the texture image. All implementations support texture images
+/// that are at least 1024 texels wide.
+/// * `format` - Specifies the format of the pixel data.
+/// * `pixel_data_type` - Specifies the data type of the pixel data.
+/// * `data` - Specifies a slice to the image data in memory, or single `u64` offset to a
+/// [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// tex_image_1d(
+///     TextureBinding1DTarget::Image1D,
+///     0,
+///     TextureInternalFormat::RGBA8,
+///     2,
+///     TextureFormat::RGBA,
+///     TexturePixelType::U8,
+///     TextureData::Data(&[255, 0, 0, 255, 0, 0, 255, 255]),
+/// );
+/// ```
+///
+/// # Description
+/// Texturing maps a portion of a specified texture image onto each graphical primitive for which
+/// texturing is enabled. One-dimensional textures are sampled by shaders with a `sampler1D`.
+///
+/// Texture images are defined with [tex_image_1d]. The arguments describe the parameters of the
+/// texture image, such as width, level-of-detail number (see [tex_parameter]), and the internal
+/// resolution and format used to store the image. The last three arguments describe how the image
+/// is represented in memory; they are identical to the pixel formats used for [tex_image_2d].
+///
+/// If `target` is [TextureBinding1DTarget::Proxy1D], no data is read from `data`, but all of the
+/// texture image state is recalculated, checked for consistency, and checked against the
+/// implementation's capabilities. If the implementation cannot handle a texture of the requested
+/// texture size, it sets all of the image state to 0, but does not generate an error (see
+/// [get_error]).
+///
+/// If `target` is [TextureBinding1DTarget::Image1D], data is read from `data` as a sequence of
+/// `i8`, `u8`, `i16`, `u16`, `i32`, `u32`, or `f32`, depending on `pixel_data_type`. These values
+/// are grouped into sets of one, two, three, or four values, depending on `format`, to form
+/// elements. The first element corresponds to the left end of the texture array.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [TextureData::Offset].
+///
+/// Before a slice of `data` is read, `width`, `format`, `pixel_data_type` and the current pixel
+/// unpack state are used to determine the number of bytes the image requires. If `data` is too
+/// small, [tex_image_1d] panics rather than let the GL read past the end of the slice, while
+/// [tex_image_1d_checked] returns [TextureError::InsufficientPixelData] without uploading anything.
+///
+/// [TextureData::Reserve] allocates texture memory to accommodate a texture of width `width`,
+/// without initializing it. The image is undefined if the user tries to apply an uninitialized
+/// portion of the texture image to a primitive.
+///
+/// # Compatability
+/// * 3.0 - [TexturePixelType::F16]
+/// * 4.4 - [TextureFormat::StencilIndex]
+///
+/// # Panics
+/// [tex_image_1d] panics if a slice of `data` is too small to hold the image.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the image,
+/// from [tex_image_1d_checked].
+/// * [Error::InvalidValue] - if `width` is greater than [get_max_texture_size].
+/// * [Error::InvalidValue] - if `level` is greater than log<sub>2</sub>([get_max_texture_size]).
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of [TexturePixelType::R3G3B2],
+/// [TexturePixelType::B2G3R3], [TexturePixelType::R5G6B5], [TexturePixelType::B5G6R5], or
+/// [TexturePixelType::B10fG11fR11f], and `format` is not [TextureFormat::RGB].
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of the packed four component types,
+/// and `format` is neither [TextureFormat::RGBA] or [TextureFormat::BGRA].
+/// * [Error::InvalidOperation] - if `format` is [TextureFormat::DepthComponent] and
+/// `internal_format` is not a depth format, or the other way around.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_image_1d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [copy_tex_image_1d]
+/// * [copy_tex_sub_image_1d]
+/// * [pixel_store]
+/// * [tex_image_2d], [tex_image_3d]
+/// * [tex_storage_1d]
+/// * [tex_sub_image_1d]
+/// * [tex_parameter]
+pub fn tex_image_1d<DataType>(
+    target: TextureBinding1DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureData<DataType>,
+) {
+    assert_pixel_data(width, 1, None, format, pixel_data_type, &data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let internal_format = GLenum::from(internal_format) as GLint;
+    let width = width as GLsizei;
+    let format = GLenum::from(format);
+    let type_ = GLenum::from(pixel_data_type);
+    let data = pixel_data_pointer(data);
+
+    // SAFE: synchronous read of `data`, which was checked to hold the whole image, no memory
+    // retained
+    unsafe {
+        gl::TexImage1D(
+            target,
+            level,
+            internal_format,
+            width,
+            0,
+            format,
+            type_,
+            data,
+        )
     }
+}
 
-    /// # Set the index of the lowest defined mipmap level
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `level` - Specifies the index of the lowest defined mipmap level.
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_base_level(TextureBindingTarget::Image2D, 42);
-    /// ```
-    ///
-    /// # Description
-    /// Specifies the index of the lowest defined mipmap level. The initial value is 0.
-    ///
-    /// # Errors
-    /// * [Error::InvalidOperation] - if the effective target is [TextureBindingTarget::Rectangle],
-    /// [TextureBindingTarget::Multisample2D] or [TextureBindingTarget::Multisample2DArray], and
-    /// `level` is a value other than zero.
-    ///
-    /// # Version Support
-    ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_base_level] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    ///
-    /// # See Also
-    /// * [tex_parameter]
-    pub fn texture_target_base_level(target: TextureBindingTarget, level: u32) {
-        let param = level as i32;
-        tex_param_i32(target, gl::TEXTURE_BASE_LEVEL, param)
+/// # Error mapped specify a one-dimensional texture image
+/// see [tex_image_1d]
+pub fn tex_image_1d_checked<DataType>(
+    target: TextureBinding1DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureData<DataType>,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, TextureData::Offset(_));
+    check_pixel_data(width, 1, None, format, pixel_data_type, &data)?;
+    tex_image_1d(
+        target,
+        level,
+        internal_format,
+        width,
+        format,
+        pixel_data_type,
+        data,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue if width > get_i32(gl::MAX_TEXTURE_SIZE) as u32 => {
+            Err(TextureError::InvalidDimensions { width, height: 1 })
+        }
+        Error::InvalidValue => Err(TextureError::InvalidLevel(level)),
+        Error::InvalidOperation if unpack_buffer => Err(TextureError::InvalidPixelBuffer),
+        Error::InvalidOperation => Err(TextureError::IncompatibleFormat {
+            format,
+            pixel_data_type,
+        }),
+        other => Err(TextureError::Unexpected(other)),
     }
+}
 
-    /// # Set a texel that should be used for border texels
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `texel` - Specifies border values that should be used for border texels
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_border_colour_f32(TextureBindingTarget::Image2D, [0.5, 0.9, 0.2, 1.0]);
-    /// texture_target_border_colour_i32(TextureBindingTarget::Image2D, [128, 250, 12, 255]);
-    /// texture_target_border_colour_u32(TextureBindingTarget::Image2D, [42, 7, 11, 255]);
-    /// texture_target_border_colour_i32_to_f32(TextureBindingTarget::Image2D, [0, 0, 0, 0]);
-    /// ```
-    ///
-    /// # Description
-    /// `texel` specifies four values that define the border values that should be used for border
-    /// texels. If a texel is sampled from the border of the texture, the values are interpreted as
-    /// an RGBA color to match the texture's internal format and substituted for the non-existent
-    /// texel data. If the texture contains depth components, the first component is interpreted as
-    /// a depth value. The initial value is `[0.0, 0.0, 0.0, 0.0]`.
-    ///
-    /// The `texel` is stored unmodified as floating-point values.
-    ///
+/// # Specify a two-dimensional texture image
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexImage2D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target texture.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image. If target is [TextureBinding2DTarget::Rectangle] or
+/// [TextureBinding2DTarget::ProxyRectangle], `level` must be 0.
+/// * `internal_format` - Specifies the number of color components in the texture.
+/// * `width` - Specifies the width of the texture image. All implementations support texture images
+/// that are at least 1024 texels wide.
+/// * `height` - Specifies the height of the texture image, or the number of layers in a texture
+/// array, in the case of the [TextureBinding2DTarget::Image1DArray] and
+/// [TextureBinding2DTarget::Proxy1DArray] targets. All implementations support 2D texture images
+/// that are at least 1024 texels high, and texture arrays that are at least 256 layers deep.
+/// * `format` - Specifies the format of the pixel data.
+/// * `pixel_data_type` - Specifies the data type of the pixel data.
+/// * `data` - Specifies a slice to the image data in memory, or single `u64` offset to a
+/// [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// tex_image_2d(
+///     TextureBinding2DTarget::Image2D,
+///     0,
+///     TextureInternalFormat::RGB,
+///     2,
+///     1,
+///     TextureFormat::RGBA,
+///     TexturePixelType::U8,
+///     TextureData::Data(&[0, 0, 1, 1, 0, 1, 0, 1]),
+/// );
+/// ```
+///
+/// # Description
+/// Texturing allows elements of an image array to be read by shaders.
+///
+/// To define texture images, call [tex_image_2d]. The arguments describe the parameters of the
+/// texture image, such as `height`, `width`, level-of-detail number (see [tex_parameter]), and
+/// number of colour components provided. The last three arguments describe how the image is
+/// represented in memory.
+///
+/// If `target` is [TextureBinding2DTarget::Proxy2D], [TextureBinding2DTarget::Proxy1DArray],
+/// [TextureBinding2DTarget::ProxyCubeMap], or [TextureBinding2DTarget::ProxyRectangle], no data is
+/// read from data, but all of the texture image state is recalculated, checked for consistency, and
+/// checked against the implementation's capabilities. If the implementation cannot handle a texture
+/// of the requested texture size, it sets all of the image state to 0, but does not generate an
+/// error (see [get_error]). To query for an entire mipmap array, use an image array level greater
+/// than or equal to 1.
+///
+/// If `target` is [TextureBinding2DTarget::Image2D], [TextureBinding2DTarget::Rectangle] or one of
+/// the `TextureBinding2DTarget::CubeMap*` targets, data is read from data as a sequence of `i8`,
+/// `u8`, `i16`, `u16`, `i32`, `u32`, or `f32`, depending on type. These values are grouped into
+/// sets of one, two, three, or four values, depending on format, to form elements. Each data byte
+/// is treated as eight 1-bit elements, with bit ordering determined by
+/// [pixel_store_unpack_lsb_first].
+///
+/// If `target` is [TextureBinding2DTarget::Image1DArray], data is interpreted as an array of
+/// one-dimensional images.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store. This is handled by using a single offset `u64` values as data
+/// instead of a slice.
+///
+/// Before a slice of `data` is read, `width`, `height`, `format`, `pixel_data_type` and the current
+/// pixel unpack state are used to determine the number of bytes the image requires. If `data` is
+/// too small, [tex_image_2d] panics rather than let the GL read past the end of the slice, while
+/// [tex_image_2d_checked] returns [TextureError::InsufficientPixelData] without uploading anything.
+///
+/// The first element corresponds to the lower left corner of the texture image. Subsequent elements
+/// progress left-to-right through the remaining texels in the lowest row of the texture image, and
+/// then in successively higher rows of the texture image. The final element corresponds to the
+/// upper right corner of the texture image.
+///
+/// `format` determines the composition of each element in `data`.
+///
+/// If an application wants to store the texture at a certain resolution or in a certain format, it
+/// can request the resolution and format with `internal_format`. The GL will choose an internal
+/// representation that closely approximates that requested by `internal_format`, but it may not
+/// match exactly. (The representations specified by [TextureInternalFormat::R],
+/// [TextureInternalFormat::RG], [TextureInternalFormat::RGB], and [TextureInternalFormat::RGB] must
+/// match exactly.)
+///
+/// If the `internal_format` parameter is one of the generic compressed formats,
+/// [TextureInternalFormat::CompressedR], [TextureInternalFormat::CompressedRG],
+/// [TextureInternalFormat::CompressedRGB], or [TextureInternalFormat::CompressedRGBA], the GL will
+/// replace the internal format with the symbolic constant for a specific internal format and
+/// compress the texture before storage. If no corresponding internal format is available, or the GL
+/// can not compress that image for any reason, the internal format is instead replaced with a
+/// corresponding base internal format.
+///
+/// If the `internal_format` parameter is [TextureInternalFormat::SRGB],
+/// [TextureInternalFormat::SRGB8], [TextureInternalFormat::SRGBA], or
+/// [TextureInternalFormat::SRGBA8], the texture is treated as if the red, green, or blue components
+/// are encoded in the sRGB color space. Any alpha component is left unchanged. The conversion from
+/// the sRGB encoded component c<sub>s</sub> to a linear component c<sub>l</sub> is:
+///
+/// TODO (latex like using https://docs.rs/rustdoc-katex-demo/0.1.5/rustdoc_katex_demo/index.html)
+/// Assume c<sub>s</sub> is the sRGB component in the range \[0,1\].
+///
+/// Use the [TextureBinding2DTarget::Proxy2D], [TextureBinding2DTarget::Proxy1DArray],
+/// [TextureBinding2DTarget::ProxyRectangle], or [TextureBinding2DTarget::ProxyCubeMap] target to
+/// try out a resolution and format. The implementation will update and recompute its best match for
+/// the requested storage resolution and format. To then query this state, call
+/// [get_tex_level_parameter]. If the texture cannot be accommodated, texture state is set to 0.
+///
+/// A one-component texture image uses only the red component of the RGBA colour extracted from
+/// data. A two-component image uses the R and G values. A three-component image uses the R, G, and
+/// B values. A four-component image uses all of the RGBA components.
+///
+/// Image-based shadowing can be enabled by comparing texture r coordinates to depth texture values
+/// to generate a boolean result. See [tex_parameter] for details on texture comparison.
+///
+/// The [pixel_store] mode affects texture images.
+///
+/// `data` may be a single `u64` value. In this case, texture memory is allocated to accommodate a
+/// texture of width `width` and height `height`. You can then download subtextures to initialize
+/// this texture memory. The image is undefined if the user tries to apply an uninitialized portion
+/// of the texture image to a primitive.
+///
+/// [tex_image_2d] specifies the two-dimensional texture for the current texture unit, specified
+/// with [active_texture].
+///
+/// # Compatability
+/// * 3.0 - [TexturePixelType::F16]
+/// * 4.4 - [TextureFormat::StencilIndex]
+///
+/// # Panics
+/// [tex_image_2d] panics if a slice of `data` is too small to hold the image.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the image,
+/// from [tex_image_2d_checked].
+/// * [Error::InvalidEnum] - if `target` is one of the six cube map 2D image targets and the `width`
+/// and `height` parameters are not equal.
+/// * [Error::InvalidValue] - if `width` or `height` is greater than [get_max_texture_size]
+/// * [Error::InvalidValue] - if `target` is [TextureBinding2DTarget::Image1DArray] or
+/// [TextureBinding2DTarget::Proxy1DArray] and `height` is greater than
+/// [get_max_array_texture_layers]
+/// * [Error::InvalidValue] - if `level` is greater than log<sub>2</sub>([get_max_texture_size])
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of [TexturePixelType::R3G3B2],
+/// [TexturePixelType::B2G3R3], [TexturePixelType::R5G6B5], [TexturePixelType::B5G6R5], or
+/// [TexturePixelType::B10fG11fR11f], and `format` is not [TextureFormat::RGB].
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of [TexturePixelType::R4G4B4A4],
+/// [TexturePixelType::A4B4G4R4], [TexturePixelType::R5G5B5A1],
+/// [TexturePixelType::A1B5G5R5], [TexturePixelType::R8G8B8A8],
+/// [TexturePixelType::A8B8G8R8], [TexturePixelType::R10G10B10A2],
+/// [TexturePixelType::A2B10G10R10], [TexturePixelType::A5B9G9R9], and `format` is neither
+/// [TextureFormat::RGBA] or [TextureFormat::BGRA].
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of [TexturePixelType::R4G4B4A4],
+/// [TexturePixelType::A4B4G4R4], [TexturePixelType::R5G5B5A1],
+/// [TexturePixelType::A1B5G5R5], [TexturePixelType::R8G8B8A8],
+/// [TexturePixelType::A8B8G8R8], [TexturePixelType::R10G10B10A2],
+/// [TexturePixelType::A2B10G10R10], [TexturePixelType::A5B9G9R9] and `format` is neither
+/// * [Error::InvalidOperation] - if `target` is not [TextureBinding2DTarget::Image2D],
+/// [TextureBinding2DTarget::Proxy2D], [TextureBinding2DTarget::Rectangle], or
+/// [TextureBinding2DTarget::ProxyRectangle], and `internalformat` is
+/// [TextureInternalFormat::DepthComponent], [TextureInternalFormat::DepthComponent16],
+/// [TextureInternalFormat::DepthComponent24], or [TextureInternalFormat::DepthComponent32F]
+/// * [Error::InvalidOperation] - if `format` is [TextureFormat::DepthComponent], and
+/// `internalformat` is not [TextureInternalFormat::DepthComponent],
+/// [TextureInternalFormat::DepthComponent16], [TextureInternalFormat::DepthComponent24], or
+/// [TextureInternalFormat::DepthComponent32F]
+/// * [Error::InvalidOperation] - if `internalformat` is [TextureInternalFormat::DepthComponent],
+/// [TextureInternalFormat::DepthComponent16], [TextureInternalFormat::DepthComponent24], or
+/// [TextureInternalFormat::DepthComponent32F], and `format` is not [TextureFormat::DepthComponent]
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the data would be unpacked from the buffer object
+/// such that the memory reads required would exceed the data store size.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the `data` is not evently divisble into the number
+/// of bytes needed to store in memory a datum indicated by `pixel_data_type`.
+/// * [Error::InvalidValue] - if `target` is [TextureBinding2DTarget::Rectangle] or
+/// [TextureBinding2DTarget::ProxyRectangle] and `level` is not 0.///
+///
+/// # Associated Gets
+/// * [get_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_image_2d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [copy_tex_image_1d], [copy_tex_image_2d],
+/// * [copy_tex_sub_image_1d], [copy_tex_sub_image_2d], [copy_tex_sub_image_3d]
+/// * [pixel_store]
+/// * [tex_image_1d], [tex_image_3d]
+/// * [tex_sub_image_1d], [tex_sub_image_2d], [tex_sub_image_2d]
+/// * [tex_parameter]
+#[allow(clippy::too_many_arguments)]
+pub fn tex_image_2d<DataType>(
+    target: TextureBinding2DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureData<DataType>,
+) {
+    assert_pixel_data(width, height, None, format, pixel_data_type, &data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let internal_format = GLenum::from(internal_format) as GLint;
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let format = GLenum::from(format);
+    let type_ = GLenum::from(pixel_data_type);
+    let data = pixel_data_pointer(data);
+
+    // SAFE: synchronous read of `data`, which was checked to hold the whole image, no memory
+    // retained
+    unsafe {
+        gl::TexImage2D(
+            target,
+            level,
+            internal_format,
+            width,
+            height,
+            0,
+            format,
+            type_,
+            data,
+        )
+    }
+}
+
+/// # Error mapped specify a two-dimensional texture image
+/// see [tex_image_2d]
+#[allow(clippy::too_many_arguments)]
+pub fn tex_image_2d_checked<DataType>(
+    target: TextureBinding2DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureData<DataType>,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, TextureData::Offset(_));
+    check_pixel_data(width, height, None, format, pixel_data_type, &data)?;
+    tex_image_2d(
+        target,
+        level,
+        internal_format,
+        width,
+        height,
+        format,
+        pixel_data_type,
+        data,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidEnum => Err(TextureError::InvalidDimensions { width, height }),
+        Error::InvalidValue => {
+            let max_size = get_i32(gl::MAX_TEXTURE_SIZE) as u32;
+            let max_height = match target {
+                TextureBinding2DTarget::Image1DArray | TextureBinding2DTarget::Proxy1DArray => {
+                    get_i32(gl::MAX_ARRAY_TEXTURE_LAYERS) as u32
+                }
+                _ => max_size,
+            };
+            if width > max_size || height > max_height {
+                Err(TextureError::InvalidDimensions { width, height })
+            } else {
+                Err(TextureError::InvalidLevel(level))
+            }
+        }
+        Error::InvalidOperation if unpack_buffer => Err(TextureError::InvalidPixelBuffer),
+        Error::InvalidOperation => Err(TextureError::IncompatibleFormat {
+            format,
+            pixel_data_type,
+        }),
+        other => Err(TextureError::Unexpected(other)),
+    }
+}
+
+/// Decode the errors shared by [tex_image_2d_multisample] and [tex_storage_2d_multisample]
+fn tex_multisample_error(
+    internal_format: TextureInternalFormat,
+    samples: u32,
+    width: u32,
+    height: u32,
+    error: Error,
+) -> TextureError {
+    match error {
+        Error::InvalidEnum => TextureError::UnrenderableInternalFormat(internal_format),
+        Error::InvalidValue if samples == 0 => TextureError::InvalidSamples(samples),
+        Error::InvalidValue => TextureError::InvalidDimensions { width, height },
+        Error::InvalidOperation => TextureError::InvalidSamples(samples),
+        other => TextureError::Unexpected(other),
+    }
+}
+
+/// # Establish the data storage, format, dimensions and number of samples of a multisample texture's image
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexImage2DMultisample.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target of the operation.
+/// * `samples` - The number of samples in the multisample texture's image.
+/// * `internal_format` - The internal format to be used to store the multisample texture's image.
+/// `internal_format` must specify a colour-renderable, depth-renderable, or stencil-renderable
+/// format.
+/// * `width` - The width of the multisample texture's image, in texels.
+/// * `height` - The height of the multisample texture's image, in texels.
+/// * `fixed_sample_locations` - Specifies whether the image will use identical sample locations and
+/// the same number of samples for all texels in the image, and the sample locations will not
+/// depend on the internal format or size of the image.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// tex_image_2d_multisample(
+///     TextureMultisample2DTarget::Multisample2D,
+///     4,
+///     TextureInternalFormat::RGBA8,
+///     1920,
+///     1080,
+///     true
+/// );
+/// ```
+///
+/// # Description
+/// [tex_image_2d_multisample] establishes the data storage, format, dimensions and number of
+/// samples of a multisample texture's image. A multisample texture has a single level, and can be
+/// attached to a framebuffer to render with multisample antialiasing. It cannot be sampled with
+/// filtering, instead each sample is fetched with `texelFetch` in a shader, or the image is
+/// resolved into a single sample texture with [blit_framebuffer].
+///
+/// `samples` must not be zero. The GL may allocate more samples than requested, but never fewer.
+///
+/// When a multisample texture is attached to a framebuffer with other multisample attachments,
+/// `fixed_sample_locations` must be true for every attachment unless they are all textures with it
+/// false, otherwise the framebuffer is [FramebufferStatus::IncompleteMultisample].
+///
+/// If `target` is [TextureMultisample2DTarget::ProxyMultisample2D], no data storage is allocated,
+/// but the texture state is checked against the implementation's capabilities.
+///
+/// # Compatability
+/// * 3.2 - [tex_image_2d_multisample]
+///
+/// # Errors
+/// * [Error::InvalidEnum] - if `internal_format` is not a colour-renderable, depth-renderable, or
+/// stencil-renderable format.
+/// * [Error::InvalidOperation] - if `samples` is greater than the maximum number of samples
+/// supported for `internal_format`, at least [get_max_samples] for normalised formats.
+/// * [Error::InvalidValue] - if `samples` is zero.
+/// * [Error::InvalidValue] - if either `width` or `height` is greater than the maximum texture
+/// size.
+///
+/// # Associated Gets
+/// * [get_framebuffer_samples]
+/// * [get_max_samples]
+/// * [get_tex_level_parameter]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_image_2d_multisample] | N | N | N | N | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [blit_framebuffer]
+/// * [framebuffer_texture_2d]
+/// * [renderbuffer_storage_multisample]
+/// * [tex_storage_2d_multisample]
+pub fn tex_image_2d_multisample(
+    target: TextureMultisample2DTarget,
+    samples: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    fixed_sample_locations: bool,
+) {
+    let target = GLenum::from(target);
+    let samples = samples as GLsizei;
+    let internal_format = GLenum::from(internal_format);
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let fixed_sample_locations = GLboolean::from(fixed_sample_locations);
+    unsafe {
+        gl::TexImage2DMultisample(
+            target,
+            samples,
+            internal_format,
+            width,
+            height,
+            fixed_sample_locations,
+        )
+    }
+}
+
+/// # Error mapped establish a multisample texture's image
+/// see [tex_image_2d_multisample]
+pub fn tex_image_2d_multisample_checked(
+    target: TextureMultisample2DTarget,
+    samples: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    fixed_sample_locations: bool,
+) -> Result<(), TextureError> {
+    tex_image_2d_multisample(
+        target,
+        samples,
+        internal_format,
+        width,
+        height,
+        fixed_sample_locations,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(tex_multisample_error(
+            internal_format,
+            samples,
+            width,
+            height,
+            error,
+        )),
+    }
+}
+
+/// # Specify a three-dimensional texture image
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexImage3D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target texture.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `internal_format` - Specifies the number of color components in the texture.
+/// * `width` - Specifies the width of the texture image. All implementations support 3D texture
+/// images that are at least 256 texels wide.
+/// * `height` - Specifies the height of the texture image. All implementations support 3D texture
+/// images that are at least 256 texels high.
+/// * `depth` - Specifies the depth of the texture image, or the number of layers in a texture
+/// array. All implementations support 3D texture images that are at least 256 texels deep, and
+/// texture arrays that are at least 256 layers deep.
+/// * `format` - Specifies the format of the pixel data.
+/// * `pixel_data_type` - Specifies the data type of the pixel data.
+/// * `data` - Specifies a slice to the image data in memory, or single `u64` offset to a
+/// [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let layers = [[0u8; 4]; 16 * 16 * 4];
+/// tex_image_3d(
+///     TextureBinding3DTarget::Image2DArray,
+///     0,
+///     TextureInternalFormat::RGBA8,
+///     16,
+///     16,
+///     4,
+///     TextureFormat::RGBA,
+///     TexturePixelType::U8,
+///     TextureData::Data(&layers),
+/// );
+/// ```
+///
+/// # Description
+/// Texturing maps a portion of a specified texture image onto each graphical primitive for which
+/// texturing is enabled.
+///
+/// To define texture images, call [tex_image_3d]. The arguments describe the parameters of the
+/// texture image, such as height, width, depth, level-of-detail number (see [tex_parameter]), and
+/// number of color components provided. The last three arguments describe how the image is
+/// represented in memory; they are identical to the pixel formats used for [tex_image_2d].
+///
+/// If `target` is [TextureBinding3DTarget::Proxy3D], [TextureBinding3DTarget::Proxy2DArray] or
+/// [TextureBinding3DTarget::ProxyCubeMapArray], no data is read from `data`, but all of the
+/// texture image state is recalculated, checked for consistency, and checked against the
+/// implementation's capabilities. If the implementation cannot handle a texture of the requested
+/// texture size, it sets all of the image state to 0, but does not generate an error (see
+/// [get_error]).
+///
+/// If `target` is [TextureBinding3DTarget::Image3D], data is read from `data` as a sequence of
+/// `i8`, `u8`, `i16`, `u16`, `i32`, `u32`, or `f32`, depending on `pixel_data_type`. These values
+/// are grouped into sets of one, two, three, or four values, depending on `format`, to form
+/// elements. The first element corresponds to the lower left corner of the first image, and each
+/// following image is progressively deeper. For [TextureBinding3DTarget::Image2DArray] and
+/// [TextureBinding3DTarget::CubeMapArray], each image is a layer of the array, and the layers of a
+/// cube map array are ordered by face (positive X, negative X, positive Y, negative Y, positive Z,
+/// negative Z) within each cube, so `depth` must be a multiple of 6.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [TextureData::Offset].
+///
+/// Before a slice of `data` is read, `width`, `height`, `depth`, `format`, `pixel_data_type` and
+/// the current pixel unpack state are used to determine the number of bytes the image requires. If
+/// `data` is too small, [tex_image_3d] panics rather than let the GL read past the end of the
+/// slice, while [tex_image_3d_checked] returns [TextureError::InsufficientPixelData] without
+/// uploading anything.
+///
+/// [TextureData::Reserve] allocates texture memory for the image, without initializing it. The
+/// image is undefined if the user tries to apply an uninitialized portion of the texture image to
+/// a primitive.
+///
+/// # Compatability
+/// * 3.0 - [TextureBinding3DTarget::Image2DArray], [TextureBinding3DTarget::Proxy2DArray]
+/// * 4.0 - [TextureBinding3DTarget::CubeMapArray], [TextureBinding3DTarget::ProxyCubeMapArray]
+/// * 4.4 - [TextureFormat::StencilIndex]
+///
+/// # Panics
+/// [tex_image_3d] panics if a slice of `data` is too small to hold the image.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the image,
+/// from [tex_image_3d_checked].
+/// * [Error::InvalidValue] - if `width`, `height` or `depth` is greater than
+/// [gl::MAX_3D_TEXTURE_SIZE] for three-dimensional textures, or `depth` is greater than
+/// [get_max_array_texture_layers] for texture arrays.
+/// * [Error::InvalidValue] - if `level` is greater than log<sub>2</sub>(max), where max is the
+/// returned value of [gl::MAX_3D_TEXTURE_SIZE].
+/// * [Error::InvalidValue] - if `target` is [TextureBinding3DTarget::CubeMapArray] and `width` is
+/// not equal to `height`, or `depth` is not a multiple of 6.
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of the packed three component types,
+/// and `format` is not [TextureFormat::RGB].
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of the packed four component types,
+/// and `format` is neither [TextureFormat::RGBA] or [TextureFormat::BGRA].
+/// * [Error::InvalidOperation] - if `format` or `internal_format` is a depth format, and `target`
+/// is [TextureBinding3DTarget::Image3D] or [TextureBinding3DTarget::Proxy3D].
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_image_3d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [copy_tex_sub_image_3d]
+/// * [pixel_store]
+/// * [tex_image_1d], [tex_image_2d]
+/// * [tex_storage_3d]
+/// * [tex_sub_image_3d]
+/// * [tex_parameter]
+#[allow(clippy::too_many_arguments)]
+pub fn tex_image_3d<DataType>(
+    target: TextureBinding3DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    depth: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureData<DataType>,
+) {
+    assert_pixel_data(width, height, Some(depth), format, pixel_data_type, &data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let internal_format = GLenum::from(internal_format) as GLint;
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let depth = depth as GLsizei;
+    let format = GLenum::from(format);
+    let type_ = GLenum::from(pixel_data_type);
+    let data = pixel_data_pointer(data);
+
+    // SAFE: synchronous read of `data`, which was checked to hold the whole image, no memory
+    // retained
+    unsafe {
+        gl::TexImage3D(
+            target,
+            level,
+            internal_format,
+            width,
+            height,
+            depth,
+            0,
+            format,
+            type_,
+            data,
+        )
+    }
+}
+
+/// # Error mapped specify a three-dimensional texture image
+/// see [tex_image_3d]
+#[allow(clippy::too_many_arguments)]
+pub fn tex_image_3d_checked<DataType>(
+    target: TextureBinding3DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    depth: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureData<DataType>,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, TextureData::Offset(_));
+    check_pixel_data(width, height, Some(depth), format, pixel_data_type, &data)?;
+    tex_image_3d(
+        target,
+        level,
+        internal_format,
+        width,
+        height,
+        depth,
+        format,
+        pixel_data_type,
+        data,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => {
+            let (max_size, max_depth) = match target {
+                TextureBinding3DTarget::Image3D | TextureBinding3DTarget::Proxy3D => {
+                    let max_size = get_i32(gl::MAX_3D_TEXTURE_SIZE) as u32;
+                    (max_size, max_size)
+                }
+                _ => (
+                    get_i32(gl::MAX_TEXTURE_SIZE) as u32,
+                    get_i32(gl::MAX_ARRAY_TEXTURE_LAYERS) as u32,
+                ),
+            };
+            let cube_map_array = matches!(
+                target,
+                TextureBinding3DTarget::CubeMapArray | TextureBinding3DTarget::ProxyCubeMapArray
+            );
+            if width > max_size
+                || height > max_size
+                || depth > max_depth
+                || (cube_map_array && (width != height || !depth.is_multiple_of(6)))
+            {
+                Err(TextureError::InvalidDimensions { width, height })
+            } else {
+                Err(TextureError::InvalidLevel(level))
+            }
+        }
+        Error::InvalidOperation if unpack_buffer => Err(TextureError::InvalidPixelBuffer),
+        Error::InvalidOperation => Err(TextureError::IncompatibleFormat {
+            format,
+            pixel_data_type,
+        }),
+        other => Err(TextureError::Unexpected(other)),
+    }
+}
+
+/// `GL_TEXTURE_MAX_ANISOTROPY`, core in 4.6 so missing from the 4.5 bindings
+pub(crate) const TEXTURE_MAX_ANISOTROPY: GLenum = 0x84FE;
+
+/// Clamp a requested anisotropy into the supported range, so the request never errors
+fn clamp_anisotropy(anisotropy: f32) -> f32 {
+    let max = get_max_texture_max_anisotropy().max(1.0);
+    anisotropy.clamp(1.0, max)
+}
+
+/// # Set texture parameters
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+///
+/// # Associated Gets
+/// * [get_tex_parameter]
+/// * [get_tex_level_parameter]
+///
+/// # See Also
+/// * [active_texture]
+/// * [bind_texture]
+/// * [copy_tex_image_1d], [copy_tex_image_2d],
+/// * [copy_tex_sub_image_1d], [copy_tex_sub_image_2d], [copy_tex_sub_image_3d]
+/// * [pixel_store]
+/// * [sampler_parameter]
+/// * [tex_image_1d], [tex_image_2d], [tex_image_3d],
+/// * [tex_sub_image_1d], [tex_sub_image_2d], [tex_sub_image_3d]
+pub mod tex_parameter {
+    use super::{clamp_anisotropy, TEXTURE_MAX_ANISOTROPY};
+    use crate::prelude::*;
+    use gl::types::*;
+
+    fn tex_param_i32(target: TextureBindingTarget, pname: GLenum, param: i32) {
+        let target = GLenum::from(target);
+
+        // SAFE: synchronous integer copy
+        unsafe { gl::TexParameteri(target, pname, param) }
+    }
+
+    fn tex_param_f32(target: TextureBindingTarget, pname: GLenum, param: f32) {
+        let target = GLenum::from(target);
+
+        // SAFE: synchronous integer copy
+        unsafe { gl::TexParameterf(target, pname, param) }
+    }
+
+    /// Parameters are rejected with either [Error::InvalidEnum] or [Error::InvalidOperation] when
+    /// the value is not allowed for `target` (e.g. mipmap filtering of rectangle textures)
+    fn tex_param_error(target: TextureBindingTarget) -> Result<(), TextureError> {
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidEnum | Error::InvalidOperation => {
+                Err(TextureError::InvalidParameterForTarget(target))
+            }
+            other => Err(TextureError::Unexpected(other)),
+        }
+    }
+
+    /// # Set the mode used to read from depth-stencil format textures
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `mode` - Specifies the mode used to read from depth-stencil format textures.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_depth_stencil_texture_mode(
+    ///     TextureBindingTarget::Image2D,
+    ///     TextureDepthStencilMode::DepthComponent
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Specifies the mode used to read from depth-stencil format textures. If the depth stencil
+    /// mode is [TextureDepthStencilMode::DepthComponent], then reads from depth-stencil format
+    /// textures will return the depth component of the texel in R<sub>t</sub> and the stencil
+    /// component will be discarded. If the depth stencil mode is
+    /// [TextureDepthStencilMode::StencilIndex] then the stencil component is returned in R<sub>t</sub>
+    /// and the depth component is discarded. The initial value is
+    /// [TextureDepthStencilMode::DepthComponent].
+    ///
+    /// # Compatability
+    /// * requires 4.3
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_depth_stencil_texture_mode] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [tex_parameter]
+    pub fn texture_target_depth_stencil_texture_mode(
+        target: TextureBindingTarget,
+        mode: TextureDepthStencilMode,
+    ) {
+        let param = GLenum::from(mode) as i32;
+        tex_param_i32(target, gl::DEPTH_STENCIL_TEXTURE_MODE, param)
+    }
+
+    /// # Error mapped set depth stencil texture mode
+    /// see [texture_target_depth_stencil_texture_mode]
+    pub fn texture_target_depth_stencil_texture_mode_checked(
+        target: TextureBindingTarget,
+        mode: TextureDepthStencilMode,
+    ) -> Result<(), TextureError> {
+        texture_target_depth_stencil_texture_mode(target, mode);
+        tex_param_error(target)
+    }
+
+    /// # Set the index of the lowest defined mipmap level
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `level` - Specifies the index of the lowest defined mipmap level.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_base_level(TextureBindingTarget::Image2D, 42);
+    /// ```
+    ///
+    /// # Description
+    /// Specifies the index of the lowest defined mipmap level. The initial value is 0.
+    ///
+    /// # Errors
+    /// * [Error::InvalidOperation] - if the effective target is [TextureBindingTarget::Rectangle],
+    /// [TextureBindingTarget::Multisample2D] or [TextureBindingTarget::Multisample2DArray], and
+    /// `level` is a value other than zero.
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_base_level] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [tex_parameter]
+    pub fn texture_target_base_level(target: TextureBindingTarget, level: u32) {
+        let param = level as i32;
+        tex_param_i32(target, gl::TEXTURE_BASE_LEVEL, param)
+    }
+
+    /// # Error mapped set base level
+    /// see [texture_target_base_level]
+    pub fn texture_target_base_level_checked(
+        target: TextureBindingTarget,
+        level: u32,
+    ) -> Result<(), TextureError> {
+        texture_target_base_level(target, level);
+        tex_param_error(target)
+    }
+
+    /// # Set the index of the highest defined mipmap level
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `level` - Specifies the index of the highest defined mipmap level.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_max_level(TextureBindingTarget::Image2D, 4);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the index of the highest defined mipmap level. Levels above `level` are ignored when
+    /// checking for completeness and when sampling, so a texture with only some of its mipmap
+    /// levels defined can still be complete. The initial value is 1000.
+    ///
+    /// # Associated Gets
+    /// * [get_texture_target_max_level]
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_max_level] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [texture_target_base_level]
+    /// * [tex_parameter]
+    pub fn texture_target_max_level(target: TextureBindingTarget, level: u32) {
+        let param = level as i32;
+        tex_param_i32(target, gl::TEXTURE_MAX_LEVEL, param)
+    }
+
+    /// # Error mapped set max level
+    /// see [texture_target_max_level]
+    pub fn texture_target_max_level_checked(
+        target: TextureBindingTarget,
+        level: u32,
+    ) -> Result<(), TextureError> {
+        texture_target_max_level(target, level);
+        tex_param_error(target)
+    }
+
+    /// # Set a texel that should be used for border texels
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `texel` - Specifies border values that should be used for border texels
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_border_colour_f32(TextureBindingTarget::Image2D, [0.5, 0.9, 0.2, 1.0]);
+    /// texture_target_border_colour_i32(TextureBindingTarget::Image2D, [128, 250, 12, 255]);
+    /// texture_target_border_colour_u32(TextureBindingTarget::Image2D, [42, 7, 11, 255]);
+    /// texture_target_border_colour_i32_to_f32(TextureBindingTarget::Image2D, [0, 0, 0, 0]);
+    /// ```
+    ///
+    /// # Description
+    /// `texel` specifies four values that define the border values that should be used for border
+    /// texels. If a texel is sampled from the border of the texture, the values are interpreted as
+    /// an RGBA color to match the texture's internal format and substituted for the non-existent
+    /// texel data. If the texture contains depth components, the first component is interpreted as
+    /// a depth value. The initial value is `[0.0, 0.0, 0.0, 0.0]`.
+    ///
+    /// The `texel` is stored unmodified as floating-point values.
+    ///
+    /// # Compatability
+    ///
+    /// # Errors
+    ///
+    /// # Associated Gets
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_border_colour_f32] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    /// | [texture_target_border_colour_i32] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    /// | [texture_target_border_colour_u32] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    /// | [texture_target_border_colour_i32_to_f32] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [tex_parameter]
+    pub fn texture_target_border_colour_f32(target: TextureBindingTarget, texel: [f32; 4]) {
+        let target = GLenum::from(target);
+        let params = texel.as_ptr();
+
+        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
+        // no memory is retained
+        unsafe { gl::TexParameterfv(target, gl::TEXTURE_BORDER_COLOR, params) }
+    }
+
+    /// # Error mapped set border colour
+    /// see [texture_target_border_colour_f32]
+    pub fn texture_target_border_colour_f32_checked(
+        target: TextureBindingTarget,
+        texel: [f32; 4],
+    ) -> Result<(), TextureError> {
+        texture_target_border_colour_f32(target, texel);
+        tex_param_error(target)
+    }
+
+    /// # Set a texel that should be used for border texels
+    /// see [texture_target_border_colour_f32]
+    ///
+    /// # Description
+    /// The `texel` they are converted to floating point with the following equation:
+    /// `f = (2c + 1) / (2^b - 1)`
+    pub fn texture_target_border_colour_i32_to_f32(target: TextureBindingTarget, texel: [i32; 4]) {
+        let target = GLenum::from(target);
+        let params = texel.as_ptr();
+
+        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
+        // no memory is retained
+        unsafe { gl::TexParameteriv(target, gl::TEXTURE_BORDER_COLOR, params) }
+    }
+
+    /// # Error mapped set normalised integer border colour
+    /// see [texture_target_border_colour_i32_to_f32]
+    pub fn texture_target_border_colour_i32_to_f32_checked(
+        target: TextureBindingTarget,
+        texel: [i32; 4],
+    ) -> Result<(), TextureError> {
+        texture_target_border_colour_i32_to_f32(target, texel);
+        tex_param_error(target)
+    }
+
+    /// # Set a texel that should be used for border texels
+    /// see [texture_target_border_colour_f32]
+    ///
+    /// # Description
+    /// The `texel` is stored unmodified with an internal data type of i32.
+    pub fn texture_target_border_colour_i32(target: TextureBindingTarget, texel: [i32; 4]) {
+        let target = GLenum::from(target);
+        let params = texel.as_ptr();
+
+        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
+        // no memory is retained
+        unsafe { gl::TexParameterIiv(target, gl::TEXTURE_BORDER_COLOR, params) }
+    }
+
+    /// # Error mapped set integer border colour
+    /// see [texture_target_border_colour_i32]
+    pub fn texture_target_border_colour_i32_checked(
+        target: TextureBindingTarget,
+        texel: [i32; 4],
+    ) -> Result<(), TextureError> {
+        texture_target_border_colour_i32(target, texel);
+        tex_param_error(target)
+    }
+
+    /// # Set a texel that should be used for border texels
+    /// see [texture_target_border_colour_f32]
+    ///
+    /// # Description
+    /// The `texel` is stored unmodified with an internal data type of u32.
+    pub fn texture_target_border_colour_u32(target: TextureBindingTarget, texel: [u32; 4]) {
+        let target = GLenum::from(target);
+        let params = texel.as_ptr();
+
+        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
+        // no memory is retained
+        unsafe { gl::TexParameterIuiv(target, gl::TEXTURE_BORDER_COLOR, params) }
+    }
+
+    /// # Error mapped set unsigned integer border colour
+    /// see [texture_target_border_colour_u32]
+    pub fn texture_target_border_colour_u32_checked(
+        target: TextureBindingTarget,
+        texel: [u32; 4],
+    ) -> Result<(), TextureError> {
+        texture_target_border_colour_u32(target, texel);
+        tex_param_error(target)
+    }
+
+    /// # Set the comparison operator for textures
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `func` - comparison operator
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_compare_func(TextureBindingTarget::Image2D, CompareFunc::LessOrEqual);
+    /// ```
+    ///
+    /// # Description
+    /// Specifies the comparison operator used when [texture_target_compare_mode] is set to
+    /// [TextureCompareMode::Ref].
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_compare_func] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [tex_parameter]
+    pub fn texture_target_compare_func(target: TextureBindingTarget, func: CompareFunc) {
+        let param = GLenum::from(func) as i32;
+        tex_param_i32(target, gl::TEXTURE_COMPARE_FUNC, param)
+    }
+
+    /// # Error mapped set compare function
+    /// see [texture_target_compare_func]
+    pub fn texture_target_compare_func_checked(
+        target: TextureBindingTarget,
+        func: CompareFunc,
+    ) -> Result<(), TextureError> {
+        texture_target_compare_func(target, func);
+        tex_param_error(target)
+    }
+
+    /// # Set the texture comparion mode
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `mode` - comparison mode
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_compare_mode(TextureBindingTarget::Image2D, TextureCompareMode::Ref);
+    /// ```
+    ///
+    /// # Description
+    /// Specifies the texture comparison mode for currently bound depth textures. That is, a texture
+    /// whose internal format is [TextureInternalFormat::DepthComponent] (or similar); (see
+    /// [tex_image_2d]).
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_compare_mode] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [tex_parameter]
+    pub fn texture_target_compare_mode(target: TextureBindingTarget, mode: TextureCompareMode) {
+        let param = GLenum::from(mode) as i32;
+        tex_param_i32(target, gl::TEXTURE_COMPARE_MODE, param)
+    }
+
+    /// # Error mapped set compare mode
+    /// see [texture_target_compare_mode]
+    pub fn texture_target_compare_mode_checked(
+        target: TextureBindingTarget,
+        mode: TextureCompareMode,
+    ) -> Result<(), TextureError> {
+        texture_target_compare_mode(target, mode);
+        tex_param_error(target)
+    }
+
+    /// # Set the fixed bias for the level-of-detail
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `bias` - floating point lod bias
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_lod_bias(TextureBindingTarget::Image2D, 42.7);
+    /// ```
+    ///
+    /// # Description
+    /// `bias` specifies a fixed bias value that is to be added to the level-of-detail parameter for
+    /// the texture before texture sampling. The specified value is added to the shader-supplied
+    /// bias value (if any) and subsequently clamped into the implementation-defined range
+    /// \[−bias<sub>max</sub>, bias<sub>max</sub>\], where bias<sub>max</sub> is the value of the
+    /// implementation defined [get_texture_lod_bias]. The initial value is 0.0.
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_lod_bias] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [tex_parameter]
+    pub fn texture_target_lod_bias(target: TextureBindingTarget, bias: f32) {
+        tex_param_f32(target, gl::TEXTURE_LOD_BIAS, bias)
+    }
+
+    /// # Error mapped set level of detail bias
+    /// see [texture_target_lod_bias]
+    pub fn texture_target_lod_bias_checked(
+        target: TextureBindingTarget,
+        bias: f32,
+    ) -> Result<(), TextureError> {
+        texture_target_lod_bias(target, bias);
+        tex_param_error(target)
+    }
+
+    /// # Set the minimum level-of-detail
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `lod` - Specifies the minimum level-of-detail
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_min_lod(TextureBindingTarget::Image2D, 2.0);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the minimum level-of-detail parameter. This floating-point value limits the selection of
+    /// the highest resolution mipmap (lowest mipmap level). The initial value is -1000.
+    ///
+    /// # Associated Gets
+    /// * [get_texture_target_min_lod]
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_min_lod] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [texture_target_max_lod]
+    /// * [tex_parameter]
+    pub fn texture_target_min_lod(target: TextureBindingTarget, lod: f32) {
+        tex_param_f32(target, gl::TEXTURE_MIN_LOD, lod)
+    }
+
+    /// # Error mapped set minimum level of detail
+    /// see [texture_target_min_lod]
+    pub fn texture_target_min_lod_checked(
+        target: TextureBindingTarget,
+        lod: f32,
+    ) -> Result<(), TextureError> {
+        texture_target_min_lod(target, lod);
+        tex_param_error(target)
+    }
+
+    /// # Set the maximum level-of-detail
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `lod` - Specifies the maximum level-of-detail
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_max_lod(TextureBindingTarget::Image2D, 2.0);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the maximum level-of-detail parameter. This floating-point value limits the selection of
+    /// the lowest resolution mipmap (highest mipmap level). The initial value is 1000.
+    ///
+    /// # Associated Gets
+    /// * [get_texture_target_max_lod]
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_max_lod] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [texture_target_min_lod]
+    /// * [tex_parameter]
+    pub fn texture_target_max_lod(target: TextureBindingTarget, lod: f32) {
+        tex_param_f32(target, gl::TEXTURE_MAX_LOD, lod)
+    }
+
+    /// # Error mapped set maximum level of detail
+    /// see [texture_target_max_lod]
+    pub fn texture_target_max_lod_checked(
+        target: TextureBindingTarget,
+        lod: f32,
+    ) -> Result<(), TextureError> {
+        texture_target_max_lod(target, lod);
+        tex_param_error(target)
+    }
+
+    /// # Set the minifying function
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `filter` - Specifies the minifying function
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_min_filter(TextureBindingTarget::Image2D, TextureMinFilter::Nearest);
+    /// ```
+    ///
+    /// # Description
+    /// The texture minifying function is used whenever the level-of-detail function used when
+    /// sampling from the texture determines that the texture should be minified. There are six
+    /// defined minifying functions. Two of them use either the nearest texture elements or a
+    /// weighted average of multiple texture elements to compute the texture value. The other four
+    /// use mipmaps. See [TextureMinFilter]
+    ///
+    /// A mipmap is an ordered set of arrays representing the same image at progressively lower
+    /// resolutions. If the texture has dimensions 2<sup>n</sup>×2<sup>m</sup>, there are
+    /// `max(n,m) + 1` mipmaps. The first mipmap is the original texture, with dimensions
+    /// 2<sup>n</sup>×2<sup>m</sup>. Each subsequent mipmap has dimensions
+    /// 2<sup>k−1</sup>×2<sup>l−1</sup>, where 2<sup>k</sup>×2<sup>l</sup> are the dimensions of the
+    /// previous mipmap, until either `k=0` or `l=0`. At that point, subsequent mipmaps have
+    /// dimension 1×2<sup>l−1</sup> or 2<sup>k−1</sup>×1 until the final mipmap, which has dimension
+    /// `1×1`. To define the mipmaps, call [tex_image_1d], [tex_image_2d], [tex_image_3d],
+    /// [copy_tex_image_1d], or [copy_tex_image_2d] with the level argument indicating the order of
+    /// the mipmaps. Level 0 is the original texture; level `max(n,m)` is the final `1×1` mipmap.
+    ///
+    /// Suppose that a program attempts to sample from a texture and has set
+    /// [texture_target_min_filter] to one of the functions that requires a mipmap. If either the
+    /// dimensions of the texture images currently defined do not follow the proper sequence for
+    /// mipmaps (described above), or there are fewer texture images defined than are needed, or the
+    /// set of texture images have differing numbers of texture components, then the texture is
+    /// considered incomplete.
+    ///
+    /// Linear filtering accesses the four nearest texture elements only in 2D textures. In 1D
+    /// textures, linear filtering accesses the two nearest texture elements. In 3D textures, linear
+    /// filtering accesses the eight nearest texture elements.
+    ///
+    /// # Errors
+    /// * [Error::InvalidEnum] - if `target` is [TextureBindingTarget::Rectangle] and `filter` is
+    /// *not* one of: [TextureMinFilter::Nearest], [TextureMinFilter::Linear] (no mipmap filtering
+    /// is permitted).
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_min_filter] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [tex_parameter]
+    pub fn texture_target_min_filter(target: TextureBindingTarget, filter: TextureMinFilter) {
+        let param = GLenum::from(filter) as i32;
+        tex_param_i32(target, gl::TEXTURE_MIN_FILTER, param)
+    }
+
+    /// # Error mapped set minifying filter
+    /// see [texture_target_min_filter]
+    pub fn texture_target_min_filter_checked(
+        target: TextureBindingTarget,
+        filter: TextureMinFilter,
+    ) -> Result<(), TextureError> {
+        texture_target_min_filter(target, filter);
+        tex_param_error(target)
+    }
+
+    /// # Set magnification function
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `filter` - Specifies the magnification function
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_mag_filter(TextureBindingTarget::Image2D, TextureMagFilter::Linear);
+    /// ```
+    ///
+    /// # Description
+    /// The texture magnification function is used whenever the level-of-detail function used when
+    /// sampling from the texture determines that the texture should be magnified.
+    /// [TextureMagFilter::Nearest] is generally faster than [TextureMagFilter::Linear], but it can
+    /// produce textured images with sharper edges because the transition between texture elements
+    /// is not as smooth. The initial value is [TextureMagFilter::Linear].
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_mag_filter] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [tex_parameter]
+    pub fn texture_target_mag_filter(target: TextureBindingTarget, filter: TextureMagFilter) {
+        let param = GLenum::from(filter) as i32;
+        tex_param_i32(target, gl::TEXTURE_MAG_FILTER, param)
+    }
+
+    /// # Error mapped set magnification filter
+    /// see [texture_target_mag_filter]
+    pub fn texture_target_mag_filter_checked(
+        target: TextureBindingTarget,
+        filter: TextureMagFilter,
+    ) -> Result<(), TextureError> {
+        texture_target_mag_filter(target, filter);
+        tex_param_error(target)
+    }
+
+    /// # Set the maximum degree of anisotropy
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `anisotropy` - Specifies the maximum degree of anisotropy
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_max_anisotropy(TextureBindingTarget::Image2D, 16.0);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the maximum degree of anisotropy used when sampling the texture. Sampling takes up to
+    /// `anisotropy` samples along the major axis of the footprint of a pixel, which keeps surfaces
+    /// viewed at a steep angle sharp. The initial value is 1.0, which disables anisotropic
+    /// filtering.
+    ///
+    /// `anisotropy` is clamped to the range \[1.0, [get_max_texture_max_anisotropy]\] before it is
+    /// set, so the same value can be requested regardless of the implementation limit.
+    ///
+    /// # Compatability
+    /// * 4.6 - or `ARB_texture_filter_anisotropic` / `EXT_texture_filter_anisotropic`
+    ///
+    /// # Errors
+    /// * [Error::InvalidEnum] - if anisotropic filtering is not supported.
+    ///
+    /// # Associated Gets
+    /// * [get_texture_target_max_anisotropy]
+    /// * [get_max_texture_max_anisotropy]
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_max_anisotropy] | N | N | N | N | N | N | N | N | N | N | N | N |
+    ///
+    /// # See Also
+    /// * [tex_parameter]
+    pub fn texture_target_max_anisotropy(target: TextureBindingTarget, anisotropy: f32) {
+        let anisotropy = clamp_anisotropy(anisotropy);
+        tex_param_f32(target, TEXTURE_MAX_ANISOTROPY, anisotropy)
+    }
+
+    /// # Error mapped set max anisotropy
+    /// see [texture_target_max_anisotropy]
+    pub fn texture_target_max_anisotropy_checked(
+        target: TextureBindingTarget,
+        anisotropy: f32,
+    ) -> Result<(), TextureError> {
+        texture_target_max_anisotropy(target, anisotropy);
+        tex_param_error(target)
+    }
+
+    /// # Set the source of the red component
+    /// see [texture_target_swizzle_rgba]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_swizzle_r(TextureBindingTarget::Image2D, TextureSwizzle::Red);
+    /// ```
+    pub fn texture_target_swizzle_r(target: TextureBindingTarget, swizzle: TextureSwizzle) {
+        let param = GLenum::from(swizzle) as i32;
+        tex_param_i32(target, gl::TEXTURE_SWIZZLE_R, param)
+    }
+
+    /// # Error mapped set red swizzle
+    /// see [texture_target_swizzle_r]
+    pub fn texture_target_swizzle_r_checked(
+        target: TextureBindingTarget,
+        swizzle: TextureSwizzle,
+    ) -> Result<(), TextureError> {
+        texture_target_swizzle_r(target, swizzle);
+        tex_param_error(target)
+    }
+
+    /// # Set the source of the green component
+    /// see [texture_target_swizzle_rgba]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_swizzle_g(TextureBindingTarget::Image2D, TextureSwizzle::Red);
+    /// ```
+    pub fn texture_target_swizzle_g(target: TextureBindingTarget, swizzle: TextureSwizzle) {
+        let param = GLenum::from(swizzle) as i32;
+        tex_param_i32(target, gl::TEXTURE_SWIZZLE_G, param)
+    }
+
+    /// # Error mapped set green swizzle
+    /// see [texture_target_swizzle_g]
+    pub fn texture_target_swizzle_g_checked(
+        target: TextureBindingTarget,
+        swizzle: TextureSwizzle,
+    ) -> Result<(), TextureError> {
+        texture_target_swizzle_g(target, swizzle);
+        tex_param_error(target)
+    }
+
+    /// # Set the source of the blue component
+    /// see [texture_target_swizzle_rgba]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_swizzle_b(TextureBindingTarget::Image2D, TextureSwizzle::Red);
+    /// ```
+    pub fn texture_target_swizzle_b(target: TextureBindingTarget, swizzle: TextureSwizzle) {
+        let param = GLenum::from(swizzle) as i32;
+        tex_param_i32(target, gl::TEXTURE_SWIZZLE_B, param)
+    }
+
+    /// # Error mapped set blue swizzle
+    /// see [texture_target_swizzle_b]
+    pub fn texture_target_swizzle_b_checked(
+        target: TextureBindingTarget,
+        swizzle: TextureSwizzle,
+    ) -> Result<(), TextureError> {
+        texture_target_swizzle_b(target, swizzle);
+        tex_param_error(target)
+    }
+
+    /// # Set the source of the alpha component
+    /// see [texture_target_swizzle_rgba]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_swizzle_a(TextureBindingTarget::Image2D, TextureSwizzle::One);
+    /// ```
+    pub fn texture_target_swizzle_a(target: TextureBindingTarget, swizzle: TextureSwizzle) {
+        let param = GLenum::from(swizzle) as i32;
+        tex_param_i32(target, gl::TEXTURE_SWIZZLE_A, param)
+    }
+
+    /// # Error mapped set alpha swizzle
+    /// see [texture_target_swizzle_a]
+    pub fn texture_target_swizzle_a_checked(
+        target: TextureBindingTarget,
+        swizzle: TextureSwizzle,
+    ) -> Result<(), TextureError> {
+        texture_target_swizzle_a(target, swizzle);
+        tex_param_error(target)
+    }
+
+    /// # Set the sources of all components
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `swizzle` - Specifies the sources of the red, green, blue and alpha components
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// // present a single channel texture as greyscale
+    /// use TextureSwizzle::*;
+    /// texture_target_swizzle_rgba(TextureBindingTarget::Image2D, [Red, Red, Red, One]);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the components that will be returned to the shader for the red, green, blue and alpha
+    /// components of a texel, in that order. Each component is sourced from one of the components
+    /// of the texel, after the internal format is expanded to RGBA, or from the constants 0 or 1.
+    /// This allows single and two channel textures to be sampled as greyscale or luminance-alpha
+    /// images without changing the shader. The initial value is `[Red, Green, Blue, Alpha]`.
+    ///
+    /// # Compatability
+    /// * 3.3 - [texture_target_swizzle_rgba]
+    ///
+    /// # Associated Gets
+    /// * [get_texture_target_swizzle_rgba]
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_swizzle_r] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+    /// | [texture_target_swizzle_g] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+    /// | [texture_target_swizzle_b] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+    /// | [texture_target_swizzle_a] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+    /// | [texture_target_swizzle_rgba] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [TextureSwizzle]
+    /// * [tex_parameter]
+    pub fn texture_target_swizzle_rgba(target: TextureBindingTarget, swizzle: [TextureSwizzle; 4]) {
+        let target = GLenum::from(target);
+        let params = swizzle.map(|component| GLenum::from(component) as i32);
+
+        // SAFE: TEXTURE_SWIZZLE_RGBA expects an array size of 4, and is synchronously read, no
+        // memory is retained
+        unsafe { gl::TexParameteriv(target, gl::TEXTURE_SWIZZLE_RGBA, params.as_ptr()) }
+    }
+
+    /// # Error mapped set swizzle
+    /// see [texture_target_swizzle_rgba]
+    pub fn texture_target_swizzle_rgba_checked(
+        target: TextureBindingTarget,
+        swizzle: [TextureSwizzle; 4],
+    ) -> Result<(), TextureError> {
+        texture_target_swizzle_rgba(target, swizzle);
+        tex_param_error(target)
+    }
+
+    /// # Set the wrap value of the `s` coordinate
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `mode` - Specifies the texture wrap mode
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_wrap_s(TextureBindingTarget::Image2D, TextureWrapMode::Repeat);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the wrap parameter for texture coordinate `s`. Initially set to
+    /// [TextureWrapMode::Repeat].
+    ///
+    /// # Compatability
+    /// * 4.4 - [TextureWrapMode::MirrorClampToEdge]
+    ///
+    /// # Errors
+    /// * [Error::InvalidEnum] - if `target` is [TextureBindingTarget::Rectangle] and `mode` is one
+    /// of: [TextureWrapMode::MirrorClampToEdge], [TextureWrapMode::MirroredRepeat],
+    /// [TextureWrapMode::Repeat].
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_wrap_s] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [TextureWrapMode]
+    /// * [tex_parameter]
+    pub fn texture_target_wrap_s(target: TextureBindingTarget, mode: TextureWrapMode) {
+        let param = GLenum::from(mode) as i32;
+        tex_param_i32(target, gl::TEXTURE_WRAP_S, param)
+    }
+
+    /// # Error mapped set wrap `s`
+    /// see [texture_target_wrap_s]
+    pub fn texture_target_wrap_s_checked(
+        target: TextureBindingTarget,
+        mode: TextureWrapMode,
+    ) -> Result<(), TextureError> {
+        texture_target_wrap_s(target, mode);
+        tex_param_error(target)
+    }
+
+    /// # Set the wrap value of the `t` coordinate
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `mode` - Specifies the texture wrap mode
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_wrap_t(TextureBindingTarget::Image2D, TextureWrapMode::Repeat);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the wrap parameter for texture coordinate `t`. Initially set to
+    /// [TextureWrapMode::Repeat].
+    ///
+    /// # Compatability
+    /// * 4.4 - [TextureWrapMode::MirrorClampToEdge]
+    ///
+    /// # Errors
+    /// * [Error::InvalidEnum] - if `target` is [TextureBindingTarget::Rectangle] and `mode` is one
+    /// of: [TextureWrapMode::MirrorClampToEdge], [TextureWrapMode::MirroredRepeat],
+    /// [TextureWrapMode::Repeat].
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_wrap_t] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [TextureWrapMode]
+    /// * [tex_parameter]
+    pub fn texture_target_wrap_t(target: TextureBindingTarget, mode: TextureWrapMode) {
+        let param = GLenum::from(mode) as i32;
+        tex_param_i32(target, gl::TEXTURE_WRAP_T, param)
+    }
+
+    /// # Error mapped set wrap `t`
+    /// see [texture_target_wrap_t]
+    pub fn texture_target_wrap_t_checked(
+        target: TextureBindingTarget,
+        mode: TextureWrapMode,
+    ) -> Result<(), TextureError> {
+        texture_target_wrap_t(target, mode);
+        tex_param_error(target)
+    }
+
+    /// # Set the wrap value of the `r` coordinate
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    /// * `mode` - Specifies the texture wrap mode
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// texture_target_wrap_r(TextureBindingTarget::Image2D, TextureWrapMode::Repeat);
+    /// ```
+    ///
+    /// # Description
+    /// Sets the wrap parameter for texture coordinate `r`. Initially set to
+    /// [TextureWrapMode::Repeat].
+    ///
+    /// # Compatability
+    /// * 4.4 - [TextureWrapMode::MirrorClampToEdge]
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [texture_target_wrap_r] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [TextureWrapMode]
+    /// * [tex_parameter]
+    pub fn texture_target_wrap_r(target: TextureBindingTarget, mode: TextureWrapMode) {
+        let param = GLenum::from(mode) as i32;
+        tex_param_i32(target, gl::TEXTURE_WRAP_R, param)
+    }
+
+    /// # Error mapped set wrap `r`
+    /// see [texture_target_wrap_r]
+    pub fn texture_target_wrap_r_checked(
+        target: TextureBindingTarget,
+        mode: TextureWrapMode,
+    ) -> Result<(), TextureError> {
+        texture_target_wrap_r(target, mode);
+        tex_param_error(target)
+    }
+}
+pub use tex_parameter::*;
+
+/// # Set texture parameters of a texture object
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
+///
+/// # Description
+/// The direct state access equivalents of [tex_parameter]. Each function sets the same parameter as
+/// its `texture_target_*` counterpart, but of `texture` rather than of the texture bound to a
+/// target of the active texture unit, so no bindings are disturbed.
+///
+/// # Compatability
+/// * 4.5 - [texture_parameter]
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if `texture` is not the name of an existing texture object. Names
+/// from [gen_textures] only become texture objects once bound with [bind_texture].
+/// * [Error::InvalidEnum] / [Error::InvalidOperation] - if the value is not allowed for the
+/// effective target of `texture`.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [texture_parameter] | N | N | N | N | N | N | N | N | N | N | N | Y |
+///
+/// # See Also
+/// * [bind_texture]
+/// * [get_tex_parameter]
+/// * [tex_parameter]
+pub mod texture_parameter {
+    use super::{clamp_anisotropy, TEXTURE_MAX_ANISOTROPY};
+    use crate::prelude::*;
+    use gl::types::*;
+
+    fn texture_param_i32(texture: Texture, pname: GLenum, param: i32) {
+        // SAFE: synchronous integer copy
+        unsafe { gl::TextureParameteri(texture.0, pname, param) }
+    }
+
+    fn texture_param_f32(texture: Texture, pname: GLenum, param: f32) {
+        // SAFE: synchronous integer copy
+        unsafe { gl::TextureParameterf(texture.0, pname, param) }
+    }
+
+    /// [Error::InvalidOperation] is raised both for a missing texture and a value not allowed for
+    /// the target of the texture, these are told apart by checking the name
+    fn texture_param_error(texture: Texture) -> Result<(), TextureError> {
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidOperation if !is_texture(texture) => {
+                Err(TextureError::InvalidTexture(texture))
+            }
+            Error::InvalidEnum | Error::InvalidOperation => {
+                Err(TextureError::InvalidParameterForTexture(texture))
+            }
+            other => Err(TextureError::Unexpected(other)),
+        }
+    }
+
+    /// # Set the mode used to read from a depth-stencil format texture
+    /// see [texture_target_depth_stencil_texture_mode]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_depth_stencil_texture_mode(texture, TextureDepthStencilMode::StencilIndex);
+    /// ```
+    pub fn texture_parameter_depth_stencil_texture_mode(
+        texture: Texture,
+        mode: TextureDepthStencilMode,
+    ) {
+        let param = GLenum::from(mode) as i32;
+        texture_param_i32(texture, gl::DEPTH_STENCIL_TEXTURE_MODE, param)
+    }
+
+    /// # Error mapped set depth stencil texture mode
+    /// see [texture_parameter_depth_stencil_texture_mode]
+    pub fn texture_parameter_depth_stencil_texture_mode_checked(
+        texture: Texture,
+        mode: TextureDepthStencilMode,
+    ) -> Result<(), TextureError> {
+        texture_parameter_depth_stencil_texture_mode(texture, mode);
+        texture_param_error(texture)
+    }
+
+    /// # Set the index of the lowest defined mipmap level of a texture
+    /// see [texture_target_base_level]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_base_level(texture, 2);
+    /// ```
+    pub fn texture_parameter_base_level(texture: Texture, level: u32) {
+        texture_param_i32(texture, gl::TEXTURE_BASE_LEVEL, level as i32)
+    }
+
+    /// # Error mapped set base level
+    /// see [texture_parameter_base_level]
+    pub fn texture_parameter_base_level_checked(
+        texture: Texture,
+        level: u32,
+    ) -> Result<(), TextureError> {
+        texture_parameter_base_level(texture, level);
+        texture_param_error(texture)
+    }
+
+    /// # Set the index of the highest defined mipmap level of a texture
+    /// see [texture_target_max_level]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_max_level(texture, 4);
+    /// ```
+    pub fn texture_parameter_max_level(texture: Texture, level: u32) {
+        texture_param_i32(texture, gl::TEXTURE_MAX_LEVEL, level as i32)
+    }
+
+    /// # Error mapped set max level
+    /// see [texture_parameter_max_level]
+    pub fn texture_parameter_max_level_checked(
+        texture: Texture,
+        level: u32,
+    ) -> Result<(), TextureError> {
+        texture_parameter_max_level(texture, level);
+        texture_param_error(texture)
+    }
+
+    /// # Set the border colour of a texture
+    /// see [texture_target_border_colour_f32]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_border_colour_f32(texture, [0.5, 0.9, 0.2, 1.0]);
+    /// ```
+    pub fn texture_parameter_border_colour_f32(texture: Texture, texel: [f32; 4]) {
+        // SAFE: TEXTURE_BORDER_COLOR expects an array size of 4, and is synchronously read, no memory is
+        // retained
+        unsafe { gl::TextureParameterfv(texture.0, gl::TEXTURE_BORDER_COLOR, texel.as_ptr()) }
+    }
+
+    /// # Error mapped set border colour
+    /// see [texture_parameter_border_colour_f32]
+    pub fn texture_parameter_border_colour_f32_checked(
+        texture: Texture,
+        texel: [f32; 4],
+    ) -> Result<(), TextureError> {
+        texture_parameter_border_colour_f32(texture, texel);
+        texture_param_error(texture)
+    }
+
+    /// # Set the normalised integer border colour of a texture
+    /// see [texture_target_border_colour_i32_to_f32]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_border_colour_i32_to_f32(texture, [0, 0, 0, 0]);
+    /// ```
+    pub fn texture_parameter_border_colour_i32_to_f32(texture: Texture, texel: [i32; 4]) {
+        // SAFE: TEXTURE_BORDER_COLOR expects an array size of 4, and is synchronously read, no memory is
+        // retained
+        unsafe { gl::TextureParameteriv(texture.0, gl::TEXTURE_BORDER_COLOR, texel.as_ptr()) }
+    }
+
+    /// # Error mapped set normalised integer border colour
+    /// see [texture_parameter_border_colour_i32_to_f32]
+    pub fn texture_parameter_border_colour_i32_to_f32_checked(
+        texture: Texture,
+        texel: [i32; 4],
+    ) -> Result<(), TextureError> {
+        texture_parameter_border_colour_i32_to_f32(texture, texel);
+        texture_param_error(texture)
+    }
+
+    /// # Set the signed integer border colour of a texture
+    /// see [texture_target_border_colour_i32]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_border_colour_i32(texture, [128, 250, 12, 255]);
+    /// ```
+    pub fn texture_parameter_border_colour_i32(texture: Texture, texel: [i32; 4]) {
+        // SAFE: TEXTURE_BORDER_COLOR expects an array size of 4, and is synchronously read, no memory is
+        // retained
+        unsafe { gl::TextureParameterIiv(texture.0, gl::TEXTURE_BORDER_COLOR, texel.as_ptr()) }
+    }
+
+    /// # Error mapped set signed integer border colour
+    /// see [texture_parameter_border_colour_i32]
+    pub fn texture_parameter_border_colour_i32_checked(
+        texture: Texture,
+        texel: [i32; 4],
+    ) -> Result<(), TextureError> {
+        texture_parameter_border_colour_i32(texture, texel);
+        texture_param_error(texture)
+    }
+
+    /// # Set the unsigned integer border colour of a texture
+    /// see [texture_target_border_colour_u32]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_border_colour_u32(texture, [42, 7, 11, 255]);
+    /// ```
+    pub fn texture_parameter_border_colour_u32(texture: Texture, texel: [u32; 4]) {
+        // SAFE: TEXTURE_BORDER_COLOR expects an array size of 4, and is synchronously read, no memory is
+        // retained
+        unsafe { gl::TextureParameterIuiv(texture.0, gl::TEXTURE_BORDER_COLOR, texel.as_ptr()) }
+    }
+
+    /// # Error mapped set unsigned integer border colour
+    /// see [texture_parameter_border_colour_u32]
+    pub fn texture_parameter_border_colour_u32_checked(
+        texture: Texture,
+        texel: [u32; 4],
+    ) -> Result<(), TextureError> {
+        texture_parameter_border_colour_u32(texture, texel);
+        texture_param_error(texture)
+    }
+
+    /// # Set the comparison operator of a texture
+    /// see [texture_target_compare_func]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_compare_func(texture, CompareFunc::LessOrEqual);
+    /// ```
+    pub fn texture_parameter_compare_func(texture: Texture, func: CompareFunc) {
+        let param = GLenum::from(func) as i32;
+        texture_param_i32(texture, gl::TEXTURE_COMPARE_FUNC, param)
+    }
+
+    /// # Error mapped set compare function
+    /// see [texture_parameter_compare_func]
+    pub fn texture_parameter_compare_func_checked(
+        texture: Texture,
+        func: CompareFunc,
+    ) -> Result<(), TextureError> {
+        texture_parameter_compare_func(texture, func);
+        texture_param_error(texture)
+    }
+
+    /// # Set the comparison mode of a texture
+    /// see [texture_target_compare_mode]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_compare_mode(texture, TextureCompareMode::Ref);
+    /// ```
+    pub fn texture_parameter_compare_mode(texture: Texture, mode: TextureCompareMode) {
+        let param = GLenum::from(mode) as i32;
+        texture_param_i32(texture, gl::TEXTURE_COMPARE_MODE, param)
+    }
+
+    /// # Error mapped set compare mode
+    /// see [texture_parameter_compare_mode]
+    pub fn texture_parameter_compare_mode_checked(
+        texture: Texture,
+        mode: TextureCompareMode,
+    ) -> Result<(), TextureError> {
+        texture_parameter_compare_mode(texture, mode);
+        texture_param_error(texture)
+    }
+
+    /// # Set the fixed bias for the level-of-detail of a texture
+    /// see [texture_target_lod_bias]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_lod_bias(texture, 0.5);
+    /// ```
+    pub fn texture_parameter_lod_bias(texture: Texture, bias: f32) {
+        texture_param_f32(texture, gl::TEXTURE_LOD_BIAS, bias)
+    }
+
+    /// # Error mapped set level of detail bias
+    /// see [texture_parameter_lod_bias]
+    pub fn texture_parameter_lod_bias_checked(
+        texture: Texture,
+        bias: f32,
+    ) -> Result<(), TextureError> {
+        texture_parameter_lod_bias(texture, bias);
+        texture_param_error(texture)
+    }
+
+    /// # Set the minimum level-of-detail of a texture
+    /// see [texture_target_min_lod]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_min_lod(texture, 2.0);
+    /// ```
+    pub fn texture_parameter_min_lod(texture: Texture, lod: f32) {
+        texture_param_f32(texture, gl::TEXTURE_MIN_LOD, lod)
+    }
+
+    /// # Error mapped set minimum level of detail
+    /// see [texture_parameter_min_lod]
+    pub fn texture_parameter_min_lod_checked(
+        texture: Texture,
+        lod: f32,
+    ) -> Result<(), TextureError> {
+        texture_parameter_min_lod(texture, lod);
+        texture_param_error(texture)
+    }
+
+    /// # Set the maximum level-of-detail of a texture
+    /// see [texture_target_max_lod]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_max_lod(texture, 2.0);
+    /// ```
+    pub fn texture_parameter_max_lod(texture: Texture, lod: f32) {
+        texture_param_f32(texture, gl::TEXTURE_MAX_LOD, lod)
+    }
+
+    /// # Error mapped set maximum level of detail
+    /// see [texture_parameter_max_lod]
+    pub fn texture_parameter_max_lod_checked(
+        texture: Texture,
+        lod: f32,
+    ) -> Result<(), TextureError> {
+        texture_parameter_max_lod(texture, lod);
+        texture_param_error(texture)
+    }
+
+    /// # Set the minifying function of a texture
+    /// see [texture_target_min_filter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_min_filter(texture, TextureMinFilter::Nearest);
+    /// ```
+    pub fn texture_parameter_min_filter(texture: Texture, filter: TextureMinFilter) {
+        let param = GLenum::from(filter) as i32;
+        texture_param_i32(texture, gl::TEXTURE_MIN_FILTER, param)
+    }
+
+    /// # Error mapped set minifying filter
+    /// see [texture_parameter_min_filter]
+    pub fn texture_parameter_min_filter_checked(
+        texture: Texture,
+        filter: TextureMinFilter,
+    ) -> Result<(), TextureError> {
+        texture_parameter_min_filter(texture, filter);
+        texture_param_error(texture)
+    }
+
+    /// # Set the magnification function of a texture
+    /// see [texture_target_mag_filter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_mag_filter(texture, TextureMagFilter::Nearest);
+    /// ```
+    pub fn texture_parameter_mag_filter(texture: Texture, filter: TextureMagFilter) {
+        let param = GLenum::from(filter) as i32;
+        texture_param_i32(texture, gl::TEXTURE_MAG_FILTER, param)
+    }
+
+    /// # Error mapped set magnification filter
+    /// see [texture_parameter_mag_filter]
+    pub fn texture_parameter_mag_filter_checked(
+        texture: Texture,
+        filter: TextureMagFilter,
+    ) -> Result<(), TextureError> {
+        texture_parameter_mag_filter(texture, filter);
+        texture_param_error(texture)
+    }
+
+    /// # Set the maximum degree of anisotropy of a texture
+    /// see [texture_target_max_anisotropy]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_max_anisotropy(texture, 16.0);
+    /// ```
+    pub fn texture_parameter_max_anisotropy(texture: Texture, anisotropy: f32) {
+        let anisotropy = clamp_anisotropy(anisotropy);
+        texture_param_f32(texture, TEXTURE_MAX_ANISOTROPY, anisotropy)
+    }
+
+    /// # Error mapped set max anisotropy
+    /// see [texture_parameter_max_anisotropy]
+    pub fn texture_parameter_max_anisotropy_checked(
+        texture: Texture,
+        anisotropy: f32,
+    ) -> Result<(), TextureError> {
+        texture_parameter_max_anisotropy(texture, anisotropy);
+        texture_param_error(texture)
+    }
+
+    /// # Set the source of the red component of a texture
+    /// see [texture_target_swizzle_r]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_swizzle_r(texture, TextureSwizzle::Zero);
+    /// ```
+    pub fn texture_parameter_swizzle_r(texture: Texture, swizzle: TextureSwizzle) {
+        let param = GLenum::from(swizzle) as i32;
+        texture_param_i32(texture, gl::TEXTURE_SWIZZLE_R, param)
+    }
+
+    /// # Error mapped set red swizzle
+    /// see [texture_parameter_swizzle_r]
+    pub fn texture_parameter_swizzle_r_checked(
+        texture: Texture,
+        swizzle: TextureSwizzle,
+    ) -> Result<(), TextureError> {
+        texture_parameter_swizzle_r(texture, swizzle);
+        texture_param_error(texture)
+    }
+
+    /// # Set the source of the green component of a texture
+    /// see [texture_target_swizzle_g]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_swizzle_g(texture, TextureSwizzle::Zero);
+    /// ```
+    pub fn texture_parameter_swizzle_g(texture: Texture, swizzle: TextureSwizzle) {
+        let param = GLenum::from(swizzle) as i32;
+        texture_param_i32(texture, gl::TEXTURE_SWIZZLE_G, param)
+    }
+
+    /// # Error mapped set green swizzle
+    /// see [texture_parameter_swizzle_g]
+    pub fn texture_parameter_swizzle_g_checked(
+        texture: Texture,
+        swizzle: TextureSwizzle,
+    ) -> Result<(), TextureError> {
+        texture_parameter_swizzle_g(texture, swizzle);
+        texture_param_error(texture)
+    }
+
+    /// # Set the source of the blue component of a texture
+    /// see [texture_target_swizzle_b]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_swizzle_b(texture, TextureSwizzle::Zero);
+    /// ```
+    pub fn texture_parameter_swizzle_b(texture: Texture, swizzle: TextureSwizzle) {
+        let param = GLenum::from(swizzle) as i32;
+        texture_param_i32(texture, gl::TEXTURE_SWIZZLE_B, param)
+    }
+
+    /// # Error mapped set blue swizzle
+    /// see [texture_parameter_swizzle_b]
+    pub fn texture_parameter_swizzle_b_checked(
+        texture: Texture,
+        swizzle: TextureSwizzle,
+    ) -> Result<(), TextureError> {
+        texture_parameter_swizzle_b(texture, swizzle);
+        texture_param_error(texture)
+    }
+
+    /// # Set the source of the alpha component of a texture
+    /// see [texture_target_swizzle_a]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_swizzle_a(texture, TextureSwizzle::Zero);
+    /// ```
+    pub fn texture_parameter_swizzle_a(texture: Texture, swizzle: TextureSwizzle) {
+        let param = GLenum::from(swizzle) as i32;
+        texture_param_i32(texture, gl::TEXTURE_SWIZZLE_A, param)
+    }
+
+    /// # Error mapped set alpha swizzle
+    /// see [texture_parameter_swizzle_a]
+    pub fn texture_parameter_swizzle_a_checked(
+        texture: Texture,
+        swizzle: TextureSwizzle,
+    ) -> Result<(), TextureError> {
+        texture_parameter_swizzle_a(texture, swizzle);
+        texture_param_error(texture)
+    }
+
+    /// # Set the sources of all components of a texture
+    /// see [texture_target_swizzle_rgba]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_swizzle_rgba(texture, [TextureSwizzle::Red; 4]);
+    /// ```
+    pub fn texture_parameter_swizzle_rgba(texture: Texture, swizzle: [TextureSwizzle; 4]) {
+        let params = swizzle.map(|component| GLenum::from(component) as i32);
+
+        // SAFE: TEXTURE_SWIZZLE_RGBA expects an array size of 4, and is synchronously read, no memory is
+        // retained
+        unsafe { gl::TextureParameteriv(texture.0, gl::TEXTURE_SWIZZLE_RGBA, params.as_ptr()) }
+    }
+
+    /// # Error mapped set swizzle
+    /// see [texture_parameter_swizzle_rgba]
+    pub fn texture_parameter_swizzle_rgba_checked(
+        texture: Texture,
+        swizzle: [TextureSwizzle; 4],
+    ) -> Result<(), TextureError> {
+        texture_parameter_swizzle_rgba(texture, swizzle);
+        texture_param_error(texture)
+    }
+
+    /// # Set the wrap value of the `s` coordinate of a texture
+    /// see [texture_target_wrap_s]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_wrap_s(texture, TextureWrapMode::ClampToEdge);
+    /// ```
+    pub fn texture_parameter_wrap_s(texture: Texture, mode: TextureWrapMode) {
+        let param = GLenum::from(mode) as i32;
+        texture_param_i32(texture, gl::TEXTURE_WRAP_S, param)
+    }
+
+    /// # Error mapped set wrap `s`
+    /// see [texture_parameter_wrap_s]
+    pub fn texture_parameter_wrap_s_checked(
+        texture: Texture,
+        mode: TextureWrapMode,
+    ) -> Result<(), TextureError> {
+        texture_parameter_wrap_s(texture, mode);
+        texture_param_error(texture)
+    }
+
+    /// # Set the wrap value of the `t` coordinate of a texture
+    /// see [texture_target_wrap_t]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_wrap_t(texture, TextureWrapMode::ClampToEdge);
+    /// ```
+    pub fn texture_parameter_wrap_t(texture: Texture, mode: TextureWrapMode) {
+        let param = GLenum::from(mode) as i32;
+        texture_param_i32(texture, gl::TEXTURE_WRAP_T, param)
+    }
+
+    /// # Error mapped set wrap `t`
+    /// see [texture_parameter_wrap_t]
+    pub fn texture_parameter_wrap_t_checked(
+        texture: Texture,
+        mode: TextureWrapMode,
+    ) -> Result<(), TextureError> {
+        texture_parameter_wrap_t(texture, mode);
+        texture_param_error(texture)
+    }
+
+    /// # Set the wrap value of the `r` coordinate of a texture
+    /// see [texture_target_wrap_r]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// # let texture = Texture::default();
+    /// texture_parameter_wrap_r(texture, TextureWrapMode::ClampToEdge);
+    /// ```
+    pub fn texture_parameter_wrap_r(texture: Texture, mode: TextureWrapMode) {
+        let param = GLenum::from(mode) as i32;
+        texture_param_i32(texture, gl::TEXTURE_WRAP_R, param)
+    }
+
+    /// # Error mapped set wrap `r`
+    /// see [texture_parameter_wrap_r]
+    pub fn texture_parameter_wrap_r_checked(
+        texture: Texture,
+        mode: TextureWrapMode,
+    ) -> Result<(), TextureError> {
+        texture_parameter_wrap_r(texture, mode);
+        texture_param_error(texture)
+    }
+}
+pub use texture_parameter::*;
+
+/// # Return texture parameter values
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetTexParameter.xhtml>
+///
+/// # Description
+/// Each getter returns the value of a single parameter of the texture bound to `target`, as set by
+/// the matching [tex_parameter] function. Enum valued parameters are returned as the same enums
+/// accepted by the setters, and an unknown value is returned as `None`.
+///
+/// # Compatability
+/// * 4.3 - [get_texture_target_depth_stencil_texture_mode]
+///
+/// # Errors
+/// * [Error::InvalidEnum] - if `target` is [TextureBindingTarget::Buffer], or is a multisample
+/// target and the parameter is a sampling parameter.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_tex_parameter] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [bind_texture]
+/// * [get_tex_level_parameter]
+/// * [tex_parameter]
+pub mod get_tex_parameter {
+    use super::TEXTURE_MAX_ANISOTROPY;
+    use crate::prelude::*;
+    use gl::types::*;
+
+    fn get_tex_param_i32(target: TextureBindingTarget, pname: GLenum) -> i32 {
+        let target = GLenum::from(target);
+        let mut param = 0;
+
+        // SAFE: synchronous write of a single integer, no memory retained
+        unsafe { gl::GetTexParameteriv(target, pname, &mut param) };
+        param
+    }
+
+    fn get_tex_param_f32(target: TextureBindingTarget, pname: GLenum) -> f32 {
+        let target = GLenum::from(target);
+        let mut param = 0.0;
+
+        // SAFE: synchronous write of a single float, no memory retained
+        unsafe { gl::GetTexParameterfv(target, pname, &mut param) };
+        param
+    }
+
+    /// Decode an enum valued parameter into the enum accepted by its [tex_parameter] setter
+    fn get_tex_param_enum<T: TryFrom<GLenum>>(
+        target: TextureBindingTarget,
+        pname: GLenum,
+    ) -> Option<T> {
+        let val = get_tex_param_i32(target, pname);
+        T::try_from(val as GLenum).ok()
+    }
+
+    /// Queries are rejected with [Error::InvalidEnum] when the parameter does not exist for
+    /// `target`
+    fn get_tex_param_error(target: TextureBindingTarget) -> Result<(), TextureError> {
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidEnum => Err(TextureError::InvalidParameterForTarget(target)),
+            other => Err(TextureError::Unexpected(other)),
+        }
+    }
+
+    /// # Return the lowest defined mipmap level of a texture
+    /// see [texture_target_base_level]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_texture_target_base_level(TextureBindingTarget::Image2D), 0);
+    /// ```
+    pub fn get_texture_target_base_level(target: TextureBindingTarget) -> u32 {
+        get_tex_param_i32(target, gl::TEXTURE_BASE_LEVEL) as u32
+    }
+
+    /// # Error mapped get lowest defined mipmap level
+    /// see [get_texture_target_base_level]
+    pub fn get_texture_target_base_level_checked(
+        target: TextureBindingTarget,
+    ) -> Result<u32, TextureError> {
+        let val = get_texture_target_base_level(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the highest defined mipmap level
+    /// see [get_tex_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_texture_target_max_level(TextureBindingTarget::Image2D), 1000);
+    /// ```
+    pub fn get_texture_target_max_level(target: TextureBindingTarget) -> u32 {
+        get_tex_param_i32(target, gl::TEXTURE_MAX_LEVEL) as u32
+    }
+
+    /// # Error mapped get highest defined mipmap level
+    /// see [get_texture_target_max_level]
+    pub fn get_texture_target_max_level_checked(
+        target: TextureBindingTarget,
+    ) -> Result<u32, TextureError> {
+        let val = get_texture_target_max_level(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the border colour of a texture
+    /// see [texture_target_border_colour_f32]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_texture_target_border_colour_f32(TextureBindingTarget::Image2D), [0.0; 4]);
+    /// ```
+    pub fn get_texture_target_border_colour_f32(target: TextureBindingTarget) -> [f32; 4] {
+        let target = GLenum::from(target);
+        let mut params = [0.0; 4];
+
+        // SAFE: TEXTURE_BORDER_COLOR writes an array size of 4, no memory retained
+        unsafe { gl::GetTexParameterfv(target, gl::TEXTURE_BORDER_COLOR, params.as_mut_ptr()) };
+        params
+    }
+
+    /// # Error mapped get border colour
+    /// see [get_texture_target_border_colour_f32]
+    pub fn get_texture_target_border_colour_f32_checked(
+        target: TextureBindingTarget,
+    ) -> Result<[f32; 4], TextureError> {
+        let val = get_texture_target_border_colour_f32(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the signed integer border colour of a texture
+    /// see [texture_target_border_colour_i32]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_texture_target_border_colour_i32(TextureBindingTarget::Image2D), [0; 4]);
+    /// ```
+    pub fn get_texture_target_border_colour_i32(target: TextureBindingTarget) -> [i32; 4] {
+        let target = GLenum::from(target);
+        let mut params = [0; 4];
+
+        // SAFE: TEXTURE_BORDER_COLOR writes an array size of 4, no memory retained
+        unsafe { gl::GetTexParameterIiv(target, gl::TEXTURE_BORDER_COLOR, params.as_mut_ptr()) };
+        params
+    }
+
+    /// # Error mapped get signed integer border colour
+    /// see [get_texture_target_border_colour_i32]
+    pub fn get_texture_target_border_colour_i32_checked(
+        target: TextureBindingTarget,
+    ) -> Result<[i32; 4], TextureError> {
+        let val = get_texture_target_border_colour_i32(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the unsigned integer border colour of a texture
+    /// see [texture_target_border_colour_u32]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_texture_target_border_colour_u32(TextureBindingTarget::Image2D), [0; 4]);
+    /// ```
+    pub fn get_texture_target_border_colour_u32(target: TextureBindingTarget) -> [u32; 4] {
+        let target = GLenum::from(target);
+        let mut params = [0; 4];
+
+        // SAFE: TEXTURE_BORDER_COLOR writes an array size of 4, no memory retained
+        unsafe { gl::GetTexParameterIuiv(target, gl::TEXTURE_BORDER_COLOR, params.as_mut_ptr()) };
+        params
+    }
+
+    /// # Error mapped get unsigned integer border colour
+    /// see [get_texture_target_border_colour_u32]
+    pub fn get_texture_target_border_colour_u32_checked(
+        target: TextureBindingTarget,
+    ) -> Result<[u32; 4], TextureError> {
+        let val = get_texture_target_border_colour_u32(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the comparison operator of a texture
+    /// see [texture_target_compare_func]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_compare_func(TextureBindingTarget::Image2D),
+    ///     Some(CompareFunc::LessOrEqual)
+    /// );
+    /// ```
+    pub fn get_texture_target_compare_func(target: TextureBindingTarget) -> Option<CompareFunc> {
+        get_tex_param_enum(target, gl::TEXTURE_COMPARE_FUNC)
+    }
+
+    /// # Error mapped get comparison operator
+    /// see [get_texture_target_compare_func]
+    pub fn get_texture_target_compare_func_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<CompareFunc>, TextureError> {
+        let val = get_texture_target_compare_func(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the comparison mode of a texture
+    /// see [texture_target_compare_mode]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_compare_mode(TextureBindingTarget::Image2D),
+    ///     Some(TextureCompareMode::None)
+    /// );
+    /// ```
+    pub fn get_texture_target_compare_mode(
+        target: TextureBindingTarget,
+    ) -> Option<TextureCompareMode> {
+        get_tex_param_enum(target, gl::TEXTURE_COMPARE_MODE)
+    }
+
+    /// # Error mapped get comparison mode
+    /// see [get_texture_target_compare_mode]
+    pub fn get_texture_target_compare_mode_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureCompareMode>, TextureError> {
+        let val = get_texture_target_compare_mode(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the depth stencil texture mode of a texture
+    /// see [texture_target_depth_stencil_texture_mode]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_depth_stencil_texture_mode(TextureBindingTarget::Image2D),
+    ///     Some(TextureDepthStencilMode::DepthComponent)
+    /// );
+    /// ```
+    pub fn get_texture_target_depth_stencil_texture_mode(
+        target: TextureBindingTarget,
+    ) -> Option<TextureDepthStencilMode> {
+        get_tex_param_enum(target, gl::DEPTH_STENCIL_TEXTURE_MODE)
+    }
+
+    /// # Error mapped get depth stencil texture mode
+    /// see [get_texture_target_depth_stencil_texture_mode]
+    pub fn get_texture_target_depth_stencil_texture_mode_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureDepthStencilMode>, TextureError> {
+        let val = get_texture_target_depth_stencil_texture_mode(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return whether the texture has immutable storage
+    /// see [tex_storage_2d]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert!(!get_texture_target_immutable_format(TextureBindingTarget::Image2D));
+    /// ```
+    ///
+    /// # Description
+    /// Returns true once the storage of the texture has been specified with [tex_storage_1d],
+    /// [tex_storage_2d] or [tex_storage_3d], after which its dimensions and internal format can no
+    /// longer change. The initial value is false.
+    pub fn get_texture_target_immutable_format(target: TextureBindingTarget) -> bool {
+        get_tex_param_i32(target, gl::TEXTURE_IMMUTABLE_FORMAT) != 0
+    }
+
+    /// # Error mapped get immutable format
+    /// see [get_texture_target_immutable_format]
+    pub fn get_texture_target_immutable_format_checked(
+        target: TextureBindingTarget,
+    ) -> Result<bool, TextureError> {
+        let val = get_texture_target_immutable_format(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the number of levels of immutable storage
+    /// see [tex_storage_2d]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_texture_target_immutable_levels(TextureBindingTarget::Image2D), 0);
+    /// ```
+    ///
+    /// # Description
+    /// Returns the `levels` given to [tex_storage_1d], [tex_storage_2d] or [tex_storage_3d], or 0
+    /// for a texture without immutable storage.
+    pub fn get_texture_target_immutable_levels(target: TextureBindingTarget) -> u32 {
+        get_tex_param_i32(target, gl::TEXTURE_IMMUTABLE_LEVELS) as u32
+    }
+
+    /// # Error mapped get immutable levels
+    /// see [get_texture_target_immutable_levels]
+    pub fn get_texture_target_immutable_levels_checked(
+        target: TextureBindingTarget,
+    ) -> Result<u32, TextureError> {
+        let val = get_texture_target_immutable_levels(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the level-of-detail bias of a texture
+    /// see [texture_target_lod_bias]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_texture_target_lod_bias(TextureBindingTarget::Image2D), 0.0);
+    /// ```
+    pub fn get_texture_target_lod_bias(target: TextureBindingTarget) -> f32 {
+        get_tex_param_f32(target, gl::TEXTURE_LOD_BIAS)
+    }
+
+    /// # Error mapped get level-of-detail bias
+    /// see [get_texture_target_lod_bias]
+    pub fn get_texture_target_lod_bias_checked(
+        target: TextureBindingTarget,
+    ) -> Result<f32, TextureError> {
+        let val = get_texture_target_lod_bias(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the minimum level-of-detail of a texture
+    /// see [texture_target_min_lod]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_texture_target_min_lod(TextureBindingTarget::Image2D), -1000.0);
+    /// ```
+    pub fn get_texture_target_min_lod(target: TextureBindingTarget) -> f32 {
+        get_tex_param_f32(target, gl::TEXTURE_MIN_LOD)
+    }
+
+    /// # Error mapped get minimum level of detail
+    /// see [get_texture_target_min_lod]
+    pub fn get_texture_target_min_lod_checked(
+        target: TextureBindingTarget,
+    ) -> Result<f32, TextureError> {
+        let val = get_texture_target_min_lod(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the maximum level-of-detail of a texture
+    /// see [texture_target_max_lod]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_texture_target_max_lod(TextureBindingTarget::Image2D), 1000.0);
+    /// ```
+    pub fn get_texture_target_max_lod(target: TextureBindingTarget) -> f32 {
+        get_tex_param_f32(target, gl::TEXTURE_MAX_LOD)
+    }
+
+    /// # Error mapped get maximum level of detail
+    /// see [get_texture_target_max_lod]
+    pub fn get_texture_target_max_lod_checked(
+        target: TextureBindingTarget,
+    ) -> Result<f32, TextureError> {
+        let val = get_texture_target_max_lod(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the maximum degree of anisotropy of a texture
+    /// see [texture_target_max_anisotropy]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(get_texture_target_max_anisotropy(TextureBindingTarget::Image2D), 1.0);
+    /// ```
+    pub fn get_texture_target_max_anisotropy(target: TextureBindingTarget) -> f32 {
+        get_tex_param_f32(target, TEXTURE_MAX_ANISOTROPY)
+    }
+
+    /// # Error mapped get max anisotropy
+    /// see [get_texture_target_max_anisotropy]
+    pub fn get_texture_target_max_anisotropy_checked(
+        target: TextureBindingTarget,
+    ) -> Result<f32, TextureError> {
+        let val = get_texture_target_max_anisotropy(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the minifying function of a texture
+    /// see [texture_target_min_filter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_min_filter(TextureBindingTarget::Image2D),
+    ///     Some(TextureMinFilter::NearestMipmapLinear)
+    /// );
+    /// ```
+    pub fn get_texture_target_min_filter(target: TextureBindingTarget) -> Option<TextureMinFilter> {
+        get_tex_param_enum(target, gl::TEXTURE_MIN_FILTER)
+    }
+
+    /// # Error mapped get minifying function
+    /// see [get_texture_target_min_filter]
+    pub fn get_texture_target_min_filter_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureMinFilter>, TextureError> {
+        let val = get_texture_target_min_filter(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the magnification function of a texture
+    /// see [texture_target_mag_filter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_mag_filter(TextureBindingTarget::Image2D),
+    ///     Some(TextureMagFilter::Linear)
+    /// );
+    /// ```
+    pub fn get_texture_target_mag_filter(target: TextureBindingTarget) -> Option<TextureMagFilter> {
+        get_tex_param_enum(target, gl::TEXTURE_MAG_FILTER)
+    }
+
+    /// # Error mapped get magnification function
+    /// see [get_texture_target_mag_filter]
+    pub fn get_texture_target_mag_filter_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureMagFilter>, TextureError> {
+        let val = get_texture_target_mag_filter(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the source of the red component of a texture
+    /// see [get_texture_target_swizzle_rgba]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_swizzle_r(TextureBindingTarget::Image2D),
+    ///     Some(TextureSwizzle::Red)
+    /// );
+    /// ```
+    pub fn get_texture_target_swizzle_r(target: TextureBindingTarget) -> Option<TextureSwizzle> {
+        get_tex_param_enum(target, gl::TEXTURE_SWIZZLE_R)
+    }
+
+    /// # Error mapped get red swizzle
+    /// see [get_texture_target_swizzle_r]
+    pub fn get_texture_target_swizzle_r_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureSwizzle>, TextureError> {
+        let val = get_texture_target_swizzle_r(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the source of the green component of a texture
+    /// see [get_texture_target_swizzle_rgba]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_swizzle_g(TextureBindingTarget::Image2D),
+    ///     Some(TextureSwizzle::Green)
+    /// );
+    /// ```
+    pub fn get_texture_target_swizzle_g(target: TextureBindingTarget) -> Option<TextureSwizzle> {
+        get_tex_param_enum(target, gl::TEXTURE_SWIZZLE_G)
+    }
+
+    /// # Error mapped get green swizzle
+    /// see [get_texture_target_swizzle_g]
+    pub fn get_texture_target_swizzle_g_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureSwizzle>, TextureError> {
+        let val = get_texture_target_swizzle_g(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the source of the blue component of a texture
+    /// see [get_texture_target_swizzle_rgba]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_swizzle_b(TextureBindingTarget::Image2D),
+    ///     Some(TextureSwizzle::Blue)
+    /// );
+    /// ```
+    pub fn get_texture_target_swizzle_b(target: TextureBindingTarget) -> Option<TextureSwizzle> {
+        get_tex_param_enum(target, gl::TEXTURE_SWIZZLE_B)
+    }
+
+    /// # Error mapped get blue swizzle
+    /// see [get_texture_target_swizzle_b]
+    pub fn get_texture_target_swizzle_b_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureSwizzle>, TextureError> {
+        let val = get_texture_target_swizzle_b(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the source of the alpha component of a texture
+    /// see [get_texture_target_swizzle_rgba]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_swizzle_a(TextureBindingTarget::Image2D),
+    ///     Some(TextureSwizzle::Alpha)
+    /// );
+    /// ```
+    pub fn get_texture_target_swizzle_a(target: TextureBindingTarget) -> Option<TextureSwizzle> {
+        get_tex_param_enum(target, gl::TEXTURE_SWIZZLE_A)
+    }
+
+    /// # Error mapped get alpha swizzle
+    /// see [get_texture_target_swizzle_a]
+    pub fn get_texture_target_swizzle_a_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureSwizzle>, TextureError> {
+        let val = get_texture_target_swizzle_a(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the sources of all components of a texture
+    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetTexParameter.xhtml>
+    ///
+    /// # Arguments
+    /// * `target` - Specifies the target to which the texture is bound
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// use TextureSwizzle::*;
+    /// assert_eq!(
+    ///     get_texture_target_swizzle_rgba(TextureBindingTarget::Image2D),
+    ///     Some([Red, Green, Blue, Alpha])
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Returns the swizzle that will be applied to the red, green, blue and alpha components of a
+    /// texel before it is returned to the shader, in that order. Each component is sourced from one
+    /// of the texel components, or from the constants 0 or 1. The initial value is
+    /// `[Red, Green, Blue, Alpha]`.
+    ///
     /// # Compatability
+    /// * 3.3 - [get_texture_target_swizzle_rgba]
+    ///
+    /// # Version Support
+    ///
+    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | [get_texture_target_swizzle_r] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+    /// | [get_texture_target_swizzle_g] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+    /// | [get_texture_target_swizzle_b] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+    /// | [get_texture_target_swizzle_a] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+    /// | [get_texture_target_swizzle_rgba] | N | N | N | N | N | Y | Y | Y | Y | Y | Y | Y |
+    ///
+    /// # See Also
+    /// * [get_tex_parameter]
+    pub fn get_texture_target_swizzle_rgba(
+        target: TextureBindingTarget,
+    ) -> Option<[TextureSwizzle; 4]> {
+        let gl_target = GLenum::from(target);
+        let mut params = [0; 4];
+
+        // SAFE: TEXTURE_SWIZZLE_RGBA writes an array size of 4, no memory retained
+        unsafe { gl::GetTexParameteriv(gl_target, gl::TEXTURE_SWIZZLE_RGBA, params.as_mut_ptr()) };
+        let mut swizzle = [TextureSwizzle::Zero; 4];
+        for (component, param) in swizzle.iter_mut().zip(params) {
+            *component = TextureSwizzle::try_from(param as GLenum).ok()?;
+        }
+        Some(swizzle)
+    }
+
+    /// # Error mapped get swizzle
+    /// see [get_texture_target_swizzle_rgba]
+    pub fn get_texture_target_swizzle_rgba_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<[TextureSwizzle; 4]>, TextureError> {
+        let val = get_texture_target_swizzle_rgba(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the wrap value of the `s` coordinate of a texture
+    /// see [texture_target_wrap_s]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_wrap_s(TextureBindingTarget::Image2D),
+    ///     Some(TextureWrapMode::Repeat)
+    /// );
+    /// ```
+    pub fn get_texture_target_wrap_s(target: TextureBindingTarget) -> Option<TextureWrapMode> {
+        get_tex_param_enum(target, gl::TEXTURE_WRAP_S)
+    }
+
+    /// # Error mapped get wrap value of the `s` coordinate
+    /// see [get_texture_target_wrap_s]
+    pub fn get_texture_target_wrap_s_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureWrapMode>, TextureError> {
+        let val = get_texture_target_wrap_s(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the wrap value of the `t` coordinate of a texture
+    /// see [texture_target_wrap_t]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_wrap_t(TextureBindingTarget::Image2D),
+    ///     Some(TextureWrapMode::Repeat)
+    /// );
+    /// ```
+    pub fn get_texture_target_wrap_t(target: TextureBindingTarget) -> Option<TextureWrapMode> {
+        get_tex_param_enum(target, gl::TEXTURE_WRAP_T)
+    }
+
+    /// # Error mapped get wrap value of the `t` coordinate
+    /// see [get_texture_target_wrap_t]
+    pub fn get_texture_target_wrap_t_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureWrapMode>, TextureError> {
+        let val = get_texture_target_wrap_t(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+
+    /// # Return the wrap value of the `r` coordinate of a texture
+    /// see [texture_target_wrap_r]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_texture_target_wrap_r(TextureBindingTarget::Image2D),
+    ///     Some(TextureWrapMode::Repeat)
+    /// );
+    /// ```
+    pub fn get_texture_target_wrap_r(target: TextureBindingTarget) -> Option<TextureWrapMode> {
+        get_tex_param_enum(target, gl::TEXTURE_WRAP_R)
+    }
+
+    /// # Error mapped get wrap value of the `r` coordinate
+    /// see [get_texture_target_wrap_r]
+    pub fn get_texture_target_wrap_r_checked(
+        target: TextureBindingTarget,
+    ) -> Result<Option<TextureWrapMode>, TextureError> {
+        let val = get_texture_target_wrap_r(target);
+        get_tex_param_error(target)?;
+        Ok(val)
+    }
+}
+pub use get_tex_parameter::*;
+
+/// # Return texture parameter values for a specific level of detail
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetTexLevelParameter.xhtml>
+///
+/// # Description
+/// Each getter returns a single parameter of the image at mipmap `level` of the texture bound to
+/// `target`. Level 0 is the base image level, and level `n` is the `n`th mipmap reduction image.
+///
+/// If `level` has no image, every size and dimension is 0, and the internal format is
+/// [TextureInternalFormat::R] (`RED`), or the base internal format the texture was created with.
+///
+/// The channel sizes are the actual resolution, in bits, of each component of the stored image,
+/// which may differ from the resolution implied by the requested internal format.
+///
+/// # Compatability
+/// * 3.0 - the channel sizes of depth and stencil components
+///
+/// # Errors
+/// * [Error::InvalidValue] - if `level` is greater than `log2(max)`, where `max` is the maximum
+/// texture size for `target`, or is not 0 for [TextureImageTarget::Rectangle].
+/// * [Error::InvalidOperation] - if [get_tex_level_compressed_image_size] is queried on an
+/// uncompressed image.
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_tex_level_parameter] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [bind_texture]
+/// * [get_tex_parameter]
+/// * [tex_image_1d], [tex_image_2d], [tex_image_3d]
+/// * [tex_storage_1d], [tex_storage_2d], [tex_storage_3d]
+pub mod get_tex_level_parameter {
+    use super::get_tex_level_parameter_i32;
+    use crate::prelude::*;
+    use gl::types::*;
+
+    fn get_tex_level_param_i32(target: TextureImageTarget, level: u32, pname: GLenum) -> i32 {
+        get_tex_level_parameter_i32(GLenum::from(target), level, pname)
+    }
+
+    fn tex_level_param_error(level: u32) -> Result<(), TextureError> {
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidValue => Err(TextureError::InvalidLevel(level)),
+            Error::InvalidOperation => Err(TextureError::UncompressedImage),
+            other => Err(TextureError::Unexpected(other)),
+        }
+    }
+
+    /// # Return the width of a texture image
+    /// see [get_tex_level_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let width = get_tex_level_width(TextureImageTarget::Image2D, 0);
+    /// ```
+    ///
+    /// # Description
+    /// Returns the width of the texture image, including any border. The initial value is 0.
+    pub fn get_tex_level_width(target: TextureImageTarget, level: u32) -> u32 {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_WIDTH) as u32
+    }
+
+    /// # Error mapped get width
+    /// see [get_tex_level_width]
+    pub fn get_tex_level_width_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<u32, TextureError> {
+        let val = get_tex_level_width(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the height of a texture image
+    /// see [get_tex_level_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let height = get_tex_level_height(TextureImageTarget::Image2D, 0);
+    /// ```
+    ///
+    /// # Description
+    /// Returns the height of the texture image, including any border, or 1 for one-dimensional
+    /// images. The initial value is 0.
+    pub fn get_tex_level_height(target: TextureImageTarget, level: u32) -> u32 {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_HEIGHT) as u32
+    }
+
+    /// # Error mapped get height
+    /// see [get_tex_level_height]
+    pub fn get_tex_level_height_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<u32, TextureError> {
+        let val = get_tex_level_height(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the depth of a texture image
+    /// see [get_tex_level_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let depth = get_tex_level_depth(TextureImageTarget::Image2D, 0);
+    /// ```
+    ///
+    /// # Description
+    /// Returns the depth of the texture image, or the number of layers of an array texture, or 1
+    /// for one and two-dimensional images. The initial value is 0.
+    pub fn get_tex_level_depth(target: TextureImageTarget, level: u32) -> u32 {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_DEPTH) as u32
+    }
+
+    /// # Error mapped get depth
+    /// see [get_tex_level_depth]
+    pub fn get_tex_level_depth_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<u32, TextureError> {
+        let val = get_tex_level_depth(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the internal format of a texture image
+    /// see [get_tex_level_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert_eq!(
+    ///     get_tex_level_internal_format(TextureImageTarget::Image2D, 0),
+    ///     Some(TextureInternalFormat::RGBA8)
+    /// );
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal format of the texture image, as given to [tex_image_2d] (or a similar
+    /// function) or [tex_storage_2d]. A format without a matching [TextureInternalFormat] is
+    /// returned as `None`.
+    pub fn get_tex_level_internal_format(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Option<TextureInternalFormat> {
+        let val = get_tex_level_param_i32(target, level, gl::TEXTURE_INTERNAL_FORMAT);
+        TextureInternalFormat::try_from(val as GLenum).ok()
+    }
+
+    /// # Error mapped get internal format
+    /// see [get_tex_level_internal_format]
+    pub fn get_tex_level_internal_format_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<Option<TextureInternalFormat>, TextureError> {
+        let val = get_tex_level_internal_format(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the compression of a texture image
+    /// see [get_tex_level_parameter]
     ///
-    /// # Errors
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// assert!(!get_tex_level_compressed(TextureImageTarget::Image2D, 0));
+    /// ```
     ///
-    /// # Associated Gets
+    /// # Description
+    /// Returns true if the texture image is stored in a compressed internal format.
+    pub fn get_tex_level_compressed(target: TextureImageTarget, level: u32) -> bool {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_COMPRESSED) != 0
+    }
+
+    /// # Error mapped get compression
+    /// see [get_tex_level_compressed]
+    pub fn get_tex_level_compressed_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<bool, TextureError> {
+        let val = get_tex_level_compressed(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the compressed size of a texture image
+    /// see [get_tex_level_parameter]
     ///
-    /// # Version Support
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let size = get_tex_level_compressed_image_size(TextureImageTarget::Image2D, 0);
+    /// ```
     ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_border_colour_f32] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    /// | [texture_target_border_colour_i32] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    /// | [texture_target_border_colour_u32] | N | N | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    /// | [texture_target_border_colour_i32_to_f32] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+    /// # Description
+    /// Returns the number of bytes of the compressed texture image that would be returned by
+    /// [get_compressed_tex_image].
+    pub fn get_tex_level_compressed_image_size(target: TextureImageTarget, level: u32) -> usize {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_COMPRESSED_IMAGE_SIZE) as usize
+    }
+
+    /// # Error mapped get compressed size
+    /// see [get_tex_level_compressed_image_size]
+    pub fn get_tex_level_compressed_image_size_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<usize, TextureError> {
+        let val = get_tex_level_compressed_image_size(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the red size of a texture image
+    /// see [get_tex_level_parameter]
     ///
-    /// # See Also
-    /// * [tex_parameter]
-    pub fn texture_target_border_colour_f32(target: TextureBindingTarget, texel: [f32; 4]) {
-        let target = GLenum::from(target);
-        let params = texel.as_ptr();
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_tex_level_red_size(TextureImageTarget::Image2D, 0);
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the red component of the texture image, or
+    /// 0 if the internal format has no red component.
+    pub fn get_tex_level_red_size(target: TextureImageTarget, level: u32) -> u32 {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_RED_SIZE) as u32
+    }
+
+    /// # Error mapped get red size
+    /// see [get_tex_level_red_size]
+    pub fn get_tex_level_red_size_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<u32, TextureError> {
+        let val = get_tex_level_red_size(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the green size of a texture image
+    /// see [get_tex_level_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_tex_level_green_size(TextureImageTarget::Image2D, 0);
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the green component of the texture image, or
+    /// 0 if the internal format has no green component.
+    pub fn get_tex_level_green_size(target: TextureImageTarget, level: u32) -> u32 {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_GREEN_SIZE) as u32
+    }
+
+    /// # Error mapped get green size
+    /// see [get_tex_level_green_size]
+    pub fn get_tex_level_green_size_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<u32, TextureError> {
+        let val = get_tex_level_green_size(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the blue size of a texture image
+    /// see [get_tex_level_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_tex_level_blue_size(TextureImageTarget::Image2D, 0);
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the blue component of the texture image, or
+    /// 0 if the internal format has no blue component.
+    pub fn get_tex_level_blue_size(target: TextureImageTarget, level: u32) -> u32 {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_BLUE_SIZE) as u32
+    }
+
+    /// # Error mapped get blue size
+    /// see [get_tex_level_blue_size]
+    pub fn get_tex_level_blue_size_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<u32, TextureError> {
+        let val = get_tex_level_blue_size(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the alpha size of a texture image
+    /// see [get_tex_level_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_tex_level_alpha_size(TextureImageTarget::Image2D, 0);
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the alpha component of the texture image, or
+    /// 0 if the internal format has no alpha component.
+    pub fn get_tex_level_alpha_size(target: TextureImageTarget, level: u32) -> u32 {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_ALPHA_SIZE) as u32
+    }
+
+    /// # Error mapped get alpha size
+    /// see [get_tex_level_alpha_size]
+    pub fn get_tex_level_alpha_size_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<u32, TextureError> {
+        let val = get_tex_level_alpha_size(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the depth size of a texture image
+    /// see [get_tex_level_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_tex_level_depth_size(TextureImageTarget::Image2D, 0);
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the depth component of the texture image, or
+    /// 0 if the internal format has no depth component.
+    pub fn get_tex_level_depth_size(target: TextureImageTarget, level: u32) -> u32 {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_DEPTH_SIZE) as u32
+    }
+
+    /// # Error mapped get depth size
+    /// see [get_tex_level_depth_size]
+    pub fn get_tex_level_depth_size_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<u32, TextureError> {
+        let val = get_tex_level_depth_size(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+
+    /// # Return the stencil size of a texture image
+    /// see [get_tex_level_parameter]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rgl::prelude::*;
+    /// let bits = get_tex_level_stencil_size(TextureImageTarget::Image2D, 0);
+    /// ```
+    ///
+    /// # Description
+    /// Returns the internal storage resolution, in bits, of the stencil component of the texture image, or
+    /// 0 if the internal format has no stencil component.
+    pub fn get_tex_level_stencil_size(target: TextureImageTarget, level: u32) -> u32 {
+        get_tex_level_param_i32(target, level, gl::TEXTURE_STENCIL_SIZE) as u32
+    }
+
+    /// # Error mapped get stencil size
+    /// see [get_tex_level_stencil_size]
+    pub fn get_tex_level_stencil_size_checked(
+        target: TextureImageTarget,
+        level: u32,
+    ) -> Result<u32, TextureError> {
+        let val = get_tex_level_stencil_size(target, level);
+        tex_level_param_error(level)?;
+        Ok(val)
+    }
+}
+pub use get_tex_level_parameter::*;
+
+/// Decode the error of an immutable storage command, where `extent` is the largest dimension that
+/// is reduced by each mipmap level
+fn tex_storage_error(
+    internal_format: TextureInternalFormat,
+    levels: u32,
+    extent: u32,
+    width: u32,
+    height: u32,
+    error: Error,
+) -> TextureError {
+    match error {
+        Error::InvalidEnum => TextureError::UnsizedInternalFormat(internal_format),
+        Error::InvalidValue if levels == 0 => TextureError::InvalidLevel(levels),
+        Error::InvalidValue => TextureError::InvalidDimensions { width, height },
+        Error::InvalidOperation if levels > extent.max(1).ilog2() + 1 => {
+            TextureError::InvalidLevel(levels)
+        }
+        Error::InvalidOperation => TextureError::ImmutableStorage,
+        other => TextureError::Unexpected(other),
+    }
+}
+
+/// # Simultaneously specify storage for all levels of a one-dimensional texture
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexStorage1D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture object is bound.
+/// * `levels` - Specify the number of texture levels.
+/// * `internal_format` - Specifies the sized internal format to be used to store texture image
+/// data.
+/// * `width` - Specifies the width of the texture, in texels.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// tex_storage_1d(TextureBinding1DTarget::Image1D, 8, TextureInternalFormat::RGBA8, 256);
+/// ```
+///
+/// # Description
+/// [tex_storage_1d] specifies the storage requirements for all levels of a one-dimensional
+/// texture simultaneously. Once a texture is specified with this command, the format and
+/// dimensions of all levels become immutable unless it is a proxy texture. The contents of the
+/// image may still be modified, however, its storage requirements may not change. Such a texture
+/// is referred to as an immutable-format texture.
+///
+/// Calling [tex_storage_1d] is equivalent to calling [tex_image_1d] with [TextureData::Reserve]
+/// for each level `0..levels`, with the width halved (rounded down, to a minimum of 1) for each
+/// level, after which the texture's `TEXTURE_IMMUTABLE_FORMAT` parameter is true and its
+/// `TEXTURE_IMMUTABLE_LEVELS` parameter is `levels`.
+///
+/// If `target` is [TextureBinding1DTarget::Proxy1D], no storage is allocated, but all of the
+/// texture state is recalculated, checked for consistency, and checked against the
+/// implementation's capabilities. If the implementation cannot handle a texture of the requested
+/// size, it sets all of the image state to 0, but does not generate an error.
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if the default texture object is bound to `target`.
+/// * [Error::InvalidOperation] - if the texture object currently bound to `target` already has
+/// immutable storage.
+/// * [Error::InvalidEnum] - if `internal_format` is not a sized internal format.
+/// * [Error::InvalidValue] - if `width` or `levels` are less than 1.
+/// * [Error::InvalidOperation] - if `levels` is greater than log<sub>2</sub>(`width`) + 1.
+///
+/// # Associated Gets
+/// * [get_tex_level_parameter]
+/// * [get_tex_parameter]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_storage_1d] | N | N | N | N | N | N | N | N | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [tex_image_1d]
+/// * [tex_storage_2d], [tex_storage_3d]
+/// * [tex_sub_image_1d]
+pub fn tex_storage_1d(
+    target: TextureBinding1DTarget,
+    levels: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+) {
+    let target = GLenum::from(target);
+    let levels = levels as GLsizei;
+    let internal_format = GLenum::from(internal_format);
+    let width = width as GLsizei;
+    unsafe { gl::TexStorage1D(target, levels, internal_format, width) }
+}
+
+/// # Error mapped simultaneously specify storage for all levels of a one-dimensional texture
+/// see [tex_storage_1d]
+pub fn tex_storage_1d_checked(
+    target: TextureBinding1DTarget,
+    levels: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+) -> Result<(), TextureError> {
+    tex_storage_1d(target, levels, internal_format, width);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(tex_storage_error(
+            internal_format,
+            levels,
+            width,
+            width,
+            1,
+            error,
+        )),
+    }
+}
+
+/// # Simultaneously specify storage for all levels of a two-dimensional or one-dimensional array texture
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexStorage2D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture object is bound.
+/// * `levels` - Specify the number of texture levels.
+/// * `internal_format` - Specifies the sized internal format to be used to store texture image
+/// data.
+/// * `width` - Specifies the width of the texture, in texels.
+/// * `height` - Specifies the height of the texture, in texels, or the number of layers of a
+/// one-dimensional array texture.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // a 1080p video frame, updated every frame by `tex_sub_image_2d`
+/// tex_storage_2d(TextureStorage2DTarget::Image2D, 1, TextureInternalFormat::RGBA8, 1920, 1080);
+/// ```
+///
+/// # Description
+/// [tex_storage_2d] specifies the storage requirements for all levels of a two-dimensional
+/// texture or one-dimensional texture array simultaneously. Once a texture is specified with this
+/// command, the format and dimensions of all levels become immutable unless it is a proxy texture.
+/// The contents of the image may still be modified, however, its storage requirements may not
+/// change. Such a texture is referred to as an immutable-format texture.
+///
+/// For [TextureStorage2DTarget::Image2D], [TextureStorage2DTarget::Rectangle] and
+/// [TextureStorage2DTarget::CubeMap], calling [tex_storage_2d] is equivalent to calling
+/// [tex_image_2d] with [TextureData::Reserve] for each level `0..levels` (and each face of a cube
+/// map), with the width and height halved (rounded down, to a minimum of 1) for each level. For
+/// [TextureStorage2DTarget::Image1DArray] only the width is halved, while `height` remains the
+/// number of layers of every level.
+///
+/// Afterwards the texture's `TEXTURE_IMMUTABLE_FORMAT` parameter is true and its
+/// `TEXTURE_IMMUTABLE_LEVELS` parameter is `levels`. Immutable textures can then only be updated by commands such as
+/// [tex_sub_image_2d].
+///
+/// If `target` is one of the proxy targets, no storage is allocated, but all of the texture state
+/// is recalculated, checked for consistency, and checked against the implementation's
+/// capabilities. If the implementation cannot handle a texture of the requested size, it sets all
+/// of the image state to 0, but does not generate an error.
+///
+/// # Compatability
+/// * 3.1 - [TextureStorage2DTarget::Rectangle], [TextureStorage2DTarget::ProxyRectangle]
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if the default texture object is bound to `target`.
+/// * [Error::InvalidOperation] - if the texture object currently bound to `target` already has
+/// immutable storage.
+/// * [Error::InvalidEnum] - if `internal_format` is not a sized internal format.
+/// * [Error::InvalidValue] - if `width`, `height` or `levels` are less than 1.
+/// * [Error::InvalidOperation] - if `target` is [TextureStorage2DTarget::Image1DArray] or
+/// [TextureStorage2DTarget::Proxy1DArray] and `levels` is greater than
+/// log<sub>2</sub>(`width`) + 1.
+/// * [Error::InvalidOperation] - if `target` is not [TextureStorage2DTarget::Image1DArray] or
+/// [TextureStorage2DTarget::Proxy1DArray] and `levels` is greater than
+/// log<sub>2</sub>(max(`width`, `height`)) + 1.
+///
+/// # Associated Gets
+/// * [get_tex_level_parameter]
+/// * [get_tex_parameter]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_storage_2d] | N | N | N | N | N | N | N | N | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [tex_image_2d]
+/// * [tex_storage_1d], [tex_storage_3d]
+/// * [tex_sub_image_2d]
+pub fn tex_storage_2d(
+    target: TextureStorage2DTarget,
+    levels: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+) {
+    let target = GLenum::from(target);
+    let levels = levels as GLsizei;
+    let internal_format = GLenum::from(internal_format);
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    unsafe { gl::TexStorage2D(target, levels, internal_format, width, height) }
+}
+
+/// # Error mapped simultaneously specify storage for all levels of a two-dimensional or one-dimensional array texture
+/// see [tex_storage_2d]
+pub fn tex_storage_2d_checked(
+    target: TextureStorage2DTarget,
+    levels: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+) -> Result<(), TextureError> {
+    tex_storage_2d(target, levels, internal_format, width, height);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => {
+            let extent = match target {
+                TextureStorage2DTarget::Image1DArray | TextureStorage2DTarget::Proxy1DArray => {
+                    width
+                }
+                _ => width.max(height),
+            };
+            Err(tex_storage_error(
+                internal_format,
+                levels,
+                extent,
+                width,
+                height,
+                error,
+            ))
+        }
+    }
+}
+
+/// # Specify storage for a two-dimensional multisample texture
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexStorage2DMultisample.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture object is bound.
+/// * `samples` - Specify the number of samples in the texture.
+/// * `internal_format` - Specifies the sized internal format to be used to store texture image
+/// data.
+/// * `width` - Specifies the width of the texture, in texels.
+/// * `height` - Specifies the height of the texture, in texels.
+/// * `fixed_sample_locations` - Specifies whether the image will use identical sample locations and
+/// the same number of samples for all texels in the image, and the sample locations will not
+/// depend on the internal format or size of the image.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// tex_storage_2d_multisample(
+///     TextureMultisample2DTarget::Multisample2D,
+///     4,
+///     TextureInternalFormat::RGBA8,
+///     1920,
+///     1080,
+///     true
+/// );
+/// ```
+///
+/// # Description
+/// [tex_storage_2d_multisample] specifies the storage requirements for a two-dimensional
+/// multisample texture. Once a texture is specified with this command, its format and dimensions
+/// become immutable unless it is a proxy texture. The contents of the image may still be modified,
+/// however, its storage requirements may not change. Such a texture is referred to as an
+/// immutable-format texture.
+///
+/// Otherwise [tex_storage_2d_multisample] behaves as [tex_image_2d_multisample], see its
+/// description for the meaning of `samples` and `fixed_sample_locations`.
+///
+/// # Compatability
+/// * 4.3 - [tex_storage_2d_multisample]
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if the default texture object is bound to `target`.
+/// * [Error::InvalidOperation] - if the texture object currently bound to `target` already has
+/// immutable storage.
+/// * [Error::InvalidEnum] - if `internal_format` is not a valid colour, depth, or stencil
+/// renderable sized internal format.
+/// * [Error::InvalidOperation] - if `samples` is greater than the maximum number of samples
+/// supported for `internal_format`.
+/// * [Error::InvalidValue] - if `samples` is zero.
+/// * [Error::InvalidValue] - if `width` or `height` are less than 1 or greater than the maximum
+/// texture size.
+///
+/// # Associated Gets
+/// * [get_framebuffer_samples]
+/// * [get_max_samples]
+/// * [get_tex_parameter]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_storage_2d_multisample] | N | N | N | N | N | N | N | N | N | Y | Y | Y |
+///
+/// # See Also
+/// * [blit_framebuffer]
+/// * [tex_image_2d_multisample]
+/// * [tex_storage_2d]
+pub fn tex_storage_2d_multisample(
+    target: TextureMultisample2DTarget,
+    samples: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    fixed_sample_locations: bool,
+) {
+    let target = GLenum::from(target);
+    let samples = samples as GLsizei;
+    let internal_format = GLenum::from(internal_format);
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let fixed_sample_locations = GLboolean::from(fixed_sample_locations);
+    unsafe {
+        gl::TexStorage2DMultisample(
+            target,
+            samples,
+            internal_format,
+            width,
+            height,
+            fixed_sample_locations,
+        )
+    }
+}
+
+/// # Error mapped specify storage for a two-dimensional multisample texture
+/// see [tex_storage_2d_multisample]
+pub fn tex_storage_2d_multisample_checked(
+    target: TextureMultisample2DTarget,
+    samples: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    fixed_sample_locations: bool,
+) -> Result<(), TextureError> {
+    tex_storage_2d_multisample(
+        target,
+        samples,
+        internal_format,
+        width,
+        height,
+        fixed_sample_locations,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation
+            if target == TextureMultisample2DTarget::Multisample2D
+                && (get_i32(gl::TEXTURE_BINDING_2D_MULTISAMPLE) == 0
+                    || get_texture_target_immutable_format(
+                        TextureBindingTarget::Multisample2D,
+                    )) =>
+        {
+            Err(TextureError::ImmutableStorage)
+        }
+        error => Err(tex_multisample_error(
+            internal_format,
+            samples,
+            width,
+            height,
+            error,
+        )),
+    }
+}
+
+/// # Simultaneously specify storage for all levels of a three-dimensional, two-dimensional array or cube-map array texture
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexStorage3D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture object is bound.
+/// * `levels` - Specify the number of texture levels.
+/// * `internal_format` - Specifies the sized internal format to be used to store texture image
+/// data.
+/// * `width` - Specifies the width of the texture, in texels.
+/// * `height` - Specifies the height of the texture, in texels.
+/// * `depth` - Specifies the depth of the texture, in texels, or the number of layers of an array
+/// texture.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// // a texture atlas of 16 pages, filled by `tex_sub_image_3d`
+/// tex_storage_3d(
+///     TextureBinding3DTarget::Image2DArray,
+///     1,
+///     TextureInternalFormat::RGBA8,
+///     1024,
+///     1024,
+///     16,
+/// );
+/// ```
+///
+/// # Description
+/// [tex_storage_3d] specifies the storage requirements for all levels of a three-dimensional,
+/// two-dimensional array or cube-map array texture simultaneously. Once a texture is specified with
+/// this command, the format and dimensions of all levels become immutable unless it is a proxy
+/// texture. The contents of the image may still be modified, however, its storage requirements may
+/// not change. Such a texture is referred to as an immutable-format texture.
+///
+/// For [TextureBinding3DTarget::Image3D], calling [tex_storage_3d] is equivalent to calling
+/// [tex_image_3d] with [TextureData::Reserve] for each level `0..levels`, with the width, height
+/// and depth halved (rounded down, to a minimum of 1) for each level. For array targets only the
+/// width and height are halved, while `depth` remains the number of layers of every level.
+///
+/// Afterwards the texture's `TEXTURE_IMMUTABLE_FORMAT` parameter is true and its
+/// `TEXTURE_IMMUTABLE_LEVELS` parameter is `levels`.
+///
+/// If `target` is one of the proxy targets, no storage is allocated, but all of the texture state
+/// is recalculated, checked for consistency, and checked against the implementation's
+/// capabilities. If the implementation cannot handle a texture of the requested size, it sets all
+/// of the image state to 0, but does not generate an error.
+///
+/// # Compatability
+/// * 4.0 - [TextureBinding3DTarget::CubeMapArray], [TextureBinding3DTarget::ProxyCubeMapArray]
+///
+/// # Errors
+/// * [Error::InvalidOperation] - if the default texture object is bound to `target`.
+/// * [Error::InvalidOperation] - if the texture object currently bound to `target` already has
+/// immutable storage.
+/// * [Error::InvalidEnum] - if `internal_format` is not a sized internal format.
+/// * [Error::InvalidValue] - if `width`, `height`, `depth` or `levels` are less than 1.
+/// * [Error::InvalidOperation] - if `target` is [TextureBinding3DTarget::Image3D] or
+/// [TextureBinding3DTarget::Proxy3D] and `levels` is greater than
+/// log<sub>2</sub>(max(`width`, `height`, `depth`)) + 1.
+/// * [Error::InvalidOperation] - if `target` is an array target and `levels` is greater than
+/// log<sub>2</sub>(max(`width`, `height`)) + 1.
+///
+/// # Associated Gets
+/// * [get_tex_level_parameter]
+/// * [get_tex_parameter]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_storage_3d] | N | N | N | N | N | N | N | N | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [tex_image_3d]
+/// * [tex_storage_1d], [tex_storage_2d]
+/// * [tex_sub_image_3d]
+pub fn tex_storage_3d(
+    target: TextureBinding3DTarget,
+    levels: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    depth: u32,
+) {
+    let target = GLenum::from(target);
+    let levels = levels as GLsizei;
+    let internal_format = GLenum::from(internal_format);
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let depth = depth as GLsizei;
+    unsafe { gl::TexStorage3D(target, levels, internal_format, width, height, depth) }
+}
+
+/// # Error mapped simultaneously specify storage for all levels of a three-dimensional, two-dimensional array or cube-map array texture
+/// see [tex_storage_3d]
+pub fn tex_storage_3d_checked(
+    target: TextureBinding3DTarget,
+    levels: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    depth: u32,
+) -> Result<(), TextureError> {
+    tex_storage_3d(target, levels, internal_format, width, height, depth);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => {
+            let extent = match target {
+                TextureBinding3DTarget::Image3D | TextureBinding3DTarget::Proxy3D => {
+                    width.max(height).max(depth)
+                }
+                _ => width.max(height),
+            };
+            Err(tex_storage_error(
+                internal_format,
+                levels,
+                extent,
+                width,
+                height,
+                error,
+            ))
+        }
+    }
+}
+
+/// Decode the error of a texture sub-image command on `target`
+fn tex_sub_image_error(
+    target: GLenum,
+    level: u32,
+    unpack_buffer: bool,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    error: Error,
+) -> TextureError {
+    let max_level = (get_i32(gl::MAX_TEXTURE_SIZE).max(1) as u32).ilog2();
+    match error {
+        Error::InvalidValue if level > max_level => TextureError::InvalidLevel(level),
+        Error::InvalidValue => TextureError::InvalidRegion,
+        Error::InvalidOperation
+            if get_tex_level_parameter_i32(target, level, gl::TEXTURE_WIDTH) == 0 =>
+        {
+            TextureError::InvalidRegion
+        }
+        Error::InvalidOperation if unpack_buffer => TextureError::InvalidPixelBuffer,
+        Error::InvalidOperation => TextureError::IncompatibleFormat {
+            format,
+            pixel_data_type,
+        },
+        other => TextureError::Unexpected(other),
+    }
+}
+
+/// # Specify a one-dimensional texture subimage
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexSubImage1D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture is bound.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `x_offset` - Specifies a texel offset in the x direction within the texture array.
+/// * `width` - Specifies the width of the texture subimage.
+/// * `format` - Specifies the format of the pixel data.
+/// * `pixel_data_type` - Specifies the data type of the pixel data.
+/// * `data` - Specifies a slice to the image data in memory, or single `u64` offset to a
+/// [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// tex_sub_image_1d(
+///     TextureBinding1DTarget::Image1D,
+///     0,
+///     4,
+///     2,
+///     TextureFormat::RGBA,
+///     TexturePixelType::U8,
+///     TextureSubData::Data(&[255, 0, 0, 255, 0, 0, 255, 255]),
+/// );
+/// ```
+///
+/// # Description
+/// Texturing maps a portion of a specified texture image onto each graphical primitive for which
+/// texturing is enabled.
+///
+/// [tex_sub_image_1d] redefines a contiguous subregion of an existing one-dimensional texture
+/// image. The texels referenced by `data` replace the portion of the existing texture array with x
+/// indices `x_offset` and `x_offset + width - 1`, inclusive. This region may not include any
+/// texels outside the range of the texture array as it was originally specified. It is not an
+/// error to specify a subtexture with width of 0, but such a specification has no effect.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [TextureSubData::Offset].
+///
+/// Before a slice of `data` is read, `width`, `format`, `pixel_data_type` and the current pixel
+/// unpack state are used to determine the number of bytes the subimage requires. If `data` is too
+/// small, [tex_sub_image_1d] panics rather than let the GL read past the end of the slice, while
+/// [tex_sub_image_1d_checked] returns [TextureError::InsufficientPixelData] without uploading
+/// anything.
+///
+/// # Compatability
+/// * 4.4 - [TextureFormat::StencilIndex]
+///
+/// # Panics
+/// [tex_sub_image_1d] panics if a slice of `data` is too small to hold the subimage.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the
+/// subimage, from [tex_sub_image_1d_checked].
+/// * [Error::InvalidEnum] - if `target` is [TextureBinding1DTarget::Proxy1D].
+/// * [Error::InvalidValue] - if `level` is greater than log<sub>2</sub>([get_max_texture_size]).
+/// * [Error::InvalidValue] - if `x_offset + width` is greater than the width of the texture image.
+/// * [Error::InvalidOperation] - if the texture image has not been defined by a previous
+/// [tex_image_1d] or [tex_storage_1d].
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of the packed types, and `format`
+/// does not have the number of components of the packed type.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_sub_image_1d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [copy_tex_sub_image_1d]
+/// * [pixel_store]
+/// * [tex_image_1d]
+/// * [tex_storage_1d]
+/// * [tex_sub_image_2d], [tex_sub_image_3d]
+pub fn tex_sub_image_1d<DataType>(
+    target: TextureBinding1DTarget,
+    level: u32,
+    x_offset: u32,
+    width: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureSubData<DataType>,
+) {
+    let data = TextureData::from(data);
+    assert_pixel_data(width, 1, None, format, pixel_data_type, &data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let x_offset = x_offset as GLint;
+    let width = width as GLsizei;
+    let format = GLenum::from(format);
+    let type_ = GLenum::from(pixel_data_type);
+    let data = pixel_data_pointer(data);
+
+    // SAFE: synchronous read of `data`, which was checked to hold the whole subimage, no memory
+    // retained
+    unsafe { gl::TexSubImage1D(target, level, x_offset, width, format, type_, data) }
+}
+
+/// # Error mapped specify a one-dimensional texture subimage
+/// see [tex_sub_image_1d]
+pub fn tex_sub_image_1d_checked<DataType>(
+    target: TextureBinding1DTarget,
+    level: u32,
+    x_offset: u32,
+    width: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureSubData<DataType>,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, TextureSubData::Offset(_));
+    check_pixel_data(width, 1, None, format, pixel_data_type, &data.into())?;
+    tex_sub_image_1d(
+        target,
+        level,
+        x_offset,
+        width,
+        format,
+        pixel_data_type,
+        data,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(tex_sub_image_error(
+            GLenum::from(target),
+            level,
+            unpack_buffer,
+            format,
+            pixel_data_type,
+            error,
+        )),
+    }
+}
+
+/// # Specify a two-dimensional texture subimage
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexSubImage2D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture is bound.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `x_offset` - Specifies a texel offset in the x direction within the texture array.
+/// * `y_offset` - Specifies a texel offset in the y direction within the texture array, or the
+/// first layer of a one-dimensional array texture.
+/// * `width` - Specifies the width of the texture subimage.
+/// * `height` - Specifies the height of the texture subimage, or the number of layers of a
+/// one-dimensional array texture.
+/// * `format` - Specifies the format of the pixel data.
+/// * `pixel_data_type` - Specifies the data type of the pixel data.
+/// * `data` - Specifies a slice to the image data in memory, or single `u64` offset to a
+/// [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// # let frame = vec![0u8; 1920 * 1080 * 4];
+/// // stream a new video frame into the immutable storage of the bound texture
+/// tex_sub_image_2d(
+///     TextureBinding2DTarget::Image2D,
+///     0,
+///     0,
+///     0,
+///     1920,
+///     1080,
+///     TextureFormat::RGBA,
+///     TexturePixelType::U8,
+///     TextureSubData::Data(&frame),
+/// );
+/// ```
+///
+/// # Description
+/// Texturing maps a portion of a specified texture image onto each graphical primitive for which
+/// texturing is enabled.
+///
+/// [tex_sub_image_2d] redefines a contiguous subregion of an existing two-dimensional or
+/// one-dimensional array texture image. The texels referenced by `data` replace the portion of the
+/// existing texture array with x indices `x_offset` and `x_offset + width - 1`, inclusive, and y
+/// indices `y_offset` and `y_offset + height - 1`, inclusive. This region may not include any
+/// texels outside the range of the texture array as it was originally specified. It is not an
+/// error to specify a subtexture with zero width or height, but such a specification has no
+/// effect.
+///
+/// Unlike [tex_image_2d], [tex_sub_image_2d] does not reallocate the texture, so it is the way
+/// to update textures with immutable storage from [tex_storage_2d], such as streamed video frames
+/// or the pages of a texture atlas.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [TextureSubData::Offset].
+///
+/// Before a slice of `data` is read, `width`, `height`, `format`, `pixel_data_type` and the current
+/// pixel unpack state are used to determine the number of bytes the subimage requires. If `data` is
+/// too small, [tex_sub_image_2d] panics rather than let the GL read past the end of the slice,
+/// while [tex_sub_image_2d_checked] returns [TextureError::InsufficientPixelData] without uploading
+/// anything.
+///
+/// # Compatability
+/// * 4.4 - [TextureFormat::StencilIndex]
+///
+/// # Panics
+/// [tex_sub_image_2d] panics if a slice of `data` is too small to hold the subimage.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the
+/// subimage, from [tex_sub_image_2d_checked].
+/// * [Error::InvalidEnum] - if `target` is one of the proxy targets.
+/// * [Error::InvalidValue] - if `level` is greater than log<sub>2</sub>([get_max_texture_size]).
+/// * [Error::InvalidValue] - if `x_offset + width` is greater than the width, or
+/// `y_offset + height` is greater than the height, of the texture image.
+/// * [Error::InvalidOperation] - if the texture image has not been defined by a previous
+/// [tex_image_2d] or [tex_storage_2d].
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of the packed types, and `format`
+/// does not have the number of components of the packed type.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_sub_image_2d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [copy_tex_sub_image_2d]
+/// * [pixel_store]
+/// * [tex_image_2d]
+/// * [tex_storage_2d]
+/// * [tex_sub_image_1d], [tex_sub_image_3d]
+#[allow(clippy::too_many_arguments)]
+pub fn tex_sub_image_2d<DataType>(
+    target: TextureBinding2DTarget,
+    level: u32,
+    x_offset: u32,
+    y_offset: u32,
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureSubData<DataType>,
+) {
+    let data = TextureData::from(data);
+    assert_pixel_data(width, height, None, format, pixel_data_type, &data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let x_offset = x_offset as GLint;
+    let y_offset = y_offset as GLint;
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let format = GLenum::from(format);
+    let type_ = GLenum::from(pixel_data_type);
+    let data = pixel_data_pointer(data);
+
+    // SAFE: synchronous read of `data`, which was checked to hold the whole subimage, no memory
+    // retained
+    unsafe {
+        gl::TexSubImage2D(
+            target, level, x_offset, y_offset, width, height, format, type_, data,
+        )
+    }
+}
+
+/// # Error mapped specify a two-dimensional texture subimage
+/// see [tex_sub_image_2d]
+#[allow(clippy::too_many_arguments)]
+pub fn tex_sub_image_2d_checked<DataType>(
+    target: TextureBinding2DTarget,
+    level: u32,
+    x_offset: u32,
+    y_offset: u32,
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureSubData<DataType>,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, TextureSubData::Offset(_));
+    check_pixel_data(width, height, None, format, pixel_data_type, &data.into())?;
+    tex_sub_image_2d(
+        target,
+        level,
+        x_offset,
+        y_offset,
+        width,
+        height,
+        format,
+        pixel_data_type,
+        data,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(tex_sub_image_error(
+            GLenum::from(target),
+            level,
+            unpack_buffer,
+            format,
+            pixel_data_type,
+            error,
+        )),
+    }
+}
+
+/// # Specify a three-dimensional texture subimage
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexSubImage3D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture is bound.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `x_offset` - Specifies a texel offset in the x direction within the texture array.
+/// * `y_offset` - Specifies a texel offset in the y direction within the texture array.
+/// * `z_offset` - Specifies a texel offset in the z direction within the texture array, or the
+/// first layer of an array texture.
+/// * `width` - Specifies the width of the texture subimage.
+/// * `height` - Specifies the height of the texture subimage.
+/// * `depth` - Specifies the depth of the texture subimage, or the number of layers of an array
+/// texture.
+/// * `format` - Specifies the format of the pixel data.
+/// * `pixel_data_type` - Specifies the data type of the pixel data.
+/// * `data` - Specifies a slice to the image data in memory, or single `u64` offset to a
+/// [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// # let glyph = [0u8; 32 * 32 * 4];
+/// // replace a 32x32 glyph on page 3 of an atlas
+/// tex_sub_image_3d(
+///     TextureBinding3DTarget::Image2DArray,
+///     0,
+///     64,
+///     96,
+///     3,
+///     32,
+///     32,
+///     1,
+///     TextureFormat::RGBA,
+///     TexturePixelType::U8,
+///     TextureSubData::Data(&glyph),
+/// );
+/// ```
+///
+/// # Description
+/// Texturing maps a portion of a specified texture image onto each graphical primitive for which
+/// texturing is enabled.
+///
+/// [tex_sub_image_3d] redefines a contiguous subregion of an existing three-dimensional or
+/// two-dimensional array texture image. The texels referenced by `data` replace the portion of the
+/// existing texture array with x indices `x_offset` and `x_offset + width - 1`, inclusive, y
+/// indices `y_offset` and `y_offset + height - 1`, inclusive, and z indices `z_offset` and
+/// `z_offset + depth - 1`, inclusive. For array textures, the z index refers to the slice index.
+/// This region may not include any texels outside the range of the texture array as it was
+/// originally specified. It is not an error to specify a subtexture with zero width, height, or
+/// depth but such a specification has no effect.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [TextureSubData::Offset].
+///
+/// Before a slice of `data` is read, `width`, `height`, `depth`, `format`, `pixel_data_type` and
+/// the current pixel unpack state are used to determine the number of bytes the subimage requires.
+/// If `data` is too small, [tex_sub_image_3d] panics rather than let the GL read past the end of
+/// the slice, while [tex_sub_image_3d_checked] returns [TextureError::InsufficientPixelData]
+/// without uploading anything.
+///
+/// # Compatability
+/// * 4.4 - [TextureFormat::StencilIndex]
+///
+/// # Panics
+/// [tex_sub_image_3d] panics if a slice of `data` is too small to hold the subimage.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the
+/// subimage, from [tex_sub_image_3d_checked].
+/// * [Error::InvalidEnum] - if `target` is one of the proxy targets.
+/// * [Error::InvalidValue] - if `level` is greater than log<sub>2</sub>(max), where max is the
+/// returned value of [gl::MAX_3D_TEXTURE_SIZE].
+/// * [Error::InvalidValue] - if `x_offset + width`, `y_offset + height` or `z_offset + depth` is
+/// greater than the width, height or depth of the texture image.
+/// * [Error::InvalidOperation] - if the texture image has not been defined by a previous
+/// [tex_image_3d] or [tex_storage_3d].
+/// * [Error::InvalidOperation] - if `pixel_data_type` is one of the packed types, and `format`
+/// does not have the number of components of the packed type.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [tex_sub_image_3d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [copy_tex_sub_image_3d]
+/// * [pixel_store]
+/// * [tex_image_3d]
+/// * [tex_storage_3d]
+/// * [tex_sub_image_1d], [tex_sub_image_2d]
+#[allow(clippy::too_many_arguments)]
+pub fn tex_sub_image_3d<DataType>(
+    target: TextureBinding3DTarget,
+    level: u32,
+    x_offset: u32,
+    y_offset: u32,
+    z_offset: u32,
+    width: u32,
+    height: u32,
+    depth: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureSubData<DataType>,
+) {
+    let data = TextureData::from(data);
+    assert_pixel_data(width, height, Some(depth), format, pixel_data_type, &data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let x_offset = x_offset as GLint;
+    let y_offset = y_offset as GLint;
+    let z_offset = z_offset as GLint;
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let depth = depth as GLsizei;
+    let format = GLenum::from(format);
+    let type_ = GLenum::from(pixel_data_type);
+    let data = pixel_data_pointer(data);
+
+    // SAFE: synchronous read of `data`, which was checked to hold the whole subimage, no memory
+    // retained
+    unsafe {
+        gl::TexSubImage3D(
+            target, level, x_offset, y_offset, z_offset, width, height, depth, format, type_, data,
+        )
+    }
+}
 
-        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
-        // no memory is retained
-        unsafe { gl::TexParameterfv(target, gl::TEXTURE_BORDER_COLOR, params) }
+/// # Error mapped specify a three-dimensional texture subimage
+/// see [tex_sub_image_3d]
+#[allow(clippy::too_many_arguments)]
+pub fn tex_sub_image_3d_checked<DataType>(
+    target: TextureBinding3DTarget,
+    level: u32,
+    x_offset: u32,
+    y_offset: u32,
+    z_offset: u32,
+    width: u32,
+    height: u32,
+    depth: u32,
+    format: TextureFormat,
+    pixel_data_type: TexturePixelType,
+    data: TextureSubData<DataType>,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, TextureSubData::Offset(_));
+    check_pixel_data(
+        width,
+        height,
+        Some(depth),
+        format,
+        pixel_data_type,
+        &data.into(),
+    )?;
+    tex_sub_image_3d(
+        target,
+        level,
+        x_offset,
+        y_offset,
+        z_offset,
+        width,
+        height,
+        depth,
+        format,
+        pixel_data_type,
+        data,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(tex_sub_image_error(
+            GLenum::from(target),
+            level,
+            unpack_buffer,
+            format,
+            pixel_data_type,
+            error,
+        )),
     }
+}
 
-    /// # Set a texel that should be used for border texels
-    /// see [texture_target_border_colour_f32]
-    ///
-    /// # Description
-    /// The `texel` they are converted to floating point with the following equation:
-    /// `f = (2c + 1) / (2^b - 1)`
-    pub fn texture_target_border_colour_i32_to_f32(target: TextureBindingTarget, texel: [i32; 4]) {
-        let target = GLenum::from(target);
-        let params = texel.as_ptr();
+/// Block width, height and size in bytes of the specific compressed formats
+fn compressed_block(internal_format: TextureInternalFormat) -> Option<(u32, u32, usize)> {
+    match internal_format {
+        TextureInternalFormat::CompressedRRGTC1 | TextureInternalFormat::CompressedSignedRRGTC1 => {
+            Some((4, 4, 8))
+        }
+        TextureInternalFormat::CompressedRGRGTC2
+        | TextureInternalFormat::CompressedSignedRGRGTC2
+        | TextureInternalFormat::CompressedRGBABPTCUNORM
+        | TextureInternalFormat::CompressedSRGBABPTCUNORM
+        | TextureInternalFormat::CompressedRGBBPTCSignedFloat
+        | TextureInternalFormat::CompressedRGBBPTCUnsignedFloat => Some((4, 4, 16)),
+        _ => None,
+    }
+}
 
-        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
-        // no memory is retained
-        unsafe { gl::TexParameteriv(target, gl::TEXTURE_BORDER_COLOR, params) }
+/// # Size of a compressed image
+/// Number of bytes of a `width` x `height` x `depth` image in `internal_format`, which must be
+/// one of the specific block compressed formats (RGTC or BPTC), or `None` otherwise. Partial blocks
+/// at the right and bottom edges of the image occupy a whole block. `None` is also returned if the
+/// size does not fit in a `usize`.
+///
+/// This is computed from the format alone and makes no GL call, so unlike the functions around it
+/// there is no `_checked` counter part.
+///
+/// # Example
+/// ```
+/// # use rgl::prelude::*;
+/// let format = TextureInternalFormat::CompressedRGBABPTCUNORM;
+/// assert_eq!(compressed_image_size(format, 256, 256, 1), Some(65536));
+/// assert_eq!(compressed_image_size(format, 2, 2, 1), Some(16));
+/// assert_eq!(compressed_image_size(TextureInternalFormat::RGBA8, 2, 2, 1), None);
+/// ```
+pub fn compressed_image_size(
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    depth: u32,
+) -> Option<usize> {
+    let (block_width, block_height, block_size) = compressed_block(internal_format)?;
+    let blocks = (width.div_ceil(block_width) as usize)
+        .checked_mul(height.div_ceil(block_height) as usize)?;
+    blocks.checked_mul(depth as usize)?.checked_mul(block_size)
+}
+
+/// Check a client slice of `data` holds the whole image of a block compressed `internal_format`.
+/// The size of other formats, and pixel unpack buffer ranges, are checked by the GL instead
+fn check_compressed_data(
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    depth: u32,
+    data: &CompressedTextureData,
+) -> Result<(), TextureError> {
+    let CompressedTextureData::Data(data) = data else {
+        return Ok(());
+    };
+    let provided = data.len();
+    match compressed_image_size(internal_format, width, height, depth) {
+        Some(required) if provided < required => {
+            Err(TextureError::InsufficientPixelData { required, provided })
+        }
+        None if compressed_block(internal_format).is_some() => {
+            Err(TextureError::InvalidDimensions { width, height })
+        }
+        _ => Ok(()),
     }
+}
 
-    /// # Set a texel that should be used for border texels
-    /// see [texture_target_border_colour_f32]
-    ///
-    /// # Description
-    /// The `texel` is stored unmodified with an internal data type of i32.
-    pub fn texture_target_border_colour_i32(target: TextureBindingTarget, texel: [i32; 4]) {
-        let target = GLenum::from(target);
-        let params = texel.as_ptr();
+/// Convert `data` into the `imageSize` and `data` arguments of the compressed pixel unpack
+/// commands, panicking if a client slice is too small for the image, as the GL would read past its
+/// end
+fn compressed_data(
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    depth: u32,
+    data: CompressedTextureData,
+) -> (GLsizei, *const std::os::raw::c_void) {
+    match check_compressed_data(internal_format, width, height, depth, &data) {
+        Ok(()) => {}
+        Err(TextureError::InsufficientPixelData { required, provided }) => {
+            panic!("pixel data holds {provided} bytes, but the image requires {required}")
+        }
+        Err(error) => panic!("{error}"),
+    }
+    match data {
+        CompressedTextureData::Data(data) => {
+            let size =
+                compressed_image_size(internal_format, width, height, depth).unwrap_or(data.len());
+            (size as GLsizei, data.as_ptr() as *const _)
+        }
+        CompressedTextureData::Offset { offset, size } => (size as GLsizei, offset as *const _),
+    }
+}
 
-        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
-        // no memory is retained
-        unsafe { gl::TexParameterIiv(target, gl::TEXTURE_BORDER_COLOR, params) }
+/// Size of the compressed data passed to GL, reported by [TextureError::InvalidCompressedImageSize]
+fn compressed_data_size(
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    depth: u32,
+    data: &CompressedTextureData,
+) -> usize {
+    match data {
+        CompressedTextureData::Data(data) => {
+            compressed_image_size(internal_format, width, height, depth).unwrap_or(data.len())
+        }
+        CompressedTextureData::Offset { size, .. } => *size,
     }
+}
 
-    /// # Set a texel that should be used for border texels
-    /// see [texture_target_border_colour_f32]
-    ///
-    /// # Description
-    /// The `texel` is stored unmodified with an internal data type of u32.
-    pub fn texture_target_border_colour_u32(target: TextureBindingTarget, texel: [u32; 4]) {
-        let target = GLenum::from(target);
-        let params = texel.as_ptr();
+/// Decode the error of a compressed texture image command
+fn compressed_tex_image_error(
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    size: usize,
+    unpack_buffer: bool,
+    error: Error,
+) -> TextureError {
+    let max_size = get_i32(gl::MAX_TEXTURE_SIZE) as u32;
+    match error {
+        Error::InvalidEnum => TextureError::InvalidCompressedFormat(internal_format),
+        Error::InvalidValue if width > max_size || height > max_size => {
+            TextureError::InvalidDimensions { width, height }
+        }
+        Error::InvalidValue => TextureError::InvalidCompressedImageSize(size),
+        Error::InvalidOperation if unpack_buffer => TextureError::InvalidPixelBuffer,
+        Error::InvalidOperation => TextureError::InvalidCompressedFormat(internal_format),
+        other => TextureError::Unexpected(other),
+    }
+}
+
+/// # Specify a one-dimensional texture image in a compressed format
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCompressedTexImage1D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target texture.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `internal_format` - Specifies the format of the compressed image data.
+/// * `width` - Specifies the width of the texture image.
+/// * `data` - Specifies a slice to the compressed image data in memory, or an offset and size into
+/// a [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// # let blocks = [0u8; 64];
+/// compressed_tex_image_1d(
+///     TextureBinding1DTarget::Image1D,
+///     0,
+///     TextureInternalFormat::CompressedRGBA,
+///     16,
+///     CompressedTextureData::Data(&blocks),
+/// );
+/// ```
+///
+/// # Description
+/// [compressed_tex_image_1d] loads a previously defined, and retrieved, compressed
+/// one-dimensional texture image if `target` is [TextureBinding1DTarget::Image1D] (see
+/// [tex_image_1d]).
+///
+/// If `target` is [TextureBinding1DTarget::Proxy1D], no data is read from `data`, but all of the
+/// texture image state is recalculated, checked for consistency, and checked against the
+/// implementation's capabilities. If the implementation cannot handle a texture of the requested
+/// texture size, it sets all of the image state to 0, but does not generate an error.
+///
+/// `internal_format` must be an extension-specified compressed-texture format. None of the block
+/// compressed formats of [TextureInternalFormat] support one-dimensional images. When a texture is
+/// loaded with [tex_image_1d] using a generic compressed texture format (e.g.,
+/// [TextureInternalFormat::CompressedRGB]) the GL selects from one of its extensions supporting
+/// compressed textures.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [CompressedTextureData::Offset].
+///
+/// # Panics
+/// [compressed_tex_image_1d] panics if a slice of `data` is too small to hold the compressed image of a block compressed `internal_format`.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the
+/// compressed image of a block compressed `internal_format`, from [compressed_tex_image_1d_checked].
+/// * [Error::InvalidEnum] - if `internal_format` is not a supported specific compressed internal
+/// format.
+/// * [Error::InvalidValue] - if `imageSize` is not consistent with the format, dimensions, and
+/// contents of the specified compressed image data.
+/// * [Error::InvalidOperation] - if parameter combinations are not supported by the specific
+/// compressed internal format as specified in the specific texture compression extension.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_compressed_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [compressed_tex_image_1d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [compressed_tex_image_2d], [compressed_tex_image_3d]
+/// * [compressed_tex_sub_image_1d]
+/// * [tex_image_1d]
+pub fn compressed_tex_image_1d(
+    target: TextureBinding1DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    data: CompressedTextureData,
+) {
+    let (image_size, data) = compressed_data(internal_format, width, 1, 1, data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let internal_format = GLenum::from(internal_format);
+    let width = width as GLsizei;
+
+    // SAFE: synchronous read of `image_size` bytes of `data`, which was checked to hold the whole
+    // image, no memory retained
+    unsafe { gl::CompressedTexImage1D(target, level, internal_format, width, 0, image_size, data) };
+}
+
+/// # Error mapped specify a one-dimensional texture image in a compressed format
+/// see [compressed_tex_image_1d]
+pub fn compressed_tex_image_1d_checked(
+    target: TextureBinding1DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    data: CompressedTextureData,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, CompressedTextureData::Offset { .. });
+    let size = compressed_data_size(internal_format, width, 1, 1, &data);
+    check_compressed_data(internal_format, width, 1, 1, &data)?;
+    compressed_tex_image_1d(target, level, internal_format, width, data);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(compressed_tex_image_error(
+            internal_format,
+            width,
+            1,
+            size,
+            unpack_buffer,
+            error,
+        )),
+    }
+}
+
+/// # Specify a two-dimensional texture image in a compressed format
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCompressedTexImage2D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target texture.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `internal_format` - Specifies the format of the compressed image data.
+/// * `width` - Specifies the width of the texture image.
+/// * `height` - Specifies the height of the texture image.
+/// * `data` - Specifies a slice to the compressed image data in memory, or an offset and size into
+/// a [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// # let blocks = vec![0u8; 65536];
+/// compressed_tex_image_2d(
+///     TextureBinding2DTarget::Image2D,
+///     0,
+///     TextureInternalFormat::CompressedRGBABPTCUNORM,
+///     256,
+///     256,
+///     CompressedTextureData::Data(&blocks),
+/// );
+/// ```
+///
+/// # Description
+/// [compressed_tex_image_2d] loads a previously defined, and retrieved, compressed
+/// two-dimensional texture image if `target` is [TextureBinding2DTarget::Image2D], or one of the
+/// cube map face targets (see [tex_image_2d]).
+///
+/// If `target` is [TextureBinding2DTarget::Image1DArray], `data` is treated as an array of
+/// compressed 1D textures.
+///
+/// If `target` is one of the proxy targets, no data is read from `data`, but all of the texture
+/// image state is recalculated, checked for consistency, and checked against the implementation's
+/// capabilities. If the implementation cannot handle a texture of the requested texture size, it
+/// sets all of the image state to 0, but does not generate an error.
+///
+/// `internal_format` must be a specific compressed format, such as
+/// [TextureInternalFormat::CompressedRGBABPTCUNORM] or [TextureInternalFormat::CompressedRGRGTC2].
+/// For these block compressed formats the size of the image is known, and a slice of `data` is
+/// checked to hold the whole image (see [compressed_image_size]) before it is read.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [CompressedTextureData::Offset].
+///
+/// # Compatability
+/// * 4.2 - [TextureInternalFormat::CompressedRGBABPTCUNORM],
+/// [TextureInternalFormat::CompressedSRGBABPTCUNORM],
+/// [TextureInternalFormat::CompressedRGBBPTCSignedFloat],
+/// [TextureInternalFormat::CompressedRGBBPTCUnsignedFloat]
+///
+/// # Panics
+/// [compressed_tex_image_2d] panics if a slice of `data` is too small to hold the compressed image of a block compressed `internal_format`.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the
+/// compressed image of a block compressed `internal_format`, from [compressed_tex_image_2d_checked].
+/// * [Error::InvalidEnum] - if `internal_format` is not a specific compressed internal format.
+/// * [Error::InvalidValue] - if `imageSize` is not consistent with the format, dimensions, and
+/// contents of the specified compressed image data.
+/// * [Error::InvalidOperation] - if parameter combinations are not supported by the specific
+/// compressed internal format as specified in the specific texture compression extension.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_compressed_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [compressed_tex_image_2d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [compressed_tex_image_1d], [compressed_tex_image_3d]
+/// * [compressed_tex_sub_image_2d]
+/// * [tex_image_2d]
+pub fn compressed_tex_image_2d(
+    target: TextureBinding2DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    data: CompressedTextureData,
+) {
+    let (image_size, data) = compressed_data(internal_format, width, height, 1, data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let internal_format = GLenum::from(internal_format);
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+
+    // SAFE: synchronous read of `image_size` bytes of `data`, which was checked to hold the whole
+    // image, no memory retained
+    unsafe {
+        gl::CompressedTexImage2D(
+            target,
+            level,
+            internal_format,
+            width,
+            height,
+            0,
+            image_size,
+            data,
+        )
+    }
+}
+
+/// # Error mapped specify a two-dimensional texture image in a compressed format
+/// see [compressed_tex_image_2d]
+pub fn compressed_tex_image_2d_checked(
+    target: TextureBinding2DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    data: CompressedTextureData,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, CompressedTextureData::Offset { .. });
+    let size = compressed_data_size(internal_format, width, height, 1, &data);
+    check_compressed_data(internal_format, width, height, 1, &data)?;
+    compressed_tex_image_2d(target, level, internal_format, width, height, data);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(compressed_tex_image_error(
+            internal_format,
+            width,
+            height,
+            size,
+            unpack_buffer,
+            error,
+        )),
+    }
+}
+
+/// # Specify a three-dimensional texture image in a compressed format
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCompressedTexImage3D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target texture.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `internal_format` - Specifies the format of the compressed image data.
+/// * `width` - Specifies the width of the texture image.
+/// * `height` - Specifies the height of the texture image.
+/// * `depth` - Specifies the depth of the texture image, or the number of layers of an array
+/// texture.
+/// * `data` - Specifies a slice to the compressed image data in memory, or an offset and size into
+/// a [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// # let blocks = vec![0u8; 4 * 4096];
+/// compressed_tex_image_3d(
+///     TextureBinding3DTarget::Image2DArray,
+///     0,
+///     TextureInternalFormat::CompressedRGRGTC2,
+///     64,
+///     64,
+///     4,
+///     CompressedTextureData::Data(&blocks),
+/// );
+/// ```
+///
+/// # Description
+/// [compressed_tex_image_3d] loads a previously defined, and retrieved, compressed
+/// three-dimensional texture image if `target` is [TextureBinding3DTarget::Image3D] (see
+/// [tex_image_3d]).
+///
+/// If `target` is [TextureBinding3DTarget::Image2DArray] or
+/// [TextureBinding3DTarget::CubeMapArray], `data` is treated as an array of compressed 2D
+/// textures, with the faces of each cube in the order +X, -X, +Y, -Y, +Z, -Z.
+///
+/// If `target` is one of the proxy targets, no data is read from `data`, but all of the texture
+/// image state is recalculated, checked for consistency, and checked against the implementation's
+/// capabilities. If the implementation cannot handle a texture of the requested texture size, it
+/// sets all of the image state to 0, but does not generate an error.
+///
+/// `internal_format` must be a specific compressed format. The RGTC and BPTC formats only support
+/// array textures, not [TextureBinding3DTarget::Image3D]. For these block compressed formats the
+/// size of the image is known, and a slice of `data` is checked to hold the whole image (see
+/// [compressed_image_size]) before it is read.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [CompressedTextureData::Offset].
+///
+/// # Compatability
+/// * 4.0 - [TextureBinding3DTarget::CubeMapArray], [TextureBinding3DTarget::ProxyCubeMapArray]
+/// * 4.2 - [TextureInternalFormat::CompressedRGBABPTCUNORM],
+/// [TextureInternalFormat::CompressedSRGBABPTCUNORM],
+/// [TextureInternalFormat::CompressedRGBBPTCSignedFloat],
+/// [TextureInternalFormat::CompressedRGBBPTCUnsignedFloat]
+///
+/// # Panics
+/// [compressed_tex_image_3d] panics if a slice of `data` is too small to hold the compressed image of a block compressed `internal_format`.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the
+/// compressed image of a block compressed `internal_format`, from [compressed_tex_image_3d_checked].
+/// * [Error::InvalidEnum] - if `internal_format` is not a specific compressed internal format.
+/// * [Error::InvalidValue] - if `imageSize` is not consistent with the format, dimensions, and
+/// contents of the specified compressed image data.
+/// * [Error::InvalidOperation] - if parameter combinations are not supported by the specific
+/// compressed internal format as specified in the specific texture compression extension.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_compressed_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [compressed_tex_image_3d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [compressed_tex_image_1d], [compressed_tex_image_2d]
+/// * [compressed_tex_sub_image_3d]
+/// * [tex_image_3d]
+pub fn compressed_tex_image_3d(
+    target: TextureBinding3DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    depth: u32,
+    data: CompressedTextureData,
+) {
+    let (image_size, data) = compressed_data(internal_format, width, height, depth, data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let internal_format = GLenum::from(internal_format);
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let depth = depth as GLsizei;
 
-        // SAFE: TEXTURE_BORDER_COLOUR expects an array size of 4, and is synchronously read,
-        // no memory is retained
-        unsafe { gl::TexParameterIuiv(target, gl::TEXTURE_BORDER_COLOR, params) }
+    // SAFE: synchronous read of `image_size` bytes of `data`, which was checked to hold the whole
+    // image, no memory retained
+    unsafe {
+        gl::CompressedTexImage3D(
+            target,
+            level,
+            internal_format,
+            width,
+            height,
+            depth,
+            0,
+            image_size,
+            data,
+        )
     }
+}
 
-    /// # Set the comparison operator for textures
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `func` - comparison operator
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_compare_func(TextureBindingTarget::Image2D, CompareFunc::LessOrEqual);
-    /// ```
-    ///
-    /// # Description
-    /// Specifies the comparison operator used when [texture_target_compare_mode] is set to
-    /// [TextureCompareMode::Ref].
-    ///
-    /// # Version Support
-    ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_compare_func] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    ///
-    /// # See Also
-    /// * [tex_parameter]
-    pub fn texture_target_compare_func(target: TextureBindingTarget, func: CompareFunc) {
-        let param = GLenum::from(func) as i32;
-        tex_param_i32(target, gl::TEXTURE_COMPARE_FUNC, param)
+/// # Error mapped specify a three-dimensional texture image in a compressed format
+/// see [compressed_tex_image_3d]
+pub fn compressed_tex_image_3d_checked(
+    target: TextureBinding3DTarget,
+    level: u32,
+    internal_format: TextureInternalFormat,
+    width: u32,
+    height: u32,
+    depth: u32,
+    data: CompressedTextureData,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, CompressedTextureData::Offset { .. });
+    let size = compressed_data_size(internal_format, width, height, depth, &data);
+    check_compressed_data(internal_format, width, height, depth, &data)?;
+    compressed_tex_image_3d(target, level, internal_format, width, height, depth, data);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(compressed_tex_image_error(
+            internal_format,
+            width,
+            height,
+            size,
+            unpack_buffer,
+            error,
+        )),
     }
+}
 
-    /// # Set the texture comparion mode
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `mode` - comparison mode
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_compare_mode(TextureBindingTarget::Image2D, TextureCompareMode::Ref);
-    /// ```
-    ///
-    /// # Description
-    /// Specifies the texture comparison mode for currently bound depth textures. That is, a texture
-    /// whose internal format is [TextureInternalFormat::DepthComponent] (or similar); (see
-    /// [tex_image_2d]).
-    ///
-    /// # Version Support
-    ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_compare_mode] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    ///
-    /// # See Also
-    /// * [tex_parameter]
-    pub fn texture_target_compare_mode(target: TextureBindingTarget, mode: TextureCompareMode) {
-        let param = GLenum::from(mode) as i32;
-        tex_param_i32(target, gl::TEXTURE_COMPARE_MODE, param)
+/// Decode the error of a compressed texture sub-image command, where `region` is the offset and
+/// extent of the updated region in the x and y directions
+fn compressed_tex_sub_image_error(
+    format: TextureInternalFormat,
+    level: u32,
+    region: [u32; 4],
+    unpack_buffer: bool,
+    error: Error,
+) -> TextureError {
+    let max_level = (get_i32(gl::MAX_TEXTURE_SIZE).max(1) as u32).ilog2();
+    let misaligned = compressed_block(format).is_some_and(|(block_width, block_height, _)| {
+        let [x_offset, y_offset, ..] = region;
+        x_offset % block_width != 0 || y_offset % block_height != 0
+    });
+    match error {
+        Error::InvalidEnum => TextureError::InvalidCompressedFormat(format),
+        Error::InvalidValue if level > max_level => TextureError::InvalidLevel(level),
+        Error::InvalidValue => TextureError::InvalidRegion,
+        Error::InvalidOperation if unpack_buffer => TextureError::InvalidPixelBuffer,
+        Error::InvalidOperation if misaligned => TextureError::InvalidRegion,
+        Error::InvalidOperation => TextureError::InvalidCompressedFormat(format),
+        other => TextureError::Unexpected(other),
     }
+}
 
-    /// # Set the fixed bias for the level-of-detail
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `bias` - floating point lod bias
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_lod_bias(TextureBindingTarget::Image2D, 42.7);
-    /// ```
-    ///
-    /// # Description
-    /// `bias` specifies a fixed bias value that is to be added to the level-of-detail parameter for
-    /// the texture before texture sampling. The specified value is added to the shader-supplied
-    /// bias value (if any) and subsequently clamped into the implementation-defined range
-    /// \[−bias<sub>max</sub>, bias<sub>max</sub>\], where bias<sub>max</sub> is the value of the
-    /// implementation defined [get_texture_lod_bias]. The initial value is 0.0.
-    ///
-    /// # Version Support
-    ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_lod_bias] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    ///
-    /// # See Also
-    /// * [tex_parameter]
-    pub fn texture_target_lod_bias(target: TextureBindingTarget, bias: f32) {
-        tex_param_f32(target, gl::TEXTURE_LOD_BIAS, bias)
+/// # Specify a one-dimensional texture subimage in a compressed format
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCompressedTexSubImage1D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture is bound.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `x_offset` - Specifies a texel offset in the x direction within the texture array.
+/// * `width` - Specifies the width of the texture subimage.
+/// * `format` - Specifies the format of the compressed image data, which must match the internal
+/// format of the texture image.
+/// * `data` - Specifies a slice to the compressed image data in memory, or an offset and size into
+/// a [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// # let blocks = [0u8; 16];
+/// compressed_tex_sub_image_1d(
+///     TextureBinding1DTarget::Image1D,
+///     0,
+///     0,
+///     4,
+///     TextureInternalFormat::CompressedRGBA,
+///     CompressedTextureData::Data(&blocks),
+/// );
+/// ```
+///
+/// # Description
+/// [compressed_tex_sub_image_1d] redefines a contiguous subregion of an existing one-dimensional
+/// texture image. The texels referenced by `data` replace the portion of the existing texture
+/// array with x indices `x_offset` and `x_offset + width - 1`, inclusive. This region may not
+/// include any texels outside the range of the texture array as it was originally specified. It
+/// is not an error to specify a subtexture with width of 0, but such a specification has no
+/// effect.
+///
+/// `format` must be a known compressed image format, and must match the internal format of the
+/// texture image being modified.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [CompressedTextureData::Offset].
+///
+/// # Panics
+/// [compressed_tex_sub_image_1d] panics if a slice of `data` is too small to hold the compressed subimage of a block compressed `format`.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the
+/// compressed subimage of a block compressed `format`, from [compressed_tex_sub_image_1d_checked].
+/// * [Error::InvalidEnum] - if `format` is not a supported specific compressed internal format.
+/// * [Error::InvalidValue] - if `imageSize` is not consistent with the format, dimensions, and
+/// contents of the specified compressed image data.
+/// * [Error::InvalidValue] - if `x_offset + width` is greater than the width of the texture image.
+/// * [Error::InvalidOperation] - if parameter combinations are not supported by the specific
+/// compressed internal format as specified in the specific texture compression extension.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_compressed_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [compressed_tex_sub_image_1d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [compressed_tex_image_1d]
+/// * [compressed_tex_sub_image_2d], [compressed_tex_sub_image_3d]
+/// * [tex_sub_image_1d]
+pub fn compressed_tex_sub_image_1d(
+    target: TextureBinding1DTarget,
+    level: u32,
+    x_offset: u32,
+    width: u32,
+    format: TextureInternalFormat,
+    data: CompressedTextureData,
+) {
+    let (image_size, data) = compressed_data(format, width, 1, 1, data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let x_offset = x_offset as GLint;
+    let width = width as GLsizei;
+    let format = GLenum::from(format);
+
+    // SAFE: synchronous read of `image_size` bytes of `data`, which was checked to hold the whole
+    // subimage, no memory retained
+    unsafe { gl::CompressedTexSubImage1D(target, level, x_offset, width, format, image_size, data) }
+}
+
+/// # Error mapped specify a one-dimensional texture subimage in a compressed format
+/// see [compressed_tex_sub_image_1d]
+pub fn compressed_tex_sub_image_1d_checked(
+    target: TextureBinding1DTarget,
+    level: u32,
+    x_offset: u32,
+    width: u32,
+    format: TextureInternalFormat,
+    data: CompressedTextureData,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, CompressedTextureData::Offset { .. });
+    check_compressed_data(format, width, 1, 1, &data)?;
+    compressed_tex_sub_image_1d(target, level, x_offset, width, format, data);
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(compressed_tex_sub_image_error(
+            format,
+            level,
+            [x_offset, 0, width, 1],
+            unpack_buffer,
+            error,
+        )),
     }
+}
 
-    /// # Set the minifying function
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `filter` - Specifies the minifying function
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_min_filter(TextureBindingTarget::Image2D, TextureMinFilter::Nearest);
-    /// ```
-    ///
-    /// # Description
-    /// The texture minifying function is used whenever the level-of-detail function used when
-    /// sampling from the texture determines that the texture should be minified. There are six
-    /// defined minifying functions. Two of them use either the nearest texture elements or a
-    /// weighted average of multiple texture elements to compute the texture value. The other four
-    /// use mipmaps. See [TextureMinFilter]
-    ///
-    /// A mipmap is an ordered set of arrays representing the same image at progressively lower
-    /// resolutions. If the texture has dimensions 2<sup>n</sup>×2<sup>m</sup>, there are
-    /// `max(n,m) + 1` mipmaps. The first mipmap is the original texture, with dimensions
-    /// 2<sup>n</sup>×2<sup>m</sup>. Each subsequent mipmap has dimensions
-    /// 2<sup>k−1</sup>×2<sup>l−1</sup>, where 2<sup>k</sup>×2<sup>l</sup> are the dimensions of the
-    /// previous mipmap, until either `k=0` or `l=0`. At that point, subsequent mipmaps have
-    /// dimension 1×2<sup>l−1</sup> or 2<sup>k−1</sup>×1 until the final mipmap, which has dimension
-    /// `1×1`. To define the mipmaps, call [tex_image_1d], [tex_image_2d], [tex_image_3d],
-    /// [copy_tex_image_1d], or [copy_tex_image_2d] with the level argument indicating the order of
-    /// the mipmaps. Level 0 is the original texture; level `max(n,m)` is the final `1×1` mipmap.
-    ///
-    /// Suppose that a program attempts to sample from a texture and has set
-    /// [texture_target_min_filter] to one of the functions that requires a mipmap. If either the
-    /// dimensions of the texture images currently defined do not follow the proper sequence for
-    /// mipmaps (described above), or there are fewer texture images defined than are needed, or the
-    /// set of texture images have differing numbers of texture components, then the texture is
-    /// considered incomplete.
-    ///
-    /// Linear filtering accesses the four nearest texture elements only in 2D textures. In 1D
-    /// textures, linear filtering accesses the two nearest texture elements. In 3D textures, linear
-    /// filtering accesses the eight nearest texture elements.
-    ///
-    /// # Errors
-    /// * [Error::InvalidEnum] - if `target` is [TextureBindingTarget::Rectangle] and `filter` is
-    /// *not* one of: [TextureMinFilter::Nearest], [TextureMinFilter::Linear] (no mipmap filtering
-    /// is permitted).
-    ///
-    /// # Version Support
-    ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_min_filter] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    ///
-    /// # See Also
-    /// * [tex_parameter]
-    pub fn texture_target_min_filter(target: TextureBindingTarget, filter: TextureMinFilter) {
-        let param = GLenum::from(filter) as i32;
-        tex_param_i32(target, gl::TEXTURE_MIN_FILTER, param)
+/// # Specify a two-dimensional texture subimage in a compressed format
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCompressedTexSubImage2D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture is bound.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `x_offset` - Specifies a texel offset in the x direction within the texture array.
+/// * `y_offset` - Specifies a texel offset in the y direction within the texture array.
+/// * `width` - Specifies the width of the texture subimage.
+/// * `height` - Specifies the height of the texture subimage.
+/// * `format` - Specifies the format of the compressed image data, which must match the internal
+/// format of the texture image.
+/// * `data` - Specifies a slice to the compressed image data in memory, or an offset and size into
+/// a [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// # let blocks = [0u8; 64];
+/// // replace the top left 8x8 texels of a BC7 texture
+/// compressed_tex_sub_image_2d(
+///     TextureBinding2DTarget::Image2D,
+///     0,
+///     0,
+///     0,
+///     8,
+///     8,
+///     TextureInternalFormat::CompressedRGBABPTCUNORM,
+///     CompressedTextureData::Data(&blocks),
+/// );
+/// ```
+///
+/// # Description
+/// [compressed_tex_sub_image_2d] redefines a contiguous subregion of an existing two-dimensional
+/// texture image. The texels referenced by `data` replace the portion of the existing texture
+/// array with x indices `x_offset` and `x_offset + width - 1`, inclusive, and y indices `y_offset`
+/// and `y_offset + height - 1`, inclusive. This region may not include any texels outside the
+/// range of the texture array as it was originally specified. It is not an error to specify a
+/// subtexture with width of 0, but such a specification has no effect.
+///
+/// `format` must be a known compressed image format, and must match the internal format of the
+/// texture image being modified. For the block compressed formats, `x_offset` and `y_offset` must
+/// be multiples of the block size, and `width` and `height` must be too, unless the region reaches
+/// the right or bottom edge of the image. This is the way to update the images of an immutable
+/// compressed texture allocated by [tex_storage_2d].
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [CompressedTextureData::Offset].
+///
+/// # Panics
+/// [compressed_tex_sub_image_2d] panics if a slice of `data` is too small to hold the compressed subimage of a block compressed `format`.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the
+/// compressed subimage of a block compressed `format`, from [compressed_tex_sub_image_2d_checked].
+/// * [Error::InvalidEnum] - if `format` is not a supported specific compressed internal format.
+/// * [Error::InvalidValue] - if `imageSize` is not consistent with the format, dimensions, and
+/// contents of the specified compressed image data.
+/// * [Error::InvalidValue] - if `x_offset + width` is greater than the width, or
+/// `y_offset + height` is greater than the height, of the texture image.
+/// * [Error::InvalidOperation] - if `format` does not match the internal format of the texture
+/// image.
+/// * [Error::InvalidOperation] - if the region is not aligned to the blocks of a block compressed
+/// `format`.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_compressed_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [compressed_tex_sub_image_2d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [compressed_tex_image_2d]
+/// * [compressed_tex_sub_image_1d], [compressed_tex_sub_image_3d]
+/// * [tex_storage_2d]
+/// * [tex_sub_image_2d]
+#[allow(clippy::too_many_arguments)]
+pub fn compressed_tex_sub_image_2d(
+    target: TextureBinding2DTarget,
+    level: u32,
+    x_offset: u32,
+    y_offset: u32,
+    width: u32,
+    height: u32,
+    format: TextureInternalFormat,
+    data: CompressedTextureData,
+) {
+    let (image_size, data) = compressed_data(format, width, height, 1, data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let x_offset = x_offset as GLint;
+    let y_offset = y_offset as GLint;
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let format = GLenum::from(format);
+
+    // SAFE: synchronous read of `image_size` bytes of `data`, which was checked to hold the whole
+    // subimage, no memory retained
+    unsafe {
+        gl::CompressedTexSubImage2D(
+            target, level, x_offset, y_offset, width, height, format, image_size, data,
+        )
     }
+}
 
-    /// # Set magnification function
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `filter` - Specifies the magnification function
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_mag_filter(TextureBindingTarget::Image2D, TextureMagFilter::Linear);
-    /// ```
-    ///
-    /// # Description
-    /// The texture magnification function is used whenever the level-of-detail function used when
-    /// sampling from the texture determines that the texture should be magnified.
-    /// [TextureMagFilter::Nearest] is generally faster than [TextureMagFilter::Linear], but it can
-    /// produce textured images with sharper edges because the transition between texture elements
-    /// is not as smooth. The initial value is [TextureMagFilter::Linear].
-    ///
-    /// # Version Support
-    ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_mag_filter] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    ///
-    /// # See Also
-    /// * [tex_parameter]
-    pub fn texture_target_mag_filter(target: TextureBindingTarget, filter: TextureMagFilter) {
-        let param = GLenum::from(filter) as i32;
-        tex_param_i32(target, gl::TEXTURE_MAG_FILTER, param)
+/// # Error mapped specify a two-dimensional texture subimage in a compressed format
+/// see [compressed_tex_sub_image_2d]
+#[allow(clippy::too_many_arguments)]
+pub fn compressed_tex_sub_image_2d_checked(
+    target: TextureBinding2DTarget,
+    level: u32,
+    x_offset: u32,
+    y_offset: u32,
+    width: u32,
+    height: u32,
+    format: TextureInternalFormat,
+    data: CompressedTextureData,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, CompressedTextureData::Offset { .. });
+    check_compressed_data(format, width, height, 1, &data)?;
+    compressed_tex_sub_image_2d(
+        target, level, x_offset, y_offset, width, height, format, data,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(compressed_tex_sub_image_error(
+            format,
+            level,
+            [x_offset, y_offset, width, height],
+            unpack_buffer,
+            error,
+        )),
     }
+}
 
-    /// # Set the wrap value of the `s` coordinate
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `mode` - Specifies the texture wrap mode
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_wrap_s(TextureBindingTarget::Image2D, TextureWrapMode::Repeat);
-    /// ```
-    ///
-    /// # Description
-    /// Sets the wrap parameter for texture coordinate `s`. Initially set to
-    /// [TextureWrapMode::Repeat].
-    ///
-    /// # Compatability
-    /// * 4.4 - [TextureWrapMode::MirrorClampToEdge]
-    ///
-    /// # Errors
-    /// * [Error::InvalidEnum] - if `target` is [TextureBindingTarget::Rectangle] and `mode` is one
-    /// of: [TextureWrapMode::MirrorClampToEdge], [TextureWrapMode::MirroredRepeat],
-    /// [TextureWrapMode::Repeat].
-    ///
-    /// # Version Support
-    ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_wrap_s] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    ///
-    /// # See Also
-    /// * [TextureWrapMode]
-    /// * [tex_parameter]
-    pub fn texture_target_wrap_s(target: TextureBindingTarget, mode: TextureWrapMode) {
-        let param = GLenum::from(mode) as i32;
-        tex_param_i32(target, gl::TEXTURE_WRAP_S, param)
+/// # Specify a three-dimensional texture subimage in a compressed format
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glCompressedTexSubImage3D.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture is bound.
+/// * `level` - Specifies the level-of-detail number. Level 0 is the base image level. Level `n` is
+/// the `n`th mipmap reduction image.
+/// * `x_offset` - Specifies a texel offset in the x direction within the texture array.
+/// * `y_offset` - Specifies a texel offset in the y direction within the texture array.
+/// * `z_offset` - Specifies a texel offset in the z direction within the texture array, or the
+/// first layer of an array texture.
+/// * `width` - Specifies the width of the texture subimage.
+/// * `height` - Specifies the height of the texture subimage.
+/// * `depth` - Specifies the depth of the texture subimage, or the number of layers of an array
+/// texture.
+/// * `format` - Specifies the format of the compressed image data, which must match the internal
+/// format of the texture image.
+/// * `data` - Specifies a slice to the compressed image data in memory, or an offset and size into
+/// a [BufferBindingTarget::PixelUnpack] buffer.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// # let blocks = vec![0u8; 4096];
+/// // replace layer 2 of a BC5 array texture
+/// compressed_tex_sub_image_3d(
+///     TextureBinding3DTarget::Image2DArray,
+///     0,
+///     0,
+///     0,
+///     2,
+///     64,
+///     64,
+///     1,
+///     TextureInternalFormat::CompressedRGRGTC2,
+///     CompressedTextureData::Data(&blocks),
+/// );
+/// ```
+///
+/// # Description
+/// [compressed_tex_sub_image_3d] redefines a contiguous subregion of an existing
+/// three-dimensional or array texture image. The texels referenced by `data` replace the portion
+/// of the existing texture array with x indices `x_offset` and `x_offset + width - 1`, inclusive,
+/// y indices `y_offset` and `y_offset + height - 1`, inclusive, and z indices `z_offset` and
+/// `z_offset + depth - 1`, inclusive. For array textures, the z index refers to the layer, which
+/// for cube map arrays is `layer * 6 + face`. This region may not include any texels outside the
+/// range of the texture array as it was originally specified. It is not an error to specify a
+/// subtexture with width of 0, but such a specification has no effect.
+///
+/// `format` must be a known compressed image format, and must match the internal format of the
+/// texture image being modified. For the block compressed formats, `x_offset` and `y_offset` must
+/// be multiples of the block size, and `width` and `height` must be too, unless the region reaches
+/// the right or bottom edge of the image.
+///
+/// If a non-zero named buffer object is bound to the [BufferBindingTarget::PixelUnpack] target
+/// (see [bind_buffer]) while a texture image is specified, `data` is treated as a byte offset into
+/// the buffer object's data store, given by [CompressedTextureData::Offset].
+///
+/// # Panics
+/// [compressed_tex_sub_image_3d] panics if a slice of `data` is too small to hold the compressed subimage of a block compressed `format`.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if a slice of `data` is too small to hold the
+/// compressed subimage of a block compressed `format`, from [compressed_tex_sub_image_3d_checked].
+/// * [Error::InvalidEnum] - if `format` is not a supported specific compressed internal format.
+/// * [Error::InvalidValue] - if `imageSize` is not consistent with the format, dimensions, and
+/// contents of the specified compressed image data.
+/// * [Error::InvalidValue] - if `x_offset + width`, `y_offset + height` or `z_offset + depth` is
+/// greater than the width, height or depth of the texture image.
+/// * [Error::InvalidOperation] - if `format` does not match the internal format of the texture
+/// image.
+/// * [Error::InvalidOperation] - if the region is not aligned to the blocks of a block compressed
+/// `format`.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelUnpack] target and the buffer object's data store is currently
+/// mapped, or the data would be unpacked from the buffer object such that the memory reads
+/// required would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_compressed_tex_image]
+/// * [get_pixel_unpack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [compressed_tex_sub_image_3d] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [active_texture]
+/// * [compressed_tex_image_3d]
+/// * [compressed_tex_sub_image_1d], [compressed_tex_sub_image_2d]
+/// * [tex_storage_3d]
+/// * [tex_sub_image_3d]
+#[allow(clippy::too_many_arguments)]
+pub fn compressed_tex_sub_image_3d(
+    target: TextureBinding3DTarget,
+    level: u32,
+    x_offset: u32,
+    y_offset: u32,
+    z_offset: u32,
+    width: u32,
+    height: u32,
+    depth: u32,
+    format: TextureInternalFormat,
+    data: CompressedTextureData,
+) {
+    let (image_size, data) = compressed_data(format, width, height, depth, data);
+
+    let target = GLenum::from(target);
+    let level = level as GLint;
+    let x_offset = x_offset as GLint;
+    let y_offset = y_offset as GLint;
+    let z_offset = z_offset as GLint;
+    let width = width as GLsizei;
+    let height = height as GLsizei;
+    let depth = depth as GLsizei;
+    let format = GLenum::from(format);
+
+    // SAFE: synchronous read of `image_size` bytes of `data`, which was checked to hold the whole
+    // subimage, no memory retained
+    unsafe {
+        gl::CompressedTexSubImage3D(
+            target, level, x_offset, y_offset, z_offset, width, height, depth, format, image_size,
+            data,
+        )
     }
+}
 
-    /// # Set the wrap value of the `t` coordinate
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `mode` - Specifies the texture wrap mode
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_wrap_t(TextureBindingTarget::Image2D, TextureWrapMode::Repeat);
-    /// ```
-    ///
-    /// # Description
-    /// Sets the wrap parameter for texture coordinate `t`. Initially set to
-    /// [TextureWrapMode::Repeat].
-    ///
-    /// # Compatability
-    /// * 4.4 - [TextureWrapMode::MirrorClampToEdge]
-    ///
-    /// # Errors
-    /// * [Error::InvalidEnum] - if `target` is [TextureBindingTarget::Rectangle] and `mode` is one
-    /// of: [TextureWrapMode::MirrorClampToEdge], [TextureWrapMode::MirroredRepeat],
-    /// [TextureWrapMode::Repeat].
-    ///
-    /// # Version Support
-    ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_wrap_t] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    ///
-    /// # See Also
-    /// * [TextureWrapMode]
-    /// * [tex_parameter]
-    pub fn texture_target_wrap_t(target: TextureBindingTarget, mode: TextureWrapMode) {
-        let param = GLenum::from(mode) as i32;
-        tex_param_i32(target, gl::TEXTURE_WRAP_T, param)
+/// # Error mapped specify a three-dimensional texture subimage in a compressed format
+/// see [compressed_tex_sub_image_3d]
+#[allow(clippy::too_many_arguments)]
+pub fn compressed_tex_sub_image_3d_checked(
+    target: TextureBinding3DTarget,
+    level: u32,
+    x_offset: u32,
+    y_offset: u32,
+    z_offset: u32,
+    width: u32,
+    height: u32,
+    depth: u32,
+    format: TextureInternalFormat,
+    data: CompressedTextureData,
+) -> Result<(), TextureError> {
+    let unpack_buffer = matches!(data, CompressedTextureData::Offset { .. });
+    check_compressed_data(format, width, height, depth, &data)?;
+    compressed_tex_sub_image_3d(
+        target, level, x_offset, y_offset, z_offset, width, height, depth, format, data,
+    );
+    match get_error() {
+        Error::NoError => Ok(()),
+        error => Err(compressed_tex_sub_image_error(
+            format,
+            level,
+            [x_offset, y_offset, width, height],
+            unpack_buffer,
+            error,
+        )),
     }
+}
 
-    /// # Set the wrap value of the `r` coordinate
-    /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glTexParameter.xhtml>
-    ///
-    /// # Arguments
-    /// * `target` - Specifies the target to which the texture is bound
-    /// * `mode` - Specifies the texture wrap mode
-    ///
-    /// # Example
-    /// ```no_run
-    /// # use rgl::prelude::*;
-    /// texture_target_wrap_r(TextureBindingTarget::Image2D, TextureWrapMode::Repeat);
-    /// ```
-    ///
-    /// # Description
-    /// Sets the wrap parameter for texture coordinate `r`. Initially set to
-    /// [TextureWrapMode::Repeat].
-    ///
-    /// # Compatability
-    /// * 4.4 - [TextureWrapMode::MirrorClampToEdge]
-    ///
-    /// # Version Support
-    ///
-    /// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
-    /// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
-    /// | [texture_target_wrap_r] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
-    ///
-    /// # See Also
-    /// * [TextureWrapMode]
-    /// * [tex_parameter]
-    pub fn texture_target_wrap_r(target: TextureBindingTarget, mode: TextureWrapMode) {
-        let param = GLenum::from(mode) as i32;
-        tex_param_i32(target, gl::TEXTURE_WRAP_R, param)
+/// # Return a compressed texture image
+/// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGetCompressedTexImage.xhtml>
+///
+/// # Arguments
+/// * `target` - Specifies the target to which the texture is bound.
+/// * `level` - Specifies the level-of-detail number of the desired image. Level 0 is the base image
+/// level. Level `n` is the `n`th mipmap reduction image.
+/// * `data` - Returns the compressed texture image.
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let mut blocks = vec![0u8; 65536];
+/// get_compressed_tex_image(TextureImageTarget::Image2D, 0, &mut blocks);
+/// ```
+///
+/// # Description
+/// [get_compressed_tex_image] returns the compressed texture image associated with `target` and
+/// `level` into `data`. `data` is an array of bytes holding the image in the internal format of
+/// the texture, which can be passed back to [compressed_tex_image_2d] or saved to a file.
+///
+/// The size of the compressed image is queried with [get_tex_level_parameter] for
+/// `TEXTURE_COMPRESSED_IMAGE_SIZE` first. If `data` is too small to hold it,
+/// [get_compressed_tex_image] panics rather than let the GL write past the end of the slice, while
+/// [get_compressed_tex_image_checked] returns [TextureError::InsufficientPixelData] without reading
+/// anything.
+///
+/// # Panics
+/// [get_compressed_tex_image] panics if `data` is too small to hold the compressed image.
+///
+/// # Errors
+/// * [TextureError::InsufficientPixelData] - if `data` is too small to hold the compressed image,
+/// from [get_compressed_tex_image_checked].
+/// * [Error::InvalidValue] - if `level` is less than zero or greater than the maximum number of
+/// LODs permitted by the implementation.
+/// * [Error::InvalidOperation] - if the texture image is stored in an uncompressed internal
+/// format.
+/// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to the
+/// [BufferBindingTarget::PixelPack] target and the buffer object's data store is currently
+/// mapped, or the data would be packed to the buffer object such that the memory writes required
+/// would exceed the data store size.
+///
+/// # Associated Gets
+/// * [get_tex_level_parameter]
+/// * [get_pixel_pack_buffer_binding]
+///
+/// # Version Support
+///
+/// | Function / Feature Name | 2.0 | 2.1 | 3.0 | 3.1 | 3.2 | 3.3 | 4.0 | 4.1 | 4.2 | 4.3 | 4.4 | 4.5 |
+/// |-------------------------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+/// | [get_compressed_tex_image] | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y | Y |
+///
+/// # See Also
+/// * [compressed_tex_image_1d], [compressed_tex_image_2d], [compressed_tex_image_3d]
+/// * [get_tex_image]
+pub fn get_compressed_tex_image(target: TextureImageTarget, level: u32, data: &mut [u8]) {
+    let target = GLenum::from(target);
+    let required =
+        get_tex_level_parameter_i32(target, level, gl::TEXTURE_COMPRESSED_IMAGE_SIZE) as usize;
+    let provided = data.len();
+    assert!(
+        provided >= required,
+        "pixel data holds {provided} bytes, but the image requires {required}"
+    );
+
+    let level = level as GLint;
+    let pixels = data.as_mut_ptr() as *mut std::os::raw::c_void;
+
+    // SAFE: synchronous write into `data`, which was checked to be large enough for the whole
+    // compressed image. No memory retained
+    unsafe { gl::GetCompressedTexImage(target, level, pixels) };
+}
+
+/// # Error mapped return a compressed texture image
+/// see [get_compressed_tex_image]
+pub fn get_compressed_tex_image_checked(
+    target: TextureImageTarget,
+    level: u32,
+    data: &mut [u8],
+) -> Result<(), TextureError> {
+    let required = get_tex_level_parameter_i32(
+        GLenum::from(target),
+        level,
+        gl::TEXTURE_COMPRESSED_IMAGE_SIZE,
+    ) as usize;
+    let provided = data.len();
+    if provided < required {
+        return Err(TextureError::InsufficientPixelData { required, provided });
+    }
+    get_compressed_tex_image(target, level, data);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(TextureError::InvalidLevel(level)),
+        Error::InvalidOperation => Err(TextureError::UncompressedImage),
+        other => Err(TextureError::Unexpected(other)),
     }
 }
-pub use tex_parameter::*;
@@ -0,0 +1,484 @@
+//! # Texture Containers
+//! <https://registry.khronos.org/KTX/specs/2.0/ktxspec.v2.html>
+//!
+//! # Description
+//! Loading of block compressed textures from KTX2 and DDS files. [TextureContainer::parse] reads
+//! the header of either container, maps its format onto a [TextureInternalFormat], and gathers
+//! the image data of every mip level, array layer and cube face. [TextureContainer::upload] then
+//! allocates immutable storage for a new texture, and uploads every image into it:
+//! ```no_run
+//! use rgl::prelude::*;
+//!
+//! let bytes = std::fs::read("albedo.ktx2").unwrap();
+//! let container = TextureContainer::parse(&bytes).unwrap();
+//! let texture = container.upload().unwrap();
+//! // `texture` is left bound to `container.target()`
+//! ```
+//!
+//! Only the block compressed formats known to [TextureInternalFormat] are supported: BC4 and BC5
+//! (RGTC), and BC6H and BC7 (BPTC). KTX2 files must not be supercompressed, and one-dimensional
+//! textures, and three-dimensional array or cube map textures, are rejected.
+
+use crate::prelude::*;
+
+/// # Errors of parsing and uploading a texture container
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ContainerError {
+    /// Uploading the images failed
+    Texture(TextureError),
+
+    /// The file is neither a KTX2 nor a DDS file
+    UnknownContainer,
+
+    /// The file ends before the data described by its header, or the header is inconsistent
+    Malformed,
+
+    /// The container format has no block compressed [TextureInternalFormat], given as the
+    /// `VkFormat` of a KTX2 file, or the DXGI format or FourCC code of a DDS file
+    UnsupportedFormat(u32),
+
+    /// The KTX2 file uses the given supercompression scheme
+    Supercompressed(u32),
+
+    /// The texture is one-dimensional, or a three-dimensional array or cube map
+    UnsupportedDimensions,
+}
+
+impl std::fmt::Display for ContainerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContainerError::Texture(error) => error.fmt(f),
+            ContainerError::UnknownContainer => write!(f, "not a KTX2 or DDS file"),
+            ContainerError::Malformed => {
+                write!(f, "the texture container is truncated or inconsistent")
+            }
+            ContainerError::UnsupportedFormat(format) => {
+                write!(f, "container format {format:#x} is not supported")
+            }
+            ContainerError::Supercompressed(scheme) => {
+                write!(f, "supercompression scheme {scheme} is not supported")
+            }
+            ContainerError::UnsupportedDimensions => {
+                write!(f, "the texture dimensions are not supported")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContainerError {}
+
+impl From<TextureError> for ContainerError {
+    fn from(value: TextureError) -> Self {
+        ContainerError::Texture(value)
+    }
+}
+
+const KTX2_IDENTIFIER: [u8; 12] = *b"\xabKTX 20\xbb\r\n\x1a\n";
+const DDS_MAGIC: [u8; 4] = *b"DDS ";
+
+/// # Texture loaded from a KTX2 or DDS file
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextureContainer {
+    /// Block compressed format of every image
+    pub internal_format: TextureInternalFormat,
+
+    /// Width of the base level, in texels
+    pub width: u32,
+
+    /// Height of the base level, in texels
+    pub height: u32,
+
+    /// Depth of the base level of a three-dimensional texture, 1 otherwise
+    pub depth: u32,
+
+    /// Number of array layers of an array texture, 0 otherwise
+    pub layers: u32,
+
+    /// 6 for a cube map (array) texture, 1 otherwise
+    pub faces: u32,
+
+    /// Compressed images of each mip level, starting at the base level. All images of a level are
+    /// stored in upload order: by layer, then by face (+X, -X, +Y, -Y, +Z, -Z), then by depth
+    pub levels: Vec<Vec<u8>>,
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, ContainerError> {
+    bytes
+        .get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ContainerError::Malformed)
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> Result<u64, ContainerError> {
+    bytes
+        .get(offset..offset + 8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ContainerError::Malformed)
+}
+
+fn slice(bytes: &[u8], offset: usize, length: usize) -> Result<&[u8], ContainerError> {
+    offset
+        .checked_add(length)
+        .and_then(|end| bytes.get(offset..end))
+        .ok_or(ContainerError::Malformed)
+}
+
+/// Number of levels of a full mip chain, down to 1x1x1, of a texture of the given size
+fn max_level_count(width: u32, height: u32, depth: u32) -> u32 {
+    u32::BITS - width.max(height).max(depth).leading_zeros()
+}
+
+/// Map a `VkFormat` onto the block compressed internal formats
+fn vk_format(format: u32) -> Option<TextureInternalFormat> {
+    match format {
+        139 => Some(TextureInternalFormat::CompressedRRGTC1),
+        140 => Some(TextureInternalFormat::CompressedSignedRRGTC1),
+        141 => Some(TextureInternalFormat::CompressedRGRGTC2),
+        142 => Some(TextureInternalFormat::CompressedSignedRGRGTC2),
+        143 => Some(TextureInternalFormat::CompressedRGBBPTCUnsignedFloat),
+        144 => Some(TextureInternalFormat::CompressedRGBBPTCSignedFloat),
+        145 => Some(TextureInternalFormat::CompressedRGBABPTCUNORM),
+        146 => Some(TextureInternalFormat::CompressedSRGBABPTCUNORM),
+        _ => None,
+    }
+}
+
+/// Map a `DXGI_FORMAT` onto the block compressed internal formats
+fn dxgi_format(format: u32) -> Option<TextureInternalFormat> {
+    match format {
+        80 => Some(TextureInternalFormat::CompressedRRGTC1),
+        81 => Some(TextureInternalFormat::CompressedSignedRRGTC1),
+        83 => Some(TextureInternalFormat::CompressedRGRGTC2),
+        84 => Some(TextureInternalFormat::CompressedSignedRGRGTC2),
+        95 => Some(TextureInternalFormat::CompressedRGBBPTCUnsignedFloat),
+        96 => Some(TextureInternalFormat::CompressedRGBBPTCSignedFloat),
+        98 => Some(TextureInternalFormat::CompressedRGBABPTCUNORM),
+        99 => Some(TextureInternalFormat::CompressedSRGBABPTCUNORM),
+        _ => None,
+    }
+}
+
+/// Map a legacy DDS FourCC code onto the block compressed internal formats
+fn four_cc_format(four_cc: &[u8]) -> Option<TextureInternalFormat> {
+    match four_cc {
+        b"ATI1" | b"BC4U" => Some(TextureInternalFormat::CompressedRRGTC1),
+        b"BC4S" => Some(TextureInternalFormat::CompressedSignedRRGTC1),
+        b"ATI2" | b"BC5U" => Some(TextureInternalFormat::CompressedRGRGTC2),
+        b"BC5S" => Some(TextureInternalFormat::CompressedSignedRGRGTC2),
+        _ => None,
+    }
+}
+
+impl TextureContainer {
+    /// Parse a KTX2 or DDS file, detected by its identifier
+    pub fn parse(bytes: &[u8]) -> Result<TextureContainer, ContainerError> {
+        if bytes.starts_with(&KTX2_IDENTIFIER) {
+            TextureContainer::parse_ktx2(bytes)
+        } else if bytes.starts_with(&DDS_MAGIC) {
+            TextureContainer::parse_dds(bytes)
+        } else {
+            Err(ContainerError::UnknownContainer)
+        }
+    }
+
+    /// Parse a KTX2 file
+    pub fn parse_ktx2(bytes: &[u8]) -> Result<TextureContainer, ContainerError> {
+        if !bytes.starts_with(&KTX2_IDENTIFIER) {
+            return Err(ContainerError::UnknownContainer);
+        }
+
+        let format = read_u32(bytes, 12)?;
+        let internal_format = vk_format(format).ok_or(ContainerError::UnsupportedFormat(format))?;
+        let width = read_u32(bytes, 20)?;
+        let height = read_u32(bytes, 24)?;
+        let depth = read_u32(bytes, 28)?;
+        let layers = read_u32(bytes, 32)?;
+        let faces = read_u32(bytes, 36)?;
+        let level_count = read_u32(bytes, 40)?.max(1);
+        let scheme = read_u32(bytes, 44)?;
+        if scheme != 0 {
+            return Err(ContainerError::Supercompressed(scheme));
+        }
+        if height == 0 || (depth > 0 && (layers > 0 || faces == 6)) {
+            return Err(ContainerError::UnsupportedDimensions);
+        }
+        if width == 0 || (faces != 1 && faces != 6) {
+            return Err(ContainerError::Malformed);
+        }
+        if level_count > max_level_count(width, height, depth) {
+            return Err(ContainerError::Malformed);
+        }
+
+        let mut container = TextureContainer {
+            internal_format,
+            width,
+            height,
+            depth: depth.max(1),
+            layers,
+            faces,
+            levels: Vec::with_capacity(level_count as usize),
+        };
+        for level in 0..level_count {
+            // the level index follows the 80 byte header, with 24 bytes per level
+            let index = 80 + 24 * level as usize;
+            let offset = read_u64(bytes, index)? as usize;
+            let length = read_u64(bytes, index + 8)? as usize;
+            if Some(length) != container.level_size(level) {
+                return Err(ContainerError::Malformed);
+            }
+            container
+                .levels
+                .push(slice(bytes, offset, length)?.to_vec());
+        }
+        Ok(container)
+    }
+
+    /// Parse a DDS file, either with a `DX10` header extension, or a legacy BC4 or BC5 FourCC
+    pub fn parse_dds(bytes: &[u8]) -> Result<TextureContainer, ContainerError> {
+        const DDSD_MIPMAPCOUNT: u32 = 0x20000;
+        const DDPF_FOURCC: u32 = 0x4;
+        const DDSCAPS2_CUBEMAP: u32 = 0x200;
+        const DDSCAPS2_VOLUME: u32 = 0x200000;
+        const DDS_RESOURCE_MISC_TEXTURECUBE: u32 = 0x4;
+        const DDS_DIMENSION_TEXTURE1D: u32 = 2;
+        const DDS_DIMENSION_TEXTURE3D: u32 = 4;
+
+        if !bytes.starts_with(&DDS_MAGIC) {
+            return Err(ContainerError::UnknownContainer);
+        }
+        if read_u32(bytes, 4)? != 124 {
+            return Err(ContainerError::Malformed);
+        }
+
+        let flags = read_u32(bytes, 8)?;
+        let height = read_u32(bytes, 12)?;
+        let width = read_u32(bytes, 16)?;
+        let depth = read_u32(bytes, 24)?;
+        let level_count = match flags & DDSD_MIPMAPCOUNT {
+            0 => 1,
+            _ => read_u32(bytes, 28)?.max(1),
+        };
+        let pixel_format_flags = read_u32(bytes, 80)?;
+        let four_cc = slice(bytes, 84, 4)?;
+        let caps2 = read_u32(bytes, 112)?;
+        if pixel_format_flags & DDPF_FOURCC == 0 {
+            return Err(ContainerError::UnsupportedFormat(0));
+        }
+
+        let mut container = TextureContainer {
+            internal_format: TextureInternalFormat::CompressedRRGTC1,
+            width,
+            height,
+            depth: 1,
+            layers: 0,
+            faces: 1,
+            levels: Vec::new(),
+        };
+        let mut data_offset = 128;
+        if four_cc == b"DX10" {
+            let format = read_u32(bytes, 128)?;
+            container.internal_format =
+                dxgi_format(format).ok_or(ContainerError::UnsupportedFormat(format))?;
+            let dimension = read_u32(bytes, 132)?;
+            let misc_flags = read_u32(bytes, 136)?;
+            let array_size = read_u32(bytes, 140)?;
+            if dimension == DDS_DIMENSION_TEXTURE1D {
+                return Err(ContainerError::UnsupportedDimensions);
+            }
+            if misc_flags & DDS_RESOURCE_MISC_TEXTURECUBE != 0 {
+                container.faces = 6;
+            }
+            if dimension == DDS_DIMENSION_TEXTURE3D {
+                container.depth = depth.max(1);
+            }
+            if array_size > 1 {
+                container.layers = array_size;
+            }
+            data_offset = 148;
+        } else {
+            let code = u32::from_le_bytes(four_cc.try_into().unwrap());
+            container.internal_format =
+                four_cc_format(four_cc).ok_or(ContainerError::UnsupportedFormat(code))?;
+            if caps2 & DDSCAPS2_CUBEMAP != 0 {
+                container.faces = 6;
+            }
+            if caps2 & DDSCAPS2_VOLUME != 0 {
+                container.depth = depth.max(1);
+            }
+        }
+        if container.depth > 1 && (container.layers > 0 || container.faces == 6) {
+            return Err(ContainerError::UnsupportedDimensions);
+        }
+        if width == 0 || height == 0 {
+            return Err(ContainerError::Malformed);
+        }
+        if level_count > max_level_count(width, height, container.depth) {
+            return Err(ContainerError::Malformed);
+        }
+
+        // each surface is a single layer of an array texture
+        let surface_sizes = (0..level_count)
+            .map(|level| {
+                let (width, height, depth) = match container.level_dimensions(level) {
+                    (width, height, _) if container.layers > 0 => (width, height, 1),
+                    dimensions => dimensions,
+                };
+                compressed_image_size(container.internal_format, width, height, depth)
+            })
+            .collect::<Option<Vec<usize>>>()
+            .ok_or(ContainerError::Malformed)?;
+        let images = container
+            .layers
+            .max(1)
+            .checked_mul(container.faces)
+            .ok_or(ContainerError::Malformed)?;
+        let chain_size = surface_sizes
+            .iter()
+            .try_fold(0usize, |total, size| total.checked_add(*size));
+        chain_size
+            .and_then(|size| size.checked_mul(images as usize))
+            .and_then(|size| size.checked_add(data_offset))
+            .filter(|end| *end <= bytes.len())
+            .ok_or(ContainerError::Malformed)?;
+
+        // DDS stores the whole mip chain of each layer and face in turn, so gather the images of
+        // each level together
+        container.levels = surface_sizes
+            .iter()
+            .map(|size| Vec::with_capacity(size * images as usize))
+            .collect();
+        for _ in 0..images {
+            for (level, size) in surface_sizes.iter().enumerate() {
+                let image = slice(bytes, data_offset, *size)?;
+                container.levels[level].extend_from_slice(image);
+                data_offset += size;
+            }
+        }
+        Ok(container)
+    }
+
+    /// Width, height and depth of a mip level, where the depth of array textures is the number of
+    /// layers
+    pub fn level_dimensions(&self, level: u32) -> (u32, u32, u32) {
+        let width = self.width.checked_shr(level).unwrap_or(0).max(1);
+        let height = self.height.checked_shr(level).unwrap_or(0).max(1);
+        let depth = match self.layers {
+            0 => self.depth.checked_shr(level).unwrap_or(0).max(1),
+            layers => layers,
+        };
+        (width, height, depth)
+    }
+
+    /// Size of all images of a mip level, in bytes, or `None` if it does not fit in a `usize`
+    pub fn level_size(&self, level: u32) -> Option<usize> {
+        let (width, height, depth) = self.level_dimensions(level);
+        compressed_image_size(self.internal_format, width, height, depth)?
+            .checked_mul(self.faces as usize)
+    }
+
+    /// Texture target matching the dimensions of the container
+    pub fn target(&self) -> TextureBindingTarget {
+        match (self.layers, self.faces) {
+            (0, 6) => TextureBindingTarget::CubeMap,
+            (0, _) if self.depth > 1 => TextureBindingTarget::Image3D,
+            (0, _) => TextureBindingTarget::Image2D,
+            (_, 6) => TextureBindingTarget::CubeMapArray,
+            (_, _) => TextureBindingTarget::Array2D,
+        }
+    }
+
+    /// # Upload the container into a new texture
+    /// Generates a texture, binds it to [target](TextureContainer::target), allocates immutable
+    /// storage for every level with [tex_storage_2d] or [tex_storage_3d], and uploads every image
+    /// with [compressed_tex_sub_image_2d] or [compressed_tex_sub_image_3d]. The texture is left
+    /// bound, and deleted again if an upload fails.
+    pub fn upload(&self) -> Result<Texture, ContainerError> {
+        let mut texture = Texture::default();
+        gen_textures(std::slice::from_mut(&mut texture));
+        bind_texture(self.target(), texture);
+        self.upload_images().map(|_| texture).inspect_err(|_| {
+            bind_texture(self.target(), Texture::default());
+            delete_textures(&[texture]);
+        })
+    }
+
+    fn upload_images(&self) -> Result<(), ContainerError> {
+        const FACES: [TextureBinding2DTarget; 6] = [
+            TextureBinding2DTarget::CubeMapPositiveX,
+            TextureBinding2DTarget::CubeMapNegativeX,
+            TextureBinding2DTarget::CubeMapPositiveY,
+            TextureBinding2DTarget::CubeMapNegativeY,
+            TextureBinding2DTarget::CubeMapPositiveZ,
+            TextureBinding2DTarget::CubeMapNegativeZ,
+        ];
+
+        let levels = self.levels.len() as u32;
+        let format = self.internal_format;
+        let target_3d = match self.target() {
+            TextureBindingTarget::Image3D => Some(TextureBinding3DTarget::Image3D),
+            TextureBindingTarget::Array2D => Some(TextureBinding3DTarget::Image2DArray),
+            TextureBindingTarget::CubeMapArray => Some(TextureBinding3DTarget::CubeMapArray),
+            _ => None,
+        };
+
+        if let Some(target) = target_3d {
+            let depth = self.depth.max(self.layers * self.faces);
+            tex_storage_3d_checked(target, levels, format, self.width, self.height, depth)?;
+        } else {
+            let target = match self.faces {
+                6 => TextureStorage2DTarget::CubeMap,
+                _ => TextureStorage2DTarget::Image2D,
+            };
+            tex_storage_2d_checked(target, levels, format, self.width, self.height)?;
+        }
+
+        for (level, data) in self.levels.iter().enumerate() {
+            let level = level as u32;
+            let (width, height, depth) = self.level_dimensions(level);
+            match target_3d {
+                Some(target) => {
+                    let depth = depth * self.faces;
+                    let data = CompressedTextureData::Data(data);
+                    compressed_tex_sub_image_3d_checked(
+                        target, level, 0, 0, 0, width, height, depth, format, data,
+                    )?
+                }
+                None if self.faces == 6 => {
+                    let face_size = data.len() / 6;
+                    for (face, data) in FACES.into_iter().zip(data.chunks(face_size)) {
+                        let data = CompressedTextureData::Data(data);
+                        compressed_tex_sub_image_2d_checked(
+                            face, level, 0, 0, width, height, format, data,
+                        )?
+                    }
+                }
+                None => {
+                    let data = CompressedTextureData::Data(data);
+                    compressed_tex_sub_image_2d_checked(
+                        TextureBinding2DTarget::Image2D,
+                        level,
+                        0,
+                        0,
+                        width,
+                        height,
+                        format,
+                        data,
+                    )?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// # Load a KTX2 or DDS file into a new texture
+/// see [TextureContainer::parse] and [TextureContainer::upload]
+///
+/// # Example
+/// ```no_run
+/// # use rgl::prelude::*;
+/// let texture = load_texture_container(&std::fs::read("normals.dds").unwrap()).unwrap();
+/// ```
+pub fn load_texture_container(bytes: &[u8]) -> Result<Texture, ContainerError> {
+    TextureContainer::parse(bytes)?.upload()
+}
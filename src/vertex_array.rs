@@ -87,6 +87,55 @@ impl From<VertexAttribFloatType> for GLenum {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum VertexArrayError {
+    Unexpected(Error),
+
+    /// Not zero or the name of a vertex array object
+    InvalidVertexArray(VertexArray),
+
+    /// No vertex array object is bound
+    NoVertexArrayBound,
+
+    /// The attribute index is not less than [get_max_vertex_attribs]
+    InvalidAttribIndex(u32),
+
+    /// Zero is bound to [BufferBindingTarget::Array]
+    UnboundArrayBuffer,
+
+    /// The attribute size is not allowed with the attribute type
+    InvalidAttribFormat {
+        size: VertexAttribSize,
+        ty: VertexAttribFloatType,
+    },
+}
+
+impl std::fmt::Display for VertexArrayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VertexArrayError::Unexpected(error) => {
+                write!(f, "unexpected vertex array error: {error}")
+            }
+            VertexArrayError::InvalidVertexArray(array) => {
+                write!(f, "{} is not the name of a vertex array object", array.0)
+            }
+            VertexArrayError::NoVertexArrayBound => write!(f, "no vertex array object is bound"),
+            VertexArrayError::InvalidAttribIndex(index) => {
+                write!(f, "vertex attribute index {index} is out of range")
+            }
+            VertexArrayError::UnboundArrayBuffer => write!(f, "no array buffer is bound"),
+            VertexArrayError::InvalidAttribFormat { size, ty } => {
+                write!(
+                    f,
+                    "vertex attribute size {size:?} is incompatible with {ty:?}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for VertexArrayError {}
+
 /// # Bind a vertex array object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBindVertexArray.xhtml>
 ///
@@ -131,6 +180,17 @@ pub fn bind_vertex_array(array: VertexArray) {
     unsafe { gl::BindVertexArray(array) }
 }
 
+/// # Error mapped bind vertex array
+/// see [bind_vertex_array]
+pub fn bind_vertex_array_checked(array: VertexArray) -> Result<(), VertexArrayError> {
+    bind_vertex_array(array);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(VertexArrayError::InvalidVertexArray(array)),
+        other => Err(VertexArrayError::Unexpected(other)),
+    }
+}
+
 /// # Delete vertex array objects
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDeleteVertexArrays.xhtml>
 ///
@@ -168,6 +228,16 @@ pub fn delete_vertex_arrays(arrays: &[VertexArray]) {
     unsafe { gl::DeleteVertexArrays(n, arrays) }
 }
 
+/// # Error mapped delete vertex arrays
+/// see [delete_vertex_arrays]
+pub fn delete_vertex_arrays_checked(arrays: &[VertexArray]) -> Result<(), VertexArrayError> {
+    delete_vertex_arrays(arrays);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(VertexArrayError::Unexpected(other)),
+    }
+}
+
 /// # Enable a generic vertex attribute array
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glEnableVertexAttribArray.xhtml>
 ///
@@ -230,6 +300,18 @@ pub fn enable_vertex_attrib_array(index: u32) {
     unsafe { gl::EnableVertexAttribArray(index) }
 }
 
+/// # Error mapped enable vertex attribute array
+/// see [enable_vertex_attrib_array]
+pub fn enable_vertex_attrib_array_checked(index: u32) -> Result<(), VertexArrayError> {
+    enable_vertex_attrib_array(index);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(VertexArrayError::NoVertexArrayBound),
+        Error::InvalidValue => Err(VertexArrayError::InvalidAttribIndex(index)),
+        other => Err(VertexArrayError::Unexpected(other)),
+    }
+}
+
 /// # Disable a generic vertex attribute array
 /// see [enable_vertex_attrib_array]
 pub fn disable_vertex_attrib_array(index: u32) {
@@ -237,6 +319,18 @@ pub fn disable_vertex_attrib_array(index: u32) {
     unsafe { gl::DisableVertexAttribArray(index) }
 }
 
+/// # Error mapped disable vertex attribute array
+/// see [disable_vertex_attrib_array]
+pub fn disable_vertex_attrib_array_checked(index: u32) -> Result<(), VertexArrayError> {
+    disable_vertex_attrib_array(index);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(VertexArrayError::NoVertexArrayBound),
+        Error::InvalidValue => Err(VertexArrayError::InvalidAttribIndex(index)),
+        other => Err(VertexArrayError::Unexpected(other)),
+    }
+}
+
 /// # Enable a generic vertex attribute array
 /// see [enable_vertex_attrib_array]
 ///
@@ -245,12 +339,28 @@ pub fn disable_vertex_attrib_array(index: u32) {
 ///
 /// # Errors
 /// * [Error::InvalidOperation] - if `vaobj` is not the name of an existing vertex array object.
+/// * [Error::InvalidValue] - if `index` is greater than or equal to [get_max_vertex_attribs]
 pub fn enable_vertex_array_attrib(vaobj: VertexArray, index: u32) {
     let vaobj = vaobj.0;
     // SAFE: synchronous integer copy
     unsafe { gl::EnableVertexArrayAttrib(vaobj, index) }
 }
 
+/// # Error mapped enable vertex array attribute
+/// see [enable_vertex_array_attrib]
+pub fn enable_vertex_array_attrib_checked(
+    vaobj: VertexArray,
+    index: u32,
+) -> Result<(), VertexArrayError> {
+    enable_vertex_array_attrib(vaobj, index);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(VertexArrayError::InvalidVertexArray(vaobj)),
+        Error::InvalidValue => Err(VertexArrayError::InvalidAttribIndex(index)),
+        other => Err(VertexArrayError::Unexpected(other)),
+    }
+}
+
 /// # Disable a generic vertex attribute array
 /// see [enable_vertex_attrib_array] and [enable_vertex_array_attrib]
 pub fn disable_vertex_array_attrib(vaobj: VertexArray, index: u32) {
@@ -259,6 +369,21 @@ pub fn disable_vertex_array_attrib(vaobj: VertexArray, index: u32) {
     unsafe { gl::DisableVertexArrayAttrib(vaobj, index) }
 }
 
+/// # Error mapped disable vertex array attribute
+/// see [disable_vertex_array_attrib]
+pub fn disable_vertex_array_attrib_checked(
+    vaobj: VertexArray,
+    index: u32,
+) -> Result<(), VertexArrayError> {
+    disable_vertex_array_attrib(vaobj, index);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation => Err(VertexArrayError::InvalidVertexArray(vaobj)),
+        Error::InvalidValue => Err(VertexArrayError::InvalidAttribIndex(index)),
+        other => Err(VertexArrayError::Unexpected(other)),
+    }
+}
+
 /// # Generate vertex array object names
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGenVertexArrays.xhtml>
 ///
@@ -300,6 +425,16 @@ pub fn gen_vertex_arrays(arrays: &mut [VertexArray]) {
     unsafe { gl::GenVertexArrays(n, arrays) }
 }
 
+/// # Error mapped generate vertex arrays
+/// see [gen_vertex_arrays]
+pub fn gen_vertex_arrays_checked(arrays: &mut [VertexArray]) -> Result<(), VertexArrayError> {
+    gen_vertex_arrays(arrays);
+    match get_error() {
+        Error::NoError => Ok(()),
+        other => Err(VertexArrayError::Unexpected(other)),
+    }
+}
+
 /// # Determine if a name corresponds to a vertex array object
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glIsVertexArray.xhtml>
 ///
@@ -337,6 +472,16 @@ pub fn is_vertex_array(array: VertexArray) -> bool {
     val == gl::TRUE
 }
 
+/// # Error mapped is vertex array
+/// see [is_vertex_array]
+pub fn is_vertex_array_checked(array: VertexArray) -> Result<bool, VertexArrayError> {
+    let val = is_vertex_array(array);
+    match get_error() {
+        Error::NoError => Ok(val),
+        other => Err(VertexArrayError::Unexpected(other)),
+    }
+}
+
 /// # Define an array of generic vertex attribute data
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glVertexAttribPointer.xhtml>
 ///
@@ -387,7 +532,7 @@ pub fn is_vertex_array(array: VertexArray) -> bool {
 /// and stride behave as described for [vertex_attrib_float_pointer] and [vertex_attrib_integer_pointer].
 ///
 /// `offset` is treated as a byte offset into the buffer object's data store. The buffer object
-/// binding (GL_ARRAY_BUFFER_BINDING ?) is saved as generic vertex attribute array state
+/// binding ([get_array_buffer_binding]) is saved as generic vertex attribute array state
 /// (GL_VERTEX_ATTRIB_ARRAY_BUFFER_BINDING?) for index `index`.
 ///
 /// When a generic vertex attribute array is specified, size, type, normalized, stride, and `offset`
@@ -418,6 +563,7 @@ pub fn is_vertex_array(array: VertexArray) -> bool {
 /// * [Error::InvalidOperation] - if `size` is [VertexAttribSize::BGRA] and `normalised` is false.
 /// * [Error::InvalidOperation] - if zero is bound to the [BufferBindingTarget::Array] buffer object
 /// binding point.
+/// * [Error::InvalidOperation] - if no vertex array object is bound.
 ///
 /// # Associated Gets
 /// * [get_max_vertex_attribs]
@@ -453,6 +599,25 @@ pub mod vertex_attrib_pointer {
     use crate::prelude::*;
     use gl::types::*;
 
+    fn vertex_attrib_pointer_error(
+        index: u32,
+        size: VertexAttribSize,
+        ty: VertexAttribFloatType,
+    ) -> Result<(), VertexArrayError> {
+        match get_error() {
+            Error::NoError => Ok(()),
+            Error::InvalidValue => Err(VertexArrayError::InvalidAttribIndex(index)),
+            Error::InvalidOperation if get_i32(gl::ARRAY_BUFFER_BINDING) == 0 => {
+                Err(VertexArrayError::UnboundArrayBuffer)
+            }
+            Error::InvalidOperation if get_i32(gl::VERTEX_ARRAY_BINDING) == 0 => {
+                Err(VertexArrayError::NoVertexArrayBound)
+            }
+            Error::InvalidOperation => Err(VertexArrayError::InvalidAttribFormat { size, ty }),
+            other => Err(VertexArrayError::Unexpected(other)),
+        }
+    }
+
     /// # Define an array of generic vertex attribute data
     /// see [vertex_attrib_pointer]
     pub fn vertex_attrib_float_pointer(
@@ -473,6 +638,20 @@ pub mod vertex_attrib_pointer {
         unsafe { gl::VertexAttribPointer(index, size, type_, normalized, stride, pointer) }
     }
 
+    /// # Error mapped define an array of generic vertex attribute data
+    /// see [vertex_attrib_float_pointer]
+    pub fn vertex_attrib_float_pointer_checked(
+        index: u32,
+        size: VertexAttribSize,
+        ty: VertexAttribFloatType,
+        normalised: bool,
+        stride: u64,
+        offset: u64,
+    ) -> Result<(), VertexArrayError> {
+        vertex_attrib_float_pointer(index, size, ty, normalised, stride, offset);
+        vertex_attrib_pointer_error(index, size, ty)
+    }
+
     /// # Define an array of generic vertex attribute data
     /// see [vertex_attrib_pointer]
     pub fn vertex_attrib_integer_pointer(
@@ -491,6 +670,19 @@ pub mod vertex_attrib_pointer {
         unsafe { gl::VertexAttribIPointer(index, size, type_, stride, pointer) }
     }
 
+    /// # Error mapped define an array of generic vertex attribute data
+    /// see [vertex_attrib_integer_pointer]
+    pub fn vertex_attrib_integer_pointer_checked(
+        index: u32,
+        size: VertexAttribSize,
+        ty: VertexAttribFloatType,
+        stride: u64,
+        offset: u64,
+    ) -> Result<(), VertexArrayError> {
+        vertex_attrib_integer_pointer(index, size, ty, stride, offset);
+        vertex_attrib_pointer_error(index, size, ty)
+    }
+
     /// # Define an array of generic vertex attribute data
     /// see [vertex_attrib_pointer]
     pub fn vertex_attrib_f64_pointer(index: u32, size: VertexAttribSize, stride: u64, offset: u64) {
@@ -501,6 +693,18 @@ pub mod vertex_attrib_pointer {
         // SAFE: synchronous integer copy
         unsafe { gl::VertexAttribLPointer(index, size, gl::DOUBLE, stride, pointer) }
     }
+
+    /// # Error mapped define an array of generic vertex attribute data
+    /// see [vertex_attrib_f64_pointer]
+    pub fn vertex_attrib_f64_pointer_checked(
+        index: u32,
+        size: VertexAttribSize,
+        stride: u64,
+        offset: u64,
+    ) -> Result<(), VertexArrayError> {
+        vertex_attrib_f64_pointer(index, size, stride, offset);
+        vertex_attrib_pointer_error(index, size, VertexAttribFloatType::F64)
+    }
 }
 pub use vertex_attrib_pointer::*;
 
@@ -530,6 +734,7 @@ pub use vertex_attrib_pointer::*;
 ///
 /// # Errors
 /// * [Error::InvalidValue] - if `index` is greater than or equal to [get_max_vertex_attribs]
+/// * [Error::InvalidOperation] - if no vertex array object is bound
 ///
 /// # Version Support
 ///
@@ -544,3 +749,15 @@ pub use vertex_attrib_pointer::*;
 pub fn vertex_attrib_divisor(index: u32, divisor: u32) {
     unsafe { gl::VertexAttribDivisor(index, divisor) }
 }
+
+/// # Error mapped vertex attribute divisor
+/// see [vertex_attrib_divisor]
+pub fn vertex_attrib_divisor_checked(index: u32, divisor: u32) -> Result<(), VertexArrayError> {
+    vertex_attrib_divisor(index, divisor);
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidValue => Err(VertexArrayError::InvalidAttribIndex(index)),
+        Error::InvalidOperation => Err(VertexArrayError::NoVertexArrayBound),
+        other => Err(VertexArrayError::Unexpected(other)),
+    }
+}
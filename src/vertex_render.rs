@@ -62,6 +62,51 @@ impl From<DrawMode> for u32 {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DrawError {
+    Unexpected(Error),
+
+    /// An enabled array or element buffer is mapped, or the mode is incompatible with the input
+    /// primitive type of the active geometry shader
+    IncompatibleState(DrawMode),
+
+    /// No vertex array object is bound
+    NoVertexArrayBound,
+
+    /// The draw framebuffer is not complete
+    IncompleteFramebuffer,
+}
+
+impl std::fmt::Display for DrawError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DrawError::Unexpected(error) => write!(f, "unexpected draw error: {error}"),
+            DrawError::IncompatibleState(mode) => {
+                write!(
+                    f,
+                    "cannot draw {mode:?} with the current buffer and program state"
+                )
+            }
+            DrawError::NoVertexArrayBound => write!(f, "no vertex array object is bound"),
+            DrawError::IncompleteFramebuffer => write!(f, "the draw framebuffer is not complete"),
+        }
+    }
+}
+
+impl std::error::Error for DrawError {}
+
+fn draw_error(mode: DrawMode) -> Result<(), DrawError> {
+    match get_error() {
+        Error::NoError => Ok(()),
+        Error::InvalidOperation if get_i32(gl::VERTEX_ARRAY_BINDING) == 0 => {
+            Err(DrawError::NoVertexArrayBound)
+        }
+        Error::InvalidOperation => Err(DrawError::IncompatibleState(mode)),
+        Error::InvalidFramebufferOperation => Err(DrawError::IncompleteFramebuffer),
+        other => Err(DrawError::Unexpected(other)),
+    }
+}
+
 /// # Render primitives from array data
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDrawArrays.xhtml>
 ///
@@ -98,6 +143,8 @@ impl From<DrawMode> for u32 {
 /// the buffer object's data store is currently mapped.
 /// * [Error::InvalidOperation] - if a geometry shader is active and mode is incompatible with the
 /// input primitive type of the geometry shader in the currently installed program object.
+/// * [Error::InvalidOperation] - if no vertex array object is bound.
+/// * [Error::InvalidFramebufferOperation] - if the draw framebuffer is not complete.
 ///
 /// # Version Support
 ///
@@ -118,6 +165,13 @@ pub fn draw_arrays(mode: DrawMode, first: u64, count: u64) {
     unsafe { gl::DrawArrays(mode, first, count) }
 }
 
+/// # Error mapped draw arrays
+/// see [draw_arrays]
+pub fn draw_arrays_checked(mode: DrawMode, first: u64, count: u64) -> Result<(), DrawError> {
+    draw_arrays(mode, first, count);
+    draw_error(mode)
+}
+
 /// # Render primitives from array data
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDrawElements.xhtml>
 ///
@@ -156,6 +210,8 @@ pub fn draw_arrays(mode: DrawMode, first: u64, count: u64) {
 /// input primitive type of the geometry shader in the currently installed program object.
 /// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to an enabled array or
 /// the element array and the buffer object's data store is currently mapped.
+/// * [Error::InvalidOperation] - if no vertex array object is bound.
+/// * [Error::InvalidFramebufferOperation] - if the draw framebuffer is not complete.
 ///
 /// # Version Support
 ///
@@ -177,6 +233,18 @@ pub fn draw_elements(mode: DrawMode, count: u64, index_type: DrawIndexType, offs
     unsafe { gl::DrawElements(mode, count, type_, indices) }
 }
 
+/// # Error mapped draw elements
+/// see [draw_elements]
+pub fn draw_elements_checked(
+    mode: DrawMode,
+    count: u64,
+    index_type: DrawIndexType,
+    offset: u64,
+) -> Result<(), DrawError> {
+    draw_elements(mode, count, index_type, offset);
+    draw_error(mode)
+}
+
 /// # Draw multiple instances of a set of elements
 /// <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDrawElementsInstanced.xhtml>
 ///
@@ -225,6 +293,8 @@ pub fn draw_elements(mode: DrawMode, count: u64, index_type: DrawIndexType, offs
 /// input primitive type of the geometry shader in the currently installed program object.
 /// * [Error::InvalidOperation] - if a non-zero buffer object name is bound to an enabled array and
 /// the buffer object's data store is currently mapped.
+/// * [Error::InvalidOperation] - if no vertex array object is bound.
+/// * [Error::InvalidFramebufferOperation] - if the draw framebuffer is not complete.
 ///
 /// # Version Support
 ///
@@ -250,3 +320,16 @@ pub fn draw_elements_instanced(
 
     unsafe { gl::DrawElementsInstanced(mode, count, type_, indices, instancecount) }
 }
+
+/// # Error mapped draw elements instanced
+/// see [draw_elements_instanced]
+pub fn draw_elements_instanced_checked(
+    mode: DrawMode,
+    count: u64,
+    index_type: DrawIndexType,
+    offset: u64,
+    instance_count: u64,
+) -> Result<(), DrawError> {
+    draw_elements_instanced(mode, count, index_type, offset, instance_count);
+    draw_error(mode)
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "headless")]
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+fn new_buffer(target: BufferBindingTarget) -> Buffer {
+    let mut buffer = Buffer::default();
+    gen_buffers(std::slice::from_mut(&mut buffer));
+    bind_buffer(target, buffer);
+    buffer
+}
+
+#[test]
+fn buffer_data_round_trip() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let buffer = new_buffer(BufferBindingTarget::Array);
+    assert!(is_buffer(buffer));
+
+    let data = [1u32, 2, 3, 4, 5, 6, 7, 8];
+    buffer_data(
+        BufferBindingTarget::Array,
+        &data,
+        BufferUsageFrequency::Dynamic,
+        BufferUsageNature::Read,
+    );
+    assert_eq!(get_buffer_size(BufferBindingTarget::Array), 32);
+    assert_eq!(
+        get_buffer_usage(BufferBindingTarget::Array),
+        Ok((BufferUsageFrequency::Dynamic, BufferUsageNature::Read))
+    );
+
+    let mut read = [0u32; 8];
+    get_buffer_sub_data(BufferBindingTarget::Array, 0, &mut read);
+    assert_eq!(read, data);
+
+    buffer_sub_data(BufferBindingTarget::Array, 8, &[42u32, 43]);
+    let mut read = [0u32; 4];
+    get_buffer_sub_data(BufferBindingTarget::Array, 4, &mut read);
+    assert_eq!(read, [2, 42, 43, 5]);
+
+    let mut read = [0u32; 8];
+    get_named_buffer_sub_data(buffer, 0, &mut read);
+    assert_eq!(read, [1, 2, 42, 43, 5, 6, 7, 8]);
+
+    delete_buffers(&[buffer]);
+    assert!(!is_buffer(buffer));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn buffer_usage_mapping() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    new_buffer(BufferBindingTarget::Uniform);
+
+    for frequency in [
+        BufferUsageFrequency::Stream,
+        BufferUsageFrequency::Static,
+        BufferUsageFrequency::Dynamic,
+    ] {
+        for nature in [
+            BufferUsageNature::Draw,
+            BufferUsageNature::Read,
+            BufferUsageNature::Copy,
+        ] {
+            buffer_data(BufferBindingTarget::Uniform, 16, frequency, nature);
+            assert_eq!(
+                get_buffer_usage(BufferBindingTarget::Uniform),
+                Ok((frequency, nature))
+            );
+        }
+    }
+}
+
+#[test]
+fn buffer_storage_is_immutable() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    new_buffer(BufferBindingTarget::CopyRead);
+
+    assert_eq!(
+        is_buffer_immutable_storage(BufferBindingTarget::CopyRead),
+        Ok(false)
+    );
+    let flags = BufferStorageFlags::DYNAMIC | BufferStorageFlags::READ;
+    buffer_storage(BufferBindingTarget::CopyRead, &[7u8; 64], flags);
+    assert_eq!(
+        is_buffer_immutable_storage(BufferBindingTarget::CopyRead),
+        Ok(true)
+    );
+    assert_eq!(
+        get_buffer_storage_flags(BufferBindingTarget::CopyRead),
+        Ok(flags)
+    );
+
+    assert_eq!(
+        buffer_data_checked(
+            BufferBindingTarget::CopyRead,
+            16,
+            BufferUsageFrequency::Static,
+            BufferUsageNature::Draw,
+        ),
+        Err(BufferError::ImmutableBufferTarget(
+            BufferBindingTarget::CopyRead
+        ))
+    );
+}
+
+#[test]
+fn map_buffer_range_reads_data() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    new_buffer(BufferBindingTarget::CopyWrite);
+    buffer_data(
+        BufferBindingTarget::CopyWrite,
+        &[10u8, 20, 30, 40],
+        BufferUsageFrequency::Static,
+        BufferUsageNature::Read,
+    );
+
+    let ptr = map_buffer_range(BufferBindingTarget::CopyWrite, 1, 2, BufferMapFlags::READ);
+    assert!(!ptr.is_null());
+    assert_eq!(is_buffer_mapped(BufferBindingTarget::CopyWrite), Ok(true));
+    assert_eq!(get_buffer_map_offset(BufferBindingTarget::CopyWrite), 1);
+    assert_eq!(get_buffer_map_length(BufferBindingTarget::CopyWrite), 2);
+
+    // SAFE: the mapped range is 2 bytes long, and is read before unmapping
+    let mapped = unsafe { std::slice::from_raw_parts(ptr as *const u8, 2) };
+    assert_eq!(mapped, [20, 30]);
+    assert!(unmap_buffer(BufferBindingTarget::CopyWrite));
+    assert_eq!(is_buffer_mapped(BufferBindingTarget::CopyWrite), Ok(false));
+}
+
+#[test]
+fn bind_buffer_checked_rejects_unknown_names() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    assert_eq!(
+        bind_buffer_checked(BufferBindingTarget::Array, Buffer(12345)),
+        Err(BufferError::InvalidBuffer(Buffer(12345)))
+    );
+}
+
+#[test]
+fn owned_buffers_are_deleted() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let buffers = OwnedBuffer::generate_many(3);
+    let raw: Vec<Buffer> = buffers.iter().map(|buffer| **buffer).collect();
+    for buffer in &buffers {
+        bind_buffer(BufferBindingTarget::Array, **buffer);
+    }
+    assert!(raw.iter().all(|buffer| is_buffer(*buffer)));
+
+    drop(buffers);
+    assert!(raw.iter().all(|buffer| !is_buffer(*buffer)));
+    assert_eq!(get_error(), Error::NoError);
+}
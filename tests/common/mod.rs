@@ -0,0 +1,115 @@
+#![allow(dead_code)]
+
+use rgl::prelude::*;
+
+pub fn compile(shader_type: ShaderType, source: &str) -> Shader {
+    let shader = create_shader(shader_type);
+    shader_source(shader, source);
+    compile_shader(shader);
+
+    let mut buffer = [0; 1024];
+    let info_log = get_shader_info_log(shader, &mut buffer);
+    assert!(
+        get_shader_compile_status(shader),
+        "{}",
+        String::from_utf8_lossy(info_log)
+    );
+    shader
+}
+
+pub fn link(vertex_source: &str, fragment_source: &str) -> Program {
+    let vertex = compile(ShaderType::Vertex, vertex_source);
+    let fragment = compile(ShaderType::Fragment, fragment_source);
+
+    let program = create_program();
+    attach_shader(program, vertex);
+    attach_shader(program, fragment);
+    link_program(program);
+
+    let mut buffer = [0; 1024];
+    let info_log = get_program_info_log(program, &mut buffer);
+    assert!(
+        get_program_link_status(program),
+        "{}",
+        String::from_utf8_lossy(info_log)
+    );
+
+    detach_shader(program, vertex);
+    detach_shader(program, fragment);
+    delete_shader(vertex);
+    delete_shader(fragment);
+    program
+}
+
+pub const POSITION_VERTEX_SHADER: &str = r#"
+#version 330 core
+layout (location=0) in vec2 position;
+void main() {
+    gl_Position = vec4(position, 0.0, 1.0);
+}
+"#;
+
+pub const DEPTH_VERTEX_SHADER: &str = r#"
+#version 330 core
+layout (location=0) in vec2 position;
+uniform float depth;
+void main() {
+    gl_Position = vec4(position, depth, 1.0);
+}
+"#;
+
+pub const UNIFORM_COLOUR_FRAGMENT_SHADER: &str = r#"
+#version 330 core
+uniform vec4 colour;
+out vec4 fragment_colour;
+void main() {
+    fragment_colour = colour;
+}
+"#;
+
+/// Upload 2D positions into a new vertex array, bound to attribute 0
+pub fn vertex_array(positions: &[[f32; 2]]) -> (VertexArray, Buffer) {
+    let mut vao = VertexArray::default();
+    gen_vertex_arrays(std::slice::from_mut(&mut vao));
+    bind_vertex_array(vao);
+
+    let mut vbo = Buffer::default();
+    gen_buffers(std::slice::from_mut(&mut vbo));
+    bind_buffer(BufferBindingTarget::Array, vbo);
+    buffer_data(
+        BufferBindingTarget::Array,
+        positions,
+        BufferUsageFrequency::Static,
+        BufferUsageNature::Draw,
+    );
+    enable_vertex_attrib_array(0);
+    vertex_attrib_float_pointer(
+        0,
+        VertexAttribSize::Double,
+        VertexAttribFloatType::F32,
+        false,
+        std::mem::size_of::<[f32; 2]>() as u64,
+        0,
+    );
+    (vao, vbo)
+}
+
+/// Two triangles covering the left half of the viewport
+pub const LEFT_HALF_QUAD: [[f32; 2]; 6] = [
+    [-1.0, -1.0],
+    [0.0, -1.0],
+    [0.0, 1.0],
+    [-1.0, -1.0],
+    [0.0, 1.0],
+    [-1.0, 1.0],
+];
+
+/// Two triangles covering the whole viewport
+pub const FULLSCREEN_QUAD: [[f32; 2]; 6] = [
+    [-1.0, -1.0],
+    [1.0, -1.0],
+    [1.0, 1.0],
+    [-1.0, -1.0],
+    [1.0, 1.0],
+    [-1.0, 1.0],
+];
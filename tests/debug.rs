@@ -0,0 +1,169 @@
+#![cfg(feature = "headless")]
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+use std::sync::{Arc, Mutex};
+
+fn collect_messages() -> Arc<Mutex<Vec<DebugMessage>>> {
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let received = messages.clone();
+    debug_message_callback(move |message| received.lock().unwrap().push(message.clone()));
+    messages
+}
+
+#[test]
+fn callback_receives_messages() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    enable(Capability::DebugOutput);
+    enable(Capability::DebugOutputSynchronous);
+    let messages = collect_messages();
+
+    debug_message_insert(
+        DebugSource::Application,
+        DebugType::Marker,
+        42,
+        DebugSeverity::Notification,
+        "hello from rgl",
+    );
+    bind_texture(TextureBindingTarget::Image2D, Texture(4242));
+    assert_eq!(get_error(), Error::InvalidOperation);
+
+    let messages = messages.lock().unwrap();
+    assert_eq!(
+        messages[0],
+        DebugMessage {
+            source: DebugSource::Application,
+            ty: DebugType::Marker,
+            id: 42,
+            severity: DebugSeverity::Notification,
+            message: "hello from rgl".to_string(),
+        }
+    );
+    assert!(messages[1..]
+        .iter()
+        .any(|message| message.source == DebugSource::Api && message.ty == DebugType::Error));
+}
+
+#[test]
+fn callback_panic_is_caught() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    enable(Capability::DebugOutput);
+    enable(Capability::DebugOutputSynchronous);
+    debug_message_callback(|message| panic!("{message}"));
+
+    debug_message_insert(
+        DebugSource::ThirdParty,
+        DebugType::Other,
+        0,
+        DebugSeverity::Low,
+        "unwinding stops at the GL",
+    );
+    clear_debug_message_callback();
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn message_control_and_log() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    enable(Capability::DebugOutput);
+    get_debug_message_log(get_debug_logged_messages());
+
+    debug_message_control(
+        Some(DebugSource::Application),
+        Some(DebugType::Performance),
+        None,
+        &[],
+        false,
+    );
+    debug_message_insert(
+        DebugSource::Application,
+        DebugType::Performance,
+        1,
+        DebugSeverity::Medium,
+        "filtered out",
+    );
+    debug_message_insert(
+        DebugSource::Application,
+        DebugType::Portability,
+        2,
+        DebugSeverity::Medium,
+        "logged",
+    );
+
+    let log = get_debug_message_log(8);
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].id, 2);
+    assert_eq!(log[0].message, "logged");
+    assert_eq!(get_debug_logged_messages(), 0);
+
+    assert_eq!(
+        debug_message_control_checked(None, None, None, &[1], true),
+        Err(DebugError::InvalidFilter)
+    );
+    assert_eq!(
+        debug_message_insert_checked(
+            DebugSource::Api,
+            DebugType::Other,
+            0,
+            DebugSeverity::Low,
+            "reserved source",
+        ),
+        Err(DebugError::InvalidSource(DebugSource::Api))
+    );
+}
+
+#[test]
+fn debug_groups() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    enable(Capability::DebugOutput);
+    enable(Capability::DebugOutputSynchronous);
+    let messages = collect_messages();
+
+    assert_eq!(
+        push_debug_group_checked(DebugSource::Application, 3, "shadow pass"),
+        Ok(())
+    );
+    assert_eq!(pop_debug_group_checked(), Ok(()));
+    assert_eq!(
+        pop_debug_group_checked(),
+        Err(DebugError::GroupStackUnderflow)
+    );
+
+    let messages = messages.lock().unwrap();
+    let types: Vec<_> = messages
+        .iter()
+        .filter(|message| message.id == 3)
+        .map(|message| (message.ty, message.message.as_str()))
+        .collect();
+    assert_eq!(
+        types,
+        [
+            (DebugType::PushGroup, "shadow pass"),
+            (DebugType::PopGroup, "shadow pass")
+        ]
+    );
+}
+
+#[test]
+fn object_labels() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let mut texture = Texture::default();
+    gen_textures(std::slice::from_mut(&mut texture));
+    bind_texture(TextureBindingTarget::Image2D, texture);
+    object_label(texture.into(), "albedo");
+    assert_eq!(get_object_label(texture.into()), "albedo");
+
+    let program = create_program();
+    assert_eq!(get_object_label(program.into()), "");
+    assert_eq!(object_label_checked(program.into(), "sky"), Ok(()));
+    assert_eq!(
+        get_object_label_checked(program.into()),
+        Ok("sky".to_string())
+    );
+
+    assert_eq!(
+        object_label_checked(DebugObject::Buffer(Buffer(4242)), "missing"),
+        Err(DebugError::InvalidObject(DebugObject::Buffer(Buffer(4242))))
+    );
+}
@@ -0,0 +1,905 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+#[test]
+fn headless_framebuffer_is_complete() {
+    let context = HeadlessContext::new(16, 16).unwrap();
+    assert_eq!(
+        check_framebuffer_status(FramebufferBindingTarget::ReadDraw),
+        Some(FramebufferStatus::Complete)
+    );
+
+    clear_colour(1.0, 0.0, 0.0, 1.0);
+    clear(ClearMask::COLOUR);
+    assert!(context.read_colour().iter().all(|p| *p == [255, 0, 0, 255]));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn framebuffer_without_attachments_is_incomplete() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let mut framebuffer = Framebuffer::default();
+    gen_framebuffers(std::slice::from_mut(&mut framebuffer));
+    bind_framebuffer(FramebufferBindingTarget::Draw, framebuffer);
+    assert_eq!(
+        check_framebuffer_status(FramebufferBindingTarget::Draw),
+        Some(FramebufferStatus::IncompleteMissingAttachment)
+    );
+    delete_framebuffers(&[framebuffer]);
+}
+
+#[test]
+fn renderbuffer_depth_stencil_attachment() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let mut framebuffer = Framebuffer::default();
+    gen_framebuffers(std::slice::from_mut(&mut framebuffer));
+    bind_framebuffer(FramebufferBindingTarget::ReadDraw, framebuffer);
+
+    let mut renderbuffer = Renderbuffer::default();
+    gen_renderbuffers(std::slice::from_mut(&mut renderbuffer));
+    bind_renderbuffer(renderbuffer);
+    renderbuffer_storage(RenderbufferInternalFormat::Depth32fStencil8, 8, 8);
+    framebuffer_renderbuffer(
+        FramebufferBindingTarget::ReadDraw,
+        FramebufferAttachment::DepthStencil,
+        renderbuffer,
+    );
+    read_buffer(ColourBuffer::None);
+    assert_eq!(get_read_buffer(), Some(ColourBuffer::None));
+    assert_eq!(
+        check_framebuffer_status(FramebufferBindingTarget::ReadDraw),
+        Some(FramebufferStatus::Complete)
+    );
+
+    delete_renderbuffers(&[renderbuffer]);
+    delete_framebuffers(&[framebuffer]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn read_pixels_validates_destination_size() {
+    let _context = HeadlessContext::new(4, 4).unwrap();
+    clear_colour(0.0, 1.0, 0.0, 1.0);
+    clear(ClearMask::COLOUR);
+
+    let mut pixels = [0u8; 4 * 4 * 4 - 1];
+    assert_eq!(
+        read_pixels_checked(
+            0,
+            0,
+            4,
+            4,
+            TextureFormat::RGBA,
+            TexturePixelType::U8,
+            &mut pixels
+        ),
+        Err(FramebufferError::InsufficientPixelData {
+            required: 64,
+            provided: 63
+        })
+    );
+
+    // 3 byte RGB rows of 3 pixels are padded to the default pack alignment of 4
+    let mut pixels = [0u8; 12 * 2 + 9];
+    assert_eq!(
+        read_pixels_checked(
+            0,
+            0,
+            3,
+            3,
+            TextureFormat::RGB,
+            TexturePixelType::U8,
+            &mut pixels
+        ),
+        Ok(())
+    );
+    assert_eq!(pixels[..3], [0, 255, 0]);
+    assert_eq!(pixels[24..27], [0, 255, 0]);
+
+    let mut pixels = [[0f32; 4]; 16];
+    assert_eq!(
+        read_pixels_checked(
+            0,
+            0,
+            4,
+            4,
+            TextureFormat::RGBA,
+            TexturePixelType::F32,
+            &mut pixels
+        ),
+        Ok(())
+    );
+    assert!(pixels.iter().all(|p| *p == [0.0, 1.0, 0.0, 1.0]));
+}
+
+#[test]
+fn read_pixels_into_pack_buffer() {
+    let _context = HeadlessContext::new(2, 2).unwrap();
+    clear_colour(0.0, 0.0, 1.0, 1.0);
+    clear(ClearMask::COLOUR);
+
+    let mut buffer = Buffer::default();
+    gen_buffers(std::slice::from_mut(&mut buffer));
+    bind_buffer(BufferBindingTarget::PixelPack, buffer);
+    buffer_data(
+        BufferBindingTarget::PixelPack,
+        32,
+        BufferUsageFrequency::Stream,
+        BufferUsageNature::Read,
+    );
+    read_pixels_to_pack_buffer(0, 0, 2, 2, TextureFormat::RGBA, TexturePixelType::U8, 16);
+    bind_buffer(BufferBindingTarget::PixelPack, Buffer::default());
+    assert_eq!(get_error(), Error::NoError);
+
+    let mut pixels = [[0u8; 4]; 8];
+    get_named_buffer_sub_data(buffer, 0, &mut pixels);
+    assert_eq!(pixels[..4], [[0; 4]; 4]);
+    assert_eq!(pixels[4..], [[0, 0, 255, 255]; 4]);
+}
+
+#[test]
+fn depth_attachment_is_cleared() {
+    let _context = HeadlessContext::new(2, 2).unwrap();
+    clear(ClearMask::DEPTH);
+
+    let mut depth = [0f32; 4];
+    read_pixels(
+        0,
+        0,
+        2,
+        2,
+        TextureFormat::DepthComponent,
+        TexturePixelType::F32,
+        &mut depth,
+    );
+    assert_eq!(depth, [1.0; 4]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+/// A framebuffer with `texture` as its only colour attachment
+fn texture_framebuffer(texture: Texture) -> Framebuffer {
+    let mut framebuffer = Framebuffer::default();
+    gen_framebuffers(std::slice::from_mut(&mut framebuffer));
+    bind_framebuffer(FramebufferBindingTarget::ReadDraw, framebuffer);
+    framebuffer_texture_checked(
+        FramebufferBindingTarget::ReadDraw,
+        FramebufferAttachment::Colour(0),
+        texture,
+        0,
+    )
+    .unwrap();
+    framebuffer
+}
+
+#[test]
+fn multisample_render_target_is_resolved() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    assert!(get_max_samples() >= 4);
+
+    let mut textures = [Texture::default(); 2];
+    gen_textures(&mut textures);
+    bind_texture(TextureBindingTarget::Multisample2D, textures[0]);
+    tex_storage_2d_multisample_checked(
+        TextureMultisample2DTarget::Multisample2D,
+        4,
+        TextureInternalFormat::RGBA8,
+        1,
+        1,
+        true,
+    )
+    .unwrap();
+    bind_texture(TextureBindingTarget::Image2D, textures[1]);
+    tex_storage_2d_checked(
+        TextureStorage2DTarget::Image2D,
+        1,
+        TextureInternalFormat::RGBA8,
+        1,
+        1,
+    )
+    .unwrap();
+    let resolved = texture_framebuffer(textures[1]);
+    let multisampled = texture_framebuffer(textures[0]);
+    assert_eq!(
+        check_framebuffer_status(FramebufferBindingTarget::ReadDraw),
+        Some(FramebufferStatus::Complete)
+    );
+    assert_eq!(
+        get_framebuffer_samples_checked(FramebufferBindingTarget::Draw),
+        Ok(4)
+    );
+    assert_eq!(get_framebuffer_samples(FramebufferBindingTarget::Read), 4);
+
+    // a triangle over the lower left half of the pixel covers half of the 4x pattern's samples
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    use_program(program);
+    uniform_4f32(get_uniform_location(program, c"colour"), 1.0, 1.0, 1.0, 1.0);
+    common::vertex_array(&[[-1.0, -1.0], [1.0, -1.0], [-1.0, 1.0]]);
+    clear_colour(0.0, 0.0, 0.0, 1.0);
+    clear(ClearMask::COLOUR);
+    draw_arrays(DrawMode::Triangles, 0, 3);
+
+    // multisample images cannot be read directly
+    let mut pixel = [0u8; 4];
+    assert!(read_pixels_checked(
+        0,
+        0,
+        1,
+        1,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        &mut pixel
+    )
+    .is_err());
+
+    bind_framebuffer(FramebufferBindingTarget::Draw, resolved);
+    assert_eq!(
+        blit_framebuffer_checked(
+            0,
+            0,
+            1,
+            1,
+            0,
+            0,
+            2,
+            2,
+            ClearMask::COLOUR,
+            BlitFilter::Nearest
+        ),
+        Err(FramebufferError::InvalidMultisampleBlit)
+    );
+    blit_framebuffer_checked(
+        0,
+        0,
+        1,
+        1,
+        0,
+        0,
+        1,
+        1,
+        ClearMask::COLOUR,
+        BlitFilter::Nearest,
+    )
+    .unwrap();
+    bind_framebuffer(FramebufferBindingTarget::Read, resolved);
+    read_pixels(
+        0,
+        0,
+        1,
+        1,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        &mut pixel,
+    );
+    assert!((126..=129).contains(&pixel[0]), "{pixel:?}");
+    assert_eq!(pixel[0], pixel[1]);
+    assert_eq!(pixel[3], 255);
+
+    // depth and stencil can only be blitted with nearest filtering
+    assert_eq!(
+        blit_framebuffer_checked(0, 0, 1, 1, 0, 0, 1, 1, ClearMask::DEPTH, BlitFilter::Linear),
+        Err(FramebufferError::InvalidBlitFilter(BlitFilter::Linear))
+    );
+
+    delete_framebuffers(&[resolved, multisampled]);
+    delete_textures(&textures);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn multisample_attachments_must_match() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let mut texture = Texture::default();
+    gen_textures(std::slice::from_mut(&mut texture));
+    bind_texture(TextureBindingTarget::Multisample2D, texture);
+    tex_image_2d_multisample_checked(
+        TextureMultisample2DTarget::Multisample2D,
+        4,
+        TextureInternalFormat::RGBA8,
+        8,
+        8,
+        false,
+    )
+    .unwrap();
+    let framebuffer = texture_framebuffer(texture);
+
+    let mut renderbuffer = Renderbuffer::default();
+    gen_renderbuffers(std::slice::from_mut(&mut renderbuffer));
+    bind_renderbuffer(renderbuffer);
+    renderbuffer_storage_multisample_checked(4, RenderbufferInternalFormat::Depth24Stencil8, 8, 8)
+        .unwrap();
+    framebuffer_renderbuffer(
+        FramebufferBindingTarget::ReadDraw,
+        FramebufferAttachment::DepthStencil,
+        renderbuffer,
+    );
+    assert_eq!(
+        check_framebuffer_status(FramebufferBindingTarget::ReadDraw),
+        Some(FramebufferStatus::IncompleteMultisample)
+    );
+    // renderbuffers always use fixed sample locations, so the texture must too
+    tex_image_2d_multisample_checked(
+        TextureMultisample2DTarget::Multisample2D,
+        4,
+        TextureInternalFormat::RGBA8,
+        8,
+        8,
+        true,
+    )
+    .unwrap();
+    assert_eq!(
+        check_framebuffer_status(FramebufferBindingTarget::ReadDraw),
+        Some(FramebufferStatus::Complete)
+    );
+
+    let max = get_max_samples();
+    assert_eq!(
+        renderbuffer_storage_multisample_checked(
+            max + 1,
+            RenderbufferInternalFormat::Depth24Stencil8,
+            8,
+            8
+        ),
+        Err(FramebufferError::InvalidSamples(max + 1))
+    );
+    assert_eq!(
+        tex_image_2d_multisample_checked(
+            TextureMultisample2DTarget::Multisample2D,
+            0,
+            TextureInternalFormat::RGBA8,
+            8,
+            8,
+            true,
+        ),
+        Err(TextureError::InvalidSamples(0))
+    );
+    assert_eq!(
+        tex_image_2d_multisample_checked(
+            TextureMultisample2DTarget::Multisample2D,
+            4,
+            TextureInternalFormat::CompressedRRGTC1,
+            8,
+            8,
+            true,
+        ),
+        Err(TextureError::UnrenderableInternalFormat(
+            TextureInternalFormat::CompressedRRGTC1
+        ))
+    );
+    bind_texture(TextureBindingTarget::Multisample2D, Texture(0));
+    assert_eq!(
+        tex_storage_2d_multisample_checked(
+            TextureMultisample2DTarget::Multisample2D,
+            4,
+            TextureInternalFormat::RGBA8,
+            8,
+            8,
+            true,
+        ),
+        Err(TextureError::ImmutableStorage)
+    );
+
+    delete_renderbuffers(&[renderbuffer]);
+    delete_framebuffers(&[framebuffer]);
+    delete_textures(&[texture]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn colour_renderbuffer_is_rendered_to() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let mut renderbuffers = [Renderbuffer::default(); 2];
+    create_renderbuffers(&mut renderbuffers);
+    assert!(renderbuffers.iter().all(|r| is_renderbuffer(*r)));
+    let [colour, depth] = renderbuffers;
+    named_renderbuffer_storage_checked(colour, RenderbufferInternalFormat::RGBA16F, 4, 2).unwrap();
+    named_renderbuffer_storage_checked(depth, RenderbufferInternalFormat::DepthComponent24, 4, 2)
+        .unwrap();
+
+    let mut framebuffer = Framebuffer::default();
+    gen_framebuffers(std::slice::from_mut(&mut framebuffer));
+    bind_framebuffer(FramebufferBindingTarget::ReadDraw, framebuffer);
+    let target = FramebufferBindingTarget::ReadDraw;
+    framebuffer_renderbuffer(target, FramebufferAttachment::Colour(0), colour);
+    framebuffer_renderbuffer(target, FramebufferAttachment::Depth, depth);
+    assert_eq!(
+        check_framebuffer_status(target),
+        Some(FramebufferStatus::Complete)
+    );
+
+    clear_colour(0.25, 0.5, 2.0, 1.0);
+    clear(ClearMask::COLOUR);
+    let mut pixel = [0f32; 4];
+    read_pixels_checked(
+        0,
+        0,
+        1,
+        1,
+        TextureFormat::RGBA,
+        TexturePixelType::F32,
+        &mut pixel,
+    )
+    .unwrap();
+    // a floating point colour buffer is not clamped to [0, 1]
+    assert_eq!(pixel, [0.25, 0.5, 2.0, 1.0]);
+
+    delete_framebuffers(&[framebuffer]);
+    delete_renderbuffers(&renderbuffers);
+    assert!(!is_renderbuffer(colour));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn renderbuffer_parameter_queries() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let mut renderbuffer = Renderbuffer::default();
+    gen_renderbuffers(std::slice::from_mut(&mut renderbuffer));
+    // a generated name is not a renderbuffer until it is bound
+    assert!(!is_renderbuffer(renderbuffer));
+    assert_eq!(
+        get_renderbuffer_width_checked(),
+        Err(FramebufferError::UnboundRenderbuffer)
+    );
+    bind_renderbuffer(renderbuffer);
+    assert!(is_renderbuffer(renderbuffer));
+    assert_eq!(get_renderbuffer_width_checked(), Ok(0));
+    // the initial internal format is the unsized GL_RGBA
+    assert_eq!(get_renderbuffer_internal_format_checked(), Ok(None));
+
+    renderbuffer_storage_checked(RenderbufferInternalFormat::SRGB8A8, 16, 8).unwrap();
+    assert_eq!(get_renderbuffer_width_checked(), Ok(16));
+    assert_eq!(get_renderbuffer_height_checked(), Ok(8));
+    assert_eq!(
+        get_renderbuffer_internal_format_checked(),
+        Ok(Some(RenderbufferInternalFormat::SRGB8A8))
+    );
+    assert_eq!(get_renderbuffer_samples_checked(), Ok(0));
+    assert_eq!(get_renderbuffer_red_size_checked(), Ok(8));
+    assert_eq!(get_renderbuffer_alpha_size_checked(), Ok(8));
+    assert_eq!(get_renderbuffer_depth_size_checked(), Ok(0));
+
+    renderbuffer_storage(RenderbufferInternalFormat::R11fG11fB10f, 16, 8);
+    assert_eq!(get_renderbuffer_red_size(), 11);
+    assert_eq!(get_renderbuffer_green_size(), 11);
+    assert_eq!(get_renderbuffer_blue_size(), 10);
+    assert_eq!(get_renderbuffer_alpha_size(), 0);
+
+    renderbuffer_storage(RenderbufferInternalFormat::DepthComponent32F, 16, 8);
+    assert_eq!(get_renderbuffer_depth_size(), 32);
+    assert_eq!(get_renderbuffer_stencil_size(), 0);
+    renderbuffer_storage(RenderbufferInternalFormat::StencilIndex8, 16, 8);
+    assert_eq!(get_renderbuffer_stencil_size(), 8);
+
+    named_renderbuffer_storage_multisample_checked(
+        renderbuffer,
+        4,
+        RenderbufferInternalFormat::RGBA8,
+        16,
+        8,
+    )
+    .unwrap();
+    assert!(get_renderbuffer_samples() >= 4);
+    assert_eq!(
+        get_renderbuffer_internal_format(),
+        Some(RenderbufferInternalFormat::RGBA8)
+    );
+
+    let size = get_max_renderbuffer_size() + 1;
+    assert_eq!(
+        named_renderbuffer_storage_checked(
+            renderbuffer,
+            RenderbufferInternalFormat::RGBA8,
+            size,
+            1
+        ),
+        Err(FramebufferError::InvalidDimensions {
+            width: size,
+            height: 1
+        })
+    );
+    assert_eq!(
+        named_renderbuffer_storage_checked(
+            Renderbuffer(999),
+            RenderbufferInternalFormat::RGBA8,
+            1,
+            1
+        ),
+        Err(FramebufferError::InvalidRenderbuffer(Renderbuffer(999)))
+    );
+
+    delete_renderbuffers(&[renderbuffer]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+/// Writes a different constant colour to the first and third draw buffers
+const TWO_OUTPUT_FRAGMENT_SHADER: &str = r#"
+#version 330 core
+layout (location = 0) out vec4 albedo;
+layout (location = 2) out vec4 normal;
+void main() {
+    albedo = vec4(1.0, 0.0, 0.0, 1.0);
+    normal = vec4(0.0, 0.0, 1.0, 1.0);
+}
+"#;
+
+fn read_pixel() -> [u8; 4] {
+    let mut pixel = [0u8; 4];
+    read_pixels(
+        0,
+        0,
+        1,
+        1,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        &mut pixel,
+    );
+    pixel
+}
+
+#[test]
+fn draw_buffers_write_multiple_render_targets() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    assert!(get_max_colour_attachments() >= 8);
+    assert!(get_max_draw_buffers() >= 8);
+
+    let mut textures = [Texture::default(); 2];
+    gen_textures(&mut textures);
+    for texture in textures {
+        bind_texture(TextureBindingTarget::Image2D, texture);
+        tex_storage_2d(
+            TextureStorage2DTarget::Image2D,
+            1,
+            TextureInternalFormat::RGBA8,
+            1,
+            1,
+        );
+    }
+    let mut framebuffer = Framebuffer::default();
+    create_framebuffers(std::slice::from_mut(&mut framebuffer));
+    assert!(is_framebuffer(framebuffer));
+    named_framebuffer_texture_checked(
+        framebuffer,
+        FramebufferAttachment::Colour(0),
+        textures[0],
+        0,
+    )
+    .unwrap();
+    named_framebuffer_texture_checked(
+        framebuffer,
+        FramebufferAttachment::Colour(2),
+        textures[1],
+        0,
+    )
+    .unwrap();
+    let buffers = [
+        ColourBuffer::Attachment(0),
+        ColourBuffer::None,
+        ColourBuffer::Attachment(2),
+    ];
+    named_framebuffer_draw_buffers_checked(framebuffer, &buffers).unwrap();
+    assert_eq!(
+        check_named_framebuffer_status_checked(framebuffer, FramebufferBindingTarget::Draw),
+        Ok(FramebufferStatus::Complete)
+    );
+
+    bind_framebuffer(FramebufferBindingTarget::ReadDraw, framebuffer);
+    assert_eq!(get_draw_buffer(0), Some(ColourBuffer::Attachment(0)));
+    assert_eq!(get_draw_buffer_checked(1), Ok(Some(ColourBuffer::None)));
+    assert_eq!(get_draw_buffer(2), Some(ColourBuffer::Attachment(2)));
+    assert_eq!(get_draw_buffer(3), Some(ColourBuffer::None));
+
+    let program = common::link(common::POSITION_VERTEX_SHADER, TWO_OUTPUT_FRAGMENT_SHADER);
+    use_program(program);
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+    draw_arrays(DrawMode::Triangles, 0, 6);
+
+    read_buffer_checked(ColourBuffer::Attachment(2)).unwrap();
+    assert_eq!(read_pixel(), [0, 0, 255, 255]);
+    named_framebuffer_read_buffer_checked(framebuffer, ColourBuffer::Attachment(0)).unwrap();
+    assert_eq!(read_pixel(), [255, 0, 0, 255]);
+
+    // a single draw buffer only receives the first output
+    draw_buffer_checked(ColourBuffer::Attachment(2)).unwrap();
+    assert_eq!(get_draw_buffer(0), Some(ColourBuffer::Attachment(2)));
+    assert_eq!(get_draw_buffer(2), Some(ColourBuffer::None));
+    draw_arrays(DrawMode::Triangles, 0, 6);
+    read_buffer(ColourBuffer::Attachment(2));
+    assert_eq!(read_pixel(), [255, 0, 0, 255]);
+
+    assert_eq!(
+        draw_buffers_checked(&[ColourBuffer::Attachment(0), ColourBuffer::Attachment(0)]),
+        Err(FramebufferError::InvalidDrawBuffers)
+    );
+    assert_eq!(
+        draw_buffers_checked(&[ColourBuffer::Back]),
+        Err(FramebufferError::InvalidDrawBuffers)
+    );
+    assert_eq!(
+        draw_buffer_checked(ColourBuffer::Back),
+        Err(FramebufferError::InvalidColourBuffer(ColourBuffer::Back))
+    );
+    let max = get_max_draw_buffers();
+    assert_eq!(
+        get_draw_buffer_checked(max),
+        Err(FramebufferError::InvalidDrawBuffers)
+    );
+    assert_eq!(
+        named_framebuffer_draw_buffers_checked(Framebuffer(42), &buffers),
+        Err(FramebufferError::InvalidFramebuffer(Framebuffer(42)))
+    );
+    assert_eq!(
+        named_framebuffer_read_buffer_checked(Framebuffer(42), ColourBuffer::Attachment(0)),
+        Err(FramebufferError::InvalidFramebuffer(Framebuffer(42)))
+    );
+    assert_eq!(
+        check_named_framebuffer_status_checked(Framebuffer(42), FramebufferBindingTarget::Draw),
+        Err(FramebufferError::InvalidFramebuffer(Framebuffer(42)))
+    );
+
+    delete_framebuffers(&[framebuffer]);
+    assert!(!is_framebuffer(framebuffer));
+    delete_textures(&textures);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn cube_map_faces_and_array_layers() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let target = FramebufferBindingTarget::ReadDraw;
+    let colour = FramebufferAttachment::Colour(0);
+
+    let mut textures = [Texture::default(); 2];
+    gen_textures(&mut textures);
+    let [cube, array] = textures;
+    bind_texture(TextureBindingTarget::CubeMap, cube);
+    tex_storage_2d(
+        TextureStorage2DTarget::CubeMap,
+        1,
+        TextureInternalFormat::SRGB8A8,
+        2,
+        2,
+    );
+    bind_texture(TextureBindingTarget::Array2D, array);
+    tex_image_3d(
+        TextureBinding3DTarget::Image2DArray,
+        0,
+        TextureInternalFormat::DepthComponent32F,
+        2,
+        2,
+        3,
+        TextureFormat::DepthComponent,
+        TexturePixelType::F32,
+        TextureData::Data(&[0.5f32; 12]),
+    );
+
+    // render into a single face of the cube map
+    let mut framebuffer = Framebuffer::default();
+    gen_framebuffers(std::slice::from_mut(&mut framebuffer));
+    bind_framebuffer(target, framebuffer);
+    framebuffer_texture_2d_checked(
+        target,
+        colour,
+        TextureBinding2DTarget::CubeMapNegativeZ,
+        cube,
+        0,
+    )
+    .unwrap();
+    assert_eq!(
+        check_framebuffer_status(target),
+        Some(FramebufferStatus::Complete)
+    );
+    clear_colour(0.0, 1.0, 0.0, 1.0);
+    clear(ClearMask::COLOUR);
+    let mut face = [[0u8; 4]; 4];
+    get_tex_image(
+        TextureImageTarget::CubeMapNegativeZ,
+        0,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        &mut face,
+    );
+    assert_eq!(face, [[0, 255, 0, 255]; 4]);
+
+    assert_eq!(
+        get_framebuffer_attachment_object_type_checked(target, colour),
+        Ok(Some(FramebufferAttachmentObjectType::Texture))
+    );
+    assert_eq!(
+        get_framebuffer_attachment_object_name(target, colour),
+        cube.0
+    );
+    assert_eq!(get_framebuffer_attachment_texture_level(target, colour), 0);
+    assert_eq!(
+        get_framebuffer_attachment_texture_cube_map_face_checked(target, colour),
+        Ok(Some(TextureBinding2DTarget::CubeMapNegativeZ))
+    );
+    assert!(!get_framebuffer_attachment_layered(target, colour));
+    assert_eq!(
+        get_framebuffer_attachment_component_type(target, colour),
+        Some(FramebufferComponentType::UnsignedNormalized)
+    );
+    assert_eq!(
+        get_framebuffer_attachment_colour_encoding_checked(target, colour),
+        Ok(Some(FramebufferColourEncoding::SRGB))
+    );
+
+    // detach the colour image, and render depth into the last layer of the array
+    framebuffer_texture_checked(target, colour, Texture(0), 0).unwrap();
+    assert_eq!(
+        get_framebuffer_attachment_object_type(target, colour),
+        Some(FramebufferAttachmentObjectType::None)
+    );
+    assert_eq!(
+        get_framebuffer_attachment_texture_level_checked(target, colour),
+        Err(FramebufferError::MissingAttachment(colour))
+    );
+    let depth = FramebufferAttachment::Depth;
+    framebuffer_texture_layer_checked(target, depth, array, 0, 2).unwrap();
+    draw_buffer(ColourBuffer::None);
+    read_buffer(ColourBuffer::None);
+    assert_eq!(
+        check_framebuffer_status(target),
+        Some(FramebufferStatus::Complete)
+    );
+    clear(ClearMask::DEPTH);
+    let mut layers = [0f32; 12];
+    get_tex_image(
+        TextureImageTarget::Array2D,
+        0,
+        TextureFormat::DepthComponent,
+        TexturePixelType::F32,
+        &mut layers,
+    );
+    assert_eq!(layers[..8], [0.5; 8]);
+    assert_eq!(layers[8..], [1.0; 4]);
+
+    assert_eq!(
+        get_framebuffer_attachment_texture_layer_checked(target, depth),
+        Ok(2)
+    );
+    assert!(!get_framebuffer_attachment_layered(target, depth));
+    assert_eq!(
+        get_framebuffer_attachment_component_type_checked(target, depth),
+        Ok(Some(FramebufferComponentType::Float))
+    );
+    assert_eq!(
+        get_framebuffer_attachment_texture_cube_map_face(target, depth),
+        None
+    );
+    framebuffer_texture(target, depth, array, 0);
+    assert_eq!(
+        get_framebuffer_attachment_layered_checked(target, depth),
+        Ok(true)
+    );
+
+    assert_eq!(
+        framebuffer_texture_layer_checked(target, depth, array, 0, 1 << 20),
+        Err(FramebufferError::InvalidLayer {
+            level: 0,
+            layer: 1 << 20
+        })
+    );
+    let max = get_max_colour_attachments();
+    assert_eq!(
+        get_framebuffer_attachment_object_name_checked(target, FramebufferAttachment::Colour(max)),
+        Err(FramebufferError::InvalidAttachment(
+            FramebufferAttachment::Colour(max)
+        ))
+    );
+
+    delete_framebuffers(&[framebuffer]);
+    delete_textures(&textures);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn one_and_three_dimensional_attachments() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let target = FramebufferBindingTarget::Draw;
+    let colour = FramebufferAttachment::Colour(0);
+
+    let mut textures = [Texture::default(); 2];
+    gen_textures(&mut textures);
+    let [line, volume] = textures;
+    bind_texture(TextureBindingTarget::Image1D, line);
+    tex_storage_1d(
+        TextureBinding1DTarget::Image1D,
+        1,
+        TextureInternalFormat::R8,
+        4,
+    );
+    bind_texture(TextureBindingTarget::Image3D, volume);
+    tex_storage_3d(
+        TextureBinding3DTarget::Image3D,
+        1,
+        TextureInternalFormat::R8,
+        4,
+        4,
+        4,
+    );
+
+    let mut framebuffer = Framebuffer::default();
+    gen_framebuffers(std::slice::from_mut(&mut framebuffer));
+    // a generated but never bound name is not yet a framebuffer
+    assert!(!is_framebuffer(framebuffer));
+    bind_framebuffer(target, framebuffer);
+    assert!(is_framebuffer(framebuffer));
+
+    framebuffer_texture_1d_checked(target, colour, TextureBinding1DTarget::Image1D, line, 0)
+        .unwrap();
+    assert_eq!(
+        get_framebuffer_attachment_object_name(target, colour),
+        line.0
+    );
+    assert_eq!(
+        check_framebuffer_status(target),
+        Some(FramebufferStatus::Complete)
+    );
+
+    framebuffer_texture_3d_checked(
+        target,
+        colour,
+        TextureBinding3DTarget::Image3D,
+        volume,
+        0,
+        3,
+    )
+    .unwrap();
+    assert_eq!(
+        get_framebuffer_attachment_object_name(target, colour),
+        volume.0
+    );
+    assert_eq!(get_framebuffer_attachment_texture_layer(target, colour), 3);
+    assert_eq!(
+        check_framebuffer_status(target),
+        Some(FramebufferStatus::Complete)
+    );
+
+    let mut renderbuffer = Renderbuffer::default();
+    create_renderbuffers(std::slice::from_mut(&mut renderbuffer));
+    named_renderbuffer_storage(
+        renderbuffer,
+        RenderbufferInternalFormat::DepthComponent24,
+        4,
+        4,
+    );
+    named_framebuffer_renderbuffer_checked(framebuffer, FramebufferAttachment::Depth, renderbuffer)
+        .unwrap();
+    assert_eq!(
+        get_framebuffer_attachment_object_type(target, FramebufferAttachment::Depth),
+        Some(FramebufferAttachmentObjectType::Renderbuffer)
+    );
+    assert_eq!(
+        named_framebuffer_renderbuffer_checked(
+            Framebuffer(42),
+            FramebufferAttachment::Depth,
+            renderbuffer
+        ),
+        Err(FramebufferError::InvalidFramebuffer(Framebuffer(42)))
+    );
+
+    // the default framebuffer's images are not named by attachment points
+    bind_framebuffer(target, Framebuffer(0));
+    assert_eq!(
+        get_framebuffer_attachment_object_type_checked(target, FramebufferAttachment::Depth),
+        Err(FramebufferError::InvalidAttachment(
+            FramebufferAttachment::Depth
+        ))
+    );
+
+    delete_framebuffers(&[framebuffer]);
+    delete_renderbuffers(&[renderbuffer]);
+    delete_textures(&textures);
+    assert_eq!(get_error(), Error::NoError);
+}
@@ -0,0 +1,423 @@
+#![cfg(feature = "headless")]
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+#[test]
+fn builds_colour_and_depth_stencil_attachments() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let mut bound = Texture::default();
+    gen_textures(std::slice::from_mut(&mut bound));
+    bind_texture(TextureBindingTarget::Image2D, bound);
+
+    let built = FramebufferBuilder::new()
+        .colour(
+            0,
+            AttachmentImage::texture(TextureInternalFormat::RGBA8, 4, 4),
+        )
+        .colour(
+            2,
+            AttachmentImage::renderbuffer(RenderbufferInternalFormat::RGBA16F, 4, 4),
+        )
+        .depth_stencil(AttachmentImage::renderbuffer(
+            RenderbufferInternalFormat::Depth24Stencil8,
+            4,
+            4,
+        ))
+        .build()
+        .unwrap();
+    assert!(is_framebuffer(built.framebuffer));
+    assert_eq!(get_texture_binding_2d(), bound);
+    let albedo = built.texture(FramebufferAttachment::Colour(0)).unwrap();
+    let normal = built
+        .renderbuffer(FramebufferAttachment::Colour(2))
+        .unwrap();
+    assert!(is_texture(albedo));
+    assert!(is_renderbuffer(normal));
+    assert_eq!(built.texture(FramebufferAttachment::Colour(2)), None);
+    assert!(built
+        .renderbuffer(FramebufferAttachment::DepthStencil)
+        .is_some());
+
+    let target = FramebufferBindingTarget::ReadDraw;
+    bind_framebuffer(target, built.framebuffer);
+    assert_eq!(
+        get_framebuffer_attachment_object_name(target, FramebufferAttachment::Colour(2)),
+        normal.0
+    );
+    assert_eq!(
+        get_framebuffer_attachment_object_type(target, FramebufferAttachment::Stencil),
+        Some(FramebufferAttachmentObjectType::Renderbuffer)
+    );
+    assert_eq!(get_draw_buffer(0), Some(ColourBuffer::Attachment(0)));
+    assert_eq!(get_draw_buffer(1), Some(ColourBuffer::None));
+    assert_eq!(get_draw_buffer(2), Some(ColourBuffer::Attachment(2)));
+
+    clear_colour(0.0, 0.0, 1.0, 1.0);
+    clear(ClearMask::COLOUR);
+    let mut texels = [[0u8; 4]; 16];
+    bind_texture(TextureBindingTarget::Image2D, albedo);
+    get_tex_image(
+        TextureImageTarget::Image2D,
+        0,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        &mut texels,
+    );
+    assert_eq!(texels, [[0, 0, 255, 255]; 16]);
+
+    bind_framebuffer(target, Framebuffer(0));
+    let framebuffer = built.framebuffer;
+    built.delete();
+    assert!(!is_framebuffer(framebuffer));
+    assert!(!is_texture(albedo));
+    assert!(!is_renderbuffer(normal));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn builds_mip_level_layer_and_multisample_attachments() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    // level 1 of an 8x8 texture matches the 4x4 layers of the depth array
+    let built = FramebufferBuilder::new()
+        .colour(
+            0,
+            AttachmentImage::texture(TextureInternalFormat::RGBA8, 8, 8).with_levels(2, 1),
+        )
+        .depth(
+            AttachmentImage::texture(TextureInternalFormat::DepthComponent32F, 4, 4)
+                .with_layers(3, 1),
+        )
+        .build()
+        .unwrap();
+    let target = FramebufferBindingTarget::Read;
+    bind_framebuffer(target, built.framebuffer);
+    let colour = FramebufferAttachment::Colour(0);
+    let depth = FramebufferAttachment::Depth;
+    assert_eq!(get_framebuffer_attachment_texture_level(target, colour), 1);
+    assert_eq!(get_framebuffer_attachment_texture_layer(target, depth), 1);
+    assert_eq!(
+        get_framebuffer_attachment_object_name(target, depth),
+        built.texture(depth).unwrap().0
+    );
+    built.delete();
+
+    let samples = get_max_samples().min(4);
+    let built = FramebufferBuilder::new()
+        .colour(
+            0,
+            AttachmentImage::texture(TextureInternalFormat::RGBA8, 4, 4).with_samples(samples),
+        )
+        .depth_stencil(
+            AttachmentImage::renderbuffer(RenderbufferInternalFormat::Depth24Stencil8, 4, 4)
+                .with_samples(samples),
+        )
+        .build()
+        .unwrap();
+    bind_framebuffer(target, built.framebuffer);
+    assert_eq!(get_framebuffer_samples(target), samples);
+    built.delete();
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn reports_attachments_at_fault() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let builder = FramebufferBuilder::new()
+        .colour(
+            0,
+            AttachmentImage::texture(TextureInternalFormat::RGBA8, 4, 4),
+        )
+        .colour(
+            1,
+            AttachmentImage::texture(TextureInternalFormat::DepthComponent24, 4, 4),
+        )
+        .colour(
+            2,
+            AttachmentImage::texture(TextureInternalFormat::RGBA8, 2, 2),
+        )
+        .colour(
+            3,
+            AttachmentImage::renderbuffer(RenderbufferInternalFormat::RGBA8, 4, 4).with_samples(4),
+        )
+        .colour(
+            4,
+            AttachmentImage::texture(TextureInternalFormat::CompressedRRGTC1, 4, 4),
+        )
+        .colour(
+            5,
+            AttachmentImage::texture(TextureInternalFormat::RGBA8, 4, 4)
+                .with_levels(1, 1)
+                .with_layers(0, 2),
+        )
+        .depth(AttachmentImage::renderbuffer(
+            RenderbufferInternalFormat::StencilIndex8,
+            4,
+            4,
+        ))
+        .stencil(AttachmentImage::renderbuffer(
+            RenderbufferInternalFormat::RGBA8,
+            0,
+            4,
+        ));
+    let problems = vec![
+        (
+            FramebufferAttachment::Colour(1),
+            AttachmentProblem::Unrenderable,
+        ),
+        (
+            FramebufferAttachment::Colour(2),
+            AttachmentProblem::SizeMismatch {
+                expected: (4, 4),
+                found: (2, 2),
+            },
+        ),
+        (
+            FramebufferAttachment::Colour(3),
+            AttachmentProblem::SampleMismatch {
+                expected: 0,
+                found: 4,
+            },
+        ),
+        (
+            FramebufferAttachment::Colour(4),
+            AttachmentProblem::Unrenderable,
+        ),
+        (
+            FramebufferAttachment::Colour(5),
+            AttachmentProblem::InvalidLevel {
+                level: 1,
+                levels: 1,
+            },
+        ),
+        (
+            FramebufferAttachment::Colour(5),
+            AttachmentProblem::InvalidLayer {
+                layer: 2,
+                layers: 0,
+            },
+        ),
+        (
+            FramebufferAttachment::Colour(5),
+            AttachmentProblem::SizeMismatch {
+                expected: (4, 4),
+                found: (2, 2),
+            },
+        ),
+        (
+            FramebufferAttachment::Depth,
+            AttachmentProblem::Unrenderable,
+        ),
+        (
+            FramebufferAttachment::Stencil,
+            AttachmentProblem::Unrenderable,
+        ),
+        (
+            FramebufferAttachment::Stencil,
+            AttachmentProblem::EmptyImage,
+        ),
+        (
+            FramebufferAttachment::Stencil,
+            AttachmentProblem::SizeMismatch {
+                expected: (4, 4),
+                found: (0, 4),
+            },
+        ),
+    ];
+    assert_eq!(builder.validate(), problems);
+    let report = builder.build().unwrap_err();
+    assert_eq!(
+        report,
+        FramebufferReport {
+            status: None,
+            pending_error: None,
+            problems
+        }
+    );
+    assert!(report
+        .to_string()
+        .starts_with("the framebuffer cannot be completed; Colour(1): the internal format"));
+
+    // replacing the offending images leaves only the stencil attachment at fault
+    let builder = builder
+        .colour(
+            1,
+            AttachmentImage::texture(TextureInternalFormat::RG16F, 4, 4),
+        )
+        .colour(
+            2,
+            AttachmentImage::texture(TextureInternalFormat::R8, 8, 8).with_levels(4, 1),
+        )
+        .colour(
+            3,
+            AttachmentImage::renderbuffer(RenderbufferInternalFormat::RGBA8, 4, 4),
+        )
+        .colour(
+            4,
+            AttachmentImage::texture(TextureInternalFormat::R11fG11fB10f, 4, 4),
+        )
+        .colour(
+            5,
+            AttachmentImage::texture(TextureInternalFormat::RGBA8, 4, 4).with_layers(3, 2),
+        )
+        .depth(AttachmentImage::renderbuffer(
+            RenderbufferInternalFormat::DepthComponent24,
+            4,
+            4,
+        ));
+    assert_eq!(
+        builder.validate(),
+        [
+            (
+                FramebufferAttachment::Stencil,
+                AttachmentProblem::Unrenderable
+            ),
+            (
+                FramebufferAttachment::Stencil,
+                AttachmentProblem::EmptyImage
+            ),
+            (
+                FramebufferAttachment::Stencil,
+                AttachmentProblem::SizeMismatch {
+                    expected: (4, 4),
+                    found: (0, 4)
+                }
+            ),
+        ]
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn reports_depth_and_stencil_attached_with_depth_stencil() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let image = || AttachmentImage::renderbuffer(RenderbufferInternalFormat::Depth24Stencil8, 4, 4);
+    let builder = FramebufferBuilder::new()
+        .depth_stencil(image())
+        .depth(image())
+        .stencil(image());
+    let problems = vec![
+        (
+            FramebufferAttachment::Depth,
+            AttachmentProblem::DepthStencilConflict,
+        ),
+        (
+            FramebufferAttachment::Stencil,
+            AttachmentProblem::DepthStencilConflict,
+        ),
+    ];
+    assert_eq!(builder.validate(), problems);
+    assert_eq!(
+        builder.build().unwrap_err(),
+        FramebufferReport {
+            status: None,
+            pending_error: None,
+            problems
+        }
+    );
+
+    assert_eq!(
+        FramebufferBuilder::new().depth_stencil(image()).validate(),
+        vec![]
+    );
+}
+
+#[test]
+fn reports_status_and_errors_from_gl() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    assert_eq!(
+        FramebufferBuilder::new().build(),
+        Err(FramebufferReport {
+            status: Some(FramebufferStatus::IncompleteMissingAttachment),
+            pending_error: None,
+            problems: vec![],
+        })
+    );
+
+    // the sample count is only limited by the driver
+    let samples = get_max_samples() + 1;
+    let report = FramebufferBuilder::new()
+        .colour(
+            0,
+            AttachmentImage::renderbuffer(RenderbufferInternalFormat::RGBA8, 4, 4)
+                .with_samples(samples),
+        )
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        report.problems,
+        [(
+            FramebufferAttachment::Colour(0),
+            AttachmentProblem::Rejected(Error::InvalidOperation)
+        )]
+    );
+    assert!(report.status.is_some());
+
+    // an error from before the build is returned instead of being blamed on an attachment
+    let builder = FramebufferBuilder::new().colour(
+        0,
+        AttachmentImage::renderbuffer(RenderbufferInternalFormat::RGBA8, 4, 4),
+    );
+    bind_buffer(BufferBindingTarget::Array, Buffer(u32::MAX));
+    let report = builder.build().unwrap_err();
+    assert_eq!(
+        report,
+        FramebufferReport {
+            status: None,
+            pending_error: Some(Error::InvalidOperation),
+            problems: vec![],
+        }
+    );
+    assert_eq!(
+        report.to_string(),
+        "the framebuffer cannot be completed; GL had already recorded InvalidOperation"
+    );
+    assert_eq!(get_error(), Error::NoError);
+    builder.build().unwrap().delete();
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn rejects_formats_that_are_not_colour_renderable() {
+    let formats = [
+        TextureInternalFormat::RGB32F,
+        TextureInternalFormat::RGB16F,
+        TextureInternalFormat::RGB32I,
+        TextureInternalFormat::RGB16UI,
+        TextureInternalFormat::RGB8I,
+        TextureInternalFormat::RGB9E5,
+        TextureInternalFormat::RGBA8snorm,
+        TextureInternalFormat::SRGB8,
+    ];
+    for format in formats {
+        assert_eq!(
+            FramebufferBuilder::new()
+                .colour(0, AttachmentImage::texture(format, 4, 4))
+                .validate(),
+            [(
+                FramebufferAttachment::Colour(0),
+                AttachmentProblem::Unrenderable
+            )],
+            "{format:?}"
+        );
+    }
+
+    let builder = FramebufferBuilder::new()
+        .colour(
+            0,
+            AttachmentImage::texture(TextureInternalFormat::RGB8, 4, 4),
+        )
+        .colour(
+            1,
+            AttachmentImage::texture(TextureInternalFormat::R11fG11fB10f, 4, 4),
+        )
+        .colour(
+            2,
+            AttachmentImage::texture(TextureInternalFormat::RGBA32UI, 4, 4),
+        );
+    assert_eq!(builder.validate(), []);
+}
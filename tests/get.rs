@@ -0,0 +1,160 @@
+#![cfg(feature = "headless")]
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+#[test]
+fn error_flag_is_reset() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    assert_eq!(get_error(), Error::NoError);
+
+    viewport(Rect {
+        x: 0,
+        y: 0,
+        width: u32::MAX,
+        height: 1,
+    });
+    assert_eq!(get_error(), Error::InvalidValue);
+    assert_eq!(get_error(), Error::NoError);
+
+    bind_vertex_array(VertexArray(1234));
+    assert_eq!(get_error(), Error::InvalidOperation);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn active_texture_unit() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    assert_eq!(get_active_texture(), 0);
+    active_texture(3);
+    assert_eq!(get_active_texture(), 3);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn indexed_capabilities() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    assert!(!is_enabled(Capability::Blend));
+    enable_indexed(Capability::Blend, 1);
+    assert!(is_enabled_indexed(Capability::Blend, 1));
+    assert!(!is_enabled_indexed(Capability::Blend, 0));
+    disable_indexed_checked(Capability::Blend, 1).unwrap();
+    assert_eq!(is_enabled_indexed_checked(Capability::Blend, 1), Ok(false));
+
+    enable(Capability::DepthTest);
+    assert_eq!(is_enabled_checked(Capability::DepthTest), Ok(true));
+    assert_eq!(
+        is_enabled_indexed_checked(Capability::DepthTest, 0),
+        Err(StateError::UnsupportedCapability(Capability::DepthTest))
+    );
+    let buffers = get_max_draw_buffers();
+    assert_eq!(
+        enable_indexed_checked(Capability::Blend, buffers),
+        Err(StateError::InvalidCapabilityIndex(
+            Capability::Blend,
+            buffers
+        ))
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn object_bindings() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let mut buffers = [Buffer::default(); 2];
+    gen_buffers(&mut buffers);
+    bind_buffer(BufferBindingTarget::Array, buffers[0]);
+    bind_buffer(BufferBindingTarget::Uniform, buffers[1]);
+    buffer_data(
+        BufferBindingTarget::Uniform,
+        1024,
+        BufferUsageFrequency::Static,
+        BufferUsageNature::Draw,
+    );
+    assert_eq!(get_array_buffer_binding(), buffers[0]);
+    assert_eq!(get_uniform_buffer_binding(), buffers[1]);
+    assert_eq!(get_element_array_buffer_binding(), Buffer(0));
+
+    let target = BufferBindingRangeTarget::Uniform;
+    let offset = get_uniform_buffer_offset_alignment() as i64;
+    bind_buffer_range(target, 2, buffers[1], offset, 64);
+    assert_eq!(get_buffer_binding_indexed(target, 2), buffers[1]);
+    assert_eq!(get_buffer_start_indexed(target, 2), offset);
+    assert_eq!(get_buffer_size_indexed_checked(target, 2), Ok(64));
+    let bindings = get_max_uniform_buffer_bindings();
+    assert_eq!(
+        get_buffer_binding_indexed_checked(target, bindings),
+        Err(BufferError::InvalidBindingIndex(target, bindings))
+    );
+
+    let mut texture = Texture::default();
+    gen_textures(std::slice::from_mut(&mut texture));
+    bind_texture(TextureBindingTarget::Image2D, texture);
+    assert_eq!(get_texture_binding_2d(), texture);
+    assert_eq!(get_texture_binding_3d(), Texture(0));
+
+    let mut framebuffer = Framebuffer::default();
+    gen_framebuffers(std::slice::from_mut(&mut framebuffer));
+    bind_framebuffer(FramebufferBindingTarget::Read, framebuffer);
+    assert_eq!(get_read_framebuffer_binding(), framebuffer);
+    assert_ne!(get_draw_framebuffer_binding(), framebuffer);
+
+    let mut renderbuffer = Renderbuffer::default();
+    gen_renderbuffers(std::slice::from_mut(&mut renderbuffer));
+    bind_renderbuffer(renderbuffer);
+    assert_eq!(get_renderbuffer_binding(), renderbuffer);
+
+    let mut array = VertexArray::default();
+    gen_vertex_arrays(std::slice::from_mut(&mut array));
+    bind_vertex_array(array);
+    assert_eq!(get_vertex_array_binding(), array);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn depth_and_stencil_state() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    assert_eq!(get_depth_func(), Some(CompareFunc::Less));
+    assert!(get_depth_writemask());
+    depth_func(CompareFunc::GreaterOrEqual);
+    depth_mask(false);
+    assert_eq!(get_depth_func(), Some(CompareFunc::GreaterOrEqual));
+    assert!(!get_depth_writemask());
+
+    stencil_func_separate(StencilFace::Back, CompareFunc::Equal, 3, 0x0f);
+    stencil_op_separate(
+        StencilFace::Front,
+        StencilOp::Zero,
+        StencilOp::Replace,
+        StencilOp::IncrementWrap,
+    );
+    assert_eq!(get_stencil_func(), Some(CompareFunc::Always));
+    assert_eq!(get_stencil_back_func(), Some(CompareFunc::Equal));
+    assert_eq!(get_stencil_back_ref(), 3);
+    assert_eq!(get_stencil_back_value_mask(), 0x0f);
+    assert_eq!(get_stencil_fail(), Some(StencilOp::Zero));
+    assert_eq!(get_stencil_pass_depth_fail(), Some(StencilOp::Replace));
+    assert_eq!(
+        get_stencil_pass_depth_pass(),
+        Some(StencilOp::IncrementWrap)
+    );
+    assert_eq!(get_stencil_back_fail(), Some(StencilOp::Keep));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn implementation_limits() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    assert!(get_major_version() >= 4);
+    assert!(get_num_extensions() > 0);
+    assert!(get_max_texture_size() >= 1024);
+    assert!(get_max_3d_texture_size() >= 64);
+    assert!(get_max_array_texture_layers() >= 256);
+    assert!(get_max_vertex_attribs() >= 16);
+    assert!(get_max_uniform_buffer_bindings() >= 24);
+    assert!(get_max_uniform_block_size() >= 16384);
+    assert!(get_min_map_buffer_alignment() >= 64);
+    assert!(get_max_element_index() >= (1 << 24) - 1);
+    assert_eq!(get_error(), Error::NoError);
+}
@@ -0,0 +1,267 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+block_struct! {
+    #[derive(Debug, Default, Copy, Clone)]
+    struct Light {
+        position: [f32; 3],
+        intensity: f32,
+        range: [f32; 2],
+    }
+
+    #[derive(Debug, Default, Copy, Clone)]
+    struct Material {
+        colour: [f32; 3],
+        shininess: f32,
+        normal_matrix: [[f32; 3]; 3],
+        weights: BlockArray<f32, 3>,
+        light: Light,
+        lit: bool,
+    }
+
+    #[derive(Debug, Default, Copy, Clone)]
+    struct Lights {
+        rotation: [[f32; 2]; 2],
+        ambient: [f32; 3],
+        lights: BlockArray<Light, 2>,
+        falloff: BlockArray<f32, 3>,
+    }
+
+    /// Material, with `colour` declared as a `vec4` instead of a `vec3`
+    struct PaddedMaterial {
+        colour: [f32; 4],
+        shininess: f32,
+    }
+
+    /// Material, with `colour` and `shininess` swapped
+    struct SwappedMaterial {
+        shininess: f32,
+        colour: [f32; 3],
+    }
+}
+
+const BLOCK_FRAGMENT_SHADER: &str = r#"
+#version 430 core
+struct Light {
+    vec3 position;
+    float intensity;
+    vec2 range;
+};
+layout (std140, binding = 2) uniform Material {
+    vec3 colour;
+    float shininess;
+    mat3 normal_matrix;
+    float weights[3];
+    Light light;
+    bool lit;
+} material;
+layout (std430, binding = 1) buffer Lights {
+    mat2 rotation;
+    vec3 ambient;
+    Light lights[2];
+    float falloff[3];
+};
+out vec4 fragment_colour;
+void main() {
+    vec3 normal = material.normal_matrix * vec3(0.0, 0.0, 1.0);
+    float red = material.colour.r * material.weights[2] * material.light.intensity * normal.z;
+    red *= material.shininess + material.light.range.y + material.light.position.x;
+    float green = lights[1].intensity * falloff[2] * (rotation * vec2(1.0, 0.0)).x;
+    green += ambient.g + lights[0].range.x;
+    fragment_colour = material.lit ? vec4(red, green, 0.0, 1.0) : vec4(0.0);
+}
+"#;
+
+fn layout_of(layout: Vec<BlockMemberLayout>) -> Vec<(String, usize, usize, usize)> {
+    layout
+        .into_iter()
+        .map(|m| (m.name, m.offset, m.array_stride, m.matrix_stride))
+        .collect()
+}
+
+fn member(
+    name: &str,
+    offset: usize,
+    array_stride: usize,
+    matrix_stride: usize,
+) -> (String, usize, usize, usize) {
+    (name.to_string(), offset, array_stride, matrix_stride)
+}
+
+#[test]
+fn std140_and_std430_offsets() {
+    assert_eq!(Material::size(BlockLayout::Std140), 160);
+    assert_eq!(
+        layout_of(Material::layout(BlockLayout::Std140)),
+        [
+            member("colour", 0, 0, 0),
+            member("shininess", 12, 0, 0),
+            member("normal_matrix", 16, 0, 16),
+            member("weights[0]", 64, 16, 0),
+            member("light.position", 112, 0, 0),
+            member("light.intensity", 124, 0, 0),
+            member("light.range", 128, 0, 0),
+            member("lit", 144, 0, 0),
+        ]
+    );
+
+    assert_eq!(Lights::size(BlockLayout::Std430), 112);
+    assert_eq!(
+        layout_of(Lights::layout(BlockLayout::Std430)),
+        [
+            member("rotation", 0, 0, 8),
+            member("ambient", 16, 0, 0),
+            member("lights[0].position", 32, 0, 0),
+            member("lights[0].intensity", 44, 0, 0),
+            member("lights[0].range", 48, 0, 0),
+            member("lights[1].position", 64, 0, 0),
+            member("lights[1].intensity", 76, 0, 0),
+            member("lights[1].range", 80, 0, 0),
+            member("falloff[0]", 96, 4, 0),
+        ]
+    );
+    assert_eq!(Lights::size(BlockLayout::Std140), 160);
+
+    let light = Light {
+        position: [1.0, 2.0, 3.0],
+        intensity: 4.0,
+        range: [5.0, 6.0],
+    };
+    let bytes = light.to_bytes(BlockLayout::Std140);
+    let floats: Vec<f32> = bytes
+        .chunks(4)
+        .map(|chunk| f32::from_ne_bytes(chunk.try_into().unwrap()))
+        .collect();
+    assert_eq!(floats, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 0.0, 0.0]);
+}
+
+#[test]
+fn block_layouts_match_the_driver() {
+    let context = HeadlessContext::new(4, 4).unwrap();
+    let program = common::link(common::POSITION_VERTEX_SHADER, BLOCK_FRAGMENT_SHADER);
+
+    assert_eq!(get_program_active_uniform_blocks(program), 1);
+    let index = get_uniform_block_index(program, c"Material").unwrap();
+    assert_eq!(get_uniform_block_index(program, c"material"), None);
+    let mut buffer = [0; 16];
+    assert_eq!(
+        get_active_uniform_block_name(program, index, &mut buffer),
+        b"Material"
+    );
+    assert_eq!(get_active_uniform_block_name_length(program, index), 9);
+    assert_eq!(get_active_uniform_block_binding(program, index), 2);
+    assert_eq!(
+        get_active_uniform_block_data_size(program, index),
+        Material::size(BlockLayout::Std140) as u32
+    );
+    let count = get_active_uniform_block_active_uniforms(program, index);
+    let mut indices = [0; 16];
+    let indices = get_active_uniform_block_active_uniform_indices(program, index, &mut indices);
+    assert_eq!(indices.len(), count as usize);
+    assert!(get_active_uniform_block_referenced_by(
+        program,
+        index,
+        ShaderType::Fragment
+    ));
+    assert!(!get_active_uniform_block_referenced_by(
+        program,
+        index,
+        ShaderType::Vertex
+    ));
+
+    let uniform_block = ProgramInterface::UniformBlock;
+    let storage_block = ProgramInterface::ShaderStorageBlock;
+    verify_block_layout::<Material>(program, uniform_block, c"Material", BlockLayout::Std140)
+        .unwrap();
+    verify_block_layout::<Lights>(program, storage_block, c"Lights", BlockLayout::Std430).unwrap();
+    assert_eq!(
+        verify_block_layout::<Lights>(program, storage_block, c"Lights", BlockLayout::Std140),
+        Err(LayoutMismatch::MatrixStride {
+            member: "rotation".to_string(),
+            expected: 8,
+            found: 16
+        })
+    );
+    let std140 = BlockLayout::Std140;
+    assert!(matches!(
+        verify_block_layout::<PaddedMaterial>(program, uniform_block, c"Material", std140),
+        Err(LayoutMismatch::Type { member, .. }) if member == "colour"
+    ));
+    assert!(matches!(
+        verify_block_layout::<SwappedMaterial>(program, uniform_block, c"Material", std140),
+        Err(LayoutMismatch::Offset { member, expected: 0, found: 16 }) if member == "colour"
+    ));
+    assert_eq!(
+        verify_block_layout::<Material>(program, uniform_block, c"Missing", BlockLayout::Std140),
+        Err(LayoutMismatch::BlockNotFound)
+    );
+
+    // move both blocks to other binding points
+    uniform_block_binding(program, index, 3);
+    assert_eq!(get_active_uniform_block_binding(program, index), 3);
+    let lights = get_program_resource_index(program, storage_block, c"Lights").unwrap();
+    shader_storage_block_binding(program, lights, 4);
+    assert_eq!(
+        uniform_block_binding_checked(program, index, 1000),
+        Err(ShaderError::InvalidBinding(1000))
+    );
+    assert_eq!(
+        shader_storage_block_binding_checked(program, 7, 0),
+        Err(ShaderError::InvalidIndex(7))
+    );
+    assert_eq!(
+        get_active_uniform_block_binding_checked(program, 7),
+        Err(ShaderError::InvalidIndex(7))
+    );
+
+    let material = Material {
+        colour: [1.0, 0.0, 0.0],
+        shininess: 0.5,
+        normal_matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        weights: BlockArray([0.0, 0.0, 1.0]),
+        light: Light {
+            position: [0.25, 0.0, 0.0],
+            intensity: 1.0,
+            range: [0.0, 0.25],
+        },
+        lit: true,
+    };
+    let mut lights = Lights {
+        rotation: [[1.0, 0.0], [0.0, 1.0]],
+        ..Default::default()
+    };
+    lights.lights[1].intensity = 0.5;
+    lights.falloff[2] = 2.0;
+
+    let mut buffers = [Buffer::default(); 2];
+    gen_buffers(&mut buffers);
+    bind_buffer(BufferBindingTarget::Uniform, buffers[0]);
+    buffer_data(
+        BufferBindingTarget::Uniform,
+        material.to_bytes(BlockLayout::Std140).as_slice(),
+        BufferUsageFrequency::Static,
+        BufferUsageNature::Draw,
+    );
+    bind_buffer(BufferBindingTarget::ShaderStorage, buffers[1]);
+    buffer_data(
+        BufferBindingTarget::ShaderStorage,
+        lights.to_bytes(BlockLayout::Std430).as_slice(),
+        BufferUsageFrequency::Static,
+        BufferUsageNature::Draw,
+    );
+    bind_buffer_base(BufferBindingRangeTarget::Uniform, 3, buffers[0]);
+    bind_buffer_base(BufferBindingRangeTarget::ShaderStorage, 4, buffers[1]);
+
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+    use_program(program);
+    draw_arrays(DrawMode::Triangles, 0, 6);
+    assert!(context
+        .read_colour()
+        .iter()
+        .all(|p| *p == [255, 255, 0, 255]));
+    assert_eq!(get_error(), Error::NoError);
+}
@@ -0,0 +1,203 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+fn draw_quad(program: Program, depth: f32, colour: [f32; 4]) {
+    uniform_1f32(get_uniform_location(program, c"depth"), depth);
+    let [r, g, b, a] = colour;
+    uniform_4f32(get_uniform_location(program, c"colour"), r, g, b, a);
+    draw_arrays(DrawMode::Triangles, 0, 6);
+}
+
+#[test]
+fn clear_masks() {
+    let context = HeadlessContext::new(2, 2).unwrap();
+    clear_colour(0.0, 0.0, 1.0, 1.0);
+    clear(ClearMask::COLOUR | ClearMask::DEPTH | ClearMask::STENCIL);
+    assert!(context.read_colour().iter().all(|p| *p == BLUE));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn depth_test_and_mask() {
+    let context = HeadlessContext::new(2, 2).unwrap();
+    let program = common::link(
+        common::DEPTH_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+    use_program(program);
+
+    enable(Capability::DepthTest);
+    depth_func(CompareFunc::Less);
+    clear(ClearMask::COLOUR | ClearMask::DEPTH);
+    draw_quad(program, 0.0, [1.0, 0.0, 0.0, 1.0]);
+    draw_quad(program, 0.5, [0.0, 0.0, 1.0, 1.0]);
+    assert!(context.read_colour().iter().all(|p| *p == RED));
+
+    depth_func(CompareFunc::GreaterOrEqual);
+    draw_quad(program, 0.5, [0.0, 0.0, 1.0, 1.0]);
+    assert!(context.read_colour().iter().all(|p| *p == BLUE));
+
+    // depth writes are masked, so the depth buffer still holds the value of 0.5
+    depth_mask(false);
+    depth_func(CompareFunc::Always);
+    draw_quad(program, -0.5, [1.0, 0.0, 0.0, 1.0]);
+    depth_mask(true);
+    depth_func(CompareFunc::Equal);
+    draw_quad(program, 0.5, [0.0, 0.0, 1.0, 1.0]);
+    assert!(context.read_colour().iter().all(|p| *p == BLUE));
+
+    disable(Capability::DepthTest);
+    draw_quad(program, 0.9, [1.0, 0.0, 0.0, 1.0]);
+    assert!(context.read_colour().iter().all(|p| *p == RED));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn stencil_write_mask() {
+    let context = HeadlessContext::new(2, 1).unwrap();
+    let program = common::link(
+        common::DEPTH_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    common::vertex_array(&common::LEFT_HALF_QUAD);
+    use_program(program);
+
+    clear(ClearMask::COLOUR | ClearMask::STENCIL);
+    enable(Capability::StencilTest);
+    stencil_op(StencilOp::Keep, StencilOp::Keep, StencilOp::Replace);
+
+    // the masked stencil write leaves the stencil buffer cleared
+    stencil_mask(0);
+    stencil_func(CompareFunc::Always, 1, 0xFF);
+    draw_quad(program, 0.0, [0.0, 0.0, 0.0, 0.0]);
+    stencil_mask_separate(StencilFace::FrontAndBack, 0xFF);
+
+    stencil_func(CompareFunc::Equal, 0, 0xFF);
+    draw_quad(program, 0.0, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(context.read_colour(), [RED, [0, 0, 0, 0]]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn clear_values_and_colour_mask() {
+    let context = HeadlessContext::new(2, 1).unwrap();
+    assert_eq!(get_colour_clear_value(), [0.0; 4]);
+    assert_eq!(get_depth_clear_value(), 1.0);
+    assert_eq!(get_stencil_clear_value(), 0);
+    assert_eq!(get_colour_writemask(), [true; 4]);
+
+    clear_colour(0.0, 0.0, 1.0, 1.0);
+    clear_depth_checked(0.25).unwrap();
+    clear_stencil_checked(3).unwrap();
+    assert_eq!(get_colour_clear_value(), [0.0, 0.0, 1.0, 1.0]);
+    assert_eq!(get_depth_clear_value(), 0.25);
+    assert_eq!(get_stencil_clear_value(), 3);
+    // clear depth values are clamped to [0, 1]
+    clear_depth(2.0);
+    assert_eq!(get_depth_clear_value(), 1.0);
+
+    clear(ClearMask::COLOUR);
+    colour_mask_checked(true, false, true, true).unwrap();
+    assert_eq!(get_colour_writemask(), [true, false, true, true]);
+    clear_colour(1.0, 1.0, 0.0, 1.0);
+    clear(ClearMask::COLOUR);
+    assert!(context.read_colour().iter().all(|p| *p == [255, 0, 0, 255]));
+
+    colour_mask_buffer_checked(0, true, true, true, false).unwrap();
+    assert_eq!(get_colour_writemask_buffer(0), [true, true, true, false]);
+    assert_eq!(get_colour_writemask(), [true, true, true, false]);
+    let buffers = get_max_draw_buffers();
+    assert_eq!(
+        colour_mask_buffer_checked(buffers, true, true, true, true),
+        Err(StateError::InvalidDrawBufferIndex(buffers))
+    );
+    colour_mask(true, true, true, true);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn clear_individual_buffers() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    // an albedo attachment, next to an unsigned integer object id attachment
+    let built = FramebufferBuilder::new()
+        .colour(
+            0,
+            AttachmentImage::texture(TextureInternalFormat::RGBA8, 2, 2),
+        )
+        .colour(
+            1,
+            AttachmentImage::texture(TextureInternalFormat::R32UI, 2, 2),
+        )
+        .depth(AttachmentImage::texture(
+            TextureInternalFormat::DepthComponent32F,
+            2,
+            2,
+        ))
+        .build()
+        .unwrap();
+    let albedo = built.texture(FramebufferAttachment::Colour(0)).unwrap();
+    let id = built.texture(FramebufferAttachment::Colour(1)).unwrap();
+    let depth = built.texture(FramebufferAttachment::Depth).unwrap();
+    let read_texture = |texture, format, pixel_type| {
+        let mut texels = [0u32; 4];
+        bind_texture(TextureBindingTarget::Image2D, texture);
+        get_tex_image(
+            TextureImageTarget::Image2D,
+            0,
+            format,
+            pixel_type,
+            &mut texels,
+        );
+        texels
+    };
+
+    bind_framebuffer(FramebufferBindingTarget::Draw, built.framebuffer);
+    clear_buffer_f32_checked(ClearBufferF32::Colour(0, [1.0, 0.0, 0.0, 1.0])).unwrap();
+    clear_buffer_u32_checked(ClearBufferU32::Colour(1, [42, 0, 0, 0])).unwrap();
+    clear_buffer_f32_checked(ClearBufferF32::Depth(0.5)).unwrap();
+    let red = u32::from_le_bytes([255, 0, 0, 255]);
+    let format = TextureFormat::RGBA;
+    assert_eq!(read_texture(albedo, format, TexturePixelType::U8), [red; 4]);
+    let format = TextureFormat::I32R;
+    assert_eq!(read_texture(id, format, TexturePixelType::U32), [42; 4]);
+    let format = TextureFormat::DepthComponent;
+    let texels = read_texture(depth, format, TexturePixelType::F32);
+    assert_eq!(texels, [0.5f32.to_bits(); 4]);
+    let buffers = get_max_draw_buffers();
+    assert_eq!(
+        clear_buffer_f32_checked(ClearBufferF32::Colour(buffers, [0.0; 4])),
+        Err(StateError::InvalidDrawBufferIndex(buffers))
+    );
+
+    // only the selected buffer is cleared, and the clear values are left unchanged
+    bind_framebuffer(FramebufferBindingTarget::Draw, Framebuffer(0));
+    clear_named_framebuffer_u32_checked(built.framebuffer, ClearBufferU32::Colour(1, [7, 0, 0, 0]))
+        .unwrap();
+    clear_named_framebuffer_depth_stencil_checked(built.framebuffer, 0.25, 0).unwrap();
+    let format = TextureFormat::RGBA;
+    assert_eq!(read_texture(albedo, format, TexturePixelType::U8), [red; 4]);
+    let format = TextureFormat::I32R;
+    assert_eq!(read_texture(id, format, TexturePixelType::U32), [7; 4]);
+    let format = TextureFormat::DepthComponent;
+    let texels = read_texture(depth, format, TexturePixelType::F32);
+    assert_eq!(texels, [0.25f32.to_bits(); 4]);
+    assert_eq!(get_colour_clear_value(), [0.0; 4]);
+    assert_eq!(get_depth_clear_value(), 1.0);
+
+    let framebuffer = built.framebuffer;
+    built.delete();
+    assert_eq!(
+        clear_named_framebuffer_i32_checked(framebuffer, ClearBufferI32::Stencil(0)),
+        Err(StateError::InvalidFramebuffer(framebuffer))
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
@@ -0,0 +1,1396 @@
+#![cfg(feature = "mock")]
+
+use rgl::mock::{MockArg, MockContext};
+use rgl::prelude::*;
+
+#[test]
+fn buffer_calls() {
+    let context = MockContext::install();
+
+    let mut buffers = [Buffer::default(); 2];
+    gen_buffers(&mut buffers);
+    assert_eq!(buffers, [Buffer(1), Buffer(2)]);
+    assert!(!is_buffer(buffers[0]));
+
+    bind_buffer(BufferBindingTarget::Array, buffers[0]);
+    buffer_data(
+        BufferBindingTarget::Array,
+        &[1u8, 2, 3, 4],
+        BufferUsageFrequency::Static,
+        BufferUsageNature::Draw,
+    );
+    buffer_sub_data(BufferBindingTarget::Array, 1, &[9u8, 8]);
+    let mut read = [0u8; 4];
+    get_buffer_sub_data(BufferBindingTarget::Array, 0, &mut read);
+    assert_eq!(read, [1, 9, 8, 4]);
+    assert_eq!(get_buffer_size(BufferBindingTarget::Array), 4);
+    delete_buffers(&buffers);
+
+    assert_eq!(
+        context.log(),
+        [
+            "GenBuffers(2, [1, 2])",
+            "IsBuffer(1)",
+            "BindBuffer(ARRAY_BUFFER, 1)",
+            "BufferData(ARRAY_BUFFER, 4, [1, 2, 3, 4], STATIC_DRAW)",
+            "BufferSubData(ARRAY_BUFFER, 1, 2, [9, 8])",
+            "GetBufferSubData(ARRAY_BUFFER, 0, 4, _)",
+            "GetBufferParameteri64v(ARRAY_BUFFER, BUFFER_SIZE, _)",
+            "DeleteBuffers(2, [1, 2])",
+        ]
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn buffer_checked_errors() {
+    let context = MockContext::install();
+
+    assert_eq!(
+        bind_buffer_checked(BufferBindingTarget::Array, Buffer(42)),
+        Err(BufferError::InvalidBuffer(Buffer(42)))
+    );
+
+    let mut buffer = Buffer::default();
+    gen_buffers(std::slice::from_mut(&mut buffer));
+    bind_buffer(BufferBindingTarget::Uniform, buffer);
+    buffer_storage(
+        BufferBindingTarget::Uniform,
+        &[0u32],
+        BufferStorageFlags::DYNAMIC | BufferStorageFlags::READ,
+    );
+    context.clear_log();
+
+    assert_eq!(
+        buffer_data_checked(
+            BufferBindingTarget::Uniform,
+            &[0u32],
+            BufferUsageFrequency::Dynamic,
+            BufferUsageNature::Draw,
+        ),
+        Err(BufferError::ImmutableBufferTarget(
+            BufferBindingTarget::Uniform
+        ))
+    );
+    assert_eq!(
+        context.log(),
+        [
+            "BufferData(UNIFORM_BUFFER, 4, [0, 0, 0, 0], DYNAMIC_DRAW)",
+            "GetError()",
+            "GetBufferParameteriv(UNIFORM_BUFFER, BUFFER_IMMUTABLE_STORAGE, _)",
+        ]
+    );
+
+    context.set_error(Error::OutOfMemory);
+    assert_eq!(
+        bind_buffer_checked(BufferBindingTarget::Uniform, buffer),
+        Err(BufferError::Unexpected(Error::OutOfMemory))
+    );
+}
+
+#[test]
+fn buffer_mapping() {
+    let _context = MockContext::install();
+
+    let mut buffer = Buffer::default();
+    gen_buffers(std::slice::from_mut(&mut buffer));
+    bind_buffer(BufferBindingTarget::CopyWrite, buffer);
+    buffer_data(
+        BufferBindingTarget::CopyWrite,
+        &[0u8; 8],
+        BufferUsageFrequency::Stream,
+        BufferUsageNature::Copy,
+    );
+
+    let pointer = map_buffer_range(BufferBindingTarget::CopyWrite, 4, 4, BufferMapFlags::WRITE);
+    assert!(!pointer.is_null());
+    assert_eq!(get_buffer_pointer(BufferBindingTarget::CopyWrite), pointer);
+    // SAFE: the mock maps 4 writable bytes
+    unsafe { std::slice::from_raw_parts_mut(pointer as *mut u8, 4) }.fill(7);
+    assert!(unmap_buffer(BufferBindingTarget::CopyWrite));
+    assert!(!unmap_buffer(BufferBindingTarget::CopyWrite));
+    assert_eq!(get_error(), Error::InvalidOperation);
+
+    let mut read = [0u8; 8];
+    get_named_buffer_sub_data(buffer, 0, &mut read);
+    assert_eq!(read, [0, 0, 0, 0, 7, 7, 7, 7]);
+}
+
+#[test]
+fn texture_calls() {
+    let context = MockContext::install();
+
+    let mut texture = Texture::default();
+    gen_textures(std::slice::from_mut(&mut texture));
+    active_texture(2);
+    bind_texture(TextureBindingTarget::Image2D, texture);
+    tex_image_2d::<u8>(
+        TextureBinding2DTarget::Image2D,
+        0,
+        TextureInternalFormat::RGBA8,
+        64,
+        32,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        TextureData::Reserve,
+    );
+    texture_target_min_filter(TextureBindingTarget::Image2D, TextureMinFilter::Nearest);
+    texture_target_wrap_s(TextureBindingTarget::Image2D, TextureWrapMode::ClampToEdge);
+    texture_target_base_level(TextureBindingTarget::Image2D, 1);
+    generate_mipmap(TextureBindingTarget::Image2D);
+    assert!(is_texture(texture));
+    assert_eq!(get_active_texture(), 2);
+
+    assert_eq!(
+        context.log(),
+        [
+            "GenTextures(1, [1])",
+            "ActiveTexture(TEXTURE2)",
+            "BindTexture(TEXTURE_2D, 1)",
+            "TexImage2D(TEXTURE_2D, 0, RGBA8, 64, 32, 0, RGBA, UNSIGNED_BYTE, NULL)",
+            "TexParameteri(TEXTURE_2D, TEXTURE_MIN_FILTER, NEAREST)",
+            "TexParameteri(TEXTURE_2D, TEXTURE_WRAP_S, CLAMP_TO_EDGE)",
+            "TexParameteri(TEXTURE_2D, TEXTURE_BASE_LEVEL, 1)",
+            "GenerateMipmap(TEXTURE_2D)",
+            "IsTexture(1)",
+            "GetIntegerv(ACTIVE_TEXTURE, _)",
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "pixel data holds 15 bytes, but the image requires 16")]
+fn short_pixel_data_is_never_passed_to_the_gl() {
+    let _context = MockContext::install();
+    tex_sub_image_2d(
+        TextureBinding2DTarget::Image2D,
+        0,
+        0,
+        0,
+        4,
+        4,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        TextureSubData::Data(&[0u8; 15]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "pixel data holds 63 bytes, but the pixels require 64")]
+fn short_pixel_destination_is_never_passed_to_the_gl() {
+    let _context = MockContext::install();
+    read_pixels(
+        0,
+        0,
+        4,
+        4,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        &mut [0u8; 63],
+    );
+}
+
+#[test]
+fn texture_storage_calls() {
+    let context = MockContext::install();
+
+    tex_storage_2d(
+        TextureStorage2DTarget::Image2D,
+        1,
+        TextureInternalFormat::RGBA8,
+        2,
+        1,
+    );
+    tex_sub_image_2d::<u8>(
+        TextureBinding2DTarget::Image2D,
+        0,
+        1,
+        0,
+        1,
+        1,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        TextureSubData::Offset(16),
+    );
+    tex_storage_3d(
+        TextureBinding3DTarget::Image2DArray,
+        1,
+        TextureInternalFormat::R8,
+        4,
+        4,
+        2,
+    );
+    assert_eq!(
+        tex_sub_image_3d_checked(
+            TextureBinding3DTarget::Image2DArray,
+            0,
+            0,
+            0,
+            1,
+            4,
+            4,
+            1,
+            TextureFormat::R,
+            TexturePixelType::U8,
+            TextureSubData::Data(&[0u8; 15]),
+        ),
+        Err(TextureError::InsufficientPixelData {
+            required: 16,
+            provided: 15
+        })
+    );
+    assert_eq!(
+        tex_storage_1d_checked(
+            TextureBinding1DTarget::Image1D,
+            0,
+            TextureInternalFormat::R8,
+            4
+        ),
+        Err(TextureError::InvalidLevel(0))
+    );
+
+    assert_eq!(
+        context.log(),
+        [
+            "TexStorage2D(TEXTURE_2D, 1, RGBA8, 2, 1)",
+            "TexSubImage2D(TEXTURE_2D, 0, 1, 0, 1, 1, RGBA, UNSIGNED_BYTE, <pointer>)",
+            "TexStorage3D(TEXTURE_2D_ARRAY, 1, R8, 4, 4, 2)",
+            "GetIntegerv(UNPACK_ALIGNMENT, _)",
+            "GetIntegerv(UNPACK_ROW_LENGTH, _)",
+            "GetIntegerv(UNPACK_SKIP_PIXELS, _)",
+            "GetIntegerv(UNPACK_SKIP_ROWS, _)",
+            "GetIntegerv(UNPACK_IMAGE_HEIGHT, _)",
+            "GetIntegerv(UNPACK_SKIP_IMAGES, _)",
+            "TexStorage1D(TEXTURE_1D, 0, R8, 4)",
+            "GetError()",
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "pixel data holds 16 bytes, but the image requires 32")]
+fn short_compressed_data_is_never_passed_to_the_gl() {
+    let _context = MockContext::install();
+    compressed_tex_image_2d(
+        TextureBinding2DTarget::Image2D,
+        0,
+        TextureInternalFormat::CompressedRGBABPTCUNORM,
+        8,
+        4,
+        CompressedTextureData::Data(&[0u8; 16]),
+    );
+}
+
+#[test]
+fn compressed_texture_calls() {
+    let context = MockContext::install();
+
+    let blocks = [0u8; 32];
+    compressed_tex_image_2d(
+        TextureBinding2DTarget::Image2D,
+        0,
+        TextureInternalFormat::CompressedRGRGTC2,
+        4,
+        4,
+        CompressedTextureData::Data(&blocks),
+    );
+    compressed_tex_sub_image_3d(
+        TextureBinding3DTarget::Image2DArray,
+        1,
+        0,
+        0,
+        2,
+        4,
+        4,
+        1,
+        TextureInternalFormat::CompressedRGBABPTCUNORM,
+        CompressedTextureData::Offset {
+            offset: 64,
+            size: 16,
+        },
+    );
+    assert_eq!(
+        compressed_tex_image_2d_checked(
+            TextureBinding2DTarget::Image2D,
+            0,
+            TextureInternalFormat::CompressedRGBABPTCUNORM,
+            8,
+            4,
+            CompressedTextureData::Data(&blocks[..16]),
+        ),
+        Err(TextureError::InsufficientPixelData {
+            required: 32,
+            provided: 16
+        })
+    );
+
+    assert_eq!(
+        context.log(),
+        [
+            "CompressedTexImage2D(TEXTURE_2D, 0, COMPRESSED_RG_RGTC2, 4, 4, 0, 16, <pointer>)",
+            "CompressedTexSubImage3D(TEXTURE_2D_ARRAY, 1, 0, 0, 2, 4, 4, 1, \
+             COMPRESSED_RGBA_BPTC_UNORM, 16, <pointer>)",
+        ]
+    );
+}
+
+#[test]
+fn texture_parameter_queries() {
+    let context = MockContext::install();
+
+    let target = TextureBindingTarget::Image2D;
+    assert_eq!(get_texture_target_base_level(target), 0);
+    assert_eq!(get_texture_target_border_colour_u32(target), [0; 4]);
+    // the mock writes 0, which is not a valid filter
+    assert_eq!(get_texture_target_min_filter(target), None);
+    assert_eq!(
+        get_texture_target_swizzle_rgba(target),
+        Some([TextureSwizzle::Zero; 4])
+    );
+    let image = TextureImageTarget::CubeMapPositiveX;
+    assert_eq!(get_tex_level_width(image, 2), 0);
+    assert_eq!(get_tex_level_red_size_checked(image, 2), Ok(0));
+
+    assert_eq!(
+        context.log(),
+        [
+            "GetTexParameteriv(TEXTURE_2D, TEXTURE_BASE_LEVEL, _)",
+            "GetTexParameterIuiv(TEXTURE_2D, TEXTURE_BORDER_COLOR, _)",
+            "GetTexParameteriv(TEXTURE_2D, TEXTURE_MIN_FILTER, _)",
+            "GetTexParameteriv(TEXTURE_2D, TEXTURE_SWIZZLE_RGBA, _)",
+            "GetTexLevelParameteriv(TEXTURE_CUBE_MAP_POSITIVE_X, 2, TEXTURE_WIDTH, _)",
+            "GetTexLevelParameteriv(TEXTURE_CUBE_MAP_POSITIVE_X, 2, TEXTURE_RED_SIZE, _)",
+            "GetError()",
+        ]
+    );
+}
+
+#[test]
+fn texture_parameter_calls() {
+    let context = MockContext::install();
+
+    use TextureSwizzle::*;
+    let target = TextureBindingTarget::Image2D;
+    texture_target_swizzle_rgba(target, [Red, Red, Red, One]);
+    texture_target_swizzle_a(target, Green);
+    texture_target_max_level(target, 3);
+    texture_target_min_lod(target, 1.0);
+
+    let mut texture = Texture::default();
+    gen_textures(std::slice::from_mut(&mut texture));
+    bind_texture(target, texture);
+    texture_parameter_wrap_s(texture, TextureWrapMode::ClampToEdge);
+    texture_parameter_border_colour_u32(texture, [1, 2, 3, 4]);
+    // the mock reports no anisotropic filtering, so every request is clamped to 1.0
+    texture_parameter_max_anisotropy(texture, 16.0);
+    assert_eq!(
+        texture_parameter_min_filter_checked(Texture(9), TextureMinFilter::Nearest),
+        Err(TextureError::InvalidTexture(Texture(9)))
+    );
+
+    assert_eq!(
+        context.log(),
+        [
+            "TexParameteriv(TEXTURE_2D, TEXTURE_SWIZZLE_RGBA, [6403, 6403, 6403, 1])",
+            "TexParameteri(TEXTURE_2D, TEXTURE_SWIZZLE_A, GREEN)",
+            "TexParameteri(TEXTURE_2D, TEXTURE_MAX_LEVEL, 3)",
+            "TexParameterf(TEXTURE_2D, TEXTURE_MIN_LOD, 1.0)",
+            "GenTextures(1, [1])",
+            "BindTexture(TEXTURE_2D, 1)",
+            "TextureParameteri(1, TEXTURE_WRAP_S, CLAMP_TO_EDGE)",
+            "TextureParameterIuiv(1, TEXTURE_BORDER_COLOR, [1, 2, 3, 4])",
+            "GetFloatv(0x84FF, _)",
+            "TextureParameterf(1, 0x84FE, 1.0)",
+            "TextureParameteri(9, TEXTURE_MIN_FILTER, NEAREST)",
+            "GetError()",
+            "IsTexture(9)",
+        ]
+    );
+}
+
+#[test]
+fn sampler_calls() {
+    let context = MockContext::install();
+
+    let mut samplers = [Sampler::default(); 2];
+    gen_samplers(&mut samplers);
+    assert_eq!(samplers, [Sampler(1), Sampler(2)]);
+    assert!(is_sampler(samplers[0]));
+    sampler_min_filter(samplers[0], TextureMinFilter::LinearMipmapLinear);
+    sampler_wrap_s(samplers[1], TextureWrapMode::ClampToEdge);
+    sampler_max_lod(samplers[1], 4.0);
+    bind_sampler(3, samplers[0]);
+    bind_samplers(0, &samplers);
+    delete_samplers(&samplers);
+    assert!(!is_sampler(samplers[0]));
+
+    assert_eq!(
+        context.log(),
+        [
+            "GenSamplers(2, [1, 2])",
+            "IsSampler(1)",
+            "SamplerParameteri(1, TEXTURE_MIN_FILTER, LINEAR_MIPMAP_LINEAR)",
+            "SamplerParameteri(2, TEXTURE_WRAP_S, CLAMP_TO_EDGE)",
+            "SamplerParameterf(2, TEXTURE_MAX_LOD, 4.0)",
+            "BindSampler(3, 1)",
+            "BindSamplers(0, 2, [1, 2])",
+            "DeleteSamplers(2, [1, 2])",
+            "IsSampler(1)",
+        ]
+    );
+
+    let mut sampler = Sampler::default();
+    create_samplers(std::slice::from_mut(&mut sampler));
+    assert_eq!(
+        bind_sampler_checked(80, sampler),
+        Err(SamplerError::InvalidTextureUnit(80))
+    );
+    assert_eq!(
+        bind_samplers_checked(79, &[sampler, sampler]),
+        Err(SamplerError::InvalidTextureUnitRange {
+            first: 79,
+            count: 2
+        })
+    );
+    assert_eq!(
+        bind_samplers_checked(0, &[Sampler(0), sampler, Sampler(9)]),
+        Err(SamplerError::InvalidSampler(Sampler(9)))
+    );
+    assert_eq!(
+        sampler_compare_mode_checked(samplers[1], TextureCompareMode::Ref),
+        Err(SamplerError::InvalidSampler(samplers[1]))
+    );
+    assert_eq!(
+        get_sampler_wrap_t_checked(Sampler(9)),
+        Err(SamplerError::InvalidSampler(Sampler(9)))
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn draw_calls() {
+    let context = MockContext::install();
+
+    let mut vao = VertexArray::default();
+    gen_vertex_arrays(std::slice::from_mut(&mut vao));
+    bind_vertex_array(vao);
+    enable_vertex_attrib_array(0);
+    vertex_attrib_float_pointer(
+        0,
+        VertexAttribSize::Triple,
+        VertexAttribFloatType::F32,
+        false,
+        12,
+        0,
+    );
+    clear(ClearMask::COLOUR | ClearMask::DEPTH);
+    draw_arrays(DrawMode::Triangles, 0, 3);
+    draw_elements(DrawMode::TriangleStrip, 4, DrawIndexType::U16, 8);
+    draw_elements_instanced(DrawMode::Points, 1, DrawIndexType::U32, 0, 10);
+
+    assert_eq!(
+        context.log(),
+        [
+            "GenVertexArrays(1, [1])",
+            "BindVertexArray(1)",
+            "EnableVertexAttribArray(0)",
+            "VertexAttribPointer(0, 3, FLOAT, FALSE, 12, 0)",
+            "Clear(COLOR_BUFFER_BIT | DEPTH_BUFFER_BIT)",
+            "DrawArrays(TRIANGLES, 0, 3)",
+            "DrawElements(TRIANGLE_STRIP, 4, UNSIGNED_SHORT, 8)",
+            "DrawElementsInstanced(POINTS, 1, UNSIGNED_INT, 0, 10)",
+        ]
+    );
+
+    let calls = context.calls();
+    assert_eq!(calls[5].name, "DrawArrays");
+    assert_eq!(
+        calls[5].args,
+        [
+            MockArg::Enum {
+                value: gl::TRIANGLES,
+                name: Some("TRIANGLES")
+            },
+            MockArg::Int(0),
+            MockArg::Int(3),
+        ]
+    );
+}
+
+#[test]
+fn shader_calls() {
+    let context = MockContext::install();
+
+    let shader = create_shader(ShaderType::Vertex);
+    shader_source(shader, "void main() {}");
+    compile_shader(shader);
+    assert!(get_shader_compile_status(shader));
+    assert_eq!(get_shader_type(shader), Ok(ShaderType::Vertex));
+
+    let program = create_program();
+    attach_shader(program, shader);
+    link_program(program);
+    assert!(get_program_link_status(program));
+    assert_eq!(get_program_attached_shaders(program), 1);
+    use_program(program);
+    uniform_4f32(UniformLocation(3), 1.0, 0.5, 0.0, 1.0);
+    delete_program(program);
+    assert!(!is_program(program));
+    assert!(is_shader(shader));
+
+    let log = context.log();
+    assert_eq!(log[0], "CreateShader(VERTEX_SHADER)");
+    assert!(log[1].starts_with("ShaderSource(1, 1, [\"void main() {}\"], "));
+    assert_eq!(log[2], "CompileShader(1)");
+    assert!(log.contains(&"Uniform4f(3, 1.0, 0.5, 0.0, 1.0)".to_string()));
+}
+
+#[test]
+fn framebuffer_status() {
+    let _context = MockContext::install();
+
+    let mut framebuffer = Framebuffer::default();
+    gen_framebuffers(std::slice::from_mut(&mut framebuffer));
+    bind_framebuffer(FramebufferBindingTarget::ReadDraw, framebuffer);
+    assert_eq!(
+        check_framebuffer_status(FramebufferBindingTarget::Draw),
+        Some(FramebufferStatus::IncompleteMissingAttachment)
+    );
+
+    let mut renderbuffer = Renderbuffer::default();
+    gen_renderbuffers(std::slice::from_mut(&mut renderbuffer));
+    bind_renderbuffer(renderbuffer);
+    framebuffer_renderbuffer(
+        FramebufferBindingTarget::ReadDraw,
+        FramebufferAttachment::DepthStencil,
+        renderbuffer,
+    );
+    assert_eq!(
+        check_framebuffer_status(FramebufferBindingTarget::Read),
+        Some(FramebufferStatus::Complete)
+    );
+
+    bind_framebuffer(FramebufferBindingTarget::ReadDraw, Framebuffer(99));
+    assert_eq!(get_error(), Error::InvalidOperation);
+}
+
+#[test]
+fn multisample_calls() {
+    let context = MockContext::install();
+
+    tex_image_2d_multisample(
+        TextureMultisample2DTarget::Multisample2D,
+        4,
+        TextureInternalFormat::RGBA8,
+        64,
+        32,
+        true,
+    );
+    tex_storage_2d_multisample(
+        TextureMultisample2DTarget::ProxyMultisample2D,
+        2,
+        TextureInternalFormat::R8,
+        8,
+        8,
+        false,
+    );
+    renderbuffer_storage_multisample(4, RenderbufferInternalFormat::Depth24Stencil8, 64, 32);
+    blit_framebuffer(
+        0,
+        0,
+        64,
+        32,
+        0,
+        32,
+        64,
+        0,
+        ClearMask::COLOUR | ClearMask::DEPTH,
+        BlitFilter::Nearest,
+    );
+    assert_eq!(get_framebuffer_samples(FramebufferBindingTarget::Read), 0);
+    assert_eq!(
+        renderbuffer_storage_multisample_checked(
+            16,
+            RenderbufferInternalFormat::Depth24Stencil8,
+            64,
+            32
+        ),
+        Err(FramebufferError::InvalidSamples(16))
+    );
+
+    assert_eq!(
+        context.log(),
+        [
+            "TexImage2DMultisample(TEXTURE_2D_MULTISAMPLE, 4, RGBA8, 64, 32, TRUE)",
+            "TexStorage2DMultisample(PROXY_TEXTURE_2D_MULTISAMPLE, 2, R8, 8, 8, FALSE)",
+            "RenderbufferStorageMultisample(RENDERBUFFER, 4, DEPTH24_STENCIL8, 64, 32)",
+            "BlitFramebuffer(0, 0, 64, 32, 0, 32, 64, 0, COLOR_BUFFER_BIT | DEPTH_BUFFER_BIT, \
+             NEAREST)",
+            "GetFramebufferParameteriv(READ_FRAMEBUFFER, SAMPLES, _)",
+            "RenderbufferStorageMultisample(RENDERBUFFER, 16, DEPTH24_STENCIL8, 64, 32)",
+            "GetError()",
+            "GetIntegerv(MAX_SAMPLES, _)",
+        ]
+    );
+}
+
+#[test]
+fn renderbuffer_calls() {
+    let context = MockContext::install();
+
+    let mut renderbuffer = Renderbuffer::default();
+    create_renderbuffers(std::slice::from_mut(&mut renderbuffer));
+    assert_eq!(renderbuffer, Renderbuffer(1));
+    assert!(is_renderbuffer(renderbuffer));
+    named_renderbuffer_storage(renderbuffer, RenderbufferInternalFormat::RGBA16F, 64, 32);
+    named_renderbuffer_storage_multisample(
+        renderbuffer,
+        4,
+        RenderbufferInternalFormat::StencilIndex8,
+        64,
+        32,
+    );
+    assert_eq!(
+        named_renderbuffer_storage_checked(
+            Renderbuffer(42),
+            RenderbufferInternalFormat::R11fG11fB10f,
+            64,
+            32
+        ),
+        Err(FramebufferError::InvalidRenderbuffer(Renderbuffer(42)))
+    );
+    assert_eq!(
+        get_renderbuffer_width_checked(),
+        Err(FramebufferError::UnboundRenderbuffer)
+    );
+    bind_renderbuffer(renderbuffer);
+    assert_eq!(get_renderbuffer_samples_checked(), Ok(0));
+
+    assert_eq!(
+        context.log(),
+        [
+            "CreateRenderbuffers(1, _)",
+            "IsRenderbuffer(1)",
+            "NamedRenderbufferStorage(1, RGBA16F, 64, 32)",
+            "NamedRenderbufferStorageMultisample(1, 4, STENCIL_INDEX8, 64, 32)",
+            "NamedRenderbufferStorage(42, R11F_G11F_B10F, 64, 32)",
+            "GetError()",
+            "GetRenderbufferParameteriv(RENDERBUFFER, RENDERBUFFER_WIDTH, _)",
+            "GetError()",
+            "BindRenderbuffer(RENDERBUFFER, 1)",
+            "GetRenderbufferParameteriv(RENDERBUFFER, RENDERBUFFER_SAMPLES, _)",
+            "GetError()",
+        ]
+    );
+}
+
+#[test]
+fn framebuffer_attachment_calls() {
+    let context = MockContext::install();
+
+    let mut framebuffer = Framebuffer::default();
+    create_framebuffers(std::slice::from_mut(&mut framebuffer));
+    assert_eq!(framebuffer, Framebuffer(1));
+    assert!(is_framebuffer(framebuffer));
+    assert_eq!(
+        check_named_framebuffer_status(framebuffer, FramebufferBindingTarget::Draw),
+        Some(FramebufferStatus::IncompleteMissingAttachment)
+    );
+    named_framebuffer_texture_layer(
+        framebuffer,
+        FramebufferAttachment::Colour(1),
+        Texture(2),
+        0,
+        3,
+    );
+    named_framebuffer_renderbuffer(
+        framebuffer,
+        FramebufferAttachment::DepthStencil,
+        Renderbuffer(3),
+    );
+    assert_eq!(
+        check_named_framebuffer_status_checked(framebuffer, FramebufferBindingTarget::Read),
+        Ok(FramebufferStatus::Complete)
+    );
+    named_framebuffer_draw_buffers(
+        framebuffer,
+        &[
+            ColourBuffer::Attachment(0),
+            ColourBuffer::None,
+            ColourBuffer::Attachment(2),
+        ],
+    );
+    named_framebuffer_read_buffer(framebuffer, ColourBuffer::Attachment(1));
+    assert_eq!(
+        named_framebuffer_texture_checked(
+            Framebuffer(42),
+            FramebufferAttachment::Depth,
+            Texture(2),
+            0
+        ),
+        Err(FramebufferError::InvalidFramebuffer(Framebuffer(42)))
+    );
+
+    bind_framebuffer(FramebufferBindingTarget::Draw, framebuffer);
+    framebuffer_texture_1d(
+        FramebufferBindingTarget::Draw,
+        FramebufferAttachment::Colour(0),
+        TextureBinding1DTarget::Image1D,
+        Texture(4),
+        0,
+    );
+    framebuffer_texture_3d(
+        FramebufferBindingTarget::Draw,
+        FramebufferAttachment::Colour(2),
+        TextureBinding3DTarget::Image3D,
+        Texture(5),
+        1,
+        2,
+    );
+    draw_buffer(ColourBuffer::Attachment(2));
+    assert_eq!(
+        draw_buffers_checked(&[ColourBuffer::None; 9]),
+        Err(FramebufferError::InvalidDrawBuffers)
+    );
+    assert_eq!(
+        get_framebuffer_attachment_object_name_checked(
+            FramebufferBindingTarget::Draw,
+            FramebufferAttachment::Colour(2)
+        ),
+        Ok(0)
+    );
+
+    assert_eq!(
+        context.log(),
+        [
+            "CreateFramebuffers(1, _)",
+            "IsFramebuffer(1)",
+            "CheckNamedFramebufferStatus(1, DRAW_FRAMEBUFFER)",
+            "NamedFramebufferTextureLayer(1, COLOR_ATTACHMENT1, 2, 0, 3)",
+            "NamedFramebufferRenderbuffer(1, DEPTH_STENCIL_ATTACHMENT, RENDERBUFFER, 3)",
+            "CheckNamedFramebufferStatus(1, READ_FRAMEBUFFER)",
+            "GetError()",
+            "NamedFramebufferDrawBuffers(1, 3, [COLOR_ATTACHMENT0, NONE, COLOR_ATTACHMENT2])",
+            "NamedFramebufferReadBuffer(1, COLOR_ATTACHMENT1)",
+            "NamedFramebufferTexture(42, DEPTH_ATTACHMENT, 2, 0)",
+            "GetError()",
+            "IsFramebuffer(42)",
+            "BindFramebuffer(DRAW_FRAMEBUFFER, 1)",
+            "FramebufferTexture1D(DRAW_FRAMEBUFFER, COLOR_ATTACHMENT0, TEXTURE_1D, 4, 0)",
+            "FramebufferTexture3D(DRAW_FRAMEBUFFER, COLOR_ATTACHMENT2, TEXTURE_3D, 5, 1, 2)",
+            "DrawBuffer(COLOR_ATTACHMENT2)",
+            "DrawBuffers(9, [NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE])",
+            "GetError()",
+            "GetFramebufferAttachmentParameteriv(DRAW_FRAMEBUFFER, COLOR_ATTACHMENT2, \
+             FRAMEBUFFER_ATTACHMENT_OBJECT_NAME, _)",
+            "GetError()",
+        ]
+    );
+}
+
+#[test]
+fn framebuffer_builder_calls() {
+    let context = MockContext::install();
+
+    let builder = FramebufferBuilder::new()
+        .colour(
+            1,
+            AttachmentImage::texture(TextureInternalFormat::RGBA8, 64, 32),
+        )
+        .depth(AttachmentImage::renderbuffer(
+            RenderbufferInternalFormat::DepthComponent24,
+            32,
+            32,
+        ));
+    assert_eq!(
+        builder.build(),
+        Err(FramebufferReport {
+            status: None,
+            pending_error: None,
+            problems: vec![(
+                FramebufferAttachment::Depth,
+                AttachmentProblem::SizeMismatch {
+                    expected: (64, 32),
+                    found: (32, 32)
+                }
+            )]
+        })
+    );
+    // nothing is created for a framebuffer that cannot be completed
+    assert!(context.log().is_empty());
+
+    let built = builder
+        .depth(AttachmentImage::renderbuffer(
+            RenderbufferInternalFormat::DepthComponent24,
+            64,
+            32,
+        ))
+        .build()
+        .unwrap();
+    assert_eq!(
+        built,
+        BuiltFramebuffer {
+            framebuffer: Framebuffer(1),
+            attachments: vec![
+                (
+                    FramebufferAttachment::Colour(1),
+                    AttachedObject::Texture(Texture(2))
+                ),
+                (
+                    FramebufferAttachment::Depth,
+                    AttachedObject::Renderbuffer(Renderbuffer(3))
+                ),
+            ]
+        }
+    );
+    built.delete();
+
+    assert_eq!(
+        context.log(),
+        [
+            "GetError()",
+            "CreateFramebuffers(1, _)",
+            "GenTextures(1, [2])",
+            "GetIntegerv(TEXTURE_BINDING_2D, _)",
+            "BindTexture(TEXTURE_2D, 2)",
+            "TexStorage2D(TEXTURE_2D, 1, RGBA8, 64, 32)",
+            "BindTexture(TEXTURE_2D, 0)",
+            "NamedFramebufferTexture(1, COLOR_ATTACHMENT1, 2, 0)",
+            "GetError()",
+            "CreateRenderbuffers(1, _)",
+            "NamedRenderbufferStorage(3, DEPTH_COMPONENT24, 64, 32)",
+            "NamedFramebufferRenderbuffer(1, DEPTH_ATTACHMENT, RENDERBUFFER, 3)",
+            "GetError()",
+            "NamedFramebufferDrawBuffers(1, 2, [NONE, COLOR_ATTACHMENT1])",
+            "NamedFramebufferReadBuffer(1, COLOR_ATTACHMENT1)",
+            "CheckNamedFramebufferStatus(1, DRAW_FRAMEBUFFER)",
+            "DeleteFramebuffers(1, [1])",
+            "DeleteTextures(1, [2])",
+            "DeleteRenderbuffers(1, [3])",
+        ]
+    );
+}
+
+#[test]
+fn clear_and_mask_calls() {
+    let context = MockContext::install();
+
+    clear_depth(0.5);
+    clear_stencil(1);
+    clear_buffer_f32(ClearBufferF32::Colour(1, [0.0, 0.5, 1.0, 1.0]));
+    clear_buffer_f32(ClearBufferF32::Depth(1.0));
+    clear_buffer_i32(ClearBufferI32::Stencil(2));
+    clear_buffer_u32(ClearBufferU32::Colour(2, [1, 2, 3, 4]));
+    clear_buffer_depth_stencil(1.0, 0);
+    clear_named_framebuffer_i32(Framebuffer(0), ClearBufferI32::Colour(0, [-1, 0, 0, 1]));
+    colour_mask(true, false, true, false);
+    colour_mask_buffer(3, false, true, false, true);
+    assert_eq!(
+        clear_buffer_u32_checked(ClearBufferU32::Colour(8, [0; 4])),
+        Err(StateError::InvalidDrawBufferIndex(8))
+    );
+    assert_eq!(
+        clear_named_framebuffer_f32_checked(Framebuffer(5), ClearBufferF32::Depth(0.0)),
+        Err(StateError::InvalidFramebuffer(Framebuffer(5)))
+    );
+    assert_eq!(
+        colour_mask_buffer_checked(8, true, true, true, true),
+        Err(StateError::InvalidDrawBufferIndex(8))
+    );
+
+    assert_eq!(
+        context.log(),
+        [
+            "ClearDepth(0.5)",
+            "ClearStencil(1)",
+            "ClearBufferfv(COLOR, 1, [0.0, 0.5, 1.0, 1.0])",
+            "ClearBufferfv(DEPTH, 0, [1.0])",
+            "ClearBufferiv(STENCIL, 0, [2])",
+            "ClearBufferuiv(COLOR, 2, [1, 2, 3, 4])",
+            "ClearBufferfi(DEPTH_STENCIL, 0, 1.0, 0)",
+            "ClearNamedFramebufferiv(0, COLOR, 0, [-1, 0, 0, 1])",
+            "ColorMask(TRUE, FALSE, TRUE, FALSE)",
+            "ColorMaski(3, FALSE, TRUE, FALSE, TRUE)",
+            "ClearBufferuiv(COLOR, 8, [0, 0, 0, 0])",
+            "GetError()",
+            "ClearNamedFramebufferfv(5, DEPTH, 0, [0.0])",
+            "GetError()",
+            "ColorMaski(8, TRUE, TRUE, TRUE, TRUE)",
+            "GetError()",
+        ]
+    );
+}
+
+#[test]
+fn blend_calls() {
+    let context = MockContext::install();
+
+    blend_colour(0.0, 0.0, 0.0, 0.5);
+    blend_equation(BlendEquation::Min);
+    blend_equation_buffer(1, BlendEquation::ReverseSubtract);
+    blend_equation_separate(BlendEquation::Add, BlendEquation::Max);
+    blend_equation_separate_buffer(2, BlendEquation::Subtract, BlendEquation::Add);
+    blend_func_separate(
+        BlendFactor::One,
+        BlendFactor::OneMinusSourceAlpha,
+        BlendFactor::Zero,
+        BlendFactor::One,
+    );
+    blend_func_separate_buffer(
+        3,
+        BlendFactor::SourceAlpha,
+        BlendFactor::One,
+        BlendFactor::ConstantAlpha,
+        BlendFactor::Zero,
+    );
+    get_blend_colour();
+    get_blend_dest_alpha_buffer(1);
+    assert_eq!(
+        blend_equation_buffer_checked(8, BlendEquation::Add),
+        Err(StateError::InvalidDrawBufferIndex(8))
+    );
+
+    assert_eq!(
+        context.log(),
+        [
+            "BlendColor(0.0, 0.0, 0.0, 0.5)",
+            "BlendEquation(MIN)",
+            "BlendEquationi(1, FUNC_REVERSE_SUBTRACT)",
+            "BlendEquationSeparate(FUNC_ADD, MAX)",
+            "BlendEquationSeparatei(2, FUNC_SUBTRACT, FUNC_ADD)",
+            "BlendFuncSeparate(ONE, ONE_MINUS_SRC_ALPHA, ZERO, ONE)",
+            "BlendFuncSeparatei(3, SRC_ALPHA, ONE, CONSTANT_ALPHA, ZERO)",
+            "GetFloatv(BLEND_COLOR, _)",
+            "GetIntegeri_v(BLEND_DST_ALPHA, 1, _)",
+            "BlendEquationi(8, FUNC_ADD)",
+            "GetError()",
+        ]
+    );
+}
+
+#[test]
+fn rasterizer_calls() {
+    let context = MockContext::install();
+
+    cull_face(CullFaceMode::FrontAndBack);
+    front_face(FrontFace::Clockwise);
+    polygon_mode(PolygonMode::Line);
+    polygon_offset(1.0, 2.0);
+    line_width(1.5);
+    point_size(4.0);
+    point_fade_threshold_size(0.5);
+    point_sprite_coord_origin(PointSpriteCoordOrigin::LowerLeft);
+    provoking_vertex(ProvokingVertex::FirstVertexConvention);
+    get_polygon_mode();
+    assert_eq!(
+        line_width_checked(0.0),
+        Err(RasterizationError::InvalidLineWidth(0.0))
+    );
+
+    assert_eq!(
+        context.log(),
+        [
+            "CullFace(FRONT_AND_BACK)",
+            "FrontFace(CW)",
+            "PolygonMode(FRONT_AND_BACK, LINE)",
+            "PolygonOffset(1.0, 2.0)",
+            "LineWidth(1.5)",
+            "PointSize(4.0)",
+            "PointParameterf(POINT_FADE_THRESHOLD_SIZE, 0.5)",
+            "PointParameteri(POINT_SPRITE_COORD_ORIGIN, LOWER_LEFT)",
+            "ProvokingVertex(FIRST_VERTEX_CONVENTION)",
+            "GetIntegerv(POLYGON_MODE, _)",
+            "LineWidth(0.0)",
+            "GetError()",
+        ]
+    );
+}
+
+#[test]
+fn sample_coverage_calls() {
+    let context = MockContext::install();
+
+    sample_coverage(0.5, true);
+    sample_mask(0, 0xff);
+    min_sample_shading(0.25);
+    get_sample_position(1);
+    get_sample_coverage_invert();
+    assert_eq!(get_max_sample_mask_words(), 1);
+    assert_eq!(
+        sample_mask_checked(1, 0),
+        Err(RasterizationError::InvalidSampleMaskWord(1))
+    );
+
+    assert_eq!(
+        context.log(),
+        [
+            "SampleCoverage(0.5, TRUE)",
+            "SampleMaski(0, 255)",
+            "MinSampleShading(0.25)",
+            "GetMultisamplefv(SAMPLE_POSITION, 1, _)",
+            "GetBooleanv(SAMPLE_COVERAGE_INVERT, _)",
+            "GetIntegerv(MAX_SAMPLE_MASK_WORDS, _)",
+            "SampleMaski(1, 0)",
+            "GetError()",
+        ]
+    );
+}
+
+#[test]
+fn get_and_enabled_calls() {
+    let context = MockContext::install();
+
+    is_enabled(Capability::Blend);
+    enable_indexed(Capability::Blend, 2);
+    assert_eq!(
+        is_enabled_indexed_checked(Capability::ScissorTest, 1),
+        Ok(false)
+    );
+    assert_eq!(
+        disable_indexed_checked(Capability::DepthTest, 0),
+        Err(StateError::UnsupportedCapability(Capability::DepthTest))
+    );
+    assert_eq!(
+        enable_indexed_checked(Capability::Blend, 8),
+        Err(StateError::InvalidCapabilityIndex(Capability::Blend, 8))
+    );
+    get_array_buffer_binding();
+    get_stencil_back_pass_depth_fail();
+    get_depth_writemask();
+    get_buffer_size_indexed(BufferBindingRangeTarget::Uniform, 3);
+    get_max_element_index();
+
+    assert_eq!(
+        context.log(),
+        [
+            "IsEnabled(BLEND)",
+            "Enablei(BLEND, 2)",
+            "IsEnabledi(SCISSOR_TEST, 1)",
+            "GetError()",
+            "Disablei(DEPTH_TEST, 0)",
+            "GetError()",
+            "Enablei(BLEND, 8)",
+            "GetError()",
+            "GetIntegerv(ARRAY_BUFFER_BINDING, _)",
+            "GetIntegerv(STENCIL_BACK_PASS_DEPTH_FAIL, _)",
+            "GetBooleanv(DEPTH_WRITEMASK, _)",
+            "GetInteger64i_v(UNIFORM_BUFFER_SIZE, 3, _)",
+            "GetInteger64v(MAX_ELEMENT_INDEX, _)",
+        ]
+    );
+}
+
+#[test]
+fn checked_errors() {
+    let context = MockContext::install();
+
+    assert_eq!(
+        bind_texture_checked(TextureBindingTarget::Image2D, Texture(42)),
+        Err(TextureError::InvalidTexture(Texture(42)))
+    );
+
+    context.set_error(Error::InvalidValue);
+    assert_eq!(
+        tex_image_2d_checked::<u8>(
+            TextureBinding2DTarget::Image2D,
+            0,
+            TextureInternalFormat::RGBA8,
+            1 << 15,
+            1,
+            TextureFormat::RGBA,
+            TexturePixelType::U8,
+            TextureData::Reserve,
+        ),
+        Err(TextureError::InvalidDimensions {
+            width: 1 << 15,
+            height: 1
+        })
+    );
+
+    let shader = create_shader(ShaderType::Fragment);
+    let program = create_program();
+    assert_eq!(attach_shader_checked(program, shader), Ok(()));
+    assert_eq!(
+        attach_shader_checked(program, shader),
+        Err(ShaderError::ShaderAlreadyAttached { program, shader })
+    );
+
+    assert_eq!(
+        framebuffer_renderbuffer_checked(
+            FramebufferBindingTarget::Draw,
+            FramebufferAttachment::Depth,
+            Renderbuffer::default(),
+        ),
+        Err(FramebufferError::UnboundTarget(
+            FramebufferBindingTarget::Draw
+        ))
+    );
+
+    context.set_error(Error::InvalidOperation);
+    assert_eq!(
+        vertex_attrib_float_pointer_checked(
+            0,
+            VertexAttribSize::Triple,
+            VertexAttribFloatType::F32,
+            false,
+            0,
+            0,
+        ),
+        Err(VertexArrayError::UnboundArrayBuffer)
+    );
+
+    context.set_error(Error::InvalidOperation);
+    assert_eq!(
+        draw_arrays_checked(DrawMode::Triangles, 0, 3),
+        Err(DrawError::NoVertexArrayBound)
+    );
+
+    context.set_error(Error::InvalidValue);
+    assert_eq!(
+        blend_func_buffer_checked(8, BlendFactor::One, BlendFactor::Zero),
+        Err(StateError::InvalidDrawBufferIndex(8))
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn unified_errors() {
+    let _context = MockContext::install();
+
+    fn setup(texture: Texture) -> Result<(), RglError> {
+        active_texture_checked(0)?;
+        bind_texture_checked(TextureBindingTarget::Image2D, texture)?;
+        Ok(())
+    }
+
+    let error = setup(Texture(7)).unwrap_err();
+    assert_eq!(
+        error,
+        RglError::Texture(TextureError::InvalidTexture(Texture(7)))
+    );
+    assert_eq!(
+        error.to_string(),
+        TextureError::InvalidTexture(Texture(7)).to_string()
+    );
+    assert!(std::error::Error::source(&error).is_some());
+    assert_eq!(
+        RglError::from(Error::InvalidEnum).to_string(),
+        "OpenGL error: GL_INVALID_ENUM"
+    );
+}
+
+#[test]
+fn debug_calls() {
+    let context = MockContext::install();
+
+    // a replaced closure is only dropped while the GL calls it synchronously, and leaked otherwise
+    let dropped = std::sync::Arc::new(());
+    let captured = dropped.clone();
+    debug_message_callback(move |_| {
+        let _ = &captured;
+    });
+    let captured = dropped.clone();
+    debug_message_callback(move |_| {
+        let _ = &captured;
+    });
+    assert_eq!(std::sync::Arc::strong_count(&dropped), 3);
+    enable(Capability::DebugOutputSynchronous);
+    clear_debug_message_callback();
+    assert_eq!(std::sync::Arc::strong_count(&dropped), 2);
+    context.clear_log();
+
+    debug_message_control(None, Some(DebugType::Performance), None, &[], false);
+    debug_message_insert(
+        DebugSource::Application,
+        DebugType::Marker,
+        7,
+        DebugSeverity::Notification,
+        "frame",
+    );
+    push_debug_group(DebugSource::ThirdParty, 1, "pass");
+    pop_debug_group();
+    assert_eq!(
+        context.log(),
+        [
+            "DebugMessageControl(DONT_CARE, DEBUG_TYPE_PERFORMANCE, DONT_CARE, 0, [], FALSE)",
+            "DebugMessageInsert(DEBUG_SOURCE_APPLICATION, DEBUG_TYPE_MARKER, 7, \
+             DEBUG_SEVERITY_NOTIFICATION, 5, \"frame\")",
+            "PushDebugGroup(DEBUG_SOURCE_THIRD_PARTY, 1, 4, \"pass\")",
+            "PopDebugGroup()",
+        ]
+    );
+
+    let mut buffer = Buffer::default();
+    gen_buffers(std::slice::from_mut(&mut buffer));
+    assert_eq!(
+        object_label_checked(buffer.into(), "vertices"),
+        Err(DebugError::InvalidObject(DebugObject::Buffer(buffer)))
+    );
+    bind_buffer(BufferBindingTarget::Array, buffer);
+    object_label(buffer.into(), "vertices");
+    assert_eq!(get_object_label(buffer.into()), "vertices");
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn owned_objects() {
+    let context = MockContext::install();
+
+    {
+        let vao = OwnedVertexArray::generate();
+        let vbos = OwnedBuffer::generate_many(2);
+        bind_vertex_array(*vao);
+        bind_buffer(BufferBindingTarget::Array, *vbos[1]);
+        assert!(is_vertex_array(*vao));
+    }
+    assert_eq!(
+        context.log(),
+        [
+            "GenVertexArrays(1, [1])",
+            "GenBuffers(2, [2, 3])",
+            "BindVertexArray(1)",
+            "BindBuffer(ARRAY_BUFFER, 3)",
+            "IsVertexArray(1)",
+            "DeleteBuffers(1, [2])",
+            "DeleteBuffers(1, [3])",
+            "DeleteVertexArrays(1, [1])",
+        ]
+    );
+    assert!(!is_vertex_array(VertexArray(1)));
+    context.clear_log();
+
+    let shader = OwnedShader::create(ShaderType::Vertex);
+    let program = OwnedProgram::create();
+    attach_shader(*program, *shader);
+    let texture = OwnedTexture::generate().into_raw();
+    drop(program);
+    drop(shader);
+    drop(OwnedTexture::from_raw(texture));
+    assert_eq!(
+        context.log(),
+        [
+            "CreateShader(VERTEX_SHADER)",
+            "CreateProgram()",
+            "AttachShader(5, 4)",
+            "GenTextures(1, [6])",
+            "DeleteProgram(5)",
+            "DeleteShader(4)",
+            "DeleteTextures(1, [6])",
+        ]
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn program_queries() {
+    let context = MockContext::install();
+
+    let program = create_program();
+    bind_attrib_location(program, 2, c"normal");
+    assert_eq!(get_attrib_location(program, c"normal"), None);
+    assert_eq!(
+        get_program_resource_index(program, ProgramInterface::UniformBlock, c"Material"),
+        None
+    );
+    let mut params = [0; 2];
+    let properties = [
+        ProgramResourceProperty::Type,
+        ProgramResourceProperty::Location,
+    ];
+    assert_eq!(
+        get_program_resource_checked(
+            program,
+            ProgramInterface::Uniform,
+            0,
+            &properties,
+            &mut params
+        ),
+        Err(ShaderError::InvalidIndex(0))
+    );
+    assert_eq!(
+        ProgramReflection::new_checked(program),
+        Ok(ProgramReflection::default())
+    );
+
+    let log = context.log();
+    assert_eq!(log[1], "BindAttribLocation(1, 2, \"normal\")");
+    assert_eq!(log[2], "GetAttribLocation(1, \"normal\")");
+    assert_eq!(
+        log[3],
+        "GetProgramResourceIndex(1, UNIFORM_BLOCK, \"Material\")"
+    );
+    assert!(log[4].starts_with("GetProgramResourceiv(1, UNIFORM, 0, 2, [TYPE, LOCATION], 2, "));
+    assert!(log.contains(
+        &"GetProgramInterfaceiv(1, SHADER_STORAGE_BLOCK, ACTIVE_RESOURCES, _)".to_string()
+    ));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn uniform_values() {
+    let context = MockContext::install();
+
+    set_uniform(UniformLocation(1), &2.0f32);
+    set_uniform(UniformLocation(2), &[1u32, 2, 3]);
+    set_uniform(UniformLocation(3), &[[true, false]][..]);
+    set_uniform(UniformLocation(4), &vec![1.5f64, 2.5]);
+    set_uniform(UniformLocation(5), &[[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(
+        context.log(),
+        [
+            "Uniform1fv(1, 1, [2.0])",
+            "Uniform3uiv(2, 1, [1, 2, 3])",
+            "Uniform2iv(3, 1, [1, 0])",
+            "Uniform1dv(4, 2, [1.5, 2.5])",
+            "UniformMatrix2x3fv(5, 1, FALSE, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0])",
+        ]
+    );
+
+    assert_eq!(
+        set_uniform_checked(UniformLocation(1), &1),
+        Err(UniformError::NoProgram)
+    );
+    let program = create_program();
+    use_program(program);
+    assert_eq!(get_current_program(), program);
+    assert_eq!(
+        set_uniform_checked(UniformLocation(1), &1),
+        Err(UniformError::InvalidLocation(UniformLocation(1)))
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn uniform_blocks() {
+    let context = MockContext::install();
+
+    let program = create_program();
+    assert_eq!(get_uniform_block_index(program, c"Material"), None);
+    uniform_block_binding(program, 0, 2);
+    shader_storage_block_binding(program, 0, 1);
+    assert_eq!(get_error(), Error::InvalidValue);
+    assert_eq!(
+        uniform_block_binding_checked(program, 0, 72),
+        Err(ShaderError::InvalidBinding(72))
+    );
+    assert_eq!(
+        shader_storage_block_binding_checked(program, 3, 1),
+        Err(ShaderError::InvalidIndex(3))
+    );
+    assert_eq!(
+        get_active_uniform_block_data_size_checked(program, 0),
+        Err(ShaderError::InvalidIndex(0))
+    );
+    assert_eq!(
+        uniform_block_binding_checked(Program(9), 0, 0),
+        Err(ShaderError::InvalidProgram(Program(9)))
+    );
+
+    let log = context.log();
+    assert_eq!(log[1], "GetUniformBlockIndex(1, \"Material\")");
+    assert_eq!(log[2], "UniformBlockBinding(1, 0, 2)");
+    assert_eq!(log[3], "ShaderStorageBlockBinding(1, 0, 1)");
+    assert!(log.contains(&"GetIntegerv(MAX_UNIFORM_BUFFER_BINDINGS, _)".to_string()));
+    assert!(log.contains(&"GetActiveUniformBlockiv(1, 0, UNIFORM_BLOCK_DATA_SIZE, _)".to_string()));
+    assert_eq!(get_error(), Error::NoError);
+}
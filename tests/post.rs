@@ -0,0 +1,218 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+fn draw_quad(program: Program, colour: [f32; 4]) {
+    let [r, g, b, a] = colour;
+    uniform_4f32(get_uniform_location(program, c"colour"), r, g, b, a);
+    draw_arrays(DrawMode::Triangles, 0, 6);
+}
+
+#[test]
+fn blend_factors() {
+    let context = HeadlessContext::new(1, 1).unwrap();
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+    use_program(program);
+
+    enable(Capability::Blend);
+    clear_colour(0.0, 0.0, 1.0, 1.0);
+    clear(ClearMask::COLOUR);
+    blend_func(BlendFactor::One, BlendFactor::One);
+    draw_quad(program, [1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(context.read_colour(), [[255, 0, 255, 255]]);
+
+    clear(ClearMask::COLOUR);
+    blend_func(BlendFactor::SourceAlpha, BlendFactor::OneMinusSourceAlpha);
+    draw_quad(program, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(context.read_colour(), [[255, 0, 0, 255]]);
+
+    clear(ClearMask::COLOUR);
+    blend_func_buffer(0, BlendFactor::Zero, BlendFactor::DestColour);
+    draw_quad(program, [1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(context.read_colour(), [[0, 0, 255, 255]]);
+
+    disable(Capability::Blend);
+    draw_quad(program, [0.0, 1.0, 0.0, 1.0]);
+    assert_eq!(context.read_colour(), [[0, 255, 0, 255]]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn stencil_func_and_op() {
+    let context = HeadlessContext::new(2, 1).unwrap();
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    let (_vao, vbo) = common::vertex_array(&common::LEFT_HALF_QUAD);
+    use_program(program);
+
+    clear(ClearMask::COLOUR | ClearMask::STENCIL);
+    enable(Capability::StencilTest);
+
+    // write a reference of 3 into the left half, without touching the colour buffer
+    stencil_func(CompareFunc::Never, 3, 0xFF);
+    stencil_op(StencilOp::Replace, StencilOp::Keep, StencilOp::Keep);
+    draw_quad(program, [1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(context.read_colour(), [[0, 0, 0, 0]; 2]);
+
+    // increment the left half to 4, only the right half passes
+    bind_buffer(BufferBindingTarget::Array, vbo);
+    buffer_data(
+        BufferBindingTarget::Array,
+        &common::FULLSCREEN_QUAD,
+        BufferUsageFrequency::Static,
+        BufferUsageNature::Draw,
+    );
+    stencil_func_separate(StencilFace::FrontAndBack, CompareFunc::NotEqual, 3, 0xFF);
+    stencil_op_separate(
+        StencilFace::FrontAndBack,
+        StencilOp::IncrementClamp,
+        StencilOp::Keep,
+        StencilOp::Keep,
+    );
+    draw_quad(program, [0.0, 1.0, 0.0, 1.0]);
+    assert_eq!(context.read_colour(), [[0, 0, 0, 0], [0, 255, 0, 255]]);
+
+    stencil_op(StencilOp::Keep, StencilOp::Keep, StencilOp::Keep);
+    stencil_func(CompareFunc::Equal, 4, 0xFF);
+    draw_quad(program, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(context.read_colour(), [[255, 0, 0, 255], [0, 255, 0, 255]]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn blend_equations_and_colour() {
+    let context = HeadlessContext::new(1, 1).unwrap();
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+    use_program(program);
+    enable(Capability::Blend);
+
+    clear_colour(1.0, 1.0, 1.0, 1.0);
+    clear(ClearMask::COLOUR);
+    blend_func(BlendFactor::One, BlendFactor::One);
+    blend_equation_checked(BlendEquation::ReverseSubtract).unwrap();
+    draw_quad(program, [1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(context.read_colour(), [[0, 255, 255, 255]]);
+
+    // min and max ignore the blend factors
+    blend_func(BlendFactor::Zero, BlendFactor::Zero);
+    blend_equation(BlendEquation::Min);
+    draw_quad(program, [1.0, 0.0, 1.0, 1.0]);
+    assert_eq!(context.read_colour(), [[0, 0, 255, 255]]);
+    blend_equation(BlendEquation::Max);
+    draw_quad(program, [1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(context.read_colour(), [[255, 0, 255, 255]]);
+
+    // replace the colour, while keeping the greatest alpha
+    clear_colour(0.0, 0.0, 0.0, 1.0);
+    clear(ClearMask::COLOUR);
+    blend_equation_separate_checked(BlendEquation::Add, BlendEquation::Max).unwrap();
+    blend_func_separate_checked(
+        BlendFactor::One,
+        BlendFactor::Zero,
+        BlendFactor::Zero,
+        BlendFactor::Zero,
+    )
+    .unwrap();
+    draw_quad(program, [0.0, 1.0, 0.0, 0.0]);
+    assert_eq!(context.read_colour(), [[0, 255, 0, 255]]);
+    assert_eq!(
+        get_blend_equation_alpha_checked(),
+        Ok(Some(BlendEquation::Max))
+    );
+    assert_eq!(get_blend_dest_rgb_checked(), Ok(Some(BlendFactor::Zero)));
+
+    blend_equation(BlendEquation::Add);
+    blend_colour_checked(1.0, 0.0, 1.0, 1.0).unwrap();
+    blend_func(BlendFactor::ConstantColour, BlendFactor::Zero);
+    draw_quad(program, [1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(context.read_colour(), [[255, 0, 255, 255]]);
+    assert_eq!(get_blend_colour_checked(), Ok([1.0, 0.0, 1.0, 1.0]));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn blend_state_per_draw_buffer() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    assert_eq!(get_blend_source_rgb(), Some(BlendFactor::One));
+    assert_eq!(get_blend_source_alpha(), Some(BlendFactor::One));
+    assert_eq!(get_blend_dest_rgb(), Some(BlendFactor::Zero));
+    assert_eq!(get_blend_dest_alpha(), Some(BlendFactor::Zero));
+    assert_eq!(get_blend_equation_rgb(), Some(BlendEquation::Add));
+    assert_eq!(get_blend_equation_alpha(), Some(BlendEquation::Add));
+    assert_eq!(get_blend_colour(), [0.0; 4]);
+
+    // a premultiplied alpha pass on draw buffer 0, next to an additive pass on draw buffer 1
+    blend_func_separate(
+        BlendFactor::One,
+        BlendFactor::OneMinusSourceAlpha,
+        BlendFactor::One,
+        BlendFactor::OneMinusSourceAlpha,
+    );
+    blend_func_separate_buffer_checked(
+        1,
+        BlendFactor::SourceAlpha,
+        BlendFactor::One,
+        BlendFactor::Zero,
+        BlendFactor::One,
+    )
+    .unwrap();
+    blend_equation_separate_buffer_checked(1, BlendEquation::Add, BlendEquation::Max).unwrap();
+    assert_eq!(get_blend_dest_rgb(), Some(BlendFactor::OneMinusSourceAlpha));
+    assert_eq!(
+        get_blend_source_rgb_buffer_checked(1),
+        Ok(Some(BlendFactor::SourceAlpha))
+    );
+    assert_eq!(get_blend_source_alpha_buffer(1), Some(BlendFactor::Zero));
+    assert_eq!(get_blend_dest_rgb_buffer(1), Some(BlendFactor::One));
+    assert_eq!(get_blend_dest_alpha_buffer(1), Some(BlendFactor::One));
+    assert_eq!(get_blend_equation_rgb_buffer(1), Some(BlendEquation::Add));
+    assert_eq!(
+        get_blend_equation_alpha_buffer_checked(1),
+        Ok(Some(BlendEquation::Max))
+    );
+    assert_eq!(
+        get_blend_dest_alpha_buffer_checked(0),
+        Ok(Some(BlendFactor::OneMinusSourceAlpha))
+    );
+    assert_eq!(get_blend_equation_alpha(), Some(BlendEquation::Add));
+
+    // the non-indexed functions set every draw buffer again
+    blend_equation_buffer_checked(2, BlendEquation::Min).unwrap();
+    assert_eq!(get_blend_equation_alpha_buffer(2), Some(BlendEquation::Min));
+    blend_equation(BlendEquation::Subtract);
+    blend_func(BlendFactor::One, BlendFactor::One);
+    for buffer in 0..3 {
+        assert_eq!(
+            get_blend_equation_rgb_buffer(buffer),
+            Some(BlendEquation::Subtract)
+        );
+        assert_eq!(
+            get_blend_source_alpha_buffer(buffer),
+            Some(BlendFactor::One)
+        );
+    }
+
+    let buffers = get_max_draw_buffers();
+    assert_eq!(
+        blend_equation_buffer_checked(buffers, BlendEquation::Add),
+        Err(StateError::InvalidDrawBufferIndex(buffers))
+    );
+    assert_eq!(
+        get_blend_dest_rgb_buffer_checked(buffers),
+        Err(StateError::InvalidDrawBufferIndex(buffers))
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
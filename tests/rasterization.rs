@@ -0,0 +1,373 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+#[test]
+fn viewport_state() {
+    let _context = HeadlessContext::new(32, 16).unwrap();
+    assert_eq!(
+        get_viewport(),
+        Rect {
+            x: 0,
+            y: 0,
+            width: 32,
+            height: 16
+        }
+    );
+
+    let rect = Rect {
+        x: 1,
+        y: 2,
+        width: 3,
+        height: 4,
+    };
+    viewport_checked(rect).unwrap();
+    assert_eq!(get_viewport(), rect);
+
+    let invalid = Rect {
+        width: u32::MAX,
+        ..rect
+    };
+    assert_eq!(
+        viewport_checked(invalid),
+        Err(RasterizationError::InvalidRect(invalid))
+    );
+    assert_eq!(get_viewport(), rect);
+
+    let max_viewports = get_max_viewports();
+    assert!(max_viewports >= 16);
+    let [max_width, max_height] = get_max_viewport_dims();
+    assert!(max_width >= 32 && max_height >= 16);
+
+    let rect = RectF32 {
+        x: 0.5,
+        y: 1.5,
+        width: 2.0,
+        height: 3.0,
+    };
+    viewport_indexed_checked(1, rect).unwrap();
+    assert_eq!(get_viewport_indexed(1), rect);
+    assert_eq!(
+        get_viewport_indexed_checked(max_viewports),
+        Err(RasterizationError::InvalidViewportIndex(max_viewports))
+    );
+    assert_eq!(
+        viewport_array_checked(max_viewports - 1, &[rect, rect]),
+        Err(RasterizationError::InvalidViewportRange {
+            first: max_viewports - 1,
+            count: 2
+        })
+    );
+}
+
+#[test]
+fn scissor_test_clips_clear() {
+    let context = HeadlessContext::new(4, 4).unwrap();
+    let rect = Rect {
+        x: 1,
+        y: 1,
+        width: 2,
+        height: 2,
+    };
+    scissor_checked(rect).unwrap();
+    assert_eq!(get_scissor_box(), rect);
+    assert_eq!(get_scissor_box_indexed(0), rect);
+
+    scissor_array_checked(2, &[rect; 2]).unwrap();
+    assert_eq!(get_scissor_box_indexed(3), rect);
+
+    enable(Capability::ScissorTest);
+    clear_colour(1.0, 1.0, 1.0, 1.0);
+    clear(ClearMask::COLOUR);
+    disable(Capability::ScissorTest);
+
+    let lit: Vec<usize> = context
+        .read_colour()
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| **p == [255, 255, 255, 255])
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(lit, [5, 6, 9, 10]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn depth_range_state() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    assert_eq!(get_depth_range(), DepthRange::default());
+
+    depth_range_checked(0.25, 0.75).unwrap();
+    assert_eq!(
+        get_depth_range(),
+        DepthRange {
+            near: 0.25,
+            far: 0.75
+        }
+    );
+
+    depth_range_indexed_checked(2, 1.0, 0.0).unwrap();
+    assert_eq!(
+        get_depth_range_indexed(2),
+        DepthRange {
+            near: 1.0,
+            far: 0.0
+        }
+    );
+
+    let ranges = [DepthRange {
+        near: 0.5,
+        far: 1.0,
+    }; 2];
+    depth_range_array_checked(4, &ranges).unwrap();
+    assert_eq!(get_depth_range_indexed_checked(5), Ok(ranges[1]));
+}
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const CLEARED: [u8; 4] = [0, 0, 0, 0];
+
+fn draw_red(program: Program, mode: DrawMode, count: u64, depth: f32) {
+    uniform_1f32(get_uniform_location(program, c"depth"), depth);
+    uniform_4f32(get_uniform_location(program, c"colour"), 1.0, 0.0, 0.0, 1.0);
+    draw_arrays(mode, 0, count);
+}
+
+#[test]
+fn face_culling() {
+    let context = HeadlessContext::new(1, 1).unwrap();
+    let program = common::link(
+        common::DEPTH_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+    use_program(program);
+    assert_eq!(get_cull_face_mode(), Some(CullFaceMode::Back));
+    assert_eq!(get_front_face(), Some(FrontFace::CounterClockwise));
+
+    // the quad is wound counterclockwise
+    enable(Capability::CullFace);
+    let drawn = |cull: CullFaceMode, front: FrontFace| {
+        cull_face_checked(cull).unwrap();
+        front_face_checked(front).unwrap();
+        clear(ClearMask::COLOUR);
+        draw_red(program, DrawMode::Triangles, 6, 0.0);
+        context.read_colour() == [RED]
+    };
+    assert!(drawn(CullFaceMode::Back, FrontFace::CounterClockwise));
+    assert!(!drawn(CullFaceMode::Back, FrontFace::Clockwise));
+    assert!(!drawn(CullFaceMode::Front, FrontFace::CounterClockwise));
+    assert!(drawn(CullFaceMode::Front, FrontFace::Clockwise));
+    assert!(!drawn(CullFaceMode::FrontAndBack, FrontFace::Clockwise));
+    assert_eq!(get_cull_face_mode(), Some(CullFaceMode::FrontAndBack));
+    assert_eq!(get_front_face(), Some(FrontFace::Clockwise));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn polygon_mode_and_offset() {
+    let context = HeadlessContext::new(8, 8).unwrap();
+    let program = common::link(
+        common::DEPTH_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    use_program(program);
+    assert_eq!(get_polygon_mode(), Some(PolygonMode::Fill));
+
+    // a quad with its edges through the centres of the pixels 1 and 6
+    let quad = common::FULLSCREEN_QUAD.map(|[x, y]| [x * 0.625, y * 0.625]);
+    common::vertex_array(&quad);
+    let pixel = |x: usize, y: usize| context.read_colour()[y * 8 + x];
+    polygon_mode_checked(PolygonMode::Line).unwrap();
+    assert_eq!(get_polygon_mode(), Some(PolygonMode::Line));
+    draw_red(program, DrawMode::Triangles, 6, 0.0);
+    assert_eq!(pixel(3, 1), RED);
+    assert_eq!(pixel(1, 4), RED);
+    assert_eq!(pixel(4, 2), CLEARED);
+
+    polygon_mode(PolygonMode::Fill);
+    draw_red(program, DrawMode::Triangles, 6, 0.0);
+    assert_eq!(pixel(4, 2), RED);
+
+    // an equal depth only passes a less than depth test with an offset towards the viewer
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+    enable(Capability::DepthTest);
+    depth_func(CompareFunc::Less);
+    assert_eq!(get_polygon_offset_factor(), 0.0);
+    assert_eq!(get_polygon_offset_units(), 0.0);
+    polygon_offset_checked(-1.0, -4.0).unwrap();
+    assert_eq!(get_polygon_offset_factor(), -1.0);
+    assert_eq!(get_polygon_offset_units(), -4.0);
+    clear(ClearMask::COLOUR | ClearMask::DEPTH);
+    draw_red(program, DrawMode::Triangles, 6, 0.5);
+    clear(ClearMask::COLOUR);
+    draw_red(program, DrawMode::Triangles, 6, 0.5);
+    assert_eq!(pixel(4, 4), CLEARED);
+    enable(Capability::PolygonOffsetFill);
+    draw_red(program, DrawMode::Triangles, 6, 0.5);
+    assert_eq!(pixel(4, 4), RED);
+    disable(Capability::PolygonOffsetFill);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn point_and_line_size() {
+    let context = HeadlessContext::new(4, 4).unwrap();
+    assert_eq!(get_point_size(), 1.0);
+    assert_eq!(get_line_width(), 1.0);
+    assert_eq!(get_point_fade_threshold_size(), 1.0);
+    assert_eq!(
+        get_point_sprite_coord_origin(),
+        Some(PointSpriteCoordOrigin::UpperLeft)
+    );
+    assert_eq!(
+        get_provoking_vertex(),
+        Some(ProvokingVertex::LastVertexConvention)
+    );
+    let [min_width, max_width] = get_aliased_line_width_range();
+    assert!(min_width <= 1.0 && max_width >= 1.0);
+    let [min_width, max_width] = get_smooth_line_width_range();
+    assert!(min_width <= 1.0 && max_width >= 1.0);
+    let [_, max_size] = get_point_size_range();
+    assert!(max_size >= 3.0);
+
+    // a point of 3 pixels, centred on the shared corner of the 4 middle pixels, covers 9 pixels
+    let program = common::link(
+        common::DEPTH_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    use_program(program);
+    common::vertex_array(&[[0.0, 0.0]]);
+    point_size_checked(3.0).unwrap();
+    assert_eq!(get_point_size(), 3.0);
+    draw_red(program, DrawMode::Points, 1, 0.0);
+    let lit = context.read_colour().iter().filter(|p| **p == RED).count();
+    assert_eq!(lit, 9);
+
+    line_width_checked(1.0).unwrap();
+    point_fade_threshold_size_checked(2.0).unwrap();
+    point_sprite_coord_origin_checked(PointSpriteCoordOrigin::LowerLeft).unwrap();
+    provoking_vertex_checked(ProvokingVertex::FirstVertexConvention).unwrap();
+    assert_eq!(get_point_fade_threshold_size(), 2.0);
+    assert_eq!(
+        get_point_sprite_coord_origin(),
+        Some(PointSpriteCoordOrigin::LowerLeft)
+    );
+    assert_eq!(
+        get_provoking_vertex(),
+        Some(ProvokingVertex::FirstVertexConvention)
+    );
+
+    assert_eq!(
+        line_width_checked(0.0),
+        Err(RasterizationError::InvalidLineWidth(0.0))
+    );
+    assert_eq!(
+        point_size_checked(-1.0),
+        Err(RasterizationError::InvalidPointSize(-1.0))
+    );
+    assert_eq!(
+        point_fade_threshold_size_checked(-1.0),
+        Err(RasterizationError::InvalidPointFadeThresholdSize(-1.0))
+    );
+    assert_eq!(get_point_size(), 3.0);
+    assert_eq!(get_error(), Error::NoError);
+
+    // a pending error is reported by the next checked getter
+    line_width(-1.0);
+    assert_eq!(
+        get_line_width_checked(),
+        Err(RasterizationError::Unexpected(Error::InvalidValue))
+    );
+    assert_eq!(get_point_size_checked(), Ok(3.0));
+}
+
+#[test]
+fn multisample_coverage() {
+    let context = HeadlessContext::new(1, 1).unwrap();
+    assert_eq!(get_sample_coverage_value(), 1.0);
+    assert!(!get_sample_coverage_invert());
+    assert_eq!(get_min_sample_shading_value(), 0.0);
+    assert_eq!(get_sample_mask_value(0), u32::MAX);
+
+    let built = FramebufferBuilder::new()
+        .colour(
+            0,
+            AttachmentImage::renderbuffer(RenderbufferInternalFormat::RGBA8, 1, 1).with_samples(4),
+        )
+        .build()
+        .unwrap();
+    bind_framebuffer(FramebufferBindingTarget::ReadDraw, built.framebuffer);
+    for index in 0..4 {
+        let [x, y] = get_sample_position_checked(index).unwrap();
+        assert!((0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y));
+    }
+    assert_eq!(
+        get_sample_position_checked(4),
+        Err(RasterizationError::InvalidSampleIndex(4))
+    );
+
+    // the red channel of the resolved pixel is the fraction of the samples drawn
+    let program = common::link(
+        common::DEPTH_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    use_program(program);
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+    let resolved_red = || {
+        bind_framebuffer(FramebufferBindingTarget::ReadDraw, built.framebuffer);
+        clear(ClearMask::COLOUR);
+        draw_red(program, DrawMode::Triangles, 6, 0.0);
+        bind_framebuffer(FramebufferBindingTarget::Draw, context.framebuffer());
+        blit_framebuffer(
+            0,
+            0,
+            1,
+            1,
+            0,
+            0,
+            1,
+            1,
+            ClearMask::COLOUR,
+            BlitFilter::Nearest,
+        );
+        context.read_colour()[0][0]
+    };
+
+    enable(Capability::SampleMask);
+    sample_mask_checked(0, 0b0011).unwrap();
+    assert_eq!(get_sample_mask_value_checked(0), Ok(0b0011));
+    assert_eq!(resolved_red(), 128);
+    disable(Capability::SampleMask);
+
+    enable(Capability::SampleCoverage);
+    sample_coverage_checked(0.25, false).unwrap();
+    assert_eq!(resolved_red(), 64);
+    sample_coverage_checked(0.25, true).unwrap();
+    assert_eq!(get_sample_coverage_value(), 0.25);
+    assert!(get_sample_coverage_invert());
+    assert_eq!(resolved_red(), 191);
+    disable(Capability::SampleCoverage);
+
+    enable(Capability::SampleShading);
+    min_sample_shading_checked(1.0).unwrap();
+    assert_eq!(get_min_sample_shading_value(), 1.0);
+    assert_eq!(resolved_red(), 255);
+    disable(Capability::SampleShading);
+
+    let words = get_max_sample_mask_words();
+    assert!(words >= 1);
+    assert_eq!(
+        sample_mask_checked(words, 0),
+        Err(RasterizationError::InvalidSampleMaskWord(words))
+    );
+    assert_eq!(
+        get_sample_mask_value_checked(words),
+        Err(RasterizationError::InvalidSampleMaskWord(words))
+    );
+    bind_framebuffer(FramebufferBindingTarget::ReadDraw, context.framebuffer());
+    built.delete();
+    assert_eq!(get_error(), Error::NoError);
+}
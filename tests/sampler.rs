@@ -0,0 +1,175 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+/// Samples the same coordinate, outside of `[0, 1]`, through the texture units 0 and 1
+const TWO_UNIT_FRAGMENT_SHADER: &str = r#"
+#version 420 core
+layout (binding = 0) uniform sampler2D repeated;
+layout (binding = 1) uniform sampler2D clamped;
+out vec4 fragment_colour;
+void main() {
+    vec2 coordinate = vec2(1.25, 0.5);
+    float red = texture(repeated, coordinate).r;
+    float green = texture(clamped, coordinate).r;
+    fragment_colour = vec4(red, green, 0.0, 1.0);
+}
+"#;
+
+#[test]
+fn one_texture_two_samplers() {
+    let context = HeadlessContext::new(1, 1).unwrap();
+    let program = common::link(common::POSITION_VERTEX_SHADER, TWO_UNIT_FRAGMENT_SHADER);
+
+    // a single level texture, which is incomplete with its default mipmap minifying filter
+    let mut texture = Texture::default();
+    gen_textures(std::slice::from_mut(&mut texture));
+    for unit in 0..2 {
+        active_texture(unit);
+        bind_texture(TextureBindingTarget::Image2D, texture);
+    }
+    tex_image_2d(
+        TextureBinding2DTarget::Image2D,
+        0,
+        TextureInternalFormat::R8,
+        4,
+        1,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        TextureData::Data(&[64u8, 64, 192, 192]),
+    );
+
+    let mut samplers = [Sampler::default(); 2];
+    gen_samplers(&mut samplers);
+    for sampler in samplers {
+        sampler_min_filter_checked(sampler, TextureMinFilter::Nearest).unwrap();
+        sampler_mag_filter_checked(sampler, TextureMagFilter::Nearest).unwrap();
+    }
+    sampler_wrap_s_checked(samplers[0], TextureWrapMode::Repeat).unwrap();
+    sampler_wrap_s_checked(samplers[1], TextureWrapMode::ClampToEdge).unwrap();
+    bind_samplers_checked(0, &samplers).unwrap();
+    assert_eq!(get_sampler_binding(), samplers[1]);
+
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+    use_program(program);
+    draw_arrays(DrawMode::Triangles, 0, 6);
+    assert_eq!(context.read_colour(), [[64, 192, 0, 255]]);
+
+    // without samplers, the texture's own (incomplete) state is used again
+    bind_sampler_checked(0, Sampler(0)).unwrap();
+    bind_sampler_checked(1, Sampler(0)).unwrap();
+    draw_arrays(DrawMode::Triangles, 0, 6);
+    assert_eq!(context.read_colour(), [[0, 0, 0, 255]]);
+
+    delete_samplers(&samplers);
+    assert!(!is_sampler(samplers[0]));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn sampler_parameters() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let mut sampler = Sampler::default();
+    create_samplers(std::slice::from_mut(&mut sampler));
+    assert!(is_sampler(sampler));
+    assert_eq!(
+        get_sampler_min_filter(sampler),
+        Some(TextureMinFilter::NearestMipmapLinear)
+    );
+    assert_eq!(
+        get_sampler_mag_filter(sampler),
+        Some(TextureMagFilter::Linear)
+    );
+    assert_eq!(get_sampler_wrap_r(sampler), Some(TextureWrapMode::Repeat));
+    assert_eq!(
+        get_sampler_compare_mode(sampler),
+        Some(TextureCompareMode::None)
+    );
+    assert_eq!(get_sampler_min_lod(sampler), -1000.0);
+    assert_eq!(get_sampler_max_lod(sampler), 1000.0);
+
+    sampler_min_filter(sampler, TextureMinFilter::LinearMipmapNearest);
+    sampler_mag_filter(sampler, TextureMagFilter::Nearest);
+    sampler_wrap_s(sampler, TextureWrapMode::MirroredRepeat);
+    sampler_wrap_t(sampler, TextureWrapMode::ClampToBorder);
+    sampler_wrap_r(sampler, TextureWrapMode::MirrorClampToEdge);
+    sampler_compare_mode(sampler, TextureCompareMode::Ref);
+    sampler_compare_func(sampler, CompareFunc::Greater);
+    sampler_lod_bias(sampler, -0.5);
+    sampler_min_lod(sampler, 1.0);
+    sampler_max_lod(sampler, 4.0);
+    sampler_border_colour_f32(sampler, [0.25, 0.5, 0.75, 1.0]);
+
+    assert_eq!(
+        get_sampler_min_filter_checked(sampler),
+        Ok(Some(TextureMinFilter::LinearMipmapNearest))
+    );
+    assert_eq!(
+        get_sampler_mag_filter_checked(sampler),
+        Ok(Some(TextureMagFilter::Nearest))
+    );
+    assert_eq!(
+        get_sampler_wrap_s_checked(sampler),
+        Ok(Some(TextureWrapMode::MirroredRepeat))
+    );
+    assert_eq!(
+        get_sampler_wrap_t_checked(sampler),
+        Ok(Some(TextureWrapMode::ClampToBorder))
+    );
+    assert_eq!(
+        get_sampler_wrap_r_checked(sampler),
+        Ok(Some(TextureWrapMode::MirrorClampToEdge))
+    );
+    assert_eq!(
+        get_sampler_compare_mode_checked(sampler),
+        Ok(Some(TextureCompareMode::Ref))
+    );
+    assert_eq!(
+        get_sampler_compare_func_checked(sampler),
+        Ok(Some(CompareFunc::Greater))
+    );
+    assert_eq!(get_sampler_lod_bias_checked(sampler), Ok(-0.5));
+    assert_eq!(get_sampler_min_lod_checked(sampler), Ok(1.0));
+    assert_eq!(get_sampler_max_lod_checked(sampler), Ok(4.0));
+    assert_eq!(
+        get_sampler_border_colour_f32_checked(sampler),
+        Ok([0.25, 0.5, 0.75, 1.0])
+    );
+
+    delete_samplers(&[sampler]);
+    assert_eq!(
+        sampler_wrap_s_checked(sampler, TextureWrapMode::Repeat),
+        Err(SamplerError::InvalidSampler(sampler))
+    );
+    assert_eq!(
+        get_sampler_min_filter_checked(sampler),
+        Err(SamplerError::InvalidSampler(sampler))
+    );
+    assert_eq!(
+        bind_sampler_checked(0, sampler),
+        Err(SamplerError::InvalidSampler(sampler))
+    );
+    let units = get_max_combined_texture_image_units();
+    assert_eq!(
+        bind_sampler_checked(units, Sampler(0)),
+        Err(SamplerError::InvalidTextureUnit(units))
+    );
+    assert_eq!(
+        bind_samplers_checked(units - 1, &[Sampler(0); 2]),
+        Err(SamplerError::InvalidTextureUnitRange {
+            first: units - 1,
+            count: 2
+        })
+    );
+
+    let owned = OwnedSampler::generate();
+    assert!(is_sampler(*owned));
+    let raw = *owned;
+    drop(owned);
+    assert!(!is_sampler(raw));
+    assert_eq!(get_error(), Error::NoError);
+}
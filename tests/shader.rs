@@ -0,0 +1,498 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+#[test]
+fn shader_compile_status() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let shader = common::compile(ShaderType::Vertex, common::POSITION_VERTEX_SHADER);
+    assert!(is_shader(shader));
+    assert_eq!(get_shader_type(shader), Ok(ShaderType::Vertex));
+    assert!(!get_shader_delete_status(shader));
+    delete_shader(shader);
+    assert!(!is_shader(shader));
+
+    let shader = create_shader(ShaderType::Fragment);
+    shader_source(
+        shader,
+        "#version 330 core\nvoid main() { undeclared = 1; }\n",
+    );
+    compile_shader(shader);
+    assert!(!get_shader_compile_status(shader));
+    assert!(get_shader_info_log_length(shader) > 0);
+
+    let mut buffer = [0; 1024];
+    let info_log = get_shader_info_log(shader, &mut buffer);
+    assert!(!info_log.is_empty());
+    assert_ne!(info_log.last(), Some(&0));
+    delete_shader(shader);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn program_link_status() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let vertex = common::compile(ShaderType::Vertex, common::POSITION_VERTEX_SHADER);
+    let fragment = common::compile(ShaderType::Fragment, common::UNIFORM_COLOUR_FRAGMENT_SHADER);
+    let program = create_program();
+    assert!(is_program(program));
+    attach_shader(program, vertex);
+    attach_shader(program, fragment);
+    assert_eq!(get_program_attached_shaders(program), 2);
+
+    let mut buffer = [Shader::default(); 4];
+    let attached = get_attached_shaders(program, &mut buffer);
+    assert_eq!(attached.len(), 2);
+    assert!(attached.contains(&vertex) && attached.contains(&fragment));
+
+    link_program(program);
+    assert!(get_program_link_status(program));
+    assert_eq!(get_program_active_attributes(program), 1);
+    assert_eq!(get_program_active_uniforms(program), 1);
+    assert_eq!(
+        get_program_attribute_max_length(program),
+        "position".len() as u32 + 1
+    );
+    assert_ne!(
+        get_uniform_location(program, c"colour"),
+        UniformLocation(-1)
+    );
+    assert_eq!(
+        get_uniform_location(program, c"missing"),
+        UniformLocation(-1)
+    );
+
+    delete_shader(vertex);
+    delete_shader(fragment);
+    delete_program(program);
+    assert!(!is_program(program));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn program_link_failure() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    // `colour` is declared with conflicting types in each stage
+    let vertex = common::compile(
+        ShaderType::Vertex,
+        r#"
+        #version 330 core
+        layout (location=0) in vec2 position;
+        uniform float colour;
+        void main() {
+            gl_Position = vec4(position, colour, 1.0);
+        }
+        "#,
+    );
+    let fragment = common::compile(ShaderType::Fragment, common::UNIFORM_COLOUR_FRAGMENT_SHADER);
+    let program = create_program();
+    attach_shader(program, vertex);
+    attach_shader(program, fragment);
+    link_program(program);
+    assert!(!get_program_link_status(program));
+    assert!(get_program_info_log_length(program) > 0);
+
+    delete_shader(vertex);
+    delete_shader(fragment);
+    delete_program(program);
+}
+
+#[test]
+fn uniform_colour_is_drawn() {
+    let context = HeadlessContext::new(4, 4).unwrap();
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+
+    use_program(program);
+    let colour = get_uniform_location(program, c"colour");
+    uniform_4f32(colour, 0.0, 1.0, 0.0, 1.0);
+    draw_arrays(DrawMode::Triangles, 0, 6);
+    assert!(context.read_colour().iter().all(|p| *p == [0, 255, 0, 255]));
+
+    uniform_4f32v(colour, &[[0.0, 0.0, 1.0, 1.0]]);
+    draw_arrays(DrawMode::Triangles, 0, 6);
+    assert!(context.read_colour().iter().all(|p| *p == [0, 0, 255, 255]));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn checked_shader_errors() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let shader = common::compile(ShaderType::Vertex, common::POSITION_VERTEX_SHADER);
+    let program = create_program();
+    assert_eq!(attach_shader_checked(program, shader), Ok(()));
+    assert_eq!(
+        attach_shader_checked(program, shader),
+        Err(ShaderError::ShaderAlreadyAttached { program, shader })
+    );
+    assert_eq!(
+        attach_shader_checked(Program(shader.0), shader),
+        Err(ShaderError::NotAProgram(Program(shader.0)))
+    );
+    assert_eq!(detach_shader_checked(program, shader), Ok(()));
+    assert_eq!(
+        detach_shader_checked(program, shader),
+        Err(ShaderError::ShaderNotAttached { program, shader })
+    );
+    assert_eq!(
+        use_program_checked(program),
+        Err(ShaderError::ProgramNotLinked(program))
+    );
+    assert_eq!(
+        use_program_checked(Program(shader.0)),
+        Err(ShaderError::NotAProgram(Program(shader.0)))
+    );
+    assert_eq!(
+        use_program_checked(Program(42)),
+        Err(ShaderError::InvalidProgram(Program(42)))
+    );
+    assert_eq!(use_program_checked(Program(0)), Ok(()));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn program_introspection() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    assert_eq!(
+        get_program_active_uniform_max_length(program),
+        "colour".len() as u32 + 1
+    );
+
+    let mut buffer = [0; 64];
+    let attrib = get_active_attrib(program, 0, &mut buffer);
+    assert_eq!(attrib.name, b"position");
+    assert_eq!(attrib.ty, GlslType::Vector(GlslScalarType::Float, 2));
+    assert_eq!(attrib.size, 1);
+
+    let uniform = get_active_uniform(program, 0, &mut buffer);
+    assert_eq!(uniform.name, b"colour");
+    assert_eq!(uniform.ty, GlslType::Vector(GlslScalarType::Float, 4));
+
+    assert_eq!(get_attrib_location(program, c"position"), Some(0));
+    assert_eq!(get_attrib_location(program, c"missing"), None);
+    assert_eq!(
+        get_active_uniform_checked(program, 1, &mut buffer),
+        Err(ShaderError::InvalidIndex(1))
+    );
+    assert_eq!(
+        bind_attrib_location_checked(program, 0, c"gl_Position"),
+        Err(ShaderError::ReservedName)
+    );
+
+    let unlinked = create_program();
+    assert_eq!(
+        get_attrib_location_checked(unlinked, c"position"),
+        Err(ShaderError::ProgramNotLinked(unlinked))
+    );
+    assert_eq!(
+        ProgramReflection::new_checked(unlinked),
+        Err(ShaderError::ProgramNotLinked(unlinked))
+    );
+    delete_program(unlinked);
+    delete_program(program);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn program_reflection() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let program = common::link(
+        r#"
+        #version 430 core
+        layout (location=0) in vec2 position;
+        layout (location=3) in vec3 normal;
+        uniform mat4 transform;
+        out vec3 vertex_normal;
+        void main() {
+            vertex_normal = normal;
+            gl_Position = transform * vec4(position, 0.0, 1.0);
+        }
+        "#,
+        r#"
+        #version 430 core
+        in vec3 vertex_normal;
+        uniform sampler2D image;
+        uniform float weights[3];
+        layout (std140, binding=2) uniform Material {
+            vec4 tint;
+            float roughness;
+        };
+        layout (std430, binding=1) buffer Lights {
+            vec4 lights[];
+        };
+        layout (location=0) out vec4 colour;
+        void main() {
+            vec4 light = lights[0] * (weights[0] + weights[1] + weights[2]) * roughness;
+            colour = texture(image, vertex_normal.xy) * tint + light;
+        }
+        "#,
+    );
+
+    let reflection = ProgramReflection::new_checked(program).unwrap();
+
+    let position = reflection.attribute("position").unwrap();
+    assert_eq!(position.ty, GlslType::Vector(GlslScalarType::Float, 2));
+    assert_eq!(position.location, Some(0));
+    let normal = reflection.attribute("normal").unwrap();
+    assert_eq!(normal.ty, GlslType::Vector(GlslScalarType::Float, 3));
+    assert_eq!(normal.location, Some(3));
+
+    let transform = reflection.uniform("transform").unwrap();
+    assert_eq!(
+        transform.ty,
+        GlslType::Matrix {
+            ty: GlslScalarType::Float,
+            columns: 4,
+            rows: 4
+        }
+    );
+    assert_eq!(transform.block_index, None);
+    assert_eq!(
+        reflection.uniform_location("transform"),
+        Some(get_uniform_location(program, c"transform"))
+    );
+    assert_eq!(
+        reflection.uniform("image").unwrap().ty,
+        GlslType::Sampler {
+            target: TextureBindingTarget::Image2D,
+            ty: GlslScalarType::Float,
+            shadow: false
+        }
+    );
+    assert_eq!(reflection.uniform("weights[0]").unwrap().array_size, 3);
+
+    let material = reflection.uniform_block("Material").unwrap();
+    assert_eq!(material.binding, 2);
+    assert_eq!(material.data_size, 32);
+    assert_eq!(material.variables.len(), 2);
+    let tint = reflection.uniform("tint").unwrap();
+    assert_eq!(tint.location, None);
+    assert_eq!(tint.offset, Some(0));
+    assert_eq!(reflection.uniform("roughness").unwrap().offset, Some(16));
+    let block = tint.block_index.unwrap() as usize;
+    assert_eq!(reflection.uniform_blocks[block], *material);
+
+    let lights = reflection.shader_storage_block("Lights").unwrap();
+    assert_eq!(lights.binding, 1);
+    assert_eq!(lights.variables.len(), 1);
+    let variable = &reflection.buffer_variables[lights.variables[0] as usize];
+    assert_eq!(variable.name, "lights[0]");
+    assert_eq!(variable.ty, GlslType::Vector(GlslScalarType::Float, 4));
+
+    let colour = reflection.output("colour").unwrap();
+    assert_eq!(colour.location, Some(0));
+    assert_eq!(reflection.outputs.len(), 1);
+
+    assert_eq!(
+        get_program_resource_index(program, ProgramInterface::UniformBlock, c"Material"),
+        tint.block_index
+    );
+    assert_eq!(
+        get_program_resource_location(program, ProgramInterface::ProgramInput, c"normal"),
+        Some(3)
+    );
+    assert_eq!(
+        get_program_interface_checked(
+            program,
+            ProgramInterface::AtomicCounterBuffer,
+            ProgramInterfaceParameter::MaxNameLength
+        ),
+        Err(ShaderError::UnsupportedInterfaceQuery(
+            ProgramInterface::AtomicCounterBuffer
+        ))
+    );
+
+    delete_program(program);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn typed_uniforms() {
+    let context = HeadlessContext::new(4, 4).unwrap();
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        r#"
+        #version 400 core
+        uniform vec4 colour;
+        uniform float weights[3];
+        uniform mat2 transform;
+        uniform bool enabled;
+        uniform sampler2D image;
+        uniform dvec2 scale_factor;
+        out vec4 fragment_colour;
+        void main() {
+            float weight = weights[0] + weights[1] + weights[2];
+            vec2 scale = transform * vec2(1.0) * vec2(scale_factor);
+            fragment_colour = enabled ? colour * weight * scale.x * scale.y : vec4(0.0);
+            if (weight > 100.0) {
+                fragment_colour = texture(image, vec2(0.5));
+            }
+        }
+        "#,
+    );
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+
+    assert_eq!(
+        set_uniform_checked(UniformLocation(0), &1.0),
+        Err(UniformError::NoProgram)
+    );
+    use_program(program);
+
+    let location = |name: &std::ffi::CStr| get_uniform_location(program, name);
+    set_uniform(location(c"colour"), &[0.0f32, 1.0, 0.0, 1.0]);
+    set_uniform(location(c"weights"), &[0.25f32, 0.5, 0.25][..]);
+    set_uniform(location(c"transform"), &[[1.0f32, 0.0], [0.0, 1.0]]);
+    set_uniform(location(c"enabled"), &true);
+    set_uniform(location(c"image"), &0);
+    set_uniform(location(c"scale_factor"), &[1.0f64, 1.0]);
+    assert_eq!(get_error(), Error::NoError);
+    draw_arrays(DrawMode::Triangles, 0, 6);
+    assert!(context.read_colour().iter().all(|p| *p == [0, 255, 0, 255]));
+
+    assert_eq!(
+        set_uniform_checked(location(c"colour"), &[0.0f32, 0.0, 1.0]),
+        Err(UniformError::TypeMismatch {
+            expected: GlslType::Vector(GlslScalarType::Float, 4),
+            found: GlslType::Vector(GlslScalarType::Float, 3),
+        })
+    );
+    assert_eq!(
+        set_uniform_checked(location(c"colour"), &vec![[0.0f32, 0.0, 1.0, 1.0]; 2]),
+        Err(UniformError::NotAnArray(location(c"colour")))
+    );
+    assert_eq!(
+        set_uniform_checked(location(c"image"), &0.0f32),
+        Err(UniformError::TypeMismatch {
+            expected: GlslType::Sampler {
+                target: TextureBindingTarget::Image2D,
+                ty: GlslScalarType::Float,
+                shadow: false,
+            },
+            found: GlslType::Scalar(GlslScalarType::Float),
+        })
+    );
+    assert_eq!(
+        set_uniform_checked(UniformLocation(1000), &1.0f32),
+        Err(UniformError::InvalidLocation(UniformLocation(1000)))
+    );
+    assert_eq!(set_uniform_checked(location(c"enabled"), &1), Ok(()));
+    assert_eq!(
+        set_uniform_checked(location(c"weights[2]"), &0.5f32),
+        Ok(())
+    );
+    assert_eq!(
+        set_uniform_checked(location(c"weights[1]"), &[0.0f32, 0.0][..]),
+        Ok(())
+    );
+    assert_eq!(
+        set_uniform_checked(location(c"transform"), &[[0.0f32, 1.0], [1.0, 0.0]]),
+        Ok(())
+    );
+    assert_eq!(set_uniform_checked(UniformLocation(-1), &[1u32, 2]), Ok(()));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn typed_uniforms_follow_relinking() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let program = common::link(
+        common::DEPTH_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    use_program(program);
+    let depth = get_uniform_location(program, c"depth");
+    assert_eq!(set_uniform_checked(depth, &0.5f32), Ok(()));
+
+    // the same program, relinked with an integer uniform at the same location
+    let vertex = common::compile(
+        ShaderType::Vertex,
+        r#"
+        #version 330 core
+        layout (location=0) in vec2 position;
+        uniform int depth;
+        void main() {
+            gl_Position = vec4(position, float(depth), 1.0);
+        }
+        "#,
+    );
+    let fragment = common::compile(ShaderType::Fragment, common::UNIFORM_COLOUR_FRAGMENT_SHADER);
+    attach_shader(program, vertex);
+    attach_shader(program, fragment);
+    link_program(program);
+    assert!(get_program_link_status(program));
+
+    let depth = get_uniform_location(program, c"depth");
+    assert_eq!(
+        set_uniform_checked(depth, &0.5f32),
+        Err(UniformError::TypeMismatch {
+            expected: GlslType::Scalar(GlslScalarType::Int),
+            found: GlslType::Scalar(GlslScalarType::Float),
+        })
+    );
+    assert_eq!(set_uniform_checked(depth, &1), Ok(()));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn typed_uniforms_follow_reused_program_names() {
+    let context = HeadlessContext::new(1, 1).unwrap();
+    let program = common::link(
+        common::DEPTH_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    use_program(program);
+    let depth = get_uniform_location(program, c"depth");
+    assert_eq!(set_uniform_checked(depth, &0.5f32), Ok(()));
+    drop(context);
+
+    // a new context on the same thread gives its program the same name, linked outside of rgl
+    // with an integer uniform at the same location
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let vertex = common::compile(
+        ShaderType::Vertex,
+        r#"
+        #version 330 core
+        layout (location=0) in vec2 position;
+        uniform int depth;
+        void main() {
+            gl_Position = vec4(position, float(depth), 1.0);
+        }
+        "#,
+    );
+    let fragment = common::compile(ShaderType::Fragment, common::UNIFORM_COLOUR_FRAGMENT_SHADER);
+    let reused = create_program();
+    assert_eq!(reused, program);
+    attach_shader(reused, vertex);
+    attach_shader(reused, fragment);
+    // SAFE: synchronous integer copy
+    unsafe { gl::LinkProgram(reused.0) };
+    assert!(get_program_link_status(reused));
+    use_program(reused);
+
+    let depth = get_uniform_location(reused, c"depth");
+    assert_eq!(
+        set_uniform_checked(depth, &0.5f32),
+        Err(UniformError::TypeMismatch {
+            expected: GlslType::Scalar(GlslScalarType::Int),
+            found: GlslType::Scalar(GlslScalarType::Float),
+        })
+    );
+    assert_eq!(set_uniform_checked(depth, &1), Ok(()));
+    assert_eq!(get_error(), Error::NoError);
+}
@@ -0,0 +1,218 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+#[test]
+fn restores_state_changed_by_other_code() {
+    let _context = HeadlessContext::new(4, 4).unwrap();
+
+    // the application's own state
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    use_program(program);
+    let (vao, _vbo) = common::vertex_array(&common::FULLSCREEN_QUAD);
+    enable(Capability::Blend);
+    blend_func(BlendFactor::SourceAlpha, BlendFactor::OneMinusSourceAlpha);
+    enable(Capability::DepthTest);
+    depth_func(CompareFunc::LessOrEqual);
+    stencil_func_separate(StencilFace::Back, CompareFunc::NotEqual, 1, 0xff);
+    clear_colour(0.25, 0.5, 0.75, 1.0);
+    let mut textures = [Texture::default(); 2];
+    gen_textures(&mut textures);
+    active_texture(2);
+    bind_texture(TextureBindingTarget::Image2D, textures[0]);
+    let built = FramebufferBuilder::new()
+        .colour(
+            0,
+            AttachmentImage::texture(TextureInternalFormat::RGBA8, 4, 4),
+        )
+        .build()
+        .unwrap();
+    bind_framebuffer(FramebufferBindingTarget::Draw, built.framebuffer);
+    let snapshot = GlStateSnapshot::capture();
+    assert_eq!(snapshot.current_program, program);
+    assert_eq!(snapshot.vertex_array, vao);
+    assert_eq!(snapshot.active_texture, 2);
+    assert_eq!(
+        snapshot.texture_units[2].textures[1],
+        (TextureBindingTarget::Image2D, textures[0])
+    );
+    assert_eq!(snapshot, GlStateSnapshot::capture());
+    assert!(snapshot.diff(&GlStateSnapshot::capture()).is_empty());
+
+    // a UI library drawing its own way
+    use_program(Program(0));
+    bind_vertex_array(VertexArray(0));
+    blend_func_separate_buffer(
+        1,
+        BlendFactor::One,
+        BlendFactor::Zero,
+        BlendFactor::One,
+        BlendFactor::Zero,
+    );
+    disable(Capability::DepthTest);
+    enable_indexed(Capability::ScissorTest, 0);
+    scissor_indexed(
+        0,
+        Rect {
+            x: 1,
+            y: 1,
+            width: 2,
+            height: 2,
+        },
+    );
+    stencil_mask_separate(StencilFace::Front, 0x0f);
+    active_texture(0);
+    bind_texture(TextureBindingTarget::Image2D, textures[1]);
+    bind_framebuffer(FramebufferBindingTarget::ReadDraw, Framebuffer(0));
+
+    let differences = snapshot.diff(&GlStateSnapshot::capture());
+    let states: Vec<&str> = differences
+        .iter()
+        .map(|difference| difference.state.as_str())
+        .collect();
+    assert_eq!(
+        states,
+        [
+            "DepthTest",
+            "ScissorTest",
+            "blend source rgb of draw buffer 1",
+            "blend dest rgb of draw buffer 1",
+            "blend source alpha of draw buffer 1",
+            "blend dest alpha of draw buffer 1",
+            "current program",
+            "vertex array",
+            "active texture",
+            "draw framebuffer",
+            "read framebuffer",
+            "Front stencil writemask",
+            "scissor test of viewport 0",
+            "scissor box of viewport 0",
+            "Image2D texture of unit 0",
+        ]
+    );
+    assert_eq!(
+        differences[6].to_string(),
+        format!("current program: {program:?} != Program(0)")
+    );
+
+    snapshot.restore();
+    assert_eq!(get_error(), Error::NoError);
+    assert_eq!(GlStateSnapshot::capture(), snapshot);
+    assert_eq!(get_texture_binding_2d(), textures[0]);
+    assert_eq!(get_element_array_buffer_binding(), Buffer(0));
+    built.delete();
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn restores_buffer_ranges() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let mut buffers = [Buffer::default(); 2];
+    gen_buffers(&mut buffers);
+    for buffer in buffers {
+        bind_buffer(BufferBindingTarget::Uniform, buffer);
+        buffer_data(
+            BufferBindingTarget::Uniform,
+            1024,
+            BufferUsageFrequency::Static,
+            BufferUsageNature::Draw,
+        );
+    }
+    let target = BufferBindingRangeTarget::Uniform;
+    let offset = get_uniform_buffer_offset_alignment() as i64;
+    bind_buffer_range(target, 1, buffers[0], offset, 128);
+    bind_buffer_base(target, 3, buffers[1]);
+    bind_buffer(BufferBindingTarget::Uniform, buffers[0]);
+    let snapshot = GlStateSnapshot::capture();
+    let uniform = &snapshot.buffer_ranges[3];
+    assert_eq!(uniform.0, target);
+    assert_eq!(
+        uniform.1[1],
+        BufferRangeBinding {
+            buffer: buffers[0],
+            start: offset,
+            size: 128
+        }
+    );
+    assert_eq!(uniform.1[3].buffer, buffers[1]);
+
+    bind_buffer_base(target, 1, buffers[1]);
+    bind_buffer_base(target, 3, Buffer(0));
+    assert_eq!(
+        snapshot
+            .diff(&GlStateSnapshot::capture())
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        [
+            format!("Uniform buffer: {:?} != {:?}", buffers[0], Buffer(0)),
+            format!(
+                "Uniform buffer binding 1: {:?} != {:?}",
+                uniform.1[1],
+                BufferRangeBinding {
+                    buffer: buffers[1],
+                    start: 0,
+                    size: 0
+                }
+            ),
+            format!(
+                "Uniform buffer binding 3: {:?} != {:?}",
+                uniform.1[3],
+                BufferRangeBinding {
+                    buffer: Buffer(0),
+                    start: 0,
+                    size: 0
+                }
+            ),
+        ]
+    );
+
+    snapshot.restore();
+    assert_eq!(GlStateSnapshot::capture(), snapshot);
+    assert_eq!(get_buffer_start_indexed(target, 1), offset);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn restores_point_state() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    enable(Capability::TextureCubeMapSeamless);
+    point_fade_threshold_size(2.0);
+    let snapshot = GlStateSnapshot::capture();
+
+    disable(Capability::TextureCubeMapSeamless);
+    enable(Capability::ProgramPointSize);
+    point_fade_threshold_size(4.0);
+    point_sprite_coord_origin(PointSpriteCoordOrigin::LowerLeft);
+    let states: Vec<String> = snapshot
+        .diff(&GlStateSnapshot::capture())
+        .into_iter()
+        .map(|difference| difference.state)
+        .collect();
+    assert_eq!(
+        states,
+        [
+            "TextureCubeMapSeamless",
+            "ProgramPointSize",
+            "point fade threshold size",
+            "point sprite coord origin",
+        ]
+    );
+
+    snapshot.restore();
+    assert_eq!(GlStateSnapshot::capture(), snapshot);
+    assert!(is_enabled(Capability::TextureCubeMapSeamless));
+    assert!(!is_enabled(Capability::ProgramPointSize));
+    assert_eq!(get_point_fade_threshold_size(), 2.0);
+    assert_eq!(
+        get_point_sprite_coord_origin(),
+        Some(PointSpriteCoordOrigin::UpperLeft)
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
@@ -0,0 +1,706 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+fn new_texture() -> Texture {
+    let mut texture = Texture::default();
+    gen_textures(std::slice::from_mut(&mut texture));
+    bind_texture(TextureBindingTarget::Image2D, texture);
+    texture
+}
+
+#[test]
+fn tex_image_round_trip() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let texture = new_texture();
+    assert!(is_texture(texture));
+
+    let texels: [[u8; 4]; 4] = [
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+        [9, 10, 11, 12],
+        [13, 14, 15, 16],
+    ];
+    tex_image_2d(
+        TextureBinding2DTarget::Image2D,
+        0,
+        TextureInternalFormat::RGBA8,
+        2,
+        2,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        TextureData::Data(&texels),
+    );
+
+    let mut read = [[0u8; 4]; 4];
+    get_tex_image(
+        TextureImageTarget::Image2D,
+        0,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        &mut read,
+    );
+    assert_eq!(read, texels);
+
+    let mut read = [[0u8; 4]; 4];
+    get_tex_image(
+        TextureImageTarget::Image2D,
+        0,
+        TextureFormat::BGRA,
+        TexturePixelType::U8,
+        &mut read,
+    );
+    assert_eq!(read[0], [3, 2, 1, 4]);
+
+    let mut read = [0u8; 15];
+    assert_eq!(
+        get_tex_image_checked(
+            TextureImageTarget::Image2D,
+            0,
+            TextureFormat::RGBA,
+            TexturePixelType::U8,
+            &mut read,
+        ),
+        Err(TextureError::InsufficientPixelData {
+            required: 16,
+            provided: 15
+        })
+    );
+
+    delete_textures(&[texture]);
+    assert!(!is_texture(texture));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn generate_mipmap_creates_levels() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    new_texture();
+
+    let texels = [[200u8, 100, 0, 255]; 16];
+    tex_image_2d(
+        TextureBinding2DTarget::Image2D,
+        0,
+        TextureInternalFormat::RGBA8,
+        4,
+        4,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        TextureData::Data(&texels),
+    );
+    generate_mipmap(TextureBindingTarget::Image2D);
+
+    let mut read = [[0u8; 4]; 1];
+    get_tex_image(
+        TextureImageTarget::Image2D,
+        2,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        &mut read,
+    );
+    assert_eq!(read, [[200, 100, 0, 255]]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn sampled_texture_is_drawn() {
+    let context = HeadlessContext::new(2, 2).unwrap();
+    let program = common::link(
+        r#"
+        #version 330 core
+        layout (location=0) in vec2 position;
+        out vec2 uv;
+        void main() {
+            uv = position * 0.5 + 0.5;
+            gl_Position = vec4(position, 0.0, 1.0);
+        }
+        "#,
+        r#"
+        #version 330 core
+        uniform sampler2D image;
+        in vec2 uv;
+        out vec4 fragment_colour;
+        void main() {
+            fragment_colour = texture(image, uv);
+        }
+        "#,
+    );
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+
+    active_texture(1);
+    new_texture();
+    let texels: [[u8; 4]; 4] = [
+        [255, 0, 0, 255],
+        [0, 255, 0, 255],
+        [0, 0, 255, 255],
+        [255, 255, 255, 255],
+    ];
+    tex_image_2d(
+        TextureBinding2DTarget::Image2D,
+        0,
+        TextureInternalFormat::RGBA8,
+        2,
+        2,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        TextureData::Data(&texels),
+    );
+    texture_target_min_filter(TextureBindingTarget::Image2D, TextureMinFilter::Nearest);
+    texture_target_mag_filter(TextureBindingTarget::Image2D, TextureMagFilter::Nearest);
+    texture_target_wrap_s(TextureBindingTarget::Image2D, TextureWrapMode::ClampToEdge);
+    texture_target_wrap_t(TextureBindingTarget::Image2D, TextureWrapMode::ClampToEdge);
+
+    use_program(program);
+    let location = get_uniform_location(program, c"image");
+    uniform_1i32(location, 1);
+    draw_arrays(DrawMode::Triangles, 0, 6);
+
+    assert_eq!(context.read_colour(), texels);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn checked_texture_errors() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    assert_eq!(
+        bind_texture_checked(TextureBindingTarget::Image2D, Texture(4242)),
+        Err(TextureError::InvalidTexture(Texture(4242)))
+    );
+
+    new_texture();
+    assert_eq!(
+        tex_image_2d_checked::<u8>(
+            TextureBinding2DTarget::Image2D,
+            0,
+            TextureInternalFormat::RGBA8,
+            1 << 20,
+            1,
+            TextureFormat::RGBA,
+            TexturePixelType::U8,
+            TextureData::Reserve,
+        ),
+        Err(TextureError::InvalidDimensions {
+            width: 1 << 20,
+            height: 1
+        })
+    );
+    assert_eq!(
+        tex_image_2d_checked::<u8>(
+            TextureBinding2DTarget::Image2D,
+            0,
+            TextureInternalFormat::RGBA8,
+            1,
+            1,
+            TextureFormat::RGBA,
+            TexturePixelType::U8,
+            TextureData::Reserve,
+        ),
+        Ok(())
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn sub_image_updates_immutable_storage() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    new_texture();
+
+    tex_storage_2d_checked(
+        TextureStorage2DTarget::Image2D,
+        2,
+        TextureInternalFormat::RGBA8,
+        4,
+        2,
+    )
+    .unwrap();
+    let texels = [[7u8; 4]; 8];
+    tex_sub_image_2d_checked(
+        TextureBinding2DTarget::Image2D,
+        0,
+        0,
+        0,
+        4,
+        2,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        TextureSubData::Data(&texels),
+    )
+    .unwrap();
+    let patch = [[1u8, 2, 3, 4], [5, 6, 7, 8]];
+    tex_sub_image_2d_checked(
+        TextureBinding2DTarget::Image2D,
+        0,
+        1,
+        1,
+        2,
+        1,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        TextureSubData::Data(&patch),
+    )
+    .unwrap();
+
+    let mut read = [[0u8; 4]; 8];
+    get_tex_image(
+        TextureImageTarget::Image2D,
+        0,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        &mut read,
+    );
+    assert_eq!(read[..5], [[7; 4]; 5]);
+    assert_eq!(read[5..7], patch);
+    assert_eq!(read[7], [7; 4]);
+
+    assert_eq!(
+        tex_sub_image_2d_checked(
+            TextureBinding2DTarget::Image2D,
+            0,
+            0,
+            0,
+            4,
+            2,
+            TextureFormat::RGBA,
+            TexturePixelType::U8,
+            TextureSubData::Data(&patch),
+        ),
+        Err(TextureError::InsufficientPixelData {
+            required: 32,
+            provided: 8
+        })
+    );
+    assert_eq!(
+        tex_sub_image_2d_checked(
+            TextureBinding2DTarget::Image2D,
+            0,
+            3,
+            0,
+            2,
+            1,
+            TextureFormat::RGBA,
+            TexturePixelType::U8,
+            TextureSubData::Data(&patch),
+        ),
+        Err(TextureError::InvalidRegion)
+    );
+    assert_eq!(
+        tex_storage_2d_checked(
+            TextureStorage2DTarget::Image2D,
+            1,
+            TextureInternalFormat::RGBA8,
+            4,
+            2,
+        ),
+        Err(TextureError::ImmutableStorage)
+    );
+
+    new_texture();
+    assert_eq!(
+        tex_storage_2d_checked(
+            TextureStorage2DTarget::Image2D,
+            4,
+            TextureInternalFormat::RGBA8,
+            4,
+            2,
+        ),
+        Err(TextureError::InvalidLevel(4))
+    );
+    assert_eq!(
+        tex_storage_2d_checked(
+            TextureStorage2DTarget::Image2D,
+            1,
+            TextureInternalFormat::RGBA,
+            4,
+            2,
+        ),
+        Err(TextureError::UnsizedInternalFormat(
+            TextureInternalFormat::RGBA
+        ))
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn one_and_three_dimensional_images() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let mut textures = [Texture::default(); 2];
+    gen_textures(&mut textures);
+
+    bind_texture(TextureBindingTarget::Image1D, textures[0]);
+    let row = [10u8, 20, 30, 40, 50, 60, 70, 80];
+    tex_image_1d_checked(
+        TextureBinding1DTarget::Image1D,
+        0,
+        TextureInternalFormat::R8,
+        8,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        TextureData::Data(&row),
+    )
+    .unwrap();
+    tex_sub_image_1d_checked(
+        TextureBinding1DTarget::Image1D,
+        0,
+        6,
+        2,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        TextureSubData::Data(&[1u8, 2]),
+    )
+    .unwrap();
+    let mut read = [0u8; 8];
+    get_tex_image(
+        TextureImageTarget::Image1D,
+        0,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        &mut read,
+    );
+    assert_eq!(read, [10, 20, 30, 40, 50, 60, 1, 2]);
+
+    // a 4x1 atlas of 3 pages, with the last page uploaded separately
+    bind_texture(TextureBindingTarget::Array2D, textures[1]);
+    tex_storage_3d_checked(
+        TextureBinding3DTarget::Image2DArray,
+        1,
+        TextureInternalFormat::R8,
+        4,
+        1,
+        3,
+    )
+    .unwrap();
+    tex_sub_image_3d_checked(
+        TextureBinding3DTarget::Image2DArray,
+        0,
+        0,
+        0,
+        0,
+        4,
+        1,
+        2,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        TextureSubData::Data(&row),
+    )
+    .unwrap();
+    tex_sub_image_3d_checked(
+        TextureBinding3DTarget::Image2DArray,
+        0,
+        0,
+        0,
+        2,
+        4,
+        1,
+        1,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        TextureSubData::Data(&[9u8; 4]),
+    )
+    .unwrap();
+    let mut read = [0u8; 12];
+    get_tex_image(
+        TextureImageTarget::Array2D,
+        0,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        &mut read,
+    );
+    assert_eq!(read, [10, 20, 30, 40, 50, 60, 70, 80, 9, 9, 9, 9]);
+
+    assert_eq!(
+        tex_image_3d_checked(
+            TextureBinding3DTarget::Image3D,
+            0,
+            TextureInternalFormat::R8,
+            4,
+            1,
+            3,
+            TextureFormat::R,
+            TexturePixelType::U8,
+            TextureData::Data(&row),
+        ),
+        Err(TextureError::InsufficientPixelData {
+            required: 12,
+            provided: 8
+        })
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn sub_image_from_pixel_unpack_buffer() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    new_texture();
+    tex_storage_2d(
+        TextureStorage2DTarget::Image2D,
+        1,
+        TextureInternalFormat::RGBA8,
+        2,
+        1,
+    );
+
+    let mut buffer = Buffer::default();
+    gen_buffers(std::slice::from_mut(&mut buffer));
+    bind_buffer(BufferBindingTarget::PixelUnpack, buffer);
+    let pixels: [u8; 12] = [0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8];
+    buffer_data(
+        BufferBindingTarget::PixelUnpack,
+        &pixels,
+        BufferUsageFrequency::Stream,
+        BufferUsageNature::Draw,
+    );
+    tex_sub_image_2d_checked::<u8>(
+        TextureBinding2DTarget::Image2D,
+        0,
+        0,
+        0,
+        2,
+        1,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        TextureSubData::Offset(4),
+    )
+    .unwrap();
+    assert_eq!(
+        tex_sub_image_2d_checked::<u8>(
+            TextureBinding2DTarget::Image2D,
+            0,
+            0,
+            0,
+            2,
+            1,
+            TextureFormat::RGBA,
+            TexturePixelType::U8,
+            TextureSubData::Offset(8),
+        ),
+        Err(TextureError::InvalidPixelBuffer)
+    );
+    bind_buffer(BufferBindingTarget::PixelUnpack, Buffer::default());
+
+    let mut read = [0u8; 8];
+    get_tex_image(
+        TextureImageTarget::Image2D,
+        0,
+        TextureFormat::RGBA,
+        TexturePixelType::U8,
+        &mut read,
+    );
+    assert_eq!(read, pixels[4..]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn parameter_and_level_queries() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let target = TextureBindingTarget::Image2D;
+    new_texture();
+
+    assert_eq!(
+        get_texture_target_min_filter(target),
+        Some(TextureMinFilter::NearestMipmapLinear)
+    );
+    assert_eq!(get_texture_target_max_level(target), 1000);
+    assert_eq!(
+        get_texture_target_swizzle_rgba(target),
+        Some([
+            TextureSwizzle::Red,
+            TextureSwizzle::Green,
+            TextureSwizzle::Blue,
+            TextureSwizzle::Alpha
+        ])
+    );
+
+    texture_target_min_filter(target, TextureMinFilter::LinearMipmapNearest);
+    texture_target_mag_filter(target, TextureMagFilter::Nearest);
+    texture_target_wrap_s(target, TextureWrapMode::MirroredRepeat);
+    texture_target_wrap_t(target, TextureWrapMode::ClampToBorder);
+    texture_target_base_level(target, 1);
+    texture_target_lod_bias(target, 0.5);
+    texture_target_compare_mode(target, TextureCompareMode::Ref);
+    texture_target_compare_func(target, CompareFunc::Greater);
+    texture_target_border_colour_f32(target, [0.25, 0.5, 0.75, 1.0]);
+    assert_eq!(
+        get_texture_target_min_filter_checked(target),
+        Ok(Some(TextureMinFilter::LinearMipmapNearest))
+    );
+    assert_eq!(
+        get_texture_target_mag_filter_checked(target),
+        Ok(Some(TextureMagFilter::Nearest))
+    );
+    assert_eq!(
+        get_texture_target_wrap_s_checked(target),
+        Ok(Some(TextureWrapMode::MirroredRepeat))
+    );
+    assert_eq!(
+        get_texture_target_wrap_t_checked(target),
+        Ok(Some(TextureWrapMode::ClampToBorder))
+    );
+    assert_eq!(get_texture_target_base_level_checked(target), Ok(1));
+    assert_eq!(get_texture_target_lod_bias_checked(target), Ok(0.5));
+    assert_eq!(
+        get_texture_target_compare_mode_checked(target),
+        Ok(Some(TextureCompareMode::Ref))
+    );
+    assert_eq!(
+        get_texture_target_compare_func_checked(target),
+        Ok(Some(CompareFunc::Greater))
+    );
+    assert_eq!(
+        get_texture_target_border_colour_f32_checked(target),
+        Ok([0.25, 0.5, 0.75, 1.0])
+    );
+    texture_target_border_colour_u32(target, [1, 2, 3, 4]);
+    assert_eq!(get_texture_target_border_colour_u32(target), [1, 2, 3, 4]);
+
+    assert!(!get_texture_target_immutable_format(target));
+    tex_storage_2d_checked(
+        TextureStorage2DTarget::Image2D,
+        2,
+        TextureInternalFormat::RGBA8,
+        4,
+        2,
+    )
+    .unwrap();
+    assert!(get_texture_target_immutable_format_checked(target).unwrap());
+    assert_eq!(get_texture_target_immutable_levels(target), 2);
+
+    let image = TextureImageTarget::Image2D;
+    assert_eq!(get_tex_level_width_checked(image, 1), Ok(2));
+    assert_eq!(get_tex_level_height_checked(image, 1), Ok(1));
+    assert_eq!(get_tex_level_depth(image, 1), 1);
+    assert_eq!(
+        get_tex_level_internal_format_checked(image, 0),
+        Ok(Some(TextureInternalFormat::RGBA8))
+    );
+    assert!(!get_tex_level_compressed(image, 0));
+    assert_eq!(get_tex_level_red_size(image, 0), 8);
+    assert_eq!(get_tex_level_alpha_size(image, 0), 8);
+    assert_eq!(get_tex_level_depth_size(image, 0), 0);
+    assert_eq!(
+        get_tex_level_compressed_image_size_checked(image, 0),
+        Err(TextureError::UncompressedImage)
+    );
+    assert_eq!(
+        get_tex_level_width_checked(image, 100),
+        Err(TextureError::InvalidLevel(100))
+    );
+
+    new_texture();
+    tex_image_2d::<u8>(
+        TextureBinding2DTarget::Image2D,
+        0,
+        TextureInternalFormat::CompressedRRGTC1,
+        8,
+        4,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        TextureData::Reserve,
+    );
+    assert!(get_tex_level_compressed(image, 0));
+    assert_eq!(
+        get_tex_level_compressed_image_size_checked(image, 0),
+        Ok(16)
+    );
+
+    assert_eq!(
+        get_texture_target_min_filter_checked(TextureBindingTarget::Buffer),
+        Err(TextureError::InvalidParameterForTarget(
+            TextureBindingTarget::Buffer
+        ))
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn swizzled_single_channel_texture_is_greyscale() {
+    let context = HeadlessContext::new(1, 1).unwrap();
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        r#"
+        #version 330 core
+        uniform sampler2D image;
+        out vec4 fragment_colour;
+        void main() {
+            fragment_colour = texture(image, vec2(0.5));
+        }
+        "#,
+    );
+    common::vertex_array(&common::FULLSCREEN_QUAD);
+    use_program(program);
+
+    let texture = new_texture();
+    tex_image_2d(
+        TextureBinding2DTarget::Image2D,
+        0,
+        TextureInternalFormat::R8,
+        1,
+        1,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        TextureData::Data(&[100u8]),
+    );
+    texture_parameter_min_filter_checked(texture, TextureMinFilter::Nearest).unwrap();
+    draw_arrays(DrawMode::Triangles, 0, 6);
+    assert_eq!(context.read_colour(), [[100, 0, 0, 255]]);
+
+    use TextureSwizzle::*;
+    let target = TextureBindingTarget::Image2D;
+    texture_target_swizzle_rgba_checked(target, [Red, Red, Red, One]).unwrap();
+    assert_eq!(
+        get_texture_target_swizzle_rgba(target),
+        Some([Red, Red, Red, One])
+    );
+    draw_arrays(DrawMode::Triangles, 0, 6);
+    assert_eq!(context.read_colour(), [[100, 100, 100, 255]]);
+
+    texture_parameter_swizzle_a_checked(texture, Green).unwrap();
+    texture_parameter_swizzle_b_checked(texture, Zero).unwrap();
+    assert_eq!(get_texture_target_swizzle_a(target), Some(Green));
+    draw_arrays(DrawMode::Triangles, 0, 6);
+    assert_eq!(context.read_colour(), [[100, 100, 0, 0]]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn level_of_detail_and_anisotropy_parameters() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let target = TextureBindingTarget::Image2D;
+    let texture = new_texture();
+
+    assert_eq!(get_texture_target_min_lod(target), -1000.0);
+    assert_eq!(get_texture_target_max_lod(target), 1000.0);
+    texture_target_max_level_checked(target, 3).unwrap();
+    texture_target_min_lod_checked(target, 0.5).unwrap();
+    texture_parameter_max_lod_checked(texture, 2.5).unwrap();
+    texture_parameter_base_level_checked(texture, 1).unwrap();
+    assert_eq!(get_texture_target_max_level(target), 3);
+    assert_eq!(get_texture_target_min_lod(target), 0.5);
+    assert_eq!(get_texture_target_max_lod(target), 2.5);
+    assert_eq!(get_texture_target_base_level(target), 1);
+
+    // requests outside of [1, max] are clamped rather than rejected
+    let max = get_max_texture_max_anisotropy();
+    assert!(max >= 2.0);
+    assert_eq!(get_texture_target_max_anisotropy(target), 1.0);
+    texture_target_max_anisotropy_checked(target, max * 2.0).unwrap();
+    assert_eq!(get_texture_target_max_anisotropy(target), max);
+    texture_parameter_max_anisotropy_checked(texture, 0.0).unwrap();
+    assert_eq!(get_texture_target_max_anisotropy_checked(target), Ok(1.0));
+
+    assert_eq!(
+        texture_parameter_wrap_s_checked(Texture(4242), TextureWrapMode::Repeat),
+        Err(TextureError::InvalidTexture(Texture(4242)))
+    );
+    let mut rectangle = Texture::default();
+    gen_textures(std::slice::from_mut(&mut rectangle));
+    bind_texture(TextureBindingTarget::Rectangle, rectangle);
+    assert_eq!(
+        texture_parameter_min_filter_checked(rectangle, TextureMinFilter::LinearMipmapLinear),
+        Err(TextureError::InvalidParameterForTexture(rectangle))
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
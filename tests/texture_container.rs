@@ -0,0 +1,378 @@
+#![cfg(feature = "headless")]
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+const VK_FORMAT_BC5_UNORM_BLOCK: u32 = 141;
+const VK_FORMAT_BC7_UNORM_BLOCK: u32 = 145;
+const DXGI_FORMAT_BC4_UNORM: u32 = 80;
+const DXGI_FORMAT_BC5_UNORM: u32 = 83;
+const DXGI_FORMAT_BC7_UNORM: u32 = 98;
+
+/// A BC7 mode 6 block, with every other bit taken from `seed`
+fn bc7_block(seed: u8) -> [u8; 16] {
+    let mut block = [seed; 16];
+    block[0] = 0x40;
+    block
+}
+
+/// A BC4 block where every texel decodes to `red`
+fn bc4_block(red: u8) -> [u8; 8] {
+    [red, red, 0, 0, 0, 0, 0, 0]
+}
+
+fn ktx2(format: u32, extent: [u32; 5], levels: &[Vec<u8>]) -> Vec<u8> {
+    let [width, height, depth, layers, faces] = extent;
+    let mut bytes = b"\xabKTX 20\xbb\r\n\x1a\n".to_vec();
+    for value in [
+        format,
+        1,
+        width,
+        height,
+        depth,
+        layers,
+        faces,
+        levels.len() as u32,
+        0,
+    ] {
+        bytes.extend(value.to_le_bytes());
+    }
+    // empty data format descriptor, key/value data and supercompression global data
+    bytes.extend([0; 32]);
+
+    let mut offset = 80 + 24 * levels.len();
+    for level in levels {
+        for value in [offset, level.len(), level.len()] {
+            bytes.extend((value as u64).to_le_bytes());
+        }
+        offset += level.len();
+    }
+    for level in levels {
+        bytes.extend(level);
+    }
+    bytes
+}
+
+fn dds(format: u32, width: u32, height: u32, levels: u32, layers: u32, cube: bool) -> Vec<u8> {
+    let mut bytes = b"DDS ".to_vec();
+    for value in [124, 0x2100f, height, width, 0, 1, levels] {
+        bytes.extend(u32::to_le_bytes(value));
+    }
+    bytes.extend([0; 44]);
+    bytes.extend([32u32, 0x4].map(u32::to_le_bytes).concat());
+    bytes.extend(b"DX10");
+    bytes.extend([0; 20]);
+    let caps2: u32 = if cube { 0xfe00 } else { 0 };
+    bytes.extend([0x1000, caps2, 0, 0, 0].map(u32::to_le_bytes).concat());
+    let misc: u32 = if cube { 0x4 } else { 0 };
+    bytes.extend([format, 3, misc, layers, 0].map(u32::to_le_bytes).concat());
+    bytes
+}
+
+#[test]
+fn ktx2_bc7_mip_chain() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    let level_0: Vec<u8> = (0..4).flat_map(bc7_block).collect();
+    let level_1 = bc7_block(9).to_vec();
+    let level_2 = bc7_block(10).to_vec();
+    let bytes = ktx2(
+        VK_FORMAT_BC7_UNORM_BLOCK,
+        [8, 8, 0, 0, 1],
+        &[level_0.clone(), level_1.clone(), level_2.clone()],
+    );
+
+    let container = TextureContainer::parse(&bytes).unwrap();
+    assert_eq!(
+        container.internal_format,
+        TextureInternalFormat::CompressedRGBABPTCUNORM
+    );
+    assert_eq!(
+        (container.width, container.height, container.depth),
+        (8, 8, 1)
+    );
+    assert_eq!(container.target(), TextureBindingTarget::Image2D);
+    assert_eq!(
+        container.levels,
+        [level_0.clone(), level_1, level_2.clone()]
+    );
+
+    let texture = container.upload().unwrap();
+    assert!(is_texture(texture));
+    let mut read = [0u8; 64];
+    get_compressed_tex_image_checked(TextureImageTarget::Image2D, 0, &mut read).unwrap();
+    assert_eq!(read.as_slice(), level_0);
+    let mut read = [0u8; 16];
+    get_compressed_tex_image_checked(TextureImageTarget::Image2D, 2, &mut read).unwrap();
+    assert_eq!(read.as_slice(), level_2);
+    let mut read = [0u8; 15];
+    assert_eq!(
+        get_compressed_tex_image_checked(TextureImageTarget::Image2D, 0, &mut read),
+        Err(TextureError::InsufficientPixelData {
+            required: 64,
+            provided: 15
+        })
+    );
+    delete_textures(&[texture]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn dds_cube_map_and_array() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+
+    // a 4x4 BC5 cube map, with a single block per face
+    let mut bytes = dds(DXGI_FORMAT_BC5_UNORM, 4, 4, 1, 1, true);
+    let faces: Vec<[u8; 16]> = (0..6).map(|face| [face * 16; 16]).collect();
+    bytes.extend(faces.concat());
+    let container = TextureContainer::parse(&bytes).unwrap();
+    assert_eq!(container.target(), TextureBindingTarget::CubeMap);
+    assert_eq!(container.faces, 6);
+
+    let texture = container.upload().unwrap();
+    let mut read = [0u8; 16];
+    get_compressed_tex_image(TextureImageTarget::CubeMapNegativeY, 0, &mut read);
+    assert_eq!(read, faces[3]);
+    delete_textures(&[texture]);
+
+    // an 8x4 BC4 array of 2 layers and 2 levels, stored layer by layer
+    let mut bytes = dds(DXGI_FORMAT_BC4_UNORM, 8, 4, 2, 2, false);
+    for red in [10, 20] {
+        bytes.extend([bc4_block(red), bc4_block(red), bc4_block(red + 5)].concat());
+    }
+    let container = TextureContainer::parse(&bytes).unwrap();
+    assert_eq!(container.target(), TextureBindingTarget::Array2D);
+    assert_eq!(container.level_dimensions(1), (4, 2, 2));
+    assert_eq!(container.level_size(0), Some(32));
+    assert_eq!(container.levels[1], [bc4_block(15), bc4_block(25)].concat());
+
+    let texture = load_texture_container(&bytes).unwrap();
+    let mut read = [0u8; 64];
+    get_tex_image(
+        TextureImageTarget::Array2D,
+        0,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        &mut read,
+    );
+    assert_eq!(read[..32], [10; 32]);
+    assert_eq!(read[32..], [20; 32]);
+    let mut read = [0u8; 16];
+    get_tex_image(
+        TextureImageTarget::Array2D,
+        1,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        &mut read,
+    );
+    assert_eq!(read[..8], [15; 8]);
+    assert_eq!(read[8..], [25; 8]);
+    delete_textures(&[texture]);
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn compressed_uploads() {
+    let _context = HeadlessContext::new(1, 1).unwrap();
+    let mut texture = Texture::default();
+    gen_textures(std::slice::from_mut(&mut texture));
+    bind_texture(TextureBindingTarget::Image2D, texture);
+
+    let format = TextureInternalFormat::CompressedRRGTC1;
+    let blocks = [bc4_block(50), bc4_block(60)].concat();
+    compressed_tex_image_2d_checked(
+        TextureBinding2DTarget::Image2D,
+        0,
+        format,
+        8,
+        4,
+        CompressedTextureData::Data(&blocks),
+    )
+    .unwrap();
+    compressed_tex_sub_image_2d_checked(
+        TextureBinding2DTarget::Image2D,
+        0,
+        4,
+        0,
+        4,
+        4,
+        format,
+        CompressedTextureData::Data(&bc4_block(70)),
+    )
+    .unwrap();
+    let mut read = [0u8; 16];
+    get_compressed_tex_image_checked(TextureImageTarget::Image2D, 0, &mut read).unwrap();
+    assert_eq!(read, [bc4_block(50), bc4_block(70)].concat().as_slice());
+
+    assert_eq!(
+        compressed_tex_image_2d_checked(
+            TextureBinding2DTarget::Image2D,
+            0,
+            format,
+            8,
+            4,
+            CompressedTextureData::Data(&blocks[..8]),
+        ),
+        Err(TextureError::InsufficientPixelData {
+            required: 16,
+            provided: 8
+        })
+    );
+    assert_eq!(
+        compressed_tex_sub_image_2d_checked(
+            TextureBinding2DTarget::Image2D,
+            0,
+            2,
+            0,
+            4,
+            4,
+            format,
+            CompressedTextureData::Data(&blocks),
+        ),
+        Err(TextureError::InvalidRegion)
+    );
+    assert_eq!(
+        compressed_tex_sub_image_2d_checked(
+            TextureBinding2DTarget::Image2D,
+            0,
+            0,
+            0,
+            4,
+            4,
+            TextureInternalFormat::CompressedRGRGTC2,
+            CompressedTextureData::Data(&[0; 16]),
+        ),
+        Err(TextureError::InvalidCompressedFormat(
+            TextureInternalFormat::CompressedRGRGTC2
+        ))
+    );
+    assert_eq!(
+        compressed_tex_image_2d_checked(
+            TextureBinding2DTarget::Image2D,
+            0,
+            TextureInternalFormat::RGBA8,
+            1,
+            1,
+            CompressedTextureData::Data(&[0; 4]),
+        ),
+        Err(TextureError::InvalidCompressedFormat(
+            TextureInternalFormat::RGBA8
+        ))
+    );
+
+    tex_image_2d(
+        TextureBinding2DTarget::Image2D,
+        0,
+        TextureInternalFormat::R8,
+        4,
+        4,
+        TextureFormat::R,
+        TexturePixelType::U8,
+        TextureData::Data(&[0u8; 16]),
+    );
+    assert_eq!(
+        get_compressed_tex_image_checked(TextureImageTarget::Image2D, 0, &mut read),
+        Err(TextureError::UncompressedImage)
+    );
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn rejected_containers() {
+    assert_eq!(
+        TextureContainer::parse(b"PNG"),
+        Err(ContainerError::UnknownContainer)
+    );
+
+    let levels = [bc7_block(0).to_vec()];
+    let bytes = ktx2(VK_FORMAT_BC7_UNORM_BLOCK, [4, 4, 0, 0, 1], &levels);
+    assert_eq!(
+        TextureContainer::parse(&bytes[..bytes.len() - 1]),
+        Err(ContainerError::Malformed)
+    );
+
+    let bytes = ktx2(37, [4, 4, 0, 0, 1], &levels);
+    assert_eq!(
+        TextureContainer::parse(&bytes),
+        Err(ContainerError::UnsupportedFormat(37))
+    );
+
+    let mut bytes = ktx2(VK_FORMAT_BC5_UNORM_BLOCK, [4, 4, 0, 0, 1], &levels);
+    bytes[44] = 2;
+    assert_eq!(
+        TextureContainer::parse(&bytes),
+        Err(ContainerError::Supercompressed(2))
+    );
+
+    let bytes = ktx2(VK_FORMAT_BC5_UNORM_BLOCK, [4, 0, 0, 0, 1], &levels);
+    assert_eq!(
+        TextureContainer::parse(&bytes),
+        Err(ContainerError::UnsupportedDimensions)
+    );
+
+    let bytes = dds(DXGI_FORMAT_BC5_UNORM, 4, 4, 1, 1, false);
+    assert_eq!(
+        TextureContainer::parse(&bytes),
+        Err(ContainerError::Malformed)
+    );
+}
+
+#[test]
+fn malformed_headers() {
+    // more levels than a full mip chain of a 4x4 texture
+    let levels = [bc7_block(0).to_vec()];
+    for level_count in [4u32, 40, u32::MAX] {
+        let mut bytes = ktx2(VK_FORMAT_BC7_UNORM_BLOCK, [4, 4, 0, 0, 1], &levels);
+        bytes[40..44].copy_from_slice(&level_count.to_le_bytes());
+        assert_eq!(
+            TextureContainer::parse(&bytes),
+            Err(ContainerError::Malformed)
+        );
+
+        let mut bytes = dds(DXGI_FORMAT_BC4_UNORM, 4, 4, level_count, 1, false);
+        bytes.extend(bc4_block(0).repeat(64));
+        assert_eq!(
+            TextureContainer::parse(&bytes),
+            Err(ContainerError::Malformed)
+        );
+    }
+
+    // sizes that overflow, or that the file is far too short for
+    for extent in [
+        [u32::MAX, u32::MAX, 0, 0, 1],
+        [u32::MAX, 4, 0, 0, 6],
+        [4, 4, 0, u32::MAX, 6],
+    ] {
+        let bytes = ktx2(VK_FORMAT_BC7_UNORM_BLOCK, extent, &levels);
+        assert_eq!(
+            TextureContainer::parse(&bytes),
+            Err(ContainerError::Malformed)
+        );
+    }
+    for bytes in [
+        dds(DXGI_FORMAT_BC5_UNORM, 4, 4, 1, u32::MAX, true),
+        dds(DXGI_FORMAT_BC4_UNORM, 4, 4, 1, u32::MAX, false),
+        dds(DXGI_FORMAT_BC7_UNORM, u32::MAX, u32::MAX, 32, 1, false),
+        dds(DXGI_FORMAT_BC7_UNORM, u32::MAX, 4, 1, 0, true),
+    ] {
+        assert_eq!(
+            TextureContainer::parse(&bytes),
+            Err(ContainerError::Malformed)
+        );
+    }
+
+    // an 8x4 BC4 array of 2 layers and 2 levels, missing the last byte of its second layer
+    let mut bytes = dds(DXGI_FORMAT_BC4_UNORM, 8, 4, 2, 2, false);
+    bytes.extend(bc4_block(0).repeat(6));
+    bytes.pop();
+    assert_eq!(
+        TextureContainer::parse(&bytes),
+        Err(ContainerError::Malformed)
+    );
+    let mut bytes = ktx2(VK_FORMAT_BC7_UNORM_BLOCK, [8, 4, 0, 0, 1], &levels);
+    bytes.truncate(80 + 24);
+    assert_eq!(
+        TextureContainer::parse(&bytes),
+        Err(ContainerError::Malformed)
+    );
+}
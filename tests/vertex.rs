@@ -0,0 +1,139 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use rgl::headless::HeadlessContext;
+use rgl::prelude::*;
+
+const WIDTH: u32 = 8;
+const HEIGHT: u32 = 8;
+
+fn pixel(pixels: &[[u8; 4]], x: u32, y: u32) -> [u8; 4] {
+    pixels[(y * WIDTH + x) as usize]
+}
+
+#[test]
+fn hello_triangle() {
+    let context = HeadlessContext::new(WIDTH, HEIGHT).unwrap();
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    let (vao, vbo) = common::vertex_array(&[[-1.0, -1.0], [1.0, -1.0], [-1.0, 1.0]]);
+    assert!(is_vertex_array(vao));
+
+    clear_colour(0.0, 0.0, 0.0, 1.0);
+    clear(ClearMask::COLOUR);
+    use_program(program);
+    uniform_4f32(get_uniform_location(program, c"colour"), 1.0, 0.0, 0.0, 1.0);
+    draw_arrays(DrawMode::Triangles, 0, 3);
+
+    let pixels = context.read_colour();
+    assert_eq!(pixel(&pixels, 0, 0), [255, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, WIDTH - 1, HEIGHT - 1), [0, 0, 0, 255]);
+    assert_eq!(
+        pixels.iter().filter(|p| **p == [255, 0, 0, 255]).count(),
+        (WIDTH * (HEIGHT - 1) / 2) as usize
+    );
+
+    delete_vertex_arrays(&[vao]);
+    delete_buffers(&[vbo]);
+    assert!(!is_vertex_array(vao));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn draw_elements_quad() {
+    let context = HeadlessContext::new(WIDTH, HEIGHT).unwrap();
+    let program = common::link(
+        common::POSITION_VERTEX_SHADER,
+        common::UNIFORM_COLOUR_FRAGMENT_SHADER,
+    );
+    common::vertex_array(&[[-1.0, -1.0], [1.0, -1.0], [1.0, 1.0], [-1.0, 1.0]]);
+
+    let mut ebo = Buffer::default();
+    gen_buffers(std::slice::from_mut(&mut ebo));
+    bind_buffer(BufferBindingTarget::ElementArray, ebo);
+    buffer_data(
+        BufferBindingTarget::ElementArray,
+        &[0u16, 1, 2, 0, 2, 3],
+        BufferUsageFrequency::Static,
+        BufferUsageNature::Draw,
+    );
+
+    use_program(program);
+    uniform_4f32(get_uniform_location(program, c"colour"), 1.0, 1.0, 0.0, 1.0);
+    clear(ClearMask::COLOUR);
+    draw_elements(DrawMode::Triangles, 3, DrawIndexType::U16, 0);
+    let pixels = context.read_colour();
+    assert_eq!(pixel(&pixels, WIDTH - 1, 0), [255, 255, 0, 255]);
+    assert_eq!(pixel(&pixels, 0, HEIGHT - 1), [0, 0, 0, 0]);
+
+    draw_elements(DrawMode::Triangles, 3, DrawIndexType::U16, 3 * 2);
+    assert!(context
+        .read_colour()
+        .iter()
+        .all(|p| *p == [255, 255, 0, 255]));
+    assert_eq!(get_error(), Error::NoError);
+}
+
+#[test]
+fn integer_attribute_and_divisor() {
+    let context = HeadlessContext::new(2, 1).unwrap();
+    let program = common::link(
+        r#"
+        #version 330 core
+        layout (location=0) in vec2 position;
+        layout (location=1) in uint shade;
+        flat out uint v_shade;
+        void main() {
+            v_shade = shade;
+            gl_Position = vec4(position, 0.0, 1.0);
+        }
+        "#,
+        r#"
+        #version 330 core
+        flat in uint v_shade;
+        out vec4 fragment_colour;
+        void main() {
+            fragment_colour = vec4(float(v_shade) / 255.0, 0.0, 0.0, 1.0);
+        }
+        "#,
+    );
+
+    // one shade per instance
+    let (_vao, _vbo) = common::vertex_array(&common::LEFT_HALF_QUAD);
+    let mut shades = Buffer::default();
+    gen_buffers(std::slice::from_mut(&mut shades));
+    bind_buffer(BufferBindingTarget::Array, shades);
+    buffer_data(
+        BufferBindingTarget::Array,
+        &[51u32, 204],
+        BufferUsageFrequency::Static,
+        BufferUsageNature::Draw,
+    );
+    enable_vertex_attrib_array(1);
+    vertex_attrib_integer_pointer(
+        1,
+        VertexAttribSize::Single,
+        VertexAttribFloatType::Integer(VertexAttribIntegerType::U32),
+        0,
+        0,
+    );
+    vertex_attrib_divisor(1, 1);
+
+    let mut ebo = Buffer::default();
+    gen_buffers(std::slice::from_mut(&mut ebo));
+    bind_buffer(BufferBindingTarget::ElementArray, ebo);
+    buffer_data(
+        BufferBindingTarget::ElementArray,
+        &[0u8, 1, 2, 3, 4, 5],
+        BufferUsageFrequency::Static,
+        BufferUsageNature::Draw,
+    );
+
+    use_program(program);
+    draw_elements_instanced(DrawMode::Triangles, 6, DrawIndexType::U8, 0, 1);
+    assert_eq!(context.read_colour(), [[51, 0, 0, 255], [0, 0, 0, 0]]);
+    assert_eq!(get_error(), Error::NoError);
+}